@@ -16,6 +16,38 @@ GOOGLE_CALLBACK_URL="http://localhost:8080/auth/google/callback"
 
 COOKIE_URL="localhost:8080" # or .yourdomain.com
 
+# Proxies allowed to set X-Forwarded-For, comma separated. Defaults to the
+# host and Docker's bridge (nginx in front of "docker run -p"); "" trusts no one.
+# TRUSTED_PROXIES="127.0.0.0/8, ::1, 172.17.0.0/16"
+
+# How emails are sent: "smtp", "file" (written to MAIL_DIR) or "log"
+MAIL_TRANSPORT="log"
+MAIL_FROM="CTRunner <no-reply@localhost>"
+# MAIL_DIR="db/emails"
+# SMTP_HOST="smtp.example.com"
+# SMTP_PORT="587"
+# SMTP_USERNAME=""
+# SMTP_PASSWORD=""
+
+# Web Push keys, from "npx web-push generate-vapid-keys" (push is off without them)
+# VAPID_PRIVATE_KEY=""
+VAPID_SUBJECT="mailto:no-reply@localhost"
+
+# Strava, for matching activities to races (off without a client ID)
+# STRAVA_CLIENT_ID=""
+# STRAVA_CLIENT_SECRET=""
+STRAVA_CALLBACK_URL="http://localhost:8080/activities/strava/callback"
+# STRAVA_BASE_URL="http://localhost:18099" # a local mock of the Strava API
+
+# Let webhooks post to localhost and private networks (handy for testing)
+# WEBHOOKS_ALLOW_PRIVATE_URLS="true"
+
+# Override a rate limit with "<requests>/<seconds>"
+# RATE_LIMIT_SIGN_IN="10/60"
+# RATE_LIMIT_SUBMIT_TOWN="5/60"
+# RATE_LIMIT_ADD_RACE="20/3600"
+# RATE_LIMIT_SEARCH="120/60"
+
 # THIS GOES TO A DUMMY FORM, CHANGE FOR PRODUCTION
 SUBMIT_FORM_ID="1FAIpQLScHViJvQL0G_ZPuCZOIFNsBPthZwDSzbkgiFFeL93wp831diA"
 SUBMIT_MEMBER_ID="1858653824"
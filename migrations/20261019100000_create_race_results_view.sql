@@ -0,0 +1,13 @@
+-- Race Results View
+CREATE VIEW race_results_view AS
+SELECT
+	rr.*,
+	r.name AS race_name,
+	r.town_id,
+	r.town,
+	r.county,
+	r.is_elusive,
+	r.miles,
+	r.start_at
+FROM race_results rr
+LEFT JOIN races_view r ON r.id = rr.race_id;
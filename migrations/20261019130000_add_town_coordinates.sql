@@ -0,0 +1,173 @@
+-- Approximate centre of each town, used for distance based searches.
+ALTER TABLE towns ADD COLUMN latitude REAL;
+ALTER TABLE towns ADD COLUMN longitude REAL;
+
+UPDATE towns SET latitude = 41.7255, longitude = -72.3836 WHERE name = 'Andover';
+UPDATE towns SET latitude = 41.3271, longitude = -73.0641 WHERE name = 'Ansonia';
+UPDATE towns SET latitude = 41.8872, longitude = -72.1829 WHERE name = 'Ashford';
+UPDATE towns SET latitude = 41.7853, longitude = -72.8631 WHERE name = 'Avon';
+UPDATE towns SET latitude = 41.9302, longitude = -72.9863 WHERE name = 'Barkhamsted';
+UPDATE towns SET latitude = 41.4243, longitude = -73.0549 WHERE name = 'Beacon Falls';
+UPDATE towns SET latitude = 41.6008, longitude = -72.7796 WHERE name = 'Berlin';
+UPDATE towns SET latitude = 41.4110, longitude = -72.9906 WHERE name = 'Bethany';
+UPDATE towns SET latitude = 41.3590, longitude = -73.3886 WHERE name = 'Bethel';
+UPDATE towns SET latitude = 41.6285, longitude = -73.2156 WHERE name = 'Bethlehem';
+UPDATE towns SET latitude = 41.8385, longitude = -72.7515 WHERE name = 'Bloomfield';
+UPDATE towns SET latitude = 41.7598, longitude = -72.4476 WHERE name = 'Bolton';
+UPDATE towns SET latitude = 41.5323, longitude = -72.1766 WHERE name = 'Bozrah';
+UPDATE towns SET latitude = 41.2667, longitude = -72.7921 WHERE name = 'Branford';
+UPDATE towns SET latitude = 41.1718, longitude = -73.1863 WHERE name = 'Bridgeport';
+UPDATE towns SET latitude = 41.5089, longitude = -73.3606 WHERE name = 'Bridgewater';
+UPDATE towns SET latitude = 41.6729, longitude = -72.9463 WHERE name = 'Bristol';
+UPDATE towns SET latitude = 41.4542, longitude = -73.3912 WHERE name = 'Brookfield';
+UPDATE towns SET latitude = 41.7800, longitude = -71.9638 WHERE name = 'Brooklyn';
+UPDATE towns SET latitude = 41.7538, longitude = -72.9672 WHERE name = 'Burlington';
+UPDATE towns SET latitude = 41.9598, longitude = -73.3242 WHERE name = 'Canaan';
+UPDATE towns SET latitude = 41.6886, longitude = -72.0064 WHERE name = 'Canterbury';
+UPDATE towns SET latitude = 41.8565, longitude = -72.9197 WHERE name = 'Canton';
+UPDATE towns SET latitude = 41.7868, longitude = -72.1371 WHERE name = 'Chaplin';
+UPDATE towns SET latitude = 41.4986, longitude = -72.9044 WHERE name = 'Cheshire';
+UPDATE towns SET latitude = 41.3896, longitude = -72.4804 WHERE name = 'Chester';
+UPDATE towns SET latitude = 41.2798, longitude = -72.5238 WHERE name = 'Clinton';
+UPDATE towns SET latitude = 41.5497, longitude = -72.3495 WHERE name = 'Colchester';
+UPDATE towns SET latitude = 42.0022, longitude = -73.0963 WHERE name = 'Colebrook';
+UPDATE towns SET latitude = 41.6851, longitude = -72.3133 WHERE name = 'Columbia';
+UPDATE towns SET latitude = 41.8444, longitude = -73.3405 WHERE name = 'Cornwall';
+UPDATE towns SET latitude = 41.7766, longitude = -72.3484 WHERE name = 'Coventry';
+UPDATE towns SET latitude = 41.6015, longitude = -72.6673 WHERE name = 'Cromwell';
+UPDATE towns SET latitude = 41.3864, longitude = -73.4673 WHERE name = 'Danbury';
+UPDATE towns SET latitude = 41.0572, longitude = -73.4692 WHERE name = 'Darien';
+UPDATE towns SET latitude = 41.3516, longitude = -72.4582 WHERE name = 'Deep River';
+UPDATE towns SET latitude = 41.3088, longitude = -73.0776 WHERE name = 'Derby';
+UPDATE towns SET latitude = 41.4477, longitude = -72.6821 WHERE name = 'Durham';
+UPDATE towns SET latitude = 41.8883, longitude = -72.1041 WHERE name = 'Eastford';
+UPDATE towns SET latitude = 41.9411, longitude = -72.7550 WHERE name = 'East Granby';
+UPDATE towns SET latitude = 41.4654, longitude = -72.3941 WHERE name = 'East Haddam';
+UPDATE towns SET latitude = 41.5579, longitude = -72.5099 WHERE name = 'East Hampton';
+UPDATE towns SET latitude = 41.7570, longitude = -72.6236 WHERE name = 'East Hartford';
+UPDATE towns SET latitude = 41.2803, longitude = -72.8513 WHERE name = 'East Haven';
+UPDATE towns SET latitude = 41.3495, longitude = -72.2315 WHERE name = 'East Lyme';
+UPDATE towns SET latitude = 41.9022, longitude = -72.5800 WHERE name = 'East Windsor';
+UPDATE towns SET latitude = 41.2459, longitude = -73.2927 WHERE name = 'Easton';
+UPDATE towns SET latitude = 41.9125, longitude = -72.4616 WHERE name = 'Ellington';
+UPDATE towns SET latitude = 41.9834, longitude = -72.5699 WHERE name = 'Enfield';
+UPDATE towns SET latitude = 41.3321, longitude = -72.4101 WHERE name = 'Essex';
+UPDATE towns SET latitude = 41.1564, longitude = -73.2633 WHERE name = 'Fairfield';
+UPDATE towns SET latitude = 41.7218, longitude = -72.8481 WHERE name = 'Farmington';
+UPDATE towns SET latitude = 41.6041, longitude = -72.1472 WHERE name = 'Franklin';
+UPDATE towns SET latitude = 41.6836, longitude = -72.5533 WHERE name = 'Glastonbury';
+UPDATE towns SET latitude = 41.8469, longitude = -73.2443 WHERE name = 'Goshen';
+UPDATE towns SET latitude = 41.9690, longitude = -72.8495 WHERE name = 'Granby';
+UPDATE towns SET latitude = 41.0453, longitude = -73.6251 WHERE name = 'Greenwich';
+UPDATE towns SET latitude = 41.5729, longitude = -71.9258 WHERE name = 'Griswold';
+UPDATE towns SET latitude = 41.3442, longitude = -72.0245 WHERE name = 'Groton';
+UPDATE towns SET latitude = 41.3167, longitude = -72.6954 WHERE name = 'Guilford';
+UPDATE towns SET latitude = 41.4529, longitude = -72.5452 WHERE name = 'Haddam';
+UPDATE towns SET latitude = 41.3800, longitude = -72.9184 WHERE name = 'Hamden';
+UPDATE towns SET latitude = 41.7775, longitude = -72.0675 WHERE name = 'Hampton';
+UPDATE towns SET latitude = 41.7597, longitude = -72.6919 WHERE name = 'Hartford';
+UPDATE towns SET latitude = 42.0049, longitude = -72.9645 WHERE name = 'Hartland';
+UPDATE towns SET latitude = 41.7490, longitude = -73.0662 WHERE name = 'Harwinton';
+UPDATE towns SET latitude = 41.6497, longitude = -72.3955 WHERE name = 'Hebron';
+UPDATE towns SET latitude = 41.7238, longitude = -73.4609 WHERE name = 'Kent';
+UPDATE towns SET latitude = 41.8252, longitude = -71.8607 WHERE name = 'Killingly';
+UPDATE towns SET latitude = 41.3621, longitude = -72.5747 WHERE name = 'Killingworth';
+UPDATE towns SET latitude = 41.6208, longitude = -72.2445 WHERE name = 'Lebanon';
+UPDATE towns SET latitude = 41.4238, longitude = -72.0150 WHERE name = 'Ledyard';
+UPDATE towns SET latitude = 41.5896, longitude = -72.0177 WHERE name = 'Lisbon';
+UPDATE towns SET latitude = 41.7351, longitude = -73.2006 WHERE name = 'Litchfield';
+UPDATE towns SET latitude = 41.3797, longitude = -72.3408 WHERE name = 'Lyme';
+UPDATE towns SET latitude = 41.3225, longitude = -72.6236 WHERE name = 'Madison';
+UPDATE towns SET latitude = 41.7690, longitude = -72.5330 WHERE name = 'Manchester';
+UPDATE towns SET latitude = 41.7828, longitude = -72.2380 WHERE name = 'Mansfield';
+UPDATE towns SET latitude = 41.6225, longitude = -72.4588 WHERE name = 'Marlborough';
+UPDATE towns SET latitude = 41.5245, longitude = -72.7958 WHERE name = 'Meriden';
+UPDATE towns SET latitude = 41.5150, longitude = -73.1236 WHERE name = 'Middlebury';
+UPDATE towns SET latitude = 41.5047, longitude = -72.7156 WHERE name = 'Middlefield';
+UPDATE towns SET latitude = 41.5352, longitude = -72.6564 WHERE name = 'Middletown';
+UPDATE towns SET latitude = 41.2077, longitude = -73.0506 WHERE name = 'Milford';
+UPDATE towns SET latitude = 41.3209, longitude = -73.2198 WHERE name = 'Monroe';
+UPDATE towns SET latitude = 41.4485, longitude = -72.1560 WHERE name = 'Montville';
+UPDATE towns SET latitude = 41.6822, longitude = -73.2138 WHERE name = 'Morris';
+UPDATE towns SET latitude = 41.4757, longitude = -73.0514 WHERE name = 'Naugatuck';
+UPDATE towns SET latitude = 41.6674, longitude = -72.7915 WHERE name = 'New Britain';
+UPDATE towns SET latitude = 41.1376, longitude = -73.4885 WHERE name = 'New Canaan';
+UPDATE towns SET latitude = 41.4752, longitude = -73.4877 WHERE name = 'New Fairfield';
+UPDATE towns SET latitude = 41.8405, longitude = -73.0162 WHERE name = 'New Hartford';
+UPDATE towns SET latitude = 41.2930, longitude = -72.9188 WHERE name = 'New Haven';
+UPDATE towns SET latitude = 41.3355, longitude = -72.1003 WHERE name = 'New London';
+UPDATE towns SET latitude = 41.5945, longitude = -73.4247 WHERE name = 'New Milford';
+UPDATE towns SET latitude = 41.6786, longitude = -72.7368 WHERE name = 'Newington';
+UPDATE towns SET latitude = 41.3835, longitude = -73.2896 WHERE name = 'Newtown';
+UPDATE towns SET latitude = 41.9804, longitude = -73.2110 WHERE name = 'Norfolk';
+UPDATE towns SET latitude = 41.3474, longitude = -72.7727 WHERE name = 'North Branford';
+UPDATE towns SET latitude = 42.0238, longitude = -73.3096 WHERE name = 'North Canaan';
+UPDATE towns SET latitude = 41.3653, longitude = -72.8539 WHERE name = 'North Haven';
+UPDATE towns SET latitude = 41.4541, longitude = -71.8749 WHERE name = 'North Stonington';
+UPDATE towns SET latitude = 41.0950, longitude = -73.4107 WHERE name = 'Norwalk';
+UPDATE towns SET latitude = 41.5363, longitude = -72.0901 WHERE name = 'Norwich';
+UPDATE towns SET latitude = 41.3020, longitude = -72.2976 WHERE name = 'Old Lyme';
+UPDATE towns SET latitude = 41.2839, longitude = -72.3814 WHERE name = 'Old Saybrook';
+UPDATE towns SET latitude = 41.2637, longitude = -73.0205 WHERE name = 'Orange';
+UPDATE towns SET latitude = 41.4166, longitude = -73.1329 WHERE name = 'Oxford';
+UPDATE towns SET latitude = 41.6898, longitude = -71.9053 WHERE name = 'Plainfield';
+UPDATE towns SET latitude = 41.6656, longitude = -72.8626 WHERE name = 'Plainville';
+UPDATE towns SET latitude = 41.6556, longitude = -73.0314 WHERE name = 'Plymouth';
+UPDATE towns SET latitude = 41.8625, longitude = -71.9953 WHERE name = 'Pomfret';
+UPDATE towns SET latitude = 41.5880, longitude = -72.5924 WHERE name = 'Portland';
+UPDATE towns SET latitude = 41.5085, longitude = -72.0019 WHERE name = 'Preston';
+UPDATE towns SET latitude = 41.4861, longitude = -72.9763 WHERE name = 'Prospect';
+UPDATE towns SET latitude = 41.9057, longitude = -71.8843 WHERE name = 'Putnam';
+UPDATE towns SET latitude = 41.2873, longitude = -73.3852 WHERE name = 'Redding';
+UPDATE towns SET latitude = 41.2886, longitude = -73.4957 WHERE name = 'Ridgefield';
+UPDATE towns SET latitude = 41.6478, longitude = -72.6683 WHERE name = 'Rocky Hill';
+UPDATE towns SET latitude = 41.5429, longitude = -73.3021 WHERE name = 'Roxbury';
+UPDATE towns SET latitude = 41.4708, longitude = -72.2663 WHERE name = 'Salem';
+UPDATE towns SET latitude = 41.9868, longitude = -73.4405 WHERE name = 'Salisbury';
+UPDATE towns SET latitude = 41.6889, longitude = -72.0945 WHERE name = 'Scotland';
+UPDATE towns SET latitude = 41.3649, longitude = -73.0835 WHERE name = 'Seymour';
+UPDATE towns SET latitude = 41.8548, longitude = -73.4485 WHERE name = 'Sharon';
+UPDATE towns SET latitude = 41.2880, longitude = -73.1322 WHERE name = 'Shelton';
+UPDATE towns SET latitude = 41.5700, longitude = -73.5015 WHERE name = 'Sherman';
+UPDATE towns SET latitude = 41.8698, longitude = -72.8374 WHERE name = 'Simsbury';
+UPDATE towns SET latitude = 41.9944, longitude = -72.4686 WHERE name = 'Somers';
+UPDATE towns SET latitude = 41.8306, longitude = -72.5840 WHERE name = 'South Windsor';
+UPDATE towns SET latitude = 41.4612, longitude = -73.2319 WHERE name = 'Southbury';
+UPDATE towns SET latitude = 41.5945, longitude = -72.8833 WHERE name = 'Southington';
+UPDATE towns SET latitude = 41.6195, longitude = -72.0802 WHERE name = 'Sprague';
+UPDATE towns SET latitude = 41.9868, longitude = -72.3274 WHERE name = 'Stafford';
+UPDATE towns SET latitude = 41.0820, longitude = -73.5466 WHERE name = 'Stamford';
+UPDATE towns SET latitude = 41.6930, longitude = -71.8252 WHERE name = 'Sterling';
+UPDATE towns SET latitude = 41.3588, longitude = -71.8989 WHERE name = 'Stonington';
+UPDATE towns SET latitude = 41.1906, longitude = -73.1210 WHERE name = 'Stratford';
+UPDATE towns SET latitude = 41.9944, longitude = -72.6943 WHERE name = 'Suffield';
+UPDATE towns SET latitude = 41.6614, longitude = -73.0911 WHERE name = 'Thomaston';
+UPDATE towns SET latitude = 41.9779, longitude = -71.8891 WHERE name = 'Thompson';
+UPDATE towns SET latitude = 41.8749, longitude = -72.3769 WHERE name = 'Tolland';
+UPDATE towns SET latitude = 41.8312, longitude = -73.1384 WHERE name = 'Torrington';
+UPDATE towns SET latitude = 41.2410, longitude = -73.2010 WHERE name = 'Trumbull';
+UPDATE towns SET latitude = 41.9920, longitude = -72.1754 WHERE name = 'Union';
+UPDATE towns SET latitude = 41.8317, longitude = -72.4714 WHERE name = 'Vernon';
+UPDATE towns SET latitude = 41.5673, longitude = -71.8354 WHERE name = 'Voluntown';
+UPDATE towns SET latitude = 41.4446, longitude = -72.8030 WHERE name = 'Wallingford';
+UPDATE towns SET latitude = 41.7346, longitude = -73.3522 WHERE name = 'Warren';
+UPDATE towns SET latitude = 41.6439, longitude = -73.3192 WHERE name = 'Washington';
+UPDATE towns SET latitude = 41.5469, longitude = -73.0378 WHERE name = 'Waterbury';
+UPDATE towns SET latitude = 41.3523, longitude = -72.1446 WHERE name = 'Waterford';
+UPDATE towns SET latitude = 41.6063, longitude = -73.1213 WHERE name = 'Watertown';
+UPDATE towns SET latitude = 41.7608, longitude = -72.7623 WHERE name = 'West Hartford';
+UPDATE towns SET latitude = 41.2548, longitude = -72.9601 WHERE name = 'West Haven';
+UPDATE towns SET latitude = 41.2880, longitude = -72.4606 WHERE name = 'Westbrook';
+UPDATE towns SET latitude = 41.2086, longitude = -73.3640 WHERE name = 'Weston';
+UPDATE towns SET latitude = 41.1229, longitude = -73.3353 WHERE name = 'Westport';
+UPDATE towns SET latitude = 41.6931, longitude = -72.6767 WHERE name = 'Wethersfield';
+UPDATE towns SET latitude = 41.8861, longitude = -72.2716 WHERE name = 'Willington';
+UPDATE towns SET latitude = 41.1866, longitude = -73.4307 WHERE name = 'Wilton';
+UPDATE towns SET latitude = 41.9205, longitude = -73.1158 WHERE name = 'Winchester';
+UPDATE towns SET latitude = 41.7012, longitude = -72.1737 WHERE name = 'Windham';
+UPDATE towns SET latitude = 41.8678, longitude = -72.6853 WHERE name = 'Windsor';
+UPDATE towns SET latitude = 41.9248, longitude = -72.6682 WHERE name = 'Windsor Locks';
+UPDATE towns SET latitude = 41.5903, longitude = -72.9763 WHERE name = 'Wolcott';
+UPDATE towns SET latitude = 41.3397, longitude = -73.0056 WHERE name = 'Woodbridge';
+UPDATE towns SET latitude = 41.5503, longitude = -73.2091 WHERE name = 'Woodbury';
+UPDATE towns SET latitude = 41.9678, longitude = -72.0374 WHERE name = 'Woodstock';
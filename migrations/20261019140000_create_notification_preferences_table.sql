@@ -0,0 +1,20 @@
+CREATE TABLE notification_preferences (
+    user_id INTEGER PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    weekly_digest BOOLEAN NOT NULL DEFAULT 0,
+    instant_alerts BOOLEAN NOT NULL DEFAULT 0,
+    radius_miles INTEGER, -- NULL means anywhere in the state
+    unsubscribe_token TEXT NOT NULL UNIQUE,
+    last_digest_at DATETIME,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Update updated_at when there are changes
+CREATE TRIGGER set_notification_preferences_updated_at
+AFTER UPDATE ON notification_preferences
+FOR EACH ROW
+BEGIN
+    UPDATE notification_preferences
+    SET updated_at = CURRENT_TIMESTAMP
+    WHERE user_id = NEW.user_id;
+END;
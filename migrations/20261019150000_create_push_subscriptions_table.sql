@@ -0,0 +1,27 @@
+CREATE TABLE push_subscriptions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    endpoint TEXT NOT NULL UNIQUE,
+    p256dh TEXT NOT NULL,
+    auth TEXT NOT NULL,
+    user_agent TEXT,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_push_subscriptions_user_id ON push_subscriptions(user_id);
+
+-- Update updated_at when there are changes
+CREATE TRIGGER set_push_subscriptions_updated_at
+AFTER UPDATE ON push_subscriptions
+FOR EACH ROW
+BEGIN
+    UPDATE push_subscriptions
+    SET updated_at = CURRENT_TIMESTAMP
+    WHERE id = NEW.id;
+END;
+
+-- Every kind of push is opt-in
+ALTER TABLE notification_preferences ADD COLUMN push_race_reminders BOOLEAN NOT NULL DEFAULT 0;
+ALTER TABLE notification_preferences ADD COLUMN push_milestones BOOLEAN NOT NULL DEFAULT 0;
+ALTER TABLE notification_preferences ADD COLUMN push_submission_failures BOOLEAN NOT NULL DEFAULT 0;
@@ -0,0 +1,8 @@
+CREATE TABLE race_rsvps (
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    race_id INTEGER NOT NULL REFERENCES races(id) ON DELETE CASCADE,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (user_id, race_id)
+);
+
+CREATE INDEX idx_race_rsvps_race_id ON race_rsvps(race_id);
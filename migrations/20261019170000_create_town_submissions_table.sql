@@ -0,0 +1,25 @@
+CREATE TABLE town_submissions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    idempotency_key TEXT NOT NULL,
+    fingerprint TEXT NOT NULL, -- sha256 of the payload, so a key can't be reused for another
+    town_id INTEGER NOT NULL REFERENCES towns(id) ON DELETE CASCADE,
+    race_id INTEGER REFERENCES races(id) ON DELETE SET NULL,
+    status TEXT NOT NULL DEFAULT 'processing', -- processing, posted, completed or failed
+    error TEXT,
+    society_posted_at DATETIME,
+    completed_at DATETIME,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(user_id, idempotency_key)
+);
+
+-- Update updated_at when there are changes
+CREATE TRIGGER set_town_submissions_updated_at
+AFTER UPDATE ON town_submissions
+FOR EACH ROW
+BEGIN
+    UPDATE town_submissions
+    SET updated_at = CURRENT_TIMESTAMP
+    WHERE id = NEW.id;
+END;
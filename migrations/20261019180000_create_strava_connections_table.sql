@@ -0,0 +1,20 @@
+CREATE TABLE strava_connections (
+    user_id INTEGER PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    athlete_id INTEGER NOT NULL,
+    access_token TEXT NOT NULL,
+    refresh_token TEXT NOT NULL,
+    -- When the access token stops working, as a unix timestamp like Strava gives it
+    expires_at INTEGER NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Update updated_at when there are changes
+CREATE TRIGGER set_strava_connections_updated_at
+AFTER UPDATE ON strava_connections
+FOR EACH ROW
+BEGIN
+    UPDATE strava_connections
+    SET updated_at = CURRENT_TIMESTAMP
+    WHERE user_id = NEW.user_id;
+END;
@@ -0,0 +1,177 @@
+-- Town boundaries as GeoJSON MultiPolygons ([longitude, latitude] pairs),
+-- traced from public/images/ct_towns.svg and simplified to within about 10m.
+-- Neighbouring towns share the exact same border points.
+CREATE TABLE town_boundaries (
+    town_id INTEGER PRIMARY KEY REFERENCES towns(id) ON DELETE CASCADE,
+    geojson TEXT NOT NULL
+);
+
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.3374,41.73238],[-72.36341,41.7605],[-72.39014,41.75721],[-72.41491,41.7587],[-72.419,41.75896],[-72.41811,41.75538],[-72.4131,41.73502],[-72.41241,41.73],[-72.4114,41.7262],[-72.43041,41.72398],[-72.42127,41.71101],[-72.41095,41.6958],[-72.40454,41.68805],[-72.38097,41.69071],[-72.36727,41.69259],[-72.36181,41.69543],[-72.36073,41.69763],[-72.35788,41.70297],[-72.35082,41.7131],[-72.33672,41.73006],[-72.33597,41.73093],[-72.3374,41.73238]]]]}' FROM towns WHERE name = 'Andover';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.04449,41.34386],[-73.06763,41.34357],[-73.07723,41.34673],[-73.08039,41.34681],[-73.08789,41.34332],[-73.08908,41.3433],[-73.09078,41.34385],[-73.09086,41.3434],[-73.09147,41.34278],[-73.09221,41.34241],[-73.09245,41.34123],[-73.09369,41.34068],[-73.09418,41.33971],[-73.09527,41.33881],[-73.09596,41.33764],[-73.09702,41.33717],[-73.09784,41.33625],[-73.09791,41.33622],[-73.09765,41.33575],[-73.0916,41.32442],[-73.09151,41.324],[-73.09109,41.32357],[-73.09113,41.32265],[-73.09075,41.32192],[-73.08938,41.3218],[-73.08844,41.32214],[-73.08772,41.32217],[-73.08614,41.32147],[-73.08482,41.32059],[-73.08306,41.31779],[-73.08013,41.31629],[-73.07913,41.31556],[-73.07388,41.31326],[-73.07405,41.31303],[-73.07214,41.31268],[-73.07211,41.31233],[-73.06971,41.3102],[-73.06713,41.30917],[-73.06393,41.30867],[-73.06267,41.30827],[-73.06158,41.30839],[-73.06104,41.30824],[-73.0605,41.30779],[-73.05954,41.30751],[-73.05086,41.30593],[-73.04573,41.30734],[-73.03719,41.30858],[-73.03353,41.3091],[-73.03356,41.3093],[-73.03496,41.31472],[-73.04125,41.33129],[-73.0427,41.34315],[-73.04282,41.34388],[-73.04449,41.34386]]]]}' FROM towns WHERE name = 'Ansonia';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.12071,41.82801],[-72.12395,41.87415],[-72.13468,41.89072],[-72.1474,41.9096],[-72.14791,41.91015],[-72.15001,41.9115],[-72.15064,41.91218],[-72.15225,41.91325],[-72.15294,41.91498],[-72.15281,41.91563],[-72.15233,41.91595],[-72.15192,41.91682],[-72.15206,41.9181],[-72.1534,41.9208],[-72.15417,41.92107],[-72.15445,41.92162],[-72.15519,41.92197],[-72.15644,41.92322],[-72.15657,41.9244],[-72.15707,41.92457],[-72.15833,41.9262],[-72.1586,41.92802],[-72.15801,41.9294],[-72.1579,41.93039],[-72.15833,41.93109],[-72.15852,41.93244],[-72.15892,41.93302],[-72.15915,41.93409],[-72.15915,41.93499],[-72.1589,41.93547],[-72.15899,41.93589],[-72.16035,41.93664],[-72.16045,41.93779],[-72.16103,41.93842],[-72.16078,41.93897],[-72.16069,41.94011],[-72.16246,41.94147],[-72.16302,41.94144],[-72.16334,41.94177],[-72.16344,41.94267],[-72.16381,41.94344],[-72.16363,41.94466],[-72.16377,41.94536],[-72.16449,41.94656],[-72.1645,41.94706],[-72.1648,41.94739],[-72.16486,41.94841],[-72.16468,41.94911],[-72.165,41.94996],[-72.16502,41.95071],[-72.16576,41.95735],[-72.16617,41.95736],[-72.19882,41.95753],[-72.21274,41.95728],[-72.21719,41.95738],[-72.2306,41.95695],[-72.23087,41.95695],[-72.23088,41.95645],[-72.23106,41.94729],[-72.22958,41.90628],[-72.22954,41.89599],[-72.22843,41.88742],[-72.22902,41.8788],[-72.229,41.86831],[-72.22839,41.85045],[-72.22834,41.83347],[-72.22827,41.83022],[-72.22719,41.8302],[-72.202,41.82983],[-72.17947,41.82982],[-72.17543,41.82979],[-72.17514,41.82979],[-72.15866,41.82969],[-72.14837,41.82943],[-72.1436,41.8292],[-72.12948,41.82773],[-72.12483,41.82744],[-72.12064,41.82716],[-72.12071,41.82801]]]]}' FROM towns WHERE name = 'Ashford';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.80758,41.80275],[-72.80851,41.8068],[-72.80782,41.81],[-72.80815,41.81692],[-72.80882,41.81976],[-72.80872,41.82044],[-72.81131,41.82014],[-72.84733,41.81585],[-72.87924,41.8116],[-72.87999,41.81148],[-72.88309,41.81096],[-72.92536,41.8055],[-72.92472,41.80271],[-72.92859,41.80209],[-72.92958,41.80191],[-72.92955,41.80176],[-72.92982,41.80107],[-72.93065,41.80024],[-72.93655,41.79855],[-72.93698,41.7982],[-72.93725,41.79756],[-72.93657,41.79591],[-72.93553,41.79458],[-72.93449,41.79368],[-72.93412,41.79048],[-72.93316,41.78506],[-72.9332,41.78416],[-72.9337,41.78279],[-72.9335,41.78204],[-72.93284,41.78116],[-72.932,41.77887],[-72.93154,41.77829],[-72.93033,41.77746],[-72.92891,41.77404],[-72.92823,41.77334],[-72.92596,41.77221],[-72.91802,41.77078],[-72.91726,41.7707],[-72.91408,41.77055],[-72.91167,41.76992],[-72.91073,41.76909],[-72.90986,41.76687],[-72.90944,41.7627],[-72.90494,41.75937],[-72.90444,41.75882],[-72.90435,41.75842],[-72.8908,41.75945],[-72.85295,41.75462],[-72.84159,41.75343],[-72.82892,41.75184],[-72.80981,41.75256],[-72.80525,41.75267],[-72.80572,41.75622],[-72.80643,41.75877],[-72.80684,41.75954],[-72.80933,41.77284],[-72.80908,41.77743],[-72.80945,41.78243],[-72.80953,41.78672],[-72.80868,41.78672],[-72.80723,41.79109],[-72.80697,41.79296],[-72.80776,41.79589],[-72.80773,41.79624],[-72.80685,41.79766],[-72.80697,41.79946],[-72.80758,41.80275]]]]}' FROM towns WHERE name = 'Avon';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.91872,41.92301],[-72.91241,41.94218],[-72.9111,41.94697],[-72.91081,41.94707],[-72.90953,41.95069],[-72.90705,41.95875],[-72.90241,41.97306],[-72.93506,41.97176],[-73.01407,41.96821],[-73.03969,41.96652],[-73.04391,41.96628],[-73.04847,41.96596],[-73.04871,41.96489],[-73.05373,41.93867],[-73.05483,41.93505],[-73.06069,41.90679],[-73.06493,41.88754],[-73.06553,41.88484],[-73.05194,41.88589],[-72.96782,41.89181],[-72.95366,41.89284],[-72.92962,41.89445],[-72.92804,41.8946],[-72.91969,41.91996],[-72.91872,41.92301]]]]}' FROM towns WHERE name = 'Barkhamsted';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.02501,41.44598],[-73.02516,41.4475],[-73.02933,41.45228],[-73.03079,41.45143],[-73.0312,41.45143],[-73.03159,41.45206],[-73.03299,41.45266],[-73.03553,41.45211],[-73.03612,41.45254],[-73.0374,41.45224],[-73.03893,41.45294],[-73.03986,41.45482],[-73.0406,41.45507],[-73.0425,41.45437],[-73.04441,41.455],[-73.04571,41.45513],[-73.04878,41.45468],[-73.05128,41.45474],[-73.05159,41.45366],[-73.0527,41.45172],[-73.05275,41.45084],[-73.05229,41.44872],[-73.05293,41.44717],[-73.05356,41.44627],[-73.05469,41.44527],[-73.05566,41.44488],[-73.05745,41.44473],[-73.05836,41.44441],[-73.05942,41.44318],[-73.05994,41.44199],[-73.06114,41.44211],[-73.06331,41.44336],[-73.06651,41.44629],[-73.06779,41.44657],[-73.06876,41.44644],[-73.07,41.44727],[-73.07584,41.45093],[-73.07804,41.44226],[-73.08398,41.41834],[-73.09025,41.39817],[-73.08028,41.38842],[-73.0802,41.38829],[-73.07387,41.39365],[-73.07245,41.39488],[-73.06573,41.39516],[-73.06738,41.39599],[-73.06628,41.39826],[-73.06508,41.40021],[-73.06321,41.40263],[-73.06153,41.40592],[-73.06069,41.40877],[-73.03971,41.40604],[-73.03878,41.40589],[-73.03241,41.40503],[-73.02616,41.4042],[-73.02552,41.40877],[-73.02417,41.41381],[-73.02295,41.41963],[-73.02281,41.42357],[-73.02314,41.42897],[-73.02368,41.43733],[-73.02433,41.44178],[-73.02493,41.4458],[-73.02501,41.44598]]]]}' FROM towns WHERE name = 'Beacon Falls';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.71723,41.61879],[-72.71645,41.62004],[-72.71672,41.62122],[-72.71626,41.62199],[-72.7172,41.62254],[-72.71707,41.62309],[-72.71804,41.62379],[-72.72012,41.64193],[-72.72028,41.64323],[-72.722,41.6431],[-72.72397,41.64293],[-72.72604,41.64306],[-72.73006,41.64282],[-72.73216,41.64289],[-72.73183,41.64005],[-72.75401,41.63746],[-72.75423,41.63826],[-72.75484,41.64078],[-72.77609,41.63926],[-72.81282,41.6368],[-72.81873,41.63621],[-72.81899,41.63586],[-72.81986,41.63483],[-72.82068,41.63241],[-72.8222,41.63127],[-72.82343,41.62902],[-72.82603,41.62675],[-72.82765,41.62481],[-72.82825,41.62381],[-72.82815,41.62278],[-72.82913,41.62059],[-72.82921,41.61767],[-72.82187,41.61763],[-72.8237,41.61486],[-72.82564,41.61304],[-72.82613,41.61159],[-72.82423,41.61134],[-72.82494,41.60904],[-72.82612,41.60887],[-72.82656,41.6073],[-72.82304,41.60564],[-72.82202,41.60167],[-72.82248,41.60165],[-72.8215,41.59655],[-72.8239,41.59643],[-72.82691,41.59393],[-72.82756,41.58624],[-72.82822,41.58312],[-72.82918,41.5811],[-72.83331,41.57611],[-72.83623,41.57319],[-72.83765,41.5712],[-72.8395,41.56391],[-72.84083,41.56039],[-72.8419,41.55734],[-72.83019,41.55915],[-72.83188,41.55046],[-72.82754,41.55268],[-72.8278,41.54413],[-72.82545,41.54278],[-72.8238,41.54221],[-72.82127,41.5422],[-72.82045,41.5424],[-72.81747,41.54507],[-72.81198,41.55078],[-72.81086,41.55013],[-72.80686,41.55502],[-72.80323,41.56148],[-72.78402,41.5642],[-72.76127,41.56694],[-72.7546,41.56745],[-72.75463,41.56765],[-72.7562,41.58171],[-72.73485,41.5848],[-72.73467,41.5869],[-72.7335,41.5906],[-72.7305,41.59102],[-72.73059,41.59167],[-72.71871,41.5934],[-72.71769,41.59347],[-72.71779,41.59359],[-72.7191,41.59515],[-72.71925,41.59612],[-72.71716,41.60014],[-72.71711,41.60109],[-72.71644,41.60306],[-72.7163,41.60626],[-72.71497,41.60725],[-72.71481,41.60768],[-72.71514,41.60813],[-72.71671,41.60865],[-72.71692,41.60925],[-72.7169,41.6101],[-72.71716,41.61063],[-72.71774,41.61128],[-72.71915,41.61228],[-72.71961,41.613],[-72.71998,41.61485],[-72.71819,41.61735],[-72.71805,41.61757],[-72.71723,41.61879]]]]}' FROM towns WHERE name = 'Berlin';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.97019,41.45289],[-72.99865,41.45613],[-73.00009,41.45623],[-73.00022,41.45556],[-73.00217,41.44862],[-73.02341,41.44598],[-73.02493,41.4458],[-73.02433,41.44178],[-73.02368,41.43733],[-73.02314,41.42897],[-73.02281,41.42357],[-73.02295,41.41963],[-73.02417,41.41381],[-73.02552,41.40877],[-73.02616,41.4042],[-73.03241,41.40503],[-73.03878,41.40589],[-73.0426,41.38839],[-73.04377,41.38289],[-73.04439,41.38007],[-73.04419,41.37982],[-73.04379,41.3795],[-73.04263,41.37947],[-73.04096,41.37909],[-73.04019,41.37924],[-73.03957,41.37984],[-73.03921,41.37976],[-73.03762,41.37874],[-73.03694,41.37784],[-73.03584,41.37764],[-73.03496,41.37676],[-73.03502,41.37629],[-73.03459,41.37586],[-73.03334,41.37546],[-73.03195,41.37603],[-73.03085,41.37586],[-73.02907,41.37403],[-73.02684,41.3728],[-72.97961,41.37583],[-72.96279,41.3775],[-72.95229,41.37833],[-72.95021,41.38098],[-72.95008,41.38422],[-72.94191,41.39051],[-72.93563,41.39259],[-72.9367,41.39654],[-72.95043,41.40363],[-72.94942,41.4129],[-72.95312,41.41413],[-72.95951,41.41778],[-72.96098,41.4223],[-72.96082,41.42355],[-72.96106,41.4241],[-72.96065,41.42503],[-72.95913,41.42725],[-72.95785,41.42822],[-72.95638,41.43006],[-72.95568,41.43129],[-72.95551,41.43209],[-72.95603,41.43299],[-72.95615,41.43389],[-72.95563,41.43593],[-72.9552,41.43638],[-72.95088,41.43885],[-72.95045,41.4393],[-72.94982,41.44067],[-72.94756,41.44174],[-72.94674,41.44281],[-72.94573,41.44551],[-72.94633,41.44613],[-72.94648,41.44776],[-72.94673,41.45026],[-72.9486,41.45046],[-72.9508,41.45074],[-72.97019,41.45289]]]]}' FROM towns WHERE name = 'Bethany';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.37793,41.40895],[-73.39373,41.41217],[-73.40457,41.41439],[-73.40426,41.41151],[-73.40287,41.3998],[-73.4021,41.39767],[-73.40026,41.39395],[-73.39901,41.39057],[-73.39773,41.38823],[-73.39873,41.3869],[-73.39872,41.38638],[-73.40151,41.38636],[-73.4016,41.38603],[-73.40244,41.38568],[-73.40249,41.38501],[-73.40276,41.38489],[-73.40302,41.38404],[-73.4034,41.38364],[-73.40325,41.38344],[-73.40351,41.38324],[-73.40328,41.38289],[-73.40383,41.38264],[-73.40449,41.38197],[-73.40445,41.38137],[-73.40383,41.38102],[-73.40385,41.38067],[-73.419,41.3656],[-73.42106,41.36541],[-73.42873,41.3628],[-73.42904,41.36182],[-73.42957,41.3614],[-73.42959,41.36053],[-73.4302,41.36003],[-73.43018,41.35935],[-73.43081,41.35676],[-73.42161,41.3263],[-73.41908,41.31818],[-73.41219,41.31946],[-73.38913,41.32375],[-73.35119,41.33133],[-73.34203,41.33312],[-73.34224,41.33359],[-73.3509,41.35234],[-73.37301,41.3988],[-73.37612,41.40513],[-73.37793,41.40895]]]]}' FROM towns WHERE name = 'Bethel';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.16162,41.60411],[-73.16414,41.61368],[-73.16569,41.62062],[-73.1693,41.63496],[-73.17616,41.661],[-73.22825,41.65853],[-73.25458,41.65702],[-73.2665,41.65647],[-73.2661,41.65352],[-73.26032,41.61587],[-73.25667,41.59476],[-73.25274,41.59513],[-73.16995,41.60235],[-73.16129,41.60298],[-73.16162,41.60411]]]]}' FROM towns WHERE name = 'Bethlehem';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.75073,41.88986],[-72.75568,41.89284],[-72.76047,41.89374],[-72.76108,41.89402],[-72.76354,41.89557],[-72.76714,41.89728],[-72.76932,41.89868],[-72.77087,41.8993],[-72.77293,41.90081],[-72.77321,41.90128],[-72.77328,41.9028],[-72.77199,41.90415],[-72.77168,41.90455],[-72.77214,41.90425],[-72.77676,41.90041],[-72.77899,41.89445],[-72.78011,41.89305],[-72.78095,41.89033],[-72.78165,41.89008],[-72.78197,41.88843],[-72.78197,41.88621],[-72.7825,41.88426],[-72.78309,41.88301],[-72.78354,41.88257],[-72.78328,41.88119],[-72.78226,41.87884],[-72.78222,41.87757],[-72.78325,41.87632],[-72.78354,41.87525],[-72.78486,41.87465],[-72.78621,41.8729],[-72.78636,41.87116],[-72.78705,41.86988],[-72.78776,41.86946],[-72.78927,41.86619],[-72.79073,41.86215],[-72.79591,41.85179],[-72.79585,41.84989],[-72.79612,41.84907],[-72.79593,41.84519],[-72.79628,41.84405],[-72.79668,41.84365],[-72.79664,41.84287],[-72.79693,41.84205],[-72.79693,41.84125],[-72.79629,41.83955],[-72.7962,41.83885],[-72.79662,41.83818],[-72.79656,41.83713],[-72.79598,41.83493],[-72.7941,41.83215],[-72.79439,41.83043],[-72.79493,41.82888],[-72.79385,41.82534],[-72.79341,41.82216],[-72.79416,41.82209],[-72.80872,41.82044],[-72.80882,41.81976],[-72.80815,41.81692],[-72.80782,41.81],[-72.80851,41.8068],[-72.80758,41.80275],[-72.80697,41.79946],[-72.76459,41.80054],[-72.74545,41.80101],[-72.72782,41.80146],[-72.70491,41.80215],[-72.70088,41.80227],[-72.69984,41.79587],[-72.69534,41.79507],[-72.69547,41.79769],[-72.6959,41.80306],[-72.70089,41.86216],[-72.75514,41.87423],[-72.75418,41.87705],[-72.75006,41.88948],[-72.75073,41.88986]]]]}' FROM towns WHERE name = 'Bloomfield';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.4201,41.76395],[-72.42744,41.79703],[-72.42829,41.80123],[-72.43867,41.7996],[-72.4692,41.7948],[-72.48307,41.79267],[-72.48192,41.78697],[-72.47307,41.74305],[-72.47258,41.74233],[-72.47217,41.73988],[-72.47242,41.73931],[-72.472,41.73828],[-72.46757,41.71962],[-72.45749,41.72075],[-72.43913,41.72292],[-72.43041,41.72398],[-72.4114,41.7262],[-72.41241,41.73],[-72.4131,41.73502],[-72.41811,41.75538],[-72.419,41.75896],[-72.4201,41.76395]]]]}' FROM towns WHERE name = 'Bolton';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.13764,41.55797],[-72.13912,41.56185],[-72.14194,41.56447],[-72.19395,41.57766],[-72.1973,41.57852],[-72.19792,41.5773],[-72.2056,41.56182],[-72.21828,41.53095],[-72.22023,41.5269],[-72.22073,41.52593],[-72.22151,41.52423],[-72.22553,41.51595],[-72.22807,41.51123],[-72.22893,41.50771],[-72.22908,41.50546],[-72.22907,41.50499],[-72.22283,41.50378],[-72.19383,41.49797],[-72.19337,41.49879],[-72.19263,41.49916],[-72.19287,41.49986],[-72.19251,41.50021],[-72.19179,41.50031],[-72.18841,41.49963],[-72.18725,41.49898],[-72.18455,41.49878],[-72.18321,41.49887],[-72.18299,41.50055],[-72.18349,41.50175],[-72.18348,41.5023],[-72.1816,41.50254],[-72.17935,41.50217],[-72.17182,41.50001],[-72.17049,41.49873],[-72.16939,41.49843],[-72.16644,41.49655],[-72.16578,41.49648],[-72.16538,41.49667],[-72.16327,41.49607],[-72.16264,41.4961],[-72.16139,41.49574],[-72.15963,41.49484],[-72.15439,41.49339],[-72.15394,41.49306],[-72.15236,41.49298],[-72.15176,41.49243],[-72.1502,41.49236],[-72.14963,41.49188],[-72.14838,41.49148],[-72.14731,41.49158],[-72.14554,41.49093],[-72.14466,41.49085],[-72.14421,41.4904],[-72.14328,41.49005],[-72.14155,41.48992],[-72.13717,41.49079],[-72.13658,41.49059],[-72.13575,41.48964],[-72.1356,41.48954],[-72.13473,41.49196],[-72.13365,41.49473],[-72.13989,41.51657],[-72.14219,41.52259],[-72.13531,41.53479],[-72.13371,41.55175],[-72.1354,41.55305],[-72.1356,41.55392],[-72.13706,41.5549],[-72.1372,41.55543],[-72.13772,41.55588],[-72.13788,41.55628],[-72.1383,41.5565],[-72.13814,41.55688],[-72.13756,41.55697],[-72.13737,41.5571],[-72.13764,41.55797]]]]}' FROM towns WHERE name = 'Bozrah';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.74378,41.29108],[-72.76544,41.28641],[-72.77493,41.29467],[-72.7944,41.30594],[-72.81691,41.3003],[-72.8184,41.29988],[-72.81972,41.29943],[-72.82932,41.29605],[-72.83063,41.29528],[-72.83215,41.29303],[-72.83278,41.29066],[-72.8333,41.28684],[-72.83388,41.28444],[-72.83499,41.28167],[-72.83638,41.2794],[-72.83826,41.27748],[-72.83983,41.27631],[-72.84129,41.27556],[-72.84442,41.27452],[-72.84794,41.2728],[-72.84951,41.27153],[-72.85149,41.26946],[-72.85243,41.26836],[-72.85351,41.26639],[-72.85387,41.26462],[-72.85377,41.26354],[-72.85352,41.26272],[-72.85235,41.26167],[-72.85236,41.26052],[-72.85274,41.25945],[-72.85403,41.25768],[-72.854,41.25693],[-72.85452,41.25618],[-72.85498,41.25618],[-72.85572,41.25418],[-72.85561,41.25221],[-72.85574,41.25171],[-72.85714,41.24966],[-72.85688,41.24924],[-72.85657,41.24914],[-72.85638,41.24929],[-72.85628,41.25019],[-72.85558,41.25029],[-72.85525,41.24996],[-72.8554,41.24919],[-72.85527,41.24881],[-72.85427,41.24886],[-72.85341,41.24843],[-72.85325,41.24808],[-72.85339,41.24791],[-72.85448,41.24774],[-72.85435,41.24744],[-72.85346,41.24679],[-72.85323,41.24584],[-72.85181,41.24616],[-72.85088,41.24608],[-72.84865,41.24466],[-72.84605,41.24448],[-72.84572,41.244],[-72.8456,41.24335],[-72.84464,41.24218],[-72.84489,41.23923],[-72.84482,41.23915],[-72.84454,41.23888],[-72.84457,41.23831],[-72.84396,41.23723],[-72.84443,41.23698],[-72.8447,41.23766],[-72.84531,41.23786],[-72.84552,41.23766],[-72.84559,41.23731],[-72.84599,41.23703],[-72.84646,41.23629],[-72.84634,41.23579],[-72.8466,41.23554],[-72.84648,41.23471],[-72.8469,41.23401],[-72.84717,41.23426],[-72.84736,41.23416],[-72.8478,41.23329],[-72.84756,41.23259],[-72.847,41.23269],[-72.84687,41.23304],[-72.84564,41.23276],[-72.8452,41.23321],[-72.84516,41.23354],[-72.84503,41.23336],[-72.84479,41.23346],[-72.84456,41.23393],[-72.84417,41.23306],[-72.84383,41.23321],[-72.8438,41.23293],[-72.84353,41.23283],[-72.84313,41.23316],[-72.84307,41.23293],[-72.84261,41.23303],[-72.84267,41.23333],[-72.84296,41.23316],[-72.84292,41.23336],[-72.84311,41.23338],[-72.8428,41.23351],[-72.84264,41.23386],[-72.8426,41.23428],[-72.84285,41.23426],[-72.84259,41.23451],[-72.84281,41.23456],[-72.84256,41.23523],[-72.84277,41.23571],[-72.84316,41.23583],[-72.84299,41.23596],[-72.84316,41.23591],[-72.84309,41.23606],[-72.84375,41.23618],[-72.84343,41.23673],[-72.8427,41.23665],[-72.84255,41.23723],[-72.84039,41.23747],[-72.8399,41.23742],[-72.83915,41.23677],[-72.83866,41.23727],[-72.83841,41.2372],[-72.83841,41.23695],[-72.8378,41.23705],[-72.8375,41.2368],[-72.83734,41.23725],[-72.83768,41.23717],[-72.8376,41.2375],[-72.83789,41.23762],[-72.83775,41.23767],[-72.83772,41.23842],[-72.83676,41.23867],[-72.83622,41.23862],[-72.83607,41.23842],[-72.83576,41.23877],[-72.83516,41.23897],[-72.83519,41.23924],[-72.83435,41.23946],[-72.83369,41.23924],[-72.83358,41.23884],[-72.83381,41.23841],[-72.83364,41.23836],[-72.8342,41.23779],[-72.83416,41.23737],[-72.83382,41.23719],[-72.83395,41.23687],[-72.83382,41.23639],[-72.8334,41.23704],[-72.83297,41.23724],[-72.83208,41.23674],[-72.83241,41.23721],[-72.83158,41.23726],[-72.83124,41.23714],[-72.83143,41.23684],[-72.83111,41.23689],[-72.83101,41.23666],[-72.83119,41.23629],[-72.83095,41.23569],[-72.83227,41.23511],[-72.83234,41.23487],[-72.83197,41.23466],[-72.83196,41.23442],[-72.8323,41.23442],[-72.83267,41.23374],[-72.83322,41.23347],[-72.83297,41.23309],[-72.83254,41.23357],[-72.83237,41.23347],[-72.83251,41.23327],[-72.83218,41.23297],[-72.83244,41.23267],[-72.83234,41.23172],[-72.83205,41.23164],[-72.83216,41.23147],[-72.83056,41.23234],[-72.83026,41.23276],[-72.83052,41.23339],[-72.83007,41.23311],[-72.82967,41.23329],[-72.8297,41.23351],[-72.82999,41.23364],[-72.83011,41.23426],[-72.83041,41.23456],[-72.8307,41.23454],[-72.83073,41.23481],[-72.82985,41.23539],[-72.82989,41.23574],[-72.82932,41.23561],[-72.82848,41.23603],[-72.82856,41.23538],[-72.82836,41.23496],[-72.82863,41.23448],[-72.82846,41.23426],[-72.82924,41.23299],[-72.82993,41.23241],[-72.8299,41.23204],[-72.831,41.23154],[-72.83101,41.23117],[-72.83087,41.23137],[-72.8307,41.23114],[-72.83041,41.23134],[-72.83026,41.23112],[-72.8299,41.23129],[-72.82925,41.23066],[-72.82807,41.23046],[-72.82848,41.23029],[-72.82836,41.23016],[-72.82857,41.23006],[-72.82844,41.22986],[-72.82856,41.22976],[-72.82954,41.22997],[-72.82932,41.22979],[-72.82956,41.22909],[-72.82889,41.22854],[-72.82883,41.22889],[-72.82854,41.22911],[-72.82791,41.22916],[-72.8278,41.22944],[-72.82758,41.22934],[-72.82771,41.22956],[-72.82746,41.22959],[-72.82754,41.22984],[-72.82704,41.22991],[-72.82612,41.23096],[-72.8267,41.23153],[-72.82606,41.23151],[-72.82613,41.23206],[-72.82556,41.23266],[-72.82575,41.23306],[-72.82536,41.23306],[-72.82577,41.23316],[-72.8259,41.23398],[-72.82615,41.23436],[-72.82606,41.23468],[-72.8256,41.23481],[-72.8254,41.2353],[-72.82458,41.2355],[-72.82456,41.2357],[-72.82434,41.23568],[-72.82428,41.23595],[-72.82391,41.23603],[-72.82373,41.2363],[-72.82362,41.2366],[-72.82384,41.23693],[-72.82358,41.23705],[-72.82359,41.23725],[-72.82379,41.23747],[-72.82403,41.23733],[-72.82427,41.23745],[-72.82446,41.2379],[-72.82398,41.2383],[-72.82346,41.23802],[-72.82285,41.23847],[-72.8229,41.23937],[-72.82415,41.23975],[-72.82424,41.24042],[-72.82399,41.2408],[-72.82339,41.2411],[-72.8228,41.24102],[-72.82283,41.24132],[-72.8224,41.24144],[-72.82234,41.24177],[-72.822,41.24187],[-72.82209,41.24332],[-72.82198,41.24374],[-72.82179,41.24377],[-72.82207,41.24407],[-72.82191,41.24459],[-72.8216,41.24479],[-72.82138,41.24461],[-72.82119,41.24484],[-72.82137,41.24514],[-72.82055,41.24526],[-72.81977,41.24514],[-72.81985,41.24489],[-72.81943,41.24449],[-72.81964,41.24414],[-72.81948,41.24376],[-72.81841,41.24376],[-72.81783,41.24448],[-72.81711,41.24428],[-72.81683,41.24383],[-72.81711,41.24323],[-72.81755,41.24326],[-72.8172,41.24308],[-72.81761,41.24226],[-72.81594,41.24176],[-72.81516,41.24218],[-72.81501,41.24208],[-72.81494,41.24226],[-72.81423,41.24206],[-72.8139,41.24173],[-72.81418,41.24143],[-72.81397,41.24086],[-72.81363,41.24086],[-72.81362,41.24043],[-72.81329,41.24066],[-72.81365,41.24141],[-72.81369,41.24205],[-72.81248,41.24213],[-72.8126,41.24278],[-72.81335,41.24293],[-72.81313,41.24348],[-72.81244,41.2433],[-72.81201,41.24348],[-72.81087,41.24347],[-72.80986,41.24307],[-72.80831,41.24342],[-72.80668,41.24332],[-72.80514,41.24372],[-72.80506,41.24426],[-72.80596,41.24509],[-72.8064,41.24527],[-72.80659,41.24507],[-72.80756,41.24512],[-72.8075,41.24479],[-72.8077,41.24474],[-72.80795,41.24517],[-72.80963,41.24609],[-72.80995,41.2461],[-72.80999,41.24662],[-72.81092,41.24695],[-72.81113,41.24732],[-72.81192,41.24765],[-72.8121,41.24797],[-72.81204,41.24857],[-72.81154,41.24885],[-72.81158,41.24934],[-72.81126,41.24997],[-72.81085,41.25019],[-72.81108,41.25039],[-72.81081,41.25054],[-72.81094,41.25142],[-72.81059,41.25187],[-72.80786,41.25271],[-72.8071,41.25331],[-72.80685,41.25378],[-72.80673,41.25371],[-72.80589,41.25508],[-72.80554,41.25535],[-72.8046,41.2542],[-72.80541,41.25366],[-72.80547,41.25313],[-72.80589,41.25266],[-72.80635,41.25251],[-72.80673,41.25221],[-72.8067,41.25203],[-72.80816,41.25139],[-72.80783,41.25079],[-72.80677,41.25119],[-72.80658,41.25059],[-72.80712,41.24989],[-72.8086,41.24976],[-72.81,41.24932],[-72.81006,41.24887],[-72.81068,41.24842],[-72.81061,41.24777],[-72.80966,41.24699],[-72.80793,41.24699],[-72.80713,41.24617],[-72.80479,41.24594],[-72.80448,41.24624],[-72.80523,41.24744],[-72.80519,41.24786],[-72.80547,41.24819],[-72.80481,41.24888],[-72.80385,41.24928],[-72.80355,41.24983],[-72.80331,41.24986],[-72.80308,41.24966],[-72.80312,41.24926],[-72.80275,41.24901],[-72.80202,41.24903],[-72.80137,41.24858],[-72.8022,41.24796],[-72.8033,41.24803],[-72.80323,41.24886],[-72.8034,41.24903],[-72.80431,41.24851],[-72.80431,41.24766],[-72.80374,41.24726],[-72.80337,41.24651],[-72.80253,41.24576],[-72.80244,41.24548],[-72.80156,41.24513],[-72.80189,41.24471],[-72.80138,41.24338],[-72.80162,41.24224],[-72.80073,41.24186],[-72.80056,41.24196],[-72.80068,41.24184],[-72.80038,41.24161],[-72.79934,41.24181],[-72.79906,41.24141],[-72.79873,41.24143],[-72.79855,41.24118],[-72.79866,41.24091],[-72.79849,41.24018],[-72.79772,41.24051],[-72.79718,41.24191],[-72.79661,41.24158],[-72.79648,41.24188],[-72.79524,41.24283],[-72.79466,41.24278],[-72.79446,41.24255],[-72.79425,41.24138],[-72.79369,41.2414],[-72.79432,41.24115],[-72.7944,41.2406],[-72.79593,41.23968],[-72.79644,41.23898],[-72.79696,41.23921],[-72.7965,41.23871],[-72.79684,41.23848],[-72.79803,41.23846],[-72.79976,41.23876],[-72.8006,41.23924],[-72.80128,41.23996],[-72.80136,41.24029],[-72.80084,41.24071],[-72.80087,41.24096],[-72.80263,41.24109],[-72.80267,41.24089],[-72.80207,41.24036],[-72.80196,41.23986],[-72.80227,41.23889],[-72.80174,41.23829],[-72.8026,41.23844],[-72.80397,41.23809],[-72.80397,41.23792],[-72.80336,41.23799],[-72.80332,41.23742],[-72.80415,41.23587],[-72.80609,41.23577],[-72.80726,41.23513],[-72.80841,41.23525],[-72.80905,41.23493],[-72.80962,41.23511],[-72.80993,41.23491],[-72.8112,41.23593],[-72.81117,41.23651],[-72.80952,41.23733],[-72.80921,41.23768],[-72.80951,41.23863],[-72.80945,41.23935],[-72.8099,41.23955],[-72.80992,41.24033],[-72.81041,41.24035],[-72.8112,41.2393],[-72.81173,41.23898],[-72.81224,41.23891],[-72.81239,41.23916],[-72.81303,41.23928],[-72.81296,41.23946],[-72.81322,41.23941],[-72.81324,41.23916],[-72.81399,41.23836],[-72.81374,41.23808],[-72.81381,41.23783],[-72.81472,41.23739],[-72.81505,41.23696],[-72.81496,41.23666],[-72.81513,41.23644],[-72.81692,41.23549],[-72.81647,41.23569],[-72.81532,41.23571],[-72.81408,41.23539],[-72.81282,41.23484],[-72.81028,41.23281],[-72.80978,41.23156],[-72.81016,41.23126],[-72.81074,41.23131],[-72.81107,41.23099],[-72.81096,41.23026],[-72.81124,41.22994],[-72.81054,41.22941],[-72.81065,41.22901],[-72.81108,41.22889],[-72.81084,41.22871],[-72.81088,41.22846],[-72.81052,41.22866],[-72.81045,41.22951],[-72.81021,41.22964],[-72.80958,41.22976],[-72.80884,41.22943],[-72.80843,41.22966],[-72.80821,41.22953],[-72.80803,41.23001],[-72.8076,41.23026],[-72.80694,41.23016],[-72.80631,41.23031],[-72.80527,41.231],[-72.80505,41.23095],[-72.80508,41.23123],[-72.80593,41.23125],[-72.80564,41.23148],[-72.80577,41.2317],[-72.80509,41.23158],[-72.80463,41.23178],[-72.8047,41.23155],[-72.80424,41.23163],[-72.80374,41.23125],[-72.8049,41.23018],[-72.80487,41.22993],[-72.80452,41.22975],[-72.80376,41.2302],[-72.80342,41.23018],[-72.80271,41.23095],[-72.80166,41.23142],[-72.80116,41.23187],[-72.80089,41.23177],[-72.8006,41.23194],[-72.80046,41.23212],[-72.80068,41.23197],[-72.80083,41.23227],[-72.79972,41.23332],[-72.79968,41.23354],[-72.79988,41.23369],[-72.79967,41.23394],[-72.79971,41.23444],[-72.79946,41.23452],[-72.79946,41.23437],[-72.79927,41.23462],[-72.79944,41.23459],[-72.79933,41.23576],[-72.799,41.23619],[-72.79881,41.23606],[-72.79908,41.23629],[-72.79719,41.23726],[-72.79512,41.23786],[-72.79335,41.23803],[-72.79259,41.238],[-72.79224,41.2376],[-72.7925,41.23735],[-72.79233,41.23728],[-72.79212,41.23763],[-72.79257,41.23815],[-72.7922,41.23855],[-72.79153,41.23885],[-72.7907,41.23957],[-72.78899,41.24037],[-72.78677,41.24062],[-72.78552,41.24051],[-72.78486,41.24026],[-72.784,41.23934],[-72.78336,41.23956],[-72.78338,41.24044],[-72.78231,41.24128],[-72.78209,41.24193],[-72.78127,41.24216],[-72.78137,41.24233],[-72.78116,41.24256],[-72.78036,41.24268],[-72.78023,41.24298],[-72.78066,41.243],[-72.78091,41.24323],[-72.78083,41.2437],[-72.78115,41.24385],[-72.78097,41.24418],[-72.7803,41.2447],[-72.77917,41.24517],[-72.77765,41.24542],[-72.77697,41.24532],[-72.77656,41.24462],[-72.77651,41.2454],[-72.77627,41.24564],[-72.77515,41.24572],[-72.7742,41.24544],[-72.77357,41.24554],[-72.77307,41.24594],[-72.77246,41.24586],[-72.76874,41.24488],[-72.76605,41.24371],[-72.76595,41.24353],[-72.76609,41.24343],[-72.76595,41.24343],[-72.76566,41.24351],[-72.76601,41.24378],[-72.7658,41.24488],[-72.76451,41.2459],[-72.76503,41.24623],[-72.76485,41.24645],[-72.76447,41.24598],[-72.76452,41.2461],[-72.76363,41.2464],[-72.76309,41.2461],[-72.76297,41.24627],[-72.76224,41.24622],[-72.76215,41.24572],[-72.76155,41.24602],[-72.76149,41.24552],[-72.76121,41.2459],[-72.76127,41.24622],[-72.76095,41.24607],[-72.76073,41.2462],[-72.76043,41.24517],[-72.75974,41.24562],[-72.75938,41.24657],[-72.75889,41.24659],[-72.75867,41.24652],[-72.75868,41.24587],[-72.75847,41.24544],[-72.75774,41.24694],[-72.75725,41.24679],[-72.75773,41.24731],[-72.75644,41.24826],[-72.75657,41.24931],[-72.75756,41.25004],[-72.7576,41.25044],[-72.75353,41.25011],[-72.75359,41.24973],[-72.75438,41.24988],[-72.75452,41.24976],[-72.75395,41.24868],[-72.75415,41.24736],[-72.75308,41.24736],[-72.75239,41.24676],[-72.7516,41.24663],[-72.75141,41.24663],[-72.75134,41.24691],[-72.75072,41.24705],[-72.75051,41.24735],[-72.75044,41.2492],[-72.75095,41.24993],[-72.74864,41.2498],[-72.74834,41.24952],[-72.74773,41.2497],[-72.74632,41.2496],[-72.74672,41.24927],[-72.74676,41.24897],[-72.74722,41.24872],[-72.74706,41.24825],[-72.74717,41.2478],[-72.74731,41.2478],[-72.74711,41.2477],[-72.74728,41.2475],[-72.74714,41.24762],[-72.74696,41.24742],[-72.74705,41.24715],[-72.74581,41.24725],[-72.74584,41.2475],[-72.7452,41.24785],[-72.74454,41.24777],[-72.74438,41.24744],[-72.74469,41.24712],[-72.74472,41.24657],[-72.74504,41.24655],[-72.74478,41.2462],[-72.74515,41.24617],[-72.74496,41.2455],[-72.74479,41.24547],[-72.745,41.24535],[-72.74485,41.2451],[-72.74501,41.24472],[-72.74539,41.24445],[-72.74595,41.24447],[-72.74599,41.2443],[-72.74541,41.24427],[-72.74554,41.2438],[-72.74532,41.24387],[-72.74529,41.24367],[-72.74304,41.2441],[-72.74343,41.24392],[-72.7429,41.24342],[-72.74327,41.24355],[-72.7438,41.24325],[-72.74467,41.2433],[-72.74471,41.24277],[-72.74385,41.24245],[-72.74281,41.24292],[-72.74246,41.24252],[-72.74181,41.2426],[-72.74163,41.24232],[-72.7423,41.24202],[-72.74253,41.24167],[-72.74287,41.24165],[-72.74298,41.2412],[-72.74324,41.24102],[-72.74302,41.24095],[-72.74335,41.24067],[-72.74322,41.2403],[-72.74334,41.2402],[-72.74304,41.23992],[-72.74207,41.24015],[-72.74202,41.23995],[-72.74317,41.23945],[-72.74304,41.23925],[-72.74319,41.23938],[-72.74343,41.23913],[-72.74401,41.23903],[-72.74393,41.23873],[-72.74203,41.2394],[-72.74195,41.23925],[-72.74198,41.23937],[-72.74142,41.2394],[-72.74104,41.23905],[-72.74044,41.23917],[-72.74065,41.23975],[-72.74008,41.23995],[-72.73956,41.23984],[-72.73921,41.23954],[-72.73853,41.23964],[-72.73808,41.23997],[-72.73777,41.23992],[-72.73799,41.23997],[-72.73787,41.24012],[-72.73739,41.24047],[-72.73672,41.24061],[-72.73663,41.24086],[-72.73604,41.24074],[-72.73571,41.24119],[-72.73528,41.24121],[-72.73504,41.24146],[-72.73491,41.24194],[-72.73511,41.24219],[-72.73462,41.24276],[-72.73319,41.24298],[-72.7337,41.24311],[-72.73402,41.24378],[-72.7336,41.24433],[-72.73296,41.24416],[-72.73144,41.24538],[-72.73136,41.24505],[-72.7316,41.24495],[-72.73104,41.24503],[-72.73049,41.2454],[-72.73041,41.24582],[-72.73023,41.24552],[-72.73036,41.24503],[-72.73018,41.24488],[-72.73053,41.24415],[-72.7301,41.24363],[-72.73003,41.24295],[-72.72959,41.24288],[-72.72925,41.24305],[-72.72898,41.24273],[-72.72842,41.2429],[-72.72795,41.24262],[-72.72804,41.2422],[-72.72752,41.24275],[-72.7269,41.24302],[-72.72641,41.24302],[-72.72572,41.2426],[-72.72451,41.24287],[-72.72412,41.24377],[-72.72384,41.24397],[-72.72407,41.24589],[-72.71995,41.24726],[-72.72457,41.25453],[-72.73196,41.26746],[-72.73969,41.28835],[-72.74025,41.29177],[-72.74378,41.29108]]],[[[-72.8445,41.23114],[-72.84569,41.23026],[-72.84622,41.23012],[-72.84732,41.22874],[-72.84919,41.22862],[-72.84956,41.22887],[-72.85016,41.22852],[-72.85048,41.22782],[-72.85022,41.228],[-72.84938,41.22765],[-72.8485,41.22832],[-72.84717,41.22837],[-72.84694,41.22819],[-72.84705,41.22769],[-72.84679,41.22787],[-72.84668,41.22772],[-72.8468,41.22739],[-72.84702,41.22752],[-72.84698,41.22719],[-72.84664,41.22714],[-72.84641,41.22754],[-72.84655,41.22807],[-72.8469,41.22832],[-72.84679,41.22889],[-72.84525,41.22947],[-72.84472,41.22939],[-72.84474,41.22924],[-72.8446,41.22944],[-72.84421,41.22944],[-72.84394,41.22929],[-72.84385,41.22886],[-72.84363,41.22894],[-72.84376,41.23001],[-72.84303,41.23001],[-72.8428,41.23031],[-72.84293,41.23061],[-72.8426,41.23078],[-72.84277,41.23098],[-72.84348,41.23099],[-72.84365,41.23121],[-72.8445,41.23114]]],[[[-72.75018,41.22515],[-72.75029,41.2249],[-72.75075,41.2248],[-72.75137,41.22433],[-72.7515,41.2239],[-72.75127,41.2233],[-72.7509,41.2232],[-72.74984,41.2235],[-72.74932,41.2241],[-72.74945,41.2243],[-72.74933,41.22447],[-72.74892,41.2246],[-72.7488,41.2244],[-72.74868,41.22467],[-72.74838,41.22432],[-72.74783,41.2245],[-72.74764,41.22485],[-72.74783,41.22535],[-72.74844,41.22547],[-72.74868,41.22532],[-72.74897,41.22552],[-72.75018,41.22515]]],[[[-72.74879,41.23077],[-72.74946,41.23039],[-72.74978,41.22969],[-72.75072,41.22942],[-72.75045,41.22915],[-72.75135,41.22867],[-72.75225,41.22758],[-72.75149,41.2274],[-72.75083,41.2281],[-72.74939,41.22892],[-72.74916,41.22934],[-72.7497,41.22944],[-72.74973,41.22967],[-72.74937,41.22979],[-72.74927,41.22952],[-72.74892,41.22949],[-72.74844,41.22972],[-72.74769,41.23059],[-72.74792,41.23097],[-72.74879,41.23077]]],[[[-72.7552,41.24022],[-72.75581,41.24032],[-72.75673,41.23935],[-72.7567,41.23897],[-72.75654,41.23877],[-72.75576,41.2387],[-72.75563,41.23822],[-72.75466,41.23844],[-72.75388,41.23902],[-72.75448,41.23967],[-72.75467,41.23959],[-72.7552,41.24022]]],[[[-72.74732,41.22882],[-72.74772,41.22832],[-72.74744,41.22789],[-72.74784,41.22812],[-72.74859,41.22735],[-72.74887,41.22692],[-72.74856,41.227],[-72.74879,41.22657],[-72.74869,41.22642],[-72.74895,41.22627],[-72.74793,41.22635],[-72.74745,41.22662],[-72.74721,41.22652],[-72.7469,41.22677],[-72.74729,41.22692],[-72.74718,41.22727],[-72.74748,41.22759],[-72.74737,41.22782],[-72.7469,41.22752],[-72.7463,41.22787],[-72.74628,41.22822],[-72.74669,41.22792],[-72.74684,41.22804],[-72.74668,41.22854],[-72.74628,41.22897],[-72.74667,41.22904],[-72.74732,41.22882]]],[[[-72.73458,41.23599],[-72.73433,41.23562],[-72.73442,41.23527],[-72.73482,41.23497],[-72.73435,41.23462],[-72.73466,41.23439],[-72.73453,41.23422],[-72.73313,41.23461],[-72.73268,41.23506],[-72.73305,41.23504],[-72.73277,41.23551],[-72.73297,41.23556],[-72.73323,41.23619],[-72.73355,41.23624],[-72.73458,41.23599]]],[[[-72.74706,41.23603],[-72.74754,41.23604],[-72.7483,41.23556],[-72.7486,41.23561],[-72.7488,41.23516],[-72.74916,41.23501],[-72.74944,41.23521],[-72.74943,41.23494],[-72.74976,41.23467],[-72.74919,41.23419],[-72.7491,41.23439],[-72.74765,41.23476],[-72.74713,41.23454],[-72.74737,41.23436],[-72.74697,41.23411],[-72.74673,41.23426],[-72.74679,41.23446],[-72.7474,41.23474],[-72.74761,41.23506],[-72.74707,41.23556],[-72.74706,41.23603]]],[[[-72.74289,41.23011],[-72.74329,41.22978],[-72.74418,41.23004],[-72.74508,41.22939],[-72.74548,41.22886],[-72.74536,41.22894],[-72.74538,41.22869],[-72.74481,41.22839],[-72.74525,41.22786],[-72.74517,41.22764],[-72.74462,41.22784],[-72.74458,41.22816],[-72.74436,41.22821],[-72.74479,41.22854],[-72.74472,41.22876],[-72.74408,41.22921],[-72.74356,41.22899],[-72.74354,41.22916],[-72.74287,41.22948],[-72.74291,41.22983],[-72.74289,41.23011]]],[[[-72.7529,41.22283],[-72.75338,41.22253],[-72.75325,41.22213],[-72.7536,41.22166],[-72.75352,41.22148],[-72.75249,41.22128],[-72.75255,41.22058],[-72.75201,41.22061],[-72.75182,41.22071],[-72.7518,41.22173],[-72.75209,41.22161],[-72.75239,41.22176],[-72.75273,41.22266],[-72.7529,41.22283]]],[[[-72.73992,41.23328],[-72.74061,41.2328],[-72.74084,41.23213],[-72.73993,41.23195],[-72.73994,41.2321],[-72.73945,41.23213],[-72.73896,41.2329],[-72.73939,41.23333],[-72.73992,41.23328]]],[[[-72.73363,41.23347],[-72.73464,41.23289],[-72.73507,41.23292],[-72.7356,41.23265],[-72.73528,41.23237],[-72.73491,41.23234],[-72.73456,41.23262],[-72.7344,41.23237],[-72.7335,41.23239],[-72.73354,41.23274],[-72.73325,41.23297],[-72.73329,41.23322],[-72.73363,41.23347]]],[[[-72.73792,41.2312],[-72.73868,41.23083],[-72.73846,41.22978],[-72.73814,41.22963],[-72.7377,41.22975],[-72.73755,41.23038],[-72.73767,41.23095],[-72.73792,41.2312]]],[[[-72.80679,41.22758],[-72.80622,41.22786],[-72.80644,41.22803],[-72.80857,41.22694],[-72.80844,41.22661],[-72.80754,41.22661],[-72.80719,41.22711],[-72.80679,41.22758]]],[[[-72.80135,41.22698],[-72.80165,41.22705],[-72.80272,41.2263],[-72.80274,41.2261],[-72.80229,41.22595],[-72.80158,41.22658],[-72.80135,41.22698]]],[[[-72.75697,41.24417],[-72.75709,41.24394],[-72.75645,41.24384],[-72.75652,41.24352],[-72.75683,41.24354],[-72.75671,41.24337],[-72.75605,41.24334],[-72.75633,41.24379],[-72.75594,41.24439],[-72.75611,41.24452],[-72.75697,41.24417]]],[[[-72.74639,41.23249],[-72.7472,41.23196],[-72.74719,41.23161],[-72.74695,41.23164],[-72.74712,41.23106],[-72.74667,41.23134],[-72.74689,41.23131],[-72.74656,41.23161],[-72.74655,41.23206],[-72.74667,41.23204],[-72.74639,41.23249]]],[[[-72.74153,41.2351],[-72.74235,41.23465],[-72.74207,41.2343],[-72.74147,41.23455],[-72.74128,41.23488],[-72.74153,41.2351]]],[[[-72.83498,41.23065],[-72.83459,41.2304],[-72.83484,41.22985],[-72.83476,41.2296],[-72.83435,41.22997],[-72.83447,41.23042],[-72.83433,41.23075],[-72.83455,41.23087],[-72.83498,41.23065]]],[[[-72.74973,41.23704],[-72.74986,41.23719],[-72.75099,41.23684],[-72.75108,41.23659],[-72.75024,41.23674],[-72.74973,41.23704]]],[[[-72.73981,41.23595],[-72.7401,41.23592],[-72.74012,41.23567],[-72.74048,41.23563],[-72.74083,41.23525],[-72.74068,41.23508],[-72.74037,41.23518],[-72.74012,41.23565],[-72.73983,41.2357],[-72.73981,41.23595]]],[[[-72.82389,41.23518],[-72.82402,41.23463],[-72.82477,41.23463],[-72.82484,41.23448],[-72.8237,41.23453],[-72.82362,41.23498],[-72.82379,41.23505],[-72.82389,41.23518]]],[[[-72.74491,41.2399],[-72.74504,41.2402],[-72.74576,41.2398],[-72.74553,41.23955],[-72.74505,41.23975],[-72.74491,41.2399]]],[[[-72.786,41.23694],[-72.78613,41.23717],[-72.78664,41.23654],[-72.78644,41.23627],[-72.78616,41.23652],[-72.786,41.23694]]],[[[-72.79658,41.22604],[-72.79715,41.22542],[-72.7965,41.22564],[-72.79607,41.22607],[-72.79658,41.22604]]],[[[-72.74471,41.23331],[-72.74496,41.23333],[-72.74575,41.23291],[-72.7455,41.23274],[-72.74529,41.23301],[-72.7449,41.23306],[-72.74471,41.23331]]],[[[-72.75317,41.23887],[-72.75339,41.23892],[-72.7534,41.23852],[-72.75374,41.23832],[-72.75361,41.23814],[-72.75331,41.23807],[-72.75328,41.23862],[-72.75317,41.23887]]],[[[-72.80011,41.22942],[-72.80033,41.22947],[-72.80095,41.22905],[-72.80066,41.22892],[-72.80011,41.22942]]],[[[-72.8181,41.2255],[-72.81826,41.22573],[-72.81864,41.2254],[-72.81834,41.22515],[-72.8181,41.2255]]],[[[-72.75815,41.24349],[-72.75854,41.24377],[-72.75869,41.24367],[-72.7587,41.2433],[-72.75858,41.24332],[-72.75815,41.24349]]],[[[-72.75489,41.24344],[-72.75505,41.24319],[-72.75454,41.24319],[-72.75435,41.24344],[-72.75452,41.24349],[-72.75489,41.24344]]],[[[-72.74128,41.22836],[-72.74166,41.22803],[-72.7414,41.22813],[-72.74127,41.22786],[-72.74093,41.22813],[-72.74128,41.22836]]],[[[-72.83222,41.23092],[-72.83261,41.23092],[-72.83262,41.23064],[-72.83245,41.23062],[-72.83254,41.23024],[-72.83233,41.23067],[-72.83222,41.23092]]],[[[-72.74056,41.23088],[-72.74092,41.23096],[-72.74111,41.23063],[-72.74062,41.23068],[-72.74056,41.23088]]],[[[-72.83556,41.23602],[-72.83605,41.23547],[-72.83585,41.23525],[-72.83556,41.23602]]],[[[-72.74459,41.23021],[-72.74512,41.22999],[-72.7448,41.22981],[-72.74459,41.23021]]],[[[-72.74209,41.22686],[-72.74214,41.22714],[-72.74248,41.22684],[-72.7424,41.22671],[-72.74209,41.22686]]],[[[-72.78907,41.23413],[-72.78939,41.23405],[-72.7894,41.2338],[-72.78923,41.23373],[-72.78907,41.23413]]],[[[-72.83266,41.23579],[-72.83289,41.23611],[-72.833,41.23587],[-72.83282,41.23564],[-72.83266,41.23579]]],[[[-72.76388,41.24435],[-72.76404,41.24393],[-72.76375,41.24385],[-72.76388,41.24435]]],[[[-72.83584,41.22595],[-72.83614,41.22623],[-72.8361,41.22583],[-72.83591,41.22583],[-72.83584,41.22595]]],[[[-72.80292,41.22828],[-72.80344,41.22835],[-72.80284,41.22803],[-72.80292,41.22828]]],[[[-72.76693,41.23816],[-72.76708,41.23816],[-72.76711,41.23784],[-72.7669,41.23784],[-72.76693,41.23816]]],[[[-72.79003,41.23293],[-72.79022,41.23265],[-72.78997,41.23245],[-72.79003,41.23293]]],[[[-72.77452,41.23191],[-72.77488,41.23168],[-72.77468,41.23153],[-72.77452,41.23191]]],[[[-72.84909,41.22607],[-72.84941,41.22622],[-72.8494,41.226],[-72.84918,41.22597],[-72.84909,41.22607]]],[[[-72.84994,41.226],[-72.85016,41.22605],[-72.85017,41.2258],[-72.84998,41.2258],[-72.84994,41.226]]],[[[-72.75182,41.24181],[-72.75233,41.24156],[-72.75213,41.24149],[-72.75182,41.24181]]],[[[-72.84917,41.2256],[-72.84937,41.22577],[-72.84951,41.22558],[-72.84934,41.22553],[-72.84917,41.2256]]],[[[-72.83466,41.22892],[-72.83479,41.22912],[-72.8348,41.22853],[-72.83466,41.22892]]],[[[-72.79794,41.23394],[-72.79845,41.23379],[-72.79823,41.23371],[-72.79794,41.23394]]],[[[-72.84894,41.22692],[-72.84926,41.22692],[-72.84923,41.2267],[-72.84894,41.22692]]],[[[-72.74378,41.23046],[-72.7443,41.23026],[-72.74408,41.23021],[-72.74378,41.23046]]],[[[-72.76611,41.23988],[-72.76639,41.23976],[-72.76624,41.23959],[-72.76611,41.23988]]],[[[-72.74761,41.24318],[-72.74797,41.24305],[-72.7478,41.24293],[-72.74761,41.24318]]],[[[-72.74673,41.22432],[-72.74711,41.22405],[-72.74677,41.22412],[-72.74673,41.22432]]],[[[-72.74761,41.24383],[-72.74799,41.24365],[-72.74779,41.24358],[-72.74761,41.24383]]],[[[-72.82043,41.23667],[-72.8206,41.23642],[-72.82038,41.2364],[-72.82043,41.23667]]],[[[-72.73884,41.2297],[-72.73913,41.22965],[-72.73901,41.22955],[-72.73884,41.2297]]],[[[-72.74064,41.23041],[-72.74078,41.23021],[-72.74063,41.23018],[-72.74064,41.23041]]],[[[-72.74303,41.23058],[-72.74317,41.23046],[-72.743,41.23043],[-72.74303,41.23058]]]]}' FROM towns WHERE name = 'Branford';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.15337,41.20663],[-73.16353,41.202],[-73.16658,41.20225],[-73.16881,41.20206],[-73.17486,41.20037],[-73.17858,41.20027],[-73.17857,41.20077],[-73.17987,41.2012],[-73.18172,41.20298],[-73.18234,41.2049],[-73.18481,41.20638],[-73.18587,41.20663],[-73.18769,41.20763],[-73.19258,41.20831],[-73.19295,41.20956],[-73.19617,41.20897],[-73.19723,41.20795],[-73.19888,41.20765],[-73.20383,41.2084],[-73.21064,41.20752],[-73.22381,41.20731],[-73.22978,41.20702],[-73.23572,41.2067],[-73.23489,41.20578],[-73.22404,41.1932],[-73.2208,41.18975],[-73.212,41.17935],[-73.21017,41.17759],[-73.20982,41.17699],[-73.21042,41.16628],[-73.20794,41.16153],[-73.20872,41.16068],[-73.20973,41.16034],[-73.20918,41.15896],[-73.20926,41.15774],[-73.21136,41.15649],[-73.21285,41.15519],[-73.21299,41.15487],[-73.21273,41.1542],[-73.21326,41.1532],[-73.21318,41.15295],[-73.21375,41.15257],[-73.21501,41.15263],[-73.21561,41.15228],[-73.21552,41.151],[-73.21707,41.14976],[-73.21675,41.14901],[-73.21723,41.14876],[-73.21734,41.14848],[-73.2168,41.14808],[-73.21754,41.14761],[-73.21723,41.14731],[-73.21567,41.14786],[-73.21552,41.14848],[-73.21489,41.14923],[-73.21427,41.14903],[-73.21502,41.14803],[-73.21429,41.14798],[-73.21348,41.14848],[-73.21397,41.14938],[-73.21351,41.1496],[-73.21313,41.14915],[-73.21298,41.1482],[-73.21201,41.1474],[-73.21328,41.14603],[-73.21315,41.14568],[-73.21259,41.1455],[-73.21258,41.14523],[-73.21385,41.14541],[-73.21418,41.14528],[-73.21432,41.14508],[-73.21413,41.14423],[-73.21492,41.14391],[-73.21495,41.14338],[-73.21523,41.14301],[-73.21591,41.14291],[-73.21701,41.14224],[-73.21702,41.14106],[-73.21754,41.14034],[-73.21967,41.13867],[-73.22032,41.13842],[-73.22204,41.13658],[-73.22559,41.13413],[-73.2266,41.13284],[-73.22662,41.13199],[-73.22538,41.13054],[-73.22494,41.13036],[-73.22376,41.13056],[-73.22322,41.13046],[-73.22244,41.12966],[-73.22167,41.12681],[-73.22165,41.12586],[-73.22258,41.12469],[-73.22387,41.12389],[-73.22433,41.12299],[-73.22398,41.12194],[-73.22244,41.12082],[-73.22223,41.12104],[-73.22141,41.12142],[-73.2191,41.12111],[-73.21738,41.12061],[-73.21622,41.12073],[-73.21444,41.12245],[-73.21392,41.12355],[-73.21302,41.12427],[-73.21271,41.12472],[-73.2128,41.12502],[-73.21183,41.126],[-73.21178,41.12665],[-73.21244,41.12677],[-73.21324,41.12767],[-73.21295,41.12842],[-73.2124,41.12889],[-73.21125,41.12942],[-73.21051,41.12914],[-73.21019,41.12967],[-73.21059,41.12997],[-73.21062,41.13027],[-73.21014,41.13061],[-73.20958,41.13036],[-73.20942,41.13064],[-73.20979,41.13094],[-73.20946,41.13144],[-73.20737,41.13121],[-73.20706,41.13146],[-73.20736,41.13168],[-73.20694,41.13158],[-73.20657,41.13226],[-73.20626,41.13238],[-73.20617,41.13266],[-73.20635,41.13296],[-73.20611,41.13371],[-73.20697,41.13416],[-73.20711,41.13406],[-73.20702,41.13418],[-73.20786,41.13391],[-73.20864,41.13326],[-73.20916,41.13329],[-73.20938,41.13356],[-73.20828,41.13503],[-73.20762,41.13556],[-73.20721,41.13558],[-73.20727,41.1361],[-73.20689,41.1364],[-73.20686,41.13703],[-73.20602,41.13723],[-73.20546,41.13713],[-73.20496,41.13683],[-73.20425,41.13595],[-73.20344,41.1357],[-73.20286,41.13567],[-73.20183,41.1362],[-73.2009,41.13679],[-73.20072,41.13742],[-73.20044,41.13769],[-73.19948,41.13797],[-73.19886,41.13839],[-73.19889,41.13941],[-73.19929,41.13969],[-73.19941,41.14061],[-73.19882,41.14026],[-73.1983,41.13939],[-73.19762,41.13944],[-73.19586,41.14151],[-73.19534,41.14253],[-73.19548,41.14258],[-73.19518,41.1431],[-73.19504,41.14305],[-73.19435,41.14453],[-73.19343,41.14465],[-73.19392,41.14315],[-73.19328,41.1437],[-73.19227,41.14407],[-73.19043,41.14525],[-73.1904,41.14475],[-73.19402,41.1423],[-73.1961,41.13968],[-73.19663,41.13869],[-73.1975,41.13776],[-73.20096,41.13475],[-73.2036,41.1328],[-73.20333,41.13263],[-73.20337,41.13245],[-73.20496,41.13193],[-73.20519,41.13146],[-73.20639,41.13038],[-73.20612,41.13011],[-73.20588,41.12933],[-73.20548,41.12734],[-73.20564,41.12629],[-73.20503,41.12616],[-73.20536,41.12516],[-73.20466,41.12364],[-73.20454,41.12294],[-73.20469,41.12221],[-73.20492,41.12191],[-73.20557,41.12169],[-73.20602,41.12102],[-73.20609,41.12017],[-73.20645,41.12012],[-73.20639,41.11984],[-73.20586,41.11982],[-73.20579,41.12067],[-73.20503,41.12069],[-73.20556,41.12049],[-73.20518,41.12002],[-73.20482,41.12014],[-73.20423,41.12079],[-73.20426,41.12314],[-73.20524,41.12519],[-73.20481,41.12634],[-73.20256,41.129],[-73.20093,41.13048],[-73.19527,41.13461],[-73.19153,41.13651],[-73.18524,41.13822],[-73.18023,41.13881],[-73.17913,41.13858],[-73.17874,41.13773],[-73.17836,41.13751],[-73.17597,41.13786],[-73.17586,41.13831],[-73.17417,41.1394],[-73.17427,41.13975],[-73.17407,41.1411],[-73.17436,41.14245],[-73.17401,41.1429],[-73.17276,41.14367],[-73.17187,41.14397],[-73.17203,41.14437],[-73.1717,41.14449],[-73.17145,41.14449],[-73.17117,41.14412],[-73.16888,41.14476],[-73.16719,41.14489],[-73.16755,41.14566],[-73.17032,41.14799],[-73.1703,41.14814],[-73.17189,41.14934],[-73.17216,41.14934],[-73.17336,41.15049],[-73.17355,41.15046],[-73.17475,41.15142],[-73.17471,41.15194],[-73.17489,41.15214],[-73.17463,41.15224],[-73.17537,41.15346],[-73.17545,41.15371],[-73.17518,41.15369],[-73.17556,41.15401],[-73.17599,41.15486],[-73.17496,41.15599],[-73.1742,41.15648],[-73.17391,41.15426],[-73.17245,41.15346],[-73.17172,41.15338],[-73.17134,41.15363],[-73.17051,41.15343],[-73.17003,41.15366],[-73.16937,41.15366],[-73.16887,41.15385],[-73.16835,41.1546],[-73.16768,41.15478],[-73.1674,41.1552],[-73.16581,41.15495],[-73.1659,41.15457],[-73.16614,41.15447],[-73.16646,41.15308],[-73.16748,41.15153],[-73.1675,41.15123],[-73.168,41.15096],[-73.16747,41.15118],[-73.16781,41.15093],[-73.16735,41.15103],[-73.1678,41.15076],[-73.16696,41.15033],[-73.16581,41.14933],[-73.16394,41.15013],[-73.16381,41.14988],[-73.16531,41.1489],[-73.16425,41.14813],[-73.1641,41.14825],[-73.16376,41.14808],[-73.1639,41.14788],[-73.16278,41.1471],[-73.16264,41.14723],[-73.16232,41.147],[-73.16151,41.14777],[-73.16112,41.14772],[-73.15954,41.14697],[-73.15947,41.14685],[-73.15993,41.14625],[-73.1548,41.14429],[-73.15439,41.14447],[-73.15413,41.14494],[-73.15502,41.14617],[-73.15546,41.14632],[-73.1557,41.14674],[-73.1549,41.14844],[-73.15491,41.14979],[-73.15513,41.15041],[-73.15497,41.15076],[-73.15515,41.15126],[-73.15474,41.15131],[-73.15439,41.15096],[-73.15432,41.14946],[-73.15364,41.14936],[-73.15284,41.14961],[-73.15263,41.15001],[-73.15185,41.15051],[-73.15057,41.15243],[-73.14918,41.1524],[-73.14917,41.15215],[-73.14868,41.15193],[-73.14954,41.15148],[-73.15089,41.14926],[-73.15085,41.14873],[-73.15181,41.14846],[-73.15306,41.14704],[-73.15313,41.14644],[-73.15277,41.14554],[-73.15202,41.14481],[-73.15163,41.14479],[-73.15168,41.14491],[-73.15137,41.14501],[-73.15073,41.14501],[-73.14854,41.15195],[-73.14913,41.15233],[-73.14553,41.15609],[-73.1453,41.15672],[-73.14532,41.15816],[-73.14448,41.15999],[-73.14418,41.16153],[-73.14552,41.16556],[-73.14505,41.1679],[-73.14593,41.16883],[-73.14537,41.16935],[-73.14514,41.17068],[-73.14449,41.17093],[-73.14419,41.1716],[-73.15409,41.19981],[-73.15394,41.20069],[-73.15325,41.20193],[-73.15279,41.20368],[-73.15219,41.20475],[-73.15158,41.20545],[-73.15047,41.20578],[-73.1503,41.20655],[-73.14942,41.20722],[-73.149,41.20855],[-73.15337,41.20663]]],[[[-73.15281,41.13902],[-73.15391,41.13975],[-73.15521,41.14025],[-73.15563,41.14065],[-73.15625,41.1425],[-73.15647,41.14267],[-73.15615,41.1426],[-73.15594,41.14277],[-73.15673,41.143],[-73.15755,41.14285],[-73.15813,41.14212],[-73.15818,41.14135],[-73.15905,41.1393],[-73.15944,41.13885],[-73.16081,41.13818],[-73.16108,41.13743],[-73.16083,41.13726],[-73.16373,41.13599],[-73.16523,41.13474],[-73.16565,41.13409],[-73.16609,41.13262],[-73.16557,41.13412],[-73.16442,41.13547],[-73.16345,41.13609],[-73.16076,41.13723],[-73.15523,41.13625],[-73.15357,41.13595],[-73.15266,41.13892],[-73.15281,41.13902]]],[[[-73.15178,41.14174],[-73.15178,41.14179],[-73.15167,41.14211],[-73.15164,41.14214],[-73.15156,41.14244],[-73.1516,41.14244],[-73.15261,41.14199],[-73.15325,41.14132],[-73.15212,41.14094],[-73.15204,41.14089],[-73.15178,41.14174]]]]}' FROM towns WHERE name = 'Bridgeport';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.32287,41.49256],[-73.33111,41.49165],[-73.33303,41.50164],[-73.33477,41.50691],[-73.33592,41.51421],[-73.33691,41.51828],[-73.33731,41.52188],[-73.34055,41.53162],[-73.3425,41.53925],[-73.34396,41.54969],[-73.345,41.55516],[-73.37429,41.55443],[-73.39267,41.54539],[-73.39273,41.54447],[-73.39181,41.54377],[-73.392,41.54189],[-73.39159,41.54124],[-73.39196,41.53979],[-73.39231,41.53922],[-73.3922,41.5387],[-73.39135,41.53817],[-73.39098,41.5377],[-73.39088,41.53687],[-73.39027,41.53682],[-73.39031,41.53575],[-73.39191,41.53577],[-73.39208,41.53547],[-73.39201,41.53497],[-73.39324,41.53448],[-73.39287,41.53375],[-73.3937,41.53358],[-73.39428,41.5337],[-73.39456,41.53323],[-73.39518,41.53361],[-73.39572,41.53316],[-73.39667,41.53298],[-73.3968,41.53263],[-73.39737,41.53278],[-73.39806,41.53244],[-73.39991,41.53249],[-73.40028,41.53281],[-73.40067,41.53279],[-73.40116,41.53202],[-73.40157,41.53187],[-73.40134,41.53164],[-73.40173,41.53159],[-73.40286,41.5304],[-73.40448,41.53002],[-73.40528,41.52938],[-73.40498,41.52803],[-73.40586,41.52573],[-73.40601,41.52368],[-73.40576,41.51931],[-73.40533,41.51801],[-73.40382,41.51556],[-73.40346,41.51331],[-73.40136,41.50964],[-73.39973,41.50784],[-73.39843,41.50691],[-73.39413,41.50528],[-73.3892,41.50383],[-73.38748,41.50312],[-73.38736,41.50307],[-73.38701,41.50285],[-73.38553,41.5007],[-73.38532,41.49937],[-73.3858,41.49828],[-73.38774,41.49718],[-73.38875,41.49606],[-73.38901,41.49506],[-73.38882,41.49441],[-73.38814,41.49361],[-73.38614,41.49261],[-73.38185,41.49148],[-73.38074,41.49098],[-73.37875,41.48945],[-73.37777,41.4884],[-73.37696,41.48732],[-73.37591,41.48462],[-73.37559,41.48298],[-73.37565,41.47823],[-73.37533,41.47731],[-73.37467,41.47636],[-73.3734,41.47553],[-73.37182,41.47485],[-73.36956,41.47472],[-73.36446,41.47319],[-73.35959,41.46984],[-73.35776,41.46884],[-73.35377,41.46793],[-73.35095,41.46693],[-73.34862,41.46643],[-73.34749,41.46522],[-73.34597,41.46455],[-73.34474,41.46425],[-73.34033,41.46377],[-73.33685,41.46286],[-73.33211,41.46041],[-73.33067,41.45943],[-73.32918,41.45828],[-73.32724,41.4569],[-73.32473,41.456],[-73.32118,41.45574],[-73.31383,41.45406],[-73.31211,41.45411],[-73.31107,41.45435],[-73.30959,41.45535],[-73.30926,41.45558],[-73.3097,41.4558],[-73.31262,41.45748],[-73.3144,41.45913],[-73.31518,41.46013],[-73.31756,41.46166],[-73.31922,41.46338],[-73.31999,41.46393],[-73.32075,41.46513],[-73.32184,41.46826],[-73.32531,41.47109],[-73.32592,41.47129],[-73.32628,41.47194],[-73.32616,41.47259],[-73.32544,41.47401],[-73.32378,41.4767],[-73.32399,41.47728],[-73.32626,41.4802],[-73.32635,41.48058],[-73.32527,41.4826],[-73.32408,41.48352],[-73.32167,41.48659],[-73.32152,41.48719],[-73.3217,41.48756],[-73.32132,41.48796],[-73.32122,41.48859],[-73.32134,41.48951],[-73.3212,41.49049],[-73.31987,41.49226],[-73.3194,41.49286],[-73.32287,41.49256]]]]}' FROM towns WHERE name = 'Bridgewater';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.89916,41.68928],[-72.89943,41.69023],[-72.90519,41.71604],[-72.92463,41.71404],[-72.9577,41.71047],[-72.97814,41.70803],[-72.98563,41.70734],[-73.00492,41.70532],[-73.00439,41.703],[-73.00076,41.68668],[-72.99678,41.67152],[-72.99261,41.65178],[-72.98927,41.63774],[-72.98747,41.63032],[-72.98461,41.63061],[-72.96836,41.63206],[-72.9526,41.63391],[-72.94276,41.6349],[-72.93304,41.63586],[-72.93198,41.63048],[-72.92613,41.63085],[-72.92621,41.63277],[-72.92661,41.63402],[-72.92697,41.63452],[-72.92826,41.6355],[-72.92838,41.63622],[-72.9035,41.63878],[-72.88789,41.64046],[-72.889,41.64503],[-72.89851,41.68628],[-72.89904,41.68865],[-72.89916,41.68928]]]]}' FROM towns WHERE name = 'Bristol';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.33211,41.46041],[-73.33685,41.46286],[-73.34033,41.46377],[-73.34474,41.46425],[-73.34597,41.46455],[-73.34749,41.46522],[-73.34862,41.46643],[-73.35095,41.46693],[-73.35377,41.46793],[-73.35776,41.46884],[-73.35959,41.46984],[-73.36446,41.47319],[-73.36956,41.47472],[-73.37182,41.47485],[-73.3734,41.47553],[-73.37467,41.47636],[-73.37533,41.47731],[-73.37565,41.47823],[-73.37559,41.48298],[-73.37591,41.48462],[-73.37696,41.48732],[-73.37777,41.4884],[-73.37875,41.48945],[-73.38074,41.49098],[-73.38185,41.49148],[-73.38614,41.49261],[-73.38814,41.49361],[-73.38882,41.49441],[-73.38901,41.49506],[-73.38875,41.49606],[-73.38774,41.49718],[-73.3858,41.49828],[-73.38532,41.49937],[-73.38553,41.5007],[-73.38701,41.50285],[-73.38736,41.50307],[-73.38916,41.5024],[-73.40293,41.49708],[-73.41751,41.48991],[-73.42286,41.48759],[-73.43039,41.48573],[-73.44124,41.47868],[-73.43949,41.47555],[-73.43659,41.47028],[-73.43862,41.47173],[-73.44126,41.47281],[-73.44198,41.47269],[-73.44256,41.47179],[-73.44265,41.47054],[-73.44231,41.46971],[-73.44257,41.46789],[-73.44213,41.46612],[-73.44338,41.465],[-73.44346,41.46435],[-73.44281,41.46127],[-73.44225,41.46042],[-73.44219,41.45942],[-73.44151,41.45855],[-73.44101,41.45747],[-73.4396,41.45717],[-73.4391,41.45677],[-73.43827,41.45515],[-73.43747,41.45432],[-73.43634,41.45245],[-73.43597,41.45217],[-73.43492,41.45269],[-73.43442,41.45322],[-73.43423,41.45412],[-73.43374,41.45484],[-73.43371,41.45551],[-73.43446,41.45769],[-73.43573,41.45889],[-73.4374,41.46007],[-73.43761,41.46059],[-73.43742,41.46067],[-73.4374,41.46107],[-73.43586,41.45984],[-73.43391,41.45884],[-73.43313,41.45886],[-73.4327,41.45908],[-73.43211,41.45896],[-73.43158,41.45728],[-73.43019,41.45573],[-73.42997,41.45563],[-73.42925,41.45598],[-73.42898,41.45673],[-73.42541,41.45033],[-73.42152,41.43889],[-73.41379,41.4162],[-73.40457,41.41439],[-73.39373,41.41217],[-73.37793,41.40895],[-73.37612,41.40513],[-73.37336,41.40879],[-73.36226,41.42366],[-73.33112,41.45896],[-73.33067,41.45943],[-73.33211,41.46041]]]]}' FROM towns WHERE name = 'Brookfield';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-71.92598,41.75204],[-71.92478,41.75254],[-71.92431,41.75296],[-71.92286,41.75488],[-71.92259,41.75563],[-71.92239,41.75793],[-71.92193,41.75875],[-71.9197,41.76052],[-71.91864,41.76252],[-71.91815,41.76309],[-71.91568,41.76426],[-71.9153,41.76474],[-71.91468,41.76591],[-71.91515,41.76758],[-71.91517,41.76836],[-71.9143,41.7709],[-71.91333,41.7726],[-71.91166,41.77355],[-71.90857,41.77449],[-71.9042,41.77531],[-71.90187,41.7762],[-71.8996,41.77668],[-71.89818,41.77797],[-71.89798,41.77962],[-71.89917,41.78252],[-71.89925,41.78459],[-71.89984,41.78627],[-71.89864,41.78771],[-71.89778,41.78819],[-71.89599,41.78851],[-71.89532,41.78956],[-71.89584,41.79151],[-71.89642,41.79238],[-71.89626,41.79348],[-71.89646,41.7943],[-71.89669,41.79465],[-71.8972,41.79478],[-71.89714,41.7952],[-71.89967,41.79616],[-71.90026,41.79626],[-71.90232,41.79596],[-71.90555,41.79682],[-71.90607,41.79719],[-71.90664,41.79834],[-71.90642,41.79891],[-71.90668,41.79974],[-71.90774,41.80014],[-71.90896,41.80107],[-71.90883,41.80246],[-71.908,41.80296],[-71.90596,41.80303],[-71.90517,41.80351],[-71.90493,41.80438],[-71.90583,41.80673],[-71.90738,41.80838],[-71.90984,41.81018],[-71.91148,41.81046],[-71.91259,41.81029],[-71.91685,41.80877],[-71.91837,41.80937],[-71.92141,41.80855],[-71.92226,41.80853],[-71.92405,41.80886],[-71.92472,41.80931],[-71.92465,41.80963],[-71.9243,41.80991],[-71.92206,41.81003],[-71.91956,41.8107],[-71.91853,41.81135],[-71.91819,41.81222],[-71.91813,41.81324],[-71.91736,41.81454],[-71.91754,41.81562],[-71.91851,41.81712],[-71.91861,41.81889],[-71.91818,41.81971],[-71.91715,41.82053],[-71.9169,41.82083],[-71.91692,41.82083],[-71.98713,41.82104],[-71.98714,41.81822],[-71.98838,41.8151],[-71.98834,41.81377],[-71.98789,41.81238],[-72.0025,41.81262],[-72.00232,41.79831],[-72.02313,41.79942],[-72.02391,41.79947],[-72.02391,41.79942],[-72.02609,41.78548],[-72.02847,41.76693],[-72.02999,41.76001],[-72.03063,41.75619],[-72.03141,41.75235],[-72.03119,41.7523],[-72.02014,41.75026],[-71.9856,41.74459],[-71.97262,41.74224],[-71.92341,41.73438],[-71.9218,41.73413],[-71.9218,41.73415],[-71.92231,41.73667],[-71.92364,41.73802],[-71.92335,41.73962],[-71.92243,41.74057],[-71.92219,41.74147],[-71.92149,41.74177],[-71.9213,41.74337],[-71.92148,41.74446],[-71.92226,41.74614],[-71.92276,41.74676],[-71.924,41.74767],[-71.92548,41.74839],[-71.92616,41.74899],[-71.92666,41.75022],[-71.92675,41.75097],[-71.92636,41.75179],[-71.92634,41.75179],[-71.92598,41.75204]]]]}' FROM towns WHERE name = 'Brooklyn';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.93028,41.80179],[-72.93061,41.80217],[-72.93209,41.80292],[-72.93427,41.80357],[-72.93534,41.80422],[-72.94646,41.80309],[-72.95845,41.80163],[-72.9624,41.80117],[-73.00701,41.79576],[-73.02604,41.79324],[-73.02626,41.79324],[-73.02617,41.79265],[-73.01778,41.75707],[-73.00632,41.71069],[-73.00541,41.70725],[-73.00492,41.70532],[-72.98563,41.70734],[-72.97814,41.70803],[-72.9577,41.71047],[-72.92463,41.71404],[-72.90519,41.71604],[-72.90627,41.72046],[-72.91171,41.74279],[-72.91722,41.77015],[-72.91726,41.7707],[-72.91802,41.77078],[-72.92596,41.77221],[-72.92823,41.77334],[-72.92891,41.77404],[-72.93033,41.77746],[-72.93154,41.77829],[-72.932,41.77887],[-72.93284,41.78116],[-72.9335,41.78204],[-72.9337,41.78279],[-72.9332,41.78416],[-72.93316,41.78506],[-72.93412,41.79048],[-72.93449,41.79368],[-72.93553,41.79458],[-72.93657,41.79591],[-72.93725,41.79756],[-72.93698,41.7982],[-72.93655,41.79855],[-72.93065,41.80024],[-72.92982,41.80107],[-72.92955,41.80176],[-72.92958,41.80191],[-72.93028,41.80179]]]]}' FROM towns WHERE name = 'Burlington';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.26477,41.99531],[-73.31285,41.99923],[-73.33208,42.00111],[-73.37251,42.00374],[-73.37285,42.00377],[-73.37294,42.00357],[-73.37329,42.00304],[-73.37408,42.00265],[-73.37821,42.00235],[-73.379,42.00208],[-73.37925,42.00165],[-73.37926,42.00118],[-73.37899,42.00095],[-73.37894,42.00016],[-73.3792,41.99976],[-73.37987,41.99961],[-73.38024,41.99976],[-73.38063,42.00051],[-73.38023,42.00108],[-73.38034,42.00133],[-73.38079,42.00168],[-73.38144,42.00176],[-73.3824,42.00118],[-73.38237,42.00086],[-73.38276,42.00031],[-73.38462,41.99969],[-73.38463,41.99924],[-73.38428,41.99909],[-73.38159,41.99943],[-73.38114,41.99903],[-73.38112,41.99731],[-73.38236,41.99584],[-73.38268,41.99504],[-73.38189,41.99392],[-73.38179,41.99309],[-73.38194,41.99144],[-73.38266,41.9895],[-73.38286,41.9883],[-73.38137,41.98622],[-73.38087,41.98437],[-73.38151,41.98298],[-73.38235,41.98265],[-73.38307,41.98305],[-73.38395,41.98393],[-73.38436,41.9841],[-73.38521,41.98398],[-73.38714,41.98273],[-73.38761,41.98214],[-73.38769,41.98166],[-73.38751,41.98034],[-73.38616,41.97689],[-73.38284,41.97371],[-73.38185,41.97231],[-73.38148,41.97134],[-73.38158,41.96896],[-73.3814,41.96814],[-73.38172,41.96719],[-73.38229,41.96619],[-73.38324,41.9653],[-73.38591,41.9635],[-73.38693,41.96205],[-73.3871,41.96021],[-73.38648,41.95923],[-73.38446,41.9581],[-73.38383,41.95755],[-73.38379,41.957],[-73.38444,41.95606],[-73.3838,41.95501],[-73.38369,41.95438],[-73.38399,41.95148],[-73.38355,41.95071],[-73.38095,41.94808],[-73.38026,41.94708],[-73.37991,41.94596],[-73.37993,41.94414],[-73.38014,41.94324],[-73.37993,41.94266],[-73.37955,41.94214],[-73.37873,41.94154],[-73.37727,41.94156],[-73.37685,41.94123],[-73.37684,41.94091],[-73.37818,41.93951],[-73.37963,41.93739],[-73.37982,41.93662],[-73.37964,41.93542],[-73.37953,41.93512],[-73.37869,41.93452],[-73.37566,41.93401],[-73.37487,41.93356],[-73.37425,41.93221],[-73.37485,41.93044],[-73.37453,41.92954],[-73.37098,41.92696],[-73.36994,41.92566],[-73.36964,41.92466],[-73.36978,41.92421],[-73.37018,41.92392],[-73.37449,41.92175],[-73.375,41.9211],[-73.37552,41.9197],[-73.37523,41.91815],[-73.37488,41.91765],[-73.37047,41.91757],[-73.28322,41.91539],[-73.27553,41.91513],[-73.27318,41.92434],[-73.27003,41.93668],[-73.26955,41.9378],[-73.26439,41.95997],[-73.26315,41.96744],[-73.26336,41.96956],[-73.26011,41.98759],[-73.2588,41.9948],[-73.26477,41.99531]]]]}' FROM towns WHERE name = 'Canaan';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-71.92341,41.73438],[-71.97262,41.74224],[-71.9856,41.74459],[-72.02014,41.75026],[-72.03119,41.7523],[-72.03141,41.75235],[-72.03153,41.7522],[-72.03806,41.74289],[-72.05319,41.72259],[-72.05869,41.72329],[-72.06015,41.7235],[-72.06015,41.72342],[-72.06205,41.70007],[-72.06233,41.7],[-72.06288,41.693],[-72.0617,41.68928],[-72.05907,41.67889],[-72.06705,41.64191],[-72.06707,41.64184],[-72.06544,41.64156],[-72.036,41.63669],[-72.03598,41.63669],[-72.03593,41.63669],[-71.98259,41.62752],[-71.98007,41.62709],[-71.97902,41.62692],[-71.95989,41.62359],[-71.95969,41.62356],[-71.95959,41.62494],[-71.95948,41.62694],[-71.95425,41.66489],[-71.95909,41.66495],[-71.9594,41.66537],[-71.95942,41.6663],[-71.95919,41.6673],[-71.95937,41.6679],[-71.96122,41.66952],[-71.9617,41.67017],[-71.96229,41.67177],[-71.96222,41.67277],[-71.96072,41.67704],[-71.96075,41.67881],[-71.96101,41.68031],[-71.96212,41.68226],[-71.96277,41.68296],[-71.96404,41.68311],[-71.96546,41.68257],[-71.96671,41.68289],[-71.96733,41.68352],[-71.96771,41.68467],[-71.96875,41.68619],[-71.96923,41.68859],[-71.96844,41.69034],[-71.96851,41.69199],[-71.96903,41.69386],[-71.96886,41.69561],[-71.96925,41.69651],[-71.97117,41.69806],[-71.97241,41.69951],[-71.97249,41.69999],[-71.97135,41.70058],[-71.97097,41.70111],[-71.97084,41.70171],[-71.97326,41.70516],[-71.97342,41.70573],[-71.97266,41.70635],[-71.97002,41.70742],[-71.96677,41.71009],[-71.96567,41.71074],[-71.96504,41.71141],[-71.96456,41.71271],[-71.96469,41.71356],[-71.96516,41.71468],[-71.9651,41.71523],[-71.96384,41.71756],[-71.96317,41.71813],[-71.96225,41.71823],[-71.95973,41.71777],[-71.95806,41.71705],[-71.95428,41.71437],[-71.95327,41.71394],[-71.95281,41.71394],[-71.95194,41.71484],[-71.9515,41.71651],[-71.95038,41.71778],[-71.94947,41.71848],[-71.9427,41.72127],[-71.94151,41.72204],[-71.94003,41.72371],[-71.93936,41.72641],[-71.93716,41.72678],[-71.93596,41.7267],[-71.93288,41.726],[-71.9303,41.72592],[-71.92774,41.72712],[-71.92569,41.72874],[-71.92221,41.73318],[-71.92182,41.7341],[-71.9218,41.73413],[-71.92341,41.73438]]]]}' FROM towns WHERE name = 'Canterbury';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.90451,41.91936],[-72.91969,41.91996],[-72.92804,41.8946],[-72.92962,41.89445],[-72.95366,41.89284],[-72.95379,41.89236],[-72.95561,41.88412],[-72.9583,41.87416],[-72.95805,41.87217],[-72.95888,41.86854],[-72.9601,41.86697],[-72.96377,41.85254],[-72.96484,41.85082],[-72.96447,41.84897],[-72.96516,41.84525],[-72.96325,41.8439],[-72.96272,41.84323],[-72.96278,41.84198],[-72.96325,41.84073],[-72.96298,41.83915],[-72.96273,41.8389],[-72.96149,41.83873],[-72.95688,41.83977],[-72.95391,41.83972],[-72.94989,41.83851],[-72.94822,41.83718],[-72.94693,41.83651],[-72.95157,41.82248],[-72.95845,41.80163],[-72.94646,41.80309],[-72.93534,41.80422],[-72.93427,41.80357],[-72.93209,41.80292],[-72.93061,41.80217],[-72.93028,41.80179],[-72.92958,41.80191],[-72.92859,41.80209],[-72.92472,41.80271],[-72.92536,41.8055],[-72.88309,41.81096],[-72.87999,41.81148],[-72.88068,41.81505],[-72.88211,41.82292],[-72.8824,41.82702],[-72.88318,41.83172],[-72.88379,41.83764],[-72.88576,41.86114],[-72.88647,41.86769],[-72.88722,41.88137],[-72.89035,41.91892],[-72.90451,41.91936]]]]}' FROM towns WHERE name = 'Canton';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.12483,41.82744],[-72.12948,41.82773],[-72.1436,41.8292],[-72.14837,41.82943],[-72.15866,41.82969],[-72.17514,41.82979],[-72.17543,41.82979],[-72.17543,41.82977],[-72.16496,41.74613],[-72.16452,41.74606],[-72.16426,41.74606],[-72.13005,41.74096],[-72.1201,41.73827],[-72.11656,41.73789],[-72.11072,41.73676],[-72.10926,41.7425],[-72.10459,41.76093],[-72.10302,41.81994],[-72.10285,41.82661],[-72.10288,41.82661],[-72.11181,41.8269],[-72.12064,41.82716],[-72.12483,41.82744]]]]}' FROM towns WHERE name = 'Chaplin';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.86,41.53522],[-72.8693,41.53725],[-72.8698,41.54008],[-72.87118,41.54168],[-72.87154,41.5429],[-72.87349,41.54325],[-72.87357,41.54513],[-72.87387,41.54543],[-72.87429,41.54538],[-72.87483,41.54483],[-72.8752,41.54493],[-72.87605,41.54661],[-72.87585,41.54733],[-72.87608,41.54778],[-72.88164,41.55078],[-72.8818,41.55126],[-72.88129,41.55201],[-72.88312,41.55219],[-72.88422,41.55251],[-72.93775,41.54617],[-72.94739,41.54504],[-72.95451,41.54418],[-72.95824,41.54371],[-72.95951,41.54074],[-72.9672,41.52494],[-72.97048,41.51815],[-72.95156,41.51068],[-72.94971,41.5099],[-72.93581,41.49462],[-72.93652,41.48918],[-72.93724,41.48728],[-72.93726,41.48641],[-72.93772,41.48526],[-72.93767,41.48441],[-72.938,41.48406],[-72.93831,41.48406],[-72.93968,41.48179],[-72.94112,41.48052],[-72.94164,41.47745],[-72.94245,41.47695],[-72.94326,41.47548],[-72.94329,41.47498],[-72.9428,41.47415],[-72.94428,41.46669],[-72.94508,41.46571],[-72.94553,41.46466],[-72.94654,41.46432],[-72.94847,41.46072],[-72.94894,41.45918],[-72.94885,41.4585],[-72.94929,41.45563],[-72.9501,41.45326],[-72.9508,41.45074],[-72.9486,41.45046],[-72.94673,41.45026],[-72.93958,41.44945],[-72.93499,41.44894],[-72.93271,41.44809],[-72.92815,41.44691],[-72.92108,41.44627],[-72.91937,41.44587],[-72.91583,41.44554],[-72.90685,41.4417],[-72.89809,41.43832],[-72.88533,41.43335],[-72.88533,41.43338],[-72.88002,41.44026],[-72.87875,41.44171],[-72.87732,41.44281],[-72.87485,41.4464],[-72.87604,41.44693],[-72.87358,41.45164],[-72.86491,41.46654],[-72.85586,41.47994],[-72.85476,41.48146],[-72.84616,41.48947],[-72.84924,41.49681],[-72.85187,41.50319],[-72.85458,41.50941],[-72.85392,41.51248],[-72.85854,41.51414],[-72.85878,41.51488],[-72.86107,41.51491],[-72.86202,41.51586],[-72.85888,41.52218],[-72.85464,41.52112],[-72.85315,41.52809],[-72.85467,41.53418],[-72.86,41.53522]]]]}' FROM towns WHERE name = 'Cheshire';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.43153,41.40826],[-72.4341,41.41112],[-72.43718,41.41347],[-72.44424,41.4163],[-72.44832,41.41766],[-72.44935,41.41803],[-72.45836,41.41682],[-72.49748,41.41174],[-72.52191,41.40918],[-72.52338,41.40883],[-72.53637,41.40578],[-72.52016,41.3593],[-72.51563,41.35969],[-72.51244,41.35953],[-72.49968,41.36124],[-72.45112,41.36534],[-72.4566,41.36974],[-72.43914,41.37608],[-72.43656,41.37917],[-72.43416,41.38112],[-72.43397,41.38179],[-72.43424,41.38289],[-72.43364,41.38394],[-72.43242,41.38454],[-72.43162,41.38466],[-72.43103,41.38519],[-72.43052,41.38531],[-72.42898,41.38483],[-72.42823,41.38491],[-72.42286,41.38427],[-72.42174,41.38415],[-72.42192,41.3846],[-72.42248,41.38954],[-72.42242,41.39079],[-72.42207,41.39194],[-72.42224,41.39359],[-72.4248,41.39881],[-72.42768,41.40229],[-72.43001,41.40549],[-72.43135,41.40796],[-72.43143,41.40806],[-72.43153,41.40826]]]]}' FROM towns WHERE name = 'Chester';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.48662,41.26886],[-72.50676,41.32269],[-72.50694,41.32314],[-72.51327,41.3216],[-72.5201,41.32016],[-72.52683,41.31825],[-72.53932,41.31165],[-72.54598,41.30788],[-72.55311,41.30325],[-72.5614,41.29919],[-72.57678,41.29247],[-72.58002,41.2912],[-72.58,41.29118],[-72.58014,41.29033],[-72.57965,41.28935],[-72.58,41.28876],[-72.57987,41.28843],[-72.57898,41.28788],[-72.57653,41.28728],[-72.57548,41.28643],[-72.57529,41.28585],[-72.57595,41.28525],[-72.57604,41.28488],[-72.57344,41.28485],[-72.57145,41.28407],[-72.5704,41.28327],[-72.57043,41.2825],[-72.57004,41.28175],[-72.56811,41.28054],[-72.56761,41.28004],[-72.5648,41.27879],[-72.56472,41.27854],[-72.56559,41.27854],[-72.56573,41.27834],[-72.56398,41.27752],[-72.56402,41.27709],[-72.56461,41.27659],[-72.56401,41.27519],[-72.56383,41.27504],[-72.56291,41.27514],[-72.56159,41.27494],[-72.56117,41.27464],[-72.56068,41.27367],[-72.55945,41.27316],[-72.55902,41.27194],[-72.55826,41.27081],[-72.55825,41.26969],[-72.55756,41.26916],[-72.55607,41.26904],[-72.55431,41.26784],[-72.55401,41.26696],[-72.55284,41.26509],[-72.55119,41.26438],[-72.55072,41.26398],[-72.55068,41.26284],[-72.55037,41.26226],[-72.54802,41.26096],[-72.54581,41.26108],[-72.54445,41.26088],[-72.54257,41.26215],[-72.54218,41.2622],[-72.54138,41.26157],[-72.53948,41.2607],[-72.53928,41.2604],[-72.53934,41.26],[-72.54026,41.259],[-72.54109,41.25768],[-72.54094,41.2573],[-72.54025,41.25698],[-72.53949,41.25622],[-72.5394,41.25627],[-72.53768,41.25565],[-72.53752,41.2553],[-72.53728,41.25527],[-72.53759,41.25502],[-72.53736,41.25472],[-72.53712,41.25477],[-72.53686,41.25435],[-72.53717,41.25412],[-72.53741,41.25335],[-72.5397,41.25165],[-72.53973,41.25123],[-72.5394,41.25063],[-72.53952,41.24891],[-72.53865,41.24771],[-72.53869,41.24631],[-72.53895,41.24528],[-72.53937,41.24496],[-72.53951,41.24454],[-72.53925,41.24321],[-72.53895,41.24284],[-72.53735,41.24303],[-72.53565,41.24411],[-72.535,41.2442],[-72.53414,41.24468],[-72.53239,41.24627],[-72.53096,41.24715],[-72.52788,41.24814],[-72.52388,41.24878],[-72.52396,41.24896],[-72.52365,41.24903],[-72.52357,41.24883],[-72.52241,41.24918],[-72.52249,41.24946],[-72.52191,41.24958],[-72.52179,41.24933],[-72.52231,41.24906],[-72.52221,41.24878],[-72.52138,41.24888],[-72.52129,41.24908],[-72.52114,41.24893],[-72.52115,41.24913],[-72.52059,41.24923],[-72.52051,41.24908],[-72.51998,41.24913],[-72.51999,41.24933],[-72.5195,41.24938],[-72.51634,41.24917],[-72.51606,41.24967],[-72.51717,41.25015],[-72.51708,41.2506],[-72.51614,41.25069],[-72.51541,41.25054],[-72.5151,41.25107],[-72.51484,41.25114],[-72.51462,41.25104],[-72.51529,41.25002],[-72.5149,41.24989],[-72.51457,41.25039],[-72.51372,41.25014],[-72.51337,41.24912],[-72.51356,41.24894],[-72.5133,41.24839],[-72.51341,41.24812],[-72.51282,41.24772],[-72.51287,41.24629],[-72.51269,41.24597],[-72.51286,41.24579],[-72.51292,41.24477],[-72.51219,41.2432],[-72.512,41.24172],[-72.51152,41.24097],[-72.51132,41.23987],[-72.51158,41.23977],[-72.51079,41.2395],[-72.51126,41.23895],[-72.51116,41.23805],[-72.51152,41.23785],[-72.51137,41.23753],[-72.51088,41.23737],[-72.51015,41.23757],[-72.50879,41.23827],[-72.50757,41.23819],[-72.50536,41.23737],[-72.50323,41.23619],[-72.50174,41.23576],[-72.49954,41.23616],[-72.49971,41.23628],[-72.49964,41.23646],[-72.49933,41.23661],[-72.49983,41.23783],[-72.5001,41.23786],[-72.50018,41.23831],[-72.50036,41.23838],[-72.5003,41.23896],[-72.49881,41.2403],[-72.49806,41.24113],[-72.49818,41.2412],[-72.49749,41.24173],[-72.49762,41.2418],[-72.4969,41.24227],[-72.49715,41.2424],[-72.49655,41.24282],[-72.49622,41.24322],[-72.49628,41.2434],[-72.49483,41.24437],[-72.495,41.24454],[-72.4935,41.24542],[-72.4936,41.24554],[-72.49052,41.24683],[-72.48981,41.24731],[-72.48738,41.24815],[-72.48382,41.24875],[-72.48076,41.25039],[-72.47917,41.25071],[-72.47925,41.25091],[-72.48662,41.26886]]],[[[-72.52837,41.23455],[-72.52847,41.23483],[-72.52966,41.23553],[-72.53036,41.23626],[-72.53008,41.2373],[-72.53047,41.2376],[-72.52895,41.23943],[-72.52746,41.24075],[-72.52707,41.24142],[-72.52706,41.24204],[-72.5268,41.24234],[-72.52395,41.24369],[-72.52139,41.24441],[-72.52037,41.24446],[-72.51838,41.24528],[-72.51734,41.24623],[-72.51775,41.2469],[-72.51896,41.24673],[-72.52047,41.24606],[-72.52078,41.24568],[-72.52204,41.24498],[-72.52299,41.24481],[-72.52349,41.24446],[-72.52393,41.24459],[-72.52519,41.24441],[-72.52549,41.24407],[-72.52636,41.24369],[-72.52654,41.24327],[-72.52733,41.24304],[-72.5288,41.24187],[-72.52908,41.24127],[-72.52947,41.2412],[-72.52969,41.2406],[-72.52996,41.24083],[-72.53077,41.24015],[-72.53235,41.24013],[-72.53356,41.23843],[-72.53259,41.24016],[-72.53335,41.24021],[-72.53368,41.23986],[-72.53497,41.24003],[-72.53542,41.23953],[-72.53502,41.23918],[-72.53504,41.23903],[-72.53529,41.23841],[-72.53509,41.23826],[-72.52839,41.2345],[-72.52837,41.23455]]],[[[-72.50585,41.23362],[-72.50607,41.23347],[-72.50589,41.23335],[-72.50585,41.23362]]]]}' FROM towns WHERE name = 'Clinton';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.22121,41.52638],[-72.22905,41.53329],[-72.25176,41.54975],[-72.25405,41.55228],[-72.26569,41.56264],[-72.28549,41.5786],[-72.31318,41.60045],[-72.31674,41.59841],[-72.32476,41.60471],[-72.32842,41.60766],[-72.32876,41.60622],[-72.33159,41.59426],[-72.34688,41.58791],[-72.3562,41.58753],[-72.35854,41.58678],[-72.35923,41.58621],[-72.35961,41.58616],[-72.36058,41.58681],[-72.36233,41.58689],[-72.36296,41.58754],[-72.3639,41.58791],[-72.36466,41.58914],[-72.36568,41.58991],[-72.36643,41.58969],[-72.36697,41.58994],[-72.36743,41.58914],[-72.36868,41.58842],[-72.36965,41.58852],[-72.37044,41.58895],[-72.37139,41.589],[-72.37388,41.58848],[-72.37457,41.58813],[-72.37511,41.58825],[-72.37621,41.58923],[-72.37754,41.58976],[-72.37772,41.59021],[-72.37869,41.59016],[-72.37936,41.58961],[-72.37978,41.58746],[-72.38042,41.58704],[-72.37994,41.58639],[-72.37993,41.58611],[-72.3807,41.58574],[-72.38082,41.58497],[-72.38195,41.58437],[-72.384,41.58402],[-72.38401,41.5834],[-72.38428,41.58277],[-72.38534,41.5823],[-72.38527,41.58183],[-72.38565,41.58055],[-72.38697,41.57931],[-72.38703,41.57891],[-72.38659,41.57876],[-72.38684,41.57826],[-72.38692,41.57743],[-72.38741,41.57698],[-72.38959,41.57826],[-72.39655,41.58097],[-72.40036,41.5831],[-72.40295,41.58348],[-72.40541,41.58463],[-72.4098,41.58758],[-72.41197,41.59101],[-72.41331,41.59111],[-72.41331,41.59101],[-72.41965,41.57883],[-72.46913,41.57201],[-72.46952,41.57199],[-72.46918,41.57141],[-72.43284,41.51316],[-72.43194,41.51142],[-72.43179,41.51141],[-72.38426,41.51586],[-72.32469,41.52112],[-72.32376,41.50461],[-72.32376,41.50458],[-72.31511,41.50562],[-72.25328,41.51879],[-72.25087,41.51948],[-72.23228,41.52348],[-72.22073,41.52593],[-72.22121,41.52638]]]]}' FROM towns WHERE name = 'Colchester';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.02697,42.04043],[-73.05608,42.041],[-73.14129,42.04388],[-73.14392,42.04398],[-73.15533,42.00084],[-73.16563,41.95945],[-73.14974,41.96025],[-73.12207,41.9617],[-73.0783,41.96353],[-73.04935,41.96591],[-73.04847,41.96596],[-73.04391,41.96628],[-73.0422,41.97389],[-73.03603,42.00066],[-73.02536,42.04038],[-73.02697,42.04043]]]]}' FROM towns WHERE name = 'Colebrook';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.25421,41.71492],[-72.25488,41.71385],[-72.25541,41.71363],[-72.25563,41.71393],[-72.25509,41.71455],[-72.2553,41.71495],[-72.25585,41.71478],[-72.25722,41.71493],[-72.25805,41.7144],[-72.25965,41.71403],[-72.25995,41.71363],[-72.26003,41.71298],[-72.26049,41.71286],[-72.26274,41.71354],[-72.26367,41.71454],[-72.26518,41.71464],[-72.26643,41.71389],[-72.26851,41.71127],[-72.26987,41.71045],[-72.27038,41.70963],[-72.27215,41.70941],[-72.27286,41.70956],[-72.27419,41.71108],[-72.27538,41.71186],[-72.27884,41.71296],[-72.27979,41.71311],[-72.2811,41.71302],[-72.28216,41.71267],[-72.28273,41.71214],[-72.28324,41.71217],[-72.28395,41.71302],[-72.28255,41.71352],[-72.28242,41.71392],[-72.28265,41.71414],[-72.28357,41.71402],[-72.28401,41.71419],[-72.28513,41.71594],[-72.2853,41.71587],[-72.28527,41.71497],[-72.28559,41.71492],[-72.2859,41.71552],[-72.28636,41.7157],[-72.2879,41.71415],[-72.2894,41.71388],[-72.28975,41.71423],[-72.28955,41.71498],[-72.29069,41.71493],[-72.29101,41.71428],[-72.29138,41.71426],[-72.29181,41.71588],[-72.29152,41.71598],[-72.29089,41.71575],[-72.29082,41.71605],[-72.29135,41.71668],[-72.29222,41.71723],[-72.29666,41.71711],[-72.2979,41.71686],[-72.30125,41.71702],[-72.30231,41.71737],[-72.30416,41.7184],[-72.30542,41.71817],[-72.30691,41.71743],[-72.30768,41.71725],[-72.30815,41.7175],[-72.3096,41.71975],[-72.31005,41.72013],[-72.31271,41.72123],[-72.31407,41.72131],[-72.31491,41.72156],[-72.31545,41.72198],[-72.31629,41.72226],[-72.31816,41.72386],[-72.31916,41.72384],[-72.32021,41.72332],[-72.32226,41.72357],[-72.32381,41.72425],[-72.32417,41.72422],[-72.32474,41.72377],[-72.32525,41.72427],[-72.32636,41.7243],[-72.32698,41.72462],[-72.32795,41.7246],[-72.32834,41.72533],[-72.32934,41.7261],[-72.3306,41.72595],[-72.33312,41.72673],[-72.33459,41.72693],[-72.33489,41.72813],[-72.33585,41.72858],[-72.33603,41.72901],[-72.33574,41.72978],[-72.3351,41.73023],[-72.33516,41.73063],[-72.33546,41.73078],[-72.33597,41.73093],[-72.33672,41.73006],[-72.35082,41.7131],[-72.35788,41.70297],[-72.36073,41.69763],[-72.36181,41.69543],[-72.35752,41.67959],[-72.34319,41.6446],[-72.3387,41.63338],[-72.30777,41.64432],[-72.28415,41.66254],[-72.27385,41.66178],[-72.2739,41.66275],[-72.2735,41.66383],[-72.27263,41.665],[-72.27314,41.66637],[-72.27274,41.66692],[-72.27191,41.6675],[-72.27185,41.6682],[-72.27218,41.66855],[-72.27311,41.66885],[-72.27306,41.66945],[-72.27337,41.66995],[-72.27327,41.67082],[-72.27366,41.67152],[-72.27375,41.67219],[-72.27449,41.67329],[-72.2746,41.67447],[-72.2756,41.67539],[-72.27513,41.67604],[-72.27527,41.67662],[-72.27611,41.67707],[-72.27685,41.67827],[-72.27808,41.67949],[-72.27929,41.6801],[-72.28074,41.68157],[-72.28062,41.68245],[-72.28018,41.68309],[-72.2804,41.68394],[-72.28026,41.68427],[-72.27961,41.68424],[-72.27922,41.68357],[-72.27803,41.68364],[-72.27775,41.68324],[-72.2761,41.68246],[-72.27538,41.68269],[-72.27468,41.68376],[-72.27435,41.68391],[-72.27431,41.68338],[-72.27359,41.68293],[-72.27357,41.68238],[-72.27283,41.68186],[-72.27224,41.68186],[-72.27183,41.68203],[-72.27175,41.68243],[-72.27016,41.68295],[-72.26971,41.68398],[-72.26984,41.68443],[-72.26859,41.68505],[-72.26825,41.68577],[-72.26759,41.68645],[-72.26753,41.68687],[-72.26795,41.68707],[-72.26717,41.68789],[-72.26739,41.68947],[-72.26719,41.68997],[-72.26543,41.68969],[-72.26482,41.68976],[-72.26447,41.69009],[-72.26425,41.69104],[-72.26343,41.69119],[-72.26231,41.69171],[-72.26126,41.69186],[-72.26017,41.69171],[-72.2583,41.69033],[-72.25713,41.69033],[-72.25668,41.69058],[-72.25665,41.69113],[-72.25743,41.69195],[-72.25792,41.69293],[-72.25773,41.69472],[-72.25682,41.6951],[-72.25408,41.69552],[-72.25259,41.69509],[-72.25186,41.69591],[-72.25185,41.69646],[-72.251,41.69651],[-72.25088,41.69669],[-72.25104,41.69699],[-72.25066,41.69714],[-72.25073,41.69793],[-72.25098,41.69821],[-72.25198,41.69831],[-72.25285,41.69816],[-72.25402,41.69841],[-72.25425,41.69879],[-72.25417,41.69926],[-72.25457,41.69951],[-72.25435,41.70031],[-72.25365,41.69974],[-72.25341,41.69974],[-72.2527,41.70191],[-72.25236,41.70221],[-72.25254,41.70238],[-72.25247,41.70261],[-72.25204,41.70271],[-72.25152,41.70233],[-72.2517,41.70283],[-72.25159,41.70328],[-72.25044,41.70355],[-72.25018,41.7038],[-72.25059,41.70458],[-72.25178,41.7055],[-72.2509,41.70605],[-72.25037,41.7055],[-72.24987,41.70573],[-72.24942,41.70535],[-72.24979,41.70488],[-72.2495,41.7047],[-72.24852,41.7052],[-72.2484,41.70472],[-72.24798,41.70515],[-72.24746,41.70497],[-72.24713,41.7053],[-72.24655,41.70525],[-72.24661,41.7057],[-72.2462,41.70562],[-72.24595,41.70562],[-72.24635,41.7058],[-72.24835,41.7069],[-72.2495,41.70807],[-72.25134,41.70868],[-72.25198,41.71122],[-72.25384,41.7149],[-72.25396,41.71497],[-72.25421,41.71492]]]]}' FROM towns WHERE name = 'Columbia';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.28322,41.91539],[-73.37047,41.91757],[-73.37488,41.91765],[-73.37465,41.9175],[-73.37402,41.9168],[-73.37354,41.91533],[-73.37342,41.91453],[-73.37376,41.91193],[-73.37366,41.90951],[-73.37288,41.90621],[-73.37291,41.90486],[-73.37193,41.90132],[-73.37302,41.89785],[-73.37317,41.89665],[-73.37267,41.89525],[-73.37125,41.89425],[-73.37084,41.89335],[-73.37064,41.89012],[-73.36973,41.88805],[-73.36964,41.88588],[-73.37161,41.88413],[-73.37604,41.88286],[-73.37678,41.88242],[-73.37727,41.88169],[-73.37761,41.88022],[-73.3768,41.87829],[-73.3766,41.87725],[-73.3766,41.8739],[-73.37682,41.87248],[-73.37643,41.8714],[-73.37524,41.8693],[-73.37484,41.86708],[-73.37546,41.86603],[-73.37776,41.86633],[-73.37889,41.86603],[-73.37992,41.86541],[-73.38042,41.86431],[-73.38045,41.86292],[-73.38202,41.86179],[-73.38276,41.86057],[-73.38403,41.85927],[-73.3851,41.85768],[-73.38535,41.85703],[-73.38542,41.85545],[-73.38462,41.85221],[-73.38456,41.85088],[-73.3848,41.85001],[-73.38605,41.84866],[-73.38646,41.84774],[-73.38583,41.84619],[-73.3849,41.84529],[-73.38434,41.84436],[-73.38458,41.84289],[-73.38653,41.83875],[-73.38704,41.8371],[-73.38787,41.8359],[-73.3879,41.83503],[-73.38835,41.83383],[-73.39235,41.83034],[-73.39336,41.82912],[-73.39424,41.82762],[-73.39425,41.82717],[-73.39386,41.82642],[-73.38825,41.82177],[-73.38651,41.82116],[-73.3845,41.82159],[-73.38362,41.82121],[-73.38274,41.82043],[-73.38188,41.81928],[-73.38141,41.81826],[-73.3814,41.81771],[-73.38252,41.81634],[-73.38345,41.81329],[-73.38649,41.80998],[-73.39146,41.80739],[-73.39298,41.80611],[-73.39347,41.80524],[-73.39389,41.80492],[-73.39703,41.80335],[-73.3992,41.80278],[-73.40077,41.80338],[-73.40154,41.80313],[-73.40271,41.80226],[-73.40451,41.80054],[-73.4058,41.79839],[-73.4086,41.79447],[-73.41082,41.7922],[-73.41609,41.78817],[-73.41869,41.784],[-73.41888,41.78378],[-73.41906,41.78353],[-73.42105,41.78073],[-73.42256,41.77781],[-73.42343,41.77666],[-73.39546,41.77615],[-73.38312,41.77585],[-73.31852,41.77426],[-73.30988,41.77402],[-73.30872,41.77671],[-73.30888,41.77704],[-73.30794,41.78131],[-73.29079,41.85276],[-73.28357,41.88427],[-73.27996,41.89758],[-73.27703,41.90926],[-73.27553,41.91513],[-73.28322,41.91539]]]]}' FROM towns WHERE name = 'Cornwall';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.32073,41.83174],[-72.3204,41.83306],[-72.32038,41.83311],[-72.3267,41.83235],[-72.385,41.82524],[-72.42204,41.82125],[-72.43193,41.82019],[-72.42829,41.80123],[-72.42744,41.79703],[-72.4201,41.76395],[-72.419,41.75896],[-72.41491,41.7587],[-72.39014,41.75721],[-72.36341,41.7605],[-72.3374,41.73238],[-72.33597,41.73093],[-72.33546,41.73078],[-72.33516,41.73063],[-72.3351,41.73023],[-72.33574,41.72978],[-72.33603,41.72901],[-72.33585,41.72858],[-72.33489,41.72813],[-72.33459,41.72693],[-72.33312,41.72673],[-72.3306,41.72595],[-72.32934,41.7261],[-72.32834,41.72533],[-72.32795,41.7246],[-72.32698,41.72462],[-72.32636,41.7243],[-72.32525,41.72427],[-72.32474,41.72377],[-72.32417,41.72422],[-72.32381,41.72425],[-72.32226,41.72357],[-72.32021,41.72332],[-72.31916,41.72384],[-72.31816,41.72386],[-72.31629,41.72226],[-72.31545,41.72198],[-72.31491,41.72156],[-72.31407,41.72131],[-72.31271,41.72123],[-72.31005,41.72013],[-72.3096,41.71975],[-72.30815,41.7175],[-72.30768,41.71725],[-72.30691,41.71743],[-72.30542,41.71817],[-72.30416,41.7184],[-72.30231,41.71737],[-72.30125,41.71702],[-72.2979,41.71686],[-72.29666,41.71711],[-72.29222,41.71723],[-72.29135,41.71668],[-72.29082,41.71605],[-72.29089,41.71575],[-72.29152,41.71598],[-72.29181,41.71588],[-72.29138,41.71426],[-72.29101,41.71428],[-72.29069,41.71493],[-72.28955,41.71498],[-72.28975,41.71423],[-72.2894,41.71388],[-72.2879,41.71415],[-72.28636,41.7157],[-72.2859,41.71552],[-72.28559,41.71492],[-72.28527,41.71497],[-72.2853,41.71587],[-72.28513,41.71594],[-72.28401,41.71419],[-72.28357,41.71402],[-72.28265,41.71414],[-72.28242,41.71392],[-72.28255,41.71352],[-72.28395,41.71302],[-72.28324,41.71217],[-72.28273,41.71214],[-72.28216,41.71267],[-72.2811,41.71302],[-72.27979,41.71311],[-72.27884,41.71296],[-72.27538,41.71186],[-72.27419,41.71108],[-72.27286,41.70956],[-72.27215,41.70941],[-72.27038,41.70963],[-72.26987,41.71045],[-72.26851,41.71127],[-72.26643,41.71389],[-72.26518,41.71464],[-72.26367,41.71454],[-72.26274,41.71354],[-72.26049,41.71286],[-72.26003,41.71298],[-72.25995,41.71363],[-72.25965,41.71403],[-72.25805,41.7144],[-72.25722,41.71493],[-72.25585,41.71478],[-72.2553,41.71495],[-72.25509,41.71455],[-72.25563,41.71393],[-72.25541,41.71363],[-72.25488,41.71385],[-72.25421,41.71492],[-72.25396,41.71497],[-72.25431,41.71532],[-72.25502,41.71602],[-72.25678,41.7165],[-72.25758,41.71645],[-72.25844,41.71593],[-72.25888,41.7159],[-72.25931,41.71648],[-72.26002,41.71688],[-72.26035,41.71731],[-72.26039,41.71778],[-72.25927,41.71915],[-72.25931,41.71965],[-72.25968,41.71998],[-72.25986,41.72025],[-72.25993,41.7203],[-72.26039,41.72105],[-72.26126,41.7216],[-72.26325,41.72143],[-72.26715,41.72196],[-72.26811,41.72384],[-72.26887,41.72489],[-72.26801,41.72616],[-72.26863,41.72726],[-72.26923,41.72778],[-72.26909,41.72886],[-72.26934,41.72913],[-72.27066,41.72931],[-72.27064,41.72961],[-72.27015,41.73021],[-72.27014,41.73078],[-72.27032,41.73113],[-72.27126,41.73168],[-72.27107,41.73258],[-72.27138,41.73301],[-72.27261,41.73366],[-72.27269,41.73393],[-72.2724,41.73461],[-72.27331,41.73603],[-72.2762,41.73729],[-72.2754,41.73753],[-72.27544,41.73803],[-72.27618,41.73831],[-72.27735,41.73836],[-72.27762,41.73869],[-72.27744,41.73901],[-72.2761,41.73963],[-72.27532,41.74036],[-72.27386,41.74275],[-72.27371,41.74345],[-72.27409,41.74575],[-72.27599,41.74892],[-72.27777,41.75065],[-72.27932,41.75305],[-72.27888,41.75445],[-72.27744,41.75502],[-72.27721,41.75559],[-72.27803,41.75717],[-72.27877,41.75732],[-72.27932,41.75712],[-72.27952,41.75727],[-72.27923,41.75827],[-72.27942,41.75892],[-72.28079,41.76005],[-72.28074,41.76227],[-72.28041,41.76349],[-72.28056,41.76419],[-72.28218,41.76574],[-72.28397,41.76704],[-72.28413,41.76757],[-72.284,41.76884],[-72.28414,41.76937],[-72.28495,41.77047],[-72.28955,41.77295],[-72.28922,41.77509],[-72.28984,41.77804],[-72.29137,41.77939],[-72.30092,41.78915],[-72.30242,41.78885],[-72.30442,41.78915],[-72.30544,41.7889],[-72.30587,41.7897],[-72.30644,41.79006],[-72.30978,41.79118],[-72.31164,41.79084],[-72.3122,41.79146],[-72.31354,41.79094],[-72.31474,41.78939],[-72.31527,41.78932],[-72.31633,41.78992],[-72.31856,41.7903],[-72.32149,41.79163],[-72.32237,41.7926],[-72.32205,41.79343],[-72.32091,41.7935],[-72.31958,41.79435],[-72.31914,41.79519],[-72.31905,41.79607],[-72.31958,41.79684],[-72.32572,41.79963],[-72.32567,41.80127],[-72.32588,41.802],[-72.32648,41.80242],[-72.32637,41.80282],[-72.32524,41.80332],[-72.3249,41.80472],[-72.32479,41.80674],[-72.32493,41.80749],[-72.32663,41.80887],[-72.32687,41.81059],[-72.32674,41.81099],[-72.32514,41.81191],[-72.32389,41.81313],[-72.32324,41.81508],[-72.32385,41.81603],[-72.32423,41.81713],[-72.32357,41.81893],[-72.32374,41.82033],[-72.32325,41.82123],[-72.32322,41.82182],[-72.32443,41.82225],[-72.32454,41.82288],[-72.32436,41.82328],[-72.32359,41.8242],[-72.32213,41.8241],[-72.32123,41.82437],[-72.32058,41.82619],[-72.31895,41.82774],[-72.32056,41.82949],[-72.32089,41.83061],[-72.32081,41.83126],[-72.32073,41.83174]]]]}' FROM towns WHERE name = 'Coventry';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.63442,41.62451],[-72.6339,41.62671],[-72.63245,41.6301],[-72.63243,41.63015],[-72.6325,41.63013],[-72.69818,41.62051],[-72.71805,41.61757],[-72.71819,41.61735],[-72.71998,41.61485],[-72.71961,41.613],[-72.71915,41.61228],[-72.71774,41.61128],[-72.71716,41.61063],[-72.7169,41.6101],[-72.71692,41.60925],[-72.71671,41.60865],[-72.71514,41.60813],[-72.71481,41.60768],[-72.71497,41.60725],[-72.7163,41.60626],[-72.71644,41.60306],[-72.71711,41.60109],[-72.71716,41.60014],[-72.71925,41.59612],[-72.7191,41.59515],[-72.71779,41.59359],[-72.71769,41.59347],[-72.71748,41.59319],[-72.71662,41.59199],[-72.7161,41.59172],[-72.71363,41.59199],[-72.71252,41.59286],[-72.71182,41.59241],[-72.71159,41.59204],[-72.71112,41.59186],[-72.70971,41.59256],[-72.70872,41.59268],[-72.70659,41.59155],[-72.70438,41.59145],[-72.7035,41.59123],[-72.70077,41.59025],[-72.6996,41.58947],[-72.69829,41.59034],[-72.69745,41.59059],[-72.69645,41.59037],[-72.69565,41.59064],[-72.69264,41.58986],[-72.68898,41.591],[-72.68769,41.59113],[-72.68422,41.58962],[-72.68372,41.5884],[-72.68364,41.58655],[-72.68266,41.58612],[-72.68149,41.58625],[-72.68054,41.58694],[-72.67937,41.58744],[-72.67831,41.58876],[-72.67727,41.58889],[-72.67571,41.58784],[-72.67474,41.58649],[-72.67506,41.58551],[-72.67599,41.58504],[-72.67629,41.58464],[-72.67606,41.58352],[-72.67278,41.58034],[-72.67208,41.58019],[-72.6707,41.58044],[-72.67028,41.58029],[-72.66962,41.57929],[-72.66999,41.57869],[-72.67002,41.57799],[-72.66943,41.57701],[-72.66828,41.57599],[-72.66831,41.57551],[-72.66862,41.57526],[-72.67039,41.57514],[-72.6708,41.57482],[-72.67107,41.57434],[-72.67115,41.57362],[-72.67072,41.57237],[-72.66931,41.57154],[-72.66804,41.57049],[-72.66613,41.56999],[-72.66484,41.56929],[-72.66372,41.56826],[-72.66323,41.56729],[-72.66262,41.56721],[-72.66122,41.56751],[-72.6606,41.56721],[-72.65949,41.56586],[-72.65838,41.56611],[-72.65796,41.56601],[-72.65793,41.56571],[-72.65845,41.56528],[-72.65884,41.56458],[-72.65866,41.56169],[-72.65703,41.56151],[-72.65646,41.56051],[-72.6552,41.56138],[-72.65423,41.56143],[-72.65146,41.56142],[-72.65146,41.5616],[-72.65109,41.5637],[-72.65035,41.56599],[-72.6454,41.57488],[-72.64385,41.5767],[-72.64251,41.58007],[-72.64077,41.58269],[-72.63888,41.58456],[-72.63205,41.58924],[-72.63023,41.59014],[-72.62693,41.59113],[-72.62523,41.59201],[-72.62342,41.59348],[-72.62229,41.5947],[-72.62091,41.59732],[-72.62058,41.59924],[-72.62058,41.60069],[-72.62082,41.60214],[-72.6211,41.60271],[-72.62442,41.60562],[-72.62797,41.60797],[-72.63081,41.61102],[-72.63274,41.61395],[-72.63388,41.61635],[-72.63459,41.61874],[-72.6346,41.62321],[-72.6346,41.62324],[-72.63442,41.62451]]]]}' FROM towns WHERE name = 'Cromwell';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.41379,41.4162],[-73.42152,41.43889],[-73.42541,41.45033],[-73.46047,41.44259],[-73.49779,41.43481],[-73.53462,41.42667],[-73.5346,41.4262],[-73.53522,41.41511],[-73.53743,41.38699],[-73.53823,41.36249],[-73.53843,41.35967],[-73.50237,41.36146],[-73.49868,41.35037],[-73.49824,41.34957],[-73.49708,41.34567],[-73.48403,41.34487],[-73.46732,41.3164],[-73.4647,41.3116],[-73.46369,41.30958],[-73.45832,41.31059],[-73.42862,41.31639],[-73.41908,41.31818],[-73.42161,41.3263],[-73.43081,41.35676],[-73.43018,41.35935],[-73.4302,41.36003],[-73.42959,41.36053],[-73.42957,41.3614],[-73.42904,41.36182],[-73.42873,41.3628],[-73.42106,41.36541],[-73.419,41.3656],[-73.40385,41.38067],[-73.40383,41.38102],[-73.40445,41.38137],[-73.40449,41.38197],[-73.40383,41.38264],[-73.40328,41.38289],[-73.40351,41.38324],[-73.40325,41.38344],[-73.4034,41.38364],[-73.40302,41.38404],[-73.40276,41.38489],[-73.40249,41.38501],[-73.40244,41.38568],[-73.4016,41.38603],[-73.40151,41.38636],[-73.39872,41.38638],[-73.39873,41.3869],[-73.39773,41.38823],[-73.39901,41.39057],[-73.40026,41.39395],[-73.4021,41.39767],[-73.40287,41.3998],[-73.40426,41.41151],[-73.40457,41.41439],[-73.41379,41.4162]]]]}' FROM towns WHERE name = 'Danbury';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.4735,41.09138],[-73.48747,41.09177],[-73.49033,41.09178],[-73.49043,41.09168],[-73.49117,41.09145],[-73.49092,41.09095],[-73.49095,41.0906],[-73.49145,41.09023],[-73.49167,41.08951],[-73.49234,41.08906],[-73.49242,41.08831],[-73.49429,41.08669],[-73.4954,41.08734],[-73.49584,41.08729],[-73.4965,41.08682],[-73.49704,41.08692],[-73.49727,41.08647],[-73.49783,41.08649],[-73.49808,41.08599],[-73.49863,41.08562],[-73.49902,41.08482],[-73.4992,41.08417],[-73.49916,41.08287],[-73.49883,41.08262],[-73.49781,41.08245],[-73.49693,41.08172],[-73.49721,41.0811],[-73.49705,41.0807],[-73.49734,41.07987],[-73.49825,41.07943],[-73.49787,41.07893],[-73.49866,41.07773],[-73.499,41.0768],[-73.49972,41.07648],[-73.4999,41.07618],[-73.50014,41.07516],[-73.50047,41.07468],[-73.50042,41.07411],[-73.50073,41.07394],[-73.50088,41.07319],[-73.50161,41.07231],[-73.50218,41.07114],[-73.50219,41.06984],[-73.50159,41.06922],[-73.50157,41.06849],[-73.50278,41.06592],[-73.5026,41.06567],[-73.50176,41.06537],[-73.50161,41.06515],[-73.50166,41.0643],[-73.50252,41.06382],[-73.5024,41.06312],[-73.50254,41.06205],[-73.50337,41.06208],[-73.50379,41.06143],[-73.50367,41.06088],[-73.50293,41.06043],[-73.50297,41.06003],[-73.50381,41.05898],[-73.50384,41.05756],[-73.50498,41.05673],[-73.50454,41.05593],[-73.5038,41.05531],[-73.50389,41.05433],[-73.50452,41.05344],[-73.50436,41.05301],[-73.50382,41.05289],[-73.50389,41.05264],[-73.50308,41.05174],[-73.50218,41.05181],[-73.5014,41.05156],[-73.50122,41.05123],[-73.50179,41.05083],[-73.50183,41.05048],[-73.50124,41.05033],[-73.50088,41.04971],[-73.50033,41.04926],[-73.49955,41.04898],[-73.49826,41.04901],[-73.49771,41.04871],[-73.49738,41.04726],[-73.49656,41.04686],[-73.49627,41.04583],[-73.49642,41.04533],[-73.49631,41.04411],[-73.49655,41.04316],[-73.49564,41.04201],[-73.49514,41.04061],[-73.495,41.03924],[-73.49443,41.03804],[-73.49362,41.03719],[-73.49356,41.03664],[-73.49346,41.03666],[-73.49291,41.03539],[-73.49245,41.03534],[-73.49074,41.03411],[-73.49,41.03383],[-73.48928,41.03408],[-73.48862,41.03391],[-73.48805,41.03358],[-73.48712,41.03253],[-73.48655,41.03243],[-73.48612,41.0327],[-73.48556,41.03248],[-73.48486,41.0327],[-73.48431,41.03233],[-73.48393,41.0326],[-73.48361,41.03245],[-73.48323,41.03257],[-73.4829,41.03307],[-73.48274,41.03237],[-73.48314,41.0321],[-73.48301,41.03177],[-73.48201,41.0316],[-73.48211,41.03115],[-73.48134,41.0306],[-73.48083,41.0305],[-73.48058,41.03112],[-73.47948,41.02922],[-73.47902,41.02932],[-73.47905,41.03012],[-73.47886,41.03037],[-73.47793,41.03024],[-73.47783,41.02947],[-73.47725,41.02944],[-73.47675,41.02967],[-73.47649,41.02934],[-73.47658,41.02904],[-73.47777,41.02902],[-73.47796,41.02884],[-73.47783,41.02854],[-73.47685,41.02809],[-73.4766,41.02719],[-73.47624,41.02737],[-73.47655,41.02789],[-73.47648,41.02819],[-73.47557,41.02787],[-73.47527,41.02839],[-73.475,41.02829],[-73.4751,41.02752],[-73.47588,41.02669],[-73.47598,41.02609],[-73.47712,41.02575],[-73.47763,41.02525],[-73.47762,41.02487],[-73.47816,41.02497],[-73.47793,41.02545],[-73.47813,41.0257],[-73.47785,41.02592],[-73.4781,41.02685],[-73.47883,41.02705],[-73.47935,41.02742],[-73.48038,41.02615],[-73.48271,41.02498],[-73.48061,41.02468],[-73.47945,41.02268],[-73.4796,41.0219],[-73.47764,41.02155],[-73.47683,41.02113],[-73.47663,41.02085],[-73.47679,41.02068],[-73.47619,41.02015],[-73.47616,41.01993],[-73.47643,41.01988],[-73.4761,41.01935],[-73.47618,41.01905],[-73.47699,41.01835],[-73.47702,41.01783],[-73.47658,41.01773],[-73.47582,41.0184],[-73.47506,41.01815],[-73.47483,41.01773],[-73.47523,41.01735],[-73.47539,41.01688],[-73.47435,41.0161],[-73.47405,41.01613],[-73.47384,41.01643],[-73.47333,41.01645],[-73.47324,41.01665],[-73.47339,41.01682],[-73.47293,41.01687],[-73.47285,41.0172],[-73.47232,41.01755],[-73.47251,41.0181],[-73.47291,41.01845],[-73.47262,41.0187],[-73.47291,41.0185],[-73.47316,41.0186],[-73.47298,41.0185],[-73.47442,41.0178],[-73.47558,41.0193],[-73.47372,41.01957],[-73.47283,41.02],[-73.47162,41.01939],[-73.4723,41.01987],[-73.47184,41.01999],[-73.4719,41.02047],[-73.47232,41.02072],[-73.47187,41.02097],[-73.47204,41.02109],[-73.47201,41.02162],[-73.47056,41.02189],[-73.47037,41.02209],[-73.47079,41.02301],[-73.4705,41.02386],[-73.47073,41.02441],[-73.47046,41.02504],[-73.47054,41.02609],[-73.47015,41.02616],[-73.47006,41.02554],[-73.46963,41.02506],[-73.46899,41.02543],[-73.46855,41.02621],[-73.46846,41.02661],[-73.46869,41.02703],[-73.46982,41.02713],[-73.47033,41.02651],[-73.47025,41.02636],[-73.47067,41.02656],[-73.47066,41.02706],[-73.47084,41.02729],[-73.47135,41.02721],[-73.47141,41.02691],[-73.47168,41.02701],[-73.47189,41.02726],[-73.47177,41.02749],[-73.47121,41.02739],[-73.47079,41.02786],[-73.47087,41.02818],[-73.47168,41.02851],[-73.47162,41.02883],[-73.47099,41.02883],[-73.47038,41.02823],[-73.46933,41.02868],[-73.46918,41.02951],[-73.46839,41.02988],[-73.46656,41.03217],[-73.46634,41.03205],[-73.46618,41.03152],[-73.46586,41.03135],[-73.46565,41.03165],[-73.46501,41.03132],[-73.46514,41.03092],[-73.46569,41.03065],[-73.4665,41.02933],[-73.46781,41.0292],[-73.46802,41.0289],[-73.46782,41.02855],[-73.46796,41.02835],[-73.46759,41.02828],[-73.46773,41.02795],[-73.46712,41.02785],[-73.46745,41.02683],[-73.46727,41.02641],[-73.46739,41.02571],[-73.46813,41.02523],[-73.46798,41.02511],[-73.46857,41.02199],[-73.4691,41.02209],[-73.46968,41.02254],[-73.46989,41.02239],[-73.46914,41.02154],[-73.4691,41.02124],[-73.46943,41.02074],[-73.46987,41.02089],[-73.46942,41.02029],[-73.46829,41.01991],[-73.46795,41.01851],[-73.46713,41.01784],[-73.46707,41.01661],[-73.46531,41.01459],[-73.46497,41.01391],[-73.46489,41.01304],[-73.46521,41.01299],[-73.46486,41.01262],[-73.46466,41.01164],[-73.46423,41.01129],[-73.46367,41.01116],[-73.46321,41.01126],[-73.46293,41.01159],[-73.4629,41.01284],[-73.46315,41.01406],[-73.46222,41.01638],[-73.46162,41.01718],[-73.46183,41.01771],[-73.46156,41.01925],[-73.46067,41.01978],[-73.4605,41.02055],[-73.45943,41.02122],[-73.45887,41.02117],[-73.45878,41.02155],[-73.45824,41.022],[-73.45801,41.02247],[-73.45752,41.02257],[-73.45711,41.02237],[-73.45649,41.02277],[-73.45588,41.02274],[-73.456,41.02294],[-73.45642,41.02292],[-73.45607,41.02337],[-73.45635,41.02384],[-73.45829,41.02379],[-73.45859,41.02407],[-73.45842,41.02419],[-73.4587,41.02432],[-73.45885,41.0239],[-73.4594,41.02362],[-73.4598,41.02305],[-73.46002,41.02217],[-73.46079,41.02188],[-73.46096,41.02123],[-73.46075,41.0208],[-73.46092,41.01993],[-73.46216,41.01983],[-73.46207,41.02],[-73.46257,41.02058],[-73.46271,41.02105],[-73.46291,41.0229],[-73.46332,41.02383],[-73.46311,41.02408],[-73.46339,41.02455],[-73.46218,41.0247],[-73.46138,41.02455],[-73.46078,41.02407],[-73.46078,41.0234],[-73.46052,41.0236],[-73.46013,41.02355],[-73.46049,41.02412],[-73.46048,41.0245],[-73.46089,41.02467],[-73.461,41.02492],[-73.46084,41.0253],[-73.46041,41.02557],[-73.46077,41.02605],[-73.46117,41.02585],[-73.46142,41.02595],[-73.46139,41.02662],[-73.46096,41.0269],[-73.46063,41.0265],[-73.46005,41.02654],[-73.45997,41.02624],[-73.45948,41.02689],[-73.45885,41.02704],[-73.45853,41.02694],[-73.45872,41.02659],[-73.45858,41.02622],[-73.45816,41.02594],[-73.4571,41.02617],[-73.45663,41.02596],[-73.45588,41.02619],[-73.45542,41.02611],[-73.45504,41.02651],[-73.45505,41.02751],[-73.45467,41.02791],[-73.45416,41.02798],[-73.45364,41.02756],[-73.45318,41.02776],[-73.45331,41.02796],[-73.45298,41.02831],[-73.4532,41.02833],[-73.45286,41.02926],[-73.4532,41.02921],[-73.45333,41.02951],[-73.4531,41.02991],[-73.45335,41.03068],[-73.45388,41.03056],[-73.45411,41.03023],[-73.45446,41.03053],[-73.45501,41.03023],[-73.45559,41.03021],[-73.45666,41.02924],[-73.4569,41.03011],[-73.45777,41.03001],[-73.45778,41.03036],[-73.45841,41.03031],[-73.4595,41.03101],[-73.46214,41.02959],[-73.46248,41.03047],[-73.46285,41.03069],[-73.46252,41.03184],[-73.46212,41.03219],[-73.46163,41.03214],[-73.46198,41.03092],[-73.46188,41.03007],[-73.46064,41.03092],[-73.46056,41.03124],[-73.46007,41.03121],[-73.4602,41.03141],[-73.45996,41.03161],[-73.45962,41.03149],[-73.45954,41.03121],[-73.45891,41.03156],[-73.45875,41.03109],[-73.45854,41.03141],[-73.45761,41.03091],[-73.45642,41.03181],[-73.45658,41.03236],[-73.45683,41.03243],[-73.45676,41.03261],[-73.45702,41.03258],[-73.4572,41.03281],[-73.45677,41.03298],[-73.45697,41.03306],[-73.45688,41.03338],[-73.45588,41.03416],[-73.45544,41.03396],[-73.45533,41.03363],[-73.45512,41.0338],[-73.45508,41.03413],[-73.45553,41.03455],[-73.45559,41.0349],[-73.45526,41.03525],[-73.45507,41.03623],[-73.45449,41.03635],[-73.45414,41.03605],[-73.45375,41.0361],[-73.45305,41.0369],[-73.45339,41.0371],[-73.4533,41.03737],[-73.45278,41.0378],[-73.45246,41.03765],[-73.45247,41.03702],[-73.45194,41.0372],[-73.45175,41.03657],[-73.45104,41.03645],[-73.45098,41.03617],[-73.45039,41.03597],[-73.45006,41.03545],[-73.45003,41.03522],[-73.45048,41.03482],[-73.45072,41.03475],[-73.45104,41.03495],[-73.45125,41.03452],[-73.4517,41.0343],[-73.45184,41.03397],[-73.45173,41.03358],[-73.45153,41.0335],[-73.45075,41.03405],[-73.4497,41.03407],[-73.44901,41.03452],[-73.44895,41.03492],[-73.44917,41.03494],[-73.4492,41.03514],[-73.44899,41.03554],[-73.44958,41.03552],[-73.44959,41.03515],[-73.45028,41.0362],[-73.44963,41.03649],[-73.44902,41.03732],[-73.44856,41.03717],[-73.44862,41.03682],[-73.44822,41.03642],[-73.44825,41.03492],[-73.44812,41.03477],[-73.44764,41.03484],[-73.44719,41.03454],[-73.44711,41.03497],[-73.44738,41.03507],[-73.44721,41.03524],[-73.44695,41.03462],[-73.44673,41.03454],[-73.4461,41.03534],[-73.44533,41.03589],[-73.44468,41.03586],[-73.44447,41.03614],[-73.44457,41.03636],[-73.44399,41.03639],[-73.4434,41.03608],[-73.44282,41.03621],[-73.44215,41.03671],[-73.44185,41.03721],[-73.44146,41.03728],[-73.44059,41.0381],[-73.44014,41.03788],[-73.44035,41.03748],[-73.44022,41.03733],[-73.44044,41.03733],[-73.44024,41.03718],[-73.44092,41.03688],[-73.44098,41.03656],[-73.44127,41.03661],[-73.4411,41.03638],[-73.44152,41.03603],[-73.44147,41.03581],[-73.44278,41.03494],[-73.44321,41.03489],[-73.44335,41.03529],[-73.44408,41.03526],[-73.44414,41.03486],[-73.44389,41.03464],[-73.44462,41.03386],[-73.44473,41.03341],[-73.44416,41.03311],[-73.4439,41.03329],[-73.44376,41.03369],[-73.44345,41.03384],[-73.44347,41.03451],[-73.44314,41.03471],[-73.44129,41.03473],[-73.44112,41.03406],[-73.44144,41.03396],[-73.44188,41.03416],[-73.44255,41.03399],[-73.44292,41.03309],[-73.44335,41.03279],[-73.44397,41.03174],[-73.44463,41.03112],[-73.44536,41.03109],[-73.44557,41.03147],[-73.44619,41.03117],[-73.44681,41.0299],[-73.44777,41.0303],[-73.44764,41.03015],[-73.44787,41.02962],[-73.44733,41.02937],[-73.44792,41.02882],[-73.44797,41.02815],[-73.44754,41.02825],[-73.44738,41.0286],[-73.44694,41.0288],[-73.44618,41.02847],[-73.44624,41.02805],[-73.44599,41.0279],[-73.44493,41.02817],[-73.44501,41.02842],[-73.44436,41.02862],[-73.4443,41.02887],[-73.44364,41.02874],[-73.44308,41.02887],[-73.44198,41.02944],[-73.44151,41.02996],[-73.44179,41.03024],[-73.44167,41.03034],[-73.44054,41.02996],[-73.44052,41.03021],[-73.44013,41.03024],[-73.43973,41.03056],[-73.43983,41.03076],[-73.4398,41.03053],[-73.44031,41.03049],[-73.4402,41.03096],[-73.44038,41.03104],[-73.44014,41.03123],[-73.44025,41.03161],[-73.44011,41.03176],[-73.43972,41.03181],[-73.43958,41.03138],[-73.43942,41.03153],[-73.43957,41.03183],[-73.43944,41.03211],[-73.43987,41.03211],[-73.44022,41.03241],[-73.44007,41.03291],[-73.43971,41.03323],[-73.43911,41.03326],[-73.43921,41.03341],[-73.43885,41.03353],[-73.43785,41.03343],[-73.43792,41.03265],[-73.43772,41.0334],[-73.43702,41.0334],[-73.43679,41.0331],[-73.43685,41.0325],[-73.43583,41.03263],[-73.43553,41.03253],[-73.4358,41.03238],[-73.4356,41.03228],[-73.43547,41.03268],[-73.4334,41.03347],[-73.43331,41.03365],[-73.43405,41.03397],[-73.43387,41.03445],[-73.4328,41.03455],[-73.43295,41.03707],[-73.43242,41.03902],[-73.43258,41.04091],[-73.43102,41.04406],[-73.43096,41.04523],[-73.43125,41.04596],[-73.43175,41.04653],[-73.43387,41.04741],[-73.43428,41.04801],[-73.43434,41.04868],[-73.43365,41.05068],[-73.43374,41.05136],[-73.43348,41.0524],[-73.43269,41.05265],[-73.43359,41.05443],[-73.43362,41.0568],[-73.43399,41.05777],[-73.43379,41.05865],[-73.43443,41.06132],[-73.43522,41.06247],[-73.43635,41.0629],[-73.43723,41.06287],[-73.43729,41.06332],[-73.43764,41.06377],[-73.43771,41.06455],[-73.43846,41.06515],[-73.43883,41.06517],[-73.43937,41.06455],[-73.43933,41.0642],[-73.44088,41.06393],[-73.44091,41.0642],[-73.44045,41.06498],[-73.44081,41.06653],[-73.44085,41.06768],[-73.45608,41.08533],[-73.4597,41.08921],[-73.46231,41.09176],[-73.4735,41.09138]]],[[[-73.44502,41.0271],[-73.44523,41.0267],[-73.44573,41.02647],[-73.44573,41.02577],[-73.44542,41.02512],[-73.44457,41.02512],[-73.44468,41.0245],[-73.44431,41.02447],[-73.44389,41.02415],[-73.44399,41.02337],[-73.44329,41.02362],[-73.44284,41.02417],[-73.44384,41.02427],[-73.44422,41.02457],[-73.4441,41.02485],[-73.44458,41.02532],[-73.44398,41.02635],[-73.44422,41.0264],[-73.4442,41.0266],[-73.44457,41.02657],[-73.4448,41.02702],[-73.44502,41.0271]]],[[[-73.44698,41.02528],[-73.44696,41.02595],[-73.44733,41.02613],[-73.44771,41.02598],[-73.44803,41.02525],[-73.44771,41.02513],[-73.44698,41.02528]]],[[[-73.46118,41.02275],[-73.4615,41.02295],[-73.462,41.02258],[-73.46199,41.02238],[-73.46182,41.02218],[-73.46138,41.02223],[-73.46118,41.02275]]],[[[-73.45458,41.02326],[-73.45483,41.02344],[-73.45502,41.02319],[-73.4547,41.02319],[-73.45458,41.02326]]],[[[-73.45771,41.02464],[-73.45793,41.02464],[-73.45809,41.02447],[-73.45773,41.02447],[-73.45771,41.02464]]],[[[-73.44321,41.02507],[-73.44362,41.02487],[-73.44313,41.02492],[-73.44321,41.02507]]]]}' FROM towns WHERE name = 'Darien';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.42286,41.38427],[-72.42823,41.38491],[-72.42898,41.38483],[-72.43052,41.38531],[-72.43103,41.38519],[-72.43162,41.38466],[-72.43242,41.38454],[-72.43364,41.38394],[-72.43424,41.38289],[-72.43397,41.38179],[-72.43416,41.38112],[-72.43656,41.37917],[-72.43914,41.37608],[-72.4566,41.36974],[-72.45112,41.36534],[-72.49968,41.36124],[-72.51244,41.35953],[-72.51563,41.35969],[-72.52016,41.3593],[-72.52016,41.35927],[-72.51358,41.34073],[-72.50906,41.32803],[-72.50872,41.32806],[-72.49366,41.32988],[-72.47112,41.31619],[-72.46788,41.31588],[-72.46779,41.31588],[-72.46626,41.321],[-72.46441,41.32727],[-72.44168,41.33845],[-72.42048,41.34718],[-72.40915,41.35678],[-72.39479,41.3603],[-72.39015,41.36122],[-72.37946,41.36545],[-72.37975,41.36608],[-72.38066,41.36735],[-72.38143,41.36788],[-72.38306,41.36865],[-72.3862,41.36963],[-72.3979,41.37155],[-72.40289,41.37166],[-72.40814,41.37254],[-72.41339,41.37242],[-72.41732,41.37315],[-72.41883,41.37383],[-72.41992,41.37468],[-72.42149,41.37668],[-72.42239,41.37833],[-72.42299,41.38053],[-72.4229,41.38095],[-72.42176,41.3826],[-72.42163,41.3838],[-72.42174,41.38415],[-72.42286,41.38427]]]]}' FROM towns WHERE name = 'Deep River';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.03719,41.30858],[-73.04573,41.30734],[-73.05086,41.30593],[-73.05954,41.30751],[-73.0605,41.30779],[-73.06104,41.30824],[-73.06158,41.30839],[-73.06267,41.30827],[-73.06393,41.30867],[-73.06713,41.30917],[-73.06971,41.3102],[-73.07211,41.31233],[-73.07214,41.31268],[-73.07405,41.31303],[-73.07388,41.31326],[-73.07913,41.31556],[-73.08013,41.31629],[-73.08306,41.31779],[-73.08482,41.32059],[-73.08614,41.32147],[-73.08772,41.32217],[-73.08844,41.32214],[-73.08938,41.3218],[-73.09075,41.32192],[-73.09113,41.32265],[-73.09109,41.32357],[-73.09151,41.324],[-73.0916,41.32442],[-73.09765,41.33575],[-73.09791,41.33622],[-73.10017,41.33542],[-73.1147,41.33045],[-73.11838,41.32893],[-73.11974,41.32829],[-73.11966,41.32811],[-73.11863,41.32669],[-73.11795,41.32449],[-73.11644,41.32206],[-73.11054,41.31496],[-73.10962,41.31433],[-73.09743,41.30815],[-73.09254,41.30544],[-73.09077,41.30484],[-73.08822,41.30351],[-73.08397,41.30026],[-73.08202,41.29781],[-73.07995,41.29603],[-73.07412,41.29447],[-73.07347,41.29397],[-73.07266,41.29285],[-73.07188,41.29102],[-73.07144,41.28943],[-73.07063,41.28848],[-73.06849,41.28675],[-73.06262,41.28314],[-73.06166,41.28262],[-73.06131,41.28309],[-73.06039,41.28419],[-73.05987,41.28616],[-73.0588,41.28708],[-73.05899,41.28758],[-73.05879,41.28813],[-73.05763,41.2892],[-73.05724,41.29],[-73.05576,41.2919],[-73.05319,41.29347],[-73.05097,41.29401],[-73.04987,41.29391],[-73.04793,41.29331],[-73.0478,41.29366],[-73.04606,41.29333],[-73.04299,41.29325],[-73.03623,41.29552],[-73.03532,41.29579],[-73.03523,41.29606],[-73.03295,41.30435],[-73.03339,41.3086],[-73.03353,41.3091],[-73.03719,41.30858]]]]}' FROM towns WHERE name = 'Derby';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.60753,41.42819],[-72.63619,41.44904],[-72.62758,41.4715],[-72.62962,41.47493],[-72.62966,41.47865],[-72.63021,41.47982],[-72.63191,41.48127],[-72.63288,41.48142],[-72.63393,41.48465],[-72.63384,41.48642],[-72.64328,41.48529],[-72.6776,41.48124],[-72.67787,41.48287],[-72.67787,41.48289],[-72.67818,41.48247],[-72.67915,41.48104],[-72.69633,41.47922],[-72.71649,41.47546],[-72.73347,41.47181],[-72.73354,41.47179],[-72.73363,41.47076],[-72.73646,41.44025],[-72.73744,41.42279],[-72.73853,41.42114],[-72.74282,41.41086],[-72.74395,41.40816],[-72.74385,41.40819],[-72.7378,41.40898],[-72.73639,41.4089],[-72.7336,41.40927],[-72.72876,41.41001],[-72.72089,41.4117],[-72.71835,41.412],[-72.71832,41.412],[-72.71254,41.41286],[-72.7064,41.41395],[-72.70646,41.41425],[-72.70189,41.41522],[-72.68089,41.41843],[-72.67688,41.41845],[-72.67681,41.41848],[-72.67635,41.41858],[-72.66641,41.42018],[-72.653,41.42301],[-72.65228,41.42318],[-72.64728,41.42353],[-72.63001,41.42462],[-72.60799,41.42721],[-72.60641,41.42739],[-72.60753,41.42819]]]]}' FROM towns WHERE name = 'Durham';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.71151,41.94757],[-72.73029,41.94895],[-72.72244,41.97316],[-72.76461,41.98284],[-72.77792,41.98571],[-72.77814,41.98578],[-72.77917,41.98219],[-72.79032,41.94392],[-72.79495,41.92896],[-72.79887,41.91628],[-72.79685,41.91625],[-72.78755,41.91564],[-72.78686,41.91536],[-72.7813,41.91563],[-72.78082,41.91508],[-72.78102,41.91363],[-72.78079,41.91318],[-72.77988,41.91288],[-72.77834,41.91335],[-72.77787,41.9131],[-72.77771,41.91263],[-72.77791,41.91193],[-72.77783,41.91098],[-72.77667,41.91018],[-72.77398,41.9091],[-72.77325,41.90802],[-72.77312,41.9069],[-72.77187,41.90595],[-72.77143,41.90515],[-72.77154,41.90478],[-72.77168,41.90455],[-72.77199,41.90415],[-72.77328,41.9028],[-72.77321,41.90128],[-72.77293,41.90081],[-72.77087,41.8993],[-72.76932,41.89868],[-72.76714,41.89728],[-72.76354,41.89557],[-72.76108,41.89402],[-72.76047,41.89374],[-72.75568,41.89284],[-72.75073,41.88986],[-72.75006,41.88948],[-72.7475,41.8974],[-72.73779,41.9267],[-72.72495,41.92494],[-72.70996,41.92047],[-72.70819,41.92611],[-72.70486,41.93664],[-72.70164,41.94685],[-72.71151,41.94757]]]]}' FROM towns WHERE name = 'East Granby';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.4333,41.51129],[-72.45149,41.50935],[-72.45487,41.51585],[-72.45992,41.52454],[-72.46046,41.52692],[-72.46087,41.52687],[-72.46075,41.52634],[-72.4612,41.52597],[-72.46284,41.52303],[-72.46419,41.52188],[-72.46475,41.52088],[-72.46832,41.51944],[-72.46954,41.51854],[-72.47106,41.51672],[-72.47251,41.51547],[-72.47696,41.51088],[-72.4809,41.50832],[-72.48115,41.50799],[-72.48122,41.5061],[-72.48124,41.50605],[-72.48126,41.506],[-72.48152,41.50565],[-72.48343,41.50517],[-72.48395,41.50465],[-72.48385,41.50308],[-72.4834,41.50265],[-72.48365,41.50198],[-72.48367,41.50115],[-72.48452,41.49851],[-72.48452,41.49638],[-72.4848,41.49429],[-72.4856,41.49187],[-72.48774,41.48937],[-72.48808,41.4884],[-72.48833,41.48463],[-72.48771,41.48208],[-72.4865,41.47975],[-72.48609,41.47748],[-72.48579,41.47701],[-72.4835,41.47535],[-72.48086,41.47552],[-72.47898,41.47535],[-72.47778,41.47499],[-72.47728,41.47459],[-72.47683,41.47332],[-72.47677,41.46743],[-72.47704,41.4641],[-72.47762,41.46158],[-72.4775,41.46106],[-72.47205,41.45833],[-72.47118,41.45768],[-72.46997,41.4561],[-72.46844,41.4532],[-72.4679,41.4498],[-72.46647,41.44571],[-72.46576,41.44236],[-72.4647,41.43938],[-72.46395,41.43793],[-72.46261,41.43606],[-72.46076,41.43291],[-72.45897,41.42904],[-72.45763,41.42679],[-72.45695,41.42586],[-72.44947,41.41816],[-72.44935,41.41803],[-72.44832,41.41766],[-72.44424,41.4163],[-72.43718,41.41347],[-72.4341,41.41112],[-72.43153,41.40826],[-72.43143,41.40806],[-72.42938,41.40779],[-72.42545,41.40733],[-72.4084,41.40935],[-72.4051,41.40897],[-72.40138,41.40894],[-72.39783,41.41026],[-72.33158,41.41723],[-72.30457,41.42038],[-72.30404,41.42046],[-72.30404,41.42056],[-72.31198,41.46305],[-72.31217,41.46365],[-72.31488,41.46336],[-72.32087,41.49107],[-72.32376,41.50458],[-72.32376,41.50461],[-72.32469,41.52112],[-72.38426,41.51586],[-72.43179,41.51141],[-72.43194,41.51142],[-72.4333,41.51129]]]]}' FROM towns WHERE name = 'East Haddam';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.47271,41.57716],[-72.48488,41.59686],[-72.51053,41.63751],[-72.51055,41.63754],[-72.54911,41.63293],[-72.54918,41.6329],[-72.54272,41.62255],[-72.53483,41.60988],[-72.53481,41.60935],[-72.53427,41.609],[-72.53302,41.60695],[-72.54858,41.6055],[-72.54414,41.5946],[-72.54611,41.58494],[-72.55164,41.57948],[-72.55308,41.57669],[-72.55657,41.57127],[-72.56116,41.56803],[-72.56145,41.56389],[-72.56146,41.55867],[-72.56068,41.55707],[-72.56104,41.55667],[-72.56127,41.55572],[-72.56123,41.55502],[-72.5618,41.55447],[-72.56153,41.55282],[-72.5613,41.55265],[-72.56154,41.55242],[-72.56143,41.55207],[-72.56163,41.55152],[-72.5615,41.55017],[-72.56349,41.5477],[-72.56399,41.54753],[-72.56415,41.54696],[-72.56493,41.54648],[-72.5648,41.54608],[-72.5655,41.54524],[-72.56554,41.54479],[-72.56604,41.54291],[-72.56595,41.54291],[-72.562,41.54186],[-72.55919,41.54048],[-72.55579,41.53738],[-72.55496,41.53638],[-72.55352,41.53383],[-72.55272,41.5315],[-72.55247,41.52813],[-72.55198,41.52651],[-72.55202,41.52521],[-72.55305,41.52081],[-72.55357,41.51942],[-72.55541,41.51585],[-72.55663,41.51195],[-72.55667,41.50598],[-72.55636,41.50231],[-72.55599,41.50039],[-72.55524,41.49829],[-72.55505,41.49769],[-72.55392,41.49779],[-72.49642,41.50405],[-72.48124,41.50605],[-72.48122,41.5061],[-72.48115,41.50799],[-72.4809,41.50832],[-72.47696,41.51088],[-72.47251,41.51547],[-72.47106,41.51672],[-72.46954,41.51854],[-72.46832,41.51944],[-72.46475,41.52088],[-72.46419,41.52188],[-72.46284,41.52303],[-72.4612,41.52597],[-72.46075,41.52634],[-72.46087,41.52687],[-72.46046,41.52692],[-72.45992,41.52454],[-72.45487,41.51585],[-72.45149,41.50935],[-72.4333,41.51129],[-72.43194,41.51142],[-72.43284,41.51316],[-72.46918,41.57141],[-72.46952,41.57199],[-72.47271,41.57716]]]]}' FROM towns WHERE name = 'East Hampton';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.58093,41.7371],[-72.59261,41.79299],[-72.59311,41.79531],[-72.59355,41.79529],[-72.60474,41.79465],[-72.65344,41.7926],[-72.6556,41.79253],[-72.65549,41.79133],[-72.6551,41.78911],[-72.65519,41.78451],[-72.6555,41.78287],[-72.65709,41.7776],[-72.65766,41.77638],[-72.65846,41.77528],[-72.65987,41.77391],[-72.66095,41.77321],[-72.66748,41.76997],[-72.67141,41.76701],[-72.67274,41.76536],[-72.67347,41.76374],[-72.67375,41.76246],[-72.67382,41.76084],[-72.67357,41.75887],[-72.67274,41.75637],[-72.67126,41.75404],[-72.66922,41.75187],[-72.66563,41.74892],[-72.66328,41.74666],[-72.66175,41.74586],[-72.65812,41.74463],[-72.65589,41.74333],[-72.65422,41.74185],[-72.6529,41.74003],[-72.65096,41.73551],[-72.65014,41.73253],[-72.64978,41.72946],[-72.65001,41.72741],[-72.65094,41.72519],[-72.65194,41.72367],[-72.65418,41.72125],[-72.65513,41.72043],[-72.65152,41.71947],[-72.64358,41.71734],[-72.64346,41.71726],[-72.64213,41.71746],[-72.61105,41.72193],[-72.57874,41.72643],[-72.58093,41.7371]]]]}' FROM towns WHERE name = 'East Hartford';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.81856,41.34706],[-72.81846,41.34461],[-72.82356,41.34452],[-72.82338,41.33301],[-72.82303,41.32534],[-72.83842,41.32212],[-72.85583,41.31845],[-72.86091,41.30539],[-72.86691,41.29011],[-72.88084,41.26656],[-72.87951,41.23943],[-72.87942,41.23716],[-72.87955,41.23671],[-72.87935,41.23639],[-72.87959,41.23556],[-72.87923,41.23401],[-72.87965,41.23361],[-72.87954,41.23321],[-72.88162,41.23112],[-72.88384,41.23132],[-72.8849,41.2303],[-72.88513,41.22975],[-72.88601,41.23003],[-72.88715,41.22995],[-72.88749,41.2293],[-72.88936,41.22843],[-72.89105,41.22881],[-72.89178,41.22856],[-72.89208,41.22804],[-72.89169,41.22734],[-72.89269,41.22661],[-72.89239,41.22651],[-72.89014,41.22584],[-72.88871,41.22511],[-72.8888,41.22491],[-72.88862,41.22471],[-72.88753,41.22396],[-72.88727,41.22358],[-72.88742,41.22346],[-72.88656,41.22231],[-72.88657,41.22206],[-72.88589,41.22211],[-72.88562,41.22193],[-72.88536,41.22226],[-72.88572,41.22218],[-72.88581,41.22261],[-72.88555,41.22283],[-72.88521,41.22298],[-72.88496,41.22263],[-72.88485,41.22313],[-72.88434,41.22316],[-72.88339,41.22375],[-72.88309,41.2243],[-72.88249,41.22453],[-72.87967,41.22445],[-72.87744,41.22402],[-72.87403,41.22296],[-72.87337,41.22261],[-72.87326,41.22229],[-72.87266,41.22346],[-72.87225,41.22346],[-72.87205,41.22326],[-72.87114,41.22368],[-72.86944,41.22386],[-72.86944,41.22368],[-72.86932,41.22396],[-72.86908,41.22386],[-72.86904,41.22413],[-72.8685,41.22426],[-72.86856,41.22438],[-72.86711,41.2247],[-72.86531,41.22473],[-72.86392,41.22445],[-72.86291,41.22497],[-72.86221,41.22497],[-72.86195,41.22522],[-72.86085,41.22509],[-72.86083,41.22529],[-72.86044,41.22539],[-72.85942,41.22532],[-72.85937,41.22512],[-72.85906,41.22531],[-72.8572,41.22516],[-72.85343,41.22568],[-72.85312,41.22606],[-72.85318,41.22631],[-72.85267,41.22648],[-72.8531,41.22673],[-72.85291,41.22703],[-72.85192,41.2273],[-72.85261,41.2275],[-72.85272,41.22788],[-72.85263,41.22818],[-72.85194,41.22873],[-72.85204,41.22978],[-72.85111,41.23017],[-72.85098,41.23082],[-72.85029,41.2313],[-72.85039,41.2315],[-72.85087,41.23122],[-72.85059,41.23145],[-72.85044,41.23207],[-72.85001,41.23244],[-72.85,41.23302],[-72.84947,41.23387],[-72.85074,41.23414],[-72.8508,41.23429],[-72.85012,41.23432],[-72.84889,41.23482],[-72.84874,41.23539],[-72.84886,41.23614],[-72.84858,41.23649],[-72.84818,41.23631],[-72.84832,41.23521],[-72.84819,41.23511],[-72.84762,41.23551],[-72.84744,41.23601],[-72.84689,41.23606],[-72.84702,41.23634],[-72.84666,41.23756],[-72.84509,41.23936],[-72.84489,41.23923],[-72.84464,41.24218],[-72.8456,41.24335],[-72.84572,41.244],[-72.84605,41.24448],[-72.84865,41.24466],[-72.85088,41.24608],[-72.85181,41.24616],[-72.85323,41.24584],[-72.85346,41.24679],[-72.85435,41.24744],[-72.85448,41.24774],[-72.85339,41.24791],[-72.85325,41.24808],[-72.85341,41.24843],[-72.85427,41.24886],[-72.85527,41.24881],[-72.8554,41.24919],[-72.85525,41.24996],[-72.85558,41.25029],[-72.85628,41.25019],[-72.85638,41.24929],[-72.85657,41.24914],[-72.85688,41.24924],[-72.85714,41.24966],[-72.85574,41.25171],[-72.85561,41.25221],[-72.85572,41.25418],[-72.85498,41.25618],[-72.85452,41.25618],[-72.854,41.25693],[-72.85403,41.25768],[-72.85274,41.25945],[-72.85236,41.26052],[-72.85235,41.26167],[-72.85352,41.26272],[-72.85377,41.26354],[-72.85387,41.26462],[-72.85351,41.26639],[-72.85243,41.26836],[-72.85149,41.26946],[-72.84951,41.27153],[-72.84794,41.2728],[-72.84442,41.27452],[-72.84129,41.27556],[-72.83983,41.27631],[-72.83826,41.27748],[-72.83638,41.2794],[-72.83499,41.28167],[-72.83388,41.28444],[-72.8333,41.28684],[-72.83278,41.29066],[-72.83215,41.29303],[-72.83063,41.29528],[-72.82932,41.29605],[-72.81972,41.29943],[-72.8184,41.29988],[-72.81829,41.30036],[-72.81275,41.3231],[-72.81334,41.34173],[-72.81351,41.34665],[-72.81856,41.34706]]]]}' FROM towns WHERE name = 'East Haven';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.17116,41.30449],[-72.17148,41.30534],[-72.17349,41.30587],[-72.17433,41.30642],[-72.1756,41.3083],[-72.17529,41.31344],[-72.1745,41.31531],[-72.17445,41.31601],[-72.17483,41.31734],[-72.17571,41.32328],[-72.17627,41.32486],[-72.177,41.32556],[-72.17779,41.32598],[-72.18315,41.32739],[-72.18348,41.32762],[-72.18394,41.32859],[-72.18343,41.33084],[-72.18439,41.33299],[-72.18475,41.33538],[-72.18527,41.33711],[-72.18532,41.33898],[-72.18713,41.34498],[-72.18759,41.34563],[-72.1876,41.34665],[-72.18894,41.34853],[-72.18916,41.34918],[-72.18879,41.35165],[-72.18934,41.3528],[-72.18875,41.35355],[-72.18789,41.35552],[-72.21598,41.39415],[-72.2169,41.39535],[-72.21736,41.3963],[-72.22642,41.40895],[-72.2394,41.42588],[-72.24077,41.42781],[-72.24806,41.42684],[-72.25807,41.42606],[-72.27502,41.42466],[-72.2751,41.42394],[-72.27558,41.41737],[-72.27641,41.41352],[-72.27691,41.40988],[-72.27688,41.40551],[-72.27755,41.40219],[-72.27791,41.39797],[-72.27857,41.39644],[-72.27949,41.38913],[-72.27677,41.37444],[-72.27591,41.37091],[-72.27402,41.3576],[-72.27255,41.34985],[-72.27235,41.349],[-72.27191,41.34713],[-72.2706,41.34151],[-72.26923,41.33714],[-72.26532,41.33079],[-72.26428,41.32791],[-72.26321,41.32606],[-72.25839,41.31796],[-72.25618,41.31481],[-72.25117,41.30661],[-72.25184,41.30614],[-72.25172,41.30479],[-72.25293,41.30462],[-72.25283,41.30344],[-72.25262,41.30319],[-72.25298,41.30307],[-72.25263,41.30272],[-72.25243,41.30167],[-72.25166,41.30119],[-72.25129,41.30019],[-72.25129,41.29927],[-72.25065,41.29835],[-72.25085,41.2977],[-72.25058,41.29685],[-72.24969,41.29547],[-72.24951,41.29425],[-72.24918,41.29367],[-72.24942,41.29198],[-72.24899,41.2915],[-72.24889,41.29078],[-72.2475,41.29037],[-72.24746,41.2898],[-72.24654,41.2886],[-72.24638,41.2879],[-72.24626,41.2879],[-72.2458,41.28727],[-72.24528,41.28695],[-72.24463,41.28572],[-72.24337,41.28235],[-72.24367,41.28188],[-72.24265,41.2805],[-72.24257,41.28013],[-72.24273,41.27985],[-72.24188,41.27978],[-72.24125,41.27815],[-72.2407,41.27763],[-72.24023,41.27763],[-72.24047,41.27822],[-72.2402,41.2782],[-72.24023,41.27842],[-72.23947,41.27872],[-72.23905,41.27867],[-72.23857,41.278],[-72.23896,41.27895],[-72.23859,41.2796],[-72.23694,41.28029],[-72.23501,41.28079],[-72.23192,41.28088],[-72.23144,41.28108],[-72.23141,41.28093],[-72.22976,41.28091],[-72.22972,41.28038],[-72.22963,41.28056],[-72.22931,41.28053],[-72.22902,41.28048],[-72.22896,41.28028],[-72.22799,41.28015],[-72.2276,41.27958],[-72.22716,41.27935],[-72.2269,41.27873],[-72.22647,41.2783],[-72.22628,41.27768],[-72.22584,41.27753],[-72.22596,41.2773],[-72.22559,41.27655],[-72.22548,41.2769],[-72.22522,41.277],[-72.22509,41.27683],[-72.22491,41.27715],[-72.22457,41.27728],[-72.22447,41.27713],[-72.22418,41.27738],[-72.22506,41.27828],[-72.22447,41.2788],[-72.22295,41.27907],[-72.2214,41.27872],[-72.22156,41.279],[-72.22135,41.28002],[-72.22097,41.28034],[-72.22017,41.28057],[-72.22013,41.28089],[-72.21919,41.28116],[-72.21848,41.28181],[-72.21876,41.28216],[-72.21907,41.28199],[-72.21904,41.28169],[-72.21958,41.28117],[-72.22002,41.28102],[-72.22053,41.28197],[-72.22023,41.28254],[-72.22051,41.28301],[-72.21913,41.28319],[-72.2183,41.28291],[-72.21801,41.28319],[-72.2179,41.28418],[-72.21747,41.28448],[-72.21639,41.28416],[-72.2161,41.28423],[-72.21591,41.28508],[-72.21648,41.28468],[-72.21675,41.28466],[-72.21688,41.28498],[-72.21572,41.28595],[-72.21543,41.28598],[-72.2151,41.2872],[-72.21404,41.28852],[-72.21331,41.2891],[-72.21267,41.28895],[-72.21226,41.2874],[-72.21228,41.28632],[-72.21182,41.28497],[-72.2119,41.28443],[-72.21168,41.28438],[-72.21145,41.2839],[-72.21176,41.28308],[-72.21132,41.28283],[-72.21018,41.2813],[-72.21039,41.28088],[-72.21011,41.28058],[-72.21011,41.2799],[-72.20967,41.2789],[-72.20975,41.2784],[-72.21002,41.27833],[-72.20967,41.2782],[-72.20941,41.27753],[-72.20873,41.27678],[-72.2083,41.2747],[-72.20785,41.27418],[-72.20761,41.27345],[-72.20787,41.27328],[-72.21047,41.27408],[-72.21298,41.27426],[-72.21376,41.27521],[-72.21325,41.27581],[-72.2126,41.27616],[-72.21299,41.27621],[-72.21338,41.27614],[-72.21358,41.27564],[-72.21408,41.27526],[-72.21343,41.27464],[-72.21334,41.27419],[-72.21387,41.27379],[-72.21426,41.27394],[-72.21423,41.27361],[-72.2139,41.27324],[-72.21396,41.27354],[-72.2133,41.27369],[-72.21092,41.27343],[-72.20854,41.27283],[-72.20723,41.27228],[-72.20653,41.27165],[-72.20625,41.26851],[-72.20415,41.26583],[-72.20371,41.26568],[-72.20181,41.26578],[-72.19955,41.2656],[-72.19875,41.26587],[-72.19775,41.26662],[-72.19722,41.26739],[-72.1969,41.26879],[-72.19701,41.26952],[-72.19678,41.26974],[-72.19703,41.26999],[-72.19675,41.27042],[-72.19612,41.27051],[-72.19646,41.27047],[-72.19678,41.27069],[-72.19658,41.27131],[-72.19622,41.27131],[-72.19656,41.27141],[-72.19664,41.27164],[-72.19656,41.27204],[-72.19634,41.27211],[-72.19661,41.27216],[-72.19644,41.27294],[-72.19615,41.27299],[-72.19639,41.27306],[-72.1963,41.27406],[-72.19612,41.27454],[-72.19569,41.27464],[-72.19637,41.27461],[-72.19697,41.27541],[-72.19752,41.27674],[-72.19733,41.27691],[-72.19755,41.27689],[-72.19766,41.27711],[-72.19769,41.27751],[-72.19753,41.27759],[-72.19772,41.27759],[-72.19775,41.27781],[-72.19761,41.27796],[-72.1979,41.27799],[-72.19796,41.27824],[-72.19792,41.27856],[-72.19753,41.27868],[-72.19821,41.27859],[-72.19842,41.27896],[-72.19839,41.27951],[-72.19749,41.27958],[-72.19839,41.27958],[-72.1985,41.27998],[-72.19834,41.28048],[-72.19754,41.28046],[-72.19851,41.28056],[-72.19873,41.28213],[-72.19849,41.28223],[-72.19869,41.28221],[-72.19894,41.28258],[-72.19874,41.28313],[-72.19911,41.28323],[-72.19924,41.28368],[-72.19878,41.28451],[-72.19812,41.28443],[-72.19883,41.28456],[-72.19879,41.28486],[-72.19857,41.28493],[-72.1988,41.28513],[-72.19849,41.28535],[-72.19876,41.28553],[-72.19868,41.28603],[-72.19888,41.28625],[-72.19872,41.28675],[-72.19844,41.2869],[-72.19846,41.28843],[-72.19762,41.28955],[-72.19763,41.28997],[-72.1965,41.29115],[-72.19586,41.29102],[-72.19532,41.29139],[-72.1954,41.29167],[-72.1957,41.29114],[-72.19675,41.2913],[-72.19666,41.2918],[-72.19725,41.29195],[-72.19785,41.29252],[-72.19783,41.29322],[-72.19733,41.29454],[-72.19682,41.29532],[-72.19637,41.29557],[-72.19662,41.29582],[-72.19652,41.29602],[-72.19269,41.29878],[-72.1907,41.29963],[-72.19176,41.30098],[-72.19106,41.30168],[-72.19017,41.30208],[-72.1903,41.30225],[-72.1886,41.30317],[-72.18531,41.30447],[-72.18173,41.30518],[-72.17849,41.3051],[-72.17512,41.30448],[-72.1731,41.30347],[-72.17316,41.30322],[-72.17281,41.30365],[-72.17223,41.30355],[-72.17186,41.3034],[-72.17116,41.30449]]],[[[-72.2139,41.28283],[-72.21475,41.28211],[-72.2154,41.28198],[-72.21532,41.28091],[-72.21593,41.28096],[-72.21665,41.28071],[-72.21652,41.28044],[-72.21677,41.27986],[-72.21585,41.27976],[-72.21628,41.27954],[-72.21696,41.27979],[-72.21705,41.27951],[-72.2163,41.27784],[-72.21554,41.27676],[-72.21539,41.27676],[-72.21557,41.27711],[-72.21546,41.27754],[-72.21518,41.27769],[-72.21545,41.27801],[-72.21504,41.27819],[-72.21423,41.27794],[-72.21398,41.27746],[-72.21394,41.27783],[-72.21336,41.27778],[-72.21278,41.27736],[-72.21282,41.27703],[-72.2126,41.27691],[-72.21163,41.27696],[-72.21147,41.27718],[-72.21133,41.27818],[-72.2118,41.27786],[-72.21151,41.27771],[-72.21164,41.27746],[-72.21189,41.27756],[-72.21199,41.27833],[-72.21242,41.27828],[-72.21265,41.27858],[-72.21231,41.27853],[-72.21244,41.27886],[-72.21211,41.27923],[-72.21252,41.27913],[-72.21213,41.27986],[-72.21276,41.27976],[-72.21255,41.28011],[-72.21288,41.28053],[-72.21275,41.28108],[-72.21302,41.28118],[-72.21263,41.28118],[-72.21247,41.28143],[-72.21257,41.2825],[-72.21282,41.28265],[-72.21369,41.28255],[-72.21343,41.28283],[-72.2139,41.28283]]],[[[-72.21929,41.2756],[-72.21945,41.27515],[-72.21983,41.27487],[-72.21962,41.27442],[-72.21993,41.27422],[-72.21992,41.27392],[-72.21957,41.27357],[-72.21924,41.27372],[-72.21901,41.27357],[-72.21858,41.27382],[-72.21797,41.27372],[-72.21774,41.27422],[-72.21795,41.27447],[-72.21775,41.27452],[-72.21793,41.27474],[-72.2178,41.27517],[-72.219,41.27542],[-72.21929,41.2756]]],[[[-72.21804,41.27822],[-72.2179,41.27867],[-72.2184,41.27909],[-72.21837,41.27797],[-72.21873,41.27792],[-72.21877,41.27774],[-72.21843,41.27752],[-72.21834,41.27719],[-72.21816,41.27737],[-72.21791,41.27717],[-72.2179,41.27784],[-72.21804,41.27822]]],[[[-72.21698,41.27309],[-72.21712,41.272],[-72.21693,41.2715],[-72.21682,41.27272],[-72.21673,41.27292],[-72.21656,41.27287],[-72.21679,41.27309],[-72.21698,41.27309]]],[[[-72.21691,41.27087],[-72.2171,41.2713],[-72.2173,41.27087],[-72.21712,41.27057],[-72.21701,41.27065],[-72.21691,41.27087]]],[[[-72.23913,41.2733],[-72.23852,41.273],[-72.23885,41.27353],[-72.23913,41.2733]]],[[[-72.17345,41.29653],[-72.17338,41.29598],[-72.17304,41.29586],[-72.17345,41.29653]]],[[[-72.22209,41.27163],[-72.22249,41.27183],[-72.22188,41.27115],[-72.22209,41.27163]]],[[[-72.23356,41.26977],[-72.23339,41.26975],[-72.23362,41.27012],[-72.23356,41.26977]]]]}' FROM towns WHERE name = 'East Lyme';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.52878,41.87151],[-72.52764,41.94112],[-72.52762,41.94367],[-72.52903,41.94354],[-72.53871,41.94281],[-72.57501,41.93844],[-72.62567,41.93365],[-72.63721,41.93254],[-72.6373,41.93232],[-72.63776,41.92902],[-72.63766,41.92663],[-72.63731,41.92455],[-72.63602,41.91976],[-72.63572,41.91461],[-72.63601,41.91046],[-72.63569,41.90817],[-72.63456,41.90292],[-72.63437,41.90207],[-72.63426,41.90165],[-72.6336,41.89752],[-72.63374,41.89158],[-72.63209,41.88121],[-72.63068,41.87631],[-72.6302,41.87244],[-72.63016,41.86959],[-72.63063,41.8651],[-72.63068,41.86498],[-72.62839,41.86487],[-72.61736,41.86436],[-72.5354,41.86613],[-72.52909,41.86629],[-72.52878,41.87151]]]]}' FROM towns WHERE name = 'East Windsor';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.06526,41.9118],[-72.0812,41.91155],[-72.10297,41.91198],[-72.11026,41.91194],[-72.1131,41.95705],[-72.11376,41.95705],[-72.1165,41.95708],[-72.16554,41.95735],[-72.16576,41.95735],[-72.16502,41.95071],[-72.165,41.94996],[-72.16468,41.94911],[-72.16486,41.94841],[-72.1648,41.94739],[-72.1645,41.94706],[-72.16449,41.94656],[-72.16377,41.94536],[-72.16363,41.94466],[-72.16381,41.94344],[-72.16344,41.94267],[-72.16334,41.94177],[-72.16302,41.94144],[-72.16246,41.94147],[-72.16069,41.94011],[-72.16078,41.93897],[-72.16103,41.93842],[-72.16045,41.93779],[-72.16035,41.93664],[-72.15899,41.93589],[-72.1589,41.93547],[-72.15915,41.93499],[-72.15915,41.93409],[-72.15892,41.93302],[-72.15852,41.93244],[-72.15833,41.93109],[-72.1579,41.93039],[-72.15801,41.9294],[-72.1586,41.92802],[-72.15833,41.9262],[-72.15707,41.92457],[-72.15657,41.9244],[-72.15644,41.92322],[-72.15519,41.92197],[-72.15445,41.92162],[-72.15417,41.92107],[-72.1534,41.9208],[-72.15206,41.9181],[-72.15192,41.91682],[-72.15233,41.91595],[-72.15281,41.91563],[-72.15294,41.91498],[-72.15225,41.91325],[-72.15064,41.91218],[-72.15001,41.9115],[-72.14791,41.91015],[-72.1474,41.9096],[-72.13468,41.89072],[-72.12395,41.87415],[-72.12071,41.82801],[-72.12064,41.82716],[-72.11181,41.8269],[-72.10288,41.82661],[-72.10285,41.82661],[-72.10254,41.82661],[-72.05814,41.82612],[-72.05541,41.82609],[-72.05543,41.82674],[-72.05611,41.85816],[-72.0565,41.86475],[-72.05678,41.89253],[-72.0576,41.91163],[-72.0576,41.91176],[-72.06526,41.9118]]]]}' FROM towns WHERE name = 'Eastford';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.2681,41.27599],[-73.27021,41.27849],[-73.27079,41.27891],[-73.27182,41.28031],[-73.27165,41.28086],[-73.26978,41.28261],[-73.26967,41.28296],[-73.26996,41.28368],[-73.26839,41.28423],[-73.26775,41.28483],[-73.26693,41.28503],[-73.26596,41.28587],[-73.26504,41.28822],[-73.26536,41.2885],[-73.26463,41.28939],[-73.26495,41.29087],[-73.26535,41.29142],[-73.26474,41.29214],[-73.26505,41.29262],[-73.26498,41.29354],[-73.26521,41.29397],[-73.26565,41.29392],[-73.26564,41.29467],[-73.26592,41.29492],[-73.26581,41.29596],[-73.26645,41.29631],[-73.26653,41.29736],[-73.26687,41.29741],[-73.26705,41.29774],[-73.26697,41.29839],[-73.26729,41.29849],[-73.26684,41.29894],[-73.26724,41.29914],[-73.26669,41.30024],[-73.2669,41.30076],[-73.26804,41.30151],[-73.29005,41.31071],[-73.30066,41.31517],[-73.30113,41.3149],[-73.31931,41.30509],[-73.31901,41.30466],[-73.31434,41.29801],[-73.31366,41.29754],[-73.31318,41.29684],[-73.31287,41.29614],[-73.31308,41.29574],[-73.31246,41.29541],[-73.31221,41.29459],[-73.31011,41.29331],[-73.30957,41.29216],[-73.30965,41.29089],[-73.30905,41.29051],[-73.3083,41.29053],[-73.30669,41.28853],[-73.30398,41.28596],[-73.30232,41.28416],[-73.30123,41.28263],[-73.2985,41.27988],[-73.35836,41.25779],[-73.36151,41.25667],[-73.36123,41.25632],[-73.31725,41.20738],[-73.31738,41.2069],[-73.31813,41.20688],[-73.31834,41.20646],[-73.31709,41.20468],[-73.31596,41.2043],[-73.31497,41.2021],[-73.31498,41.20141],[-73.31666,41.20013],[-73.31664,41.19933],[-73.31713,41.19789],[-73.31717,41.19689],[-73.3169,41.19586],[-73.31767,41.19551],[-73.31902,41.19372],[-73.32027,41.19305],[-73.32031,41.19297],[-73.31357,41.19281],[-73.2929,41.19246],[-73.29087,41.19358],[-73.2859,41.19794],[-73.283,41.19948],[-73.28143,41.20066],[-73.28066,41.20155],[-73.27965,41.20367],[-73.27858,41.20425],[-73.27571,41.20524],[-73.27541,41.20577],[-73.27523,41.20686],[-73.27461,41.20751],[-73.27386,41.20921],[-73.27263,41.21013],[-73.27167,41.21138],[-73.27124,41.21175],[-73.27097,41.21175],[-73.27051,41.21098],[-73.26949,41.21008],[-73.26923,41.2095],[-73.26769,41.20758],[-73.26551,41.20545],[-73.2646,41.20415],[-73.26078,41.19997],[-73.25608,41.19432],[-73.25523,41.19355],[-73.25441,41.192],[-73.2536,41.1926],[-73.25346,41.19207],[-73.25245,41.19392],[-73.25127,41.19509],[-73.25138,41.19556],[-73.25121,41.19616],[-73.24968,41.19704],[-73.24773,41.19926],[-73.24768,41.20023],[-73.24689,41.20235],[-73.24636,41.20302],[-73.24519,41.2055],[-73.24375,41.20699],[-73.24344,41.20779],[-73.24347,41.20876],[-73.24282,41.20989],[-73.24296,41.21051],[-73.24176,41.21108],[-73.24128,41.21201],[-73.24014,41.21211],[-73.23983,41.21168],[-73.23847,41.21243],[-73.22759,41.21833],[-73.26822,41.26547],[-73.26841,41.27182],[-73.2687,41.27337],[-73.26864,41.27376],[-73.26789,41.27484],[-73.26792,41.27571],[-73.26807,41.27596],[-73.2681,41.27599]]]]}' FROM towns WHERE name = 'Easton';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.32096,41.93876],[-72.34058,41.93884],[-72.38847,41.93976],[-72.42142,41.93941],[-72.42137,41.95567],[-72.42135,41.95587],[-72.42477,41.9555],[-72.47271,41.95003],[-72.50703,41.94588],[-72.50718,41.94588],[-72.51358,41.94542],[-72.52762,41.94367],[-72.52764,41.94112],[-72.52878,41.87151],[-72.52909,41.86629],[-72.5294,41.86073],[-72.52983,41.85291],[-72.51088,41.85585],[-72.50839,41.85627],[-72.50723,41.86224],[-72.44041,41.87098],[-72.43978,41.87108],[-72.43986,41.87138],[-72.44464,41.91227],[-72.42472,41.91491],[-72.39969,41.91765],[-72.32518,41.92648],[-72.32039,41.92707],[-72.32052,41.92737],[-72.32082,41.92829],[-72.32256,41.92885],[-72.32293,41.92977],[-72.32274,41.93167],[-72.32192,41.93436],[-72.32128,41.93549],[-72.321,41.93689],[-72.32043,41.93818],[-72.32011,41.93876],[-72.32096,41.93876]]]]}' FROM towns WHERE name = 'Ellington';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.53162,42.03511],[-72.54842,42.03526],[-72.58937,42.0309],[-72.59853,42.02532],[-72.60734,42.02551],[-72.62332,42.02581],[-72.6213,42.01846],[-72.6208,42.01344],[-72.62129,42.00695],[-72.62236,42.00458],[-72.62305,42.00153],[-72.62304,41.99806],[-72.62286,41.99694],[-72.6222,41.99531],[-72.61895,41.98981],[-72.61842,41.98826],[-72.61827,41.98646],[-72.61874,41.98434],[-72.61973,41.98185],[-72.62063,41.976],[-72.62361,41.96599],[-72.6242,41.963],[-72.62469,41.96162],[-72.62656,41.9584],[-72.62673,41.95763],[-72.62681,41.95691],[-72.62617,41.94934],[-72.62714,41.94612],[-72.62734,41.94302],[-72.62787,41.94202],[-72.62808,41.94175],[-72.62812,41.94167],[-72.63494,41.93571],[-72.63626,41.93429],[-72.63696,41.93319],[-72.63721,41.93254],[-72.62567,41.93365],[-72.57501,41.93844],[-72.53871,41.94281],[-72.52903,41.94354],[-72.52762,41.94367],[-72.51358,41.94542],[-72.50718,41.94588],[-72.50815,41.95066],[-72.51846,42.0002],[-72.52605,42.03505],[-72.53162,42.03511]]]]}' FROM towns WHERE name = 'Enfield';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.36562,41.32467],[-72.36683,41.32527],[-72.36948,41.32707],[-72.37357,41.32888],[-72.37475,41.33015],[-72.37509,41.3316],[-72.37521,41.3333],[-72.37512,41.3342],[-72.374,41.33817],[-72.3736,41.34194],[-72.37348,41.34694],[-72.37242,41.35125],[-72.37063,41.35387],[-72.37011,41.35592],[-72.37009,41.357],[-72.37035,41.3576],[-72.37155,41.35857],[-72.37266,41.35902],[-72.3749,41.35938],[-72.37582,41.35985],[-72.37725,41.3614],[-72.37936,41.36525],[-72.37946,41.36545],[-72.39015,41.36122],[-72.39479,41.3603],[-72.40915,41.35678],[-72.42048,41.34718],[-72.44168,41.33845],[-72.46441,41.32727],[-72.46626,41.321],[-72.46779,41.31588],[-72.46769,41.31588],[-72.42986,41.3125],[-72.42693,41.30805],[-72.42298,41.3069],[-72.42308,41.30598],[-72.42061,41.3057],[-72.41562,41.30694],[-72.41128,41.30875],[-72.39772,41.31308],[-72.377,41.31502],[-72.36758,41.32048],[-72.36795,41.32128],[-72.3673,41.32155],[-72.36726,41.32185],[-72.36687,41.32192],[-72.36754,41.32223],[-72.3672,41.32295],[-72.36689,41.32335],[-72.36476,41.32429],[-72.36473,41.32429],[-72.36562,41.32467]]]]}' FROM towns WHERE name = 'Essex';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.24014,41.21211],[-73.24128,41.21201],[-73.24176,41.21108],[-73.24296,41.21051],[-73.24282,41.20989],[-73.24347,41.20876],[-73.24344,41.20779],[-73.24375,41.20699],[-73.24519,41.2055],[-73.24636,41.20302],[-73.24689,41.20235],[-73.24768,41.20023],[-73.24773,41.19926],[-73.24968,41.19704],[-73.25121,41.19616],[-73.25138,41.19556],[-73.25127,41.19509],[-73.25245,41.19392],[-73.25346,41.19207],[-73.2536,41.1926],[-73.25441,41.192],[-73.25523,41.19355],[-73.25608,41.19432],[-73.26078,41.19997],[-73.2646,41.20415],[-73.26551,41.20545],[-73.26769,41.20758],[-73.26923,41.2095],[-73.26949,41.21008],[-73.27051,41.21098],[-73.27097,41.21175],[-73.27124,41.21175],[-73.27167,41.21138],[-73.27263,41.21013],[-73.27386,41.20921],[-73.27461,41.20751],[-73.27523,41.20686],[-73.27541,41.20577],[-73.27571,41.20524],[-73.27858,41.20425],[-73.27965,41.20367],[-73.28066,41.20155],[-73.28143,41.20066],[-73.283,41.19948],[-73.2859,41.19794],[-73.29087,41.19358],[-73.2929,41.19246],[-73.31357,41.19281],[-73.32031,41.19297],[-73.32035,41.19272],[-73.32034,41.19155],[-73.3212,41.19105],[-73.32133,41.1906],[-73.32106,41.19025],[-73.3203,41.19015],[-73.31975,41.18967],[-73.32069,41.18845],[-73.32197,41.18753],[-73.32262,41.18561],[-73.32329,41.18453],[-73.32314,41.18356],[-73.32396,41.18249],[-73.32404,41.18209],[-73.32366,41.18054],[-73.3241,41.17917],[-73.32407,41.17819],[-73.32751,41.17682],[-73.32824,41.17617],[-73.32838,41.17498],[-73.32876,41.174],[-73.32897,41.17383],[-73.32885,41.17368],[-73.29711,41.13105],[-73.29658,41.13045],[-73.29616,41.13045],[-73.29525,41.13087],[-73.29432,41.13072],[-73.2939,41.13044],[-73.29367,41.12997],[-73.29406,41.12912],[-73.29407,41.12807],[-73.29495,41.1273],[-73.2946,41.12707],[-73.29314,41.12679],[-73.29296,41.12577],[-73.29233,41.12517],[-73.29212,41.1236],[-73.29167,41.12332],[-73.29073,41.12362],[-73.29012,41.12344],[-73.28917,41.12354],[-73.28809,41.12302],[-73.28766,41.12259],[-73.28693,41.12097],[-73.28765,41.11967],[-73.2878,41.11837],[-73.28748,41.11744],[-73.28662,41.11622],[-73.28641,41.11557],[-73.28542,41.11434],[-73.28546,41.11402],[-73.28586,41.1137],[-73.28592,41.11325],[-73.28475,41.11135],[-73.28593,41.10933],[-73.2858,41.10845],[-73.28508,41.1072],[-73.28572,41.1066],[-73.28533,41.1056],[-73.28601,41.10485],[-73.28565,41.1043],[-73.28548,41.10351],[-73.28739,41.10228],[-73.28719,41.10211],[-73.28714,41.10213],[-73.28608,41.10231],[-73.28558,41.10191],[-73.28588,41.10221],[-73.28574,41.10241],[-73.28405,41.10348],[-73.28085,41.10447],[-73.27999,41.1043],[-73.27983,41.10387],[-73.27936,41.10355],[-73.27907,41.1037],[-73.27866,41.10357],[-73.2779,41.10417],[-73.27752,41.10477],[-73.27709,41.10487],[-73.2771,41.10507],[-73.2769,41.10494],[-73.27698,41.10509],[-73.27584,41.10536],[-73.2757,41.10556],[-73.27514,41.10549],[-73.27493,41.10591],[-73.27518,41.10614],[-73.2749,41.10641],[-73.27361,41.10646],[-73.27352,41.10656],[-73.27379,41.10663],[-73.27337,41.10716],[-73.27286,41.10741],[-73.27305,41.10946],[-73.27283,41.1096],[-73.27249,41.1095],[-73.27038,41.11058],[-73.26891,41.1116],[-73.26856,41.11217],[-73.26738,41.11249],[-73.26688,41.11302],[-73.26542,41.11369],[-73.2645,41.11459],[-73.26435,41.11519],[-73.26381,41.11499],[-73.26315,41.11508],[-73.2631,41.11416],[-73.26427,41.11341],[-73.26484,41.11207],[-73.26491,41.11142],[-73.26534,41.11089],[-73.26579,41.11067],[-73.26652,41.11072],[-73.26952,41.11015],[-73.2709,41.1093],[-73.27281,41.10626],[-73.27361,41.10559],[-73.2745,41.10531],[-73.27456,41.10476],[-73.27513,41.10429],[-73.27561,41.10322],[-73.2751,41.10426],[-73.27451,41.10476],[-73.27141,41.10448],[-73.26946,41.10408],[-73.2685,41.1037],[-73.26773,41.10313],[-73.2676,41.10293],[-73.26785,41.10248],[-73.26668,41.10223],[-73.26523,41.10155],[-73.26428,41.09928],[-73.26318,41.09828],[-73.26207,41.09763],[-73.26128,41.09792],[-73.25948,41.09802],[-73.25789,41.09784],[-73.25691,41.09739],[-73.25679,41.09757],[-73.25609,41.09754],[-73.25393,41.09684],[-73.25448,41.09597],[-73.25331,41.09589],[-73.25081,41.09511],[-73.25056,41.09489],[-73.24964,41.09581],[-73.24926,41.09598],[-73.24887,41.09586],[-73.24901,41.09598],[-73.24767,41.09733],[-73.24671,41.0979],[-73.24411,41.10022],[-73.2403,41.10266],[-73.23928,41.10296],[-73.23901,41.10264],[-73.23916,41.10289],[-73.2382,41.10343],[-73.23416,41.10488],[-73.23312,41.10492],[-73.23283,41.1042],[-73.23304,41.10485],[-73.2329,41.10492],[-73.23115,41.10502],[-73.23072,41.1044],[-73.23105,41.10492],[-73.23101,41.10517],[-73.23073,41.10619],[-73.23044,41.10639],[-73.23056,41.10644],[-73.23044,41.10724],[-73.22997,41.10852],[-73.23017,41.10859],[-73.23011,41.10917],[-73.22892,41.11323],[-73.22721,41.11605],[-73.22419,41.11982],[-73.2228,41.12064],[-73.22186,41.12007],[-73.22258,41.12064],[-73.22244,41.12082],[-73.22398,41.12194],[-73.22433,41.12299],[-73.22387,41.12389],[-73.22258,41.12469],[-73.22165,41.12586],[-73.22167,41.12681],[-73.22244,41.12966],[-73.22322,41.13046],[-73.22376,41.13056],[-73.22494,41.13036],[-73.22538,41.13054],[-73.22662,41.13199],[-73.2266,41.13284],[-73.22559,41.13413],[-73.22204,41.13658],[-73.22032,41.13842],[-73.21967,41.13867],[-73.21754,41.14034],[-73.21702,41.14106],[-73.21701,41.14224],[-73.21591,41.14291],[-73.21523,41.14301],[-73.21495,41.14338],[-73.21492,41.14391],[-73.21413,41.14423],[-73.21432,41.14508],[-73.21418,41.14528],[-73.21385,41.14541],[-73.21258,41.14523],[-73.21259,41.1455],[-73.21315,41.14568],[-73.21328,41.14603],[-73.21201,41.1474],[-73.21298,41.1482],[-73.21313,41.14915],[-73.21351,41.1496],[-73.21397,41.14938],[-73.21348,41.14848],[-73.21429,41.14798],[-73.21502,41.14803],[-73.21427,41.14903],[-73.21489,41.14923],[-73.21552,41.14848],[-73.21567,41.14786],[-73.21723,41.14731],[-73.21754,41.14761],[-73.2168,41.14808],[-73.21734,41.14848],[-73.21723,41.14876],[-73.21675,41.14901],[-73.21707,41.14976],[-73.21552,41.151],[-73.21561,41.15228],[-73.21501,41.15263],[-73.21375,41.15257],[-73.21318,41.15295],[-73.21326,41.1532],[-73.21273,41.1542],[-73.21299,41.15487],[-73.21285,41.15519],[-73.21136,41.15649],[-73.20926,41.15774],[-73.20918,41.15896],[-73.20973,41.16034],[-73.20872,41.16068],[-73.20794,41.16153],[-73.21042,41.16628],[-73.20982,41.17699],[-73.21017,41.17759],[-73.212,41.17935],[-73.2208,41.18975],[-73.22404,41.1932],[-73.23489,41.20578],[-73.23572,41.2067],[-73.23658,41.20763],[-73.23911,41.21088],[-73.23983,41.21168],[-73.24014,41.21211]]]]}' FROM towns WHERE name = 'Fairfield';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.80981,41.75256],[-72.82892,41.75184],[-72.84159,41.75343],[-72.85295,41.75462],[-72.8908,41.75945],[-72.90435,41.75842],[-72.90444,41.75882],[-72.90494,41.75937],[-72.90944,41.7627],[-72.90986,41.76687],[-72.91073,41.76909],[-72.91167,41.76992],[-72.91408,41.77055],[-72.91726,41.7707],[-72.91722,41.77015],[-72.91171,41.74279],[-72.90627,41.72046],[-72.90519,41.71604],[-72.89943,41.69023],[-72.89916,41.68928],[-72.89904,41.68865],[-72.88414,41.6902],[-72.85323,41.68486],[-72.82228,41.67987],[-72.82131,41.67974],[-72.82098,41.68007],[-72.80567,41.69241],[-72.80249,41.69567],[-72.79939,41.69787],[-72.79874,41.69812],[-72.78968,41.70535],[-72.77009,41.70811],[-72.76869,41.70829],[-72.76912,41.71061],[-72.76929,41.71111],[-72.76975,41.7194],[-72.77064,41.73099],[-72.77615,41.73075],[-72.77644,41.73247],[-72.78018,41.72976],[-72.78168,41.72796],[-72.78195,41.72806],[-72.78482,41.72422],[-72.78642,41.72317],[-72.78935,41.74134],[-72.80285,41.74081],[-72.80524,41.75255],[-72.80525,41.75267],[-72.80981,41.75256]]]]}' FROM towns WHERE name = 'Farmington';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.12832,41.65217],[-72.15904,41.65606],[-72.16143,41.65124],[-72.16312,41.64773],[-72.16583,41.64233],[-72.19544,41.58224],[-72.1973,41.57852],[-72.19395,41.57766],[-72.14194,41.56447],[-72.13912,41.56185],[-72.13764,41.55797],[-72.13737,41.5571],[-72.12754,41.55044],[-72.12688,41.55169],[-72.10736,41.58902],[-72.11259,41.60661],[-72.12181,41.6386],[-72.12552,41.65181],[-72.12832,41.65217]]]]}' FROM towns WHERE name = 'Franklin';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.472,41.73828],[-72.48169,41.73722],[-72.57465,41.72692],[-72.57874,41.72643],[-72.61105,41.72193],[-72.64213,41.71746],[-72.64346,41.71726],[-72.64743,41.71669],[-72.65593,41.71548],[-72.65503,41.71383],[-72.64834,41.7072],[-72.64621,41.70595],[-72.64396,41.70547],[-72.6354,41.70479],[-72.63125,41.70481],[-72.62912,41.70443],[-72.62602,41.70315],[-72.62418,41.70187],[-72.62267,41.70002],[-72.6224,41.699],[-72.62249,41.6974],[-72.62302,41.6963],[-72.62366,41.69563],[-72.62537,41.69453],[-72.62775,41.69371],[-72.63677,41.69193],[-72.64149,41.69056],[-72.64281,41.68921],[-72.64336,41.68802],[-72.64355,41.68707],[-72.64341,41.68557],[-72.64277,41.68377],[-72.64164,41.68262],[-72.64082,41.68209],[-72.63807,41.68116],[-72.63459,41.68081],[-72.63175,41.6811],[-72.62778,41.6808],[-72.62524,41.6805],[-72.62299,41.67987],[-72.62215,41.67949],[-72.62178,41.67932],[-72.61975,41.67816],[-72.61739,41.67644],[-72.61597,41.67464],[-72.61508,41.67154],[-72.61479,41.66749],[-72.6153,41.66587],[-72.6158,41.66497],[-72.6172,41.66375],[-72.61855,41.66325],[-72.63004,41.66055],[-72.63247,41.65833],[-72.63317,41.6573],[-72.63358,41.65628],[-72.6333,41.65518],[-72.63335,41.65436],[-72.63295,41.65153],[-72.63139,41.64809],[-72.62878,41.64131],[-72.62834,41.63976],[-72.6282,41.63839],[-72.62835,41.63692],[-72.62881,41.63592],[-72.63013,41.63372],[-72.63221,41.63088],[-72.63239,41.63035],[-72.63243,41.63015],[-72.63245,41.6301],[-72.6339,41.62671],[-72.63442,41.62451],[-72.6346,41.62324],[-72.54928,41.6329],[-72.54918,41.6329],[-72.54911,41.63293],[-72.51055,41.63754],[-72.50975,41.63903],[-72.50225,41.65326],[-72.4814,41.65977],[-72.48249,41.66457],[-72.48774,41.67324],[-72.48263,41.67716],[-72.45835,41.67989],[-72.45922,41.68359],[-72.46617,41.71352],[-72.46757,41.71962],[-72.472,41.73828]]]]}' FROM towns WHERE name = 'Glastonbury';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.26234,41.91459],[-73.26672,41.91477],[-73.27553,41.91513],[-73.27703,41.90926],[-73.27996,41.89758],[-73.28357,41.88427],[-73.29079,41.85276],[-73.30794,41.78131],[-73.30888,41.77704],[-73.30872,41.77671],[-73.30988,41.77402],[-73.30709,41.77404],[-73.30554,41.77424],[-73.23955,41.78263],[-73.23333,41.78334],[-73.21267,41.78506],[-73.21078,41.78523],[-73.21003,41.78788],[-73.19918,41.8288],[-73.19347,41.85157],[-73.18798,41.87251],[-73.18279,41.89216],[-73.18054,41.90085],[-73.17771,41.91176],[-73.26234,41.91459]]]]}' FROM towns WHERE name = 'Goshen';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.79295,42.00231],[-72.83232,41.9979],[-72.83049,42.03804],[-72.87906,42.03888],[-72.88042,42.03894],[-72.89858,41.98477],[-72.90241,41.97306],[-72.90705,41.95875],[-72.90953,41.95069],[-72.91081,41.94707],[-72.9111,41.94697],[-72.91241,41.94218],[-72.91872,41.92301],[-72.91969,41.91996],[-72.90451,41.91936],[-72.89035,41.91892],[-72.88762,41.91884],[-72.86775,41.91826],[-72.82453,41.91659],[-72.80407,41.91631],[-72.79887,41.91628],[-72.79495,41.92896],[-72.79032,41.94392],[-72.77917,41.98219],[-72.77814,41.98578],[-72.7857,41.98747],[-72.79366,41.98925],[-72.79056,42.00263],[-72.79295,42.00231]]]]}' FROM towns WHERE name = 'Granby';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.5405,41.00199],[-73.54083,41.00239],[-73.54083,41.00324],[-73.54957,41.01681],[-73.55446,41.03553],[-73.56317,41.04608],[-73.56444,41.04796],[-73.60031,41.09369],[-73.61881,41.11795],[-73.62004,41.11927],[-73.62096,41.1207],[-73.62259,41.12242],[-73.62309,41.12217],[-73.71518,41.07801],[-73.69232,41.0495],[-73.65839,41.00934],[-73.63993,40.98708],[-73.64039,40.98476],[-73.64456,40.97452],[-73.64385,40.97293],[-73.64371,40.97135],[-73.64395,40.96958],[-73.6435,40.9686],[-73.64324,40.96883],[-73.64326,40.96778],[-73.64306,40.96755],[-73.64253,40.9676],[-73.64256,40.96728],[-73.64196,40.96648],[-73.64112,40.96625],[-73.64131,40.96583],[-73.641,40.9654],[-73.64112,40.96518],[-73.64096,40.9648],[-73.64076,40.96473],[-73.64199,40.96358],[-73.64231,40.96358],[-73.64273,40.96321],[-73.64289,40.96276],[-73.6428,40.96223],[-73.64184,40.96173],[-73.64128,40.96106],[-73.64109,40.96106],[-73.64019,40.96273],[-73.63972,40.96308],[-73.63937,40.9629],[-73.63893,40.96378],[-73.63845,40.96373],[-73.63872,40.9639],[-73.6383,40.96448],[-73.63806,40.96442],[-73.63835,40.9646],[-73.63744,40.96582],[-73.6375,40.9661],[-73.63777,40.96625],[-73.63781,40.96667],[-73.63693,40.9673],[-73.63641,40.96804],[-73.63589,40.96827],[-73.63616,40.96837],[-73.63556,40.96952],[-73.63552,40.96987],[-73.63579,40.97004],[-73.63575,40.97024],[-73.63539,40.97027],[-73.63535,40.97051],[-73.63513,40.97049],[-73.63489,40.97084],[-73.63443,40.97081],[-73.63425,40.97114],[-73.6345,40.97139],[-73.63443,40.97154],[-73.63373,40.97169],[-73.63361,40.97186],[-73.63385,40.97186],[-73.63391,40.97204],[-73.63335,40.97283],[-73.63184,40.97368],[-73.63157,40.97443],[-73.6303,40.97495],[-73.63038,40.97525],[-73.631,40.97558],[-73.63077,40.97595],[-73.63109,40.97615],[-73.631,40.9763],[-73.63051,40.97625],[-73.62986,40.97653],[-73.62942,40.97625],[-73.62923,40.97568],[-73.62826,40.9757],[-73.62839,40.97602],[-73.62925,40.97635],[-73.62862,40.97647],[-73.62839,40.97687],[-73.62849,40.9771],[-73.62928,40.97717],[-73.62948,40.9768],[-73.62923,40.97652],[-73.62947,40.9765],[-73.62999,40.9768],[-73.63,40.97698],[-73.6305,40.97685],[-73.63081,40.97723],[-73.63032,40.97802],[-73.63045,40.97822],[-73.63,40.97852],[-73.62958,40.9785],[-73.62895,40.9793],[-73.62915,40.97957],[-73.62882,40.97995],[-73.62851,40.9799],[-73.62857,40.98037],[-73.62962,40.9806],[-73.62899,40.9806],[-73.62868,40.98082],[-73.62886,40.98114],[-73.62804,40.98227],[-73.62803,40.98262],[-73.62833,40.98299],[-73.62757,40.98284],[-73.62637,40.98471],[-73.62614,40.98444],[-73.62619,40.98379],[-73.62593,40.98299],[-73.62641,40.98294],[-73.62659,40.98244],[-73.62621,40.98204],[-73.62583,40.98229],[-73.62561,40.98209],[-73.625,40.98214],[-73.62502,40.98119],[-73.62441,40.98014],[-73.62336,40.97946],[-73.6238,40.97929],[-73.62404,40.97946],[-73.62507,40.97952],[-73.62492,40.97879],[-73.62421,40.97849],[-73.62415,40.97814],[-73.62398,40.97809],[-73.62366,40.97884],[-73.6233,40.97889],[-73.62232,40.97776],[-73.62259,40.97726],[-73.62168,40.97696],[-73.62077,40.97721],[-73.62025,40.97626],[-73.61982,40.97624],[-73.61951,40.97651],[-73.61964,40.97686],[-73.61928,40.97708],[-73.61873,40.97718],[-73.61777,40.97683],[-73.61848,40.97708],[-73.61818,40.97751],[-73.61776,40.97731],[-73.61787,40.97703],[-73.61757,40.97741],[-73.6182,40.97756],[-73.61848,40.97786],[-73.61735,40.97898],[-73.61589,40.97905],[-73.61552,40.9788],[-73.61402,40.97663],[-73.61303,40.97595],[-73.61232,40.9758],[-73.6116,40.97625],[-73.61107,40.97627],[-73.61055,40.97757],[-73.60915,40.97957],[-73.60904,40.98099],[-73.60824,40.98161],[-73.60889,40.98141],[-73.60942,40.98209],[-73.60906,40.98241],[-73.60949,40.98221],[-73.61008,40.98301],[-73.60949,40.98366],[-73.60912,40.98341],[-73.60888,40.98349],[-73.60887,40.98394],[-73.60867,40.98404],[-73.60889,40.98471],[-73.60938,40.98491],[-73.60976,40.98541],[-73.61018,40.98484],[-73.61077,40.98496],[-73.61118,40.98574],[-73.61084,40.98584],[-73.61081,40.98631],[-73.61144,40.98639],[-73.61157,40.98661],[-73.61143,40.98666],[-73.61198,40.98734],[-73.61373,40.98821],[-73.61483,40.98917],[-73.61484,40.98939],[-73.61368,40.98969],[-73.61338,40.98944],[-73.6134,40.98921],[-73.61316,40.98929],[-73.61197,40.98856],[-73.6097,40.98828],[-73.61019,40.98991],[-73.61312,40.99214],[-73.61352,40.99393],[-73.61121,40.99383],[-73.61156,40.99361],[-73.61148,40.99238],[-73.6096,40.992],[-73.60945,40.9921],[-73.60962,40.9935],[-73.60889,40.99358],[-73.60829,40.98978],[-73.60805,40.9898],[-73.60802,40.98813],[-73.6078,40.98808],[-73.60766,40.98736],[-73.60751,40.98736],[-73.60753,40.98711],[-73.60777,40.98698],[-73.60778,40.98656],[-73.60751,40.98573],[-73.60707,40.98581],[-73.60704,40.98558],[-73.60644,40.98661],[-73.60633,40.98763],[-73.60618,40.98763],[-73.60647,40.98918],[-73.60634,40.98973],[-73.60657,40.99],[-73.60644,40.99048],[-73.60674,40.99088],[-73.60647,40.9909],[-73.6065,40.9916],[-73.60567,40.99155],[-73.60553,40.99107],[-73.60576,40.99055],[-73.60554,40.99038],[-73.60554,40.98978],[-73.6052,40.98883],[-73.60449,40.98863],[-73.60403,40.9879],[-73.60391,40.98718],[-73.60409,40.98688],[-73.60414,40.98595],[-73.60363,40.9853],[-73.6038,40.9853],[-73.60379,40.98495],[-73.60333,40.98483],[-73.60277,40.98508],[-73.60283,40.98525],[-73.6024,40.98558],[-73.6025,40.98635],[-73.60201,40.98647],[-73.60182,40.98632],[-73.60136,40.98732],[-73.60206,40.9889],[-73.60226,40.98897],[-73.60135,40.98952],[-73.60119,40.98982],[-73.60155,40.99029],[-73.60126,40.99129],[-73.60134,40.99242],[-73.60093,40.99249],[-73.60065,40.99212],[-73.60044,40.99219],[-73.60112,40.99307],[-73.60093,40.99324],[-73.59947,40.99326],[-73.59876,40.99294],[-73.59858,40.99346],[-73.59838,40.99346],[-73.59938,40.99519],[-73.59895,40.99529],[-73.59919,40.99606],[-73.59888,40.99603],[-73.59859,40.99558],[-73.5982,40.99551],[-73.59829,40.99526],[-73.59749,40.99431],[-73.59761,40.99364],[-73.59724,40.99333],[-73.59717,40.99271],[-73.59755,40.99231],[-73.59756,40.99181],[-73.59736,40.99156],[-73.59665,40.99169],[-73.59638,40.99151],[-73.59652,40.99129],[-73.59809,40.99081],[-73.59859,40.99044],[-73.59702,40.99026],[-73.59707,40.99004],[-73.59835,40.98994],[-73.59868,40.98862],[-73.59898,40.98834],[-73.59894,40.98769],[-73.59795,40.98722],[-73.59799,40.98674],[-73.59989,40.98682],[-73.60038,40.98635],[-73.60047,40.98587],[-73.60088,40.98577],[-73.60053,40.98562],[-73.6003,40.98525],[-73.59991,40.98527],[-73.59884,40.9845],[-73.59892,40.98385],[-73.5986,40.98365],[-73.59826,40.98454],[-73.59688,40.98542],[-73.59693,40.98564],[-73.59658,40.98602],[-73.59554,40.98624],[-73.59397,40.98731],[-73.59372,40.98724],[-73.59377,40.98706],[-73.59355,40.98719],[-73.59267,40.98703],[-73.59249,40.98751],[-73.59227,40.98743],[-73.59194,40.98771],[-73.59235,40.98761],[-73.59229,40.98818],[-73.59259,40.98911],[-73.59202,40.98951],[-73.59108,40.98975],[-73.59143,40.99],[-73.59124,40.99033],[-73.59076,40.9904],[-73.59031,40.9902],[-73.59018,40.99058],[-73.58944,40.9904],[-73.58938,40.99075],[-73.58916,40.9906],[-73.58885,40.9907],[-73.58907,40.99095],[-73.58891,40.99125],[-73.5881,40.99092],[-73.58807,40.98982],[-73.58758,40.98975],[-73.5877,40.9913],[-73.58813,40.99102],[-73.58899,40.99135],[-73.58853,40.9922],[-73.58899,40.99317],[-73.58793,40.99272],[-73.58773,40.99225],[-73.58698,40.9924],[-73.58633,40.99287],[-73.58514,40.99284],[-73.58496,40.99307],[-73.58514,40.99354],[-73.58549,40.99379],[-73.58538,40.99417],[-73.58582,40.99447],[-73.58643,40.99539],[-73.58643,40.99617],[-73.58694,40.99697],[-73.58689,40.99759],[-73.58713,40.99829],[-73.58699,40.99936],[-73.58656,40.99944],[-73.58601,40.99919],[-73.58557,40.99981],[-73.58605,41.00029],[-73.58588,41.00106],[-73.58594,41.00228],[-73.58655,41.00323],[-73.58593,41.00376],[-73.58596,41.00318],[-73.58356,41.00246],[-73.58362,41.00266],[-73.58274,41.00268],[-73.58191,41.00318],[-73.58155,41.0043],[-73.58094,41.00432],[-73.58038,41.0058],[-73.57833,41.00622],[-73.57796,41.00602],[-73.57769,41.00539],[-73.57803,41.00537],[-73.57833,41.00487],[-73.5782,41.0046],[-73.57876,41.00292],[-73.57863,41.0026],[-73.57904,41.0018],[-73.57918,41.00063],[-73.57908,40.99973],[-73.57869,40.99973],[-73.57894,40.99845],[-73.57853,40.9985],[-73.57823,40.99803],[-73.57831,40.9977],[-73.57861,40.99778],[-73.57841,40.9976],[-73.57845,40.99675],[-73.57799,40.99665],[-73.57786,40.9964],[-73.57748,40.99658],[-73.57705,40.99758],[-73.57653,40.99807],[-73.57625,40.99797],[-73.57627,40.99772],[-73.57592,40.99745],[-73.57522,40.99742],[-73.57441,40.99807],[-73.57446,40.99717],[-73.57419,40.99655],[-73.57467,40.99612],[-73.57478,40.9957],[-73.57516,40.99552],[-73.57508,40.99517],[-73.57478,40.995],[-73.57496,40.99447],[-73.57562,40.9937],[-73.57516,40.99315],[-73.5755,40.99243],[-73.57719,40.99266],[-73.57905,40.99221],[-73.5793,40.99246],[-73.57928,40.99196],[-73.57974,40.99168],[-73.58054,40.99184],[-73.58102,40.99219],[-73.58101,40.99199],[-73.5812,40.99199],[-73.58071,40.99164],[-73.58074,40.99129],[-73.58036,40.99049],[-73.57981,40.99024],[-73.58002,40.98989],[-73.58032,40.98994],[-73.58045,40.98954],[-73.57991,40.98936],[-73.57945,40.98946],[-73.57875,40.98886],[-73.57895,40.98834],[-73.57893,40.98764],[-73.57965,40.98726],[-73.57952,40.98684],[-73.57908,40.98686],[-73.57866,40.98729],[-73.578,40.98716],[-73.57759,40.98669],[-73.57768,40.98644],[-73.57824,40.98624],[-73.57813,40.98589],[-73.57783,40.98574],[-73.57757,40.98599],[-73.57699,40.98594],[-73.57732,40.98654],[-73.57674,40.98728],[-73.57625,40.98736],[-73.57583,40.98713],[-73.57478,40.98758],[-73.57414,40.98748],[-73.57303,40.987],[-73.57253,40.98658],[-73.57278,40.98578],[-73.57233,40.98558],[-73.57217,40.98568],[-73.57247,40.98608],[-73.57244,40.98663],[-73.57425,40.98781],[-73.57331,40.98885],[-73.5729,40.989],[-73.57243,40.9889],[-73.5722,40.99002],[-73.572,40.99002],[-73.5717,40.98942],[-73.5714,40.98932],[-73.57101,40.98932],[-73.57092,40.98952],[-73.57122,40.98965],[-73.57146,40.98952],[-73.57181,40.98987],[-73.57189,40.99015],[-73.57168,40.9904],[-73.57134,40.99042],[-73.57096,40.99002],[-73.56942,40.98975],[-73.56932,40.98805],[-73.56872,40.98845],[-73.56791,40.98952],[-73.5674,40.98969],[-73.56731,40.99002],[-73.56754,40.99014],[-73.56715,40.99024],[-73.56742,40.99042],[-73.56712,40.99094],[-73.56725,40.99109],[-73.56667,40.99129],[-73.56691,40.99206],[-73.56629,40.99224],[-73.56536,40.99216],[-73.56547,40.99181],[-73.56497,40.99119],[-73.56478,40.99131],[-73.56493,40.99171],[-73.56508,40.99159],[-73.5653,40.99181],[-73.56529,40.99214],[-73.56495,40.99239],[-73.56514,40.99279],[-73.56493,40.99324],[-73.56269,40.99396],[-73.56238,40.99488],[-73.56255,40.99555],[-73.56282,40.99576],[-73.56303,40.99536],[-73.56337,40.99553],[-73.5637,40.99586],[-73.56366,40.99623],[-73.56289,40.99583],[-73.56285,40.99601],[-73.56174,40.99633],[-73.56125,40.99605],[-73.56139,40.99593],[-73.56096,40.99603],[-73.56084,40.99635],[-73.55979,40.996],[-73.56028,40.99705],[-73.56045,40.99705],[-73.56031,40.9971],[-73.56038,40.99787],[-73.5606,40.99792],[-73.56038,40.99792],[-73.56038,40.99867],[-73.56002,40.99872],[-73.55988,40.99902],[-73.56005,40.99912],[-73.56075,40.99885],[-73.56141,40.99992],[-73.56188,41.00022],[-73.5616,41.00042],[-73.56123,41.00032],[-73.56129,41.0006],[-73.56084,41.001],[-73.56071,41.00157],[-73.56049,41.00155],[-73.56056,41.00132],[-73.55988,41.00145],[-73.5606,41.00195],[-73.56062,41.0027],[-73.55982,41.00197],[-73.55905,41.00202],[-73.55929,41.00294],[-73.5584,41.00317],[-73.55849,41.00387],[-73.55791,41.00389],[-73.55822,41.00369],[-73.55807,41.00359],[-73.5573,41.00384],[-73.55766,41.00369],[-73.55767,41.00329],[-73.55914,41.00114],[-73.55942,41.00087],[-73.5601,41.00072],[-73.56033,41.00042],[-73.55953,40.99967],[-73.55907,41.0004],[-73.55815,41.00039],[-73.55797,41.00024],[-73.55798,41.00047],[-73.55726,41.00084],[-73.55652,41.00062],[-73.5565,41.00047],[-73.55691,41.00039],[-73.5573,40.99974],[-73.55719,40.99937],[-73.55687,40.99912],[-73.55818,40.9983],[-73.5584,40.99845],[-73.55824,40.99877],[-73.55853,40.99857],[-73.55875,40.99882],[-73.55872,40.9985],[-73.55801,40.99755],[-73.55634,40.99697],[-73.55561,40.99692],[-73.55494,40.99724],[-73.5548,40.99814],[-73.5545,40.99807],[-73.55442,40.99759],[-73.55407,40.99732],[-73.55375,40.99736],[-73.55395,40.99677],[-73.55374,40.99604],[-73.55344,40.99579],[-73.55368,40.99507],[-73.55402,40.99509],[-73.55421,40.99489],[-73.55497,40.99509],[-73.55516,40.99502],[-73.55503,40.99457],[-73.55522,40.99454],[-73.55514,40.99434],[-73.55476,40.99444],[-73.55458,40.99419],[-73.55393,40.99269],[-73.5518,40.99322],[-73.55148,40.99207],[-73.55154,40.99164],[-73.55266,40.99187],[-73.55283,40.99167],[-73.55151,40.99149],[-73.55131,40.99134],[-73.55141,40.99072],[-73.55277,40.99072],[-73.55316,40.9905],[-73.55331,40.98995],[-73.55387,40.98995],[-73.55408,40.99117],[-73.55447,40.99147],[-73.55492,40.99085],[-73.55472,40.98927],[-73.55691,40.98835],[-73.55677,40.9879],[-73.55628,40.98778],[-73.55393,40.98865],[-73.55396,40.9874],[-73.55357,40.98735],[-73.55408,40.9863],[-73.55401,40.98585],[-73.55437,40.98493],[-73.55533,40.98346],[-73.55573,40.98311],[-73.55717,40.98263],[-73.55738,40.98146],[-73.55887,40.98011],[-73.55977,40.97837],[-73.56046,40.97809],[-73.56173,40.97822],[-73.56506,40.9798],[-73.56485,40.98092],[-73.56511,40.98135],[-73.56571,40.98127],[-73.56627,40.98195],[-73.56698,40.98212],[-73.56952,40.98003],[-73.56987,40.97893],[-73.56985,40.97726],[-73.57026,40.97713],[-73.56984,40.97706],[-73.56911,40.97553],[-73.56787,40.97533],[-73.56484,40.97565],[-73.5631,40.97615],[-73.56085,40.97622],[-73.5595,40.97664],[-73.5561,40.97606],[-73.55511,40.97641],[-73.55531,40.97716],[-73.55587,40.97816],[-73.5555,40.98098],[-73.55421,40.98423],[-73.55321,40.9851],[-73.5535,40.9857],[-73.55329,40.98603],[-73.5529,40.98595],[-73.55274,40.9863],[-73.5529,40.98677],[-73.55271,40.98767],[-73.5513,40.98762],[-73.55073,40.98819],[-73.55011,40.98949],[-73.54921,40.98867],[-73.54882,40.98862],[-73.54874,40.98917],[-73.54977,40.99017],[-73.54888,40.99061],[-73.54851,40.99036],[-73.54837,40.99059],[-73.5485,40.99081],[-73.54769,40.99146],[-73.5478,40.99189],[-73.54764,40.99204],[-73.5459,40.99176],[-73.54557,40.99186],[-73.54558,40.99153],[-73.5454,40.99203],[-73.54486,40.99196],[-73.54481,40.99176],[-73.54416,40.99198],[-73.54355,40.9918],[-73.54368,40.99153],[-73.54334,40.99148],[-73.5428,40.99208],[-73.54244,40.99205],[-73.54232,40.9924],[-73.54259,40.99248],[-73.54206,40.99338],[-73.5423,40.99393],[-73.54196,40.99415],[-73.54181,40.99472],[-73.54142,40.99475],[-73.54109,40.99567],[-73.5406,40.9956],[-73.54038,40.99575],[-73.5403,40.9963],[-73.53983,40.99687],[-73.53993,40.99699],[-73.53958,40.99722],[-73.53967,40.99787],[-73.54012,40.99814],[-73.53993,40.99837],[-73.54029,40.99832],[-73.54037,40.99867],[-73.54049,40.99852],[-73.54065,40.99879],[-73.54083,40.99864],[-73.54092,40.99889],[-73.5411,40.99867],[-73.54133,40.99894],[-73.54097,40.99989],[-73.54115,41.00029],[-73.54102,41.00059],[-73.54051,41.00062],[-73.54047,41.00104],[-73.54035,41.00107],[-73.5405,41.00199]]],[[[-73.60712,40.95818],[-73.60746,40.95828],[-73.60902,40.95771],[-73.60997,40.95676],[-73.61028,40.95669],[-73.61189,40.95674],[-73.61309,40.95707],[-73.61308,40.95662],[-73.61362,40.956],[-73.61361,40.95502],[-73.61327,40.95502],[-73.61218,40.95612],[-73.61151,40.95647],[-73.61025,40.95639],[-73.60932,40.95539],[-73.60886,40.95536],[-73.60819,40.95571],[-73.60795,40.95606],[-73.60662,40.95621],[-73.6062,40.95673],[-73.60712,40.95818]]],[[[-73.6232,40.96847],[-73.62337,40.9683],[-73.62473,40.9682],[-73.62466,40.96758],[-73.62394,40.9663],[-73.62439,40.96578],[-73.62522,40.966],[-73.62563,40.96655],[-73.62617,40.96606],[-73.62615,40.96541],[-73.62452,40.9636],[-73.62401,40.96373],[-73.6237,40.9641],[-73.62388,40.96433],[-73.6239,40.96503],[-73.62375,40.96575],[-73.62225,40.96652],[-73.6221,40.96722],[-73.62242,40.96752],[-73.62259,40.9682],[-73.62278,40.96825],[-73.6232,40.96847]]],[[[-73.62761,40.971],[-73.6276,40.97175],[-73.62737,40.97188],[-73.62762,40.97225],[-73.62801,40.97225],[-73.62873,40.97185],[-73.62928,40.97098],[-73.62988,40.97051],[-73.62992,40.97033],[-73.62892,40.97011],[-73.62761,40.971]]],[[[-73.5953,40.96369],[-73.59559,40.96371],[-73.59642,40.96279],[-73.59692,40.96264],[-73.59635,40.96221],[-73.59594,40.96224],[-73.59409,40.96291],[-73.59436,40.96318],[-73.59475,40.96321],[-73.5953,40.96369]]],[[[-73.60181,40.98383],[-73.60171,40.98353],[-73.60202,40.98333],[-73.60154,40.98273],[-73.60026,40.983],[-73.60072,40.98387],[-73.6016,40.984],[-73.60181,40.98383]]],[[[-73.58894,40.98913],[-73.58923,40.98975],[-73.58968,40.98918],[-73.59028,40.98908],[-73.59036,40.9885],[-73.58993,40.98795],[-73.58957,40.98813],[-73.58941,40.98848],[-73.5889,40.98855],[-73.58891,40.9888],[-73.58906,40.9888],[-73.58894,40.98913]]],[[[-73.62803,40.9747],[-73.62848,40.97507],[-73.62928,40.97495],[-73.62927,40.97453],[-73.62892,40.9741],[-73.62812,40.97447],[-73.62803,40.9747]]],[[[-73.56224,40.98701],[-73.56218,40.98749],[-73.56194,40.98761],[-73.562,40.98794],[-73.56257,40.98756],[-73.56287,40.98696],[-73.56291,40.98664],[-73.56262,40.98656],[-73.56224,40.98701]]],[[[-73.58526,40.99194],[-73.58434,40.99137],[-73.58403,40.99142],[-73.58425,40.99159],[-73.58414,40.99182],[-73.58526,40.99194]]],[[[-73.62841,40.97837],[-73.62865,40.97832],[-73.62854,40.9779],[-73.6287,40.9775],[-73.6285,40.97742],[-73.62826,40.97747],[-73.6281,40.9778],[-73.62845,40.97795],[-73.62841,40.97837]]],[[[-73.58425,40.98567],[-73.58407,40.98552],[-73.58319,40.9853],[-73.58334,40.98555],[-73.58413,40.98575],[-73.58425,40.98567]]],[[[-73.59209,40.96435],[-73.59221,40.96451],[-73.59292,40.96458],[-73.59228,40.96421],[-73.59209,40.96435]]],[[[-73.61871,40.97014],[-73.6192,40.97034],[-73.61904,40.96994],[-73.6188,40.96989],[-73.61871,40.97014]]],[[[-73.58462,40.98852],[-73.58464,40.98817],[-73.58488,40.98812],[-73.58439,40.98817],[-73.58462,40.98852]]],[[[-73.56944,40.98323],[-73.56968,40.9831],[-73.56956,40.983],[-73.56944,40.98323]]]]}' FROM towns WHERE name = 'Greenwich';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-71.86451,41.50116],[-71.87744,41.5623],[-71.88754,41.61374],[-71.88844,41.61914],[-71.88928,41.63013],[-71.88928,41.6303],[-71.88943,41.6303],[-71.91378,41.62784],[-71.95627,41.62306],[-71.95962,41.62354],[-71.95969,41.62356],[-71.95989,41.62359],[-71.97902,41.62692],[-71.98007,41.62709],[-71.98011,41.62677],[-71.9808,41.6248],[-71.98265,41.62243],[-71.98358,41.61998],[-71.98392,41.61778],[-71.9838,41.61706],[-71.98279,41.61481],[-71.98282,41.61276],[-71.98326,41.61196],[-71.98496,41.61039],[-71.98689,41.6075],[-71.98704,41.6068],[-71.98662,41.60482],[-71.98868,41.59886],[-71.99443,41.5946],[-71.99534,41.59335],[-71.9952,41.59272],[-71.99483,41.59237],[-71.99394,41.59205],[-71.99209,41.59192],[-71.99129,41.59124],[-71.99043,41.59004],[-71.98965,41.58829],[-71.98845,41.58729],[-71.98794,41.58564],[-71.98834,41.58275],[-71.98806,41.58087],[-71.98814,41.5802],[-71.9885,41.57938],[-71.99005,41.57736],[-71.99021,41.57643],[-71.98989,41.57541],[-71.98773,41.57373],[-71.98758,41.57308],[-71.98895,41.57076],[-71.99159,41.56794],[-71.99256,41.56387],[-71.99241,41.56287],[-71.99108,41.56072],[-71.99076,41.55905],[-71.99159,41.55505],[-71.99159,41.55356],[-71.99177,41.55301],[-71.99188,41.55273],[-71.98824,41.55283],[-71.95668,41.55348],[-71.90608,41.50842],[-71.90341,41.50602],[-71.89835,41.50534],[-71.86709,41.50112],[-71.8644,41.50076],[-71.86451,41.50116]]]]}' FROM towns WHERE name = 'Griswold';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-71.96705,41.33198],[-71.96699,41.33311],[-71.96514,41.33553],[-71.96317,41.33959],[-71.96336,41.34109],[-71.96388,41.34292],[-71.96368,41.34599],[-71.9639,41.34999],[-71.96374,41.35131],[-71.96249,41.35498],[-71.96019,41.3585],[-71.95983,41.36027],[-71.95999,41.36157],[-71.96076,41.36357],[-71.96091,41.36519],[-71.96058,41.36579],[-71.95949,41.36646],[-71.95883,41.36906],[-71.95847,41.36921],[-71.9574,41.36901],[-71.95571,41.3704],[-71.9561,41.37113],[-71.95685,41.37175],[-71.95734,41.3719],[-71.95765,41.37248],[-71.95739,41.37353],[-71.95768,41.37438],[-71.95756,41.3751],[-71.95804,41.37563],[-71.95781,41.37615],[-71.95803,41.3771],[-71.95792,41.37755],[-71.95691,41.37857],[-71.9562,41.37997],[-71.95625,41.38019],[-71.97118,41.38092],[-72.09074,41.3834],[-72.09369,41.3834],[-72.09366,41.38318],[-72.09337,41.38015],[-72.09311,41.37945],[-72.08889,41.37263],[-72.08698,41.36988],[-72.08653,41.36923],[-72.08636,41.36858],[-72.08639,41.36131],[-72.0847,41.35444],[-72.08385,41.34495],[-72.08274,41.34505],[-72.08118,41.34517],[-72.08045,41.34502],[-72.08088,41.34487],[-72.08065,41.34447],[-72.08119,41.34382],[-72.08103,41.34347],[-72.08054,41.34322],[-72.08041,41.34284],[-72.08066,41.34157],[-72.08037,41.3406],[-72.08095,41.3399],[-72.08089,41.33945],[-72.08067,41.3394],[-72.08069,41.33917],[-72.08095,41.33915],[-72.08094,41.33877],[-72.08043,41.33865],[-72.08062,41.33785],[-72.07975,41.33647],[-72.0798,41.3362],[-72.08028,41.33615],[-72.08009,41.33535],[-72.07928,41.33535],[-72.07912,41.3341],[-72.0793,41.33343],[-72.07873,41.33235],[-72.07821,41.3321],[-72.07867,41.33178],[-72.07764,41.33073],[-72.07705,41.3305],[-72.07716,41.33023],[-72.07757,41.33025],[-72.07745,41.33015],[-72.0776,41.32855],[-72.07701,41.3284],[-72.07886,41.32846],[-72.07911,41.32718],[-72.07894,41.32716],[-72.07868,41.32818],[-72.07829,41.32811],[-72.07848,41.32721],[-72.07833,41.32721],[-72.07812,41.32813],[-72.07732,41.3281],[-72.07741,41.32718],[-72.07702,41.32703],[-72.07701,41.32683],[-72.07929,41.32523],[-72.07905,41.32523],[-72.07883,41.32503],[-72.07894,41.32491],[-72.0787,41.32493],[-72.07852,41.32461],[-72.07697,41.32561],[-72.07698,41.32518],[-72.07775,41.32468],[-72.07781,41.32446],[-72.07749,41.32418],[-72.07749,41.32346],[-72.07668,41.32308],[-72.07619,41.32071],[-72.07547,41.32001],[-72.07537,41.31913],[-72.07494,41.31863],[-72.07513,41.31846],[-72.0749,41.31818],[-72.07518,41.31764],[-72.07466,41.31669],[-72.07424,41.31654],[-72.07514,41.31551],[-72.07442,41.31256],[-72.07448,41.31154],[-72.074,41.31002],[-72.07369,41.30942],[-72.073,41.30914],[-72.07271,41.30834],[-72.07192,41.30782],[-72.07196,41.30687],[-72.07232,41.30657],[-72.07181,41.30657],[-72.0714,41.30607],[-72.07047,41.30584],[-72.07007,41.30551],[-72.07003,41.30509],[-72.07055,41.30447],[-72.07033,41.30424],[-72.07099,41.30437],[-72.07115,41.30412],[-72.0709,41.30389],[-72.07027,41.30404],[-72.06992,41.30382],[-72.06943,41.30289],[-72.06883,41.30304],[-72.06839,41.30291],[-72.0679,41.30211],[-72.06875,41.30197],[-72.06931,41.30214],[-72.06903,41.30149],[-72.06921,41.30112],[-72.07036,41.30142],[-72.07023,41.30112],[-72.0705,41.30102],[-72.06963,41.30039],[-72.06977,41.30024],[-72.07038,41.30037],[-72.07035,41.30022],[-72.06937,41.29984],[-72.06898,41.29989],[-72.06872,41.30017],[-72.06845,41.29997],[-72.06865,41.30022],[-72.06849,41.30072],[-72.06677,41.30084],[-72.06567,41.30059],[-72.06564,41.30044],[-72.06533,41.30064],[-72.06445,41.30059],[-72.06304,41.30028],[-72.06284,41.30016],[-72.0629,41.29996],[-72.06252,41.30008],[-72.06153,41.29943],[-72.06132,41.29911],[-72.06161,41.29726],[-72.06134,41.29644],[-72.06025,41.29583],[-72.06006,41.29596],[-72.05823,41.29563],[-72.05725,41.2961],[-72.0569,41.29675],[-72.05647,41.29693],[-72.05471,41.2965],[-72.05581,41.2969],[-72.05534,41.2974],[-72.05582,41.29718],[-72.05626,41.29733],[-72.0583,41.29975],[-72.05787,41.29923],[-72.05726,41.2989],[-72.05655,41.29898],[-72.05591,41.29867],[-72.05572,41.29877],[-72.05588,41.29917],[-72.05559,41.2993],[-72.05575,41.29967],[-72.05531,41.29972],[-72.05554,41.3],[-72.05466,41.30065],[-72.05467,41.30087],[-72.05418,41.3008],[-72.05421,41.30107],[-72.05404,41.30092],[-72.05415,41.30147],[-72.05392,41.30117],[-72.05356,41.30134],[-72.05293,41.30124],[-72.05294,41.30157],[-72.05269,41.30157],[-72.05144,41.30099],[-72.05106,41.29972],[-72.05079,41.29952],[-72.05057,41.29897],[-72.05056,41.29939],[-72.05021,41.29972],[-72.05025,41.30021],[-72.05053,41.30066],[-72.05031,41.30066],[-72.05034,41.30081],[-72.05056,41.30081],[-72.05055,41.30124],[-72.05069,41.30124],[-72.05054,41.30186],[-72.05074,41.30206],[-72.05048,41.30241],[-72.05051,41.30256],[-72.0509,41.30261],[-72.05103,41.30294],[-72.05084,41.30296],[-72.05073,41.30331],[-72.05099,41.30401],[-72.05073,41.30409],[-72.051,41.30406],[-72.05117,41.30429],[-72.05084,41.30471],[-72.05113,41.30541],[-72.05071,41.30603],[-72.05072,41.30633],[-72.05115,41.30671],[-72.05261,41.30689],[-72.05304,41.30746],[-72.05361,41.30781],[-72.05318,41.30799],[-72.05198,41.30754],[-72.05071,41.30756],[-72.05175,41.30816],[-72.05174,41.30863],[-72.05141,41.30911],[-72.05245,41.30968],[-72.05286,41.30961],[-72.0528,41.30998],[-72.05239,41.31011],[-72.0512,41.31021],[-72.05117,41.30983],[-72.05089,41.30958],[-72.04952,41.30903],[-72.04866,41.30738],[-72.0482,41.30795],[-72.04717,41.30778],[-72.04648,41.30813],[-72.04678,41.30778],[-72.04817,41.3071],[-72.04854,41.30633],[-72.0492,41.30576],[-72.049,41.30491],[-72.0488,41.30538],[-72.04852,41.30516],[-72.04847,41.30426],[-72.04876,41.30401],[-72.04874,41.30341],[-72.0479,41.30386],[-72.04784,41.30496],[-72.04759,41.30496],[-72.0469,41.30603],[-72.04693,41.30401],[-72.04666,41.30368],[-72.04617,41.30376],[-72.04639,41.3044],[-72.04594,41.30485],[-72.04533,41.30468],[-72.04518,41.30403],[-72.04438,41.30385],[-72.04425,41.30445],[-72.04378,41.3042],[-72.04379,41.3046],[-72.04349,41.30435],[-72.04315,41.30515],[-72.04289,41.30515],[-72.04268,41.3049],[-72.0428,41.30405],[-72.04211,41.30295],[-72.04192,41.30307],[-72.04194,41.30365],[-72.04259,41.30437],[-72.04243,41.30462],[-72.04204,41.30462],[-72.04164,41.3043],[-72.04177,41.3037],[-72.04133,41.3035],[-72.041,41.3039],[-72.04123,41.30442],[-72.04113,41.30495],[-72.04031,41.30532],[-72.04004,41.30584],[-72.04,41.30462],[-72.03978,41.30442],[-72.03944,41.30444],[-72.03957,41.30484],[-72.03918,41.30489],[-72.03914,41.30522],[-72.03837,41.30532],[-72.0372,41.30619],[-72.03707,41.30664],[-72.03772,41.30719],[-72.03746,41.30814],[-72.03785,41.30831],[-72.03756,41.30844],[-72.03743,41.30891],[-72.03771,41.30921],[-72.03772,41.30966],[-72.03644,41.30991],[-72.03624,41.30956],[-72.03604,41.30956],[-72.03625,41.30991],[-72.03584,41.31008],[-72.03539,41.30971],[-72.03372,41.30981],[-72.03351,41.31008],[-72.033,41.31018],[-72.03234,41.31095],[-72.03176,41.31108],[-72.03261,41.31168],[-72.0329,41.31163],[-72.03298,41.3113],[-72.03318,41.3115],[-72.03312,41.31195],[-72.03272,41.31238],[-72.03334,41.31415],[-72.03309,41.31415],[-72.03269,41.31375],[-72.033,41.31427],[-72.03237,41.3142],[-72.03136,41.31385],[-72.03135,41.3136],[-72.0309,41.31395],[-72.03165,41.3148],[-72.03276,41.3151],[-72.03257,41.31542],[-72.03096,41.31592],[-72.03219,41.31575],[-72.03264,41.31597],[-72.03269,41.31625],[-72.03216,41.31712],[-72.03221,41.3178],[-72.03134,41.31959],[-72.03166,41.32049],[-72.03162,41.32092],[-72.03205,41.32139],[-72.03239,41.32239],[-72.03255,41.32199],[-72.03256,41.32239],[-72.0343,41.32282],[-72.03325,41.32249],[-72.03132,41.32239],[-72.0296,41.32169],[-72.02897,41.32081],[-72.02905,41.32031],[-72.0295,41.31989],[-72.02949,41.31887],[-72.02987,41.31854],[-72.02992,41.31792],[-72.03023,41.31764],[-72.03012,41.31699],[-72.02932,41.31649],[-72.03082,41.31602],[-72.02917,41.31637],[-72.0285,41.31597],[-72.02775,41.31514],[-72.0282,41.31382],[-72.02813,41.31314],[-72.02835,41.31242],[-72.02897,41.31197],[-72.0289,41.31122],[-72.0292,41.31075],[-72.02898,41.31002],[-72.02908,41.30912],[-72.02953,41.30882],[-72.03006,41.30793],[-72.03063,41.30753],[-72.02997,41.30723],[-72.03004,41.3063],[-72.0303,41.30613],[-72.03005,41.30585],[-72.03116,41.30581],[-72.03153,41.30501],[-72.03152,41.30471],[-72.03086,41.30393],[-72.03134,41.30341],[-72.03118,41.30293],[-72.03178,41.30268],[-72.03183,41.30366],[-72.03362,41.30349],[-72.034,41.30301],[-72.03516,41.30276],[-72.03617,41.30172],[-72.03645,41.30127],[-72.03652,41.30057],[-72.03568,41.29992],[-72.03552,41.30027],[-72.03563,41.30079],[-72.03475,41.30049],[-72.03385,41.30056],[-72.03239,41.29959],[-72.03208,41.29914],[-72.03154,41.29896],[-72.03086,41.29896],[-72.03079,41.29914],[-72.03178,41.29946],[-72.0319,41.29971],[-72.03149,41.29956],[-72.03125,41.29974],[-72.03081,41.29966],[-72.03036,41.30013],[-72.02998,41.29958],[-72.02958,41.29829],[-72.02997,41.29841],[-72.03045,41.29826],[-72.03048,41.29696],[-72.03087,41.29676],[-72.03169,41.29746],[-72.03528,41.29944],[-72.03949,41.30072],[-72.04103,41.30085],[-72.04393,41.30038],[-72.04538,41.30078],[-72.04524,41.30108],[-72.04566,41.30128],[-72.04548,41.30098],[-72.0462,41.30063],[-72.04647,41.30001],[-72.04109,41.30045],[-72.03747,41.29967],[-72.03398,41.29829],[-72.0315,41.29689],[-72.03112,41.29639],[-72.03133,41.29587],[-72.03113,41.29502],[-72.02987,41.29439],[-72.02954,41.29376],[-72.028,41.29361],[-72.02704,41.29306],[-72.02455,41.29281],[-72.02399,41.29363],[-72.02332,41.29381],[-72.0231,41.29558],[-72.02334,41.29615],[-72.02313,41.29638],[-72.02207,41.29688],[-72.01994,41.29722],[-72.01961,41.29919],[-72.01872,41.29944],[-72.01823,41.29999],[-72.01722,41.30029],[-72.01698,41.30061],[-72.01551,41.30081],[-72.0146,41.30126],[-72.01419,41.30156],[-72.01426,41.30213],[-72.01398,41.30256],[-72.01445,41.30286],[-72.0143,41.30261],[-72.01459,41.30266],[-72.01485,41.30236],[-72.01478,41.30166],[-72.01549,41.30099],[-72.01638,41.30089],[-72.01691,41.30131],[-72.01683,41.30184],[-72.01719,41.30259],[-72.01718,41.30301],[-72.01768,41.30354],[-72.01775,41.30419],[-72.01829,41.30539],[-72.01842,41.30703],[-72.01865,41.30741],[-72.01875,41.30836],[-72.01848,41.30908],[-72.01895,41.31008],[-72.01821,41.31063],[-72.01795,41.31108],[-72.01818,41.31203],[-72.01813,41.31298],[-72.0186,41.31303],[-72.02001,41.31386],[-72.0197,41.3149],[-72.02037,41.31553],[-72.02041,41.31598],[-72.02024,41.31605],[-72.02004,41.3157],[-72.01927,41.3152],[-72.01932,41.3144],[-72.01787,41.31388],[-72.01743,41.31395],[-72.01699,41.3145],[-72.01622,41.31417],[-72.01643,41.31378],[-72.01619,41.3131],[-72.01637,41.3124],[-72.01593,41.31178],[-72.0162,41.311],[-72.01607,41.31083],[-72.01651,41.31088],[-72.01638,41.31045],[-72.01656,41.30995],[-72.01661,41.30826],[-72.01633,41.30803],[-72.01514,41.30788],[-72.01407,41.30793],[-72.01378,41.3082],[-72.01198,41.30737],[-72.01194,41.30668],[-72.01089,41.30565],[-72.01085,41.30508],[-72.01049,41.30465],[-72.01048,41.30418],[-72.01004,41.30348],[-72.00999,41.30255],[-72.00946,41.30168],[-72.00986,41.3013],[-72.00959,41.30135],[-72.00891,41.30063],[-72.00872,41.30063],[-72.00805,41.30112],[-72.007,41.3034],[-72.00659,41.3035],[-72.0063,41.30332],[-72.00629,41.30307],[-72.00681,41.30275],[-72.00815,41.30033],[-72.00776,41.30018],[-72.00768,41.29933],[-72.00686,41.2988],[-72.00592,41.29887],[-72.0055,41.29867],[-72.00521,41.29872],[-72.00517,41.2991],[-72.00449,41.29992],[-72.00377,41.30022],[-72.00308,41.29977],[-72.00326,41.29939],[-72.00396,41.29912],[-72.00397,41.29867],[-72.00455,41.29852],[-72.00549,41.29762],[-72.00651,41.29753],[-72.00704,41.29818],[-72.00812,41.29845],[-72.00822,41.29865],[-72.00885,41.29843],[-72.00925,41.29795],[-72.00926,41.29763],[-72.00901,41.29728],[-72.00907,41.29691],[-72.00841,41.29678],[-72.00845,41.29643],[-72.0096,41.29671],[-72.01065,41.29631],[-72.0109,41.29648],[-72.01289,41.29639],[-72.01393,41.29544],[-72.0132,41.29601],[-72.01264,41.29619],[-72.01182,41.29616],[-72.01037,41.29561],[-72.00998,41.29586],[-72.00904,41.29596],[-72.00734,41.29533],[-72.00533,41.2937],[-72.00512,41.29338],[-72.00522,41.29268],[-72.00476,41.29198],[-72.00483,41.29183],[-72.00556,41.29275],[-72.00793,41.29451],[-72.00982,41.29526],[-72.01182,41.29561],[-72.01207,41.29581],[-72.01297,41.29581],[-72.01246,41.29581],[-72.01221,41.29551],[-72.01215,41.29526],[-72.01243,41.29479],[-72.01205,41.29411],[-72.0117,41.29401],[-72.01184,41.29439],[-72.01126,41.29466],[-72.00957,41.29431],[-72.00677,41.29248],[-72.00545,41.29126],[-72.00506,41.29038],[-72.0057,41.29001],[-72.00559,41.28968],[-72.00592,41.28928],[-72.00588,41.28871],[-72.00627,41.28873],[-72.00576,41.28868],[-72.00607,41.28838],[-72.00566,41.28696],[-72.0045,41.28738],[-72.00402,41.28736],[-72.00389,41.28803],[-72.00305,41.28843],[-72.00232,41.2884],[-72.00095,41.28793],[-72.00062,41.28768],[-72.00061,41.28738],[-71.9996,41.28668],[-71.99894,41.28752],[-71.99863,41.28755],[-71.99912,41.28767],[-71.99917,41.288],[-71.99903,41.28962],[-71.99842,41.29104],[-71.99835,41.29219],[-71.99886,41.29274],[-71.99891,41.29369],[-71.99945,41.29462],[-71.99879,41.29554],[-71.99849,41.29584],[-71.99829,41.29579],[-71.99849,41.29589],[-71.9986,41.29801],[-71.99806,41.29856],[-71.99801,41.30004],[-71.99779,41.30014],[-71.99768,41.30058],[-71.99653,41.30121],[-71.99576,41.30121],[-71.99548,41.30103],[-71.99567,41.30081],[-71.99542,41.30066],[-71.99483,41.29951],[-71.99465,41.29903],[-71.99476,41.29893],[-71.99452,41.29896],[-71.99404,41.29833],[-71.99385,41.29848],[-71.99403,41.29876],[-71.99347,41.29888],[-71.99405,41.29961],[-71.9944,41.30125],[-71.99298,41.30188],[-71.99157,41.30192],[-71.99072,41.30175],[-71.9907,41.30122],[-71.99043,41.30102],[-71.99033,41.30187],[-71.99,41.30202],[-71.98998,41.3023],[-71.98928,41.30257],[-71.98889,41.30314],[-71.98859,41.30314],[-71.98863,41.30344],[-71.98821,41.30419],[-71.9879,41.30422],[-71.98806,41.30472],[-71.98773,41.30514],[-71.98666,41.30499],[-71.98657,41.30519],[-71.9864,41.30374],[-71.98662,41.30366],[-71.98671,41.30284],[-71.9869,41.30267],[-71.98665,41.30229],[-71.98677,41.30224],[-71.98657,41.30127],[-71.98582,41.30052],[-71.9862,41.29962],[-71.98567,41.29959],[-71.98598,41.29929],[-71.98617,41.29934],[-71.98562,41.29892],[-71.98566,41.29852],[-71.98541,41.29827],[-71.98558,41.29734],[-71.9852,41.29689],[-71.98473,41.29677],[-71.9847,41.29657],[-71.98384,41.29684],[-71.9839,41.29729],[-71.98373,41.29754],[-71.98421,41.29782],[-71.98419,41.29827],[-71.9844,41.29849],[-71.98416,41.29944],[-71.98437,41.29994],[-71.98423,41.30011],[-71.98448,41.30034],[-71.98371,41.30009],[-71.9836,41.30029],[-71.98414,41.30054],[-71.98391,41.30086],[-71.98347,41.30079],[-71.98365,41.30109],[-71.98333,41.30106],[-71.98334,41.30134],[-71.98317,41.30131],[-71.98294,41.30179],[-71.98309,41.30191],[-71.98298,41.30234],[-71.98229,41.30296],[-71.98221,41.30341],[-71.98176,41.30373],[-71.9812,41.30381],[-71.98078,41.30443],[-71.98105,41.30453],[-71.98089,41.30481],[-71.98033,41.30488],[-71.98011,41.3055],[-71.97982,41.30548],[-71.9799,41.30568],[-71.97973,41.30565],[-71.97969,41.30595],[-71.9794,41.30613],[-71.97956,41.3065],[-71.97922,41.30678],[-71.97948,41.30708],[-71.97924,41.30725],[-71.97971,41.30755],[-71.97954,41.30765],[-71.97996,41.3077],[-71.97946,41.308],[-71.98009,41.30803],[-71.98005,41.30828],[-71.98064,41.30873],[-71.9806,41.30965],[-71.98078,41.31007],[-71.98005,41.31],[-71.98003,41.31022],[-71.98047,41.31025],[-71.9805,41.31052],[-71.98097,41.31075],[-71.98073,41.31142],[-71.9809,41.31152],[-71.98229,41.31013],[-71.98395,41.31045],[-71.98542,41.30976],[-71.98586,41.30983],[-71.98565,41.31023],[-71.9857,41.31048],[-71.98603,41.31063],[-71.98576,41.31156],[-71.98586,41.31171],[-71.98572,41.31176],[-71.98622,41.31231],[-71.98675,41.31218],[-71.98673,41.31236],[-71.9871,41.31246],[-71.98781,41.31341],[-71.9877,41.31461],[-71.98704,41.3154],[-71.98683,41.31543],[-71.98678,41.31565],[-71.98721,41.31603],[-71.98668,41.31865],[-71.98627,41.31875],[-71.98572,41.31827],[-71.98373,41.3185],[-71.98355,41.31792],[-71.98221,41.31725],[-71.9821,41.31597],[-71.98131,41.3154],[-71.98058,41.31542],[-71.98013,41.31592],[-71.97987,41.31697],[-71.9779,41.31836],[-71.97783,41.31871],[-71.97809,41.31909],[-71.97906,41.31976],[-71.97786,41.32136],[-71.9773,41.32113],[-71.97775,41.32083],[-71.97727,41.32091],[-71.97651,41.32063],[-71.97606,41.32101],[-71.97549,41.32078],[-71.97569,41.32021],[-71.97614,41.31981],[-71.97591,41.31951],[-71.97605,41.31908],[-71.97585,41.31908],[-71.97562,41.31953],[-71.97509,41.31963],[-71.97493,41.31988],[-71.97529,41.32053],[-71.97505,41.32058],[-71.97503,41.32086],[-71.97458,41.32123],[-71.97403,41.32148],[-71.97419,41.32215],[-71.97473,41.32215],[-71.97342,41.32482],[-71.97299,41.3243],[-71.973,41.32365],[-71.97265,41.3233],[-71.97271,41.3229],[-71.97297,41.3227],[-71.97246,41.3219],[-71.97241,41.32262],[-71.9722,41.3229],[-71.97096,41.3237],[-71.97051,41.32425],[-71.96976,41.32439],[-71.96965,41.32459],[-71.96982,41.32469],[-71.96963,41.32464],[-71.96923,41.32527],[-71.97013,41.32589],[-71.97071,41.32604],[-71.97092,41.32642],[-71.97109,41.32634],[-71.97103,41.32697],[-71.97032,41.32749],[-71.97026,41.32779],[-71.97046,41.32819],[-71.96945,41.32851],[-71.96648,41.33001],[-71.96617,41.33013],[-71.96705,41.33198]]],[[[-71.9768,41.31661],[-71.97592,41.31901],[-71.97609,41.31903],[-71.97725,41.31611],[-71.97808,41.31544],[-71.97812,41.31464],[-71.97843,41.31429],[-71.97844,41.31464],[-71.97921,41.31442],[-71.9796,41.31454],[-71.97977,41.31437],[-71.97893,41.31402],[-71.97979,41.31412],[-71.97978,41.31387],[-71.97949,41.31382],[-71.9798,41.31387],[-71.97991,41.3135],[-71.97938,41.31339],[-71.98059,41.31187],[-71.98042,41.31177],[-71.97837,41.31394],[-71.97582,41.31411],[-71.97416,41.31366],[-71.9739,41.31389],[-71.97358,41.31384],[-71.97359,41.31408],[-71.9733,41.31428],[-71.97262,41.31421],[-71.97272,41.31448],[-71.97329,41.31476],[-71.97365,41.31463],[-71.9746,41.31476],[-71.97487,41.31464],[-71.97489,41.31391],[-71.97504,41.31386],[-71.97529,41.31424],[-71.97563,41.31406],[-71.97576,41.31446],[-71.97652,41.31459],[-71.97503,41.31439],[-71.97496,41.31459],[-71.97545,41.31476],[-71.97439,41.31494],[-71.97379,41.31526],[-71.9739,41.31553],[-71.9737,41.31531],[-71.97332,41.31568],[-71.97416,41.31633],[-71.97442,41.31688],[-71.97509,41.31721],[-71.97496,41.31788],[-71.97554,41.31828],[-71.97568,41.31828],[-71.97555,41.31803],[-71.97582,41.31736],[-71.97562,41.31696],[-71.9754,41.31701],[-71.97559,41.31694],[-71.97553,41.31661],[-71.97582,41.31626],[-71.9763,41.31629],[-71.97647,41.31551],[-71.97651,41.31594],[-71.97689,41.31631],[-71.97638,41.31629],[-71.97638,41.31644],[-71.9768,41.31661]]],[[[-72.04988,41.29522],[-72.04966,41.29534],[-72.04991,41.29539],[-72.0505,41.295],[-72.05096,41.29495],[-72.0513,41.29467],[-72.05152,41.29405],[-72.05314,41.29442],[-72.054,41.29403],[-72.05425,41.29363],[-72.05506,41.29365],[-72.05551,41.29345],[-72.05557,41.29303],[-72.05539,41.2926],[-72.05497,41.29228],[-72.05279,41.29335],[-72.05157,41.29322],[-72.05084,41.29402],[-72.05083,41.29455],[-72.04993,41.29449],[-72.04958,41.29514],[-72.04978,41.29512],[-72.04988,41.29522]]],[[[-72.04555,41.29848],[-72.04597,41.29876],[-72.04689,41.29834],[-72.04731,41.29779],[-72.04794,41.29799],[-72.04818,41.29769],[-72.04909,41.29729],[-72.04907,41.29647],[-72.04858,41.29634],[-72.04785,41.29646],[-72.04702,41.29721],[-72.04693,41.29756],[-72.0465,41.29756],[-72.04626,41.29784],[-72.04555,41.29848]]],[[[-71.98664,41.29622],[-71.987,41.29607],[-71.98642,41.29565],[-71.98679,41.29495],[-71.9866,41.29502],[-71.9862,41.29472],[-71.98643,41.29512],[-71.98608,41.29557],[-71.9864,41.2958],[-71.98641,41.29607],[-71.98664,41.29622]]],[[[-72.07144,41.30249],[-72.07101,41.30269],[-72.07149,41.30269],[-72.07147,41.30287],[-72.07179,41.30292],[-72.07144,41.30249]]],[[[-71.98022,41.29147],[-71.98058,41.29122],[-71.98064,41.29084],[-71.9805,41.29087],[-71.98022,41.29147]]]]}' FROM towns WHERE name = 'Groton';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.64928,41.25012],[-72.6481,41.25045],[-72.64734,41.25129],[-72.64731,41.25169],[-72.64785,41.25287],[-72.6475,41.25419],[-72.64535,41.25519],[-72.64373,41.25473],[-72.64281,41.25481],[-72.64228,41.25511],[-72.64126,41.25605],[-72.64108,41.25645],[-72.64249,41.25973],[-72.64253,41.26018],[-72.64072,41.2615],[-72.64004,41.26155],[-72.63957,41.2611],[-72.63898,41.26097],[-72.63797,41.26132],[-72.63776,41.26164],[-72.63799,41.26274],[-72.63832,41.26322],[-72.64009,41.26467],[-72.6409,41.26649],[-72.64075,41.26714],[-72.63983,41.26744],[-72.63966,41.26814],[-72.63986,41.26842],[-72.64095,41.26887],[-72.6421,41.26992],[-72.64122,41.27062],[-72.64076,41.27071],[-72.64019,41.27054],[-72.63957,41.26994],[-72.63887,41.27001],[-72.63851,41.27116],[-72.63779,41.27208],[-72.62717,41.28023],[-72.62755,41.28418],[-72.62819,41.28511],[-72.62774,41.28556],[-72.62773,41.28608],[-72.62748,41.2865],[-72.62744,41.28763],[-72.6271,41.2885],[-72.62721,41.2889],[-72.62652,41.2894],[-72.62605,41.2907],[-72.62665,41.29135],[-72.62729,41.29155],[-72.62768,41.29257],[-72.62875,41.2934],[-72.62878,41.29417],[-72.62984,41.29408],[-72.63024,41.29425],[-72.63029,41.29513],[-72.63084,41.29568],[-72.63065,41.29597],[-72.63082,41.2966],[-72.63048,41.29672],[-72.63044,41.29695],[-72.63095,41.29782],[-72.63184,41.29825],[-72.63283,41.29952],[-72.63369,41.29988],[-72.63419,41.2997],[-72.63488,41.30008],[-72.63587,41.30125],[-72.63676,41.30193],[-72.63647,41.30275],[-72.63717,41.30415],[-72.63769,41.30445],[-72.63881,41.30443],[-72.63912,41.30415],[-72.63983,41.30451],[-72.64001,41.30468],[-72.63989,41.30575],[-72.64109,41.30598],[-72.64227,41.30721],[-72.64385,41.30801],[-72.64517,41.30834],[-72.64549,41.30913],[-72.64517,41.30978],[-72.64532,41.31003],[-72.64773,41.31161],[-72.64972,41.31241],[-72.65041,41.31289],[-72.65263,41.31154],[-72.65907,41.31635],[-72.66506,41.32395],[-72.66965,41.33018],[-72.67257,41.3382],[-72.67421,41.34105],[-72.67718,41.34919],[-72.6765,41.35251],[-72.67594,41.35671],[-72.67648,41.35996],[-72.67636,41.36825],[-72.67679,41.37659],[-72.67669,41.38066],[-72.67683,41.38458],[-72.67726,41.38821],[-72.6773,41.3994],[-72.67668,41.41168],[-72.67676,41.41758],[-72.67681,41.41848],[-72.67688,41.41845],[-72.68089,41.41843],[-72.70189,41.41522],[-72.70646,41.41425],[-72.7064,41.41395],[-72.71254,41.41286],[-72.71832,41.412],[-72.7183,41.41195],[-72.71932,41.40805],[-72.71976,41.40735],[-72.72098,41.40318],[-72.72367,41.40221],[-72.72434,41.40259],[-72.72812,41.39905],[-72.73059,41.39571],[-72.73564,41.39047],[-72.73973,41.38665],[-72.74172,41.38256],[-72.74434,41.38334],[-72.74401,41.38024],[-72.74382,41.37492],[-72.74316,41.36813],[-72.74169,41.35831],[-72.74015,41.33768],[-72.73818,41.32229],[-72.73625,41.3091],[-72.73602,41.3063],[-72.73691,41.30183],[-72.74003,41.29252],[-72.74025,41.29177],[-72.73969,41.28835],[-72.73196,41.26746],[-72.72457,41.25453],[-72.71995,41.24726],[-72.72407,41.24589],[-72.72384,41.24397],[-72.72412,41.24377],[-72.72451,41.24287],[-72.72572,41.2426],[-72.72641,41.24302],[-72.7269,41.24302],[-72.72752,41.24275],[-72.72804,41.2422],[-72.7281,41.24188],[-72.72839,41.241],[-72.72892,41.24073],[-72.72888,41.24038],[-72.72919,41.24005],[-72.72894,41.23978],[-72.72833,41.23968],[-72.72887,41.2391],[-72.72825,41.2388],[-72.72815,41.23855],[-72.72937,41.23808],[-72.72963,41.23783],[-72.72951,41.23771],[-72.72998,41.23736],[-72.72932,41.23706],[-72.72926,41.23673],[-72.72886,41.23651],[-72.729,41.23623],[-72.72872,41.23651],[-72.72715,41.23628],[-72.72704,41.23645],[-72.72565,41.23633],[-72.72476,41.23653],[-72.72481,41.23593],[-72.72586,41.23538],[-72.72662,41.2346],[-72.72505,41.23493],[-72.72477,41.23548],[-72.724,41.23575],[-72.72363,41.23622],[-72.7241,41.23647],[-72.72396,41.23657],[-72.72413,41.2368],[-72.724,41.2366],[-72.72436,41.23635],[-72.72471,41.23665],[-72.72419,41.23695],[-72.72441,41.23707],[-72.72355,41.23762],[-72.72385,41.23807],[-72.72377,41.23855],[-72.72406,41.2386],[-72.72382,41.23867],[-72.72358,41.23952],[-72.7229,41.23934],[-72.72251,41.23869],[-72.72222,41.23857],[-72.72218,41.23822],[-72.72125,41.23872],[-72.72145,41.23879],[-72.72146,41.23917],[-72.72118,41.23964],[-72.72072,41.23962],[-72.72073,41.23934],[-72.72046,41.23914],[-72.72032,41.23869],[-72.72001,41.23872],[-72.71993,41.23852],[-72.72031,41.23814],[-72.72027,41.23774],[-72.72051,41.23772],[-72.72093,41.23702],[-72.71962,41.23727],[-72.71978,41.23744],[-72.71969,41.23769],[-72.71893,41.23851],[-72.71867,41.23851],[-72.71916,41.23889],[-72.7196,41.23892],[-72.71924,41.23971],[-72.71988,41.24009],[-72.71946,41.24054],[-72.71951,41.24159],[-72.71915,41.24179],[-72.71853,41.24129],[-72.71834,41.24056],[-72.71848,41.24051],[-72.71774,41.24014],[-72.71641,41.24096],[-72.71608,41.24078],[-72.71603,41.23973],[-72.71687,41.23949],[-72.71706,41.23911],[-72.71727,41.23909],[-72.71714,41.23879],[-72.71759,41.23841],[-72.71727,41.23819],[-72.71521,41.23933],[-72.7152,41.23966],[-72.71383,41.24013],[-72.71212,41.24163],[-72.70969,41.2432],[-72.70826,41.24249],[-72.70838,41.24232],[-72.70745,41.24157],[-72.70768,41.24092],[-72.70825,41.24045],[-72.70764,41.2403],[-72.70738,41.2399],[-72.70803,41.23895],[-72.70808,41.23812],[-72.70827,41.23805],[-72.70817,41.23787],[-72.70767,41.23817],[-72.70761,41.23782],[-72.70789,41.2374],[-72.70844,41.23715],[-72.70915,41.23738],[-72.70961,41.23728],[-72.70993,41.23748],[-72.70959,41.23748],[-72.70969,41.2376],[-72.71045,41.2376],[-72.71127,41.23653],[-72.71177,41.23618],[-72.71103,41.23583],[-72.70978,41.2365],[-72.70965,41.23688],[-72.70774,41.2366],[-72.70626,41.2373],[-72.70596,41.23722],[-72.70541,41.23757],[-72.70524,41.23749],[-72.70443,41.23799],[-72.70367,41.23777],[-72.70311,41.23787],[-72.70257,41.23841],[-72.70135,41.23894],[-72.70081,41.23954],[-72.69994,41.23973],[-72.69973,41.24006],[-72.69875,41.24001],[-72.69784,41.23961],[-72.6969,41.23881],[-72.69684,41.23853],[-72.6971,41.23838],[-72.69726,41.23793],[-72.69684,41.23771],[-72.69643,41.23781],[-72.69537,41.23648],[-72.69556,41.23636],[-72.69538,41.23618],[-72.69547,41.23581],[-72.69632,41.23506],[-72.69654,41.23426],[-72.69807,41.23326],[-72.6997,41.23267],[-72.70137,41.23085],[-72.70218,41.231],[-72.70355,41.23055],[-72.70422,41.23015],[-72.70457,41.22963],[-72.70488,41.2296],[-72.70485,41.2294],[-72.70554,41.22873],[-72.70651,41.22871],[-72.70572,41.22823],[-72.70563,41.2278],[-72.70591,41.22738],[-72.70544,41.22703],[-72.70546,41.22686],[-72.7046,41.2268],[-72.70405,41.22703],[-72.70383,41.22688],[-72.70402,41.22665],[-72.7038,41.22665],[-72.70289,41.22715],[-72.70256,41.2269],[-72.7023,41.22708],[-72.70247,41.22713],[-72.70246,41.22743],[-72.702,41.22757],[-72.70166,41.22747],[-72.70171,41.22685],[-72.70132,41.22677],[-72.70114,41.22727],[-72.70135,41.22765],[-72.70118,41.22787],[-72.70077,41.22792],[-72.70069,41.22762],[-72.7003,41.22765],[-72.70032,41.22742],[-72.7001,41.2274],[-72.69955,41.22767],[-72.6994,41.22819],[-72.69842,41.22809],[-72.69771,41.22887],[-72.69796,41.22907],[-72.69745,41.22894],[-72.69752,41.22874],[-72.69717,41.22847],[-72.69753,41.22752],[-72.69816,41.22739],[-72.69831,41.22702],[-72.69895,41.2271],[-72.69923,41.22667],[-72.69912,41.22642],[-72.6995,41.22617],[-72.6992,41.22572],[-72.69898,41.22575],[-72.69909,41.2254],[-72.7001,41.22567],[-72.70043,41.22555],[-72.7005,41.22532],[-72.70166,41.22513],[-72.70157,41.22443],[-72.70085,41.2241],[-72.70113,41.22378],[-72.70101,41.2237],[-72.70065,41.22375],[-72.70037,41.22415],[-72.69983,41.22415],[-72.70013,41.2244],[-72.70006,41.2246],[-72.69907,41.2248],[-72.69855,41.22427],[-72.69859,41.2239],[-72.69891,41.22352],[-72.69837,41.22317],[-72.69855,41.22345],[-72.69802,41.22355],[-72.69781,41.22395],[-72.69737,41.22395],[-72.6971,41.22367],[-72.69691,41.2239],[-72.69673,41.22377],[-72.6966,41.22417],[-72.69439,41.22519],[-72.69426,41.22541],[-72.6944,41.22551],[-72.694,41.22576],[-72.69445,41.22619],[-72.69347,41.22671],[-72.69319,41.22714],[-72.69224,41.22711],[-72.69202,41.22703],[-72.69209,41.22681],[-72.69162,41.22673],[-72.69069,41.22728],[-72.68934,41.22756],[-72.688,41.2266],[-72.68837,41.22616],[-72.68947,41.22566],[-72.68932,41.22538],[-72.68829,41.22518],[-72.68722,41.22438],[-72.68615,41.22493],[-72.68556,41.22575],[-72.68553,41.22623],[-72.68524,41.22633],[-72.68527,41.22648],[-72.68495,41.22645],[-72.68496,41.22672],[-72.68463,41.22707],[-72.68426,41.22697],[-72.68427,41.2272],[-72.68393,41.22727],[-72.68388,41.22702],[-72.68386,41.22742],[-72.68323,41.2275],[-72.68286,41.22727],[-72.68292,41.22675],[-72.68238,41.2267],[-72.68251,41.22677],[-72.68176,41.22772],[-72.68118,41.22804],[-72.68111,41.22867],[-72.68065,41.22902],[-72.68057,41.22934],[-72.68004,41.22944],[-72.6795,41.23014],[-72.67902,41.23031],[-72.67832,41.23136],[-72.67815,41.23228],[-72.67772,41.23321],[-72.67775,41.23413],[-72.67736,41.23433],[-72.67766,41.23438],[-72.67768,41.23503],[-72.67688,41.23528],[-72.677,41.23583],[-72.67724,41.2359],[-72.67805,41.23768],[-72.67753,41.23833],[-72.67703,41.23855],[-72.67642,41.23848],[-72.67581,41.23775],[-72.67487,41.23725],[-72.67477,41.237],[-72.67384,41.23675],[-72.67371,41.2364],[-72.67359,41.2366],[-72.67281,41.23647],[-72.67143,41.23515],[-72.67116,41.2351],[-72.67096,41.23467],[-72.67008,41.23477],[-72.67019,41.23577],[-72.66976,41.23627],[-72.67056,41.23692],[-72.6706,41.23734],[-72.67032,41.23789],[-72.66962,41.23799],[-72.66984,41.23884],[-72.67038,41.23969],[-72.67131,41.23994],[-72.67145,41.24059],[-72.67165,41.24064],[-72.67148,41.24082],[-72.67227,41.24124],[-72.6724,41.24159],[-72.67262,41.24164],[-72.67234,41.24177],[-72.67263,41.24189],[-72.67132,41.24261],[-72.67004,41.24234],[-72.6701,41.24256],[-72.67034,41.24249],[-72.67076,41.24271],[-72.67089,41.24304],[-72.67067,41.24306],[-72.67068,41.24326],[-72.67087,41.24319],[-72.67117,41.24349],[-72.67144,41.24334],[-72.67154,41.24356],[-72.67057,41.24461],[-72.66988,41.24503],[-72.66896,41.24493],[-72.66855,41.24513],[-72.66801,41.24491],[-72.66767,41.24511],[-72.66805,41.24566],[-72.66913,41.24653],[-72.66817,41.2472],[-72.66761,41.24715],[-72.66709,41.2475],[-72.66667,41.24738],[-72.66649,41.24785],[-72.66554,41.24777],[-72.66438,41.248],[-72.6649,41.2482],[-72.66442,41.24847],[-72.66346,41.24822],[-72.66312,41.2474],[-72.66327,41.2473],[-72.66359,41.24762],[-72.66371,41.24755],[-72.66333,41.24692],[-72.66237,41.24645],[-72.66216,41.24612],[-72.66168,41.24617],[-72.66178,41.24627],[-72.66132,41.24657],[-72.66095,41.24619],[-72.66113,41.24737],[-72.66156,41.24784],[-72.66061,41.24862],[-72.66024,41.24854],[-72.66052,41.24879],[-72.66024,41.24924],[-72.65971,41.24954],[-72.65861,41.25081],[-72.66159,41.25127],[-72.66201,41.25094],[-72.66256,41.25119],[-72.66181,41.25132],[-72.66133,41.25169],[-72.66187,41.25184],[-72.66174,41.25221],[-72.66135,41.25226],[-72.6612,41.25199],[-72.66098,41.25219],[-72.66148,41.25249],[-72.66157,41.25301],[-72.66022,41.25279],[-72.6598,41.25254],[-72.65974,41.25221],[-72.65933,41.25209],[-72.65918,41.25219],[-72.6595,41.25289],[-72.65908,41.25278],[-72.65846,41.25246],[-72.65808,41.25168],[-72.65834,41.25144],[-72.65665,41.25203],[-72.65583,41.25071],[-72.65475,41.25018],[-72.65441,41.24951],[-72.6544,41.24903],[-72.65331,41.24746],[-72.65256,41.24693],[-72.6523,41.24638],[-72.65137,41.2461],[-72.65035,41.24608],[-72.64987,41.24568],[-72.64894,41.24608],[-72.64885,41.24628],[-72.6478,41.24637],[-72.64763,41.24632],[-72.64752,41.24587],[-72.64715,41.24572],[-72.64713,41.24572],[-72.64736,41.24625],[-72.64762,41.24675],[-72.64928,41.25012]]],[[[-72.70552,41.23622],[-72.70623,41.23572],[-72.7062,41.23542],[-72.70495,41.23575],[-72.70487,41.23635],[-72.70552,41.23622]]],[[[-72.72639,41.23356],[-72.72625,41.23383],[-72.72724,41.23358],[-72.72741,41.23333],[-72.72718,41.23316],[-72.72639,41.23356]]],[[[-72.72195,41.23615],[-72.72196,41.23655],[-72.72244,41.23637],[-72.72255,41.23607],[-72.72233,41.23597],[-72.72195,41.23615]]],[[[-72.70055,41.23599],[-72.70077,41.23601],[-72.7013,41.23572],[-72.70107,41.23542],[-72.70072,41.23574],[-72.70055,41.23599]]],[[[-72.72549,41.23443],[-72.72598,41.23433],[-72.7259,41.23415],[-72.72556,41.23413],[-72.72549,41.23443]]],[[[-72.72951,41.23284],[-72.72968,41.23281],[-72.7297,41.23266],[-72.72953,41.23269],[-72.72951,41.23284]]]]}' FROM towns WHERE name = 'Guilford';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.44947,41.41816],[-72.45695,41.42586],[-72.45763,41.42679],[-72.45897,41.42904],[-72.46076,41.43291],[-72.46261,41.43606],[-72.46395,41.43793],[-72.4647,41.43938],[-72.46576,41.44236],[-72.46647,41.44571],[-72.4679,41.4498],[-72.46844,41.4532],[-72.46997,41.4561],[-72.47118,41.45768],[-72.47205,41.45833],[-72.4775,41.46106],[-72.47762,41.46158],[-72.47704,41.4641],[-72.47677,41.46743],[-72.47683,41.47332],[-72.47728,41.47459],[-72.47778,41.47499],[-72.47898,41.47535],[-72.48086,41.47552],[-72.4835,41.47535],[-72.48579,41.47701],[-72.48609,41.47748],[-72.4865,41.47975],[-72.48771,41.48208],[-72.48833,41.48463],[-72.48808,41.4884],[-72.48774,41.48937],[-72.4856,41.49187],[-72.4848,41.49429],[-72.48452,41.49638],[-72.48452,41.49851],[-72.48367,41.50115],[-72.48365,41.50198],[-72.4834,41.50265],[-72.48385,41.50308],[-72.48395,41.50465],[-72.48343,41.50517],[-72.48152,41.50565],[-72.48126,41.506],[-72.48124,41.50605],[-72.49642,41.50405],[-72.55392,41.49779],[-72.55505,41.49769],[-72.55525,41.49767],[-72.57754,41.49448],[-72.63384,41.48642],[-72.63393,41.48465],[-72.63288,41.48142],[-72.63191,41.48127],[-72.63021,41.47982],[-72.62966,41.47865],[-72.62962,41.47493],[-72.62758,41.4715],[-72.63619,41.44904],[-72.60753,41.42819],[-72.60641,41.42739],[-72.60636,41.42736],[-72.56723,41.39845],[-72.53687,41.40568],[-72.53637,41.40578],[-72.52338,41.40883],[-72.52191,41.40918],[-72.49748,41.41174],[-72.45836,41.41682],[-72.44935,41.41803],[-72.44947,41.41816]]]]}' FROM towns WHERE name = 'Haddam';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.89809,41.43832],[-72.90685,41.4417],[-72.91583,41.44554],[-72.91937,41.44587],[-72.92108,41.44627],[-72.92815,41.44691],[-72.93271,41.44809],[-72.93499,41.44894],[-72.93958,41.44945],[-72.94673,41.45026],[-72.94648,41.44776],[-72.94633,41.44613],[-72.94573,41.44551],[-72.94674,41.44281],[-72.94756,41.44174],[-72.94982,41.44067],[-72.95045,41.4393],[-72.95088,41.43885],[-72.9552,41.43638],[-72.95563,41.43593],[-72.95615,41.43389],[-72.95603,41.43299],[-72.95551,41.43209],[-72.95568,41.43129],[-72.95638,41.43006],[-72.95785,41.42822],[-72.95913,41.42725],[-72.96065,41.42503],[-72.96106,41.4241],[-72.96082,41.42355],[-72.96098,41.4223],[-72.95951,41.41778],[-72.95312,41.41413],[-72.94942,41.4129],[-72.95043,41.40363],[-72.9367,41.39654],[-72.93563,41.39259],[-72.94191,41.39051],[-72.95008,41.38422],[-72.95021,41.38098],[-72.95229,41.37833],[-72.95422,41.37696],[-72.9619,41.36484],[-72.96504,41.35865],[-72.96986,41.34826],[-72.97011,41.34522],[-72.97077,41.3416],[-72.97007,41.33443],[-72.96861,41.33025],[-72.96425,41.33062],[-72.96332,41.33067],[-72.96231,41.32855],[-72.96099,41.32742],[-72.95956,41.32667],[-72.95941,41.32917],[-72.95864,41.32922],[-72.95809,41.32894],[-72.95736,41.32954],[-72.95746,41.32971],[-72.95673,41.33211],[-72.95383,41.33188],[-72.95334,41.33323],[-72.95035,41.33315],[-72.9481,41.33202],[-72.94904,41.33015],[-72.94826,41.33002],[-72.94754,41.33025],[-72.9465,41.3297],[-72.94623,41.32817],[-72.94611,41.32647],[-72.94827,41.3265],[-72.95019,41.32483],[-72.95046,41.32486],[-72.95146,41.32321],[-72.95196,41.32291],[-72.95259,41.32284],[-72.94929,41.31981],[-72.93814,41.31852],[-72.90833,41.31568],[-72.89737,41.30877],[-72.88817,41.31078],[-72.88765,41.31028],[-72.88731,41.31048],[-72.88707,41.3103],[-72.88654,41.31072],[-72.88752,41.31167],[-72.88769,41.31237],[-72.8875,41.31265],[-72.88635,41.31317],[-72.88417,41.31352],[-72.88396,41.31452],[-72.88397,41.31497],[-72.88421,41.31564],[-72.88431,41.31642],[-72.88362,41.31769],[-72.88357,41.31839],[-72.88379,41.31936],[-72.88349,41.32148],[-72.88301,41.32258],[-72.88257,41.32311],[-72.8807,41.32485],[-72.8785,41.32615],[-72.87722,41.32632],[-72.87574,41.32582],[-72.87528,41.32587],[-72.87495,41.32612],[-72.87473,41.32697],[-72.87641,41.32842],[-72.87664,41.32892],[-72.87642,41.32954],[-72.87524,41.33009],[-72.87278,41.32926],[-72.8722,41.32921],[-72.87138,41.32948],[-72.86938,41.33078],[-72.86898,41.3314],[-72.86883,41.33258],[-72.87012,41.33455],[-72.87077,41.33515],[-72.87258,41.33625],[-72.87383,41.33641],[-72.87632,41.33593],[-72.87809,41.33589],[-72.879,41.33624],[-72.87958,41.33686],[-72.88004,41.33774],[-72.87992,41.33931],[-72.87952,41.34051],[-72.87808,41.34133],[-72.87764,41.34193],[-72.87739,41.34263],[-72.87767,41.34385],[-72.89582,41.34763],[-72.90398,41.34981],[-72.90013,41.36157],[-72.89813,41.36287],[-72.89628,41.36364],[-72.89542,41.36489],[-72.89403,41.36561],[-72.89211,41.36715],[-72.89182,41.3681],[-72.89094,41.36875],[-72.89074,41.36932],[-72.88994,41.37017],[-72.88971,41.37075],[-72.88987,41.37125],[-72.8895,41.37184],[-72.8895,41.37337],[-72.88884,41.37399],[-72.88899,41.37427],[-72.88884,41.37499],[-72.88776,41.37679],[-72.88529,41.37716],[-72.88315,41.38597],[-72.8829,41.39059],[-72.88361,41.39331],[-72.88402,41.39641],[-72.88339,41.39636],[-72.88099,41.4079],[-72.87952,41.40777],[-72.87557,41.40896],[-72.87469,41.40944],[-72.87294,41.41106],[-72.86678,41.4109],[-72.86205,41.41124],[-72.86031,41.41059],[-72.85936,41.41051],[-72.85758,41.41291],[-72.85638,41.41418],[-72.855,41.41435],[-72.85325,41.41495],[-72.85049,41.41804],[-72.84958,41.41919],[-72.85064,41.41961],[-72.88533,41.43333],[-72.88533,41.43335],[-72.89809,41.43832]]]]}' FROM towns WHERE name = 'Hamden';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.05814,41.82612],[-72.10254,41.82661],[-72.10285,41.82661],[-72.10302,41.81994],[-72.10459,41.76093],[-72.10926,41.7425],[-72.11072,41.73676],[-72.10562,41.73453],[-72.0885,41.72711],[-72.06281,41.72385],[-72.06015,41.7235],[-72.05869,41.72329],[-72.05319,41.72259],[-72.03806,41.74289],[-72.03153,41.7522],[-72.03141,41.75235],[-72.03063,41.75619],[-72.02999,41.76001],[-72.02847,41.76693],[-72.02609,41.78548],[-72.02391,41.79942],[-72.02391,41.79947],[-72.02399,41.79964],[-72.02937,41.81446],[-72.03182,41.82243],[-72.04127,41.82612],[-72.05539,41.82609],[-72.05541,41.82609],[-72.05814,41.82612]]]]}' FROM towns WHERE name = 'Hampton';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.69534,41.79507],[-72.69984,41.79587],[-72.70088,41.80227],[-72.70491,41.80215],[-72.72782,41.80146],[-72.72437,41.78157],[-72.72278,41.77136],[-72.72515,41.76469],[-72.72463,41.75944],[-72.72304,41.75008],[-72.72274,41.74983],[-72.72295,41.74064],[-72.72253,41.73809],[-72.72313,41.73721],[-72.72169,41.71746],[-72.7216,41.71628],[-72.71426,41.71697],[-72.71339,41.71704],[-72.70194,41.7178],[-72.69183,41.71883],[-72.66304,41.72254],[-72.65513,41.72043],[-72.65418,41.72125],[-72.65194,41.72367],[-72.65094,41.72519],[-72.65001,41.72741],[-72.64978,41.72946],[-72.65014,41.73253],[-72.65096,41.73551],[-72.6529,41.74003],[-72.65422,41.74185],[-72.65589,41.74333],[-72.65812,41.74463],[-72.66175,41.74586],[-72.66328,41.74666],[-72.66563,41.74892],[-72.66922,41.75187],[-72.67126,41.75404],[-72.67274,41.75637],[-72.67357,41.75887],[-72.67382,41.76084],[-72.67375,41.76246],[-72.67347,41.76374],[-72.67274,41.76536],[-72.67141,41.76701],[-72.66748,41.76997],[-72.66095,41.77321],[-72.65987,41.77391],[-72.65846,41.77528],[-72.65766,41.77638],[-72.65709,41.7776],[-72.6555,41.78287],[-72.65519,41.78451],[-72.6551,41.78911],[-72.65549,41.79133],[-72.6556,41.79253],[-72.65563,41.79273],[-72.65581,41.79805],[-72.65569,41.8005],[-72.65565,41.80073],[-72.65604,41.80073],[-72.66321,41.80091],[-72.6653,41.80064],[-72.66966,41.79618],[-72.67211,41.79603],[-72.67165,41.79843],[-72.67191,41.79995],[-72.66908,41.8004],[-72.66825,41.80024],[-72.6685,41.80199],[-72.69453,41.79784],[-72.69547,41.79769],[-72.69534,41.79507]]]]}' FROM towns WHERE name = 'Hartford';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.88998,42.03905],[-72.95633,42.03895],[-73.01974,42.04022],[-73.02536,42.04038],[-73.03603,42.00066],[-73.0422,41.97389],[-73.04391,41.96628],[-73.03969,41.96652],[-73.01407,41.96821],[-72.93506,41.97176],[-72.90241,41.97306],[-72.89858,41.98477],[-72.88042,42.03894],[-72.88998,42.03905]]]]}' FROM towns WHERE name = 'Hartland';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.08645,41.79354],[-73.1132,41.79378],[-73.11277,41.7902],[-73.12108,41.78018],[-73.11975,41.77905],[-73.11937,41.77845],[-73.11937,41.77758],[-73.11973,41.77658],[-73.12028,41.77633],[-73.12098,41.77693],[-73.12171,41.77701],[-73.12396,41.77668],[-73.12593,41.77604],[-73.12621,41.77564],[-73.12655,41.77239],[-73.12817,41.77042],[-73.12824,41.77032],[-73.12866,41.76982],[-73.12903,41.76915],[-73.12877,41.76847],[-73.12693,41.76715],[-73.12453,41.7642],[-73.12498,41.76302],[-73.12424,41.76085],[-73.12455,41.75905],[-73.12529,41.75716],[-73.1248,41.75613],[-73.12337,41.75458],[-73.12318,41.75398],[-73.12352,41.75333],[-73.1244,41.75326],[-73.12492,41.75293],[-73.12522,41.75069],[-73.12461,41.74986],[-73.12302,41.74868],[-73.12144,41.74718],[-73.12017,41.74513],[-73.11876,41.74363],[-73.11865,41.74331],[-73.11886,41.74286],[-73.12018,41.74239],[-73.12178,41.74149],[-73.12173,41.74064],[-73.12239,41.73994],[-73.1229,41.73894],[-73.12282,41.73812],[-73.12316,41.73635],[-73.12289,41.73547],[-73.12148,41.734],[-73.11973,41.73302],[-73.11931,41.73279],[-73.11667,41.73234],[-73.11446,41.73074],[-73.11332,41.73024],[-73.11227,41.72916],[-73.11224,41.72796],[-73.11143,41.72716],[-73.11095,41.72636],[-73.11048,41.72624],[-73.10924,41.72451],[-73.10855,41.72421],[-73.10597,41.72398],[-73.10428,41.72276],[-73.10345,41.72123],[-73.1026,41.72041],[-73.10133,41.71871],[-73.10005,41.71795],[-73.1,41.71648],[-73.09941,41.71543],[-73.09901,41.71501],[-73.09837,41.71478],[-73.09724,41.71345],[-73.096,41.7128],[-73.0958,41.71185],[-73.09524,41.711],[-73.09458,41.71065],[-73.09247,41.71025],[-73.08819,41.71004],[-73.0844,41.70684],[-73.07949,41.70441],[-73.07689,41.70271],[-73.07573,41.70063],[-73.07468,41.69971],[-73.07466,41.69968],[-73.06845,41.7002],[-73.06135,41.70089],[-73.0541,41.7016],[-73.00621,41.7071],[-73.00541,41.70725],[-73.00632,41.71069],[-73.01778,41.75707],[-73.02617,41.79265],[-73.02626,41.79324],[-73.0264,41.7932],[-73.08543,41.79356],[-73.0857,41.79358],[-73.08645,41.79354]]]]}' FROM towns WHERE name = 'Harwinton';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.36727,41.69259],[-72.38097,41.69071],[-72.40454,41.68805],[-72.41095,41.6958],[-72.42127,41.71101],[-72.43041,41.72398],[-72.43913,41.72292],[-72.45749,41.72075],[-72.46757,41.71962],[-72.46617,41.71352],[-72.45922,41.68359],[-72.45835,41.67989],[-72.45392,41.66273],[-72.4344,41.65286],[-72.41976,41.62848],[-72.41883,41.62743],[-72.41899,41.62706],[-72.41869,41.62681],[-72.4188,41.62648],[-72.41861,41.62511],[-72.41836,41.62473],[-72.41592,41.62288],[-72.41354,41.62048],[-72.41364,41.61976],[-72.41339,41.61961],[-72.41252,41.61566],[-72.41198,41.61459],[-72.41311,41.61186],[-72.41291,41.59628],[-72.41334,41.59133],[-72.41331,41.59111],[-72.41197,41.59101],[-72.4098,41.58758],[-72.40541,41.58463],[-72.40295,41.58348],[-72.40036,41.5831],[-72.39655,41.58097],[-72.38959,41.57826],[-72.38741,41.57698],[-72.38692,41.57743],[-72.38684,41.57826],[-72.38659,41.57876],[-72.38703,41.57891],[-72.38697,41.57931],[-72.38565,41.58055],[-72.38527,41.58183],[-72.38534,41.5823],[-72.38428,41.58277],[-72.38401,41.5834],[-72.384,41.58402],[-72.38195,41.58437],[-72.38082,41.58497],[-72.3807,41.58574],[-72.37993,41.58611],[-72.37994,41.58639],[-72.38042,41.58704],[-72.37978,41.58746],[-72.37936,41.58961],[-72.37869,41.59016],[-72.37772,41.59021],[-72.37754,41.58976],[-72.37621,41.58923],[-72.37511,41.58825],[-72.37457,41.58813],[-72.37388,41.58848],[-72.37139,41.589],[-72.37044,41.58895],[-72.36965,41.58852],[-72.36868,41.58842],[-72.36743,41.58914],[-72.36697,41.58994],[-72.36643,41.58969],[-72.36568,41.58991],[-72.36466,41.58914],[-72.3639,41.58791],[-72.36296,41.58754],[-72.36233,41.58689],[-72.36058,41.58681],[-72.35961,41.58616],[-72.35923,41.58621],[-72.35854,41.58678],[-72.3562,41.58753],[-72.34688,41.58791],[-72.33159,41.59426],[-72.32876,41.60622],[-72.32842,41.60766],[-72.33054,41.61289],[-72.3311,41.61446],[-72.3387,41.63338],[-72.34319,41.6446],[-72.35752,41.67959],[-72.36181,41.69543],[-72.36727,41.69259]]]]}' FROM towns WHERE name = 'Hebron';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.44097,41.78451],[-73.51699,41.78725],[-73.51722,41.78357],[-73.51868,41.7578],[-73.51932,41.73537],[-73.52043,41.71267],[-73.5223,41.68273],[-73.52292,41.66382],[-73.52307,41.65903],[-73.52217,41.65898],[-73.52066,41.65828],[-73.51928,41.65825],[-73.51555,41.65884],[-73.51196,41.65814],[-73.51154,41.65791],[-73.51149,41.65804],[-73.5095,41.66043],[-73.50898,41.66163],[-73.50873,41.66168],[-73.42489,41.67117],[-73.39016,41.67536],[-73.38752,41.67566],[-73.38822,41.67958],[-73.39007,41.6917],[-73.38491,41.69241],[-73.38522,41.69294],[-73.38602,41.69371],[-73.38643,41.69444],[-73.38653,41.69531],[-73.3862,41.69619],[-73.38639,41.69711],[-73.38827,41.69971],[-73.3895,41.70104],[-73.38992,41.70196],[-73.38979,41.70268],[-73.40576,41.73016],[-73.40922,41.74872],[-73.40116,41.75555],[-73.39829,41.76596],[-73.39546,41.77615],[-73.42343,41.77666],[-73.42256,41.77781],[-73.42105,41.78073],[-73.41906,41.78353],[-73.41888,41.78378],[-73.44097,41.78451]]]]}' FROM towns WHERE name = 'Kent';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-71.80079,41.77365],[-71.80951,41.8951],[-71.80992,41.90064],[-71.91083,41.87615],[-71.9131,41.87408],[-71.91566,41.87258],[-71.91724,41.86931],[-71.91773,41.86869],[-71.91845,41.86829],[-71.92058,41.86804],[-71.92083,41.86764],[-71.92083,41.86582],[-71.92101,41.86535],[-71.92245,41.86475],[-71.92371,41.8646],[-71.92648,41.86548],[-71.92828,41.86541],[-71.92904,41.86493],[-71.92909,41.86491],[-71.92935,41.86474],[-71.93136,41.86287],[-71.93249,41.86094],[-71.93484,41.858],[-71.9346,41.85485],[-71.93516,41.85413],[-71.93656,41.85301],[-71.93757,41.85176],[-71.93867,41.84792],[-71.93864,41.84557],[-71.93821,41.84479],[-71.93668,41.84427],[-71.93466,41.84489],[-71.93291,41.84481],[-71.93251,41.84448],[-71.93076,41.84131],[-71.93016,41.84061],[-71.92954,41.84026],[-71.92824,41.83996],[-71.927,41.83921],[-71.92613,41.83781],[-71.92587,41.83718],[-71.92579,41.83611],[-71.92542,41.83586],[-71.9252,41.83511],[-71.92347,41.83418],[-71.92207,41.83296],[-71.92131,41.83193],[-71.92107,41.83023],[-71.92079,41.82976],[-71.91852,41.82723],[-71.91626,41.8264],[-71.91507,41.82545],[-71.91542,41.82333],[-71.91687,41.82088],[-71.9169,41.82083],[-71.91715,41.82053],[-71.91818,41.81971],[-71.91861,41.81889],[-71.91851,41.81712],[-71.91754,41.81562],[-71.91736,41.81454],[-71.91813,41.81324],[-71.91819,41.81222],[-71.91853,41.81135],[-71.91956,41.8107],[-71.92206,41.81003],[-71.9243,41.80991],[-71.92465,41.80963],[-71.92472,41.80931],[-71.92405,41.80886],[-71.92226,41.80853],[-71.92141,41.80855],[-71.91837,41.80937],[-71.91685,41.80877],[-71.91259,41.81029],[-71.91148,41.81046],[-71.90984,41.81018],[-71.90738,41.80838],[-71.90583,41.80673],[-71.90493,41.80438],[-71.90517,41.80351],[-71.90596,41.80303],[-71.908,41.80296],[-71.90883,41.80246],[-71.90896,41.80107],[-71.90774,41.80014],[-71.90668,41.79974],[-71.90642,41.79891],[-71.90664,41.79834],[-71.90607,41.79719],[-71.90555,41.79682],[-71.90232,41.79596],[-71.90026,41.79626],[-71.89967,41.79616],[-71.89714,41.7952],[-71.8972,41.79478],[-71.89669,41.79465],[-71.89646,41.7943],[-71.89626,41.79348],[-71.89642,41.79238],[-71.89584,41.79151],[-71.89532,41.78956],[-71.89599,41.78851],[-71.89778,41.78819],[-71.89864,41.78771],[-71.89984,41.78627],[-71.89925,41.78459],[-71.89917,41.78252],[-71.89798,41.77962],[-71.89818,41.77797],[-71.8996,41.77668],[-71.90187,41.7762],[-71.9042,41.77531],[-71.90857,41.77449],[-71.91166,41.77355],[-71.91333,41.7726],[-71.9143,41.7709],[-71.91517,41.76836],[-71.91515,41.76758],[-71.91468,41.76591],[-71.9153,41.76474],[-71.91568,41.76426],[-71.91815,41.76309],[-71.91864,41.76252],[-71.9197,41.76052],[-71.92193,41.75875],[-71.92239,41.75793],[-71.92259,41.75563],[-71.92286,41.75488],[-71.92431,41.75296],[-71.92478,41.75254],[-71.92598,41.75204],[-71.92634,41.75179],[-71.92595,41.75184],[-71.84811,41.76119],[-71.84758,41.76124],[-71.8475,41.76126],[-71.80033,41.76703],[-71.80079,41.77365]]]]}' FROM towns WHERE name = 'Killingly';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.53687,41.40568],[-72.56723,41.39845],[-72.60636,41.42736],[-72.60641,41.42739],[-72.60799,41.42721],[-72.63001,41.42462],[-72.64728,41.42353],[-72.65228,41.42318],[-72.65223,41.42313],[-72.6519,41.42288],[-72.652,41.42216],[-72.65231,41.42196],[-72.65223,41.42166],[-72.65276,41.42131],[-72.65275,41.42099],[-72.6508,41.41939],[-72.65033,41.41921],[-72.64796,41.42031],[-72.64745,41.42033],[-72.64651,41.41985],[-72.64679,41.41943],[-72.64656,41.41901],[-72.64669,41.41776],[-72.64703,41.41693],[-72.64768,41.41656],[-72.64762,41.41559],[-72.64624,41.41548],[-72.64418,41.4157],[-72.64361,41.4155],[-72.64331,41.41513],[-72.64248,41.41508],[-72.64023,41.41375],[-72.6409,41.41333],[-72.64096,41.41295],[-72.64189,41.41166],[-72.64293,41.41071],[-72.64337,41.40986],[-72.64448,41.40889],[-72.64654,41.40634],[-72.64725,41.40462],[-72.64813,41.4034],[-72.64746,41.4028],[-72.64701,41.40177],[-72.64681,41.40155],[-72.64577,41.40157],[-72.645,41.40117],[-72.6445,41.40059],[-72.64461,41.40027],[-72.64443,41.39992],[-72.64363,41.40012],[-72.64273,41.40002],[-72.64248,41.39969],[-72.6412,41.39917],[-72.63884,41.39766],[-72.63683,41.39706],[-72.63577,41.39654],[-72.63523,41.39638],[-72.63427,41.39681],[-72.63187,41.39625],[-72.63043,41.39658],[-72.62949,41.39618],[-72.629,41.3954],[-72.62992,41.39445],[-72.63002,41.3935],[-72.62947,41.39315],[-72.6297,41.39271],[-72.62966,41.39211],[-72.62909,41.39185],[-72.62772,41.39065],[-72.62803,41.38983],[-72.62804,41.38761],[-72.62745,41.38646],[-72.62637,41.38548],[-72.62591,41.38481],[-72.62336,41.3839],[-72.62232,41.38323],[-72.62209,41.3828],[-72.62214,41.38225],[-72.6229,41.38081],[-72.62136,41.37961],[-72.62098,41.3791],[-72.62099,41.37871],[-72.61961,41.37818],[-72.61967,41.37773],[-72.61934,41.37713],[-72.61797,41.3769],[-72.61767,41.37668],[-72.61744,41.37608],[-72.61704,41.376],[-72.61682,41.37565],[-72.61611,41.37548],[-72.61574,41.37558],[-72.6151,41.37535],[-72.61495,41.37505],[-72.61471,41.37512],[-72.6148,41.37477],[-72.61447,41.3745],[-72.61448,41.37412],[-72.6137,41.37397],[-72.61361,41.37332],[-72.61317,41.3731],[-72.61281,41.37262],[-72.6128,41.37215],[-72.61185,41.3715],[-72.61142,41.37095],[-72.60937,41.36982],[-72.60895,41.36937],[-72.60942,41.3681],[-72.61012,41.36802],[-72.6104,41.3677],[-72.61082,41.3654],[-72.6096,41.3628],[-72.60704,41.35948],[-72.60684,41.35855],[-72.60626,41.35755],[-72.60576,41.35548],[-72.60456,41.3537],[-72.60406,41.35236],[-72.6044,41.35153],[-72.60414,41.35018],[-72.60583,41.35001],[-72.60654,41.34936],[-72.60636,41.34881],[-72.60492,41.34791],[-72.60489,41.34609],[-72.60561,41.34579],[-72.60759,41.34547],[-72.60812,41.34522],[-72.6084,41.34477],[-72.60815,41.34392],[-72.6086,41.34255],[-72.60817,41.34187],[-72.60836,41.34097],[-72.60825,41.33995],[-72.60858,41.33942],[-72.60828,41.33852],[-72.60836,41.3371],[-72.60799,41.33513],[-72.60725,41.33475],[-72.60706,41.33425],[-72.60729,41.33363],[-72.60843,41.33276],[-72.60772,41.33196],[-72.6072,41.33096],[-72.6071,41.32986],[-72.60684,41.32936],[-72.60683,41.32746],[-72.60649,41.32646],[-72.60662,41.32521],[-72.60801,41.32454],[-72.60801,41.32284],[-72.60896,41.32232],[-72.60868,41.32172],[-72.60955,41.3193],[-72.60954,41.319],[-72.60872,41.3182],[-72.6093,41.31587],[-72.61,41.31547],[-72.60944,41.31472],[-72.60971,41.3132],[-72.60917,41.3114],[-72.60882,41.3112],[-72.60771,41.3113],[-72.60697,41.311],[-72.60653,41.31012],[-72.60636,41.3092],[-72.60471,41.30942],[-72.60353,41.30992],[-72.60297,41.30987],[-72.60206,41.30939],[-72.60085,41.30954],[-72.59977,41.30917],[-72.59781,41.30781],[-72.59731,41.30764],[-72.59647,41.30779],[-72.59558,41.30749],[-72.59529,41.30756],[-72.59519,41.30811],[-72.59488,41.30823],[-72.59436,41.30896],[-72.59272,41.31],[-72.59188,41.31028],[-72.59057,41.30975],[-72.59073,41.30935],[-72.58986,41.30868],[-72.58959,41.30778],[-72.58822,41.3066],[-72.58707,41.30483],[-72.5862,41.30422],[-72.58397,41.30427],[-72.58308,41.30489],[-72.58307,41.30534],[-72.58227,41.30594],[-72.58162,41.30557],[-72.5811,41.30584],[-72.58072,41.30561],[-72.58057,41.30539],[-72.58083,41.30519],[-72.58044,41.30429],[-72.58063,41.30334],[-72.57993,41.30187],[-72.57971,41.30087],[-72.57981,41.30022],[-72.58235,41.29738],[-72.58273,41.2973],[-72.58347,41.29763],[-72.58397,41.2972],[-72.5841,41.29675],[-72.58272,41.2959],[-72.58177,41.29503],[-72.58025,41.2956],[-72.57969,41.29555],[-72.57937,41.29527],[-72.57903,41.29457],[-72.57924,41.29425],[-72.58087,41.29425],[-72.5829,41.29298],[-72.58262,41.29271],[-72.58087,41.29203],[-72.58005,41.29123],[-72.58002,41.2912],[-72.57678,41.29247],[-72.5614,41.29919],[-72.55311,41.30325],[-72.54598,41.30788],[-72.53932,41.31165],[-72.52683,41.31825],[-72.5201,41.32016],[-72.51327,41.3216],[-72.50694,41.32314],[-72.507,41.32329],[-72.50906,41.32803],[-72.51358,41.34073],[-72.52016,41.35927],[-72.52016,41.3593],[-72.53637,41.40578],[-72.53687,41.40568]]]]}' FROM towns WHERE name = 'Killingworth';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.2462,41.70562],[-72.24661,41.7057],[-72.24655,41.70525],[-72.24713,41.7053],[-72.24746,41.70497],[-72.24798,41.70515],[-72.2484,41.70472],[-72.24852,41.7052],[-72.2495,41.7047],[-72.24979,41.70488],[-72.24942,41.70535],[-72.24987,41.70573],[-72.25037,41.7055],[-72.2509,41.70605],[-72.25178,41.7055],[-72.25059,41.70458],[-72.25018,41.7038],[-72.25044,41.70355],[-72.25159,41.70328],[-72.2517,41.70283],[-72.25152,41.70233],[-72.25204,41.70271],[-72.25247,41.70261],[-72.25254,41.70238],[-72.25236,41.70221],[-72.2527,41.70191],[-72.25341,41.69974],[-72.25365,41.69974],[-72.25435,41.70031],[-72.25457,41.69951],[-72.25417,41.69926],[-72.25425,41.69879],[-72.25402,41.69841],[-72.25285,41.69816],[-72.25198,41.69831],[-72.25098,41.69821],[-72.25073,41.69793],[-72.25066,41.69714],[-72.25104,41.69699],[-72.25088,41.69669],[-72.251,41.69651],[-72.25185,41.69646],[-72.25186,41.69591],[-72.25259,41.69509],[-72.25408,41.69552],[-72.25682,41.6951],[-72.25773,41.69472],[-72.25792,41.69293],[-72.25743,41.69195],[-72.25665,41.69113],[-72.25668,41.69058],[-72.25713,41.69033],[-72.2583,41.69033],[-72.26017,41.69171],[-72.26126,41.69186],[-72.26231,41.69171],[-72.26343,41.69119],[-72.26425,41.69104],[-72.26447,41.69009],[-72.26482,41.68976],[-72.26543,41.68969],[-72.26719,41.68997],[-72.26739,41.68947],[-72.26717,41.68789],[-72.26795,41.68707],[-72.26753,41.68687],[-72.26759,41.68645],[-72.26825,41.68577],[-72.26859,41.68505],[-72.26984,41.68443],[-72.26971,41.68398],[-72.27016,41.68295],[-72.27175,41.68243],[-72.27183,41.68203],[-72.27224,41.68186],[-72.27283,41.68186],[-72.27357,41.68238],[-72.27359,41.68293],[-72.27431,41.68338],[-72.27435,41.68391],[-72.27468,41.68376],[-72.27538,41.68269],[-72.2761,41.68246],[-72.27775,41.68324],[-72.27803,41.68364],[-72.27922,41.68357],[-72.27961,41.68424],[-72.28026,41.68427],[-72.2804,41.68394],[-72.28018,41.68309],[-72.28062,41.68245],[-72.28074,41.68157],[-72.27929,41.6801],[-72.27808,41.67949],[-72.27685,41.67827],[-72.27611,41.67707],[-72.27527,41.67662],[-72.27513,41.67604],[-72.2756,41.67539],[-72.2746,41.67447],[-72.27449,41.67329],[-72.27375,41.67219],[-72.27366,41.67152],[-72.27327,41.67082],[-72.27337,41.66995],[-72.27306,41.66945],[-72.27311,41.66885],[-72.27218,41.66855],[-72.27185,41.6682],[-72.27191,41.6675],[-72.27274,41.66692],[-72.27314,41.66637],[-72.27263,41.665],[-72.2735,41.66383],[-72.2739,41.66275],[-72.27385,41.66178],[-72.28415,41.66254],[-72.30777,41.64432],[-72.3387,41.63338],[-72.3311,41.61446],[-72.33054,41.61289],[-72.32842,41.60766],[-72.32476,41.60471],[-72.31674,41.59841],[-72.31318,41.60045],[-72.28549,41.5786],[-72.26569,41.56264],[-72.25405,41.55228],[-72.25176,41.54975],[-72.22905,41.53329],[-72.22121,41.52638],[-72.22073,41.52593],[-72.22023,41.5269],[-72.21828,41.53095],[-72.2056,41.56182],[-72.19792,41.5773],[-72.1973,41.57852],[-72.19544,41.58224],[-72.16583,41.64233],[-72.16312,41.64773],[-72.16399,41.64825],[-72.19022,41.6655],[-72.2048,41.67659],[-72.23462,41.69731],[-72.24267,41.70322],[-72.24395,41.70387],[-72.24535,41.70494],[-72.24563,41.70542],[-72.24595,41.70562],[-72.2462,41.70562]]]]}' FROM towns WHERE name = 'Lebanon';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-71.94171,41.41711],[-71.94126,41.41764],[-71.941,41.41853],[-71.94131,41.41911],[-71.94132,41.41963],[-71.94205,41.42101],[-71.94196,41.42141],[-71.94121,41.42226],[-71.94062,41.42355],[-71.94067,41.42383],[-71.94102,41.425],[-71.9413,41.4252],[-71.94242,41.42531],[-71.94351,41.42508],[-71.9441,41.42528],[-71.94482,41.42741],[-71.94541,41.43016],[-71.94749,41.43213],[-71.9481,41.43316],[-71.94826,41.43458],[-71.94787,41.43693],[-71.94822,41.43805],[-71.94746,41.4393],[-71.9468,41.44309],[-71.956,41.47103],[-71.9564,41.47233],[-71.97244,41.47143],[-72.01732,41.46918],[-72.02356,41.46831],[-72.03358,41.46998],[-72.03958,41.46821],[-72.03981,41.46849],[-72.03964,41.46934],[-72.03979,41.46961],[-72.04089,41.46989],[-72.04218,41.46969],[-72.04383,41.47042],[-72.04703,41.47025],[-72.04852,41.47052],[-72.0512,41.4699],[-72.05325,41.47023],[-72.05395,41.46996],[-72.05539,41.46866],[-72.0584,41.46507],[-72.05821,41.46227],[-72.05731,41.46037],[-72.05755,41.45947],[-72.05939,41.45683],[-72.06134,41.45473],[-72.06387,41.45076],[-72.06495,41.45014],[-72.06615,41.44982],[-72.06765,41.4494],[-72.06738,41.44867],[-72.06726,41.44775],[-72.06744,41.44583],[-72.06787,41.44378],[-72.06944,41.43956],[-72.07144,41.43724],[-72.0723,41.43669],[-72.08125,41.43386],[-72.08213,41.43316],[-72.08297,41.43206],[-72.0835,41.43091],[-72.08404,41.42619],[-72.0846,41.42467],[-72.08598,41.423],[-72.09059,41.42033],[-72.09235,41.41909],[-72.09406,41.41692],[-72.096,41.4126],[-72.09631,41.41095],[-72.09592,41.40915],[-72.09559,41.40858],[-72.09538,41.40825],[-72.09482,41.4072],[-72.08954,41.40143],[-72.08913,41.40013],[-72.0892,41.399],[-72.08938,41.39865],[-72.09331,41.39262],[-72.09395,41.38657],[-72.09369,41.3834],[-72.09074,41.3834],[-71.97118,41.38092],[-71.95625,41.38019],[-71.95625,41.38022],[-71.9578,41.38075],[-71.95855,41.38055],[-71.95931,41.38162],[-71.95995,41.382],[-71.96039,41.38277],[-71.96028,41.38317],[-71.95959,41.38352],[-71.95938,41.38387],[-71.95993,41.38447],[-71.96032,41.38615],[-71.96013,41.38707],[-71.9607,41.38839],[-71.96103,41.38872],[-71.96121,41.38979],[-71.96055,41.39142],[-71.96069,41.39209],[-71.96004,41.39294],[-71.96065,41.39379],[-71.96061,41.39406],[-71.96,41.39491],[-71.95869,41.39558],[-71.95797,41.39533],[-71.95736,41.39588],[-71.95592,41.3958],[-71.9551,41.39673],[-71.95464,41.39693],[-71.95459,41.39748],[-71.95424,41.39805],[-71.95426,41.39877],[-71.95261,41.40034],[-71.95222,41.40117],[-71.95146,41.40179],[-71.95161,41.40267],[-71.95148,41.40334],[-71.95055,41.40379],[-71.95063,41.40426],[-71.95009,41.40471],[-71.95061,41.40521],[-71.95004,41.40559],[-71.94998,41.40614],[-71.94887,41.40788],[-71.94496,41.41052],[-71.94419,41.41137],[-71.94315,41.41162],[-71.94148,41.41254],[-71.94107,41.41351],[-71.94172,41.41499],[-71.9415,41.41671],[-71.94171,41.41709],[-71.94171,41.41711]]]]}' FROM towns WHERE name = 'Ledyard';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-71.98259,41.62752],[-72.03593,41.63669],[-72.03598,41.63669],[-72.03597,41.63649],[-72.0353,41.63432],[-72.03568,41.63267],[-72.03668,41.63117],[-72.03649,41.62883],[-72.03708,41.6259],[-72.03678,41.62476],[-72.03681,41.62411],[-72.04008,41.62136],[-72.04456,41.6183],[-72.04497,41.61745],[-72.04467,41.6148],[-72.04481,41.61373],[-72.04454,41.61205],[-72.0442,41.61111],[-72.04314,41.60988],[-72.04286,41.60856],[-72.04206,41.60788],[-72.04174,41.60603],[-72.04121,41.60466],[-72.04026,41.60388],[-72.04058,41.60388],[-72.04024,41.60163],[-72.03942,41.60011],[-72.03951,41.59918],[-72.04192,41.59447],[-72.0446,41.5923],[-72.04598,41.59028],[-72.04732,41.58893],[-72.04781,41.58801],[-72.0481,41.58796],[-72.04859,41.58676],[-72.04909,41.58636],[-72.04948,41.58476],[-72.05077,41.58299],[-72.0511,41.58274],[-72.05089,41.58227],[-72.05049,41.58107],[-72.04943,41.57425],[-72.04836,41.57157],[-72.04757,41.57037],[-72.04504,41.56962],[-72.04453,41.56817],[-72.04823,41.56096],[-72.04818,41.55941],[-72.04866,41.55756],[-72.04851,41.55651],[-72.04707,41.55309],[-72.04576,41.55079],[-72.04534,41.54892],[-72.04593,41.54737],[-72.04706,41.5462],[-72.04793,41.54375],[-72.04815,41.54288],[-72.0476,41.54315],[-72.04583,41.5433],[-72.04468,41.54312],[-72.04003,41.54129],[-72.03787,41.54066],[-72.03461,41.54206],[-72.03156,41.54253],[-72.02924,41.54225],[-72.02604,41.54097],[-72.02497,41.54072],[-72.02205,41.54086],[-72.02094,41.54166],[-72.01926,41.54241],[-72.01864,41.54303],[-72.01657,41.5494],[-72.01502,41.55187],[-72.01468,41.55279],[-72.01432,41.55596],[-72.01392,41.55651],[-72.01274,41.55696],[-72.01118,41.55671],[-72.0098,41.55611],[-72.00687,41.5541],[-72.00273,41.55282],[-71.99967,41.55299],[-71.99602,41.55381],[-71.99458,41.55341],[-71.99386,41.55231],[-71.99329,41.55198],[-71.99211,41.55241],[-71.99188,41.55273],[-71.99177,41.55301],[-71.99159,41.55356],[-71.99159,41.55505],[-71.99076,41.55905],[-71.99108,41.56072],[-71.99241,41.56287],[-71.99256,41.56387],[-71.99159,41.56794],[-71.98895,41.57076],[-71.98758,41.57308],[-71.98773,41.57373],[-71.98989,41.57541],[-71.99021,41.57643],[-71.99005,41.57736],[-71.9885,41.57938],[-71.98814,41.5802],[-71.98806,41.58087],[-71.98834,41.58275],[-71.98794,41.58564],[-71.98845,41.58729],[-71.98965,41.58829],[-71.99043,41.59004],[-71.99129,41.59124],[-71.99209,41.59192],[-71.99394,41.59205],[-71.99483,41.59237],[-71.9952,41.59272],[-71.99534,41.59335],[-71.99443,41.5946],[-71.98868,41.59886],[-71.98662,41.60482],[-71.98704,41.6068],[-71.98689,41.6075],[-71.98496,41.61039],[-71.98326,41.61196],[-71.98282,41.61276],[-71.98279,41.61481],[-71.9838,41.61706],[-71.98392,41.61778],[-71.98358,41.61998],[-71.98265,41.62243],[-71.9808,41.6248],[-71.98011,41.62677],[-71.98007,41.62709],[-71.98259,41.62752]]]]}' FROM towns WHERE name = 'Lisbon';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.1292,41.77057],[-73.15207,41.77623],[-73.16722,41.77972],[-73.17205,41.7891],[-73.20777,41.78553],[-73.21078,41.78523],[-73.21267,41.78506],[-73.23333,41.78334],[-73.23955,41.78263],[-73.30554,41.77424],[-73.30709,41.77404],[-73.30599,41.76667],[-73.30139,41.73417],[-73.30122,41.73197],[-73.30057,41.73052],[-73.30075,41.72762],[-73.30149,41.7264],[-73.30234,41.72618],[-73.30287,41.72448],[-73.30229,41.72131],[-73.30221,41.71958],[-73.30111,41.71856],[-73.29937,41.71171],[-73.29945,41.71016],[-73.29989,41.70774],[-73.30153,41.70567],[-73.30211,41.7041],[-73.30188,41.70372],[-73.30129,41.70347],[-73.30076,41.7021],[-73.30058,41.70177],[-73.30028,41.7015],[-73.28178,41.68761],[-73.28166,41.68753],[-73.28169,41.68771],[-73.28264,41.701],[-73.20665,41.7047],[-73.17993,41.70699],[-73.16359,41.70903],[-73.14753,41.66678],[-73.14674,41.66448],[-73.14005,41.66774],[-73.0747,41.69968],[-73.07466,41.69968],[-73.07468,41.69971],[-73.07573,41.70063],[-73.07689,41.70271],[-73.07949,41.70441],[-73.0844,41.70684],[-73.08819,41.71004],[-73.09247,41.71025],[-73.09458,41.71065],[-73.09524,41.711],[-73.0958,41.71185],[-73.096,41.7128],[-73.09724,41.71345],[-73.09837,41.71478],[-73.09901,41.71501],[-73.09941,41.71543],[-73.1,41.71648],[-73.10005,41.71795],[-73.10133,41.71871],[-73.1026,41.72041],[-73.10345,41.72123],[-73.10428,41.72276],[-73.10597,41.72398],[-73.10855,41.72421],[-73.10924,41.72451],[-73.11048,41.72624],[-73.11095,41.72636],[-73.11143,41.72716],[-73.11224,41.72796],[-73.11227,41.72916],[-73.11332,41.73024],[-73.11446,41.73074],[-73.11667,41.73234],[-73.11931,41.73279],[-73.11973,41.73302],[-73.12148,41.734],[-73.12289,41.73547],[-73.12316,41.73635],[-73.12282,41.73812],[-73.1229,41.73894],[-73.12239,41.73994],[-73.12173,41.74064],[-73.12178,41.74149],[-73.12018,41.74239],[-73.11886,41.74286],[-73.11865,41.74331],[-73.11876,41.74363],[-73.12017,41.74513],[-73.12144,41.74718],[-73.12302,41.74868],[-73.12461,41.74986],[-73.12522,41.75069],[-73.12492,41.75293],[-73.1244,41.75326],[-73.12352,41.75333],[-73.12318,41.75398],[-73.12337,41.75458],[-73.1248,41.75613],[-73.12529,41.75716],[-73.12455,41.75905],[-73.12424,41.76085],[-73.12498,41.76302],[-73.12453,41.7642],[-73.12693,41.76715],[-73.12877,41.76847],[-73.12903,41.76915],[-73.12866,41.76982],[-73.12824,41.77032],[-73.1292,41.77057]]]]}' FROM towns WHERE name = 'Litchfield';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.27255,41.34985],[-72.27402,41.3576],[-72.27591,41.37091],[-72.27677,41.37444],[-72.27949,41.38913],[-72.27857,41.39644],[-72.27791,41.39797],[-72.27755,41.40219],[-72.27688,41.40551],[-72.27691,41.40988],[-72.27641,41.41352],[-72.27558,41.41737],[-72.2751,41.42394],[-72.27502,41.42466],[-72.27505,41.42466],[-72.28481,41.42353],[-72.28913,41.42164],[-72.30308,41.41684],[-72.30404,41.42046],[-72.30457,41.42038],[-72.33158,41.41723],[-72.39783,41.41026],[-72.40138,41.40894],[-72.4051,41.40897],[-72.4084,41.40935],[-72.42545,41.40733],[-72.42938,41.40779],[-72.43143,41.40806],[-72.43135,41.40796],[-72.43001,41.40549],[-72.42768,41.40229],[-72.4248,41.39881],[-72.42224,41.39359],[-72.42207,41.39194],[-72.42242,41.39079],[-72.42248,41.38954],[-72.42192,41.3846],[-72.42174,41.38415],[-72.42163,41.3838],[-72.42176,41.3826],[-72.4229,41.38095],[-72.42299,41.38053],[-72.42239,41.37833],[-72.42149,41.37668],[-72.41992,41.37468],[-72.41883,41.37383],[-72.41732,41.37315],[-72.41339,41.37242],[-72.40814,41.37254],[-72.40289,41.37166],[-72.3979,41.37155],[-72.3862,41.36963],[-72.38306,41.36865],[-72.38143,41.36788],[-72.38066,41.36735],[-72.37975,41.36608],[-72.37946,41.36545],[-72.37936,41.36525],[-72.37725,41.3614],[-72.37582,41.35985],[-72.3749,41.35938],[-72.37266,41.35902],[-72.37155,41.35857],[-72.37035,41.3576],[-72.37009,41.357],[-72.37011,41.35592],[-72.37063,41.35387],[-72.37242,41.35125],[-72.37348,41.34694],[-72.3736,41.34194],[-72.374,41.33817],[-72.37512,41.3342],[-72.37521,41.3333],[-72.37509,41.3316],[-72.37475,41.33015],[-72.37357,41.32888],[-72.36948,41.32707],[-72.36683,41.32527],[-72.36562,41.32467],[-72.36473,41.32429],[-72.35985,41.32531],[-72.35736,41.32573],[-72.35719,41.32638],[-72.35666,41.32665],[-72.35684,41.32693],[-72.35505,41.3274],[-72.35427,41.32815],[-72.35214,41.32832],[-72.35178,41.32855],[-72.35139,41.32829],[-72.35019,41.32817],[-72.35088,41.32989],[-72.35031,41.33122],[-72.34952,41.33154],[-72.34956,41.33224],[-72.33511,41.33511],[-72.33451,41.33464],[-72.33375,41.33261],[-72.33262,41.33481],[-72.33011,41.3376],[-72.32689,41.3383],[-72.32474,41.33854],[-72.319,41.33861],[-72.31419,41.34033],[-72.31035,41.341],[-72.30352,41.34261],[-72.30042,41.34305],[-72.28933,41.34596],[-72.27284,41.34893],[-72.27235,41.349],[-72.27255,41.34985]]]]}' FROM towns WHERE name = 'Lyme';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.58005,41.29123],[-72.58087,41.29203],[-72.58262,41.29271],[-72.5829,41.29298],[-72.58087,41.29425],[-72.57924,41.29425],[-72.57903,41.29457],[-72.57937,41.29527],[-72.57969,41.29555],[-72.58025,41.2956],[-72.58177,41.29503],[-72.58272,41.2959],[-72.5841,41.29675],[-72.58397,41.2972],[-72.58347,41.29763],[-72.58273,41.2973],[-72.58235,41.29738],[-72.57981,41.30022],[-72.57971,41.30087],[-72.57993,41.30187],[-72.58063,41.30334],[-72.58044,41.30429],[-72.58083,41.30519],[-72.58057,41.30539],[-72.58072,41.30561],[-72.5811,41.30584],[-72.58162,41.30557],[-72.58227,41.30594],[-72.58307,41.30534],[-72.58308,41.30489],[-72.58397,41.30427],[-72.5862,41.30422],[-72.58707,41.30483],[-72.58822,41.3066],[-72.58959,41.30778],[-72.58986,41.30868],[-72.59073,41.30935],[-72.59057,41.30975],[-72.59188,41.31028],[-72.59272,41.31],[-72.59436,41.30896],[-72.59488,41.30823],[-72.59519,41.30811],[-72.59529,41.30756],[-72.59558,41.30749],[-72.59647,41.30779],[-72.59731,41.30764],[-72.59781,41.30781],[-72.59977,41.30917],[-72.60085,41.30954],[-72.60206,41.30939],[-72.60297,41.30987],[-72.60353,41.30992],[-72.60471,41.30942],[-72.60636,41.3092],[-72.60653,41.31012],[-72.60697,41.311],[-72.60771,41.3113],[-72.60882,41.3112],[-72.60917,41.3114],[-72.60971,41.3132],[-72.60944,41.31472],[-72.61,41.31547],[-72.6093,41.31587],[-72.60872,41.3182],[-72.60954,41.319],[-72.60955,41.3193],[-72.60868,41.32172],[-72.60896,41.32232],[-72.60801,41.32284],[-72.60801,41.32454],[-72.60662,41.32521],[-72.60649,41.32646],[-72.60683,41.32746],[-72.60684,41.32936],[-72.6071,41.32986],[-72.6072,41.33096],[-72.60772,41.33196],[-72.60843,41.33276],[-72.60729,41.33363],[-72.60706,41.33425],[-72.60725,41.33475],[-72.60799,41.33513],[-72.60836,41.3371],[-72.60828,41.33852],[-72.60858,41.33942],[-72.60825,41.33995],[-72.60836,41.34097],[-72.60817,41.34187],[-72.6086,41.34255],[-72.60815,41.34392],[-72.6084,41.34477],[-72.60812,41.34522],[-72.60759,41.34547],[-72.60561,41.34579],[-72.60489,41.34609],[-72.60492,41.34791],[-72.60636,41.34881],[-72.60654,41.34936],[-72.60583,41.35001],[-72.60414,41.35018],[-72.6044,41.35153],[-72.60406,41.35236],[-72.60456,41.3537],[-72.60576,41.35548],[-72.60626,41.35755],[-72.60684,41.35855],[-72.60704,41.35948],[-72.6096,41.3628],[-72.61082,41.3654],[-72.6104,41.3677],[-72.61012,41.36802],[-72.60942,41.3681],[-72.60895,41.36937],[-72.60937,41.36982],[-72.61142,41.37095],[-72.61185,41.3715],[-72.6128,41.37215],[-72.61281,41.37262],[-72.61317,41.3731],[-72.61361,41.37332],[-72.6137,41.37397],[-72.61448,41.37412],[-72.61447,41.3745],[-72.6148,41.37477],[-72.61471,41.37512],[-72.61495,41.37505],[-72.6151,41.37535],[-72.61574,41.37558],[-72.61611,41.37548],[-72.61682,41.37565],[-72.61704,41.376],[-72.61744,41.37608],[-72.61767,41.37668],[-72.61797,41.3769],[-72.61934,41.37713],[-72.61967,41.37773],[-72.61961,41.37818],[-72.62099,41.37871],[-72.62098,41.3791],[-72.62136,41.37961],[-72.6229,41.38081],[-72.62214,41.38225],[-72.62209,41.3828],[-72.62232,41.38323],[-72.62336,41.3839],[-72.62591,41.38481],[-72.62637,41.38548],[-72.62745,41.38646],[-72.62804,41.38761],[-72.62803,41.38983],[-72.62772,41.39065],[-72.62909,41.39185],[-72.62966,41.39211],[-72.6297,41.39271],[-72.62947,41.39315],[-72.63002,41.3935],[-72.62992,41.39445],[-72.629,41.3954],[-72.62949,41.39618],[-72.63043,41.39658],[-72.63187,41.39625],[-72.63427,41.39681],[-72.63523,41.39638],[-72.63577,41.39654],[-72.63683,41.39706],[-72.63884,41.39766],[-72.6412,41.39917],[-72.64248,41.39969],[-72.64273,41.40002],[-72.64363,41.40012],[-72.64443,41.39992],[-72.64461,41.40027],[-72.6445,41.40059],[-72.645,41.40117],[-72.64577,41.40157],[-72.64681,41.40155],[-72.64701,41.40177],[-72.64746,41.4028],[-72.64813,41.4034],[-72.64725,41.40462],[-72.64654,41.40634],[-72.64448,41.40889],[-72.64337,41.40986],[-72.64293,41.41071],[-72.64189,41.41166],[-72.64096,41.41295],[-72.6409,41.41333],[-72.64023,41.41375],[-72.64248,41.41508],[-72.64331,41.41513],[-72.64361,41.4155],[-72.64418,41.4157],[-72.64624,41.41548],[-72.64762,41.41559],[-72.64768,41.41656],[-72.64703,41.41693],[-72.64669,41.41776],[-72.64656,41.41901],[-72.64679,41.41943],[-72.64651,41.41985],[-72.64745,41.42033],[-72.64796,41.42031],[-72.65033,41.41921],[-72.6508,41.41939],[-72.65275,41.42099],[-72.65276,41.42131],[-72.65223,41.42166],[-72.65231,41.42196],[-72.652,41.42216],[-72.6519,41.42288],[-72.65223,41.42313],[-72.65228,41.42318],[-72.653,41.42301],[-72.66641,41.42018],[-72.67635,41.41858],[-72.67681,41.41848],[-72.67676,41.41758],[-72.67668,41.41168],[-72.6773,41.3994],[-72.67726,41.38821],[-72.67683,41.38458],[-72.67669,41.38066],[-72.67679,41.37659],[-72.67636,41.36825],[-72.67648,41.35996],[-72.67594,41.35671],[-72.6765,41.35251],[-72.67718,41.34919],[-72.67421,41.34105],[-72.67257,41.3382],[-72.66965,41.33018],[-72.66506,41.32395],[-72.65907,41.31635],[-72.65263,41.31154],[-72.65041,41.31289],[-72.64972,41.31241],[-72.64773,41.31161],[-72.64532,41.31003],[-72.64517,41.30978],[-72.64549,41.30913],[-72.64517,41.30834],[-72.64385,41.30801],[-72.64227,41.30721],[-72.64109,41.30598],[-72.63989,41.30575],[-72.64001,41.30468],[-72.63983,41.30451],[-72.63912,41.30415],[-72.63881,41.30443],[-72.63769,41.30445],[-72.63717,41.30415],[-72.63647,41.30275],[-72.63676,41.30193],[-72.63587,41.30125],[-72.63488,41.30008],[-72.63419,41.2997],[-72.63369,41.29988],[-72.63283,41.29952],[-72.63184,41.29825],[-72.63095,41.29782],[-72.63044,41.29695],[-72.63048,41.29672],[-72.63082,41.2966],[-72.63065,41.29597],[-72.63084,41.29568],[-72.63029,41.29513],[-72.63024,41.29425],[-72.62984,41.29408],[-72.62878,41.29417],[-72.62875,41.2934],[-72.62768,41.29257],[-72.62721,41.29147],[-72.62665,41.29135],[-72.62605,41.2907],[-72.62652,41.2894],[-72.62721,41.2889],[-72.6271,41.2885],[-72.62744,41.28763],[-72.62748,41.2865],[-72.62773,41.28608],[-72.62774,41.28556],[-72.62819,41.28511],[-72.62755,41.28418],[-72.62717,41.28023],[-72.63779,41.27208],[-72.63851,41.27116],[-72.63887,41.27001],[-72.63957,41.26994],[-72.64019,41.27054],[-72.64076,41.27071],[-72.64122,41.27062],[-72.6421,41.26992],[-72.64095,41.26887],[-72.63986,41.26842],[-72.63966,41.26814],[-72.63983,41.26744],[-72.64075,41.26714],[-72.6409,41.26649],[-72.64009,41.26467],[-72.63832,41.26322],[-72.63799,41.26274],[-72.63776,41.26164],[-72.63797,41.26132],[-72.63898,41.26097],[-72.63957,41.2611],[-72.64004,41.26155],[-72.64072,41.2615],[-72.64253,41.26018],[-72.64249,41.25973],[-72.64108,41.25645],[-72.64126,41.25605],[-72.64228,41.25511],[-72.64281,41.25481],[-72.64373,41.25473],[-72.64535,41.25519],[-72.64734,41.25434],[-72.64767,41.25394],[-72.64785,41.25287],[-72.64731,41.25169],[-72.64734,41.25129],[-72.6481,41.25045],[-72.64928,41.25012],[-72.64713,41.24572],[-72.64708,41.24575],[-72.64726,41.2462],[-72.64715,41.24632],[-72.64399,41.24662],[-72.64321,41.24637],[-72.64308,41.24614],[-72.6433,41.24604],[-72.64299,41.24549],[-72.64226,41.24547],[-72.63714,41.24663],[-72.63688,41.24688],[-72.63567,41.24718],[-72.63259,41.2474],[-72.63142,41.24705],[-72.63157,41.24735],[-72.63114,41.24762],[-72.62964,41.24765],[-72.62929,41.24759],[-72.62931,41.24744],[-72.62596,41.24841],[-72.62492,41.24831],[-72.62476,41.24814],[-72.6245,41.24831],[-72.62482,41.24836],[-72.62499,41.24909],[-72.62397,41.25006],[-72.62314,41.25056],[-72.62046,41.25123],[-72.61968,41.2512],[-72.6186,41.25157],[-72.61875,41.25177],[-72.61858,41.25185],[-72.61672,41.25215],[-72.61492,41.25202],[-72.61474,41.25187],[-72.61483,41.25172],[-72.61461,41.25164],[-72.61427,41.25169],[-72.61419,41.25202],[-72.61388,41.25219],[-72.6122,41.25214],[-72.61225,41.25226],[-72.61193,41.25229],[-72.61107,41.25201],[-72.6107,41.25239],[-72.6091,41.25248],[-72.60319,41.25095],[-72.60201,41.25045],[-72.60202,41.24993],[-72.60176,41.25028],[-72.60049,41.24995],[-72.60118,41.25022],[-72.60111,41.25045],[-72.6008,41.2508],[-72.60006,41.25105],[-72.59918,41.251],[-72.59817,41.25124],[-72.59688,41.25114],[-72.5961,41.25102],[-72.59429,41.25006],[-72.59425,41.24959],[-72.5945,41.24956],[-72.59408,41.24951],[-72.59409,41.24979],[-72.59396,41.24959],[-72.59288,41.24929],[-72.59132,41.24909],[-72.5885,41.24998],[-72.58732,41.25023],[-72.587,41.2501],[-72.58655,41.25033],[-72.58382,41.2503],[-72.5826,41.25],[-72.58253,41.25015],[-72.58304,41.2502],[-72.58272,41.2509],[-72.58197,41.25107],[-72.58097,41.25097],[-72.58057,41.25119],[-72.57986,41.25117],[-72.57941,41.25144],[-72.57856,41.25151],[-72.5784,41.25169],[-72.57926,41.25201],[-72.57914,41.25224],[-72.57865,41.25209],[-72.57652,41.25233],[-72.57242,41.25173],[-72.57024,41.25113],[-72.56574,41.24882],[-72.56351,41.24804],[-72.56312,41.24814],[-72.56337,41.24762],[-72.56311,41.24789],[-72.56265,41.24777],[-72.56052,41.24667],[-72.55434,41.24244],[-72.54733,41.23708],[-72.54064,41.2313],[-72.53873,41.2291],[-72.53867,41.22875],[-72.53838,41.22887],[-72.53658,41.22817],[-72.5356,41.22847],[-72.53451,41.22947],[-72.53396,41.22972],[-72.5308,41.22984],[-72.52903,41.23143],[-72.52956,41.23213],[-72.52975,41.23306],[-72.53039,41.23311],[-72.53084,41.23351],[-72.5306,41.23433],[-72.52964,41.23496],[-72.52831,41.23448],[-72.52839,41.2345],[-72.53509,41.23826],[-72.53529,41.23841],[-72.53504,41.23903],[-72.53538,41.23911],[-72.53563,41.23929],[-72.53714,41.23936],[-72.53757,41.23984],[-72.54019,41.24047],[-72.5405,41.24127],[-72.54107,41.24152],[-72.54075,41.24217],[-72.54091,41.24424],[-72.54069,41.24496],[-72.53976,41.24638],[-72.53963,41.24698],[-72.54026,41.24841],[-72.5401,41.25056],[-72.54026,41.25163],[-72.53998,41.2521],[-72.53777,41.2538],[-72.53807,41.25502],[-72.53839,41.25502],[-72.53852,41.25532],[-72.53835,41.2554],[-72.53915,41.256],[-72.53973,41.25613],[-72.53949,41.25622],[-72.54025,41.25698],[-72.54094,41.2573],[-72.54109,41.25768],[-72.54026,41.259],[-72.53934,41.26],[-72.53928,41.2604],[-72.53948,41.2607],[-72.54138,41.26157],[-72.54218,41.2622],[-72.54257,41.26215],[-72.54445,41.26088],[-72.54581,41.26108],[-72.54802,41.26096],[-72.55037,41.26226],[-72.55068,41.26284],[-72.55072,41.26398],[-72.55119,41.26438],[-72.55284,41.26509],[-72.55401,41.26696],[-72.55431,41.26784],[-72.55607,41.26904],[-72.55756,41.26916],[-72.55825,41.26969],[-72.55826,41.27081],[-72.55902,41.27194],[-72.55945,41.27316],[-72.56068,41.27367],[-72.56117,41.27464],[-72.56159,41.27494],[-72.56291,41.27514],[-72.56383,41.27504],[-72.56401,41.27519],[-72.56461,41.27659],[-72.56402,41.27709],[-72.56398,41.27752],[-72.56573,41.27834],[-72.56559,41.27854],[-72.56472,41.27854],[-72.5648,41.27879],[-72.56761,41.28004],[-72.56811,41.28054],[-72.57004,41.28175],[-72.57043,41.2825],[-72.5704,41.28327],[-72.57145,41.28407],[-72.57344,41.28485],[-72.57604,41.28488],[-72.57595,41.28525],[-72.57529,41.28585],[-72.57548,41.28643],[-72.57653,41.28728],[-72.57898,41.28788],[-72.57987,41.28843],[-72.58,41.28876],[-72.57965,41.28935],[-72.58014,41.29033],[-72.58,41.29118],[-72.58002,41.2912],[-72.58005,41.29123]]],[[[-72.59565,41.24667],[-72.59534,41.24607],[-72.59462,41.24577],[-72.59385,41.24589],[-72.59446,41.24669],[-72.59485,41.24674],[-72.59565,41.24667]]]]}' FROM towns WHERE name = 'Madison';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.48324,41.79355],[-72.4862,41.80781],[-72.50935,41.80487],[-72.51747,41.80414],[-72.51658,41.8101],[-72.51569,41.81525],[-72.5174,41.8148],[-72.56947,41.80139],[-72.59311,41.79531],[-72.59261,41.79299],[-72.58093,41.7371],[-72.57874,41.72643],[-72.57465,41.72692],[-72.48169,41.73722],[-72.472,41.73828],[-72.47242,41.73931],[-72.47217,41.73988],[-72.47258,41.74233],[-72.47307,41.74305],[-72.48192,41.78697],[-72.48307,41.79267],[-72.48324,41.79355]]]]}' FROM towns WHERE name = 'Manchester';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.23354,41.83023],[-72.30768,41.83114],[-72.32081,41.83126],[-72.32089,41.83061],[-72.32056,41.82949],[-72.31895,41.82774],[-72.32058,41.82619],[-72.32123,41.82437],[-72.32213,41.8241],[-72.32359,41.8242],[-72.32436,41.82328],[-72.32454,41.82288],[-72.32443,41.82225],[-72.32322,41.82182],[-72.32325,41.82123],[-72.32374,41.82033],[-72.32357,41.81893],[-72.32423,41.81713],[-72.32385,41.81603],[-72.32324,41.81508],[-72.32389,41.81313],[-72.32514,41.81191],[-72.32674,41.81099],[-72.32687,41.81059],[-72.32663,41.80887],[-72.32493,41.80749],[-72.32479,41.80674],[-72.3249,41.80472],[-72.32524,41.80332],[-72.32637,41.80282],[-72.32648,41.80242],[-72.32588,41.802],[-72.32567,41.80127],[-72.32572,41.79963],[-72.31958,41.79684],[-72.31905,41.79607],[-72.31914,41.79519],[-72.31958,41.79435],[-72.32091,41.7935],[-72.32205,41.79343],[-72.32237,41.7926],[-72.32149,41.79163],[-72.31856,41.7903],[-72.31633,41.78992],[-72.31527,41.78932],[-72.31474,41.78939],[-72.31354,41.79094],[-72.3122,41.79146],[-72.31164,41.79084],[-72.30978,41.79118],[-72.30644,41.79006],[-72.30587,41.7897],[-72.30544,41.7889],[-72.30442,41.78915],[-72.30242,41.78885],[-72.30092,41.78915],[-72.29137,41.77939],[-72.28984,41.77804],[-72.28922,41.77509],[-72.28955,41.77295],[-72.28495,41.77047],[-72.28414,41.76937],[-72.284,41.76884],[-72.28413,41.76757],[-72.28397,41.76704],[-72.28218,41.76574],[-72.28056,41.76419],[-72.28041,41.76349],[-72.28074,41.76227],[-72.28079,41.76005],[-72.27942,41.75892],[-72.27923,41.75827],[-72.27952,41.75727],[-72.27932,41.75712],[-72.27877,41.75732],[-72.27803,41.75717],[-72.27721,41.75559],[-72.27744,41.75502],[-72.27888,41.75445],[-72.27932,41.75305],[-72.27777,41.75065],[-72.27599,41.74892],[-72.27409,41.74575],[-72.27371,41.74345],[-72.27386,41.74275],[-72.27532,41.74036],[-72.2761,41.73963],[-72.27744,41.73901],[-72.27762,41.73869],[-72.27735,41.73836],[-72.27618,41.73831],[-72.27544,41.73803],[-72.2754,41.73753],[-72.2762,41.73729],[-72.27331,41.73603],[-72.2724,41.73461],[-72.27269,41.73393],[-72.27261,41.73366],[-72.27138,41.73301],[-72.27107,41.73258],[-72.27126,41.73168],[-72.27032,41.73113],[-72.27014,41.73078],[-72.27015,41.73021],[-72.27064,41.72961],[-72.27066,41.72931],[-72.26934,41.72913],[-72.26909,41.72886],[-72.26923,41.72778],[-72.26863,41.72726],[-72.26801,41.72616],[-72.26887,41.72489],[-72.26811,41.72384],[-72.26715,41.72196],[-72.26325,41.72143],[-72.26126,41.7216],[-72.26039,41.72105],[-72.25993,41.7203],[-72.25986,41.72025],[-72.25721,41.72027],[-72.21865,41.72001],[-72.20538,41.72017],[-72.20498,41.72039],[-72.20514,41.72097],[-72.20556,41.72124],[-72.20644,41.72127],[-72.20659,41.72159],[-72.20636,41.72347],[-72.2061,41.72369],[-72.20537,41.72359],[-72.20449,41.72264],[-72.2024,41.72199],[-72.20007,41.72218],[-72.19923,41.72256],[-72.19844,41.72353],[-72.19847,41.72455],[-72.19893,41.72538],[-72.20004,41.72663],[-72.20053,41.72688],[-72.20162,41.72691],[-72.20267,41.72761],[-72.20332,41.72908],[-72.20337,41.73166],[-72.20294,41.73253],[-72.20248,41.73285],[-72.20226,41.73435],[-72.20156,41.73615],[-72.20057,41.73712],[-72.19816,41.73852],[-72.19719,41.73941],[-72.19676,41.74066],[-72.19739,41.74214],[-72.19732,41.74289],[-72.19651,41.74358],[-72.19455,41.74368],[-72.19402,41.74393],[-72.19374,41.74433],[-72.1939,41.74498],[-72.19541,41.74588],[-72.19611,41.74653],[-72.19622,41.74755],[-72.19575,41.748],[-72.19488,41.7483],[-72.1931,41.7483],[-72.19125,41.74875],[-72.18988,41.74862],[-72.18807,41.74812],[-72.18644,41.74821],[-72.18614,41.74794],[-72.18605,41.74641],[-72.18662,41.74599],[-72.18673,41.74567],[-72.18647,41.74522],[-72.18437,41.74394],[-72.18473,41.74312],[-72.18452,41.74262],[-72.18376,41.74229],[-72.18308,41.74241],[-72.18299,41.74336],[-72.18258,41.74356],[-72.18028,41.74134],[-72.17941,41.74086],[-72.17882,41.74123],[-72.17824,41.74303],[-72.17777,41.7437],[-72.17706,41.7442],[-72.17659,41.74485],[-72.17599,41.74495],[-72.17445,41.74462],[-72.17398,41.7443],[-72.1736,41.74307],[-72.17325,41.74267],[-72.1717,41.74222],[-72.16982,41.74269],[-72.16875,41.74262],[-72.16731,41.74329],[-72.16702,41.74404],[-72.16675,41.74421],[-72.16581,41.74436],[-72.16452,41.74606],[-72.16496,41.74613],[-72.17543,41.82977],[-72.17543,41.82979],[-72.17947,41.82982],[-72.202,41.82983],[-72.22719,41.8302],[-72.22827,41.83022],[-72.23354,41.83023]]]]}' FROM towns WHERE name = 'Mansfield';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.41334,41.59133],[-72.41291,41.59628],[-72.41311,41.61186],[-72.41198,41.61459],[-72.41252,41.61566],[-72.41339,41.61961],[-72.41364,41.61976],[-72.41354,41.62048],[-72.41592,41.62288],[-72.41836,41.62473],[-72.41861,41.62511],[-72.4188,41.62648],[-72.41869,41.62681],[-72.41899,41.62706],[-72.41883,41.62743],[-72.41976,41.62848],[-72.4344,41.65286],[-72.45392,41.66273],[-72.45835,41.67989],[-72.48263,41.67716],[-72.48774,41.67324],[-72.48249,41.66457],[-72.4814,41.65977],[-72.50225,41.65326],[-72.50975,41.63903],[-72.51055,41.63754],[-72.51053,41.63751],[-72.48488,41.59686],[-72.47271,41.57716],[-72.46952,41.57199],[-72.46913,41.57201],[-72.41965,41.57883],[-72.41331,41.59101],[-72.41331,41.59111],[-72.41334,41.59133]]]]}' FROM towns WHERE name = 'Marlborough';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.75184,41.54517],[-72.75257,41.54899],[-72.7545,41.56648],[-72.7546,41.56745],[-72.76127,41.56694],[-72.78402,41.5642],[-72.80323,41.56148],[-72.80686,41.55502],[-72.81086,41.55013],[-72.81198,41.55078],[-72.81747,41.54507],[-72.82045,41.5424],[-72.82127,41.5422],[-72.8238,41.54221],[-72.82545,41.54278],[-72.8278,41.54413],[-72.82754,41.55268],[-72.83188,41.55046],[-72.83019,41.55915],[-72.8419,41.55734],[-72.84461,41.55692],[-72.85068,41.55583],[-72.8486,41.55053],[-72.84771,41.54282],[-72.84603,41.53287],[-72.8526,41.53391],[-72.85467,41.53418],[-72.85315,41.52809],[-72.85464,41.52112],[-72.85888,41.52218],[-72.86202,41.51586],[-72.86107,41.51491],[-72.85878,41.51488],[-72.85854,41.51414],[-72.85392,41.51248],[-72.85458,41.50941],[-72.85187,41.50319],[-72.848,41.50238],[-72.81807,41.49624],[-72.79612,41.49149],[-72.7829,41.48675],[-72.77268,41.48336],[-72.74785,41.48457],[-72.74482,41.48482],[-72.74529,41.48809],[-72.7461,41.49641],[-72.75009,41.53063],[-72.75022,41.53175],[-72.75184,41.54517]]]]}' FROM towns WHERE name = 'Meriden';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.08158,41.51395],[-73.089,41.52785],[-73.09526,41.5566],[-73.09585,41.5592],[-73.09648,41.55905],[-73.12144,41.5524],[-73.14589,41.54676],[-73.14695,41.54654],[-73.15414,41.54625],[-73.1663,41.54575],[-73.15747,41.50929],[-73.1557,41.5019],[-73.15574,41.50175],[-73.15599,41.50105],[-73.15578,41.50072],[-73.156,41.50005],[-73.15572,41.4994],[-73.1558,41.49905],[-73.15597,41.49888],[-73.15641,41.49915],[-73.15726,41.4989],[-73.15741,41.4983],[-73.15835,41.49718],[-73.15812,41.49686],[-73.15842,41.49636],[-73.15836,41.49591],[-73.15862,41.49571],[-73.15753,41.49426],[-73.15759,41.49386],[-73.15691,41.49291],[-73.15736,41.49253],[-73.15749,41.49214],[-73.15723,41.49081],[-73.15759,41.49051],[-73.15741,41.49026],[-73.15757,41.48986],[-73.15791,41.48974],[-73.15793,41.48891],[-73.15854,41.48819],[-73.15909,41.48799],[-73.15965,41.48634],[-73.16032,41.48515],[-73.16015,41.48435],[-73.15985,41.4842],[-73.15884,41.48434],[-73.15787,41.48362],[-73.1571,41.48219],[-73.1571,41.48082],[-73.15666,41.48],[-73.15715,41.47925],[-73.15668,41.47812],[-73.15692,41.47727],[-73.15746,41.47658],[-73.15746,41.47648],[-73.15721,41.47645],[-73.14189,41.47535],[-73.12446,41.47448],[-73.10858,41.47575],[-73.1039,41.47609],[-73.10397,41.47679],[-73.10434,41.47784],[-73.10425,41.47894],[-73.10331,41.48146],[-73.10209,41.48226],[-73.10193,41.48259],[-73.10205,41.48338],[-73.10157,41.48426],[-73.10102,41.48483],[-73.0995,41.48515],[-73.09888,41.48635],[-73.09747,41.48777],[-73.09538,41.48877],[-73.09546,41.48967],[-73.09682,41.49379],[-73.09602,41.49456],[-73.09594,41.49604],[-73.09485,41.49926],[-73.09469,41.50131],[-73.07093,41.50172],[-73.06993,41.50172],[-73.08158,41.51395]]]]}' FROM towns WHERE name = 'Middlebury';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.67862,41.48744],[-72.68377,41.51809],[-72.68887,41.52367],[-72.69605,41.53759],[-72.71322,41.53542],[-72.71349,41.53627],[-72.72487,41.53584],[-72.73491,41.5341],[-72.73557,41.53183],[-72.73648,41.53143],[-72.7493,41.53173],[-72.75022,41.53175],[-72.75009,41.53063],[-72.7461,41.49641],[-72.74529,41.48809],[-72.74482,41.48482],[-72.74272,41.46968],[-72.73354,41.47179],[-72.73347,41.47181],[-72.71649,41.47546],[-72.69633,41.47922],[-72.67915,41.48104],[-72.67818,41.48247],[-72.67787,41.48289],[-72.67862,41.48744]]]]}' FROM towns WHERE name = 'Middlefield';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.65423,41.56143],[-72.6552,41.56138],[-72.65646,41.56051],[-72.65703,41.56151],[-72.65866,41.56169],[-72.65884,41.56458],[-72.65845,41.56528],[-72.65793,41.56571],[-72.65796,41.56601],[-72.65838,41.56611],[-72.65949,41.56586],[-72.6606,41.56721],[-72.66122,41.56751],[-72.66262,41.56721],[-72.66323,41.56729],[-72.66372,41.56826],[-72.66484,41.56929],[-72.66613,41.56999],[-72.66804,41.57049],[-72.66931,41.57154],[-72.67072,41.57237],[-72.67115,41.57362],[-72.67107,41.57434],[-72.6708,41.57482],[-72.67039,41.57514],[-72.66862,41.57526],[-72.66831,41.57551],[-72.66828,41.57599],[-72.66943,41.57701],[-72.67002,41.57799],[-72.66999,41.57869],[-72.66962,41.57929],[-72.67028,41.58029],[-72.6707,41.58044],[-72.67208,41.58019],[-72.67278,41.58034],[-72.67606,41.58352],[-72.67629,41.58464],[-72.67599,41.58504],[-72.67506,41.58551],[-72.67474,41.58649],[-72.67571,41.58784],[-72.67727,41.58889],[-72.67831,41.58876],[-72.67937,41.58744],[-72.68054,41.58694],[-72.68149,41.58625],[-72.68266,41.58612],[-72.68364,41.58655],[-72.68372,41.5884],[-72.68422,41.58962],[-72.68769,41.59113],[-72.68898,41.591],[-72.69264,41.58986],[-72.69565,41.59064],[-72.69645,41.59037],[-72.69745,41.59059],[-72.69829,41.59034],[-72.6996,41.58947],[-72.70077,41.59025],[-72.7035,41.59123],[-72.70438,41.59145],[-72.70659,41.59155],[-72.70872,41.59268],[-72.70971,41.59256],[-72.71112,41.59186],[-72.71159,41.59204],[-72.71182,41.59241],[-72.71252,41.59286],[-72.71363,41.59199],[-72.7161,41.59172],[-72.71662,41.59199],[-72.71748,41.59319],[-72.71769,41.59347],[-72.71871,41.5934],[-72.73059,41.59167],[-72.7305,41.59102],[-72.7335,41.5906],[-72.73467,41.5869],[-72.73485,41.5848],[-72.7562,41.58171],[-72.75463,41.56765],[-72.7546,41.56745],[-72.7545,41.56648],[-72.75257,41.54899],[-72.75184,41.54517],[-72.75022,41.53175],[-72.7493,41.53173],[-72.73648,41.53143],[-72.73557,41.53183],[-72.73491,41.5341],[-72.72487,41.53584],[-72.71349,41.53627],[-72.71322,41.53542],[-72.69605,41.53759],[-72.68887,41.52367],[-72.68377,41.51809],[-72.67862,41.48744],[-72.67787,41.48289],[-72.67787,41.48287],[-72.6776,41.48124],[-72.64328,41.48529],[-72.63384,41.48642],[-72.57754,41.49448],[-72.55525,41.49767],[-72.55505,41.49769],[-72.55524,41.49829],[-72.55599,41.50039],[-72.55636,41.50231],[-72.55667,41.50598],[-72.55663,41.51195],[-72.55541,41.51585],[-72.55357,41.51942],[-72.55305,41.52081],[-72.55202,41.52521],[-72.55198,41.52651],[-72.55247,41.52813],[-72.55272,41.5315],[-72.55352,41.53383],[-72.55496,41.53638],[-72.55579,41.53738],[-72.55919,41.54048],[-72.562,41.54186],[-72.56595,41.54291],[-72.56604,41.54291],[-72.56631,41.54296],[-72.56953,41.54327],[-72.57458,41.5447],[-72.58202,41.54566],[-72.5897,41.54642],[-72.59702,41.54633],[-72.60253,41.54769],[-72.60764,41.55034],[-72.61121,41.55105],[-72.61518,41.55068],[-72.62286,41.54892],[-72.63472,41.54889],[-72.63896,41.54847],[-72.64094,41.54797],[-72.64218,41.54807],[-72.64348,41.54853],[-72.64504,41.54948],[-72.64684,41.55098],[-72.64923,41.55365],[-72.64997,41.55495],[-72.65074,41.55715],[-72.65151,41.56008],[-72.65148,41.56138],[-72.65146,41.56142],[-72.65423,41.56143]]]]}' FROM towns WHERE name = 'Middletown';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.99174,41.22659],[-73.02646,41.23416],[-73.04442,41.23953],[-73.07335,41.25511],[-73.0796,41.25849],[-73.07979,41.25824],[-73.08,41.25642],[-73.08094,41.25362],[-73.08123,41.25113],[-73.08107,41.24908],[-73.0803,41.24556],[-73.08042,41.24139],[-73.08004,41.23909],[-73.07924,41.23611],[-73.07916,41.23589],[-73.07913,41.23397],[-73.07943,41.23269],[-73.08054,41.23065],[-73.08287,41.22733],[-73.08388,41.22633],[-73.08548,41.22526],[-73.0893,41.22207],[-73.09358,41.2198],[-73.09527,41.21858],[-73.09662,41.21671],[-73.09814,41.21384],[-73.10083,41.21035],[-73.10188,41.20803],[-73.10202,41.20635],[-73.10166,41.20318],[-73.09977,41.19866],[-73.09968,41.19793],[-73.10003,41.19666],[-73.1009,41.19509],[-73.1017,41.19179],[-73.10181,41.18792],[-73.10001,41.18302],[-73.10025,41.1813],[-73.10123,41.17923],[-73.09971,41.1789],[-73.10024,41.1786],[-73.10135,41.17688],[-73.10178,41.17421],[-73.10159,41.17416],[-73.10165,41.17386],[-73.10143,41.17371],[-73.10152,41.17288],[-73.10113,41.17278],[-73.10104,41.17228],[-73.1014,41.17211],[-73.10163,41.17169],[-73.10062,41.17208],[-73.10033,41.17201],[-73.09986,41.17083],[-73.09827,41.16903],[-73.09677,41.16988],[-73.0965,41.16983],[-73.09648,41.17003],[-73.09626,41.16998],[-73.0962,41.1705],[-73.09586,41.17055],[-73.09568,41.17088],[-73.09536,41.17073],[-73.09544,41.17098],[-73.09363,41.1716],[-73.09218,41.17267],[-73.09209,41.17429],[-73.09154,41.17484],[-73.09169,41.17499],[-73.0923,41.17492],[-73.09207,41.17527],[-73.09225,41.17552],[-73.09206,41.17569],[-73.09097,41.17604],[-73.08881,41.17599],[-73.08927,41.17591],[-73.08981,41.17546],[-73.09091,41.17557],[-73.09107,41.17522],[-73.09083,41.17464],[-73.09129,41.17352],[-73.09058,41.17202],[-73.09086,41.17137],[-73.09119,41.17124],[-73.09017,41.17124],[-73.08998,41.17044],[-73.08963,41.17024],[-73.08972,41.16997],[-73.09028,41.16984],[-73.09026,41.16867],[-73.08982,41.16837],[-73.09023,41.1682],[-73.09018,41.16762],[-73.08979,41.16752],[-73.08981,41.1672],[-73.09014,41.1671],[-73.09036,41.16632],[-73.08953,41.16615],[-73.08923,41.1658],[-73.08959,41.16575],[-73.09026,41.16607],[-73.09061,41.1657],[-73.0924,41.16535],[-73.09277,41.16555],[-73.09284,41.1653],[-73.09242,41.16518],[-73.09181,41.1644],[-73.09115,41.16403],[-73.09089,41.1636],[-73.09069,41.1625],[-73.09033,41.16208],[-73.08934,41.16148],[-73.08945,41.16103],[-73.08929,41.16068],[-73.08842,41.15993],[-73.08789,41.15995],[-73.08803,41.15973],[-73.08763,41.1596],[-73.08715,41.15878],[-73.08807,41.15873],[-73.08816,41.1585],[-73.089,41.15818],[-73.08943,41.15775],[-73.08941,41.15728],[-73.08977,41.15706],[-73.08914,41.15641],[-73.08922,41.15593],[-73.09084,41.15533],[-73.09171,41.15456],[-73.09227,41.15376],[-73.09522,41.15177],[-73.09731,41.1516],[-73.09826,41.15085],[-73.09956,41.15113],[-73.09988,41.15138],[-73.10005,41.15203],[-73.09967,41.15255],[-73.10023,41.15263],[-73.1006,41.15195],[-73.10026,41.15123],[-73.09952,41.15073],[-73.09845,41.15055],[-73.09707,41.15075],[-73.09574,41.15105],[-73.09295,41.15227],[-73.09116,41.15229],[-73.0901,41.15351],[-73.08885,41.15388],[-73.08871,41.15421],[-73.08832,41.15318],[-73.08881,41.15253],[-73.08989,41.15211],[-73.09024,41.15166],[-73.09091,41.15129],[-73.09146,41.15042],[-73.09195,41.15029],[-73.09179,41.15007],[-73.09191,41.14997],[-73.09295,41.14969],[-73.09188,41.14959],[-73.09126,41.15002],[-73.0904,41.15131],[-73.08992,41.15146],[-73.08971,41.15189],[-73.08849,41.15253],[-73.08788,41.15331],[-73.08698,41.15565],[-73.08592,41.15762],[-73.08379,41.16017],[-73.08325,41.16019],[-73.08342,41.16029],[-73.08222,41.16224],[-73.08172,41.16256],[-73.0818,41.16274],[-73.08105,41.16389],[-73.0792,41.16613],[-73.07787,41.16713],[-73.07492,41.16992],[-73.07441,41.1702],[-73.07425,41.17052],[-73.07374,41.17059],[-73.07211,41.17199],[-73.0693,41.17383],[-73.06356,41.17612],[-73.06189,41.17637],[-73.06134,41.17607],[-73.06096,41.17532],[-73.06124,41.17584],[-73.06032,41.17617],[-73.0567,41.17689],[-73.05625,41.17656],[-73.05643,41.17671],[-73.05593,41.17711],[-73.05567,41.17808],[-73.05509,41.17901],[-73.05548,41.17926],[-73.05468,41.18071],[-73.05245,41.18347],[-73.05039,41.18544],[-73.04816,41.18704],[-73.04591,41.18828],[-73.0431,41.18943],[-73.04278,41.18933],[-73.04083,41.18983],[-73.04082,41.19042],[-73.04051,41.1905],[-73.04076,41.19082],[-73.04117,41.1906],[-73.0427,41.19078],[-73.04327,41.19103],[-73.04341,41.19145],[-73.04384,41.19135],[-73.0444,41.19155],[-73.04431,41.19168],[-73.04516,41.19238],[-73.04553,41.19245],[-73.04615,41.19203],[-73.04649,41.19213],[-73.0468,41.19193],[-73.04884,41.19286],[-73.04772,41.19341],[-73.04774,41.19401],[-73.04806,41.19436],[-73.04727,41.19453],[-73.04814,41.19466],[-73.04747,41.19493],[-73.0484,41.19498],[-73.04834,41.19553],[-73.04873,41.19563],[-73.04788,41.19548],[-73.04747,41.19575],[-73.04756,41.19618],[-73.04721,41.19683],[-73.04749,41.19713],[-73.04728,41.19825],[-73.04684,41.1983],[-73.04591,41.19798],[-73.04587,41.1982],[-73.04616,41.19828],[-73.04606,41.199],[-73.04623,41.19902],[-73.04606,41.19905],[-73.04624,41.1991],[-73.04607,41.1991],[-73.04597,41.20002],[-73.04673,41.20087],[-73.04703,41.2022],[-73.04673,41.20192],[-73.04667,41.20155],[-73.04528,41.20032],[-73.04531,41.19977],[-73.04509,41.19977],[-73.0451,41.19935],[-73.04452,41.19872],[-73.04453,41.1981],[-73.04492,41.19745],[-73.04463,41.19735],[-73.04381,41.19757],[-73.04431,41.19715],[-73.04496,41.19707],[-73.04488,41.19695],[-73.04517,41.19685],[-73.04457,41.1953],[-73.0448,41.195],[-73.0444,41.19448],[-73.04415,41.19368],[-73.04174,41.1919],[-73.04042,41.1917],[-73.04053,41.1919],[-73.03943,41.19192],[-73.03845,41.19154],[-73.03812,41.19124],[-73.03818,41.1908],[-73.03795,41.19022],[-73.03775,41.19027],[-73.03765,41.19005],[-73.03796,41.18975],[-73.03948,41.18945],[-73.03924,41.18945],[-73.03918,41.18922],[-73.03706,41.18905],[-73.03635,41.18877],[-73.03587,41.18829],[-73.03581,41.18782],[-73.03492,41.18729],[-73.03416,41.18629],[-73.03198,41.18429],[-73.03072,41.18269],[-73.02968,41.18194],[-73.02896,41.18082],[-73.02841,41.18089],[-73.02824,41.18119],[-73.02838,41.18176],[-73.02807,41.18189],[-73.02846,41.18194],[-73.02859,41.18229],[-73.0284,41.18314],[-73.02578,41.18508],[-73.02353,41.18608],[-73.02019,41.18647],[-73.01901,41.18682],[-73.01399,41.18664],[-73.01154,41.18593],[-73.01119,41.18561],[-73.01102,41.18481],[-73.01101,41.18354],[-73.01122,41.18324],[-73.01003,41.18343],[-73.00879,41.18333],[-73.00653,41.1835],[-73.005,41.18413],[-73.00081,41.18872],[-73.00043,41.18901],[-72.99932,41.18934],[-72.99882,41.19056],[-72.99825,41.19348],[-72.99684,41.19743],[-72.99689,41.19818],[-72.99582,41.19992],[-72.99578,41.20022],[-72.99613,41.20047],[-72.99574,41.20105],[-72.99388,41.20239],[-72.99207,41.20289],[-72.99155,41.20264],[-72.99121,41.20161],[-72.99103,41.20219],[-72.98978,41.20266],[-72.98891,41.20206],[-72.9883,41.20188],[-72.9884,41.20203],[-72.98789,41.20276],[-72.98734,41.20316],[-72.98658,41.20308],[-72.98635,41.20256],[-72.98491,41.20318],[-72.98407,41.20285],[-72.98397,41.20255],[-72.98378,41.20265],[-72.98362,41.20323],[-72.98322,41.20365],[-72.98341,41.20432],[-72.98326,41.20492],[-72.98297,41.20482],[-72.98316,41.2049],[-72.98307,41.2051],[-72.98206,41.20635],[-72.98182,41.20639],[-72.98195,41.20664],[-72.98096,41.20772],[-72.98018,41.20931],[-72.98004,41.20949],[-72.97982,41.20939],[-72.98009,41.20954],[-72.98005,41.20976],[-72.97914,41.21096],[-72.97899,41.21089],[-72.97934,41.21189],[-72.97878,41.21258],[-72.97823,41.21286],[-72.97896,41.21388],[-72.97903,41.21443],[-72.97854,41.21518],[-72.97864,41.21528],[-72.97857,41.21531],[-72.97978,41.21516],[-72.9817,41.21451],[-72.98253,41.21456],[-72.98414,41.21399],[-72.98458,41.21409],[-72.98425,41.21447],[-72.9844,41.21529],[-72.98531,41.21572],[-72.98523,41.21637],[-72.98453,41.21641],[-72.98432,41.21664],[-72.98445,41.21681],[-72.98503,41.21681],[-72.98484,41.21714],[-72.98519,41.21721],[-72.98433,41.21784],[-72.98492,41.21809],[-72.98508,41.21859],[-72.98475,41.21876],[-72.98442,41.21851],[-72.98416,41.21874],[-72.98415,41.21919],[-72.98455,41.21939],[-72.98483,41.21991],[-72.98462,41.22031],[-72.98518,41.22024],[-72.9851,41.22094],[-72.98567,41.22099],[-72.98591,41.22121],[-72.98615,41.22096],[-72.9864,41.22101],[-72.98631,41.22136],[-72.9867,41.22161],[-72.986,41.22321],[-72.98538,41.22356],[-72.98559,41.22418],[-72.98541,41.22473],[-72.98522,41.22481],[-72.98509,41.22456],[-72.9842,41.22478],[-72.98425,41.22493],[-72.99174,41.22659]]],[[[-73.10259,41.16399],[-73.10468,41.16774],[-73.10507,41.16802],[-73.10811,41.16715],[-73.10986,41.16623],[-73.11016,41.1659],[-73.11047,41.16495],[-73.11059,41.16316],[-73.11051,41.16288],[-73.11016,41.16276],[-73.11062,41.16261],[-73.11073,41.16208],[-73.11052,41.16171],[-73.11072,41.16173],[-73.11078,41.16128],[-73.11049,41.15894],[-73.10994,41.15771],[-73.10883,41.15646],[-73.10763,41.15683],[-73.10774,41.15741],[-73.10735,41.15878],[-73.10742,41.15948],[-73.107,41.16015],[-73.1068,41.16013],[-73.10715,41.15951],[-73.10703,41.15893],[-73.10625,41.1594],[-73.10562,41.15953],[-73.10334,41.16147],[-73.10319,41.16132],[-73.10263,41.16182],[-73.1023,41.16297],[-73.10259,41.16399]]],[[[-73.04627,41.17105],[-73.04735,41.17083],[-73.04714,41.17015],[-73.04636,41.16933],[-73.04499,41.1689],[-73.04436,41.16905],[-73.04384,41.16962],[-73.04368,41.17057],[-73.04398,41.171],[-73.04627,41.17105]]],[[[-72.98663,41.20128],[-72.98664,41.20098],[-72.98693,41.20088],[-72.98651,41.20071],[-72.98649,41.20088],[-72.98618,41.20088],[-72.98621,41.20123],[-72.98648,41.20123],[-72.98663,41.20128]]],[[[-72.99008,41.19974],[-72.98985,41.19941],[-72.98979,41.19959],[-72.99008,41.19974]]]]}' FROM towns WHERE name = 'Milford';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.15371,41.36101],[-73.15433,41.36143],[-73.15509,41.36243],[-73.1552,41.36286],[-73.1545,41.36623],[-73.15453,41.36703],[-73.155,41.36753],[-73.15603,41.3678],[-73.15822,41.36793],[-73.16326,41.36739],[-73.16905,41.36752],[-73.17155,41.36908],[-73.17673,41.37305],[-73.18,41.37586],[-73.18005,41.37593],[-73.18041,41.37568],[-73.18234,41.37449],[-73.18362,41.37336],[-73.18444,41.37324],[-73.18559,41.37269],[-73.18673,41.37252],[-73.18784,41.37315],[-73.18894,41.37315],[-73.19104,41.37288],[-73.19202,41.37218],[-73.19251,41.3715],[-73.19281,41.36931],[-73.19344,41.36868],[-73.19438,41.36681],[-73.19718,41.36514],[-73.19979,41.36317],[-73.19983,41.36282],[-73.19934,41.36202],[-73.19844,41.3619],[-73.19817,41.35923],[-73.19718,41.35805],[-73.19735,41.35718],[-73.19795,41.3561],[-73.20077,41.35431],[-73.20305,41.35359],[-73.20611,41.35167],[-73.20739,41.35145],[-73.20917,41.35157],[-73.21046,41.35108],[-73.21096,41.35073],[-73.2109,41.35035],[-73.21307,41.34881],[-73.21518,41.34799],[-73.21746,41.34759],[-73.2189,41.34787],[-73.22066,41.34889],[-73.22342,41.34877],[-73.22457,41.3496],[-73.22512,41.35022],[-73.22524,41.351],[-73.22495,41.3519],[-73.22435,41.35205],[-73.22397,41.35247],[-73.22447,41.35377],[-73.22545,41.35477],[-73.22962,41.35278],[-73.24517,41.34439],[-73.29905,41.31602],[-73.30066,41.31517],[-73.29005,41.31071],[-73.26804,41.30151],[-73.2669,41.30076],[-73.26669,41.30024],[-73.26724,41.29914],[-73.26684,41.29894],[-73.26729,41.29849],[-73.26697,41.29839],[-73.26705,41.29774],[-73.26687,41.29741],[-73.26653,41.29736],[-73.26645,41.29631],[-73.26581,41.29596],[-73.26592,41.29492],[-73.26564,41.29467],[-73.26565,41.29392],[-73.26521,41.29397],[-73.26498,41.29354],[-73.26505,41.29262],[-73.26474,41.29214],[-73.26535,41.29142],[-73.26495,41.29087],[-73.26463,41.28939],[-73.26536,41.2885],[-73.26504,41.28822],[-73.26596,41.28587],[-73.26693,41.28503],[-73.26775,41.28483],[-73.26839,41.28423],[-73.26996,41.28368],[-73.26967,41.28296],[-73.26978,41.28261],[-73.27165,41.28086],[-73.27182,41.28031],[-73.27079,41.27891],[-73.27021,41.27849],[-73.2681,41.27599],[-73.26807,41.27596],[-73.25573,41.27692],[-73.20375,41.28058],[-73.19819,41.28098],[-73.19824,41.281],[-73.17157,41.32025],[-73.16634,41.34122],[-73.15551,41.35676],[-73.15579,41.35901],[-73.15368,41.36068],[-73.15349,41.36091],[-73.15371,41.36101]]]]}' FROM towns WHERE name = 'Monroe';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.13575,41.48964],[-72.13658,41.49059],[-72.13717,41.49079],[-72.14155,41.48992],[-72.14328,41.49005],[-72.14421,41.4904],[-72.14466,41.49085],[-72.14554,41.49093],[-72.14731,41.49158],[-72.14838,41.49148],[-72.14963,41.49188],[-72.1502,41.49236],[-72.15176,41.49243],[-72.15236,41.49298],[-72.15394,41.49306],[-72.15439,41.49339],[-72.15963,41.49484],[-72.16139,41.49574],[-72.16264,41.4961],[-72.16327,41.49607],[-72.16538,41.49667],[-72.16578,41.49648],[-72.16644,41.49655],[-72.16939,41.49843],[-72.17049,41.49873],[-72.17182,41.50001],[-72.17935,41.50217],[-72.1816,41.50254],[-72.18348,41.5023],[-72.18349,41.50175],[-72.18299,41.50055],[-72.18321,41.49887],[-72.18455,41.49878],[-72.18725,41.49898],[-72.18841,41.49963],[-72.19179,41.50031],[-72.19251,41.50021],[-72.19287,41.49986],[-72.19263,41.49916],[-72.19337,41.49879],[-72.19383,41.49797],[-72.22283,41.50378],[-72.22907,41.50499],[-72.22889,41.50384],[-72.22799,41.50047],[-72.22674,41.49802],[-72.21835,41.48639],[-72.21931,41.48592],[-72.21723,41.47193],[-72.21603,41.46416],[-72.21163,41.44235],[-72.22151,41.44126],[-72.22023,41.43097],[-72.23927,41.42805],[-72.24077,41.42781],[-72.2394,41.42588],[-72.22642,41.40895],[-72.21736,41.3963],[-72.2169,41.39535],[-72.21571,41.39557],[-72.10254,41.41119],[-72.098,41.40948],[-72.09559,41.40858],[-72.09592,41.40915],[-72.09631,41.41095],[-72.096,41.4126],[-72.09406,41.41692],[-72.09235,41.41909],[-72.09059,41.42033],[-72.08598,41.423],[-72.0846,41.42467],[-72.08404,41.42619],[-72.0835,41.43091],[-72.08297,41.43206],[-72.08213,41.43316],[-72.08125,41.43386],[-72.0723,41.43669],[-72.07144,41.43724],[-72.06944,41.43956],[-72.06787,41.44378],[-72.06744,41.44583],[-72.06726,41.44775],[-72.06738,41.44867],[-72.06765,41.4494],[-72.06807,41.45042],[-72.07148,41.45802],[-72.07259,41.46252],[-72.07462,41.46692],[-72.07538,41.46802],[-72.07936,41.47184],[-72.08045,41.47489],[-72.08072,41.47749],[-72.09267,41.48278],[-72.09331,41.48295],[-72.09545,41.48166],[-72.09778,41.48066],[-72.09842,41.47991],[-72.09895,41.47992],[-72.09947,41.48009],[-72.10081,41.48112],[-72.10212,41.48267],[-72.10244,41.48352],[-72.10447,41.48467],[-72.10565,41.48457],[-72.10686,41.48515],[-72.10712,41.4856],[-72.1067,41.48704],[-72.1069,41.48727],[-72.10763,41.48735],[-72.1085,41.48857],[-72.10891,41.4886],[-72.11066,41.48773],[-72.11221,41.4877],[-72.11274,41.48658],[-72.11445,41.48621],[-72.1155,41.48641],[-72.11887,41.48826],[-72.12046,41.48866],[-72.12155,41.48847],[-72.12197,41.48789],[-72.12247,41.48764],[-72.12425,41.48777],[-72.1258,41.48832],[-72.12595,41.48867],[-72.12754,41.48895],[-72.12909,41.48868],[-72.12995,41.48905],[-72.13086,41.48851],[-72.1322,41.48856],[-72.13341,41.48836],[-72.13384,41.48843],[-72.13548,41.48939],[-72.1356,41.48954],[-72.13575,41.48964]]]]}' FROM towns WHERE name = 'Montville';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.14753,41.66678],[-73.16359,41.70903],[-73.17993,41.70699],[-73.20665,41.7047],[-73.28264,41.701],[-73.28169,41.68771],[-73.28166,41.68753],[-73.28026,41.68648],[-73.26572,41.6759],[-73.26615,41.66416],[-73.2665,41.65647],[-73.25458,41.65702],[-73.22825,41.65853],[-73.17616,41.661],[-73.17292,41.66137],[-73.1649,41.66228],[-73.14674,41.66448],[-73.14753,41.66678]]]]}' FROM towns WHERE name = 'Morris';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.00377,41.4647],[-73.00664,41.47113],[-73.01748,41.50354],[-73.01858,41.50688],[-73.01928,41.50681],[-73.04944,41.50251],[-73.06717,41.50181],[-73.06993,41.50172],[-73.07093,41.50172],[-73.09469,41.50131],[-73.09485,41.49926],[-73.09594,41.49604],[-73.09602,41.49456],[-73.09682,41.49379],[-73.09546,41.48967],[-73.09538,41.48877],[-73.09747,41.48777],[-73.09888,41.48635],[-73.0995,41.48515],[-73.10102,41.48483],[-73.10157,41.48426],[-73.10205,41.48338],[-73.10193,41.48259],[-73.10209,41.48226],[-73.10331,41.48146],[-73.10425,41.47894],[-73.10434,41.47784],[-73.10397,41.47679],[-73.1039,41.47609],[-73.10386,41.47577],[-73.10401,41.47425],[-73.10377,41.47277],[-73.10386,41.47067],[-73.10375,41.47027],[-73.10299,41.46935],[-73.10277,41.4685],[-73.09561,41.46409],[-73.07688,41.45165],[-73.07584,41.45093],[-73.07,41.44727],[-73.06876,41.44644],[-73.06779,41.44657],[-73.06651,41.44629],[-73.06331,41.44336],[-73.06114,41.44211],[-73.05994,41.44199],[-73.05942,41.44318],[-73.05836,41.44441],[-73.05745,41.44473],[-73.05566,41.44488],[-73.05469,41.44527],[-73.05356,41.44627],[-73.05293,41.44717],[-73.05229,41.44872],[-73.05275,41.45084],[-73.0527,41.45172],[-73.05159,41.45366],[-73.05128,41.45474],[-73.04878,41.45468],[-73.04571,41.45513],[-73.04441,41.455],[-73.0425,41.45437],[-73.0406,41.45507],[-73.03986,41.45482],[-73.03893,41.45294],[-73.0374,41.45224],[-73.03612,41.45254],[-73.03553,41.45211],[-73.03299,41.45266],[-73.03159,41.45206],[-73.0312,41.45143],[-73.03079,41.45143],[-73.02933,41.45228],[-73.02516,41.4475],[-73.02501,41.44598],[-73.02493,41.4458],[-73.02341,41.44598],[-73.00217,41.44862],[-73.00022,41.45556],[-73.00009,41.45623],[-73.00377,41.4647]]]]}' FROM towns WHERE name = 'Naugatuck';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.75503,41.64148],[-72.7634,41.68038],[-72.76762,41.70276],[-72.76869,41.70829],[-72.77009,41.70811],[-72.78968,41.70535],[-72.79874,41.69812],[-72.79939,41.69787],[-72.80249,41.69567],[-72.80567,41.69241],[-72.82098,41.68007],[-72.82131,41.67974],[-72.82434,41.67708],[-72.82517,41.67633],[-72.82912,41.65995],[-72.82684,41.64718],[-72.82676,41.64701],[-72.82608,41.64708],[-72.8239,41.63584],[-72.81958,41.63613],[-72.81873,41.63621],[-72.81282,41.6368],[-72.77609,41.63926],[-72.75484,41.64078],[-72.75503,41.64148]]]]}' FROM towns WHERE name = 'New Britain';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.43804,41.13201],[-73.43843,41.13281],[-73.43913,41.13573],[-73.44016,41.13691],[-73.44159,41.13756],[-73.44207,41.13813],[-73.44236,41.13821],[-73.44419,41.13759],[-73.44668,41.13864],[-73.44796,41.13852],[-73.44957,41.13937],[-73.45247,41.13957],[-73.4535,41.13985],[-73.45375,41.1409],[-73.45354,41.1414],[-73.45416,41.14235],[-73.45443,41.1427],[-73.45507,41.1427],[-73.45524,41.14295],[-73.45605,41.14325],[-73.4562,41.14403],[-73.45531,41.14917],[-73.45522,41.15499],[-73.45623,41.15556],[-73.45696,41.15646],[-73.45871,41.15642],[-73.45926,41.15689],[-73.45983,41.15799],[-73.46257,41.15944],[-73.46322,41.1601],[-73.46349,41.16099],[-73.46223,41.16177],[-73.46284,41.16279],[-73.46295,41.16404],[-73.46244,41.16484],[-73.46234,41.16546],[-73.46128,41.16674],[-73.46094,41.16748],[-73.46084,41.16803],[-73.46101,41.16896],[-73.47061,41.18516],[-73.47523,41.19163],[-73.4784,41.19024],[-73.5025,41.17929],[-73.52077,41.17032],[-73.54153,41.16064],[-73.54474,41.15909],[-73.54398,41.15882],[-73.54361,41.15847],[-73.54351,41.15779],[-73.54233,41.15574],[-73.54201,41.15484],[-73.54256,41.15357],[-73.54264,41.15287],[-73.54215,41.15215],[-73.54169,41.1507],[-73.54079,41.149],[-73.53968,41.1475],[-73.53866,41.14665],[-73.5339,41.14384],[-73.53348,41.14339],[-73.53336,41.14277],[-73.53299,41.14242],[-73.53164,41.14202],[-73.53121,41.14167],[-73.53097,41.14092],[-73.53039,41.14022],[-73.53002,41.13912],[-73.5295,41.13877],[-73.52806,41.13861],[-73.52646,41.13709],[-73.52495,41.13624],[-73.52391,41.13411],[-73.52352,41.13386],[-73.52249,41.13371],[-73.52147,41.13298],[-73.52218,41.13241],[-73.5221,41.13201],[-73.52173,41.13181],[-73.52203,41.13056],[-73.52243,41.13014],[-73.52347,41.12989],[-73.52355,41.12944],[-73.50868,41.09166],[-73.50166,41.09172],[-73.49033,41.09178],[-73.48747,41.09177],[-73.4735,41.09138],[-73.46231,41.09176],[-73.45473,41.10264],[-73.44744,41.11227],[-73.43861,41.12821],[-73.4378,41.13181],[-73.43804,41.13201]]]]}' FROM towns WHERE name = 'New Canaan';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.44173,41.4811],[-73.443,41.48775],[-73.44398,41.48885],[-73.44431,41.49007],[-73.44367,41.49167],[-73.44416,41.49327],[-73.44468,41.49672],[-73.44544,41.49719],[-73.44555,41.49744],[-73.44432,41.50014],[-73.44336,41.50146],[-73.44319,41.50223],[-73.44379,41.50281],[-73.44375,41.50326],[-73.44466,41.50508],[-73.4448,41.50731],[-73.44597,41.50896],[-73.44601,41.50956],[-73.44553,41.51138],[-73.44562,41.51178],[-73.45014,41.5201],[-73.45187,41.52318],[-73.45561,41.52246],[-73.51426,41.51083],[-73.53184,41.50859],[-73.53208,41.50449],[-73.53304,41.46865],[-73.53431,41.43344],[-73.53462,41.42667],[-73.49779,41.43481],[-73.46047,41.44259],[-73.42541,41.45033],[-73.42898,41.45673],[-73.42925,41.45598],[-73.42997,41.45563],[-73.43019,41.45573],[-73.43158,41.45728],[-73.43211,41.45896],[-73.4327,41.45908],[-73.43313,41.45886],[-73.43391,41.45884],[-73.43586,41.45984],[-73.4374,41.46107],[-73.43742,41.46067],[-73.43761,41.46059],[-73.4374,41.46007],[-73.43573,41.45889],[-73.43446,41.45769],[-73.43371,41.45551],[-73.43374,41.45484],[-73.43423,41.45412],[-73.43442,41.45322],[-73.43492,41.45269],[-73.43597,41.45217],[-73.43634,41.45245],[-73.43747,41.45432],[-73.43827,41.45515],[-73.4391,41.45677],[-73.4396,41.45717],[-73.44101,41.45747],[-73.44151,41.45855],[-73.44219,41.45942],[-73.44225,41.46042],[-73.44281,41.46127],[-73.44346,41.46435],[-73.44338,41.465],[-73.44213,41.46612],[-73.44257,41.46789],[-73.44231,41.46971],[-73.44265,41.47054],[-73.44256,41.47179],[-73.44198,41.47269],[-73.44126,41.47281],[-73.43862,41.47173],[-73.43659,41.47028],[-73.43949,41.47555],[-73.44124,41.47868],[-73.44173,41.4811]]]]}' FROM towns WHERE name = 'New Fairfield';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.95157,41.82248],[-72.94693,41.83651],[-72.94822,41.83718],[-72.94989,41.83851],[-72.95391,41.83972],[-72.95688,41.83977],[-72.96149,41.83873],[-72.96273,41.8389],[-72.96298,41.83915],[-72.96325,41.84073],[-72.96278,41.84198],[-72.96272,41.84323],[-72.96325,41.8439],[-72.96516,41.84525],[-72.96447,41.84897],[-72.96484,41.85082],[-72.96377,41.85254],[-72.9601,41.86697],[-72.95888,41.86854],[-72.95805,41.87217],[-72.9583,41.87416],[-72.95561,41.88412],[-72.95379,41.89236],[-72.95366,41.89284],[-72.96782,41.89181],[-73.05194,41.88589],[-73.06553,41.88484],[-73.06687,41.87875],[-73.08038,41.81538],[-73.08303,41.8034],[-73.08559,41.79401],[-73.0857,41.79358],[-73.08543,41.79356],[-73.0264,41.7932],[-73.02626,41.79324],[-73.02604,41.79324],[-73.00701,41.79576],[-72.9624,41.80117],[-72.95845,41.80163],[-72.95157,41.82248]]]]}' FROM towns WHERE name = 'New Hartford';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.88396,41.31452],[-72.88417,41.31352],[-72.88635,41.31317],[-72.8875,41.31265],[-72.88769,41.31237],[-72.88752,41.31167],[-72.88654,41.31072],[-72.88707,41.3103],[-72.88731,41.31048],[-72.88765,41.31028],[-72.88817,41.31078],[-72.89737,41.30877],[-72.90833,41.31568],[-72.93814,41.31852],[-72.94929,41.31981],[-72.95259,41.32284],[-72.95196,41.32291],[-72.95146,41.32321],[-72.95046,41.32486],[-72.95019,41.32483],[-72.94827,41.3265],[-72.94611,41.32647],[-72.94623,41.32817],[-72.9465,41.3297],[-72.94754,41.33025],[-72.94826,41.33002],[-72.94904,41.33015],[-72.9481,41.33202],[-72.95035,41.33315],[-72.95334,41.33323],[-72.95383,41.33188],[-72.95673,41.33211],[-72.95746,41.32971],[-72.95736,41.32954],[-72.95809,41.32894],[-72.95864,41.32922],[-72.95941,41.32917],[-72.95956,41.32667],[-72.96099,41.32742],[-72.96231,41.32855],[-72.96332,41.33067],[-72.96425,41.33062],[-72.96861,41.33025],[-72.96764,41.3287],[-72.96721,41.32805],[-72.9656,41.32723],[-72.96508,41.32528],[-72.97224,41.32229],[-72.97762,41.32045],[-72.98007,41.31544],[-72.98193,41.31324],[-72.98356,41.31257],[-72.98371,41.31112],[-72.99236,41.30527],[-72.99206,41.2968],[-72.99203,41.29662],[-72.98815,41.29614],[-72.98346,41.29561],[-72.97868,41.29508],[-72.95026,41.29224],[-72.95095,41.29117],[-72.95102,41.29002],[-72.95148,41.28924],[-72.95153,41.28857],[-72.95102,41.28767],[-72.94991,41.28732],[-72.94968,41.28677],[-72.94968,41.28619],[-72.95039,41.28622],[-72.95037,41.28557],[-72.95069,41.28487],[-72.95018,41.2841],[-72.94998,41.28312],[-72.94916,41.28232],[-72.94701,41.28127],[-72.94675,41.28072],[-72.94741,41.28015],[-72.94756,41.27945],[-72.94729,41.27937],[-72.94604,41.27974],[-72.94543,41.27957],[-72.94327,41.27742],[-72.94356,41.27722],[-72.94441,41.27724],[-72.94478,41.2766],[-72.94549,41.27595],[-72.9454,41.2756],[-72.94412,41.2749],[-72.94344,41.27422],[-72.94157,41.27349],[-72.94053,41.27434],[-72.94002,41.27424],[-72.93984,41.27414],[-72.93962,41.27317],[-72.93751,41.27166],[-72.93658,41.27069],[-72.93377,41.26954],[-72.93319,41.26864],[-72.93398,41.26769],[-72.93378,41.26661],[-72.93223,41.26284],[-72.93156,41.26221],[-72.93149,41.26236],[-72.93098,41.26314],[-72.92973,41.26276],[-72.9274,41.26306],[-72.92633,41.26298],[-72.92592,41.26288],[-72.92631,41.26146],[-72.92618,41.26118],[-72.9241,41.26143],[-72.92313,41.26175],[-72.92263,41.2611],[-72.92237,41.26125],[-72.9229,41.26213],[-72.92262,41.26228],[-72.92246,41.2621],[-72.92119,41.26325],[-72.92063,41.26337],[-72.92035,41.26377],[-72.91968,41.26409],[-72.9185,41.26377],[-72.91924,41.26404],[-72.91913,41.26439],[-72.91939,41.26509],[-72.92036,41.26497],[-72.921,41.26587],[-72.91998,41.26582],[-72.91977,41.26559],[-72.91997,41.26562],[-72.92004,41.26539],[-72.91984,41.26532],[-72.91803,41.26649],[-72.91754,41.26729],[-72.91789,41.26766],[-72.91724,41.26769],[-72.91467,41.2714],[-72.91377,41.27218],[-72.91345,41.27258],[-72.91355,41.27278],[-72.91129,41.27512],[-72.91047,41.27549],[-72.90917,41.27437],[-72.91065,41.27574],[-72.91076,41.27619],[-72.90991,41.27719],[-72.90899,41.27652],[-72.90932,41.27682],[-72.90851,41.27721],[-72.90894,41.27714],[-72.90959,41.27762],[-72.909,41.27829],[-72.9083,41.27766],[-72.90703,41.27826],[-72.9047,41.27823],[-72.90412,41.27838],[-72.90395,41.28006],[-72.90285,41.28008],[-72.90261,41.27905],[-72.90217,41.27905],[-72.90098,41.27925],[-72.89987,41.28015],[-72.89733,41.27832],[-72.8975,41.27747],[-72.89804,41.27747],[-72.89896,41.2765],[-72.89888,41.27633],[-72.89775,41.27612],[-72.89783,41.27563],[-72.90044,41.2759],[-72.90044,41.27573],[-72.89798,41.27553],[-72.89808,41.27485],[-72.89903,41.27488],[-72.89889,41.2742],[-72.8991,41.2741],[-72.89925,41.27343],[-72.90015,41.27353],[-72.90022,41.27321],[-72.9006,41.27316],[-72.90119,41.27153],[-72.9015,41.27138],[-72.89933,41.27106],[-72.89913,41.27088],[-72.89916,41.27051],[-72.90162,41.27064],[-72.89928,41.27031],[-72.8993,41.27008],[-72.89863,41.26978],[-72.89887,41.26721],[-72.89916,41.26721],[-72.8992,41.26696],[-72.89891,41.26686],[-72.89936,41.26426],[-72.90124,41.26442],[-72.90126,41.26427],[-72.89943,41.26406],[-72.89942,41.26371],[-72.89863,41.26314],[-72.89632,41.25829],[-72.89675,41.25734],[-72.89615,41.25612],[-72.8962,41.25522],[-72.89658,41.25427],[-72.89718,41.25372],[-72.89721,41.2532],[-72.8976,41.25317],[-72.89752,41.2529],[-72.89819,41.2528],[-72.89783,41.25277],[-72.8977,41.2525],[-72.89803,41.25227],[-72.89839,41.25132],[-72.89877,41.25105],[-72.89604,41.25],[-72.89579,41.24962],[-72.8953,41.24955],[-72.89357,41.24859],[-72.89349,41.24837],[-72.89218,41.24759],[-72.89186,41.24687],[-72.88979,41.24512],[-72.88796,41.24317],[-72.88728,41.24224],[-72.88754,41.24209],[-72.88715,41.24214],[-72.88599,41.23974],[-72.88614,41.23824],[-72.88703,41.23655],[-72.88829,41.2363],[-72.88851,41.2365],[-72.88851,41.2363],[-72.88882,41.2361],[-72.89046,41.2356],[-72.89141,41.23573],[-72.89182,41.23491],[-72.89291,41.23468],[-72.89329,41.23523],[-72.89301,41.23571],[-72.89341,41.23521],[-72.89326,41.23436],[-72.89383,41.23378],[-72.89502,41.23311],[-72.89512,41.23229],[-72.89592,41.23206],[-72.89569,41.23181],[-72.896,41.23159],[-72.89638,41.23044],[-72.89611,41.22952],[-72.89643,41.22912],[-72.89626,41.22892],[-72.8963,41.22784],[-72.89469,41.22772],[-72.89276,41.22671],[-72.89269,41.22661],[-72.89169,41.22734],[-72.89208,41.22804],[-72.89178,41.22856],[-72.89105,41.22881],[-72.88936,41.22843],[-72.88749,41.2293],[-72.88715,41.22995],[-72.88601,41.23003],[-72.88513,41.22975],[-72.8849,41.2303],[-72.88384,41.23132],[-72.88162,41.23112],[-72.87954,41.23321],[-72.87965,41.23361],[-72.87923,41.23401],[-72.87959,41.23556],[-72.87935,41.23639],[-72.87955,41.23671],[-72.87942,41.23716],[-72.87951,41.23943],[-72.88084,41.26656],[-72.86691,41.29011],[-72.86091,41.30539],[-72.85583,41.31845],[-72.86145,41.31778],[-72.87456,41.3162],[-72.88397,41.31497],[-72.88396,41.31452]]]]}' FROM towns WHERE name = 'New Haven';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.09747,41.28553],[-72.096,41.28587],[-72.09571,41.28582],[-72.09577,41.28562],[-72.0956,41.28557],[-72.09492,41.28567],[-72.09536,41.28572],[-72.09547,41.28597],[-72.0952,41.28665],[-72.09487,41.28712],[-72.09318,41.28837],[-72.08962,41.28951],[-72.08917,41.28926],[-72.08943,41.28976],[-72.08903,41.29011],[-72.08866,41.29011],[-72.08889,41.29018],[-72.08908,41.29083],[-72.08843,41.29123],[-72.08923,41.29283],[-72.08892,41.29303],[-72.08924,41.29306],[-72.08927,41.29328],[-72.08906,41.2942],[-72.08739,41.29555],[-72.08477,41.2965],[-72.0849,41.29662],[-72.08479,41.29702],[-72.0842,41.29747],[-72.08432,41.29762],[-72.0842,41.29769],[-72.08472,41.29797],[-72.0847,41.29817],[-72.08347,41.29937],[-72.08368,41.29979],[-72.0841,41.29994],[-72.08411,41.30022],[-72.08376,41.30161],[-72.08334,41.30221],[-72.08349,41.30301],[-72.08309,41.30351],[-72.08319,41.30369],[-72.08301,41.30401],[-72.08358,41.30436],[-72.0842,41.30586],[-72.08452,41.30596],[-72.08451,41.30621],[-72.08473,41.30621],[-72.08531,41.30689],[-72.08526,41.30711],[-72.08554,41.30726],[-72.08572,41.30764],[-72.08566,41.30824],[-72.08601,41.30834],[-72.08617,41.30889],[-72.08621,41.30959],[-72.08786,41.31114],[-72.08982,41.31231],[-72.09023,41.31314],[-72.0904,41.31306],[-72.09083,41.31371],[-72.09095,41.31364],[-72.09102,41.31419],[-72.09175,41.31509],[-72.09216,41.31491],[-72.09453,41.31764],[-72.09489,41.31769],[-72.09507,41.31792],[-72.09483,41.31802],[-72.09519,41.31797],[-72.09535,41.31832],[-72.09505,41.31899],[-72.09444,41.31884],[-72.09421,41.31919],[-72.09433,41.31929],[-72.09416,41.31926],[-72.09422,41.31954],[-72.09476,41.31969],[-72.09448,41.32029],[-72.09266,41.32023],[-72.09233,41.32051],[-72.09041,41.32076],[-72.0897,41.32118],[-72.08977,41.32198],[-72.08963,41.322],[-72.08991,41.3227],[-72.09011,41.32273],[-72.08987,41.32278],[-72.09301,41.32221],[-72.09348,41.32256],[-72.09351,41.32346],[-72.09312,41.32353],[-72.09283,41.32278],[-72.09256,41.32263],[-72.0912,41.32278],[-72.09117,41.3234],[-72.09098,41.32348],[-72.09061,41.3233],[-72.09053,41.32293],[-72.09,41.32325],[-72.08932,41.32333],[-72.08896,41.32315],[-72.08783,41.32375],[-72.0879,41.32455],[-72.08759,41.32557],[-72.08788,41.32605],[-72.08784,41.32632],[-72.08892,41.32752],[-72.0889,41.3278],[-72.09062,41.32762],[-72.09097,41.32795],[-72.09122,41.32905],[-72.0905,41.32925],[-72.09056,41.32947],[-72.09147,41.32917],[-72.09199,41.32957],[-72.0925,41.3295],[-72.0928,41.3297],[-72.09243,41.33125],[-72.09188,41.33242],[-72.09171,41.33237],[-72.09205,41.33162],[-72.09183,41.33157],[-72.09162,41.3325],[-72.09077,41.33332],[-72.09014,41.33274],[-72.0899,41.33289],[-72.09071,41.33367],[-72.09025,41.33389],[-72.08958,41.33337],[-72.08962,41.33322],[-72.08922,41.33352],[-72.08953,41.33342],[-72.08998,41.33377],[-72.08955,41.33414],[-72.08921,41.33394],[-72.08951,41.33417],[-72.08908,41.33469],[-72.08866,41.33429],[-72.08815,41.33451],[-72.08805,41.33516],[-72.0884,41.33541],[-72.08713,41.33521],[-72.08711,41.33539],[-72.08809,41.33554],[-72.08798,41.33604],[-72.0883,41.33604],[-72.08811,41.33636],[-72.08838,41.33724],[-72.08826,41.33731],[-72.08892,41.33811],[-72.08836,41.33824],[-72.08872,41.33869],[-72.08894,41.33864],[-72.08995,41.33929],[-72.09013,41.33946],[-72.08999,41.33961],[-72.09044,41.33934],[-72.09055,41.33991],[-72.09133,41.34001],[-72.0916,41.33984],[-72.09168,41.34029],[-72.09127,41.34101],[-72.08914,41.34071],[-72.08688,41.33813],[-72.08621,41.33841],[-72.08801,41.34026],[-72.08813,41.34086],[-72.08763,41.34126],[-72.08544,41.33883],[-72.08484,41.3391],[-72.087,41.34153],[-72.08545,41.34403],[-72.08548,41.3443],[-72.086,41.34465],[-72.0846,41.34487],[-72.08385,41.34495],[-72.0847,41.35444],[-72.08639,41.36131],[-72.08636,41.36858],[-72.08653,41.36923],[-72.08698,41.36988],[-72.09961,41.3669],[-72.10653,41.36524],[-72.12453,41.34698],[-72.12422,41.33914],[-72.10843,41.32508],[-72.10404,41.31156],[-72.10001,41.30189],[-72.10027,41.30099],[-72.09969,41.30024],[-72.09878,41.29844],[-72.09786,41.29749],[-72.095,41.29369],[-72.09526,41.29182],[-72.09617,41.29147],[-72.09583,41.29074],[-72.09623,41.29014],[-72.09707,41.28992],[-72.098,41.29015],[-72.09821,41.2897],[-72.09784,41.28897],[-72.09676,41.28922],[-72.09626,41.28954],[-72.0959,41.28897],[-72.09589,41.28855],[-72.09634,41.28815],[-72.09605,41.28762],[-72.09628,41.287],[-72.09677,41.28657],[-72.0981,41.28605],[-72.09803,41.2854],[-72.09768,41.28508],[-72.09741,41.28513],[-72.09747,41.28553]]],[[[-72.08794,41.3217],[-72.08823,41.3217],[-72.08803,41.32148],[-72.08822,41.32135],[-72.08818,41.32085],[-72.08799,41.32085],[-72.08807,41.32113],[-72.08778,41.32135],[-72.08794,41.3217]]],[[[-72.08241,41.29956],[-72.08261,41.29976],[-72.08267,41.29936],[-72.08242,41.29914],[-72.08241,41.29956]]],[[[-72.08719,41.3185],[-72.0865,41.3182],[-72.0864,41.31833],[-72.08719,41.3185]]],[[[-72.08221,41.29944],[-72.08225,41.29916],[-72.08204,41.29931],[-72.08221,41.29944]]]]}' FROM towns WHERE name = 'New London';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.35183,41.58659],[-73.35356,41.59306],[-73.35911,41.616],[-73.36349,41.63801],[-73.37605,41.64664],[-73.38274,41.6509],[-73.38571,41.66394],[-73.38713,41.67311],[-73.38752,41.67566],[-73.39016,41.67536],[-73.42489,41.67117],[-73.50873,41.66168],[-73.50898,41.66163],[-73.5095,41.66043],[-73.51149,41.65804],[-73.51154,41.65791],[-73.51157,41.65744],[-73.51176,41.65629],[-73.51125,41.65564],[-73.50909,41.65414],[-73.50712,41.65326],[-73.5034,41.65091],[-73.50304,41.65081],[-73.50241,41.65101],[-73.49988,41.65337],[-73.49923,41.6535],[-73.49886,41.65335],[-73.49797,41.6521],[-73.49687,41.64875],[-73.49579,41.64657],[-73.49461,41.64477],[-73.49005,41.64002],[-73.48905,41.63937],[-73.48943,41.63877],[-73.48979,41.63862],[-73.49113,41.63875],[-73.49129,41.63848],[-73.49163,41.63845],[-73.49215,41.638],[-73.49212,41.63773],[-73.49242,41.63728],[-73.49314,41.6378],[-73.49408,41.63756],[-73.49422,41.63806],[-73.49475,41.63811],[-73.49541,41.63736],[-73.49813,41.63641],[-73.49918,41.63569],[-73.49904,41.63507],[-73.49972,41.63437],[-73.50051,41.63409],[-73.50057,41.63257],[-73.50014,41.63224],[-73.50088,41.6301],[-73.50148,41.62967],[-73.50191,41.6297],[-73.49086,41.60563],[-73.47095,41.56429],[-73.45717,41.53617],[-73.45563,41.531],[-73.45353,41.52765],[-73.45216,41.52488],[-73.45193,41.5238],[-73.45187,41.52318],[-73.45014,41.5201],[-73.44562,41.51178],[-73.44553,41.51138],[-73.44601,41.50956],[-73.44597,41.50896],[-73.4448,41.50731],[-73.44466,41.50508],[-73.44375,41.50326],[-73.44379,41.50281],[-73.44319,41.50223],[-73.44336,41.50146],[-73.44432,41.50014],[-73.44555,41.49744],[-73.44544,41.49719],[-73.44468,41.49672],[-73.44416,41.49327],[-73.44367,41.49167],[-73.44431,41.49007],[-73.44398,41.48885],[-73.443,41.48775],[-73.44173,41.4811],[-73.44124,41.47868],[-73.43039,41.48573],[-73.42286,41.48759],[-73.41751,41.48991],[-73.40293,41.49708],[-73.38916,41.5024],[-73.38736,41.50307],[-73.38748,41.50312],[-73.3892,41.50383],[-73.39413,41.50528],[-73.39843,41.50691],[-73.39973,41.50784],[-73.40136,41.50964],[-73.40346,41.51331],[-73.40382,41.51556],[-73.40533,41.51801],[-73.40576,41.51931],[-73.40601,41.52368],[-73.40586,41.52573],[-73.40498,41.52803],[-73.40528,41.52938],[-73.40448,41.53002],[-73.40286,41.5304],[-73.40173,41.53159],[-73.40134,41.53164],[-73.40157,41.53187],[-73.40116,41.53202],[-73.40067,41.53279],[-73.40028,41.53281],[-73.39991,41.53249],[-73.39806,41.53244],[-73.39737,41.53278],[-73.3968,41.53263],[-73.39667,41.53298],[-73.39572,41.53316],[-73.39518,41.53361],[-73.39456,41.53323],[-73.39428,41.5337],[-73.3937,41.53358],[-73.39287,41.53375],[-73.39324,41.53448],[-73.39201,41.53497],[-73.39208,41.53547],[-73.39191,41.53577],[-73.39031,41.53575],[-73.39027,41.53682],[-73.39088,41.53687],[-73.39098,41.5377],[-73.39135,41.53817],[-73.3922,41.5387],[-73.39231,41.53922],[-73.39196,41.53979],[-73.39159,41.54124],[-73.392,41.54189],[-73.39181,41.54377],[-73.39273,41.54447],[-73.39267,41.54539],[-73.37429,41.55443],[-73.345,41.55516],[-73.34547,41.55773],[-73.34797,41.56725],[-73.35069,41.58174],[-73.35153,41.58536],[-73.35183,41.58659]]]]}' FROM towns WHERE name = 'New Milford';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.71161,41.66035],[-72.71307,41.66519],[-72.7059,41.66603],[-72.715,41.70283],[-72.71411,41.7097],[-72.71339,41.71704],[-72.71426,41.71697],[-72.7216,41.71628],[-72.73151,41.71512],[-72.74976,41.71323],[-72.76929,41.71111],[-72.76912,41.71061],[-72.76869,41.70829],[-72.76762,41.70276],[-72.7634,41.68038],[-72.75503,41.64148],[-72.75484,41.64078],[-72.75423,41.63826],[-72.75401,41.63746],[-72.73183,41.64005],[-72.73216,41.64289],[-72.73006,41.64282],[-72.72604,41.64306],[-72.72397,41.64293],[-72.722,41.6431],[-72.72028,41.64323],[-72.71185,41.64429],[-72.70805,41.64481],[-72.70856,41.64705],[-72.71152,41.66005],[-72.71161,41.66035]]]]}' FROM towns WHERE name = 'Newington';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.30959,41.45535],[-73.31107,41.45435],[-73.31211,41.45411],[-73.31383,41.45406],[-73.32118,41.45574],[-73.32473,41.456],[-73.32724,41.4569],[-73.32918,41.45828],[-73.33067,41.45943],[-73.33112,41.45896],[-73.36226,41.42366],[-73.37336,41.40879],[-73.37612,41.40513],[-73.37301,41.3988],[-73.3509,41.35234],[-73.34224,41.33359],[-73.34203,41.33312],[-73.34196,41.33294],[-73.32713,41.30088],[-73.32111,41.30412],[-73.31931,41.30509],[-73.30113,41.3149],[-73.30066,41.31517],[-73.29905,41.31602],[-73.24517,41.34439],[-73.22962,41.35278],[-73.22545,41.35477],[-73.22447,41.35377],[-73.22397,41.35247],[-73.22435,41.35205],[-73.22495,41.3519],[-73.22524,41.351],[-73.22512,41.35022],[-73.22457,41.3496],[-73.22342,41.34877],[-73.22066,41.34889],[-73.2189,41.34787],[-73.21746,41.34759],[-73.21518,41.34799],[-73.21307,41.34881],[-73.2109,41.35035],[-73.21096,41.35073],[-73.21046,41.35108],[-73.20917,41.35157],[-73.20739,41.35145],[-73.20611,41.35167],[-73.20305,41.35359],[-73.20077,41.35431],[-73.19795,41.3561],[-73.19735,41.35718],[-73.19718,41.35805],[-73.19817,41.35923],[-73.19844,41.3619],[-73.19934,41.36202],[-73.19983,41.36282],[-73.19979,41.36317],[-73.19718,41.36514],[-73.19438,41.36681],[-73.19344,41.36868],[-73.19281,41.36931],[-73.19251,41.3715],[-73.19202,41.37218],[-73.19104,41.37288],[-73.18894,41.37315],[-73.18784,41.37315],[-73.18673,41.37252],[-73.18559,41.37269],[-73.18444,41.37324],[-73.18362,41.37336],[-73.18234,41.37449],[-73.18041,41.37568],[-73.18005,41.37593],[-73.18068,41.37663],[-73.18483,41.38136],[-73.18536,41.38261],[-73.18493,41.38383],[-73.18312,41.38583],[-73.18225,41.38767],[-73.18202,41.38895],[-73.18208,41.39092],[-73.18325,41.39265],[-73.18509,41.39382],[-73.18755,41.39485],[-73.18958,41.39538],[-73.19407,41.39603],[-73.1955,41.39651],[-73.19706,41.39759],[-73.19879,41.40019],[-73.20375,41.40429],[-73.20524,41.40529],[-73.20744,41.40654],[-73.20965,41.408],[-73.2111,41.40932],[-73.21412,41.4128],[-73.21507,41.41365],[-73.21685,41.41455],[-73.21817,41.4149],[-73.22477,41.41536],[-73.22715,41.41519],[-73.22948,41.41424],[-73.23376,41.41178],[-73.2353,41.41158],[-73.2369,41.41198],[-73.23856,41.41316],[-73.2399,41.41486],[-73.24026,41.41621],[-73.24113,41.42353],[-73.24199,41.42463],[-73.24323,41.4252],[-73.24447,41.42523],[-73.24786,41.42401],[-73.25096,41.42214],[-73.25273,41.4204],[-73.2553,41.41918],[-73.25619,41.41906],[-73.25826,41.41923],[-73.26113,41.42009],[-73.2621,41.42076],[-73.26298,41.42184],[-73.2641,41.42494],[-73.26519,41.42883],[-73.26645,41.43123],[-73.26775,41.43311],[-73.27102,41.43694],[-73.27207,41.43784],[-73.2736,41.43849],[-73.27777,41.43902],[-73.27922,41.4389],[-73.28541,41.43683],[-73.28816,41.43541],[-73.29001,41.43387],[-73.29063,41.43357],[-73.293,41.43372],[-73.2954,41.43438],[-73.29642,41.43505],[-73.29756,41.43663],[-73.29883,41.44097],[-73.29943,41.4423],[-73.29852,41.44447],[-73.29828,41.44774],[-73.29694,41.45084],[-73.29699,41.45421],[-73.29727,41.45543],[-73.29919,41.45763],[-73.30091,41.45824],[-73.30321,41.45819],[-73.3044,41.45792],[-73.30602,41.45699],[-73.30813,41.45612],[-73.30926,41.45558],[-73.30959,41.45535]]]]}' FROM towns WHERE name = 'Newtown';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.14728,42.04409],[-73.24844,42.04709],[-73.24968,42.04711],[-73.25779,42.001],[-73.2588,41.9948],[-73.26011,41.98759],[-73.26336,41.96956],[-73.26315,41.96744],[-73.26439,41.95997],[-73.26955,41.9378],[-73.27003,41.93668],[-73.27318,41.92434],[-73.27553,41.91513],[-73.26672,41.91477],[-73.26234,41.91459],[-73.17771,41.91176],[-73.16563,41.95945],[-73.15533,42.00084],[-73.14392,42.04398],[-73.14728,42.04409]]]]}' FROM towns WHERE name = 'Norfolk';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.74003,41.29252],[-72.73691,41.30183],[-72.73602,41.3063],[-72.73625,41.3091],[-72.73818,41.32229],[-72.74015,41.33768],[-72.74169,41.35831],[-72.74316,41.36813],[-72.74382,41.37492],[-72.74401,41.38024],[-72.74434,41.38334],[-72.74172,41.38256],[-72.73973,41.38665],[-72.73564,41.39047],[-72.73059,41.39571],[-72.72812,41.39905],[-72.72434,41.40259],[-72.72367,41.40221],[-72.72098,41.40318],[-72.71976,41.40735],[-72.71932,41.40805],[-72.7183,41.41195],[-72.71832,41.412],[-72.71835,41.412],[-72.72089,41.4117],[-72.72876,41.41001],[-72.7336,41.40927],[-72.73639,41.4089],[-72.7378,41.40898],[-72.74385,41.40819],[-72.74395,41.40816],[-72.74726,41.40669],[-72.75879,41.40309],[-72.77106,41.39871],[-72.78314,41.39396],[-72.79966,41.38802],[-72.81334,41.38372],[-72.81457,41.38334],[-72.81444,41.38137],[-72.81378,41.36104],[-72.81372,41.35342],[-72.81351,41.34665],[-72.81334,41.34173],[-72.81275,41.3231],[-72.81829,41.30036],[-72.8184,41.29988],[-72.81691,41.3003],[-72.7944,41.30594],[-72.77493,41.29467],[-72.76544,41.28641],[-72.74378,41.29108],[-72.74025,41.29177],[-72.74003,41.29252]]]]}' FROM towns WHERE name = 'North Branford';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.25043,42.04716],[-73.26635,42.04769],[-73.27331,42.04812],[-73.35684,42.0507],[-73.35749,42.05072],[-73.357,42.0479],[-73.35695,42.0472],[-73.35715,42.04668],[-73.35774,42.04608],[-73.35896,42.04551],[-73.35955,42.04486],[-73.35997,42.04411],[-73.3601,42.04291],[-73.36116,42.03999],[-73.36294,42.03797],[-73.36483,42.03527],[-73.3665,42.03243],[-73.36868,42.03056],[-73.36887,42.02816],[-73.36941,42.02589],[-73.36915,42.02542],[-73.3682,42.02459],[-73.36826,42.02257],[-73.36784,42.02124],[-73.36684,42.01957],[-73.36699,42.01807],[-73.36743,42.01755],[-73.37015,42.01658],[-73.37261,42.01511],[-73.37351,42.01431],[-73.37402,42.01336],[-73.37491,42.01079],[-73.37485,42.00966],[-73.3742,42.00809],[-73.37287,42.00587],[-73.37273,42.00457],[-73.37285,42.00377],[-73.37251,42.00374],[-73.33208,42.00111],[-73.31285,41.99923],[-73.26477,41.99531],[-73.2588,41.9948],[-73.25779,42.001],[-73.24968,42.04711],[-73.25043,42.04716]]]]}' FROM towns WHERE name = 'North Canaan';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.81818,41.3823],[-72.82455,41.38054],[-72.82526,41.40894],[-72.82631,41.40924],[-72.82676,41.40896],[-72.82744,41.40889],[-72.82816,41.40937],[-72.82867,41.40919],[-72.82868,41.40874],[-72.82909,41.40864],[-72.82987,41.40889],[-72.83033,41.40867],[-72.83156,41.40912],[-72.83194,41.4089],[-72.83216,41.40905],[-72.83229,41.41005],[-72.83249,41.4103],[-72.83425,41.41127],[-72.83495,41.4119],[-72.83597,41.41207],[-72.83752,41.41178],[-72.83792,41.4112],[-72.83885,41.41148],[-72.83893,41.41103],[-72.84054,41.41123],[-72.84104,41.41091],[-72.84194,41.41078],[-72.84212,41.41108],[-72.84185,41.41178],[-72.84235,41.41166],[-72.84295,41.41201],[-72.84352,41.41176],[-72.84402,41.41194],[-72.84443,41.41184],[-72.84454,41.41149],[-72.84493,41.41161],[-72.84499,41.41196],[-72.84538,41.41201],[-72.84575,41.41141],[-72.84564,41.41114],[-72.84578,41.41094],[-72.84611,41.41132],[-72.8461,41.41249],[-72.84757,41.41367],[-72.84793,41.41516],[-72.84894,41.41714],[-72.84843,41.41876],[-72.84958,41.41919],[-72.85049,41.41804],[-72.85325,41.41495],[-72.855,41.41435],[-72.85638,41.41418],[-72.85758,41.41291],[-72.85936,41.41051],[-72.86031,41.41059],[-72.86205,41.41124],[-72.86678,41.4109],[-72.87294,41.41106],[-72.87469,41.40944],[-72.87557,41.40896],[-72.87952,41.40777],[-72.88099,41.4079],[-72.88339,41.39636],[-72.88402,41.39641],[-72.88361,41.39331],[-72.8829,41.39059],[-72.88315,41.38597],[-72.88529,41.37716],[-72.88776,41.37679],[-72.88884,41.37499],[-72.88899,41.37427],[-72.88884,41.37399],[-72.8895,41.37337],[-72.8895,41.37184],[-72.88987,41.37125],[-72.88971,41.37075],[-72.88994,41.37017],[-72.89074,41.36932],[-72.89094,41.36875],[-72.89182,41.3681],[-72.89211,41.36715],[-72.89403,41.36561],[-72.89542,41.36489],[-72.89628,41.36364],[-72.89813,41.36287],[-72.90013,41.36157],[-72.90398,41.34981],[-72.89582,41.34763],[-72.87767,41.34385],[-72.87739,41.34263],[-72.87764,41.34193],[-72.87808,41.34133],[-72.87952,41.34051],[-72.87992,41.33931],[-72.88004,41.33774],[-72.87958,41.33686],[-72.879,41.33624],[-72.87809,41.33589],[-72.87632,41.33593],[-72.87383,41.33641],[-72.87258,41.33625],[-72.87077,41.33515],[-72.87012,41.33455],[-72.86883,41.33258],[-72.86898,41.3314],[-72.86938,41.33078],[-72.87138,41.32948],[-72.8722,41.32921],[-72.87278,41.32926],[-72.87524,41.33009],[-72.87642,41.32954],[-72.87664,41.32892],[-72.87641,41.32842],[-72.87473,41.32697],[-72.87495,41.32612],[-72.87528,41.32587],[-72.87574,41.32582],[-72.87722,41.32632],[-72.8785,41.32615],[-72.8807,41.32485],[-72.88257,41.32311],[-72.88301,41.32258],[-72.88349,41.32148],[-72.88379,41.31936],[-72.88357,41.31839],[-72.88362,41.31769],[-72.88431,41.31642],[-72.88421,41.31564],[-72.88397,41.31497],[-72.87456,41.3162],[-72.86145,41.31778],[-72.85583,41.31845],[-72.83842,41.32212],[-72.82303,41.32534],[-72.82338,41.33301],[-72.82356,41.34452],[-72.81846,41.34461],[-72.81856,41.34706],[-72.81351,41.34665],[-72.81372,41.35342],[-72.81378,41.36104],[-72.81444,41.38137],[-72.81457,41.38334],[-72.81818,41.3823]]]]}' FROM towns WHERE name = 'North Haven';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-71.90426,41.50612],[-71.9684,41.51476],[-71.96081,41.48552],[-71.95646,41.47251],[-71.9564,41.47233],[-71.956,41.47103],[-71.9468,41.44309],[-71.94746,41.4393],[-71.94822,41.43805],[-71.94787,41.43693],[-71.94826,41.43458],[-71.9481,41.43316],[-71.94749,41.43213],[-71.94541,41.43016],[-71.94482,41.42741],[-71.9441,41.42528],[-71.94351,41.42508],[-71.94242,41.42531],[-71.9413,41.4252],[-71.94102,41.425],[-71.94067,41.42383],[-71.94062,41.42355],[-71.94121,41.42226],[-71.94196,41.42141],[-71.94205,41.42101],[-71.94132,41.41963],[-71.94131,41.41911],[-71.941,41.41853],[-71.94126,41.41764],[-71.94171,41.41711],[-71.94171,41.41709],[-71.92588,41.41289],[-71.87237,41.3989],[-71.84003,41.39103],[-71.84001,41.39121],[-71.84028,41.39238],[-71.83997,41.39336],[-71.83823,41.3943],[-71.83728,41.39525],[-71.83644,41.3954],[-71.8338,41.39512],[-71.83252,41.39444],[-71.83189,41.39442],[-71.82597,41.39723],[-71.82522,41.39745],[-71.82326,41.39758],[-71.8224,41.3982],[-71.82177,41.4],[-71.81899,41.40197],[-71.81774,41.40249],[-71.8153,41.40283],[-71.81064,41.4023],[-71.80963,41.402],[-71.80489,41.39952],[-71.80397,41.39962],[-71.80245,41.40014],[-71.80131,41.40019],[-71.80084,41.39994],[-71.80011,41.39894],[-71.79931,41.39831],[-71.79793,41.39846],[-71.79685,41.39901],[-71.79621,41.39958],[-71.79566,41.39968],[-71.79535,41.4354],[-71.79491,41.44349],[-71.79392,41.4864],[-71.79383,41.49089],[-71.85371,41.49922],[-71.8644,41.50076],[-71.86709,41.50112],[-71.89835,41.50534],[-71.90341,41.50602],[-71.90426,41.50612]]]]}' FROM towns WHERE name = 'North Stonington';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.37184,41.07981],[-73.37106,41.08138],[-73.37103,41.08193],[-73.3719,41.08435],[-73.36857,41.10056],[-73.37163,41.1171],[-73.37178,41.11887],[-73.37152,41.12022],[-73.37542,41.12377],[-73.37786,41.12705],[-73.37698,41.14168],[-73.37657,41.149],[-73.37838,41.14928],[-73.38466,41.15034],[-73.40819,41.13921],[-73.4212,41.13595],[-73.4378,41.13181],[-73.43861,41.12821],[-73.44744,41.11227],[-73.45473,41.10264],[-73.46231,41.09176],[-73.4597,41.08921],[-73.45608,41.08533],[-73.44085,41.06768],[-73.44081,41.06653],[-73.44045,41.06498],[-73.44091,41.0642],[-73.44088,41.06393],[-73.43933,41.0642],[-73.43937,41.06455],[-73.43883,41.06517],[-73.43846,41.06515],[-73.43771,41.06455],[-73.43764,41.06377],[-73.43729,41.06332],[-73.43723,41.06287],[-73.43635,41.0629],[-73.43522,41.06247],[-73.43443,41.06132],[-73.43379,41.05865],[-73.43399,41.05777],[-73.43362,41.0568],[-73.43359,41.05443],[-73.43269,41.05265],[-73.43348,41.0524],[-73.43374,41.05136],[-73.43365,41.05068],[-73.43434,41.04868],[-73.43428,41.04801],[-73.43387,41.04741],[-73.43175,41.04653],[-73.43125,41.04596],[-73.43096,41.04523],[-73.43102,41.04406],[-73.43258,41.04091],[-73.43242,41.03902],[-73.43295,41.03707],[-73.4328,41.03455],[-73.43219,41.03454],[-73.43195,41.03452],[-73.43193,41.03399],[-73.43083,41.03374],[-73.43055,41.03329],[-73.42963,41.03342],[-73.42728,41.03274],[-73.42715,41.03256],[-73.42728,41.03189],[-73.42661,41.03089],[-73.42633,41.03101],[-73.42685,41.03154],[-73.42625,41.03184],[-73.42595,41.03154],[-73.42606,41.03184],[-73.42553,41.03204],[-73.42501,41.03189],[-73.42473,41.03136],[-73.42479,41.03094],[-73.42423,41.03084],[-73.42415,41.03134],[-73.42447,41.03154],[-73.42414,41.03173],[-73.42385,41.03268],[-73.4231,41.03278],[-73.42166,41.03258],[-73.42141,41.03231],[-73.42167,41.03223],[-73.42142,41.03206],[-73.42144,41.03168],[-73.42187,41.03166],[-73.42114,41.03151],[-73.42106,41.03208],[-73.4206,41.03215],[-73.41965,41.0312],[-73.4194,41.0312],[-73.41918,41.03283],[-73.41941,41.03315],[-73.41904,41.03353],[-73.41981,41.0335],[-73.42023,41.0345],[-73.41958,41.03552],[-73.41932,41.03562],[-73.41912,41.03545],[-73.41893,41.03577],[-73.41956,41.03637],[-73.41999,41.03622],[-73.42042,41.03655],[-73.42056,41.03635],[-73.42054,41.03665],[-73.42058,41.0363],[-73.42158,41.0364],[-73.42173,41.03728],[-73.42129,41.03735],[-73.4212,41.03757],[-73.42142,41.03763],[-73.42131,41.03785],[-73.42075,41.03795],[-73.42011,41.0386],[-73.42008,41.03917],[-73.42033,41.03952],[-73.42035,41.03992],[-73.42012,41.04032],[-73.42016,41.04085],[-73.41958,41.04107],[-73.41978,41.04117],[-73.41928,41.04169],[-73.4194,41.04237],[-73.41873,41.04354],[-73.41884,41.04479],[-73.41807,41.04591],[-73.41768,41.04579],[-73.41678,41.04643],[-73.41646,41.04636],[-73.41708,41.04371],[-73.41737,41.04361],[-73.41695,41.04329],[-73.41673,41.04161],[-73.41653,41.04154],[-73.41662,41.04116],[-73.4162,41.04019],[-73.41581,41.04024],[-73.41553,41.03986],[-73.41582,41.03969],[-73.4156,41.03901],[-73.41462,41.03956],[-73.4144,41.03949],[-73.41294,41.04111],[-73.41258,41.04188],[-73.41195,41.04198],[-73.41092,41.04166],[-73.41078,41.04178],[-73.41061,41.04203],[-73.41097,41.04255],[-73.41106,41.04325],[-73.41014,41.04563],[-73.41037,41.04615],[-73.4111,41.0467],[-73.4109,41.04755],[-73.41079,41.04787],[-73.41036,41.04807],[-73.41035,41.04852],[-73.40944,41.04905],[-73.40825,41.05054],[-73.40803,41.05162],[-73.40748,41.05246],[-73.40796,41.05304],[-73.40847,41.05249],[-73.40874,41.05249],[-73.40924,41.05302],[-73.40991,41.05327],[-73.41012,41.05374],[-73.411,41.05389],[-73.41117,41.05409],[-73.41034,41.05462],[-73.4102,41.05502],[-73.40958,41.05541],[-73.40941,41.05529],[-73.4098,41.05457],[-73.40976,41.05407],[-73.40951,41.05394],[-73.40894,41.05434],[-73.40874,41.05494],[-73.4089,41.05531],[-73.40865,41.05596],[-73.4086,41.05664],[-73.40878,41.05711],[-73.40858,41.05771],[-73.40731,41.05746],[-73.40685,41.05916],[-73.40488,41.06145],[-73.40425,41.06145],[-73.40451,41.06123],[-73.40458,41.06048],[-73.40495,41.05973],[-73.40561,41.05898],[-73.40602,41.05813],[-73.40689,41.05791],[-73.40642,41.05691],[-73.40708,41.05636],[-73.40723,41.05559],[-73.40752,41.05559],[-73.40716,41.05499],[-73.40713,41.05391],[-73.40598,41.05366],[-73.40558,41.05319],[-73.40528,41.05211],[-73.40472,41.05214],[-73.40498,41.05261],[-73.40411,41.05296],[-73.40381,41.05338],[-73.40441,41.05491],[-73.40469,41.05513],[-73.40458,41.05543],[-73.40474,41.05591],[-73.40346,41.05705],[-73.40203,41.05655],[-73.40166,41.05623],[-73.40164,41.05575],[-73.40121,41.05573],[-73.40045,41.05498],[-73.40036,41.05345],[-73.39989,41.05225],[-73.40004,41.05155],[-73.39985,41.051],[-73.39919,41.05023],[-73.3995,41.04921],[-73.39877,41.04913],[-73.39947,41.04901],[-73.39872,41.04741],[-73.39583,41.0474],[-73.39477,41.04792],[-73.3948,41.05032],[-73.3942,41.05002],[-73.39411,41.04772],[-73.39396,41.04765],[-73.39403,41.05162],[-73.3955,41.05522],[-73.39529,41.05547],[-73.39558,41.05549],[-73.39606,41.05604],[-73.39566,41.05784],[-73.39501,41.05886],[-73.3947,41.06071],[-73.39521,41.06076],[-73.39556,41.06036],[-73.39685,41.06054],[-73.39751,41.06039],[-73.39728,41.06009],[-73.39818,41.05949],[-73.3981,41.05819],[-73.39771,41.05814],[-73.39747,41.05762],[-73.39838,41.05717],[-73.39851,41.05652],[-73.3989,41.05652],[-73.39883,41.05675],[-73.39898,41.05682],[-73.39931,41.05647],[-73.4013,41.0565],[-73.40313,41.0573],[-73.40238,41.05743],[-73.40214,41.0577],[-73.40137,41.0579],[-73.40133,41.05812],[-73.40011,41.0582],[-73.40001,41.05865],[-73.40019,41.05922],[-73.39984,41.05972],[-73.39937,41.06014],[-73.3985,41.06032],[-73.39798,41.06084],[-73.39761,41.06064],[-73.39782,41.06207],[-73.39813,41.06242],[-73.39864,41.06252],[-73.39859,41.06324],[-73.39881,41.06322],[-73.39915,41.06417],[-73.3985,41.06521],[-73.39879,41.06589],[-73.3987,41.06624],[-73.39842,41.06659],[-73.39739,41.06701],[-73.39726,41.06746],[-73.39768,41.06863],[-73.3974,41.06908],[-73.39771,41.06893],[-73.3985,41.06903],[-73.39998,41.06984],[-73.39981,41.06991],[-73.40003,41.07006],[-73.39987,41.07011],[-73.39997,41.07036],[-73.40044,41.07074],[-73.40004,41.07099],[-73.40014,41.07116],[-73.39978,41.07136],[-73.39986,41.07156],[-73.40039,41.07146],[-73.4005,41.07176],[-73.40002,41.07186],[-73.40036,41.07216],[-73.40129,41.07209],[-73.40117,41.07219],[-73.40148,41.07279],[-73.40122,41.07304],[-73.40242,41.07431],[-73.40269,41.07529],[-73.40294,41.07529],[-73.4028,41.07546],[-73.40095,41.07558],[-73.40059,41.07481],[-73.39911,41.07328],[-73.39732,41.0726],[-73.39495,41.07313],[-73.39484,41.0734],[-73.39508,41.0743],[-73.39505,41.0756],[-73.39492,41.0761],[-73.39433,41.07602],[-73.39371,41.07555],[-73.39355,41.075],[-73.39381,41.07495],[-73.39309,41.07357],[-73.39275,41.07365],[-73.39264,41.07317],[-73.39188,41.07305],[-73.39171,41.07285],[-73.39127,41.07292],[-73.39124,41.07255],[-73.39155,41.07242],[-73.39111,41.0724],[-73.39127,41.07197],[-73.39102,41.0719],[-73.39091,41.07142],[-73.39128,41.07075],[-73.39147,41.06972],[-73.39136,41.06945],[-73.38933,41.06895],[-73.38893,41.06927],[-73.38865,41.06987],[-73.38885,41.07002],[-73.38772,41.07052],[-73.38772,41.07032],[-73.38824,41.06984],[-73.38831,41.06892],[-73.38756,41.06737],[-73.3878,41.06662],[-73.3888,41.06577],[-73.3889,41.06427],[-73.38918,41.0641],[-73.38906,41.06403],[-73.38927,41.0631],[-73.3888,41.06273],[-73.38761,41.06282],[-73.38744,41.06275],[-73.38743,41.06242],[-73.38737,41.06275],[-73.38662,41.06277],[-73.38617,41.06317],[-73.38609,41.0639],[-73.38739,41.0644],[-73.38687,41.0647],[-73.38681,41.0644],[-73.38622,41.06405],[-73.38579,41.06445],[-73.38541,41.06552],[-73.38548,41.06614],[-73.38505,41.06649],[-73.38471,41.06729],[-73.38482,41.06767],[-73.38463,41.06876],[-73.38502,41.07039],[-73.38456,41.07044],[-73.38414,41.06944],[-73.38403,41.06816],[-73.38446,41.06612],[-73.38446,41.06464],[-73.38386,41.06419],[-73.38191,41.06476],[-73.3813,41.06374],[-73.38247,41.06302],[-73.38535,41.06267],[-73.38599,41.0614],[-73.38616,41.06142],[-73.38626,41.0606],[-73.38557,41.05937],[-73.38448,41.05887],[-73.38306,41.05865],[-73.38178,41.05894],[-73.38119,41.05844],[-73.38145,41.05899],[-73.38087,41.05939],[-73.38038,41.05917],[-73.38068,41.05944],[-73.38045,41.05974],[-73.37932,41.05954],[-73.37989,41.05987],[-73.37961,41.06174],[-73.379,41.06341],[-73.37813,41.06496],[-73.37763,41.06553],[-73.37724,41.06551],[-73.37732,41.06576],[-73.37685,41.06628],[-73.37436,41.0684],[-73.3738,41.06905],[-73.37364,41.06955],[-73.37305,41.06925],[-73.37397,41.06997],[-73.37436,41.0699],[-73.37429,41.0693],[-73.37466,41.06922],[-73.3752,41.0695],[-73.3749,41.0691],[-73.37537,41.0687],[-73.37581,41.06883],[-73.37664,41.06818],[-73.37644,41.06795],[-73.3768,41.0677],[-73.37758,41.06773],[-73.37757,41.0682],[-73.3781,41.0682],[-73.37809,41.0685],[-73.37778,41.06875],[-73.37642,41.0688],[-73.37519,41.07012],[-73.37552,41.07035],[-73.37562,41.0699],[-73.37648,41.07023],[-73.37663,41.0703],[-73.37655,41.0707],[-73.37697,41.07033],[-73.37671,41.06973],[-73.37726,41.0692],[-73.3776,41.06918],[-73.37824,41.06973],[-73.3774,41.07153],[-73.37793,41.07218],[-73.37797,41.07258],[-73.37713,41.07397],[-73.37741,41.07422],[-73.37822,41.0739],[-73.37874,41.07415],[-73.37842,41.07467],[-73.37781,41.07482],[-73.37701,41.07545],[-73.37618,41.07552],[-73.37611,41.07632],[-73.37583,41.07672],[-73.37453,41.07702],[-73.37399,41.07769],[-73.37345,41.07746],[-73.37198,41.07894],[-73.37171,41.07878],[-73.37313,41.07741],[-73.37285,41.07721],[-73.37272,41.07674],[-73.37323,41.07591],[-73.37206,41.07596],[-73.37139,41.07634],[-73.37127,41.07639],[-73.3713,41.07651],[-73.37133,41.07651],[-73.37208,41.07639],[-73.37224,41.07609],[-73.37251,41.07616],[-73.37224,41.07681],[-73.37168,41.07696],[-73.37139,41.07701],[-73.37184,41.07981]]],[[[-73.37726,41.04433],[-73.37898,41.04326],[-73.37993,41.04331],[-73.38018,41.04348],[-73.3801,41.04336],[-73.38064,41.04283],[-73.38038,41.04146],[-73.38076,41.04016],[-73.38029,41.03986],[-73.37823,41.03951],[-73.37782,41.03961],[-73.37728,41.04008],[-73.3754,41.04075],[-73.37342,41.04187],[-73.37317,41.04237],[-73.37351,41.04395],[-73.37449,41.04437],[-73.37578,41.04442],[-73.37726,41.04433]]],[[[-73.40314,41.02956],[-73.40306,41.02833],[-73.40341,41.02793],[-73.40434,41.02748],[-73.40485,41.02571],[-73.40563,41.02411],[-73.40699,41.02334],[-73.40874,41.02334],[-73.40882,41.0227],[-73.40729,41.02274],[-73.40582,41.02339],[-73.40333,41.02528],[-73.40124,41.02601],[-73.39882,41.0281],[-73.39868,41.02825],[-73.3988,41.02842],[-73.39924,41.0284],[-73.40057,41.02905],[-73.40078,41.02935],[-73.40054,41.02945],[-73.40214,41.02958],[-73.40314,41.02956]]],[[[-73.38616,41.0378],[-73.38681,41.03782],[-73.38905,41.0372],[-73.39133,41.03765],[-73.39129,41.03571],[-73.38979,41.03431],[-73.38794,41.03405],[-73.38787,41.03333],[-73.38865,41.03258],[-73.38849,41.03236],[-73.3874,41.03305],[-73.38621,41.0349],[-73.38567,41.0369],[-73.38578,41.0375],[-73.38616,41.0378]]],[[[-73.37059,41.07234],[-73.371,41.07469],[-73.37103,41.07466],[-73.37186,41.07249],[-73.37191,41.07167],[-73.3723,41.07099],[-73.37281,41.07097],[-73.37294,41.07027],[-73.37225,41.06997],[-73.37122,41.07134],[-73.3705,41.07171],[-73.37048,41.07171],[-73.37059,41.07234]]],[[[-73.39397,41.02937],[-73.39404,41.02924],[-73.39266,41.02864],[-73.39196,41.02794],[-73.39123,41.02796],[-73.3887,41.02873],[-73.38876,41.02901],[-73.389,41.02903],[-73.38987,41.02884],[-73.39309,41.02912],[-73.39397,41.02937]]],[[[-73.3746,41.03516],[-73.37385,41.03433],[-73.37299,41.0342],[-73.37233,41.0347],[-73.37225,41.0352],[-73.3724,41.03535],[-73.3746,41.03516]]],[[[-73.40612,41.04949],[-73.40565,41.05069],[-73.40603,41.05111],[-73.4065,41.05081],[-73.40695,41.04959],[-73.4068,41.04922],[-73.40648,41.04917],[-73.40612,41.04949]]],[[[-73.40807,41.03743],[-73.40787,41.03725],[-73.40791,41.03701],[-73.40851,41.03671],[-73.40847,41.03631],[-73.40873,41.03611],[-73.40893,41.03608],[-73.40931,41.03658],[-73.409,41.03606],[-73.40908,41.03556],[-73.40867,41.03571],[-73.40821,41.03561],[-73.40858,41.03573],[-73.40808,41.03623],[-73.40742,41.03618],[-73.40758,41.03646],[-73.40734,41.03663],[-73.40736,41.03718],[-73.4077,41.03725],[-73.40807,41.03743]]],[[[-73.37012,41.04691],[-73.37113,41.04584],[-73.37206,41.04582],[-73.37117,41.04539],[-73.37054,41.04549],[-73.37006,41.04589],[-73.36995,41.04599],[-73.36998,41.04704],[-73.37012,41.04691]]],[[[-73.3924,41.03443],[-73.39246,41.03478],[-73.39224,41.03491],[-73.39252,41.03518],[-73.39219,41.03556],[-73.39215,41.03601],[-73.39257,41.03598],[-73.3928,41.03473],[-73.3931,41.03426],[-73.39278,41.03413],[-73.3924,41.03443]]],[[[-73.3748,41.04897],[-73.37509,41.04889],[-73.37553,41.0483],[-73.37377,41.04852],[-73.3748,41.04897]]],[[[-73.39253,41.05911],[-73.39236,41.05996],[-73.39287,41.05989],[-73.39313,41.05944],[-73.39312,41.05906],[-73.39277,41.05896],[-73.39253,41.05911]]],[[[-73.37061,41.0585],[-73.37075,41.059],[-73.3717,41.05898],[-73.37107,41.05825],[-73.37087,41.05828],[-73.37061,41.0585]]],[[[-73.37772,41.04672],[-73.37869,41.04663],[-73.37865,41.04615],[-73.37836,41.04603],[-73.37772,41.04672]]],[[[-73.39116,41.03216],[-73.39119,41.03241],[-73.39199,41.03216],[-73.39191,41.03184],[-73.39152,41.03179],[-73.39116,41.03216]]],[[[-73.3857,41.04859],[-73.38633,41.04841],[-73.38576,41.04809],[-73.3857,41.04859]]],[[[-73.39442,41.03064],[-73.39462,41.03074],[-73.39534,41.03054],[-73.39473,41.03044],[-73.39442,41.03064]]],[[[-73.39104,41.04904],[-73.39106,41.04887],[-73.39028,41.04879],[-73.39006,41.04894],[-73.39065,41.04894],[-73.39104,41.04904]]],[[[-73.39667,41.03427],[-73.39662,41.03407],[-73.3962,41.03392],[-73.39579,41.03409],[-73.39642,41.03414],[-73.39667,41.03427]]],[[[-73.40841,41.03893],[-73.40858,41.03905],[-73.40886,41.0388],[-73.40862,41.03863],[-73.40841,41.03893]]],[[[-73.40482,41.04562],[-73.40481,41.04544],[-73.40446,41.04524],[-73.4044,41.04544],[-73.40464,41.04559],[-73.40482,41.04562]]],[[[-73.37696,41.03688],[-73.37757,41.03686],[-73.37674,41.03666],[-73.37696,41.03688]]],[[[-73.40619,41.03888],[-73.40632,41.03905],[-73.4066,41.03875],[-73.40641,41.0387],[-73.40619,41.03888]]],[[[-73.39284,41.03394],[-73.39274,41.03371],[-73.39245,41.03371],[-73.39284,41.03394]]],[[[-73.40428,41.04629],[-73.40417,41.04599],[-73.40393,41.04594],[-73.40428,41.04629]]],[[[-73.37051,41.05266],[-73.37027,41.05263],[-73.37037,41.05291],[-73.37051,41.05266]]],[[[-73.39075,41.03046],[-73.39124,41.03059],[-73.39079,41.03034],[-73.39075,41.03046]]],[[[-73.40602,41.04285],[-73.40628,41.04272],[-73.40616,41.04257],[-73.40602,41.04285]]],[[[-73.39163,41.04118],[-73.39169,41.0409],[-73.39155,41.04083],[-73.39163,41.04118]]]]}' FROM towns WHERE name = 'Norwalk';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.05158,41.58354],[-72.05426,41.58684],[-72.05783,41.59244],[-72.06366,41.59552],[-72.06707,41.59648],[-72.06807,41.59666],[-72.09154,41.5921],[-72.10736,41.58902],[-72.12688,41.55169],[-72.12754,41.55044],[-72.13737,41.5571],[-72.13756,41.55697],[-72.13814,41.55688],[-72.1383,41.5565],[-72.13788,41.55628],[-72.13772,41.55588],[-72.1372,41.55543],[-72.13706,41.5549],[-72.1356,41.55392],[-72.1354,41.55305],[-72.13371,41.55175],[-72.13531,41.53479],[-72.14219,41.52259],[-72.13989,41.51657],[-72.13365,41.49473],[-72.13473,41.49196],[-72.1356,41.48954],[-72.13548,41.48939],[-72.13384,41.48843],[-72.13341,41.48836],[-72.1322,41.48856],[-72.13086,41.48851],[-72.12995,41.48905],[-72.12909,41.48868],[-72.12754,41.48895],[-72.12595,41.48867],[-72.1258,41.48832],[-72.12425,41.48777],[-72.12247,41.48764],[-72.12197,41.48789],[-72.12155,41.48847],[-72.12046,41.48866],[-72.11887,41.48826],[-72.1155,41.48641],[-72.11445,41.48621],[-72.11274,41.48658],[-72.11221,41.4877],[-72.11066,41.48773],[-72.10891,41.4886],[-72.1085,41.48857],[-72.10763,41.48735],[-72.1069,41.48727],[-72.1067,41.48704],[-72.10712,41.4856],[-72.10686,41.48515],[-72.10565,41.48457],[-72.10447,41.48467],[-72.10244,41.48352],[-72.10212,41.48267],[-72.10081,41.48112],[-72.09947,41.48009],[-72.09895,41.47992],[-72.09842,41.47991],[-72.09778,41.48066],[-72.09545,41.48166],[-72.09331,41.48295],[-72.09267,41.48278],[-72.08072,41.47749],[-72.07798,41.47626],[-72.07252,41.4771],[-72.0688,41.50385],[-72.05945,41.49776],[-72.05291,41.50335],[-72.04191,41.51739],[-72.04829,41.52165],[-72.04717,41.52729],[-72.05186,41.52882],[-72.05134,41.53179],[-72.05077,41.53302],[-72.04988,41.53421],[-72.0481,41.53578],[-72.04725,41.53731],[-72.04728,41.53823],[-72.04847,41.54058],[-72.04839,41.54205],[-72.04815,41.54288],[-72.04793,41.54375],[-72.04706,41.5462],[-72.04593,41.54737],[-72.04534,41.54892],[-72.04576,41.55079],[-72.04707,41.55309],[-72.04851,41.55651],[-72.04866,41.55756],[-72.04818,41.55941],[-72.04823,41.56096],[-72.04453,41.56817],[-72.04504,41.56962],[-72.04757,41.57037],[-72.04836,41.57157],[-72.04943,41.57425],[-72.05049,41.58107],[-72.05089,41.58227],[-72.0511,41.58274],[-72.05158,41.58354]]]]}' FROM towns WHERE name = 'Norwich';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.24654,41.2886],[-72.24746,41.2898],[-72.2475,41.29037],[-72.24889,41.29078],[-72.24899,41.2915],[-72.24942,41.29198],[-72.24918,41.29367],[-72.24951,41.29425],[-72.24969,41.29547],[-72.25058,41.29685],[-72.25085,41.2977],[-72.25065,41.29835],[-72.25129,41.29927],[-72.25129,41.30019],[-72.25166,41.30119],[-72.25243,41.30167],[-72.25263,41.30272],[-72.25298,41.30307],[-72.25262,41.30319],[-72.25283,41.30344],[-72.25293,41.30462],[-72.25172,41.30479],[-72.25184,41.30614],[-72.25117,41.30661],[-72.25618,41.31481],[-72.25839,41.31796],[-72.26321,41.32606],[-72.26428,41.32791],[-72.26532,41.33079],[-72.26923,41.33714],[-72.2706,41.34151],[-72.27191,41.34713],[-72.27235,41.349],[-72.27284,41.34893],[-72.28933,41.34596],[-72.30042,41.34305],[-72.30352,41.34261],[-72.31035,41.341],[-72.31419,41.34033],[-72.319,41.33861],[-72.32474,41.33854],[-72.32689,41.3383],[-72.33011,41.3376],[-72.33262,41.33481],[-72.33375,41.33261],[-72.33451,41.33464],[-72.33511,41.33511],[-72.34956,41.33224],[-72.34952,41.33154],[-72.35031,41.33122],[-72.35088,41.32989],[-72.35019,41.32817],[-72.35139,41.32829],[-72.35178,41.32855],[-72.35214,41.32832],[-72.35427,41.32815],[-72.35505,41.3274],[-72.35684,41.32693],[-72.35666,41.32665],[-72.35719,41.32638],[-72.35736,41.32573],[-72.35985,41.32531],[-72.36473,41.32429],[-72.3638,41.32387],[-72.35792,41.32071],[-72.35553,41.31908],[-72.35201,41.31598],[-72.35,41.31383],[-72.34768,41.31088],[-72.3463,41.30801],[-72.34488,41.30673],[-72.34349,41.30488],[-72.34254,41.30241],[-72.34187,41.29886],[-72.34181,41.29751],[-72.34244,41.29354],[-72.34272,41.29154],[-72.34274,41.29152],[-72.3407,41.29131],[-72.33979,41.29114],[-72.33997,41.29074],[-72.33979,41.29016],[-72.33872,41.29021],[-72.33763,41.28971],[-72.33704,41.28924],[-72.33655,41.28829],[-72.336,41.28796],[-72.33513,41.28826],[-72.3341,41.28951],[-72.33409,41.28986],[-72.33464,41.29043],[-72.33455,41.29078],[-72.33379,41.2905],[-72.33359,41.2901],[-72.33356,41.28941],[-72.33225,41.28918],[-72.33189,41.28873],[-72.332,41.28843],[-72.33172,41.28778],[-72.33169,41.28695],[-72.33372,41.28561],[-72.33357,41.28471],[-72.3328,41.28428],[-72.33242,41.28381],[-72.33301,41.28461],[-72.33294,41.28478],[-72.33333,41.28481],[-72.33337,41.28531],[-72.33234,41.28596],[-72.33205,41.28586],[-72.33191,41.28533],[-72.33177,41.28538],[-72.33091,41.28446],[-72.32991,41.2843],[-72.33036,41.2847],[-72.3303,41.285],[-72.32828,41.2857],[-72.32809,41.28593],[-72.32818,41.28643],[-72.32786,41.28642],[-72.32761,41.2869],[-72.32695,41.28685],[-72.32578,41.28605],[-72.32476,41.28602],[-72.32508,41.28545],[-72.32565,41.28557],[-72.32593,41.28527],[-72.32676,41.28525],[-72.32696,41.28492],[-72.32649,41.28465],[-72.32641,41.2843],[-72.32731,41.2836],[-72.32723,41.28325],[-72.32771,41.283],[-72.32753,41.28273],[-72.3266,41.28245],[-72.32658,41.28183],[-72.32638,41.28155],[-72.32588,41.28135],[-72.3255,41.2815],[-72.32566,41.2805],[-72.32609,41.2802],[-72.32579,41.27998],[-72.32487,41.2801],[-72.32442,41.27968],[-72.32442,41.27945],[-72.32348,41.2797],[-72.32283,41.27935],[-72.32233,41.27945],[-72.3227,41.27902],[-72.32333,41.27882],[-72.32314,41.27833],[-72.32367,41.27795],[-72.32361,41.27753],[-72.32323,41.2773],[-72.3231,41.27685],[-72.32249,41.27665],[-72.32276,41.2761],[-72.32324,41.276],[-72.3233,41.27528],[-72.3237,41.27505],[-72.32391,41.27458],[-72.32348,41.27498],[-72.32273,41.27505],[-72.32235,41.27473],[-72.32248,41.27393],[-72.32192,41.274],[-72.32203,41.27358],[-72.3218,41.27338],[-72.32043,41.2729],[-72.32031,41.2723],[-72.31986,41.2719],[-72.31939,41.27088],[-72.31884,41.2702],[-72.31808,41.2683],[-72.31809,41.26793],[-72.31766,41.26743],[-72.3178,41.2673],[-72.31759,41.26673],[-72.31654,41.26655],[-72.31686,41.26678],[-72.31667,41.2668],[-72.31591,41.26643],[-72.31409,41.26667],[-72.314,41.26677],[-72.31428,41.26722],[-72.31386,41.26785],[-72.31417,41.2684],[-72.3138,41.26845],[-72.31328,41.26797],[-72.31149,41.26902],[-72.31122,41.26897],[-72.31162,41.26869],[-72.31155,41.26857],[-72.31232,41.26752],[-72.31206,41.26707],[-72.31158,41.26724],[-72.31031,41.26802],[-72.31006,41.26849],[-72.31046,41.26889],[-72.30997,41.26949],[-72.31003,41.26984],[-72.31045,41.27001],[-72.31026,41.27031],[-72.31047,41.27061],[-72.31021,41.27089],[-72.30907,41.27036],[-72.30891,41.26974],[-72.30861,41.26954],[-72.30632,41.27008],[-72.30604,41.26978],[-72.30593,41.26923],[-72.30683,41.26861],[-72.30718,41.26879],[-72.3091,41.26799],[-72.30879,41.26736],[-72.30824,41.26704],[-72.30828,41.26664],[-72.30874,41.26641],[-72.3099,41.26639],[-72.31101,41.26529],[-72.31322,41.2645],[-72.31481,41.263],[-72.31484,41.26263],[-72.31462,41.2623],[-72.31502,41.26205],[-72.3152,41.26163],[-72.31425,41.26148],[-72.31331,41.26175],[-72.31304,41.26165],[-72.31271,41.262],[-72.30949,41.26072],[-72.30896,41.2608],[-72.30863,41.26052],[-72.30877,41.2604],[-72.30932,41.26065],[-72.30923,41.26032],[-72.30779,41.25937],[-72.30651,41.25794],[-72.30542,41.25809],[-72.30271,41.25936],[-72.29977,41.26008],[-72.29926,41.26006],[-72.29915,41.25961],[-72.29913,41.25976],[-72.29777,41.25993],[-72.29764,41.25963],[-72.29757,41.25983],[-72.29678,41.26],[-72.29631,41.25998],[-72.29626,41.2597],[-72.29578,41.25995],[-72.29418,41.25995],[-72.29281,41.25902],[-72.29244,41.25897],[-72.29091,41.25957],[-72.28915,41.25949],[-72.2887,41.25989],[-72.28655,41.26036],[-72.28379,41.26041],[-72.28376,41.26026],[-72.28325,41.26041],[-72.28315,41.26016],[-72.28298,41.26038],[-72.28107,41.26078],[-72.28088,41.26065],[-72.28093,41.26078],[-72.28057,41.26085],[-72.28025,41.26083],[-72.28012,41.26058],[-72.2802,41.26083],[-72.28003,41.2609],[-72.27955,41.26103],[-72.2795,41.2609],[-72.27953,41.26105],[-72.27912,41.26115],[-72.27885,41.26115],[-72.27875,41.26095],[-72.27878,41.26117],[-72.27822,41.26125],[-72.2783,41.2614],[-72.27747,41.26145],[-72.27762,41.26167],[-72.27734,41.26185],[-72.27615,41.26199],[-72.27635,41.26224],[-72.27551,41.26262],[-72.27529,41.26252],[-72.27547,41.26282],[-72.27396,41.26341],[-72.27007,41.26413],[-72.26752,41.2643],[-72.26757,41.26448],[-72.2674,41.26435],[-72.26554,41.26478],[-72.26471,41.26467],[-72.26448,41.26417],[-72.26359,41.26442],[-72.26016,41.26454],[-72.25991,41.26424],[-72.26012,41.26389],[-72.25965,41.26364],[-72.25937,41.26317],[-72.25821,41.26347],[-72.25645,41.26331],[-72.25611,41.26316],[-72.25568,41.26251],[-72.25529,41.26246],[-72.25483,41.26351],[-72.25394,41.26446],[-72.25198,41.2658],[-72.24996,41.26635],[-72.24944,41.26692],[-72.24932,41.26767],[-72.24873,41.26832],[-72.24872,41.26885],[-72.24901,41.26945],[-72.24868,41.27005],[-72.24622,41.27149],[-72.24609,41.27112],[-72.24532,41.27041],[-72.24517,41.27124],[-72.24475,41.27176],[-72.24478,41.27209],[-72.24461,41.27211],[-72.24505,41.27286],[-72.24466,41.27351],[-72.24489,41.27394],[-72.24465,41.27491],[-72.24474,41.27551],[-72.24544,41.27703],[-72.24567,41.27738],[-72.24584,41.27728],[-72.24599,41.27748],[-72.24593,41.27803],[-72.24632,41.27858],[-72.24633,41.27906],[-72.24503,41.27961],[-72.24312,41.27985],[-72.24318,41.28028],[-72.24297,41.28048],[-72.24376,41.2817],[-72.24468,41.28233],[-72.24462,41.28368],[-72.24565,41.28627],[-72.24595,41.2867],[-72.24673,41.28665],[-72.24659,41.28685],[-72.24677,41.28718],[-72.24655,41.28733],[-72.2469,41.28748],[-72.24728,41.28803],[-72.24685,41.28817],[-72.24652,41.28787],[-72.24638,41.2879],[-72.24654,41.2886]]],[[[-72.33614,41.27894],[-72.3358,41.27872],[-72.33589,41.27854],[-72.33562,41.27842],[-72.33546,41.27792],[-72.33569,41.27769],[-72.33575,41.2772],[-72.33543,41.27697],[-72.33588,41.2768],[-72.33574,41.2762],[-72.33588,41.27597],[-72.33558,41.2757],[-72.33515,41.27592],[-72.33515,41.27577],[-72.33495,41.2758],[-72.3351,41.27567],[-72.33488,41.275],[-72.33406,41.27452],[-72.33404,41.2744],[-72.33421,41.2745],[-72.33415,41.2742],[-72.33381,41.27417],[-72.33398,41.27397],[-72.33389,41.27352],[-72.3334,41.27335],[-72.33364,41.27327],[-72.33327,41.27305],[-72.33346,41.27292],[-72.33338,41.2727],[-72.33311,41.27272],[-72.33333,41.2726],[-72.33331,41.27215],[-72.33293,41.27165],[-72.33302,41.2714],[-72.33268,41.27067],[-72.33235,41.2707],[-72.33251,41.27052],[-72.33202,41.2704],[-72.33226,41.27022],[-72.3322,41.27005],[-72.33186,41.27007],[-72.33208,41.26982],[-72.33156,41.26977],[-72.3318,41.2697],[-72.33177,41.26942],[-72.33141,41.2695],[-72.33172,41.26932],[-72.33131,41.26925],[-72.33164,41.2692],[-72.33119,41.2685],[-72.3309,41.26855],[-72.33108,41.2684],[-72.33087,41.26777],[-72.33038,41.26747],[-72.33047,41.26732],[-72.33022,41.26727],[-72.33029,41.2671],[-72.33015,41.2672],[-72.33022,41.26705],[-72.32985,41.26682],[-72.32951,41.26685],[-72.32962,41.26667],[-72.32935,41.2666],[-72.3295,41.26652],[-72.32923,41.26655],[-72.32937,41.2664],[-72.32895,41.26627],[-72.3288,41.266],[-72.32861,41.26605],[-72.3282,41.26535],[-72.32826,41.26515],[-72.32762,41.26477],[-72.32774,41.26467],[-72.32745,41.26405],[-72.3265,41.26319],[-72.32519,41.26247],[-72.32394,41.26109],[-72.32148,41.26109],[-72.32126,41.26094],[-72.32142,41.26051],[-72.3202,41.26039],[-72.31933,41.26059],[-72.31854,41.26121],[-72.31792,41.26218],[-72.31767,41.26373],[-72.31914,41.26873],[-72.3198,41.26995],[-72.3209,41.27085],[-72.3243,41.27223],[-72.33146,41.27669],[-72.33546,41.27879],[-72.33614,41.27894]]],[[[-72.33906,41.28956],[-72.33977,41.28894],[-72.33975,41.28812],[-72.33944,41.28747],[-72.33902,41.28744],[-72.33946,41.28739],[-72.33952,41.28689],[-72.33929,41.28667],[-72.33919,41.28577],[-72.33897,41.28572],[-72.33911,41.28557],[-72.33828,41.28469],[-72.33799,41.28462],[-72.33804,41.28399],[-72.33787,41.28384],[-72.3378,41.28397],[-72.33754,41.28339],[-72.33715,41.28329],[-72.33696,41.28277],[-72.33667,41.28262],[-72.3365,41.28274],[-72.3364,41.28257],[-72.33638,41.28204],[-72.33585,41.28142],[-72.33617,41.28142],[-72.33621,41.28117],[-72.33545,41.28109],[-72.33541,41.28044],[-72.33479,41.27994],[-72.33455,41.27944],[-72.33269,41.27814],[-72.32866,41.27579],[-72.32501,41.27321],[-72.32252,41.27198],[-72.322,41.27185],[-72.32184,41.272],[-72.32189,41.27228],[-72.32431,41.27348],[-72.32469,41.27466],[-72.32508,41.27481],[-72.32469,41.27486],[-72.32379,41.27628],[-72.32405,41.27703],[-72.32442,41.27718],[-72.32465,41.27678],[-72.3255,41.27743],[-72.32547,41.27718],[-72.32575,41.27701],[-72.32637,41.27718],[-72.32664,41.27753],[-72.32636,41.27771],[-72.32656,41.27791],[-72.32595,41.27783],[-72.32718,41.27851],[-72.32727,41.27888],[-72.32789,41.27948],[-72.32785,41.27978],[-72.32893,41.28003],[-72.32948,41.28041],[-72.32898,41.28068],[-72.32951,41.28153],[-72.33073,41.28161],[-72.33097,41.28146],[-72.33067,41.28211],[-72.32951,41.28228],[-72.32967,41.28288],[-72.32997,41.28303],[-72.33117,41.28268],[-72.33118,41.28208],[-72.33157,41.28198],[-72.33224,41.28248],[-72.33227,41.28281],[-72.33206,41.28288],[-72.33265,41.28316],[-72.33271,41.28271],[-72.33305,41.28274],[-72.33324,41.28344],[-72.33375,41.28341],[-72.33388,41.28379],[-72.33448,41.28416],[-72.33453,41.28441],[-72.33425,41.28459],[-72.33436,41.28506],[-72.334,41.28523],[-72.33397,41.28588],[-72.33211,41.28708],[-72.33206,41.28788],[-72.33248,41.2889],[-72.33375,41.28906],[-72.33485,41.28783],[-72.33538,41.28758],[-72.33606,41.28759],[-72.33745,41.28834],[-72.33816,41.28936],[-72.33906,41.28956]]],[[[-72.31746,41.26643],[-72.3175,41.26623],[-72.31662,41.26338],[-72.31599,41.26363],[-72.31374,41.26545],[-72.31385,41.26582],[-72.31463,41.26597],[-72.31423,41.26637],[-72.31549,41.2663],[-72.31561,41.2662],[-72.31541,41.26605],[-72.31717,41.26645],[-72.31746,41.26643]]],[[[-72.31593,41.26088],[-72.31566,41.26138],[-72.31566,41.26223],[-72.31618,41.26268],[-72.31647,41.26243],[-72.31608,41.26173],[-72.31624,41.26071],[-72.31596,41.26016],[-72.31222,41.25958],[-72.31182,41.25983],[-72.31402,41.26015],[-72.31368,41.2604],[-72.31396,41.26073],[-72.3139,41.26105],[-72.31358,41.2611],[-72.31373,41.26125],[-72.31512,41.26115],[-72.31551,41.26053],[-72.31592,41.26043],[-72.31593,41.26088]]],[[[-72.33376,41.28449],[-72.33402,41.28499],[-72.33424,41.28506],[-72.33412,41.28456],[-72.3344,41.28419],[-72.33342,41.28381],[-72.33385,41.28421],[-72.33326,41.28423],[-72.33376,41.28449]]],[[[-72.32121,41.27155],[-72.32003,41.27105],[-72.32034,41.27165],[-72.32089,41.272],[-72.32121,41.27155]]],[[[-72.33224,41.28346],[-72.33238,41.28321],[-72.33206,41.28296],[-72.33189,41.28316],[-72.33176,41.28286],[-72.33162,41.28306],[-72.33193,41.28348],[-72.33224,41.28346]]],[[[-72.33316,41.28413],[-72.3332,41.28371],[-72.33301,41.28376],[-72.33251,41.28328],[-72.33244,41.28348],[-72.33286,41.28378],[-72.33316,41.28413]]]]}' FROM towns WHERE name = 'Old Lyme';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.34181,41.29751],[-72.34187,41.29886],[-72.34254,41.30241],[-72.34349,41.30488],[-72.34488,41.30673],[-72.3463,41.30801],[-72.34768,41.31088],[-72.35,41.31383],[-72.35201,41.31598],[-72.35553,41.31908],[-72.35792,41.32071],[-72.3638,41.32387],[-72.36473,41.32429],[-72.36476,41.32429],[-72.36689,41.32335],[-72.3672,41.32295],[-72.36754,41.32223],[-72.36687,41.32192],[-72.36726,41.32185],[-72.3673,41.32155],[-72.36795,41.32128],[-72.36758,41.32048],[-72.377,41.31502],[-72.39772,41.31308],[-72.41128,41.30875],[-72.41562,41.30694],[-72.42061,41.3057],[-72.42066,41.30555],[-72.42152,41.3026],[-72.42418,41.29981],[-72.42622,41.29806],[-72.4274,41.29612],[-72.41496,41.26388],[-72.41059,41.25943],[-72.4104,41.25948],[-72.40967,41.25947],[-72.40962,41.25863],[-72.40656,41.2587],[-72.40188,41.25754],[-72.40001,41.25671],[-72.39948,41.25599],[-72.39889,41.25579],[-72.39993,41.25664],[-72.39887,41.25759],[-72.39878,41.25776],[-72.39895,41.25784],[-72.39874,41.25818],[-72.39845,41.25821],[-72.39858,41.25843],[-72.39919,41.25856],[-72.39914,41.25868],[-72.39805,41.25868],[-72.39742,41.25786],[-72.39764,41.25698],[-72.39847,41.25649],[-72.39863,41.25616],[-72.39759,41.25693],[-72.39728,41.25816],[-72.39641,41.25818],[-72.39605,41.25756],[-72.39568,41.25746],[-72.39434,41.2582],[-72.39433,41.25848],[-72.39267,41.25833],[-72.39248,41.25855],[-72.39179,41.2583],[-72.39125,41.2574],[-72.39168,41.25697],[-72.39129,41.25627],[-72.39025,41.25542],[-72.38966,41.2546],[-72.38912,41.25445],[-72.38924,41.25435],[-72.38908,41.25385],[-72.38882,41.25392],[-72.38832,41.25347],[-72.38848,41.25317],[-72.38794,41.2523],[-72.38825,41.25217],[-72.38821,41.2514],[-72.38647,41.24935],[-72.38636,41.24902],[-72.38655,41.24892],[-72.38608,41.24852],[-72.38627,41.24845],[-72.38355,41.24545],[-72.38347,41.24492],[-72.38413,41.2445],[-72.38354,41.24482],[-72.38234,41.24387],[-72.38223,41.24352],[-72.38269,41.24345],[-72.3823,41.24345],[-72.38239,41.2432],[-72.38211,41.24272],[-72.38247,41.24262],[-72.38223,41.24265],[-72.38218,41.24247],[-72.38237,41.24242],[-72.38215,41.24245],[-72.382,41.24215],[-72.38224,41.24202],[-72.38126,41.24107],[-72.38113,41.24067],[-72.38136,41.2404],[-72.38126,41.2403],[-72.37939,41.24127],[-72.3778,41.24239],[-72.37582,41.24284],[-72.37376,41.24396],[-72.37135,41.24471],[-72.37021,41.24475],[-72.36813,41.24433],[-72.36742,41.24408],[-72.36754,41.24383],[-72.36735,41.244],[-72.36492,41.24342],[-72.36494,41.2431],[-72.36483,41.24355],[-72.36464,41.2436],[-72.36276,41.24334],[-72.3604,41.24352],[-72.3602,41.24322],[-72.35998,41.24329],[-72.36028,41.24347],[-72.36009,41.24364],[-72.35977,41.24356],[-72.35976,41.24386],[-72.35758,41.24503],[-72.35768,41.24516],[-72.35721,41.24548],[-72.356,41.24591],[-72.35604,41.24618],[-72.35143,41.24815],[-72.35,41.24829],[-72.34979,41.24857],[-72.34859,41.24849],[-72.34846,41.24872],[-72.34766,41.24879],[-72.34632,41.24869],[-72.34525,41.24889],[-72.34496,41.24884],[-72.34475,41.24851],[-72.34489,41.24889],[-72.34442,41.24881],[-72.34456,41.24856],[-72.34431,41.24909],[-72.34407,41.24911],[-72.34396,41.24879],[-72.34367,41.24951],[-72.34318,41.24961],[-72.34235,41.24943],[-72.34107,41.24976],[-72.33835,41.24903],[-72.33612,41.24817],[-72.33564,41.24288],[-72.33588,41.24278],[-72.33563,41.24268],[-72.33602,41.24807],[-72.33642,41.2487],[-72.33621,41.25127],[-72.33708,41.25192],[-72.33776,41.25195],[-72.339,41.25265],[-72.33927,41.25372],[-72.33935,41.25562],[-72.34112,41.25602],[-72.34128,41.25498],[-72.343,41.25403],[-72.34323,41.25353],[-72.3439,41.25331],[-72.34473,41.25343],[-72.34494,41.25373],[-72.34806,41.25431],[-72.35041,41.25399],[-72.34562,41.26123],[-72.34469,41.26092],[-72.34253,41.26097],[-72.34264,41.2616],[-72.34279,41.26145],[-72.34442,41.26167],[-72.34394,41.26352],[-72.34344,41.26365],[-72.34328,41.26407],[-72.34339,41.26547],[-72.34351,41.26599],[-72.34451,41.26692],[-72.34524,41.26792],[-72.34602,41.26859],[-72.3491,41.27017],[-72.34925,41.27015],[-72.34882,41.26972],[-72.34891,41.26947],[-72.34973,41.26937],[-72.35013,41.26967],[-72.34998,41.27012],[-72.35025,41.26965],[-72.35082,41.26995],[-72.352,41.26875],[-72.35211,41.26835],[-72.35344,41.26808],[-72.35507,41.26738],[-72.35621,41.26731],[-72.35676,41.26696],[-72.35691,41.26626],[-72.35874,41.26502],[-72.35953,41.26527],[-72.35937,41.26579],[-72.36072,41.26607],[-72.36107,41.26639],[-72.36117,41.26722],[-72.36059,41.26814],[-72.36113,41.26829],[-72.36064,41.26837],[-72.36109,41.26882],[-72.36103,41.26907],[-72.36128,41.26924],[-72.36139,41.26977],[-72.3611,41.26984],[-72.36118,41.27012],[-72.36012,41.27114],[-72.36007,41.27129],[-72.36027,41.27131],[-72.36003,41.27161],[-72.36134,41.27291],[-72.36188,41.27316],[-72.36211,41.27346],[-72.36203,41.27399],[-72.36082,41.27598],[-72.35875,41.27511],[-72.36098,41.27648],[-72.36055,41.27756],[-72.36061,41.27793],[-72.36023,41.27808],[-72.36015,41.27781],[-72.35948,41.27751],[-72.35945,41.27731],[-72.35885,41.27738],[-72.35859,41.27775],[-72.3583,41.27765],[-72.35829,41.27733],[-72.35749,41.2774],[-72.35742,41.27605],[-72.35768,41.27573],[-72.35697,41.27545],[-72.35688,41.27495],[-72.35723,41.27468],[-72.35725,41.27438],[-72.3586,41.27491],[-72.35668,41.27391],[-72.35644,41.27326],[-72.35605,41.27313],[-72.35552,41.2733],[-72.35356,41.27223],[-72.35287,41.2727],[-72.35236,41.27255],[-72.35225,41.27225],[-72.35182,41.27245],[-72.35158,41.27335],[-72.35185,41.27345],[-72.35154,41.2737],[-72.35184,41.274],[-72.35157,41.27387],[-72.35181,41.2755],[-72.35169,41.2761],[-72.35186,41.27637],[-72.3517,41.27645],[-72.3519,41.27672],[-72.3515,41.27719],[-72.3517,41.27744],[-72.35151,41.27752],[-72.35168,41.27757],[-72.35126,41.27792],[-72.35134,41.27844],[-72.35103,41.27849],[-72.35111,41.27872],[-72.3509,41.27892],[-72.35101,41.27954],[-72.35086,41.27947],[-72.351,41.27989],[-72.3515,41.28027],[-72.35119,41.28062],[-72.35053,41.28056],[-72.35036,41.28121],[-72.35056,41.28141],[-72.35025,41.28154],[-72.35011,41.28194],[-72.35034,41.28219],[-72.35015,41.28224],[-72.35025,41.28251],[-72.35003,41.28259],[-72.34998,41.28324],[-72.34977,41.28334],[-72.34997,41.28364],[-72.3499,41.28386],[-72.34918,41.28491],[-72.34895,41.28546],[-72.34906,41.28586],[-72.34882,41.28576],[-72.3487,41.28596],[-72.34906,41.28641],[-72.34887,41.28661],[-72.34891,41.28708],[-72.34913,41.28718],[-72.34868,41.28768],[-72.34863,41.28845],[-72.34886,41.28868],[-72.34858,41.28908],[-72.34857,41.28955],[-72.34816,41.2898],[-72.34846,41.29013],[-72.34821,41.29063],[-72.34814,41.2915],[-72.34745,41.2919],[-72.34638,41.29185],[-72.34274,41.29152],[-72.34181,41.29751]],[[-72.35516,41.26161],[-72.3552,41.26186],[-72.35441,41.26156],[-72.35303,41.26176],[-72.353,41.26159],[-72.35353,41.26131],[-72.35337,41.26094],[-72.35151,41.25989],[-72.35006,41.26013],[-72.34875,41.26093],[-72.3477,41.26083],[-72.3466,41.2612],[-72.34587,41.2612],[-72.35099,41.25382],[-72.35159,41.25342],[-72.3527,41.25339],[-72.35347,41.25292],[-72.3536,41.25257],[-72.35345,41.25242],[-72.35371,41.25207],[-72.35513,41.25147],[-72.35635,41.25165],[-72.35698,41.25248],[-72.35824,41.25238],[-72.3593,41.25196],[-72.36142,41.25211],[-72.36169,41.25163],[-72.36273,41.25146],[-72.36294,41.25114],[-72.36529,41.25161],[-72.36526,41.25209],[-72.36588,41.25339],[-72.36609,41.25466],[-72.3659,41.25581],[-72.36553,41.25644],[-72.36378,41.25798],[-72.36375,41.25843],[-72.36272,41.26055],[-72.36186,41.2612],[-72.36053,41.26147],[-72.35859,41.26077],[-72.35785,41.2603],[-72.35702,41.26119],[-72.35668,41.26122],[-72.35657,41.26154],[-72.35647,41.26139],[-72.3554,41.26152],[-72.35516,41.26161]]],[[[-72.35239,41.27188],[-72.35259,41.27218],[-72.35245,41.27235],[-72.35267,41.27255],[-72.35327,41.27218],[-72.35048,41.27077],[-72.35239,41.27188]]]]}' FROM towns WHERE name = 'Old Saybrook';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.98815,41.29614],[-72.99203,41.29662],[-72.99307,41.29508],[-73.00606,41.2912],[-73.01478,41.29261],[-73.02798,41.2952],[-73.03481,41.29576],[-73.03532,41.29579],[-73.03623,41.29552],[-73.04299,41.29325],[-73.04606,41.29333],[-73.0478,41.29366],[-73.04793,41.29331],[-73.04987,41.29391],[-73.05097,41.29401],[-73.05319,41.29347],[-73.05576,41.2919],[-73.05724,41.29],[-73.05763,41.2892],[-73.05879,41.28813],[-73.05899,41.28758],[-73.0588,41.28708],[-73.05987,41.28616],[-73.06039,41.28419],[-73.06131,41.28309],[-73.06166,41.28262],[-73.06094,41.28217],[-73.06007,41.28137],[-73.05889,41.27954],[-73.05864,41.27857],[-73.05873,41.27747],[-73.05944,41.27502],[-73.06189,41.27095],[-73.06421,41.26901],[-73.06574,41.26816],[-73.07074,41.26632],[-73.07503,41.26413],[-73.07719,41.26234],[-73.07953,41.25864],[-73.0796,41.25849],[-73.07335,41.25511],[-73.04442,41.23953],[-73.02646,41.23416],[-72.99174,41.22659],[-72.98425,41.22493],[-72.98416,41.22503],[-72.983,41.2252],[-72.9831,41.22555],[-72.98272,41.2265],[-72.98261,41.22778],[-72.98294,41.22815],[-72.98403,41.22793],[-72.98456,41.22878],[-72.98384,41.22978],[-72.98386,41.2303],[-72.98358,41.2307],[-72.98383,41.23122],[-72.98478,41.2319],[-72.985,41.24893],[-72.97917,41.25387],[-72.97335,41.25995],[-72.97663,41.26228],[-72.97809,41.26166],[-72.97928,41.26154],[-72.97987,41.26179],[-72.98052,41.26239],[-72.9805,41.26429],[-72.97951,41.26671],[-72.98078,41.26938],[-72.9797,41.27238],[-72.97819,41.28364],[-72.98242,41.29311],[-72.98346,41.29561],[-72.98815,41.29614]]]]}' FROM towns WHERE name = 'Orange';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.10858,41.47575],[-73.12446,41.47448],[-73.14189,41.47535],[-73.15721,41.47645],[-73.15746,41.47648],[-73.1576,41.47635],[-73.15728,41.47553],[-73.15734,41.4749],[-73.15708,41.47453],[-73.15713,41.4737],[-73.15667,41.473],[-73.1566,41.4721],[-73.15613,41.47128],[-73.15635,41.47038],[-73.15548,41.46968],[-73.15552,41.46868],[-73.15564,41.46788],[-73.15592,41.46751],[-73.15586,41.46688],[-73.15605,41.46618],[-73.15581,41.46451],[-73.15678,41.46216],[-73.15667,41.46166],[-73.15726,41.46091],[-73.15697,41.46034],[-73.15728,41.45937],[-73.15789,41.45844],[-73.15867,41.45777],[-73.15872,41.45707],[-73.15861,41.45672],[-73.15825,41.45677],[-73.15813,41.45605],[-73.15774,41.45602],[-73.15795,41.4557],[-73.15777,41.45545],[-73.15809,41.45477],[-73.15801,41.4543],[-73.15673,41.45242],[-73.15641,41.45137],[-73.15713,41.45023],[-73.15695,41.44838],[-73.15728,41.44793],[-73.1588,41.44758],[-73.15896,41.44711],[-73.15882,41.44656],[-73.15923,41.44558],[-73.15966,41.44541],[-73.16054,41.44551],[-73.16058,41.44386],[-73.16102,41.44381],[-73.16337,41.44452],[-73.16383,41.44429],[-73.16404,41.44332],[-73.16444,41.44282],[-73.16336,41.43997],[-73.16367,41.43897],[-73.16474,41.43832],[-73.16456,41.43787],[-73.16414,41.43755],[-73.16393,41.4364],[-73.16257,41.43542],[-73.16233,41.4348],[-73.16257,41.4338],[-73.18025,41.42146],[-73.1811,41.42116],[-73.18252,41.41994],[-73.20489,41.40557],[-73.20524,41.40529],[-73.20375,41.40429],[-73.19879,41.40019],[-73.19706,41.39759],[-73.1955,41.39651],[-73.19407,41.39603],[-73.18958,41.39538],[-73.18755,41.39485],[-73.18509,41.39382],[-73.18325,41.39265],[-73.18208,41.39092],[-73.18202,41.38895],[-73.18225,41.38767],[-73.18312,41.38583],[-73.18493,41.38383],[-73.18536,41.38261],[-73.18483,41.38136],[-73.18068,41.37663],[-73.18005,41.37593],[-73.18,41.37586],[-73.17673,41.37305],[-73.17155,41.36908],[-73.16905,41.36752],[-73.16326,41.36739],[-73.15822,41.36793],[-73.15603,41.3678],[-73.155,41.36753],[-73.15453,41.36703],[-73.1545,41.36623],[-73.1552,41.36286],[-73.15509,41.36243],[-73.15433,41.36143],[-73.15371,41.36101],[-73.15349,41.36091],[-73.15339,41.36088],[-73.1528,41.36066],[-73.15178,41.35975],[-73.14719,41.35425],[-73.14406,41.3519],[-73.14349,41.3515],[-73.14251,41.3521],[-73.12054,41.36525],[-73.12024,41.36583],[-73.1187,41.36642],[-73.11668,41.36797],[-73.11245,41.37023],[-73.11088,41.37213],[-73.11028,41.37318],[-73.10992,41.37493],[-73.10732,41.38246],[-73.08539,41.38373],[-73.08065,41.38794],[-73.0802,41.38829],[-73.08028,41.38842],[-73.09025,41.39817],[-73.08398,41.41834],[-73.07804,41.44226],[-73.07584,41.45093],[-73.07688,41.45165],[-73.09561,41.46409],[-73.10277,41.4685],[-73.10299,41.46935],[-73.10375,41.47027],[-73.10386,41.47067],[-73.10377,41.47277],[-73.10401,41.47425],[-73.10386,41.47577],[-73.1039,41.47609],[-73.10858,41.47575]]]]}' FROM towns WHERE name = 'Oxford';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-71.86257,41.63426],[-71.85168,41.72148],[-71.84759,41.76101],[-71.84758,41.76124],[-71.84811,41.76119],[-71.92595,41.75184],[-71.92634,41.75179],[-71.92636,41.75179],[-71.92675,41.75097],[-71.92666,41.75022],[-71.92616,41.74899],[-71.92548,41.74839],[-71.924,41.74767],[-71.92276,41.74676],[-71.92226,41.74614],[-71.92148,41.74446],[-71.9213,41.74337],[-71.92149,41.74177],[-71.92219,41.74147],[-71.92243,41.74057],[-71.92335,41.73962],[-71.92364,41.73802],[-71.92231,41.73667],[-71.9218,41.73415],[-71.9218,41.73413],[-71.92182,41.7341],[-71.92221,41.73318],[-71.92569,41.72874],[-71.92774,41.72712],[-71.9303,41.72592],[-71.93288,41.726],[-71.93596,41.7267],[-71.93716,41.72678],[-71.93936,41.72641],[-71.94003,41.72371],[-71.94151,41.72204],[-71.9427,41.72127],[-71.94947,41.71848],[-71.95038,41.71778],[-71.9515,41.71651],[-71.95194,41.71484],[-71.95281,41.71394],[-71.95327,41.71394],[-71.95428,41.71437],[-71.95806,41.71705],[-71.95973,41.71777],[-71.96225,41.71823],[-71.96317,41.71813],[-71.96384,41.71756],[-71.9651,41.71523],[-71.96516,41.71468],[-71.96469,41.71356],[-71.96456,41.71271],[-71.96504,41.71141],[-71.96567,41.71074],[-71.96677,41.71009],[-71.97002,41.70742],[-71.97266,41.70635],[-71.97342,41.70573],[-71.97326,41.70516],[-71.97084,41.70171],[-71.97097,41.70111],[-71.97135,41.70058],[-71.97249,41.69999],[-71.97241,41.69951],[-71.97117,41.69806],[-71.96925,41.69651],[-71.96886,41.69561],[-71.96903,41.69386],[-71.96851,41.69199],[-71.96844,41.69034],[-71.96923,41.68859],[-71.96875,41.68619],[-71.96771,41.68467],[-71.96733,41.68352],[-71.96671,41.68289],[-71.96546,41.68257],[-71.96404,41.68311],[-71.96277,41.68296],[-71.96212,41.68226],[-71.96101,41.68031],[-71.96075,41.67881],[-71.96072,41.67704],[-71.96222,41.67277],[-71.96229,41.67177],[-71.9617,41.67017],[-71.96122,41.66952],[-71.95937,41.6679],[-71.95919,41.6673],[-71.95942,41.6663],[-71.9594,41.66537],[-71.95909,41.66495],[-71.95425,41.66489],[-71.95948,41.62694],[-71.95959,41.62494],[-71.95969,41.62356],[-71.95962,41.62354],[-71.95627,41.62306],[-71.91378,41.62784],[-71.88943,41.6303],[-71.88928,41.6303],[-71.88923,41.63033],[-71.86273,41.63328],[-71.86268,41.63328],[-71.86257,41.63426]]]]}' FROM towns WHERE name = 'Plainfield';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.82228,41.67987],[-72.85323,41.68486],[-72.88414,41.6902],[-72.89904,41.68865],[-72.89851,41.68628],[-72.889,41.64503],[-72.88789,41.64046],[-72.88712,41.64048],[-72.83992,41.64558],[-72.82676,41.64701],[-72.82684,41.64718],[-72.82912,41.65995],[-72.82517,41.67633],[-72.82434,41.67708],[-72.82131,41.67974],[-72.82228,41.67987]]]]}' FROM towns WHERE name = 'Plainville';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.00541,41.70725],[-73.00621,41.7071],[-73.0541,41.7016],[-73.06135,41.70089],[-73.06404,41.67894],[-73.06906,41.63264],[-73.05511,41.60185],[-73.0542,41.59982],[-73.05364,41.60002],[-73.03988,41.60562],[-73.02387,41.60435],[-73.0222,41.60437],[-73.02489,41.61551],[-73.02544,41.61769],[-73.00811,41.61926],[-73.00364,41.62163],[-72.98747,41.63032],[-72.98927,41.63774],[-72.99261,41.65178],[-72.99678,41.67152],[-73.00076,41.68668],[-73.00439,41.703],[-73.00492,41.70532],[-73.00541,41.70725]]]]}' FROM towns WHERE name = 'Plymouth';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-71.92916,41.86491],[-71.9351,41.86594],[-71.93567,41.86724],[-71.93687,41.86892],[-71.93707,41.87002],[-71.93659,41.87104],[-71.93591,41.87361],[-71.93538,41.87686],[-71.9359,41.87871],[-71.93586,41.87916],[-71.9337,41.88257],[-71.93369,41.88285],[-71.9351,41.88465],[-71.93549,41.88542],[-71.93646,41.8878],[-71.93828,41.89335],[-71.94009,41.89697],[-71.94267,41.89922],[-71.94473,41.90152],[-71.94524,41.90235],[-71.94538,41.90397],[-71.94583,41.90585],[-71.94629,41.90645],[-71.9472,41.91344],[-71.94723,41.91349],[-71.94759,41.91347],[-72.02231,41.91173],[-72.0568,41.91176],[-72.0576,41.91176],[-72.0576,41.91163],[-72.05678,41.89253],[-72.0565,41.86475],[-72.05611,41.85816],[-72.05543,41.82674],[-72.05541,41.82609],[-72.05539,41.82609],[-72.04127,41.82612],[-72.03182,41.82243],[-72.02937,41.81446],[-72.02399,41.79964],[-72.02391,41.79947],[-72.02313,41.79942],[-72.00232,41.79831],[-72.0025,41.81262],[-71.98789,41.81238],[-71.98834,41.81377],[-71.98838,41.8151],[-71.98714,41.81822],[-71.98713,41.82104],[-71.91692,41.82083],[-71.9169,41.82083],[-71.91687,41.82088],[-71.91542,41.82333],[-71.91507,41.82545],[-71.91626,41.8264],[-71.91852,41.82723],[-71.92079,41.82976],[-71.92107,41.83023],[-71.92131,41.83193],[-71.92207,41.83296],[-71.92347,41.83418],[-71.9252,41.83511],[-71.92542,41.83586],[-71.92579,41.83611],[-71.92587,41.83718],[-71.92613,41.83781],[-71.927,41.83921],[-71.92824,41.83996],[-71.92954,41.84026],[-71.93016,41.84061],[-71.93076,41.84131],[-71.93251,41.84448],[-71.93291,41.84481],[-71.93466,41.84489],[-71.93668,41.84427],[-71.93821,41.84479],[-71.93864,41.84557],[-71.93867,41.84792],[-71.93757,41.85176],[-71.93656,41.85301],[-71.93516,41.85413],[-71.9346,41.85485],[-71.93484,41.858],[-71.93249,41.86094],[-71.93136,41.86287],[-71.92935,41.86474],[-71.92909,41.86491],[-71.92916,41.86491]]]]}' FROM towns WHERE name = 'Pomfret';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.56554,41.54479],[-72.5655,41.54524],[-72.5648,41.54608],[-72.56493,41.54648],[-72.56415,41.54696],[-72.56399,41.54753],[-72.56349,41.5477],[-72.5615,41.55017],[-72.56163,41.55152],[-72.56143,41.55207],[-72.56154,41.55242],[-72.5613,41.55265],[-72.56153,41.55282],[-72.5618,41.55447],[-72.56123,41.55502],[-72.56127,41.55572],[-72.56104,41.55667],[-72.56068,41.55707],[-72.56146,41.55867],[-72.56145,41.56389],[-72.56116,41.56803],[-72.55657,41.57127],[-72.55308,41.57669],[-72.55164,41.57948],[-72.54611,41.58494],[-72.54414,41.5946],[-72.54858,41.6055],[-72.53302,41.60695],[-72.53427,41.609],[-72.53481,41.60935],[-72.53483,41.60988],[-72.54272,41.62255],[-72.54918,41.6329],[-72.54928,41.6329],[-72.6346,41.62324],[-72.6346,41.62321],[-72.63459,41.61874],[-72.63388,41.61635],[-72.63274,41.61395],[-72.63081,41.61102],[-72.62797,41.60797],[-72.62442,41.60562],[-72.6211,41.60271],[-72.62082,41.60214],[-72.62058,41.60069],[-72.62058,41.59924],[-72.62091,41.59732],[-72.62229,41.5947],[-72.62342,41.59348],[-72.62523,41.59201],[-72.62693,41.59113],[-72.63023,41.59014],[-72.63205,41.58924],[-72.63888,41.58456],[-72.64077,41.58269],[-72.64251,41.58007],[-72.64385,41.5767],[-72.6454,41.57488],[-72.65035,41.56599],[-72.65109,41.5637],[-72.65146,41.5616],[-72.65146,41.56142],[-72.65148,41.56138],[-72.65151,41.56008],[-72.65074,41.55715],[-72.64997,41.55495],[-72.64923,41.55365],[-72.64684,41.55098],[-72.64504,41.54948],[-72.64348,41.54853],[-72.64218,41.54807],[-72.64094,41.54797],[-72.63896,41.54847],[-72.63472,41.54889],[-72.62286,41.54892],[-72.61518,41.55068],[-72.61121,41.55105],[-72.60764,41.55034],[-72.60253,41.54769],[-72.59702,41.54633],[-72.5897,41.54642],[-72.58202,41.54566],[-72.57458,41.5447],[-72.56953,41.54327],[-72.56631,41.54296],[-72.56604,41.54291],[-72.56554,41.54479]]]]}' FROM towns WHERE name = 'Portland';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-71.99211,41.55241],[-71.99329,41.55198],[-71.99386,41.55231],[-71.99458,41.55341],[-71.99602,41.55381],[-71.99967,41.55299],[-72.00273,41.55282],[-72.00687,41.5541],[-72.0098,41.55611],[-72.01118,41.55671],[-72.01274,41.55696],[-72.01392,41.55651],[-72.01432,41.55596],[-72.01468,41.55279],[-72.01502,41.55187],[-72.01657,41.5494],[-72.01864,41.54303],[-72.01926,41.54241],[-72.02094,41.54166],[-72.02205,41.54086],[-72.02497,41.54072],[-72.02604,41.54097],[-72.02924,41.54225],[-72.03156,41.54253],[-72.03461,41.54206],[-72.03787,41.54066],[-72.04003,41.54129],[-72.04468,41.54312],[-72.04583,41.5433],[-72.0476,41.54315],[-72.04815,41.54288],[-72.04839,41.54205],[-72.04847,41.54058],[-72.04728,41.53823],[-72.04725,41.53731],[-72.0481,41.53578],[-72.04988,41.53421],[-72.05077,41.53302],[-72.05134,41.53179],[-72.05186,41.52882],[-72.04717,41.52729],[-72.04829,41.52165],[-72.04191,41.51739],[-72.05291,41.50335],[-72.05945,41.49776],[-72.0688,41.50385],[-72.07252,41.4771],[-72.07798,41.47626],[-72.08072,41.47749],[-72.08045,41.47489],[-72.07936,41.47184],[-72.07538,41.46802],[-72.07462,41.46692],[-72.07259,41.46252],[-72.07148,41.45802],[-72.06807,41.45042],[-72.06765,41.4494],[-72.06615,41.44982],[-72.06495,41.45014],[-72.06387,41.45076],[-72.06134,41.45473],[-72.05939,41.45683],[-72.05755,41.45947],[-72.05731,41.46037],[-72.05821,41.46227],[-72.0584,41.46507],[-72.05539,41.46866],[-72.05395,41.46996],[-72.05325,41.47023],[-72.0512,41.4699],[-72.04852,41.47052],[-72.04703,41.47025],[-72.04383,41.47042],[-72.04218,41.46969],[-72.04089,41.46989],[-72.03979,41.46961],[-72.03964,41.46934],[-72.03981,41.46849],[-72.03958,41.46821],[-72.03358,41.46998],[-72.02356,41.46831],[-72.01732,41.46918],[-71.97244,41.47143],[-71.9564,41.47233],[-71.95646,41.47251],[-71.96081,41.48552],[-71.9684,41.51476],[-71.90426,41.50612],[-71.90341,41.50602],[-71.90608,41.50842],[-71.95668,41.55348],[-71.98824,41.55283],[-71.99188,41.55273],[-71.99211,41.55241]]]]}' FROM towns WHERE name = 'Preston';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.97929,41.52171],[-73.00728,41.51101],[-73.01155,41.50985],[-73.01726,41.50746],[-73.01858,41.50688],[-73.01748,41.50354],[-73.00664,41.47113],[-73.00377,41.4647],[-73.00009,41.45623],[-72.99865,41.45613],[-72.97019,41.45289],[-72.9508,41.45074],[-72.9501,41.45326],[-72.94929,41.45563],[-72.94885,41.4585],[-72.94894,41.45918],[-72.94847,41.46072],[-72.94654,41.46432],[-72.94553,41.46466],[-72.94508,41.46571],[-72.94428,41.46669],[-72.9428,41.47415],[-72.94329,41.47498],[-72.94326,41.47548],[-72.94245,41.47695],[-72.94164,41.47745],[-72.94112,41.48052],[-72.93968,41.48179],[-72.93831,41.48406],[-72.938,41.48406],[-72.93767,41.48441],[-72.93772,41.48526],[-72.93726,41.48641],[-72.93724,41.48728],[-72.93652,41.48918],[-72.93581,41.49462],[-72.94971,41.5099],[-72.95156,41.51068],[-72.97048,41.51815],[-72.97929,41.52171]]]]}' FROM towns WHERE name = 'Prospect';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-71.80992,41.90067],[-71.81175,41.92772],[-71.81182,41.92922],[-71.88075,41.92892],[-71.88355,41.92908],[-71.94583,41.92857],[-71.94586,41.92857],[-71.94567,41.92802],[-71.9405,41.91371],[-71.94623,41.91351],[-71.94723,41.91349],[-71.9472,41.91344],[-71.94629,41.90645],[-71.94583,41.90585],[-71.94538,41.90397],[-71.94524,41.90235],[-71.94473,41.90152],[-71.94267,41.89922],[-71.94009,41.89697],[-71.93828,41.89335],[-71.93646,41.8878],[-71.93549,41.88542],[-71.9351,41.88465],[-71.93369,41.88285],[-71.9337,41.88257],[-71.93586,41.87916],[-71.9359,41.87871],[-71.93538,41.87686],[-71.93591,41.87361],[-71.93659,41.87104],[-71.93707,41.87002],[-71.93687,41.86892],[-71.93567,41.86724],[-71.9351,41.86594],[-71.92916,41.86491],[-71.92909,41.86491],[-71.92904,41.86493],[-71.92828,41.86541],[-71.92648,41.86548],[-71.92371,41.8646],[-71.92245,41.86475],[-71.92101,41.86535],[-71.92083,41.86582],[-71.92083,41.86764],[-71.92058,41.86804],[-71.91845,41.86829],[-71.91773,41.86869],[-71.91724,41.86931],[-71.91566,41.87258],[-71.9131,41.87408],[-71.91083,41.87615],[-71.80992,41.90064],[-71.80992,41.90067]]]]}' FROM towns WHERE name = 'Putnam';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.32111,41.30412],[-73.32713,41.30088],[-73.34196,41.33294],[-73.34203,41.33312],[-73.35119,41.33133],[-73.38913,41.32375],[-73.41219,41.31946],[-73.41908,41.31818],[-73.42862,41.31639],[-73.45832,41.31059],[-73.46369,41.30958],[-73.46358,41.3092],[-73.46001,41.29708],[-73.45956,41.29503],[-73.44514,41.28867],[-73.44108,41.26186],[-73.43959,41.26001],[-73.43699,41.25616],[-73.43092,41.24649],[-73.42869,41.24359],[-73.42369,41.23726],[-73.4222,41.23541],[-73.4215,41.23566],[-73.36255,41.2563],[-73.36151,41.25667],[-73.35836,41.25779],[-73.2985,41.27988],[-73.30123,41.28263],[-73.30232,41.28416],[-73.30398,41.28596],[-73.30669,41.28853],[-73.3083,41.29053],[-73.30905,41.29051],[-73.30965,41.29089],[-73.30957,41.29216],[-73.31011,41.29331],[-73.31221,41.29459],[-73.31246,41.29541],[-73.31308,41.29574],[-73.31287,41.29614],[-73.31318,41.29684],[-73.31366,41.29754],[-73.31434,41.29801],[-73.31901,41.30466],[-73.31931,41.30509],[-73.32111,41.30412]]]]}' FROM towns WHERE name = 'Redding';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.4647,41.3116],[-73.46732,41.3164],[-73.48403,41.34487],[-73.49708,41.34567],[-73.49824,41.34957],[-73.49868,41.35037],[-73.50237,41.36146],[-73.53843,41.35967],[-73.53841,41.35952],[-73.54247,41.29761],[-73.54488,41.27746],[-73.49383,41.21771],[-73.49371,41.21764],[-73.46464,41.23148],[-73.43955,41.24263],[-73.43092,41.24649],[-73.43699,41.25616],[-73.43959,41.26001],[-73.44108,41.26186],[-73.44514,41.28867],[-73.45956,41.29503],[-73.46001,41.29708],[-73.46358,41.3092],[-73.46369,41.30958],[-73.4647,41.3116]]]]}' FROM towns WHERE name = 'Ridgefield';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.63239,41.63035],[-72.63221,41.63088],[-72.63013,41.63372],[-72.62881,41.63592],[-72.62835,41.63692],[-72.6282,41.63839],[-72.62834,41.63976],[-72.62878,41.64131],[-72.63139,41.64809],[-72.63295,41.65153],[-72.63335,41.65436],[-72.6333,41.65518],[-72.63358,41.65628],[-72.63317,41.6573],[-72.63247,41.65833],[-72.63004,41.66055],[-72.61855,41.66325],[-72.6172,41.66375],[-72.6158,41.66497],[-72.6153,41.66587],[-72.61479,41.66749],[-72.61508,41.67154],[-72.61597,41.67464],[-72.61739,41.67644],[-72.61975,41.67816],[-72.62178,41.67932],[-72.62215,41.67949],[-72.63051,41.67868],[-72.65461,41.67649],[-72.67076,41.67137],[-72.67215,41.66341],[-72.67292,41.66241],[-72.67478,41.66101],[-72.67814,41.66042],[-72.68326,41.66105],[-72.68847,41.66211],[-72.68818,41.66288],[-72.70787,41.66051],[-72.71152,41.66005],[-72.70856,41.64705],[-72.70805,41.64481],[-72.71185,41.64429],[-72.72028,41.64323],[-72.72012,41.64193],[-72.71804,41.62379],[-72.71707,41.62309],[-72.7172,41.62254],[-72.71626,41.62199],[-72.71672,41.62122],[-72.71645,41.62004],[-72.71723,41.61879],[-72.71805,41.61757],[-72.69818,41.62051],[-72.6325,41.63013],[-72.63243,41.63015],[-72.63239,41.63035]]]]}' FROM towns WHERE name = 'Rocky Hill';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.25547,41.50232],[-73.25666,41.51064],[-73.25875,41.52176],[-73.25908,41.52563],[-73.26358,41.55221],[-73.26448,41.55966],[-73.26581,41.5657],[-73.27043,41.59238],[-73.27061,41.59341],[-73.27136,41.59336],[-73.29605,41.59083],[-73.31467,41.58915],[-73.32348,41.58799],[-73.33083,41.58668],[-73.33671,41.58652],[-73.34665,41.58568],[-73.35153,41.58536],[-73.35069,41.58174],[-73.34797,41.56725],[-73.34547,41.55773],[-73.345,41.55516],[-73.34396,41.54969],[-73.3425,41.53925],[-73.34055,41.53162],[-73.33731,41.52188],[-73.33691,41.51828],[-73.33592,41.51421],[-73.33477,41.50691],[-73.33303,41.50164],[-73.33111,41.49165],[-73.32287,41.49256],[-73.3194,41.49286],[-73.31828,41.49293],[-73.308,41.49361],[-73.25856,41.49921],[-73.2549,41.49958],[-73.25547,41.50232]]]]}' FROM towns WHERE name = 'Roxbury';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.22908,41.50546],[-72.22893,41.50771],[-72.22807,41.51123],[-72.22553,41.51595],[-72.22151,41.52423],[-72.22073,41.52593],[-72.23228,41.52348],[-72.25087,41.51948],[-72.25328,41.51879],[-72.31511,41.50562],[-72.32376,41.50458],[-72.32087,41.49107],[-72.31488,41.46336],[-72.31217,41.46365],[-72.31198,41.46305],[-72.30404,41.42056],[-72.30404,41.42046],[-72.30308,41.41684],[-72.28913,41.42164],[-72.28481,41.42353],[-72.27505,41.42466],[-72.27502,41.42466],[-72.25807,41.42606],[-72.24806,41.42684],[-72.24077,41.42781],[-72.23927,41.42805],[-72.22023,41.43097],[-72.22151,41.44126],[-72.21163,41.44235],[-72.21603,41.46416],[-72.21723,41.47193],[-72.21931,41.48592],[-72.21835,41.48639],[-72.22674,41.49802],[-72.22799,41.50047],[-72.22889,41.50384],[-72.22907,41.50499],[-72.22908,41.50546]]]]}' FROM towns WHERE name = 'Salem';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.36119,42.05085],[-73.44984,42.05316],[-73.50421,42.05217],[-73.50513,42.00137],[-73.50683,41.96743],[-73.50961,41.9215],[-73.50964,41.92101],[-73.44964,41.91982],[-73.39748,41.91746],[-73.38047,41.91759],[-73.37488,41.91765],[-73.37523,41.91815],[-73.37552,41.9197],[-73.375,41.9211],[-73.37449,41.92175],[-73.37018,41.92392],[-73.36978,41.92421],[-73.36964,41.92466],[-73.36994,41.92566],[-73.37098,41.92696],[-73.37453,41.92954],[-73.37485,41.93044],[-73.37425,41.93221],[-73.37487,41.93356],[-73.37566,41.93401],[-73.37869,41.93452],[-73.37953,41.93512],[-73.37964,41.93542],[-73.37982,41.93662],[-73.37963,41.93739],[-73.37818,41.93951],[-73.37684,41.94091],[-73.37685,41.94123],[-73.37727,41.94156],[-73.37873,41.94154],[-73.37955,41.94214],[-73.37993,41.94266],[-73.38014,41.94324],[-73.37993,41.94414],[-73.37991,41.94596],[-73.38026,41.94708],[-73.38095,41.94808],[-73.38355,41.95071],[-73.38399,41.95148],[-73.38369,41.95438],[-73.3838,41.95501],[-73.38444,41.95606],[-73.38379,41.957],[-73.38383,41.95755],[-73.38446,41.9581],[-73.38648,41.95923],[-73.3871,41.96021],[-73.38693,41.96205],[-73.38591,41.9635],[-73.38324,41.9653],[-73.38229,41.96619],[-73.38172,41.96719],[-73.3814,41.96814],[-73.38158,41.96896],[-73.38148,41.97134],[-73.38185,41.97231],[-73.38284,41.97371],[-73.38616,41.97689],[-73.38751,41.98034],[-73.38769,41.98166],[-73.38761,41.98214],[-73.38714,41.98273],[-73.38521,41.98398],[-73.38436,41.9841],[-73.38395,41.98393],[-73.38307,41.98305],[-73.38235,41.98265],[-73.38151,41.98298],[-73.38087,41.98437],[-73.38137,41.98622],[-73.38286,41.9883],[-73.38266,41.9895],[-73.38194,41.99144],[-73.38179,41.99309],[-73.38189,41.99392],[-73.38268,41.99504],[-73.38236,41.99584],[-73.38112,41.99731],[-73.38114,41.99903],[-73.38159,41.99943],[-73.38428,41.99909],[-73.38463,41.99924],[-73.38462,41.99969],[-73.38276,42.00031],[-73.38237,42.00086],[-73.3824,42.00118],[-73.38144,42.00176],[-73.38079,42.00168],[-73.38034,42.00133],[-73.38023,42.00108],[-73.38063,42.00051],[-73.38024,41.99976],[-73.37987,41.99961],[-73.3792,41.99976],[-73.37894,42.00016],[-73.37899,42.00095],[-73.37926,42.00118],[-73.37925,42.00165],[-73.379,42.00208],[-73.37821,42.00235],[-73.37408,42.00265],[-73.37329,42.00304],[-73.37294,42.00357],[-73.37285,42.00377],[-73.37273,42.00457],[-73.37287,42.00587],[-73.3742,42.00809],[-73.37485,42.00966],[-73.37491,42.01079],[-73.37402,42.01336],[-73.37351,42.01431],[-73.37261,42.01511],[-73.37015,42.01658],[-73.36743,42.01755],[-73.36699,42.01807],[-73.36684,42.01957],[-73.36784,42.02124],[-73.36826,42.02257],[-73.3682,42.02459],[-73.36915,42.02542],[-73.36941,42.02589],[-73.36887,42.02816],[-73.36868,42.03056],[-73.3665,42.03243],[-73.36483,42.03527],[-73.36294,42.03797],[-73.36116,42.03999],[-73.3601,42.04291],[-73.35997,42.04411],[-73.35955,42.04486],[-73.35896,42.04551],[-73.35774,42.04608],[-73.35715,42.04668],[-73.35695,42.0472],[-73.357,42.0479],[-73.35749,42.05072],[-73.36119,42.05085]]]]}' FROM towns WHERE name = 'Salisbury';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.11656,41.73789],[-72.1201,41.73827],[-72.12115,41.73455],[-72.12993,41.704],[-72.1257,41.67409],[-72.12548,41.65306],[-72.12552,41.65181],[-72.11482,41.65002],[-72.07108,41.64259],[-72.06707,41.64184],[-72.06705,41.64191],[-72.05907,41.67889],[-72.0617,41.68928],[-72.06288,41.693],[-72.06233,41.7],[-72.06205,41.70007],[-72.06015,41.72342],[-72.06015,41.7235],[-72.06281,41.72385],[-72.0885,41.72711],[-72.10562,41.73453],[-72.11072,41.73676],[-72.11656,41.73789]]]]}' FROM towns WHERE name = 'Scotland';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.03971,41.40604],[-73.06069,41.40877],[-73.06153,41.40592],[-73.06321,41.40263],[-73.06508,41.40021],[-73.06628,41.39826],[-73.06738,41.39599],[-73.06573,41.39516],[-73.07245,41.39488],[-73.07387,41.39365],[-73.0802,41.38829],[-73.08065,41.38794],[-73.08539,41.38373],[-73.10732,41.38246],[-73.10992,41.37493],[-73.11028,41.37318],[-73.11088,41.37213],[-73.11245,41.37023],[-73.11668,41.36797],[-73.1187,41.36642],[-73.12024,41.36583],[-73.12054,41.36525],[-73.14251,41.3521],[-73.14349,41.3515],[-73.13548,41.34544],[-73.1349,41.34414],[-73.13353,41.3372],[-73.13253,41.33545],[-73.13148,41.33452],[-73.13046,41.33397],[-73.12623,41.33227],[-73.12206,41.33019],[-73.12105,41.32959],[-73.12022,41.32879],[-73.11974,41.32829],[-73.11838,41.32893],[-73.1147,41.33045],[-73.10017,41.33542],[-73.09791,41.33622],[-73.09784,41.33625],[-73.09702,41.33717],[-73.09596,41.33764],[-73.09527,41.33881],[-73.09418,41.33971],[-73.09369,41.34068],[-73.09245,41.34123],[-73.09221,41.34241],[-73.09147,41.34278],[-73.09086,41.3434],[-73.09078,41.34385],[-73.08908,41.3433],[-73.08789,41.34332],[-73.08039,41.34681],[-73.07723,41.34673],[-73.06763,41.34357],[-73.04449,41.34386],[-73.04282,41.34388],[-73.04296,41.34533],[-73.04429,41.3557],[-73.04517,41.36476],[-73.04466,41.37425],[-73.04439,41.38007],[-73.04377,41.38289],[-73.0426,41.38839],[-73.03878,41.40589],[-73.03971,41.40604]]]]}' FROM towns WHERE name = 'Seymour';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.38047,41.91759],[-73.39748,41.91746],[-73.44964,41.91982],[-73.50964,41.92101],[-73.50978,41.91499],[-73.50997,41.90617],[-73.5108,41.88856],[-73.51166,41.87992],[-73.5123,41.86961],[-73.51601,41.80415],[-73.51699,41.78725],[-73.44097,41.78451],[-73.41888,41.78378],[-73.41869,41.784],[-73.41609,41.78817],[-73.41082,41.7922],[-73.4086,41.79447],[-73.4058,41.79839],[-73.40451,41.80054],[-73.40271,41.80226],[-73.40154,41.80313],[-73.40077,41.80338],[-73.3992,41.80278],[-73.39703,41.80335],[-73.39389,41.80492],[-73.39347,41.80524],[-73.39298,41.80611],[-73.39146,41.80739],[-73.38649,41.80998],[-73.38345,41.81329],[-73.38252,41.81634],[-73.3814,41.81771],[-73.38141,41.81826],[-73.38188,41.81928],[-73.38274,41.82043],[-73.38362,41.82121],[-73.3845,41.82159],[-73.38651,41.82116],[-73.38825,41.82177],[-73.39386,41.82642],[-73.39425,41.82717],[-73.39424,41.82762],[-73.39336,41.82912],[-73.39235,41.83034],[-73.38835,41.83383],[-73.3879,41.83503],[-73.38787,41.8359],[-73.38704,41.8371],[-73.38653,41.83875],[-73.38458,41.84289],[-73.38434,41.84436],[-73.3849,41.84529],[-73.38583,41.84619],[-73.38646,41.84774],[-73.38605,41.84866],[-73.3848,41.85001],[-73.38456,41.85088],[-73.38462,41.85221],[-73.38542,41.85545],[-73.38535,41.85703],[-73.3851,41.85768],[-73.38403,41.85927],[-73.38276,41.86057],[-73.38202,41.86179],[-73.38045,41.86292],[-73.38042,41.86431],[-73.37992,41.86541],[-73.37889,41.86603],[-73.37776,41.86633],[-73.37546,41.86603],[-73.37484,41.86708],[-73.37524,41.8693],[-73.37643,41.8714],[-73.37682,41.87248],[-73.3766,41.8739],[-73.3766,41.87725],[-73.3768,41.87829],[-73.37761,41.88022],[-73.37727,41.88169],[-73.37678,41.88242],[-73.37604,41.88286],[-73.37161,41.88413],[-73.36964,41.88588],[-73.36973,41.88805],[-73.37064,41.89012],[-73.37084,41.89335],[-73.37125,41.89425],[-73.37267,41.89525],[-73.37317,41.89665],[-73.37302,41.89785],[-73.37193,41.90132],[-73.37291,41.90486],[-73.37288,41.90621],[-73.37366,41.90951],[-73.37376,41.91193],[-73.37342,41.91453],[-73.37354,41.91533],[-73.37402,41.9168],[-73.37465,41.9175],[-73.37488,41.91765],[-73.38047,41.91759]]]]}' FROM towns WHERE name = 'Sharon';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.14406,41.3519],[-73.14719,41.35425],[-73.15178,41.35975],[-73.1528,41.36066],[-73.15339,41.36088],[-73.15349,41.36091],[-73.15368,41.36068],[-73.15579,41.35901],[-73.15551,41.35676],[-73.16634,41.34122],[-73.17157,41.32025],[-73.19824,41.281],[-73.19819,41.28098],[-73.1924,41.27517],[-73.16654,41.24921],[-73.14736,41.2501],[-73.1402,41.23746],[-73.13961,41.23646],[-73.13528,41.23797],[-73.1169,41.24451],[-73.10117,41.24983],[-73.10107,41.24911],[-73.09969,41.24846],[-73.09875,41.24776],[-73.09876,41.24751],[-73.09941,41.24711],[-73.10002,41.24549],[-73.0999,41.24501],[-73.0994,41.24454],[-73.09753,41.24438],[-73.09676,41.24386],[-73.09562,41.24236],[-73.0956,41.24013],[-73.09149,41.24],[-73.08872,41.23938],[-73.08743,41.23847],[-73.08648,41.23657],[-73.0855,41.23662],[-73.08366,41.23767],[-73.08288,41.23739],[-73.08182,41.23622],[-73.08058,41.23612],[-73.07924,41.23611],[-73.08004,41.23909],[-73.08042,41.24139],[-73.0803,41.24556],[-73.08107,41.24908],[-73.08123,41.25113],[-73.08094,41.25362],[-73.08,41.25642],[-73.07979,41.25824],[-73.0796,41.25849],[-73.07953,41.25864],[-73.07719,41.26234],[-73.07503,41.26413],[-73.07074,41.26632],[-73.06574,41.26816],[-73.06421,41.26901],[-73.06189,41.27095],[-73.05944,41.27502],[-73.05873,41.27747],[-73.05864,41.27857],[-73.05889,41.27954],[-73.06007,41.28137],[-73.06094,41.28217],[-73.06166,41.28262],[-73.06262,41.28314],[-73.06849,41.28675],[-73.07063,41.28848],[-73.07144,41.28943],[-73.07188,41.29102],[-73.07266,41.29285],[-73.07347,41.29397],[-73.07412,41.29447],[-73.07995,41.29603],[-73.08202,41.29781],[-73.08397,41.30026],[-73.08822,41.30351],[-73.09077,41.30484],[-73.09254,41.30544],[-73.09743,41.30815],[-73.10962,41.31433],[-73.11054,41.31496],[-73.11644,41.32206],[-73.11795,41.32449],[-73.11863,41.32669],[-73.11966,41.32811],[-73.11974,41.32829],[-73.12022,41.32879],[-73.12105,41.32959],[-73.12206,41.33019],[-73.12623,41.33227],[-73.13046,41.33397],[-73.13148,41.33452],[-73.13253,41.33545],[-73.13353,41.3372],[-73.1349,41.34414],[-73.13548,41.34544],[-73.14349,41.3515],[-73.14406,41.3519]]]]}' FROM towns WHERE name = 'Shelton';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.51196,41.65814],[-73.51555,41.65884],[-73.51928,41.65825],[-73.52066,41.65828],[-73.52217,41.65898],[-73.52307,41.65903],[-73.52308,41.6586],[-73.5247,41.62596],[-73.52493,41.6109],[-73.52623,41.5936],[-73.53144,41.51388],[-73.53184,41.50859],[-73.51426,41.51083],[-73.45561,41.52246],[-73.45187,41.52318],[-73.45193,41.5238],[-73.45216,41.52488],[-73.45353,41.52765],[-73.45563,41.531],[-73.45717,41.53617],[-73.47095,41.56429],[-73.49086,41.60563],[-73.50191,41.6297],[-73.50148,41.62967],[-73.50088,41.6301],[-73.50014,41.63224],[-73.50057,41.63257],[-73.50051,41.63409],[-73.49972,41.63437],[-73.49904,41.63507],[-73.49918,41.63569],[-73.49813,41.63641],[-73.49541,41.63736],[-73.49475,41.63811],[-73.49422,41.63806],[-73.49408,41.63756],[-73.49314,41.6378],[-73.49242,41.63728],[-73.49212,41.63773],[-73.49215,41.638],[-73.49163,41.63845],[-73.49129,41.63848],[-73.49113,41.63875],[-73.48979,41.63862],[-73.48943,41.63877],[-73.48905,41.63937],[-73.49005,41.64002],[-73.49461,41.64477],[-73.49579,41.64657],[-73.49687,41.64875],[-73.49797,41.6521],[-73.49886,41.65335],[-73.49923,41.6535],[-73.49988,41.65337],[-73.50241,41.65101],[-73.50304,41.65081],[-73.5034,41.65091],[-73.50712,41.65326],[-73.50909,41.65414],[-73.51125,41.65564],[-73.51176,41.65629],[-73.51157,41.65744],[-73.51154,41.65791],[-73.51196,41.65814]]]]}' FROM towns WHERE name = 'Sherman';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.80407,41.91631],[-72.82453,41.91659],[-72.86775,41.91826],[-72.88762,41.91884],[-72.89035,41.91892],[-72.88722,41.88137],[-72.88647,41.86769],[-72.88576,41.86114],[-72.88379,41.83764],[-72.88318,41.83172],[-72.8824,41.82702],[-72.88211,41.82292],[-72.88068,41.81505],[-72.87999,41.81148],[-72.87924,41.8116],[-72.84733,41.81585],[-72.81131,41.82014],[-72.80872,41.82044],[-72.79416,41.82209],[-72.79341,41.82216],[-72.79385,41.82534],[-72.79493,41.82888],[-72.79439,41.83043],[-72.7941,41.83215],[-72.79598,41.83493],[-72.79656,41.83713],[-72.79662,41.83818],[-72.7962,41.83885],[-72.79629,41.83955],[-72.79693,41.84125],[-72.79693,41.84205],[-72.79664,41.84287],[-72.79668,41.84365],[-72.79628,41.84405],[-72.79593,41.84519],[-72.79612,41.84907],[-72.79585,41.84989],[-72.79591,41.85179],[-72.79073,41.86215],[-72.78927,41.86619],[-72.78776,41.86946],[-72.78705,41.86988],[-72.78636,41.87116],[-72.78621,41.8729],[-72.78486,41.87465],[-72.78354,41.87525],[-72.78325,41.87632],[-72.78222,41.87757],[-72.78226,41.87884],[-72.78328,41.88119],[-72.78354,41.88257],[-72.78309,41.88301],[-72.7825,41.88426],[-72.78197,41.88621],[-72.78197,41.88843],[-72.78165,41.89008],[-72.78095,41.89033],[-72.78011,41.89305],[-72.77899,41.89445],[-72.77676,41.90041],[-72.77214,41.90425],[-72.77168,41.90455],[-72.77154,41.90478],[-72.77143,41.90515],[-72.77187,41.90595],[-72.77312,41.9069],[-72.77325,41.90802],[-72.77398,41.9091],[-72.77667,41.91018],[-72.77783,41.91098],[-72.77791,41.91193],[-72.77771,41.91263],[-72.77787,41.9131],[-72.77834,41.91335],[-72.77988,41.91288],[-72.78079,41.91318],[-72.78102,41.91363],[-72.78082,41.91508],[-72.7813,41.91563],[-72.78686,41.91536],[-72.78755,41.91564],[-72.79685,41.91625],[-72.79887,41.91628],[-72.80407,41.91631]]]]}' FROM towns WHERE name = 'Simsbury';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.4355,42.03397],[-72.47514,42.0349],[-72.50898,42.03483],[-72.51761,42.03499],[-72.52605,42.03505],[-72.51846,42.0002],[-72.50815,41.95066],[-72.50718,41.94588],[-72.50703,41.94588],[-72.47271,41.95003],[-72.42477,41.9555],[-72.42135,41.95587],[-72.42109,41.95847],[-72.41757,41.99895],[-72.41757,42.00047],[-72.41424,42.03356],[-72.4355,42.03397]]]]}' FROM towns WHERE name = 'Somers';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.51088,41.85585],[-72.52983,41.85291],[-72.5294,41.86073],[-72.52909,41.86629],[-72.5354,41.86613],[-72.61736,41.86436],[-72.62839,41.86487],[-72.63068,41.86498],[-72.63136,41.86185],[-72.63373,41.85012],[-72.63554,41.8458],[-72.63748,41.83901],[-72.63889,41.83579],[-72.64049,41.8333],[-72.64444,41.82963],[-72.64865,41.82509],[-72.65077,41.8213],[-72.65183,41.81828],[-72.65397,41.81051],[-72.65552,41.80137],[-72.65565,41.80073],[-72.65569,41.8005],[-72.65581,41.79805],[-72.65563,41.79273],[-72.6556,41.79253],[-72.65344,41.7926],[-72.60474,41.79465],[-72.59355,41.79529],[-72.59311,41.79531],[-72.56947,41.80139],[-72.5174,41.8148],[-72.51569,41.81525],[-72.51546,41.81647],[-72.50975,41.84903],[-72.50839,41.85627],[-72.51088,41.85585]]]]}' FROM towns WHERE name = 'South Windsor';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.15746,41.47658],[-73.15692,41.47727],[-73.15668,41.47812],[-73.15715,41.47925],[-73.15666,41.48],[-73.1571,41.48082],[-73.1571,41.48219],[-73.15787,41.48362],[-73.15884,41.48434],[-73.15985,41.4842],[-73.16015,41.48435],[-73.16032,41.48515],[-73.15965,41.48634],[-73.15909,41.48799],[-73.15854,41.48819],[-73.15793,41.48891],[-73.15791,41.48974],[-73.15757,41.48986],[-73.15741,41.49026],[-73.15759,41.49051],[-73.15723,41.49081],[-73.15749,41.49214],[-73.15736,41.49253],[-73.15691,41.49291],[-73.15759,41.49386],[-73.15753,41.49426],[-73.15862,41.49571],[-73.15836,41.49591],[-73.15842,41.49636],[-73.15812,41.49686],[-73.15835,41.49718],[-73.15741,41.4983],[-73.15726,41.4989],[-73.15641,41.49915],[-73.15597,41.49888],[-73.1558,41.49905],[-73.15572,41.4994],[-73.156,41.50005],[-73.15578,41.50072],[-73.15599,41.50105],[-73.15574,41.50175],[-73.1557,41.5019],[-73.15829,41.50163],[-73.20438,41.49613],[-73.24327,41.50076],[-73.24988,41.50014],[-73.2549,41.49958],[-73.25856,41.49921],[-73.308,41.49361],[-73.31828,41.49293],[-73.3194,41.49286],[-73.31987,41.49226],[-73.3212,41.49049],[-73.32134,41.48951],[-73.32122,41.48859],[-73.32132,41.48796],[-73.3217,41.48756],[-73.32152,41.48719],[-73.32167,41.48659],[-73.32408,41.48352],[-73.32527,41.4826],[-73.32635,41.48058],[-73.32626,41.4802],[-73.32399,41.47728],[-73.32378,41.4767],[-73.32544,41.47401],[-73.32616,41.47259],[-73.32628,41.47194],[-73.32592,41.47129],[-73.32531,41.47109],[-73.32184,41.46826],[-73.32075,41.46513],[-73.31999,41.46393],[-73.31922,41.46338],[-73.31756,41.46166],[-73.31518,41.46013],[-73.3144,41.45913],[-73.31262,41.45748],[-73.3097,41.4558],[-73.30926,41.45558],[-73.30813,41.45612],[-73.30602,41.45699],[-73.3044,41.45792],[-73.30321,41.45819],[-73.30091,41.45824],[-73.29919,41.45763],[-73.29727,41.45543],[-73.29699,41.45421],[-73.29694,41.45084],[-73.29828,41.44774],[-73.29852,41.44447],[-73.29943,41.4423],[-73.29883,41.44097],[-73.29756,41.43663],[-73.29642,41.43505],[-73.2954,41.43438],[-73.293,41.43372],[-73.29063,41.43357],[-73.29001,41.43387],[-73.28816,41.43541],[-73.28541,41.43683],[-73.27922,41.4389],[-73.27777,41.43902],[-73.2736,41.43849],[-73.27207,41.43784],[-73.27102,41.43694],[-73.26775,41.43311],[-73.26645,41.43123],[-73.26519,41.42883],[-73.2641,41.42494],[-73.26298,41.42184],[-73.2621,41.42076],[-73.26113,41.42009],[-73.25826,41.41923],[-73.25619,41.41906],[-73.2553,41.41918],[-73.25273,41.4204],[-73.25096,41.42214],[-73.24786,41.42401],[-73.24447,41.42523],[-73.24323,41.4252],[-73.24199,41.42463],[-73.24113,41.42353],[-73.24026,41.41621],[-73.2399,41.41486],[-73.23856,41.41316],[-73.2369,41.41198],[-73.2353,41.41158],[-73.23376,41.41178],[-73.22948,41.41424],[-73.22715,41.41519],[-73.22477,41.41536],[-73.21817,41.4149],[-73.21685,41.41455],[-73.21507,41.41365],[-73.21412,41.4128],[-73.2111,41.40932],[-73.20965,41.408],[-73.20744,41.40654],[-73.20524,41.40529],[-73.20489,41.40557],[-73.18252,41.41994],[-73.1811,41.42116],[-73.18025,41.42146],[-73.16257,41.4338],[-73.16233,41.4348],[-73.16257,41.43542],[-73.16393,41.4364],[-73.16414,41.43755],[-73.16456,41.43787],[-73.16474,41.43832],[-73.16367,41.43897],[-73.16336,41.43997],[-73.16444,41.44282],[-73.16404,41.44332],[-73.16383,41.44429],[-73.16337,41.44452],[-73.16102,41.44381],[-73.16058,41.44386],[-73.16054,41.44551],[-73.15966,41.44541],[-73.15923,41.44558],[-73.15882,41.44656],[-73.15896,41.44711],[-73.1588,41.44758],[-73.15728,41.44793],[-73.15695,41.44838],[-73.15713,41.45023],[-73.15641,41.45137],[-73.15673,41.45242],[-73.15801,41.4543],[-73.15809,41.45477],[-73.15777,41.45545],[-73.15795,41.4557],[-73.15774,41.45602],[-73.15813,41.45605],[-73.15825,41.45677],[-73.15861,41.45672],[-73.15872,41.45707],[-73.15867,41.45777],[-73.15789,41.45844],[-73.15728,41.45937],[-73.15697,41.46034],[-73.15726,41.46091],[-73.15667,41.46166],[-73.15678,41.46216],[-73.15581,41.46451],[-73.15605,41.46618],[-73.15586,41.46688],[-73.15592,41.46751],[-73.15564,41.46788],[-73.15552,41.46868],[-73.15548,41.46968],[-73.15635,41.47038],[-73.15613,41.47128],[-73.1566,41.4721],[-73.15667,41.473],[-73.15713,41.4737],[-73.15708,41.47453],[-73.15734,41.4749],[-73.15728,41.47553],[-73.1576,41.47635],[-73.15746,41.47648],[-73.15746,41.47658]]]]}' FROM towns WHERE name = 'Southbury';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.81958,41.63613],[-72.8239,41.63584],[-72.82608,41.64708],[-72.82676,41.64701],[-72.83992,41.64558],[-72.88712,41.64048],[-72.88789,41.64046],[-72.9035,41.63878],[-72.92838,41.63622],[-72.92826,41.6355],[-72.92697,41.63452],[-72.92661,41.63402],[-72.92621,41.63277],[-72.92613,41.63085],[-72.93198,41.63048],[-72.93304,41.63586],[-72.94276,41.6349],[-72.9424,41.63125],[-72.94124,41.6234],[-72.93654,41.59717],[-72.93858,41.58831],[-72.93974,41.58486],[-72.94046,41.58137],[-72.94095,41.57745],[-72.94188,41.57445],[-72.94196,41.57213],[-72.94687,41.54791],[-72.94739,41.54504],[-72.93775,41.54617],[-72.88422,41.55251],[-72.88312,41.55219],[-72.88129,41.55201],[-72.8818,41.55126],[-72.88164,41.55078],[-72.87608,41.54778],[-72.87585,41.54733],[-72.87605,41.54661],[-72.8752,41.54493],[-72.87483,41.54483],[-72.87429,41.54538],[-72.87387,41.54543],[-72.87357,41.54513],[-72.87349,41.54325],[-72.87154,41.5429],[-72.87118,41.54168],[-72.8698,41.54008],[-72.8693,41.53725],[-72.86,41.53522],[-72.85467,41.53418],[-72.8526,41.53391],[-72.84603,41.53287],[-72.84771,41.54282],[-72.8486,41.55053],[-72.85068,41.55583],[-72.84461,41.55692],[-72.8419,41.55734],[-72.84083,41.56039],[-72.8395,41.56391],[-72.83765,41.5712],[-72.83623,41.57319],[-72.83331,41.57611],[-72.82918,41.5811],[-72.82822,41.58312],[-72.82756,41.58624],[-72.82691,41.59393],[-72.8239,41.59643],[-72.8215,41.59655],[-72.82248,41.60165],[-72.82202,41.60167],[-72.82304,41.60564],[-72.82656,41.6073],[-72.82612,41.60887],[-72.82494,41.60904],[-72.82423,41.61134],[-72.82613,41.61159],[-72.82564,41.61304],[-72.8237,41.61486],[-72.82187,41.61763],[-72.82921,41.61767],[-72.82913,41.62059],[-72.82815,41.62278],[-72.82825,41.62381],[-72.82765,41.62481],[-72.82603,41.62675],[-72.82343,41.62902],[-72.8222,41.63127],[-72.82068,41.63241],[-72.81986,41.63483],[-72.81899,41.63586],[-72.81873,41.63621],[-72.81958,41.63613]]]]}' FROM towns WHERE name = 'Southington';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.07108,41.64259],[-72.11482,41.65002],[-72.12552,41.65181],[-72.12181,41.6386],[-72.11259,41.60661],[-72.10736,41.58902],[-72.09154,41.5921],[-72.06807,41.59666],[-72.06707,41.59648],[-72.06366,41.59552],[-72.05783,41.59244],[-72.05426,41.58684],[-72.05158,41.58354],[-72.0511,41.58274],[-72.05077,41.58299],[-72.04948,41.58476],[-72.04909,41.58636],[-72.04859,41.58676],[-72.0481,41.58796],[-72.04781,41.58801],[-72.04732,41.58893],[-72.04598,41.59028],[-72.0446,41.5923],[-72.04192,41.59447],[-72.03951,41.59918],[-72.03942,41.60011],[-72.04024,41.60163],[-72.04058,41.60388],[-72.04026,41.60388],[-72.04121,41.60466],[-72.04174,41.60603],[-72.04206,41.60788],[-72.04286,41.60856],[-72.04314,41.60988],[-72.0442,41.61111],[-72.04454,41.61205],[-72.04481,41.61373],[-72.04467,41.6148],[-72.04497,41.61745],[-72.04456,41.6183],[-72.04008,41.62136],[-72.03681,41.62411],[-72.03678,41.62476],[-72.03708,41.6259],[-72.03649,41.62883],[-72.03668,41.63117],[-72.03568,41.63267],[-72.0353,41.63432],[-72.03597,41.63649],[-72.03598,41.63669],[-72.036,41.63669],[-72.06544,41.64156],[-72.06707,41.64184],[-72.07108,41.64259]]]]}' FROM towns WHERE name = 'Sprague';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.22914,42.03128],[-72.3387,42.0323],[-72.37037,42.03299],[-72.40203,42.03339],[-72.41424,42.03356],[-72.41757,42.00047],[-72.41757,41.99895],[-72.42109,41.95847],[-72.42135,41.95587],[-72.42137,41.95567],[-72.42142,41.93941],[-72.38847,41.93976],[-72.34058,41.93884],[-72.32096,41.93876],[-72.32011,41.93876],[-72.31301,41.9387],[-72.25046,41.9375],[-72.25122,41.95644],[-72.24889,41.95661],[-72.24787,41.95895],[-72.23841,41.98052],[-72.23122,41.99779],[-72.22159,42.0218],[-72.22185,42.03124],[-72.22914,42.03128]]]]}' FROM towns WHERE name = 'Stafford';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.50166,41.09172],[-73.50868,41.09166],[-73.52355,41.12944],[-73.52347,41.12989],[-73.52243,41.13014],[-73.52203,41.13056],[-73.52173,41.13181],[-73.5221,41.13201],[-73.52218,41.13241],[-73.52147,41.13298],[-73.52249,41.13371],[-73.52352,41.13386],[-73.52391,41.13411],[-73.52495,41.13624],[-73.52646,41.13709],[-73.52806,41.13861],[-73.5295,41.13877],[-73.53002,41.13912],[-73.53039,41.14022],[-73.53097,41.14092],[-73.53121,41.14167],[-73.53164,41.14202],[-73.53299,41.14242],[-73.53336,41.14277],[-73.53348,41.14339],[-73.5339,41.14384],[-73.53866,41.14665],[-73.53968,41.1475],[-73.54079,41.149],[-73.54169,41.1507],[-73.54215,41.15215],[-73.54264,41.15287],[-73.54256,41.15357],[-73.54201,41.15484],[-73.54233,41.15574],[-73.54351,41.15779],[-73.54361,41.15847],[-73.54398,41.15882],[-73.54474,41.15909],[-73.55131,41.15601],[-73.62024,41.12352],[-73.62259,41.12242],[-73.62096,41.1207],[-73.62004,41.11927],[-73.61881,41.11795],[-73.60031,41.09369],[-73.56444,41.04796],[-73.56317,41.04608],[-73.55446,41.03553],[-73.54957,41.01681],[-73.54083,41.00324],[-73.54083,41.00239],[-73.5405,41.00199],[-73.54035,41.00107],[-73.54018,41.00109],[-73.5398,41.00134],[-73.53953,41.00126],[-73.54,40.99987],[-73.53989,40.99954],[-73.53951,40.99969],[-73.5388,40.99887],[-73.53856,40.99882],[-73.53807,41.00051],[-73.53656,41.00124],[-73.53566,41.00113],[-73.53582,41.00313],[-73.53524,41.00338],[-73.53508,41.00376],[-73.53562,41.00396],[-73.53617,41.00358],[-73.53613,41.00396],[-73.53483,41.00438],[-73.53467,41.00538],[-73.53489,41.00635],[-73.53438,41.00643],[-73.53425,41.00603],[-73.53439,41.0059],[-73.53403,41.00588],[-73.5341,41.00515],[-73.53358,41.00478],[-73.53389,41.0045],[-73.53377,41.0037],[-73.5331,41.00335],[-73.53394,41.00283],[-73.53373,41.0025],[-73.53416,41.00226],[-73.53452,41.00276],[-73.53541,41.00263],[-73.53531,41.00178],[-73.53435,41.00118],[-73.53548,41.00098],[-73.53591,41.00061],[-73.5356,41.00078],[-73.53469,41.00053],[-73.5343,41.00028],[-73.53453,41.00008],[-73.53439,41.00018],[-73.53373,40.99988],[-73.53362,40.99868],[-73.53403,40.99863],[-73.53359,40.99848],[-73.53332,40.99781],[-73.53262,40.99773],[-73.53226,40.99816],[-73.5328,41.00153],[-73.53305,41.00175],[-73.53296,41.002],[-73.53244,41.0023],[-73.53122,41.00235],[-73.53052,41.00145],[-73.53038,41.00095],[-73.53014,41.00095],[-73.5301,41.00225],[-73.52923,41.0025],[-73.52968,41.00345],[-73.52946,41.0035],[-73.52935,41.00392],[-73.52893,41.00435],[-73.52898,41.0046],[-73.52804,41.00487],[-73.52868,41.00679],[-73.52893,41.00704],[-73.52903,41.00782],[-73.52944,41.00784],[-73.52953,41.00817],[-73.5297,41.00819],[-73.52962,41.00869],[-73.52933,41.00877],[-73.53005,41.00867],[-73.53025,41.00887],[-73.53039,41.00937],[-73.52969,41.00944],[-73.52972,41.00962],[-73.53052,41.01062],[-73.53062,41.01049],[-73.5303,41.01027],[-73.53038,41.00979],[-73.53103,41.00962],[-73.53129,41.01009],[-73.53123,41.01062],[-73.53096,41.01067],[-73.53092,41.01099],[-73.53047,41.01109],[-73.53038,41.01132],[-73.53054,41.01204],[-73.53087,41.01227],[-73.53096,41.01282],[-73.53068,41.01326],[-73.53095,41.01429],[-73.53132,41.01526],[-73.53156,41.01529],[-73.53132,41.01534],[-73.53134,41.01581],[-73.53191,41.01804],[-73.53092,41.01816],[-73.53088,41.01923],[-73.53103,41.01948],[-73.5305,41.01948],[-73.53035,41.01788],[-73.52932,41.01576],[-73.52974,41.01509],[-73.52972,41.01469],[-73.52887,41.01284],[-73.52817,41.01219],[-73.52829,41.01216],[-73.5281,41.01176],[-73.52832,41.01156],[-73.52765,41.01039],[-73.52678,41.00814],[-73.52526,41.00846],[-73.52637,41.01133],[-73.52643,41.01258],[-73.52582,41.01268],[-73.52567,41.01248],[-73.52591,41.01221],[-73.52525,41.01058],[-73.52477,41.01016],[-73.52482,41.00991],[-73.52308,41.00711],[-73.52281,41.00703],[-73.52262,41.00728],[-73.52257,41.00811],[-73.52296,41.00981],[-73.52265,41.0108],[-73.52185,41.01163],[-73.5213,41.01185],[-73.52117,41.01225],[-73.52011,41.01195],[-73.51985,41.01227],[-73.52074,41.0127],[-73.51778,41.01527],[-73.51725,41.01607],[-73.51667,41.01622],[-73.51679,41.01639],[-73.51665,41.01716],[-73.51626,41.01736],[-73.51636,41.01879],[-73.51706,41.02046],[-73.51801,41.02209],[-73.52021,41.02416],[-73.51978,41.02434],[-73.5178,41.02239],[-73.51622,41.01994],[-73.51605,41.01996],[-73.51516,41.01769],[-73.51481,41.01761],[-73.5149,41.01726],[-73.5147,41.01704],[-73.51494,41.01699],[-73.51452,41.01681],[-73.51492,41.01539],[-73.51549,41.01519],[-73.51621,41.01542],[-73.51632,41.01519],[-73.51612,41.01512],[-73.51639,41.01419],[-73.51686,41.01369],[-73.51666,41.01359],[-73.51618,41.01209],[-73.5163,41.01204],[-73.51658,41.01254],[-73.51709,41.01244],[-73.51722,41.01289],[-73.51785,41.01342],[-73.51815,41.01305],[-73.51854,41.01315],[-73.51795,41.01287],[-73.51803,41.01205],[-73.51773,41.01177],[-73.51956,41.0121],[-73.51977,41.01182],[-73.51784,41.01147],[-73.51777,41.01075],[-73.51929,41.00978],[-73.51932,41.00908],[-73.52015,41.009],[-73.52034,41.00975],[-73.52074,41.0101],[-73.52099,41.00973],[-73.52136,41.00816],[-73.51907,41.00815],[-73.51795,41.00862],[-73.51764,41.0098],[-73.51667,41.00995],[-73.51681,41.00977],[-73.51725,41.0098],[-73.51703,41.00965],[-73.51696,41.00912],[-73.51738,41.00825],[-73.51793,41.00815],[-73.51819,41.0078],[-73.51673,41.00533],[-73.51553,41.0044],[-73.51532,41.0012],[-73.51537,41.00073],[-73.51552,41.0007],[-73.51524,40.99958],[-73.51555,40.99933],[-73.5157,40.99848],[-73.51608,40.99838],[-73.51597,40.99793],[-73.51622,40.99741],[-73.51561,40.99573],[-73.51568,40.99548],[-73.51479,40.99336],[-73.51491,40.99321],[-73.51471,40.99224],[-73.51498,40.99176],[-73.51437,40.99164],[-73.51388,40.99296],[-73.51217,40.9944],[-73.51017,40.99493],[-73.50917,40.99487],[-73.5086,40.9946],[-73.50824,40.99472],[-73.50827,40.99487],[-73.50785,40.99475],[-73.50675,40.99614],[-73.50623,40.99719],[-73.50443,40.99876],[-73.50396,41.00019],[-73.50424,41.00154],[-73.50403,41.00263],[-73.5033,41.00426],[-73.50265,41.00446],[-73.50228,41.00505],[-73.5039,41.00636],[-73.50593,41.00666],[-73.50606,41.00636],[-73.50643,41.00648],[-73.50749,41.00768],[-73.50788,41.00851],[-73.50787,41.00888],[-73.50804,41.00886],[-73.50804,41.00916],[-73.50839,41.00938],[-73.5083,41.01026],[-73.50847,41.01026],[-73.50846,41.01076],[-73.50812,41.01158],[-73.50858,41.01166],[-73.50866,41.01196],[-73.50795,41.01313],[-73.50726,41.01373],[-73.50711,41.01363],[-73.50796,41.01433],[-73.50805,41.01485],[-73.50769,41.0152],[-73.50747,41.01675],[-73.50716,41.01683],[-73.50834,41.01738],[-73.50867,41.01778],[-73.50856,41.0181],[-73.50793,41.01825],[-73.50687,41.01775],[-73.50647,41.0173],[-73.50554,41.01722],[-73.50464,41.0157],[-73.50456,41.01542],[-73.50519,41.01517],[-73.50604,41.01612],[-73.50663,41.0164],[-73.50636,41.01545],[-73.50643,41.01513],[-73.50667,41.0151],[-73.50665,41.01458],[-73.50682,41.01453],[-73.50639,41.01383],[-73.50428,41.01477],[-73.5036,41.01412],[-73.504,41.0146],[-73.50292,41.01507],[-73.50219,41.01499],[-73.50226,41.01474],[-73.50209,41.01492],[-73.50145,41.01472],[-73.4991,41.01491],[-73.49713,41.01469],[-73.49674,41.01479],[-73.49657,41.01549],[-73.49568,41.01576],[-73.49527,41.01513],[-73.49584,41.01469],[-73.49494,41.01388],[-73.49402,41.01391],[-73.49354,41.01343],[-73.49297,41.01383],[-73.4932,41.01411],[-73.49306,41.01441],[-73.49326,41.01456],[-73.49246,41.01558],[-73.49254,41.01585],[-73.49216,41.01618],[-73.49236,41.01628],[-73.49197,41.017],[-73.49164,41.01723],[-73.492,41.01733],[-73.49199,41.01778],[-73.48975,41.01915],[-73.49015,41.01947],[-73.49064,41.02042],[-73.48984,41.02052],[-73.48956,41.0202],[-73.48934,41.02324],[-73.489,41.02322],[-73.48852,41.02426],[-73.48906,41.02459],[-73.48904,41.02474],[-73.48879,41.02511],[-73.48806,41.02529],[-73.48779,41.02599],[-73.48837,41.02659],[-73.48924,41.02649],[-73.48942,41.02676],[-73.48969,41.02746],[-73.48979,41.02936],[-73.48884,41.03003],[-73.48929,41.03051],[-73.48987,41.03046],[-73.48994,41.03101],[-73.49057,41.03199],[-73.49046,41.03219],[-73.49085,41.03298],[-73.49097,41.03289],[-73.49356,41.03494],[-73.49375,41.03576],[-73.49368,41.03664],[-73.49356,41.03664],[-73.49362,41.03719],[-73.49443,41.03804],[-73.495,41.03924],[-73.49514,41.04061],[-73.49564,41.04201],[-73.49655,41.04316],[-73.49631,41.04411],[-73.49642,41.04533],[-73.49627,41.04583],[-73.49656,41.04686],[-73.49738,41.04726],[-73.49771,41.04871],[-73.49826,41.04901],[-73.49955,41.04898],[-73.50033,41.04926],[-73.50088,41.04971],[-73.50124,41.05033],[-73.50183,41.05048],[-73.50179,41.05083],[-73.50122,41.05123],[-73.5014,41.05156],[-73.50218,41.05181],[-73.50308,41.05174],[-73.50389,41.05264],[-73.50382,41.05289],[-73.50436,41.05301],[-73.50452,41.05344],[-73.50389,41.05433],[-73.5038,41.05531],[-73.50454,41.05593],[-73.50498,41.05673],[-73.50384,41.05756],[-73.50381,41.05898],[-73.50297,41.06003],[-73.50293,41.06043],[-73.50367,41.06088],[-73.50379,41.06143],[-73.50337,41.06208],[-73.50254,41.06205],[-73.5024,41.06312],[-73.50252,41.06382],[-73.50166,41.0643],[-73.50161,41.06515],[-73.50176,41.06537],[-73.5026,41.06567],[-73.50278,41.06592],[-73.50157,41.06849],[-73.50159,41.06922],[-73.50219,41.06984],[-73.50218,41.07114],[-73.50161,41.07231],[-73.50088,41.07319],[-73.50073,41.07394],[-73.50042,41.07411],[-73.50047,41.07468],[-73.50014,41.07516],[-73.4999,41.07618],[-73.49972,41.07648],[-73.499,41.0768],[-73.49866,41.07773],[-73.49787,41.07893],[-73.49825,41.07943],[-73.49734,41.07987],[-73.49705,41.0807],[-73.49721,41.0811],[-73.49693,41.08172],[-73.49781,41.08245],[-73.49883,41.08262],[-73.49916,41.08287],[-73.4992,41.08417],[-73.49902,41.08482],[-73.49863,41.08562],[-73.49808,41.08599],[-73.49783,41.08649],[-73.49727,41.08647],[-73.49704,41.08692],[-73.4965,41.08682],[-73.49584,41.08729],[-73.4954,41.08734],[-73.49429,41.08669],[-73.49242,41.08831],[-73.49234,41.08906],[-73.49167,41.08951],[-73.49145,41.09023],[-73.49095,41.0906],[-73.49092,41.09095],[-73.49117,41.09145],[-73.49043,41.09168],[-73.49033,41.09178],[-73.50166,41.09172]]],[[[-73.48749,41.02554],[-73.48784,41.02509],[-73.48748,41.02439],[-73.4877,41.02384],[-73.48823,41.02357],[-73.48877,41.02359],[-73.48887,41.02312],[-73.48828,41.02264],[-73.4889,41.02079],[-73.48892,41.01982],[-73.48947,41.01947],[-73.48884,41.0188],[-73.48837,41.01932],[-73.48736,41.01974],[-73.48667,41.01949],[-73.48652,41.01907],[-73.48629,41.01899],[-73.48601,41.01937],[-73.48615,41.01974],[-73.48599,41.02026],[-73.4868,41.02054],[-73.48691,41.02097],[-73.48678,41.02134],[-73.48537,41.02214],[-73.483,41.02266],[-73.48266,41.02251],[-73.48319,41.02316],[-73.48317,41.02338],[-73.48169,41.02425],[-73.48281,41.02436],[-73.48335,41.02383],[-73.48383,41.02368],[-73.48479,41.02401],[-73.48498,41.02463],[-73.48556,41.02454],[-73.48596,41.02471],[-73.48749,41.02554]]],[[[-73.49304,41.01303],[-73.49259,41.01266],[-73.49267,41.01208],[-73.49226,41.01201],[-73.4919,41.01148],[-73.49139,41.01153],[-73.49095,41.01133],[-73.49076,41.01146],[-73.49033,41.01111],[-73.49002,41.01118],[-73.49098,41.01171],[-73.49098,41.01238],[-73.49218,41.01266],[-73.49304,41.01303]]],[[[-73.5192,41.00675],[-73.52086,41.00598],[-73.5214,41.00633],[-73.52183,41.00616],[-73.52124,41.00571],[-73.51915,41.0066],[-73.5192,41.00675]]],[[[-73.49534,41.01104],[-73.49588,41.01126],[-73.49627,41.01116],[-73.49599,41.01066],[-73.49518,41.01051],[-73.49534,41.01104]]],[[[-73.52485,41.00149],[-73.5253,41.00172],[-73.52551,41.00162],[-73.52557,41.00109],[-73.5252,41.00097],[-73.52519,41.00072],[-73.52491,41.00089],[-73.52485,41.00149]]],[[[-73.4872,41.01757],[-73.48741,41.01804],[-73.48782,41.01789],[-73.4878,41.01732],[-73.48738,41.01724],[-73.4872,41.01757]]],[[[-73.49004,41.01026],[-73.48993,41.01066],[-73.48926,41.011],[-73.49001,41.01071],[-73.49016,41.01026],[-73.49071,41.00991],[-73.49156,41.00993],[-73.49184,41.01011],[-73.491,41.00968],[-73.49042,41.00993],[-73.49004,41.01026]]],[[[-73.49652,41.01052],[-73.49676,41.01067],[-73.49687,41.01034],[-73.49661,41.01027],[-73.49652,41.01052]]],[[[-73.49454,41.01124],[-73.49493,41.01124],[-73.49449,41.01104],[-73.49454,41.01124]]],[[[-73.52732,40.98821],[-73.52711,40.98838],[-73.5275,40.98833],[-73.52732,40.98821]]],[[[-73.49781,41.00977],[-73.498,41.00977],[-73.49785,41.00964],[-73.49781,41.00977]]]]}' FROM towns WHERE name = 'Stamford';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-71.7925,41.62901],[-71.79253,41.64546],[-71.80027,41.76584],[-71.80033,41.76703],[-71.8475,41.76126],[-71.84758,41.76124],[-71.84759,41.76101],[-71.85168,41.72148],[-71.86257,41.63426],[-71.86268,41.63328],[-71.86246,41.63326],[-71.80596,41.6295],[-71.7925,41.62891],[-71.7925,41.62901]]]]}' FROM towns WHERE name = 'Sterling';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-71.87237,41.3989],[-71.92588,41.41289],[-71.94171,41.41709],[-71.9415,41.41671],[-71.94172,41.41499],[-71.94107,41.41351],[-71.94148,41.41254],[-71.94315,41.41162],[-71.94419,41.41137],[-71.94496,41.41052],[-71.94887,41.40788],[-71.94998,41.40614],[-71.95004,41.40559],[-71.95061,41.40521],[-71.95009,41.40471],[-71.95063,41.40426],[-71.95055,41.40379],[-71.95148,41.40334],[-71.95161,41.40267],[-71.95146,41.40179],[-71.95222,41.40117],[-71.95261,41.40034],[-71.95426,41.39877],[-71.95424,41.39805],[-71.95459,41.39748],[-71.95464,41.39693],[-71.9551,41.39673],[-71.95592,41.3958],[-71.95736,41.39588],[-71.95797,41.39533],[-71.95869,41.39558],[-71.96,41.39491],[-71.96061,41.39406],[-71.96065,41.39379],[-71.96004,41.39294],[-71.96069,41.39209],[-71.96055,41.39142],[-71.96121,41.38979],[-71.96103,41.38872],[-71.9607,41.38839],[-71.96013,41.38707],[-71.96032,41.38615],[-71.95993,41.38447],[-71.95938,41.38387],[-71.95959,41.38352],[-71.96028,41.38317],[-71.96039,41.38277],[-71.95995,41.382],[-71.95931,41.38162],[-71.95855,41.38055],[-71.9578,41.38075],[-71.95625,41.38022],[-71.95625,41.38019],[-71.9562,41.37997],[-71.95691,41.37857],[-71.95792,41.37755],[-71.95803,41.3771],[-71.95781,41.37615],[-71.95804,41.37563],[-71.95756,41.3751],[-71.95768,41.37438],[-71.95739,41.37353],[-71.95765,41.37248],[-71.95734,41.3719],[-71.95685,41.37175],[-71.9561,41.37113],[-71.95571,41.3704],[-71.9574,41.36901],[-71.95847,41.36921],[-71.95883,41.36906],[-71.95949,41.36646],[-71.96058,41.36579],[-71.96091,41.36519],[-71.96076,41.36357],[-71.95999,41.36157],[-71.95983,41.36027],[-71.96019,41.3585],[-71.96249,41.35498],[-71.96374,41.35131],[-71.9639,41.34999],[-71.96368,41.34599],[-71.96388,41.34292],[-71.96336,41.34109],[-71.96317,41.33959],[-71.96514,41.33553],[-71.96699,41.33311],[-71.96705,41.33198],[-71.96617,41.33013],[-71.96463,41.33081],[-71.96302,41.33143],[-71.96243,41.331],[-71.96232,41.3307],[-71.96167,41.33085],[-71.96153,41.33113],[-71.96129,41.331],[-71.96093,41.33052],[-71.9618,41.33018],[-71.96124,41.3296],[-71.96164,41.32903],[-71.96146,41.32885],[-71.96198,41.32825],[-71.96264,41.32818],[-71.96313,41.32848],[-71.9636,41.32958],[-71.96428,41.32943],[-71.9635,41.32791],[-71.96326,41.32796],[-71.96294,41.32773],[-71.96298,41.32758],[-71.96235,41.32763],[-71.96151,41.32725],[-71.96111,41.3275],[-71.96112,41.32788],[-71.96055,41.32835],[-71.96109,41.32848],[-71.96087,41.32925],[-71.96012,41.32927],[-71.96052,41.32913],[-71.96064,41.3288],[-71.95978,41.32927],[-71.95926,41.3291],[-71.95795,41.3292],[-71.95735,41.33009],[-71.95747,41.33017],[-71.95718,41.33034],[-71.9575,41.33114],[-71.95741,41.33132],[-71.95617,41.33077],[-71.95488,41.32969],[-71.9556,41.32957],[-71.9553,41.32932],[-71.95482,41.32939],[-71.95479,41.32919],[-71.9551,41.32904],[-71.95428,41.32914],[-71.95431,41.32867],[-71.95456,41.32869],[-71.95568,41.32802],[-71.95603,41.3276],[-71.95534,41.3258],[-71.95487,41.32547],[-71.95477,41.32455],[-71.95449,41.32472],[-71.95455,41.3252],[-71.9543,41.32507],[-71.95407,41.32537],[-71.95468,41.32547],[-71.95488,41.32582],[-71.95427,41.32577],[-71.95368,41.32609],[-71.95311,41.32587],[-71.95358,41.32527],[-71.9534,41.32504],[-71.95391,41.32492],[-71.95399,41.32452],[-71.95377,41.32437],[-71.95401,41.3244],[-71.95399,41.3237],[-71.95441,41.32305],[-71.95436,41.32215],[-71.95453,41.32217],[-71.95504,41.32127],[-71.95564,41.3212],[-71.95498,41.32107],[-71.95486,41.32087],[-71.9552,41.32008],[-71.95507,41.3198],[-71.95362,41.31952],[-71.95349,41.31915],[-71.9532,41.31925],[-71.95323,41.3194],[-71.95502,41.32003],[-71.95489,41.3205],[-71.95377,41.32042],[-71.95342,41.32087],[-71.95141,41.32107],[-71.9511,41.32139],[-71.95091,41.32212],[-71.95038,41.32224],[-71.94973,41.32351],[-71.94894,41.32379],[-71.94874,41.32351],[-71.94739,41.32326],[-71.94733,41.32351],[-71.94568,41.32306],[-71.94557,41.32258],[-71.94629,41.32209],[-71.94656,41.32211],[-71.94672,41.32269],[-71.94701,41.32269],[-71.94715,41.32236],[-71.94696,41.32191],[-71.94659,41.32181],[-71.94668,41.32136],[-71.94632,41.32089],[-71.94634,41.32049],[-71.94597,41.31966],[-71.94518,41.31919],[-71.94517,41.31894],[-71.94601,41.31846],[-71.94627,41.31814],[-71.9462,41.31769],[-71.94709,41.31714],[-71.94763,41.31647],[-71.94829,41.31664],[-71.94826,41.31642],[-71.94881,41.31625],[-71.94892,41.31592],[-71.94919,41.31595],[-71.94909,41.3158],[-71.9493,41.31555],[-71.94933,41.31502],[-71.94908,41.31462],[-71.94929,41.3143],[-71.949,41.31365],[-71.94817,41.3136],[-71.94763,41.31417],[-71.94727,41.31422],[-71.94685,41.31407],[-71.94672,41.31382],[-71.94637,41.31422],[-71.94553,41.31459],[-71.94544,41.31499],[-71.94501,41.31517],[-71.94478,41.31554],[-71.94431,41.31544],[-71.94468,41.31569],[-71.94462,41.31604],[-71.9437,41.31766],[-71.94296,41.31813],[-71.94357,41.31811],[-71.94353,41.31921],[-71.94325,41.31968],[-71.94232,41.32026],[-71.94223,41.32071],[-71.94065,41.3196],[-71.94054,41.31935],[-71.94103,41.31928],[-71.94099,41.31893],[-71.9412,41.31863],[-71.94086,41.31793],[-71.94166,41.31781],[-71.94257,41.31796],[-71.94256,41.31771],[-71.94209,41.31741],[-71.9417,41.31733],[-71.93741,41.3186],[-71.93661,41.31862],[-71.93608,41.3182],[-71.93455,41.3189],[-71.93278,41.31904],[-71.93068,41.31877],[-71.92923,41.31826],[-71.92824,41.31769],[-71.92816,41.31741],[-71.92851,41.31681],[-71.92845,41.31654],[-71.92904,41.31612],[-71.92817,41.31536],[-71.92839,41.31529],[-71.92842,41.31492],[-71.92771,41.31526],[-71.92769,41.31631],[-71.92678,41.31699],[-71.92524,41.31653],[-71.9248,41.31566],[-71.92503,41.31509],[-71.92479,41.31456],[-71.92507,41.31406],[-71.92503,41.31371],[-71.92359,41.31351],[-71.92343,41.31368],[-71.92356,41.31418],[-71.92306,41.31431],[-71.92326,41.31461],[-71.92286,41.31483],[-71.92269,41.31483],[-71.92257,41.31436],[-71.92233,41.31441],[-71.92234,41.31471],[-71.92203,41.31473],[-71.92158,41.31436],[-71.92155,41.31501],[-71.92114,41.31515],[-71.92155,41.31678],[-71.92139,41.31715],[-71.92101,41.3174],[-71.92072,41.3173],[-71.92031,41.31588],[-71.9206,41.3157],[-71.92059,41.31548],[-71.91975,41.31513],[-71.91914,41.31425],[-71.91873,41.3142],[-71.9185,41.31475],[-71.91896,41.31465],[-71.91957,41.3156],[-71.91935,41.31692],[-71.91886,41.31702],[-71.91872,41.3165],[-71.91785,41.31572],[-71.91738,41.31557],[-71.91703,41.3158],[-71.91688,41.31565],[-71.91675,41.31485],[-71.91696,41.31355],[-71.91714,41.31328],[-71.91761,41.31328],[-71.91774,41.31303],[-71.91779,41.31358],[-71.91864,41.31363],[-71.91956,41.31283],[-71.92045,41.31251],[-71.92078,41.31203],[-71.92031,41.31191],[-71.92017,41.31118],[-71.91965,41.31091],[-71.919,41.31128],[-71.91899,41.31173],[-71.91844,41.3119],[-71.91804,41.31243],[-71.91756,41.3126],[-71.917,41.31253],[-71.91664,41.31103],[-71.91681,41.31013],[-71.91628,41.30893],[-71.9157,41.30985],[-71.91503,41.3099],[-71.91489,41.31027],[-71.91408,41.31072],[-71.91359,41.31077],[-71.91307,41.3113],[-71.91271,41.31207],[-71.91228,41.31242],[-71.91183,41.31347],[-71.91197,41.31434],[-71.91166,41.31514],[-71.91184,41.31549],[-71.91167,41.31559],[-71.91187,41.31554],[-71.91192,41.31574],[-71.91168,41.31594],[-71.91188,41.31594],[-71.9122,41.31696],[-71.91206,41.31701],[-71.91225,41.31706],[-71.91206,41.31801],[-71.91236,41.31836],[-71.91256,41.31921],[-71.9125,41.32036],[-71.91293,41.32109],[-71.91289,41.32144],[-71.91272,41.32149],[-71.9129,41.32174],[-71.91233,41.32221],[-71.91251,41.32228],[-71.91242,41.32266],[-71.91106,41.32361],[-71.90693,41.32265],[-71.90595,41.3218],[-71.90541,41.32162],[-71.90545,41.3214],[-71.90536,41.3215],[-71.90388,41.32075],[-71.90382,41.32052],[-71.90318,41.3202],[-71.90318,41.31997],[-71.90344,41.32],[-71.9034,41.31935],[-71.90453,41.31815],[-71.90468,41.31735],[-71.90526,41.31733],[-71.9054,41.31643],[-71.90676,41.31626],[-71.9066,41.31598],[-71.90629,41.31593],[-71.90434,41.31595],[-71.90429,41.3158],[-71.90588,41.31548],[-71.90578,41.31528],[-71.90421,41.31558],[-71.90415,41.31535],[-71.90437,41.31528],[-71.90429,41.3149],[-71.9037,41.3149],[-71.90346,41.31425],[-71.90283,41.31433],[-71.90275,41.31395],[-71.90336,41.31393],[-71.90333,41.3138],[-71.90263,41.31385],[-71.90269,41.31368],[-71.90235,41.3136],[-71.90243,41.31313],[-71.90301,41.31295],[-71.90298,41.3128],[-71.90237,41.3127],[-71.90339,41.31268],[-71.90339,41.3125],[-71.90285,41.3125],[-71.90286,41.31198],[-71.9031,41.31198],[-71.90312,41.31161],[-71.90276,41.31088],[-71.90471,41.31131],[-71.90204,41.31065],[-71.90151,41.30893],[-71.9012,41.30863],[-71.90154,41.30831],[-71.90115,41.30843],[-71.90053,41.30796],[-71.90029,41.30808],[-71.90021,41.30873],[-71.8998,41.3087],[-71.90019,41.3088],[-71.90017,41.30895],[-71.89992,41.30888],[-71.90012,41.30903],[-71.89962,41.30938],[-71.89947,41.31],[-71.89923,41.31005],[-71.89956,41.3106],[-71.89939,41.31068],[-71.89954,41.31135],[-71.89978,41.31138],[-71.89942,41.3115],[-71.90025,41.3116],[-71.90026,41.31185],[-71.89965,41.31197],[-71.90006,41.31193],[-71.90014,41.31218],[-71.89959,41.31222],[-71.9001,41.31225],[-71.90019,41.31275],[-71.89998,41.313],[-71.89908,41.31322],[-71.89894,41.31345],[-71.89828,41.31332],[-71.8983,41.31372],[-71.89857,41.31387],[-71.89841,41.31437],[-71.89744,41.31507],[-71.89731,41.31577],[-71.89492,41.31616],[-71.89435,41.31599],[-71.89439,41.31626],[-71.89348,41.31621],[-71.89328,41.31586],[-71.89336,41.31521],[-71.89318,41.31486],[-71.89344,41.31404],[-71.89308,41.31489],[-71.89092,41.31506],[-71.89012,41.31576],[-71.88977,41.31633],[-71.88882,41.31693],[-71.88858,41.31795],[-71.88919,41.31823],[-71.88923,41.3186],[-71.88873,41.31898],[-71.88876,41.31918],[-71.8881,41.3199],[-71.88662,41.32087],[-71.8863,41.32065],[-71.88638,41.32],[-71.88611,41.31982],[-71.88612,41.32042],[-71.88581,41.32052],[-71.88634,41.3211],[-71.88539,41.32172],[-71.88426,41.32079],[-71.88527,41.32177],[-71.88525,41.32194],[-71.87834,41.3268],[-71.87713,41.32755],[-71.87688,41.32735],[-71.87652,41.32742],[-71.87683,41.32733],[-71.8767,41.32725],[-71.87689,41.32698],[-71.87683,41.3267],[-71.87654,41.3267],[-71.87686,41.3266],[-71.87653,41.3264],[-71.87662,41.32618],[-71.87629,41.32628],[-71.87662,41.32605],[-71.87621,41.326],[-71.87642,41.32588],[-71.87602,41.32548],[-71.87626,41.32538],[-71.87616,41.32515],[-71.87644,41.32493],[-71.87593,41.3249],[-71.87639,41.32488],[-71.87646,41.32463],[-71.87597,41.32463],[-71.8765,41.32448],[-71.87637,41.32433],[-71.87589,41.32448],[-71.87586,41.32418],[-71.87606,41.32433],[-71.87651,41.32415],[-71.87581,41.3227],[-71.8761,41.32173],[-71.87576,41.32163],[-71.87595,41.3214],[-71.87577,41.32115],[-71.87536,41.3213],[-71.87564,41.32108],[-71.87532,41.3207],[-71.87613,41.31956],[-71.87466,41.31913],[-71.87374,41.31773],[-71.87396,41.31748],[-71.87375,41.31726],[-71.87241,41.31768],[-71.87208,41.31725],[-71.87114,41.3175],[-71.87094,41.31808],[-71.87108,41.3182],[-71.87067,41.31892],[-71.87119,41.3193],[-71.87143,41.3199],[-71.8711,41.32117],[-71.87012,41.32085],[-71.86886,41.32107],[-71.86804,41.32049],[-71.86732,41.32084],[-71.86714,41.32134],[-71.86621,41.32114],[-71.86569,41.32074],[-71.86499,41.32071],[-71.86462,41.31979],[-71.86365,41.32001],[-71.86272,41.32069],[-71.86164,41.32023],[-71.86188,41.31993],[-71.86282,41.31981],[-71.86367,41.31914],[-71.86364,41.31894],[-71.86295,41.31914],[-71.86286,41.31949],[-71.86226,41.31989],[-71.86155,41.31958],[-71.86117,41.31908],[-71.86096,41.31951],[-71.86142,41.32023],[-71.86121,41.32048],[-71.86088,41.32063],[-71.86046,41.32043],[-71.85958,41.32041],[-71.85878,41.31958],[-71.85819,41.31856],[-71.85895,41.31801],[-71.85834,41.31801],[-71.85792,41.31763],[-71.85838,41.31693],[-71.85904,41.31683],[-71.859,41.31633],[-71.85863,41.31613],[-71.85804,41.31618],[-71.8589,41.31643],[-71.85894,41.31678],[-71.85865,41.31696],[-71.85857,41.31656],[-71.85806,41.31661],[-71.8579,41.31623],[-71.85779,41.31676],[-71.85639,41.31768],[-71.85557,41.31715],[-71.85579,41.3172],[-71.85557,41.3165],[-71.85637,41.31628],[-71.85646,41.31596],[-71.85737,41.31576],[-71.85744,41.31548],[-71.85666,41.31546],[-71.85655,41.31578],[-71.85595,41.31595],[-71.85543,41.31653],[-71.85483,41.31515],[-71.85403,41.31448],[-71.85185,41.31537],[-71.85047,41.31647],[-71.85035,41.31667],[-71.85062,41.31675],[-71.85065,41.31695],[-71.85021,41.31752],[-71.84827,41.31839],[-71.8478,41.31829],[-71.84825,41.31707],[-71.8479,41.31677],[-71.84741,41.31669],[-71.8465,41.31714],[-71.84582,41.31706],[-71.84576,41.31679],[-71.84597,41.31651],[-71.84653,41.31641],[-71.84584,41.31629],[-71.84571,41.31581],[-71.84587,41.31556],[-71.84887,41.31572],[-71.84977,41.3152],[-71.84977,41.31505],[-71.84862,41.31492],[-71.84961,41.31462],[-71.84929,41.31445],[-71.84948,41.3143],[-71.85147,41.31417],[-71.85204,41.31383],[-71.85342,41.31368],[-71.85392,41.31338],[-71.85566,41.31298],[-71.85659,41.31301],[-71.8574,41.31244],[-71.85783,41.31059],[-71.85769,41.31019],[-71.85785,41.30966],[-71.85752,41.30936],[-71.8571,41.30831],[-71.85741,41.30806],[-71.85709,41.30806],[-71.85713,41.30766],[-71.85698,41.30761],[-71.85724,41.30739],[-71.85704,41.30719],[-71.85718,41.30677],[-71.857,41.30667],[-71.8567,41.30547],[-71.85696,41.30537],[-71.8567,41.30537],[-71.8565,41.30512],[-71.85653,41.30477],[-71.85628,41.30437],[-71.8566,41.30359],[-71.85587,41.30372],[-71.85541,41.30302],[-71.8548,41.30297],[-71.85395,41.30359],[-71.85374,41.30416],[-71.85411,41.30486],[-71.85392,41.30526],[-71.85277,41.30586],[-71.85135,41.30618],[-71.84876,41.3072],[-71.84879,41.30743],[-71.84918,41.30753],[-71.84931,41.308],[-71.84897,41.30793],[-71.84874,41.3083],[-71.84816,41.30848],[-71.84856,41.3095],[-71.84844,41.31037],[-71.84816,41.31082],[-71.84753,41.3109],[-71.8471,41.31032],[-71.84656,41.31017],[-71.84594,41.31069],[-71.84367,41.31122],[-71.84241,41.31046],[-71.84025,41.31129],[-71.83312,41.31577],[-71.82431,41.32285],[-71.82697,41.33277],[-71.83152,41.3347],[-71.83418,41.33985],[-71.83407,41.34734],[-71.82945,41.35063],[-71.82836,41.3517],[-71.8281,41.35353],[-71.82934,41.3574],[-71.82882,41.35875],[-71.82742,41.36054],[-71.82762,41.36099],[-71.82923,41.36254],[-71.83042,41.36437],[-71.83035,41.36609],[-71.82962,41.36824],[-71.82966,41.36901],[-71.82998,41.36974],[-71.83196,41.37166],[-71.83515,41.37284],[-71.83582,41.37327],[-71.83954,41.37804],[-71.8396,41.37939],[-71.83855,41.38149],[-71.83846,41.38249],[-71.83892,41.38576],[-71.83915,41.38609],[-71.84043,41.38676],[-71.84098,41.38789],[-71.84064,41.38954],[-71.84006,41.39063],[-71.84003,41.39103],[-71.87237,41.3989]]],[[[-71.9583,41.32383],[-71.95895,41.3236],[-71.95915,41.32295],[-71.95941,41.32268],[-71.95975,41.32268],[-71.95954,41.32238],[-71.95983,41.32221],[-71.96011,41.32253],[-71.96047,41.32166],[-71.9615,41.32108],[-71.96204,41.32119],[-71.96241,41.32156],[-71.96334,41.32156],[-71.96381,41.32209],[-71.96442,41.32209],[-71.96467,41.32229],[-71.965,41.32194],[-71.96491,41.32131],[-71.96552,41.32079],[-71.96525,41.32054],[-71.96462,41.32071],[-71.96445,41.32054],[-71.96577,41.32024],[-71.96649,41.32049],[-71.9667,41.32109],[-71.96661,41.32134],[-71.96728,41.32187],[-71.9675,41.32254],[-71.96781,41.32257],[-71.96792,41.32132],[-71.96852,41.3203],[-71.96822,41.3199],[-71.96845,41.31952],[-71.96918,41.3194],[-71.96933,41.31887],[-71.96762,41.31845],[-71.96758,41.318],[-71.96816,41.31712],[-71.96808,41.31672],[-71.96822,41.31655],[-71.9681,41.31592],[-71.96741,41.31557],[-71.96733,41.3153],[-71.96787,41.31475],[-71.96956,41.3144],[-71.9696,41.31423],[-71.96931,41.31423],[-71.96937,41.31383],[-71.96911,41.31303],[-71.96921,41.31248],[-71.9704,41.31153],[-71.97091,41.31141],[-71.97125,41.31061],[-71.97213,41.31019],[-71.97206,41.30931],[-71.97165,41.30939],[-71.97142,41.30904],[-71.97135,41.30926],[-71.97072,41.30946],[-71.97047,41.30983],[-71.97063,41.31021],[-71.97094,41.31031],[-71.97121,41.31011],[-71.97112,41.30981],[-71.97141,41.30966],[-71.97131,41.31039],[-71.97093,41.31061],[-71.97029,41.31023],[-71.96989,41.31073],[-71.96994,41.31171],[-71.96944,41.31201],[-71.96902,41.3126],[-71.96847,41.3128],[-71.9682,41.31263],[-71.96771,41.31268],[-71.96762,41.31293],[-71.96809,41.31328],[-71.96761,41.31353],[-71.96754,41.31413],[-71.9668,41.31467],[-71.96692,41.31547],[-71.96675,41.3156],[-71.96629,41.31547],[-71.96645,41.31457],[-71.96571,41.31485],[-71.96516,41.31452],[-71.96392,41.31524],[-71.9635,41.31584],[-71.96268,41.31597],[-71.96264,41.31537],[-71.96338,41.31489],[-71.9642,41.313],[-71.96387,41.3128],[-71.96322,41.31305],[-71.9634,41.3125],[-71.96488,41.31232],[-71.9655,41.31357],[-71.96596,41.31352],[-71.9664,41.3129],[-71.96719,41.30998],[-71.96759,41.3103],[-71.96829,41.31006],[-71.9687,41.31018],[-71.96916,41.30983],[-71.969,41.30958],[-71.96926,41.30926],[-71.96851,41.30933],[-71.96868,41.30868],[-71.96904,41.30843],[-71.96886,41.30803],[-71.96914,41.30778],[-71.96793,41.30776],[-71.96734,41.30836],[-71.96733,41.30878],[-71.96603,41.30943],[-71.9655,41.31023],[-71.96458,41.31035],[-71.96447,41.31003],[-71.96471,41.30983],[-71.9647,41.30955],[-71.96508,41.30928],[-71.96511,41.3088],[-71.9657,41.30815],[-71.96595,41.30753],[-71.96548,41.30723],[-71.96522,41.30675],[-71.96531,41.30648],[-71.96544,41.30658],[-71.96574,41.30628],[-71.96549,41.30591],[-71.96465,41.30543],[-71.96513,41.30461],[-71.96481,41.30426],[-71.96522,41.30423],[-71.96548,41.30388],[-71.96501,41.30371],[-71.96483,41.30328],[-71.96454,41.30338],[-71.96424,41.30313],[-71.96479,41.30191],[-71.96478,41.30166],[-71.96453,41.30153],[-71.96402,41.30161],[-71.96358,41.30231],[-71.96363,41.30308],[-71.96283,41.30398],[-71.96278,41.3049],[-71.96226,41.3052],[-71.96151,41.3052],[-71.96168,41.30533],[-71.96118,41.30585],[-71.96048,41.3075],[-71.95987,41.3075],[-71.9596,41.30722],[-71.96,41.30615],[-71.96029,41.30595],[-71.96013,41.30552],[-71.95957,41.30537],[-71.95887,41.30485],[-71.95935,41.30535],[-71.9592,41.3054],[-71.95945,41.30545],[-71.95939,41.3061],[-71.95904,41.30637],[-71.95837,41.30757],[-71.95923,41.30787],[-71.95944,41.30832],[-71.95914,41.30907],[-71.9589,41.30909],[-71.95908,41.30929],[-71.95889,41.30939],[-71.95889,41.31032],[-71.95863,41.31136],[-71.95842,41.31156],[-71.95861,41.31229],[-71.95815,41.31234],[-71.95864,41.31241],[-71.95872,41.31266],[-71.9583,41.31271],[-71.95856,41.31301],[-71.95837,41.31331],[-71.95846,41.31384],[-71.95765,41.31603],[-71.95787,41.31751],[-71.95826,41.31856],[-71.95813,41.31896],[-71.95849,41.31958],[-71.95838,41.32013],[-71.95776,41.3205],[-71.9578,41.32083],[-71.95748,41.3207],[-71.95773,41.32035],[-71.95753,41.32015],[-71.95685,41.32008],[-71.95727,41.32103],[-71.95708,41.32133],[-71.95572,41.32135],[-71.95748,41.32163],[-71.95717,41.322],[-71.95761,41.32188],[-71.95759,41.32218],[-71.95716,41.32233],[-71.95684,41.32208],[-71.95624,41.3223],[-71.95591,41.32275],[-71.9571,41.3229],[-71.95773,41.32358],[-71.9583,41.32383]]],[[[-71.88166,41.32059],[-71.88205,41.32086],[-71.88268,41.32057],[-71.88322,41.31994],[-71.88429,41.32072],[-71.88311,41.31967],[-71.88305,41.31912],[-71.88346,41.31814],[-71.88377,41.31799],[-71.88458,41.31827],[-71.88541,41.31772],[-71.88571,41.31705],[-71.88609,41.31672],[-71.88603,41.31575],[-71.88625,41.31568],[-71.88631,41.31528],[-71.88729,41.3156],[-71.88834,41.315],[-71.8876,41.31463],[-71.88634,41.31473],[-71.88596,41.31493],[-71.8861,41.3156],[-71.88566,41.31555],[-71.88541,41.31605],[-71.8846,41.31672],[-71.88423,41.318],[-71.88354,41.31779],[-71.88381,41.31695],[-71.88387,41.31565],[-71.88359,41.3154],[-71.88292,41.31555],[-71.88303,41.31605],[-71.88269,41.31624],[-71.88252,41.31679],[-71.88217,41.31659],[-71.88175,41.31717],[-71.88138,41.31712],[-71.88165,41.31724],[-71.88138,41.31792],[-71.88172,41.31872],[-71.88158,41.31892],[-71.88166,41.31971],[-71.88144,41.31996],[-71.88166,41.32059]]],[[[-71.95158,41.31555],[-71.95256,41.315],[-71.95333,41.31365],[-71.95289,41.31303],[-71.95271,41.31176],[-71.95224,41.31138],[-71.95173,41.31153],[-71.95098,41.3124],[-71.95096,41.31263],[-71.95123,41.31275],[-71.95083,41.31308],[-71.95028,41.31432],[-71.95019,41.31522],[-71.95086,41.31565],[-71.95158,41.31555]]],[[[-71.86342,41.31224],[-71.86393,41.31137],[-71.86513,41.31007],[-71.86508,41.30935],[-71.86478,41.30902],[-71.8637,41.30942],[-71.86319,41.30937],[-71.86263,41.3104],[-71.86128,41.31139],[-71.86121,41.31219],[-71.86141,41.31262],[-71.86225,41.31284],[-71.86285,41.31267],[-71.86342,41.31224]]],[[[-71.97326,41.29443],[-71.97172,41.29565],[-71.9715,41.29647],[-71.97185,41.2969],[-71.97217,41.29685],[-71.97264,41.2963],[-71.97455,41.29373],[-71.97386,41.29253],[-71.97392,41.29208],[-71.97419,41.29203],[-71.97439,41.29158],[-71.97391,41.29108],[-71.97279,41.29101],[-71.97072,41.2917],[-71.97081,41.29198],[-71.97154,41.29213],[-71.97165,41.29248],[-71.97183,41.29218],[-71.9729,41.29206],[-71.97351,41.29296],[-71.97363,41.29383],[-71.97326,41.29443]]],[[[-71.95856,41.30435],[-71.95885,41.30405],[-71.95862,41.3039],[-71.95894,41.30395],[-71.95869,41.30377],[-71.95863,41.30342],[-71.95898,41.30217],[-71.95847,41.30197],[-71.95799,41.30145],[-71.95694,41.3028],[-71.95702,41.30392],[-71.95772,41.30462],[-71.95842,41.30445],[-71.95882,41.30482],[-71.95894,41.30475],[-71.95856,41.30435]]],[[[-71.94953,41.31103],[-71.94963,41.3113],[-71.95015,41.31153],[-71.95043,41.31128],[-71.95077,41.3104],[-71.95056,41.3097],[-71.95078,41.30833],[-71.95048,41.30806],[-71.94924,41.30863],[-71.94852,41.31003],[-71.94868,41.3102],[-71.94969,41.31005],[-71.94992,41.31023],[-71.94953,41.31103]]],[[[-71.86652,41.31844],[-71.86675,41.31799],[-71.86674,41.31749],[-71.86519,41.31714],[-71.86571,41.31742],[-71.8655,41.31767],[-71.86372,41.31756],[-71.86369,41.31786],[-71.86447,41.31822],[-71.8647,41.31854],[-71.86509,41.31847],[-71.86652,41.31844]]],[[[-71.94847,41.32047],[-71.94856,41.32089],[-71.94896,41.32124],[-71.94937,41.32127],[-71.94976,41.32102],[-71.95001,41.32049],[-71.94996,41.31987],[-71.9493,41.31957],[-71.94847,41.32047]]],[[[-71.86062,41.30884],[-71.86051,41.30844],[-71.85913,41.30867],[-71.85909,41.30899],[-71.85963,41.30907],[-71.85985,41.30929],[-71.86062,41.30884]]],[[[-71.85986,41.31039],[-71.85976,41.31092],[-71.86033,41.31134],[-71.86064,41.31109],[-71.86072,41.31064],[-71.86022,41.31014],[-71.85986,41.31039]]],[[[-71.94863,41.31175],[-71.94868,41.3121],[-71.94923,41.31232],[-71.94936,41.312],[-71.94921,41.31168],[-71.94889,41.31162],[-71.94863,41.31175]]],[[[-71.88277,41.32274],[-71.88209,41.32299],[-71.88212,41.32314],[-71.88261,41.32309],[-71.88323,41.32264],[-71.88307,41.32231],[-71.88288,41.32229],[-71.88293,41.32244],[-71.88277,41.32274]]],[[[-71.94173,41.31201],[-71.94208,41.31231],[-71.94213,41.31164],[-71.94184,41.31166],[-71.94173,41.31201]]],[[[-71.88447,41.32192],[-71.88437,41.32174],[-71.88391,41.32189],[-71.88377,41.32217],[-71.88421,41.32209],[-71.88447,41.32192]]],[[[-71.88121,41.32181],[-71.88073,41.32204],[-71.88074,41.32231],[-71.88094,41.32244],[-71.881,41.32231],[-71.88121,41.32181]]],[[[-71.9711,41.2977],[-71.97142,41.29777],[-71.97133,41.29737],[-71.97104,41.2973],[-71.9711,41.2977]]],[[[-71.92955,41.3113],[-71.92983,41.31145],[-71.92987,41.31122],[-71.92952,41.31102],[-71.92955,41.3113]]],[[[-71.96178,41.29578],[-71.96207,41.29571],[-71.96207,41.29556],[-71.96182,41.29559],[-71.96178,41.29578]]],[[[-71.8575,41.31511],[-71.8576,41.31528],[-71.85779,41.31516],[-71.85764,41.31501],[-71.8575,41.31511]]],[[[-71.97017,41.29822],[-71.96993,41.29842],[-71.97039,41.29835],[-71.97017,41.29822]]]]}' FROM towns WHERE name = 'Stonington';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.08058,41.23612],[-73.08182,41.23622],[-73.08288,41.23739],[-73.08366,41.23767],[-73.0855,41.23662],[-73.08648,41.23657],[-73.08743,41.23847],[-73.08872,41.23938],[-73.09149,41.24],[-73.0956,41.24013],[-73.09562,41.24236],[-73.09676,41.24386],[-73.09753,41.24438],[-73.0994,41.24454],[-73.0999,41.24501],[-73.10002,41.24549],[-73.09941,41.24711],[-73.09876,41.24751],[-73.09875,41.24776],[-73.09969,41.24846],[-73.10107,41.24911],[-73.10117,41.24983],[-73.1169,41.24451],[-73.13528,41.23797],[-73.13961,41.23646],[-73.139,41.23486],[-73.13768,41.23128],[-73.13675,41.22776],[-73.13543,41.22511],[-73.13526,41.22136],[-73.13496,41.22081],[-73.13381,41.21996],[-73.13337,41.21911],[-73.13247,41.21806],[-73.13564,41.21757],[-73.13939,41.21625],[-73.13965,41.21602],[-73.13998,41.2149],[-73.14128,41.2138],[-73.14169,41.21276],[-73.14374,41.21134],[-73.14559,41.21079],[-73.14587,41.21036],[-73.14532,41.20979],[-73.14886,41.20857],[-73.149,41.20855],[-73.14942,41.20722],[-73.1503,41.20655],[-73.15047,41.20578],[-73.15158,41.20545],[-73.15219,41.20475],[-73.15279,41.20368],[-73.15325,41.20193],[-73.15394,41.20069],[-73.15409,41.19981],[-73.14419,41.1716],[-73.14449,41.17093],[-73.14514,41.17068],[-73.14537,41.16935],[-73.14593,41.16883],[-73.14505,41.1679],[-73.14552,41.16556],[-73.14418,41.16153],[-73.14448,41.15999],[-73.14532,41.15816],[-73.1453,41.15672],[-73.14553,41.15609],[-73.14913,41.15233],[-73.14854,41.15195],[-73.15073,41.14501],[-73.15061,41.14499],[-73.14968,41.14481],[-73.14901,41.14431],[-73.15084,41.14274],[-73.15033,41.14294],[-73.14846,41.14211],[-73.14874,41.14161],[-73.14834,41.14141],[-73.14875,41.14116],[-73.14759,41.14071],[-73.14726,41.14018],[-73.14642,41.13983],[-73.1454,41.13903],[-73.14404,41.13838],[-73.14331,41.13838],[-73.14205,41.13691],[-73.1412,41.1376],[-73.14162,41.13865],[-73.14244,41.13908],[-73.14122,41.13985],[-73.14085,41.13973],[-73.14081,41.13928],[-73.14098,41.13908],[-73.1407,41.13883],[-73.14085,41.13815],[-73.13999,41.13778],[-73.13971,41.13805],[-73.13892,41.13782],[-73.13824,41.1379],[-73.13923,41.139],[-73.13707,41.1392],[-73.1377,41.13987],[-73.13887,41.14025],[-73.1397,41.14092],[-73.14033,41.14105],[-73.13931,41.1427],[-73.13888,41.14274],[-73.13525,41.14099],[-73.13273,41.14431],[-73.13236,41.14428],[-73.12452,41.14065],[-73.12347,41.13977],[-73.12293,41.13857],[-73.12305,41.13768],[-73.12413,41.1357],[-73.1242,41.13483],[-73.12382,41.13366],[-73.12408,41.13323],[-73.12349,41.13316],[-73.12201,41.13068],[-73.1235,41.12923],[-73.12602,41.12746],[-73.12677,41.12732],[-73.12804,41.12762],[-73.13379,41.13027],[-73.13508,41.1305],[-73.13685,41.1313],[-73.13987,41.13221],[-73.14019,41.13213],[-73.14452,41.13334],[-73.14637,41.13419],[-73.14704,41.13471],[-73.14743,41.13467],[-73.1479,41.13489],[-73.14798,41.13524],[-73.14855,41.13559],[-73.14843,41.13617],[-73.14896,41.13612],[-73.15039,41.13759],[-73.15111,41.13807],[-73.15167,41.13802],[-73.15259,41.13884],[-73.15266,41.13892],[-73.15357,41.13595],[-73.1535,41.13595],[-73.15031,41.13507],[-73.14992,41.13482],[-73.15012,41.13444],[-73.14991,41.13469],[-73.14955,41.13467],[-73.14665,41.13384],[-73.14606,41.13351],[-73.14627,41.13312],[-73.14613,41.13329],[-73.14564,41.13326],[-73.1439,41.13276],[-73.14208,41.13213],[-73.14196,41.13203],[-73.14207,41.13181],[-73.14193,41.13198],[-73.14144,41.13196],[-73.13902,41.13123],[-73.1384,41.13098],[-73.13845,41.13086],[-73.13833,41.13106],[-73.13804,41.13105],[-73.13691,41.1308],[-73.13536,41.13015],[-73.13533,41.12988],[-73.13482,41.12993],[-73.13379,41.1296],[-73.13231,41.12895],[-73.13219,41.12882],[-73.1323,41.12865],[-73.13152,41.12862],[-73.1296,41.12774],[-73.1295,41.12747],[-73.12667,41.12649],[-73.12519,41.12654],[-73.12496,41.12631],[-73.12485,41.12644],[-73.12409,41.12624],[-73.12401,41.12599],[-73.12337,41.12596],[-73.12332,41.12574],[-73.1216,41.12521],[-73.11967,41.12503],[-73.11933,41.12483],[-73.1191,41.12518],[-73.11754,41.12525],[-73.11632,41.12593],[-73.11366,41.12625],[-73.11151,41.12744],[-73.10687,41.12918],[-73.10454,41.12931],[-73.10105,41.12878],[-73.09959,41.129],[-73.09811,41.12977],[-73.09793,41.13012],[-73.09667,41.13039],[-73.09437,41.12999],[-73.0934,41.12906],[-73.09224,41.12949],[-73.09153,41.13081],[-73.09078,41.13158],[-73.09062,41.13216],[-73.09093,41.13268],[-73.09228,41.13311],[-73.09319,41.13254],[-73.09465,41.13256],[-73.09658,41.13316],[-73.09721,41.13376],[-73.09943,41.13484],[-73.09986,41.13619],[-73.09986,41.13709],[-73.09834,41.14218],[-73.09811,41.14358],[-73.0983,41.14421],[-73.09868,41.14461],[-73.09895,41.14473],[-73.09943,41.14453],[-73.09978,41.14556],[-73.1001,41.14593],[-73.10146,41.14638],[-73.10161,41.14661],[-73.1015,41.14696],[-73.10167,41.14711],[-73.10256,41.14671],[-73.10313,41.14721],[-73.10392,41.14841],[-73.10381,41.14869],[-73.10202,41.14728],[-73.10144,41.14763],[-73.10167,41.14798],[-73.10352,41.14884],[-73.10425,41.14961],[-73.10444,41.14949],[-73.10516,41.15004],[-73.10626,41.15029],[-73.1086,41.14957],[-73.11075,41.14992],[-73.11074,41.15037],[-73.1094,41.15177],[-73.10964,41.15194],[-73.11127,41.15015],[-73.11354,41.15062],[-73.11469,41.15152],[-73.11615,41.15158],[-73.11578,41.15322],[-73.11488,41.15477],[-73.1142,41.15544],[-73.11418,41.15569],[-73.1151,41.15637],[-73.11518,41.15675],[-73.1155,41.15657],[-73.11555,41.15675],[-73.11454,41.15704],[-73.11466,41.15714],[-73.11445,41.15809],[-73.11484,41.15809],[-73.11453,41.15827],[-73.11495,41.15849],[-73.11538,41.15844],[-73.11605,41.15894],[-73.11577,41.16009],[-73.11607,41.16109],[-73.1174,41.16082],[-73.11768,41.16117],[-73.11804,41.16122],[-73.11744,41.16129],[-73.1171,41.16157],[-73.11579,41.16164],[-73.11469,41.16276],[-73.11437,41.16266],[-73.11452,41.16221],[-73.11355,41.16381],[-73.1139,41.16406],[-73.11349,41.16439],[-73.11345,41.16466],[-73.11301,41.16456],[-73.11302,41.16486],[-73.11356,41.16501],[-73.1136,41.16548],[-73.11385,41.16578],[-73.11354,41.16661],[-73.11439,41.16673],[-73.11444,41.16606],[-73.11513,41.16606],[-73.11486,41.16616],[-73.11511,41.16631],[-73.11504,41.16649],[-73.11463,41.16643],[-73.11448,41.16723],[-73.11449,41.16746],[-73.1151,41.16756],[-73.11447,41.16761],[-73.11423,41.16858],[-73.11384,41.16846],[-73.11395,41.16733],[-73.11297,41.16706],[-73.11266,41.16721],[-73.1124,41.16771],[-73.11255,41.16776],[-73.11219,41.16793],[-73.1122,41.16833],[-73.11196,41.16825],[-73.11164,41.169],[-73.11189,41.16915],[-73.11172,41.1691],[-73.1116,41.16938],[-73.11173,41.16955],[-73.11122,41.17023],[-73.11139,41.17048],[-73.11128,41.17065],[-73.11049,41.17112],[-73.11033,41.1714],[-73.11048,41.17152],[-73.11002,41.17165],[-73.10986,41.17197],[-73.10969,41.17187],[-73.10946,41.17242],[-73.10921,41.17235],[-73.1091,41.17252],[-73.10929,41.17262],[-73.10869,41.17357],[-73.10821,41.17367],[-73.10831,41.17394],[-73.10795,41.17384],[-73.10802,41.17402],[-73.10761,41.17414],[-73.1076,41.17457],[-73.10725,41.17489],[-73.10724,41.17527],[-73.10699,41.17534],[-73.10699,41.17606],[-73.10635,41.17669],[-73.10658,41.17706],[-73.10578,41.17701],[-73.10533,41.17724],[-73.10517,41.17756],[-73.10527,41.17776],[-73.10466,41.17851],[-73.10358,41.17821],[-73.10413,41.17861],[-73.10379,41.17878],[-73.10313,41.17863],[-73.10283,41.17913],[-73.10254,41.1792],[-73.1025,41.17948],[-73.10123,41.17923],[-73.10025,41.1813],[-73.10001,41.18302],[-73.10181,41.18792],[-73.1017,41.19179],[-73.1009,41.19509],[-73.10003,41.19666],[-73.09968,41.19793],[-73.09977,41.19866],[-73.10166,41.20318],[-73.10202,41.20635],[-73.10188,41.20803],[-73.10083,41.21035],[-73.09814,41.21384],[-73.09662,41.21671],[-73.09527,41.21858],[-73.09358,41.2198],[-73.0893,41.22207],[-73.08548,41.22526],[-73.08388,41.22633],[-73.08287,41.22733],[-73.08054,41.23065],[-73.07943,41.23269],[-73.07913,41.23397],[-73.07916,41.23589],[-73.07924,41.23611],[-73.08058,41.23612]]],[[[-73.15202,41.14089],[-73.15121,41.14064],[-73.14957,41.14104],[-73.15066,41.14101],[-73.1517,41.14172],[-73.15178,41.14174],[-73.15204,41.14089],[-73.15202,41.14089]]],[[[-73.15145,41.14229],[-73.15093,41.14266],[-73.15146,41.14246],[-73.15156,41.14244],[-73.15164,41.14214],[-73.15145,41.14229]]]]}' FROM towns WHERE name = 'Stratford';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.62411,42.02961],[-72.62456,42.03175],[-72.65671,42.0329],[-72.68957,42.03612],[-72.71581,42.03801],[-72.73117,42.03801],[-72.73116,42.03766],[-72.7727,42.03752],[-72.77264,42.03632],[-72.77341,42.0354],[-72.77377,42.03445],[-72.77178,42.03385],[-72.77001,42.033],[-72.76861,42.03175],[-72.7683,42.03112],[-72.76835,42.03037],[-72.769,42.0292],[-72.77013,42.02823],[-72.77091,42.02661],[-72.77205,42.02563],[-72.77224,42.02486],[-72.77292,42.02376],[-72.77264,42.02281],[-72.77344,42.02184],[-72.77499,42.02164],[-72.77531,42.02187],[-72.77546,42.02264],[-72.77586,42.02307],[-72.77733,42.02359],[-72.77765,42.02354],[-72.77972,42.02195],[-72.77994,42.02202],[-72.78008,42.0227],[-72.78138,42.02325],[-72.78142,42.0227],[-72.78067,42.02228],[-72.78071,42.02173],[-72.78084,42.02145],[-72.78144,42.02123],[-72.782,42.02043],[-72.78166,42.0202],[-72.78051,42.02078],[-72.77896,42.02042],[-72.7784,42.01875],[-72.77803,42.01838],[-72.77707,42.01795],[-72.77607,42.0181],[-72.77582,42.01775],[-72.77598,42.01722],[-72.7753,42.01672],[-72.77507,42.01605],[-72.77564,42.01587],[-72.7766,42.01633],[-72.77718,42.0152],[-72.77837,42.01455],[-72.77896,42.01393],[-72.77918,42.01311],[-72.7791,42.01271],[-72.77806,42.01138],[-72.77789,42.01071],[-72.77723,42.01031],[-72.77731,42.00978],[-72.77799,42.00916],[-72.78075,42.00881],[-72.78203,42.00829],[-72.78239,42.00352],[-72.78673,42.00305],[-72.79056,42.00263],[-72.79366,41.98925],[-72.7857,41.98747],[-72.77814,41.98578],[-72.77792,41.98571],[-72.76461,41.98284],[-72.72244,41.97316],[-72.73029,41.94895],[-72.71151,41.94757],[-72.70164,41.94685],[-72.68827,41.94588],[-72.6384,41.94229],[-72.62808,41.94175],[-72.62787,41.94202],[-72.62734,41.94302],[-72.62714,41.94612],[-72.62617,41.94934],[-72.62681,41.95691],[-72.62673,41.95763],[-72.62656,41.9584],[-72.62469,41.96162],[-72.6242,41.963],[-72.62361,41.96599],[-72.62063,41.976],[-72.61973,41.98185],[-72.61874,41.98434],[-72.61827,41.98646],[-72.61842,41.98826],[-72.61895,41.98981],[-72.6222,41.99531],[-72.62286,41.99694],[-72.62304,41.99806],[-72.62305,42.00153],[-72.62236,42.00458],[-72.62129,42.00695],[-72.6208,42.01344],[-72.6213,42.01846],[-72.62332,42.02581],[-72.62411,42.02961]]]]}' FROM towns WHERE name = 'Suffield';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.05511,41.60185],[-73.06906,41.63264],[-73.06404,41.67894],[-73.06135,41.70089],[-73.06845,41.7002],[-73.07466,41.69968],[-73.0747,41.69968],[-73.14005,41.66774],[-73.14674,41.66448],[-73.14668,41.66423],[-73.14567,41.6629],[-73.14449,41.66025],[-73.1443,41.6601],[-73.14353,41.6604],[-73.14328,41.66023],[-73.14054,41.6574],[-73.13903,41.65735],[-73.13866,41.6571],[-73.13846,41.65677],[-73.1384,41.65577],[-73.13604,41.6531],[-73.1356,41.65317],[-73.1343,41.65502],[-73.13278,41.65546],[-73.13203,41.65546],[-73.13085,41.65604],[-73.13019,41.65599],[-73.1296,41.65546],[-73.12817,41.65493],[-73.12749,41.65253],[-73.12672,41.65188],[-73.12493,41.65303],[-73.12345,41.65333],[-73.12216,41.65325],[-73.11993,41.65267],[-73.11837,41.65252],[-73.11837,41.65227],[-73.11887,41.6519],[-73.11895,41.6505],[-73.11926,41.64977],[-73.11927,41.64915],[-73.11903,41.64853],[-73.11866,41.64818],[-73.11539,41.64942],[-73.11388,41.64944],[-73.11356,41.64849],[-73.11224,41.64804],[-73.11094,41.64861],[-73.11053,41.64944],[-73.10875,41.64933],[-73.10846,41.64873],[-73.10523,41.64796],[-73.1024,41.6468],[-73.09995,41.6461],[-73.09907,41.64417],[-73.09855,41.64385],[-73.09772,41.64372],[-73.09789,41.64295],[-73.09572,41.642],[-73.09445,41.64172],[-73.09356,41.64207],[-73.09371,41.64134],[-73.09253,41.64034],[-73.09187,41.64027],[-73.09057,41.63959],[-73.08972,41.63959],[-73.08954,41.63939],[-73.08545,41.63936],[-73.08464,41.63731],[-73.08389,41.63671],[-73.08565,41.63541],[-73.08515,41.63406],[-73.08527,41.63324],[-73.08365,41.63201],[-73.0835,41.63166],[-73.08357,41.63011],[-73.08381,41.62922],[-73.0837,41.62862],[-73.08182,41.62507],[-73.08164,41.62422],[-73.08228,41.62274],[-73.08216,41.62202],[-73.08115,41.62139],[-73.07725,41.62034],[-73.07472,41.61936],[-73.07297,41.61804],[-73.07178,41.61624],[-73.07142,41.61486],[-73.06996,41.61331],[-73.06666,41.61098],[-73.06602,41.61081],[-73.06416,41.61105],[-73.06345,41.61083],[-73.06295,41.6103],[-73.06152,41.60665],[-73.06204,41.60361],[-73.062,41.59921],[-73.06175,41.59789],[-73.06198,41.59674],[-73.06205,41.59661],[-73.06001,41.59746],[-73.05441,41.59972],[-73.0542,41.59982],[-73.05511,41.60185]]]]}' FROM towns WHERE name = 'Thomaston';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-71.81189,41.93082],[-71.8154,42.00698],[-71.81615,42.01484],[-71.81736,42.02286],[-71.92008,42.02412],[-71.97555,42.02578],[-71.97985,42.02591],[-71.97727,42.01998],[-71.96987,42.00107],[-71.96096,41.97003],[-71.95114,41.94267],[-71.94645,41.93057],[-71.94594,41.92885],[-71.94586,41.92857],[-71.94583,41.92857],[-71.88355,41.92908],[-71.88075,41.92892],[-71.81182,41.92922],[-71.81189,41.93082]]]]}' FROM towns WHERE name = 'Thompson';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.32022,41.83343],[-72.3198,41.83413],[-72.31957,41.83538],[-72.31978,41.83656],[-72.32054,41.83853],[-72.31999,41.83955],[-72.31986,41.8402],[-72.32069,41.84165],[-72.32082,41.8428],[-72.32045,41.8435],[-72.31903,41.84467],[-72.31824,41.84507],[-72.31762,41.84574],[-72.31702,41.84594],[-72.31585,41.84582],[-72.31518,41.84611],[-72.3151,41.84654],[-72.3155,41.84716],[-72.31552,41.84774],[-72.31495,41.84879],[-72.31517,41.84909],[-72.31641,41.84964],[-72.31709,41.85064],[-72.31773,41.85096],[-72.31861,41.85189],[-72.31797,41.85386],[-72.31785,41.85641],[-72.3184,41.85761],[-72.31941,41.85893],[-72.31957,41.85936],[-72.31937,41.86011],[-72.31959,41.86103],[-72.32094,41.86231],[-72.3222,41.86451],[-72.32234,41.86503],[-72.322,41.86578],[-72.32197,41.8666],[-72.32294,41.86713],[-72.32383,41.86851],[-72.32379,41.86965],[-72.32337,41.87025],[-72.32114,41.8704],[-72.31995,41.87135],[-72.32005,41.87205],[-72.32093,41.8739],[-72.32095,41.8746],[-72.31864,41.87719],[-72.31861,41.87759],[-72.31915,41.87864],[-72.31855,41.87954],[-72.31816,41.87974],[-72.31762,41.87964],[-72.3174,41.87946],[-72.31731,41.87879],[-72.31694,41.87866],[-72.31636,41.87971],[-72.31475,41.88023],[-72.31477,41.88081],[-72.31384,41.88233],[-72.31353,41.88325],[-72.31281,41.8837],[-72.31109,41.88385],[-72.31037,41.88427],[-72.31051,41.88472],[-72.31143,41.8853],[-72.31537,41.88563],[-72.3156,41.88603],[-72.31548,41.88673],[-72.31455,41.88877],[-72.31365,41.88959],[-72.3114,41.89077],[-72.30573,41.89216],[-72.30401,41.8933],[-72.30352,41.8941],[-72.30501,41.89598],[-72.30538,41.89678],[-72.30499,41.89837],[-72.30423,41.89972],[-72.30413,41.90122],[-72.30375,41.90172],[-72.30396,41.90304],[-72.30464,41.90367],[-72.30626,41.90414],[-72.30707,41.90622],[-72.30868,41.90702],[-72.30993,41.90825],[-72.31043,41.90957],[-72.31078,41.91147],[-72.31061,41.91294],[-72.31075,41.91369],[-72.3122,41.91659],[-72.31346,41.91744],[-72.31511,41.91794],[-72.31656,41.92014],[-72.3185,41.92162],[-72.31856,41.9227],[-72.3197,41.92345],[-72.32016,41.92422],[-72.32023,41.92654],[-72.32039,41.92707],[-72.32518,41.92648],[-72.39969,41.91765],[-72.42472,41.91491],[-72.44464,41.91227],[-72.43986,41.87138],[-72.43978,41.87108],[-72.43942,41.86871],[-72.43258,41.82491],[-72.43193,41.82019],[-72.42204,41.82125],[-72.385,41.82524],[-72.3267,41.83235],[-72.32038,41.83311],[-72.32022,41.83343]]]]}' FROM towns WHERE name = 'Tolland';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.06623,41.88472],[-73.08596,41.88148],[-73.13164,41.87445],[-73.1646,41.87018],[-73.16425,41.87245],[-73.16431,41.87515],[-73.16319,41.87927],[-73.16369,41.88134],[-73.16357,41.88269],[-73.16513,41.88284],[-73.16508,41.88459],[-73.17025,41.88982],[-73.1723,41.89017],[-73.17355,41.89065],[-73.17431,41.89065],[-73.18279,41.89216],[-73.18798,41.87251],[-73.19347,41.85157],[-73.19918,41.8288],[-73.21003,41.78788],[-73.21078,41.78523],[-73.20777,41.78553],[-73.17205,41.7891],[-73.16722,41.77972],[-73.15207,41.77623],[-73.1292,41.77057],[-73.12824,41.77032],[-73.12817,41.77042],[-73.12655,41.77239],[-73.12621,41.77564],[-73.12593,41.77604],[-73.12396,41.77668],[-73.12171,41.77701],[-73.12098,41.77693],[-73.12028,41.77633],[-73.11973,41.77658],[-73.11937,41.77758],[-73.11937,41.77845],[-73.11975,41.77905],[-73.12108,41.78018],[-73.11277,41.7902],[-73.1132,41.79378],[-73.08645,41.79354],[-73.0857,41.79358],[-73.08559,41.79401],[-73.08303,41.8034],[-73.08038,41.81538],[-73.06687,41.87875],[-73.06553,41.88484],[-73.06623,41.88472]]]]}' FROM towns WHERE name = 'Torrington';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.1402,41.23746],[-73.14736,41.2501],[-73.16654,41.24921],[-73.1924,41.27517],[-73.19819,41.28098],[-73.20375,41.28058],[-73.25573,41.27692],[-73.26807,41.27596],[-73.26792,41.27571],[-73.26789,41.27484],[-73.26864,41.27376],[-73.2687,41.27337],[-73.26841,41.27182],[-73.26822,41.26547],[-73.22759,41.21833],[-73.23847,41.21243],[-73.23983,41.21168],[-73.23911,41.21088],[-73.23658,41.20763],[-73.23572,41.2067],[-73.22978,41.20702],[-73.22381,41.20731],[-73.21064,41.20752],[-73.20383,41.2084],[-73.19888,41.20765],[-73.19723,41.20795],[-73.19617,41.20897],[-73.19295,41.20956],[-73.19258,41.20831],[-73.18769,41.20763],[-73.18587,41.20663],[-73.18481,41.20638],[-73.18234,41.2049],[-73.18172,41.20298],[-73.17987,41.2012],[-73.17857,41.20077],[-73.17858,41.20027],[-73.17486,41.20037],[-73.16881,41.20206],[-73.16658,41.20225],[-73.16353,41.202],[-73.15337,41.20663],[-73.149,41.20855],[-73.14886,41.20857],[-73.14532,41.20979],[-73.14587,41.21036],[-73.14559,41.21079],[-73.14374,41.21134],[-73.14169,41.21276],[-73.14128,41.2138],[-73.13998,41.2149],[-73.13965,41.21602],[-73.13939,41.21625],[-73.13564,41.21757],[-73.13247,41.21806],[-73.13337,41.21911],[-73.13381,41.21996],[-73.13496,41.22081],[-73.13526,41.22136],[-73.13543,41.22511],[-73.13675,41.22776],[-73.13768,41.23128],[-73.139,41.23486],[-73.13961,41.23646],[-73.1402,41.23746]]]]}' FROM towns WHERE name = 'Trumbull';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.11888,42.02881],[-72.16082,42.0306],[-72.22175,42.03124],[-72.22185,42.03124],[-72.22159,42.0218],[-72.23122,41.99779],[-72.23841,41.98052],[-72.24787,41.95895],[-72.24889,41.95661],[-72.24656,41.95665],[-72.23675,41.95684],[-72.23087,41.95695],[-72.2306,41.95695],[-72.21719,41.95738],[-72.21274,41.95728],[-72.19882,41.95753],[-72.16617,41.95736],[-72.16576,41.95735],[-72.16554,41.95735],[-72.1165,41.95708],[-72.11376,41.95705],[-72.11376,41.9572],[-72.11448,41.96427],[-72.11463,41.97004],[-72.11562,41.9842],[-72.11624,41.99197],[-72.11674,41.99572],[-72.11883,42.02881],[-72.11888,42.02881]]]]}' FROM towns WHERE name = 'Union';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.43258,41.82491],[-72.43942,41.86871],[-72.43978,41.87108],[-72.44041,41.87098],[-72.50723,41.86224],[-72.50839,41.85627],[-72.50975,41.84903],[-72.51546,41.81647],[-72.51569,41.81525],[-72.51658,41.8101],[-72.51747,41.80414],[-72.50935,41.80487],[-72.4862,41.80781],[-72.48324,41.79355],[-72.48307,41.79267],[-72.4692,41.7948],[-72.43867,41.7996],[-72.42829,41.80123],[-72.43193,41.82019],[-72.43258,41.82491]]]]}' FROM towns WHERE name = 'Vernon';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-71.80596,41.6295],[-71.86246,41.63326],[-71.86268,41.63328],[-71.86273,41.63328],[-71.88923,41.63033],[-71.88928,41.6303],[-71.88928,41.63013],[-71.88844,41.61914],[-71.88754,41.61374],[-71.87744,41.5623],[-71.86451,41.50116],[-71.8644,41.50076],[-71.85371,41.49922],[-71.79383,41.49089],[-71.79379,41.49256],[-71.79288,41.58285],[-71.7929,41.58405],[-71.79321,41.58482],[-71.79284,41.58462],[-71.79281,41.58512],[-71.79288,41.59144],[-71.79249,41.60945],[-71.79264,41.61842],[-71.79245,41.62801],[-71.7925,41.62891],[-71.80596,41.6295]]]]}' FROM towns WHERE name = 'Voluntown';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.74785,41.48457],[-72.77268,41.48336],[-72.7829,41.48675],[-72.79612,41.49149],[-72.81807,41.49624],[-72.848,41.50238],[-72.85187,41.50319],[-72.84924,41.49681],[-72.84616,41.48947],[-72.85476,41.48146],[-72.85586,41.47994],[-72.86491,41.46654],[-72.87358,41.45164],[-72.87604,41.44693],[-72.87485,41.4464],[-72.87732,41.44281],[-72.87875,41.44171],[-72.88002,41.44026],[-72.88533,41.43338],[-72.88533,41.43335],[-72.88533,41.43333],[-72.85064,41.41961],[-72.84958,41.41919],[-72.84843,41.41876],[-72.84894,41.41714],[-72.84793,41.41516],[-72.84757,41.41367],[-72.8461,41.41249],[-72.84611,41.41132],[-72.84578,41.41094],[-72.84564,41.41114],[-72.84575,41.41141],[-72.84538,41.41201],[-72.84499,41.41196],[-72.84493,41.41161],[-72.84454,41.41149],[-72.84443,41.41184],[-72.84402,41.41194],[-72.84352,41.41176],[-72.84295,41.41201],[-72.84235,41.41166],[-72.84185,41.41178],[-72.84212,41.41108],[-72.84194,41.41078],[-72.84104,41.41091],[-72.84054,41.41123],[-72.83893,41.41103],[-72.83885,41.41148],[-72.83792,41.4112],[-72.83752,41.41178],[-72.83597,41.41207],[-72.83495,41.4119],[-72.83425,41.41127],[-72.83249,41.4103],[-72.83229,41.41005],[-72.83216,41.40905],[-72.83194,41.4089],[-72.83156,41.40912],[-72.83033,41.40867],[-72.82987,41.40889],[-72.82909,41.40864],[-72.82868,41.40874],[-72.82867,41.40919],[-72.82816,41.40937],[-72.82744,41.40889],[-72.82676,41.40896],[-72.82631,41.40924],[-72.82526,41.40894],[-72.82455,41.38054],[-72.81818,41.3823],[-72.81457,41.38334],[-72.81334,41.38372],[-72.79966,41.38802],[-72.78314,41.39396],[-72.77106,41.39871],[-72.75879,41.40309],[-72.74726,41.40669],[-72.74395,41.40816],[-72.74282,41.41086],[-72.73853,41.42114],[-72.73744,41.42279],[-72.73646,41.44025],[-72.73363,41.47076],[-72.73354,41.47179],[-72.74272,41.46968],[-72.74482,41.48482],[-72.74785,41.48457]]]]}' FROM towns WHERE name = 'Wallingford';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.31852,41.77426],[-73.38312,41.77585],[-73.39546,41.77615],[-73.39829,41.76596],[-73.40116,41.75555],[-73.40922,41.74872],[-73.40576,41.73016],[-73.38979,41.70268],[-73.38992,41.70196],[-73.3895,41.70104],[-73.38827,41.69971],[-73.38639,41.69711],[-73.3862,41.69619],[-73.38653,41.69531],[-73.38643,41.69444],[-73.38602,41.69371],[-73.38522,41.69294],[-73.38491,41.69241],[-73.3806,41.69203],[-73.35022,41.68974],[-73.31117,41.69807],[-73.30058,41.70177],[-73.30076,41.7021],[-73.30129,41.70347],[-73.30188,41.70372],[-73.30211,41.7041],[-73.30153,41.70567],[-73.29989,41.70774],[-73.29945,41.71016],[-73.29937,41.71171],[-73.30111,41.71856],[-73.30221,41.71958],[-73.30229,41.72131],[-73.30287,41.72448],[-73.30234,41.72618],[-73.30149,41.7264],[-73.30075,41.72762],[-73.30057,41.73052],[-73.30122,41.73197],[-73.30139,41.73417],[-73.30599,41.76667],[-73.30709,41.77404],[-73.30988,41.77402],[-73.31852,41.77426]]]]}' FROM towns WHERE name = 'Warren';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.28178,41.68761],[-73.30028,41.7015],[-73.30058,41.70177],[-73.31117,41.69807],[-73.35022,41.68974],[-73.3806,41.69203],[-73.38491,41.69241],[-73.39007,41.6917],[-73.38822,41.67958],[-73.38752,41.67566],[-73.38713,41.67311],[-73.38571,41.66394],[-73.38274,41.6509],[-73.37605,41.64664],[-73.36349,41.63801],[-73.35911,41.616],[-73.35356,41.59306],[-73.35183,41.58659],[-73.35153,41.58536],[-73.34665,41.58568],[-73.33671,41.58652],[-73.33083,41.58668],[-73.32348,41.58799],[-73.31467,41.58915],[-73.29605,41.59083],[-73.27136,41.59336],[-73.27061,41.59341],[-73.26935,41.59356],[-73.2654,41.59395],[-73.25667,41.59476],[-73.26032,41.61587],[-73.2661,41.65352],[-73.2665,41.65647],[-73.26615,41.66416],[-73.26572,41.6759],[-73.28026,41.68648],[-73.28166,41.68753],[-73.28178,41.68761]]]]}' FROM towns WHERE name = 'Washington';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.02387,41.60435],[-73.03988,41.60562],[-73.05364,41.60002],[-73.0542,41.59982],[-73.05441,41.59972],[-73.06001,41.59746],[-73.06205,41.59661],[-73.0625,41.59637],[-73.06306,41.59564],[-73.06226,41.59399],[-73.06208,41.59289],[-73.0636,41.59072],[-73.06305,41.58955],[-73.06192,41.58348],[-73.0623,41.58165],[-73.06013,41.57905],[-73.06062,41.57823],[-73.06447,41.57781],[-73.06698,41.57801],[-73.08471,41.57667],[-73.09442,41.55942],[-73.09585,41.5592],[-73.09526,41.5566],[-73.089,41.52785],[-73.08158,41.51395],[-73.06993,41.50172],[-73.06717,41.50181],[-73.04944,41.50251],[-73.01928,41.50681],[-73.01858,41.50688],[-73.01726,41.50746],[-73.01155,41.50985],[-73.00728,41.51101],[-72.97929,41.52171],[-72.97048,41.51815],[-72.9672,41.52494],[-72.95951,41.54074],[-72.95824,41.54371],[-72.9627,41.54319],[-72.96759,41.5426],[-72.98145,41.54189],[-73.00153,41.56061],[-73.01468,41.57336],[-73.01923,41.59228],[-73.0222,41.60437],[-73.02387,41.60435]]]]}' FROM towns WHERE name = 'Waterbury';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.09768,41.28508],[-72.09803,41.2854],[-72.0981,41.28605],[-72.09677,41.28657],[-72.09628,41.287],[-72.09605,41.28762],[-72.09634,41.28815],[-72.09589,41.28855],[-72.0959,41.28897],[-72.09626,41.28954],[-72.09676,41.28922],[-72.09784,41.28897],[-72.09821,41.2897],[-72.098,41.29015],[-72.09707,41.28992],[-72.09623,41.29014],[-72.09583,41.29074],[-72.09617,41.29147],[-72.09526,41.29182],[-72.095,41.29369],[-72.09786,41.29749],[-72.09878,41.29844],[-72.09969,41.30024],[-72.10027,41.30099],[-72.10001,41.30189],[-72.10404,41.31156],[-72.10843,41.32508],[-72.12422,41.33914],[-72.12453,41.34698],[-72.10653,41.36524],[-72.09961,41.3669],[-72.08698,41.36988],[-72.08889,41.37263],[-72.09311,41.37945],[-72.09337,41.38015],[-72.09366,41.38318],[-72.09369,41.3834],[-72.09395,41.38657],[-72.09331,41.39262],[-72.08938,41.39865],[-72.0892,41.399],[-72.08913,41.40013],[-72.08954,41.40143],[-72.09482,41.4072],[-72.09538,41.40825],[-72.09559,41.40858],[-72.098,41.40948],[-72.10254,41.41119],[-72.21571,41.39557],[-72.2169,41.39535],[-72.21598,41.39415],[-72.18789,41.35552],[-72.18875,41.35355],[-72.18934,41.3528],[-72.18879,41.35165],[-72.18916,41.34918],[-72.18894,41.34853],[-72.1876,41.34665],[-72.18759,41.34563],[-72.18713,41.34498],[-72.18532,41.33898],[-72.18527,41.33711],[-72.18475,41.33538],[-72.18439,41.33299],[-72.18343,41.33084],[-72.18394,41.32859],[-72.18348,41.32762],[-72.18315,41.32739],[-72.17779,41.32598],[-72.177,41.32556],[-72.17627,41.32486],[-72.17571,41.32328],[-72.17483,41.31734],[-72.17445,41.31601],[-72.1745,41.31531],[-72.17529,41.31344],[-72.1756,41.3083],[-72.17433,41.30642],[-72.17349,41.30587],[-72.17148,41.30534],[-72.17116,41.30449],[-72.17186,41.3034],[-72.17158,41.30327],[-72.17203,41.30115],[-72.17133,41.30052],[-72.16973,41.29975],[-72.16881,41.29905],[-72.16872,41.2986],[-72.16838,41.29842],[-72.16799,41.29772],[-72.16782,41.297],[-72.16679,41.29565],[-72.16674,41.2943],[-72.16684,41.2941],[-72.16715,41.29407],[-72.16762,41.29342],[-72.16786,41.29268],[-72.16753,41.2912],[-72.16773,41.2908],[-72.16812,41.29075],[-72.16816,41.2905],[-72.16776,41.29023],[-72.16668,41.29058],[-72.16569,41.2906],[-72.16531,41.29092],[-72.16472,41.29085],[-72.16488,41.2904],[-72.16377,41.2901],[-72.16348,41.2893],[-72.16371,41.28947],[-72.16375,41.28917],[-72.16346,41.2892],[-72.16352,41.2889],[-72.16177,41.28647],[-72.1616,41.28642],[-72.1612,41.287],[-72.16145,41.28807],[-72.16121,41.28814],[-72.16088,41.28757],[-72.15944,41.28662],[-72.15936,41.28632],[-72.15959,41.28609],[-72.16003,41.28592],[-72.1604,41.28625],[-72.16068,41.28587],[-72.16003,41.28517],[-72.15995,41.2849],[-72.16014,41.2847],[-72.15996,41.2846],[-72.15952,41.2851],[-72.15754,41.28574],[-72.15767,41.28597],[-72.15728,41.28599],[-72.15744,41.28627],[-72.15732,41.28639],[-72.15712,41.28632],[-72.1564,41.28669],[-72.15555,41.28646],[-72.1552,41.28709],[-72.15548,41.28736],[-72.15611,41.28751],[-72.15587,41.28681],[-72.1569,41.28694],[-72.15699,41.28744],[-72.15612,41.28774],[-72.15721,41.28754],[-72.15756,41.28784],[-72.15769,41.28839],[-72.15806,41.28854],[-72.15862,41.28916],[-72.15862,41.29081],[-72.15844,41.29126],[-72.15692,41.29251],[-72.15536,41.29236],[-72.15532,41.29261],[-72.1557,41.29318],[-72.15533,41.29366],[-72.15501,41.29368],[-72.15521,41.29396],[-72.15498,41.29423],[-72.15307,41.29478],[-72.15283,41.2948],[-72.15273,41.29458],[-72.15279,41.29488],[-72.15216,41.29512],[-72.15073,41.29527],[-72.15043,41.29502],[-72.15035,41.29547],[-72.14977,41.29577],[-72.15031,41.29647],[-72.15027,41.29677],[-72.15132,41.29705],[-72.15151,41.29765],[-72.15135,41.2979],[-72.15076,41.29792],[-72.1508,41.29839],[-72.1505,41.29877],[-72.15058,41.29912],[-72.14985,41.29917],[-72.14958,41.29829],[-72.14791,41.29849],[-72.14742,41.29759],[-72.14703,41.29746],[-72.14623,41.29764],[-72.14591,41.29814],[-72.14569,41.29816],[-72.14574,41.29899],[-72.14553,41.29916],[-72.1448,41.29923],[-72.14528,41.29983],[-72.14488,41.30021],[-72.14305,41.30001],[-72.14282,41.29978],[-72.14295,41.29906],[-72.14276,41.29868],[-72.14297,41.29821],[-72.14328,41.29803],[-72.14336,41.29746],[-72.14322,41.29611],[-72.14286,41.29531],[-72.14256,41.29518],[-72.14276,41.29446],[-72.14194,41.29229],[-72.14227,41.29186],[-72.1426,41.29234],[-72.14256,41.29276],[-72.14288,41.29274],[-72.14256,41.29184],[-72.14292,41.29174],[-72.14288,41.29116],[-72.142,41.29041],[-72.14193,41.28971],[-72.142,41.28951],[-72.14253,41.28942],[-72.14269,41.28902],[-72.14289,41.28927],[-72.14281,41.28894],[-72.143,41.28889],[-72.14264,41.28822],[-72.14219,41.28837],[-72.14164,41.28807],[-72.14055,41.28719],[-72.14044,41.28699],[-72.14066,41.28684],[-72.13924,41.28571],[-72.13825,41.28436],[-72.13826,41.28324],[-72.13647,41.28326],[-72.13502,41.28294],[-72.13401,41.28233],[-72.13302,41.28108],[-72.13268,41.28103],[-72.13208,41.28136],[-72.13103,41.28131],[-72.13051,41.28088],[-72.12991,41.28105],[-72.12873,41.28078],[-72.1283,41.28015],[-72.12803,41.28008],[-72.12589,41.28072],[-72.1255,41.28065],[-72.12556,41.28037],[-72.12531,41.28085],[-72.12475,41.28082],[-72.12459,41.2811],[-72.1242,41.2812],[-72.12351,41.28097],[-72.12353,41.28077],[-72.12313,41.28122],[-72.12224,41.28132],[-72.12229,41.28162],[-72.12155,41.28184],[-72.11817,41.28204],[-72.11381,41.2816],[-72.10822,41.28002],[-72.10743,41.2795],[-72.10638,41.27952],[-72.10609,41.27959],[-72.10599,41.28009],[-72.10453,41.28191],[-72.10309,41.28246],[-72.10251,41.28244],[-72.10237,41.28266],[-72.10246,41.28339],[-72.10204,41.28373],[-72.09948,41.28438],[-72.09831,41.2843],[-72.09676,41.28455],[-72.09576,41.2844],[-72.09642,41.2853],[-72.09717,41.28533],[-72.09741,41.28505],[-72.09741,41.28513],[-72.09768,41.28508]]],[[[-72.14728,41.27524],[-72.14675,41.27449],[-72.14656,41.27456],[-72.14728,41.27524]]]]}' FROM towns WHERE name = 'Waterford';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.06198,41.59674],[-73.06175,41.59789],[-73.062,41.59921],[-73.06204,41.60361],[-73.06152,41.60665],[-73.06295,41.6103],[-73.06345,41.61083],[-73.06416,41.61105],[-73.06602,41.61081],[-73.06666,41.61098],[-73.06996,41.61331],[-73.07142,41.61486],[-73.07178,41.61624],[-73.07297,41.61804],[-73.07472,41.61936],[-73.07725,41.62034],[-73.08115,41.62139],[-73.08216,41.62202],[-73.08228,41.62274],[-73.08164,41.62422],[-73.08182,41.62507],[-73.0837,41.62862],[-73.08381,41.62922],[-73.08357,41.63011],[-73.0835,41.63166],[-73.08365,41.63201],[-73.08527,41.63324],[-73.08515,41.63406],[-73.08565,41.63541],[-73.08389,41.63671],[-73.08464,41.63731],[-73.08545,41.63936],[-73.08954,41.63939],[-73.08972,41.63959],[-73.09057,41.63959],[-73.09187,41.64027],[-73.09253,41.64034],[-73.09371,41.64134],[-73.09356,41.64207],[-73.09445,41.64172],[-73.09572,41.642],[-73.09789,41.64295],[-73.09772,41.64372],[-73.09855,41.64385],[-73.09907,41.64417],[-73.09995,41.6461],[-73.1024,41.6468],[-73.10523,41.64796],[-73.10846,41.64873],[-73.10875,41.64933],[-73.11053,41.64944],[-73.11094,41.64861],[-73.11224,41.64804],[-73.11356,41.64849],[-73.11388,41.64944],[-73.11539,41.64942],[-73.11866,41.64818],[-73.11903,41.64853],[-73.11927,41.64915],[-73.11926,41.64977],[-73.11895,41.6505],[-73.11887,41.6519],[-73.11837,41.65227],[-73.11837,41.65252],[-73.11993,41.65267],[-73.12216,41.65325],[-73.12345,41.65333],[-73.12493,41.65303],[-73.12672,41.65188],[-73.12749,41.65253],[-73.12817,41.65493],[-73.1296,41.65546],[-73.13019,41.65599],[-73.13085,41.65604],[-73.13203,41.65546],[-73.13278,41.65546],[-73.1343,41.65502],[-73.1356,41.65317],[-73.13604,41.6531],[-73.1384,41.65577],[-73.13846,41.65677],[-73.13866,41.6571],[-73.13903,41.65735],[-73.14054,41.6574],[-73.14328,41.66023],[-73.14353,41.6604],[-73.1443,41.6601],[-73.14449,41.66025],[-73.14567,41.6629],[-73.14668,41.66423],[-73.14674,41.66448],[-73.1649,41.66228],[-73.17292,41.66137],[-73.17616,41.661],[-73.1693,41.63496],[-73.16569,41.62062],[-73.16414,41.61368],[-73.16162,41.60411],[-73.16129,41.60298],[-73.16079,41.60091],[-73.14739,41.54804],[-73.14695,41.54654],[-73.14589,41.54676],[-73.12144,41.5524],[-73.09648,41.55905],[-73.09585,41.5592],[-73.09442,41.55942],[-73.08471,41.57667],[-73.06698,41.57801],[-73.06447,41.57781],[-73.06062,41.57823],[-73.06013,41.57905],[-73.0623,41.58165],[-73.06192,41.58348],[-73.06305,41.58955],[-73.0636,41.59072],[-73.06208,41.59289],[-73.06226,41.59399],[-73.06306,41.59564],[-73.0625,41.59637],[-73.06205,41.59661],[-73.06198,41.59674]]]]}' FROM towns WHERE name = 'Watertown';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.72169,41.71746],[-72.72313,41.73721],[-72.72253,41.73809],[-72.72295,41.74064],[-72.72274,41.74983],[-72.72304,41.75008],[-72.72463,41.75944],[-72.72515,41.76469],[-72.72278,41.77136],[-72.72437,41.78157],[-72.72782,41.80146],[-72.74545,41.80101],[-72.76459,41.80054],[-72.80697,41.79946],[-72.80685,41.79766],[-72.80773,41.79624],[-72.80776,41.79589],[-72.80697,41.79296],[-72.80723,41.79109],[-72.80868,41.78672],[-72.80953,41.78672],[-72.80945,41.78243],[-72.80908,41.77743],[-72.80933,41.77284],[-72.80684,41.75954],[-72.80643,41.75877],[-72.80572,41.75622],[-72.80525,41.75267],[-72.80524,41.75255],[-72.80285,41.74081],[-72.78935,41.74134],[-72.78642,41.72317],[-72.78482,41.72422],[-72.78195,41.72806],[-72.78168,41.72796],[-72.78018,41.72976],[-72.77644,41.73247],[-72.77615,41.73075],[-72.77064,41.73099],[-72.76975,41.7194],[-72.76929,41.71111],[-72.74976,41.71323],[-72.73151,41.71512],[-72.7216,41.71628],[-72.72169,41.71746]]]]}' FROM towns WHERE name = 'West Hartford';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.93223,41.26284],[-72.93378,41.26661],[-72.93398,41.26769],[-72.93319,41.26864],[-72.93377,41.26954],[-72.93658,41.27069],[-72.93751,41.27166],[-72.93962,41.27317],[-72.93984,41.27414],[-72.94002,41.27424],[-72.94053,41.27434],[-72.94157,41.27349],[-72.94344,41.27422],[-72.94412,41.2749],[-72.9454,41.2756],[-72.94549,41.27595],[-72.94478,41.2766],[-72.94441,41.27724],[-72.94356,41.27722],[-72.94327,41.27742],[-72.94543,41.27957],[-72.94604,41.27974],[-72.94729,41.27937],[-72.94756,41.27945],[-72.94741,41.28015],[-72.94675,41.28072],[-72.94701,41.28127],[-72.94916,41.28232],[-72.94998,41.28312],[-72.95018,41.2841],[-72.95069,41.28487],[-72.95037,41.28557],[-72.95039,41.28622],[-72.94968,41.28619],[-72.94968,41.28677],[-72.94991,41.28732],[-72.95102,41.28767],[-72.95153,41.28857],[-72.95148,41.28924],[-72.95102,41.29002],[-72.95095,41.29117],[-72.95026,41.29224],[-72.97868,41.29508],[-72.98346,41.29561],[-72.98242,41.29311],[-72.97819,41.28364],[-72.9797,41.27238],[-72.98078,41.26938],[-72.97951,41.26671],[-72.9805,41.26429],[-72.98052,41.26239],[-72.97987,41.26179],[-72.97928,41.26154],[-72.97809,41.26166],[-72.97663,41.26228],[-72.97335,41.25995],[-72.97917,41.25387],[-72.985,41.24893],[-72.98478,41.2319],[-72.98383,41.23122],[-72.98358,41.2307],[-72.98386,41.2303],[-72.98384,41.22978],[-72.98456,41.22878],[-72.98403,41.22793],[-72.98294,41.22815],[-72.98261,41.22778],[-72.98272,41.2265],[-72.9831,41.22555],[-72.983,41.2252],[-72.98416,41.22503],[-72.98425,41.22493],[-72.9842,41.22478],[-72.98509,41.22456],[-72.98522,41.22481],[-72.98541,41.22473],[-72.98559,41.22418],[-72.98538,41.22356],[-72.986,41.22321],[-72.9867,41.22161],[-72.98631,41.22136],[-72.9864,41.22101],[-72.98615,41.22096],[-72.98591,41.22121],[-72.98567,41.22099],[-72.9851,41.22094],[-72.98518,41.22024],[-72.98462,41.22031],[-72.98483,41.21991],[-72.98455,41.21939],[-72.98415,41.21919],[-72.98416,41.21874],[-72.98442,41.21851],[-72.98475,41.21876],[-72.98508,41.21859],[-72.98492,41.21809],[-72.98433,41.21784],[-72.98519,41.21721],[-72.98484,41.21714],[-72.98503,41.21681],[-72.98445,41.21681],[-72.98432,41.21664],[-72.98453,41.21641],[-72.98523,41.21637],[-72.98531,41.21572],[-72.9844,41.21529],[-72.98425,41.21447],[-72.98458,41.21409],[-72.98414,41.21399],[-72.98253,41.21456],[-72.9817,41.21451],[-72.97978,41.21516],[-72.97857,41.21531],[-72.97857,41.21536],[-72.97768,41.21568],[-72.97706,41.21538],[-72.97615,41.21565],[-72.97406,41.21492],[-72.97336,41.21432],[-72.97158,41.21432],[-72.97021,41.21302],[-72.96752,41.21332],[-72.96384,41.21701],[-72.96327,41.219],[-72.96307,41.21903],[-72.96322,41.2192],[-72.96268,41.21963],[-72.96295,41.21983],[-72.96284,41.22008],[-72.96234,41.2206],[-72.96188,41.22065],[-72.96252,41.22073],[-72.96257,41.2209],[-72.96249,41.2213],[-72.96222,41.22145],[-72.96261,41.2215],[-72.96267,41.22175],[-72.96232,41.2223],[-72.96208,41.2223],[-72.96232,41.2224],[-72.96223,41.22265],[-72.96148,41.22335],[-72.96088,41.22355],[-72.96091,41.22387],[-72.95959,41.22522],[-72.95631,41.22689],[-72.9563,41.22739],[-72.95573,41.22788],[-72.95553,41.22778],[-72.95574,41.22808],[-72.95514,41.22853],[-72.95515,41.22948],[-72.95387,41.23073],[-72.95349,41.23178],[-72.95263,41.23238],[-72.95243,41.23277],[-72.95195,41.23227],[-72.95113,41.23255],[-72.95038,41.23255],[-72.94984,41.23245],[-72.94946,41.23192],[-72.94917,41.23195],[-72.9491,41.23212],[-72.94874,41.23214],[-72.94959,41.23295],[-72.94922,41.23364],[-72.94858,41.23424],[-72.94627,41.23501],[-72.94602,41.23546],[-72.94476,41.23564],[-72.94448,41.23516],[-72.94469,41.23568],[-72.94442,41.23581],[-72.94221,41.23643],[-72.94148,41.23645],[-72.94123,41.23613],[-72.94134,41.23655],[-72.93932,41.23735],[-72.93808,41.23747],[-72.93785,41.23705],[-72.93803,41.2375],[-72.93779,41.23762],[-72.93632,41.2378],[-72.93614,41.2375],[-72.93617,41.23784],[-72.93441,41.23817],[-72.93454,41.23852],[-72.93401,41.23867],[-72.93246,41.23891],[-72.93081,41.23881],[-72.93099,41.23939],[-72.93001,41.23976],[-72.92855,41.23993],[-72.92861,41.24031],[-72.92797,41.24073],[-72.92354,41.2429],[-72.92009,41.24616],[-72.9183,41.24733],[-72.91731,41.24823],[-72.91546,41.24913],[-72.91509,41.2497],[-72.9152,41.24995],[-72.91548,41.24988],[-72.91657,41.2489],[-72.9198,41.24776],[-72.92004,41.24709],[-72.92097,41.24624],[-72.92105,41.24566],[-72.92176,41.24507],[-72.92185,41.24477],[-72.92316,41.24409],[-72.92351,41.24347],[-72.92396,41.24315],[-72.92462,41.24335],[-72.92475,41.24362],[-72.92492,41.2436],[-72.92481,41.24402],[-72.92396,41.24402],[-72.92357,41.24569],[-72.92296,41.24619],[-72.92288,41.24669],[-72.92145,41.24771],[-72.92346,41.24739],[-72.92357,41.24727],[-72.92308,41.24717],[-72.92376,41.24694],[-72.92411,41.24724],[-72.92477,41.24737],[-72.92374,41.24787],[-72.9238,41.24822],[-72.92344,41.24847],[-72.9237,41.24904],[-72.92441,41.24937],[-72.92437,41.24957],[-72.92492,41.24999],[-72.92531,41.25007],[-72.92539,41.25029],[-72.92687,41.25114],[-72.9275,41.25182],[-72.92852,41.25249],[-72.92938,41.25359],[-72.92954,41.25402],[-72.92937,41.25424],[-72.92957,41.25444],[-72.92938,41.25459],[-72.92969,41.25489],[-72.92997,41.25634],[-72.9298,41.25694],[-72.93009,41.25709],[-72.93012,41.25732],[-72.9296,41.25757],[-72.92951,41.25789],[-72.93005,41.25802],[-72.93102,41.25879],[-72.93142,41.25934],[-72.9317,41.26049],[-72.9319,41.26049],[-72.93211,41.26099],[-72.93237,41.26094],[-72.9318,41.26132],[-72.9316,41.26206],[-72.93156,41.26221],[-72.93223,41.26284]]]]}' FROM towns WHERE name = 'West Haven';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.41496,41.26388],[-72.4274,41.29612],[-72.42622,41.29806],[-72.42418,41.29981],[-72.42152,41.3026],[-72.42066,41.30555],[-72.42061,41.3057],[-72.42308,41.30598],[-72.42298,41.3069],[-72.42693,41.30805],[-72.42986,41.3125],[-72.46769,41.31588],[-72.46779,41.31588],[-72.46788,41.31588],[-72.47112,41.31619],[-72.49366,41.32988],[-72.50872,41.32806],[-72.50906,41.32803],[-72.507,41.32329],[-72.50694,41.32314],[-72.50676,41.32269],[-72.48662,41.26886],[-72.47925,41.25091],[-72.47922,41.25086],[-72.47738,41.25106],[-72.47295,41.25018],[-72.46693,41.24955],[-72.466,41.24922],[-72.46605,41.2485],[-72.46581,41.25024],[-72.46598,41.25039],[-72.46688,41.2503],[-72.46806,41.2509],[-72.46845,41.25082],[-72.46807,41.25037],[-72.46827,41.25032],[-72.46942,41.25135],[-72.46928,41.25167],[-72.47052,41.25345],[-72.47062,41.2542],[-72.47045,41.25492],[-72.46967,41.25485],[-72.4696,41.25412],[-72.46893,41.25315],[-72.46612,41.25104],[-72.46559,41.25092],[-72.463,41.25396],[-72.46334,41.25454],[-72.46318,41.25563],[-72.46251,41.25601],[-72.4611,41.25598],[-72.46063,41.25678],[-72.45987,41.25718],[-72.46031,41.25743],[-72.46186,41.25701],[-72.46202,41.25748],[-72.46083,41.25778],[-72.46106,41.25793],[-72.46072,41.258],[-72.45925,41.25768],[-72.45914,41.25733],[-72.45876,41.2574],[-72.45865,41.25693],[-72.45914,41.25625],[-72.4597,41.2564],[-72.46074,41.25536],[-72.46222,41.25523],[-72.4625,41.25501],[-72.46244,41.25476],[-72.46222,41.25448],[-72.4623,41.25471],[-72.46203,41.25481],[-72.45947,41.25528],[-72.45908,41.25428],[-72.46154,41.25386],[-72.46226,41.25356],[-72.46249,41.25319],[-72.46075,41.25341],[-72.46052,41.25313],[-72.46267,41.25271],[-72.46293,41.25234],[-72.46213,41.25244],[-72.462,41.25201],[-72.46337,41.25171],[-72.46382,41.25121],[-72.46184,41.25154],[-72.46156,41.25136],[-72.46208,41.25081],[-72.46342,41.25066],[-72.46352,41.25094],[-72.46417,41.25079],[-72.46437,41.25102],[-72.46476,41.25094],[-72.46412,41.24982],[-72.46282,41.24944],[-72.46202,41.24954],[-72.46145,41.24919],[-72.46045,41.24974],[-72.46057,41.24991],[-72.45773,41.2532],[-72.45642,41.25403],[-72.45547,41.25412],[-72.4546,41.25502],[-72.45241,41.25657],[-72.4492,41.25804],[-72.44527,41.25913],[-72.4428,41.2594],[-72.43971,41.25935],[-72.4347,41.25856],[-72.43223,41.25868],[-72.42753,41.25783],[-72.42496,41.25708],[-72.42505,41.25695],[-72.42419,41.25642],[-72.42415,41.2561],[-72.42439,41.25588],[-72.42151,41.25477],[-72.42131,41.25465],[-72.42141,41.25452],[-72.42114,41.25462],[-72.42026,41.2543],[-72.41985,41.2538],[-72.41936,41.25427],[-72.41767,41.25402],[-72.41747,41.25382],[-72.41675,41.25414],[-72.41671,41.25434],[-72.41651,41.25419],[-72.41578,41.25479],[-72.41502,41.25476],[-72.41492,41.25456],[-72.41498,41.25486],[-72.41467,41.25531],[-72.41491,41.25576],[-72.41443,41.25614],[-72.41451,41.25634],[-72.41322,41.25718],[-72.41204,41.25753],[-72.41212,41.25768],[-72.4119,41.25773],[-72.41208,41.25783],[-72.41204,41.25813],[-72.41151,41.25838],[-72.41166,41.2586],[-72.41147,41.25878],[-72.41153,41.25913],[-72.41124,41.2592],[-72.41059,41.25943],[-72.41496,41.26388]]],[[[-72.46794,41.23703],[-72.468,41.23758],[-72.46781,41.24001],[-72.46801,41.23708],[-72.46838,41.23641],[-72.46839,41.23591],[-72.47794,41.23593],[-72.46836,41.23584],[-72.46747,41.23524],[-72.46711,41.23539],[-72.4674,41.23594],[-72.46794,41.23703]]],[[[-72.45723,41.24376],[-72.458,41.24439],[-72.45773,41.24336],[-72.45738,41.24311],[-72.45745,41.24294],[-72.45676,41.24261],[-72.45679,41.24226],[-72.45636,41.24236],[-72.45644,41.24266],[-72.45676,41.24286],[-72.45723,41.24376]]],[[[-72.43399,41.25582],[-72.434,41.25606],[-72.43429,41.25604],[-72.43423,41.25582],[-72.43476,41.25572],[-72.43579,41.25589],[-72.43558,41.25549],[-72.43435,41.25577],[-72.43399,41.25582]]],[[[-72.42243,41.25293],[-72.42267,41.25283],[-72.42257,41.25268],[-72.42243,41.25293]]]]}' FROM towns WHERE name = 'Westbrook';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.32027,41.19305],[-73.31902,41.19372],[-73.31767,41.19551],[-73.3169,41.19586],[-73.31717,41.19689],[-73.31713,41.19789],[-73.31664,41.19933],[-73.31666,41.20013],[-73.31498,41.20141],[-73.31497,41.2021],[-73.31596,41.2043],[-73.31709,41.20468],[-73.31834,41.20646],[-73.31813,41.20688],[-73.31738,41.2069],[-73.31725,41.20738],[-73.36123,41.25632],[-73.36151,41.25667],[-73.36255,41.2563],[-73.4215,41.23566],[-73.4222,41.23541],[-73.41871,41.23096],[-73.4006,41.20668],[-73.37081,41.16605],[-73.36922,41.16387],[-73.36661,41.1603],[-73.36426,41.15737],[-73.36374,41.15642],[-73.36319,41.15597],[-73.36154,41.15355],[-73.3291,41.17403],[-73.32897,41.17383],[-73.32876,41.174],[-73.32838,41.17498],[-73.32824,41.17617],[-73.32751,41.17682],[-73.32407,41.17819],[-73.3241,41.17917],[-73.32366,41.18054],[-73.32404,41.18209],[-73.32396,41.18249],[-73.32314,41.18356],[-73.32329,41.18453],[-73.32262,41.18561],[-73.32197,41.18753],[-73.32069,41.18845],[-73.31975,41.18967],[-73.3203,41.19015],[-73.32106,41.19025],[-73.32133,41.1906],[-73.3212,41.19105],[-73.32034,41.19155],[-73.32035,41.19272],[-73.32031,41.19297],[-73.32027,41.19305]]]]}' FROM towns WHERE name = 'Weston';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.28739,41.10228],[-73.28548,41.10351],[-73.28565,41.1043],[-73.28601,41.10485],[-73.28533,41.1056],[-73.28572,41.1066],[-73.28508,41.1072],[-73.2858,41.10845],[-73.28593,41.10933],[-73.28475,41.11135],[-73.28592,41.11325],[-73.28586,41.1137],[-73.28546,41.11402],[-73.28542,41.11434],[-73.28641,41.11557],[-73.28662,41.11622],[-73.28748,41.11744],[-73.2878,41.11837],[-73.28765,41.11967],[-73.28693,41.12097],[-73.28766,41.12259],[-73.28809,41.12302],[-73.28917,41.12354],[-73.29012,41.12344],[-73.29073,41.12362],[-73.29167,41.12332],[-73.29212,41.1236],[-73.29233,41.12517],[-73.29296,41.12577],[-73.29314,41.12679],[-73.2946,41.12707],[-73.29495,41.1273],[-73.29407,41.12807],[-73.29406,41.12912],[-73.29367,41.12997],[-73.2939,41.13044],[-73.29432,41.13072],[-73.29525,41.13087],[-73.29616,41.13045],[-73.29658,41.13045],[-73.29711,41.13105],[-73.32885,41.17368],[-73.32897,41.17383],[-73.3291,41.17403],[-73.36154,41.15355],[-73.36319,41.15597],[-73.36374,41.15642],[-73.36426,41.15737],[-73.36661,41.1603],[-73.36922,41.16387],[-73.37657,41.149],[-73.37698,41.14168],[-73.37786,41.12705],[-73.37542,41.12377],[-73.37152,41.12022],[-73.37178,41.11887],[-73.37163,41.1171],[-73.36857,41.10056],[-73.3719,41.08435],[-73.37103,41.08193],[-73.37106,41.08138],[-73.37184,41.07981],[-73.37139,41.07701],[-73.37115,41.07706],[-73.37018,41.07706],[-73.36941,41.07918],[-73.36913,41.07948],[-73.36879,41.07933],[-73.36845,41.07858],[-73.36778,41.07815],[-73.36749,41.07815],[-73.36602,41.07718],[-73.36359,41.07618],[-73.36343,41.0758],[-73.3636,41.0751],[-73.36466,41.07473],[-73.3661,41.07503],[-73.36917,41.07658],[-73.36977,41.07661],[-73.36954,41.07606],[-73.3666,41.07361],[-73.36602,41.07221],[-73.36616,41.07273],[-73.366,41.07318],[-73.36475,41.0736],[-73.36382,41.07353],[-73.36357,41.07315],[-73.36204,41.07313],[-73.36179,41.073],[-73.36176,41.07268],[-73.36081,41.07267],[-73.36054,41.07252],[-73.36055,41.07225],[-73.35909,41.07215],[-73.35886,41.07182],[-73.35839,41.07232],[-73.35694,41.07244],[-73.35515,41.07199],[-73.35547,41.07229],[-73.35524,41.07334],[-73.35484,41.07399],[-73.35297,41.07409],[-73.35336,41.07414],[-73.35339,41.07429],[-73.3534,41.07596],[-73.35311,41.07608],[-73.35396,41.07614],[-73.35405,41.07664],[-73.35429,41.07656],[-73.35471,41.07691],[-73.3546,41.07719],[-73.35513,41.07696],[-73.3557,41.07744],[-73.35625,41.07696],[-73.355,41.07586],[-73.35499,41.07569],[-73.35519,41.07569],[-73.35494,41.07554],[-73.35498,41.07436],[-73.3566,41.07429],[-73.35665,41.07522],[-73.35628,41.07574],[-73.35639,41.07606],[-73.3584,41.07599],[-73.36024,41.07647],[-73.36187,41.07717],[-73.36499,41.07915],[-73.36636,41.08043],[-73.36663,41.08125],[-73.36702,41.08128],[-73.36723,41.08085],[-73.36834,41.0805],[-73.37016,41.08061],[-73.37061,41.08091],[-73.37069,41.08191],[-73.371,41.0827],[-73.37074,41.08288],[-73.37015,41.08255],[-73.36948,41.08138],[-73.36882,41.08115],[-73.36817,41.0813],[-73.36793,41.0824],[-73.36772,41.08255],[-73.36681,41.08235],[-73.36674,41.08175],[-73.36609,41.08187],[-73.36619,41.0827],[-73.36754,41.08317],[-73.36763,41.08365],[-73.36711,41.0841],[-73.36723,41.08415],[-73.36683,41.08542],[-73.36576,41.08707],[-73.36405,41.08831],[-73.3641,41.08849],[-73.36444,41.08824],[-73.36478,41.08837],[-73.36464,41.08924],[-73.36413,41.08951],[-73.36393,41.08911],[-73.36356,41.08904],[-73.36276,41.08999],[-73.36291,41.09019],[-73.36252,41.09083],[-73.36256,41.09131],[-73.36165,41.09191],[-73.36175,41.09256],[-73.36132,41.09283],[-73.36152,41.09298],[-73.36139,41.09348],[-73.36346,41.09386],[-73.36328,41.09418],[-73.36219,41.09421],[-73.36185,41.09503],[-73.36092,41.095],[-73.36086,41.09308],[-73.36061,41.0927],[-73.36067,41.0924],[-73.36092,41.09258],[-73.36111,41.09246],[-73.36107,41.09203],[-73.36053,41.09166],[-73.36046,41.09111],[-73.36067,41.09073],[-73.36099,41.09081],[-73.36113,41.09063],[-73.36084,41.09058],[-73.36046,41.09003],[-73.35988,41.09031],[-73.35949,41.09103],[-73.35911,41.09228],[-73.35918,41.09295],[-73.35881,41.09348],[-73.35849,41.09345],[-73.3576,41.0946],[-73.35742,41.09575],[-73.35718,41.0959],[-73.35724,41.09637],[-73.35584,41.09667],[-73.35608,41.09582],[-73.35586,41.09572],[-73.3561,41.09567],[-73.35598,41.09552],[-73.35612,41.0953],[-73.35636,41.09542],[-73.35626,41.09452],[-73.35643,41.09372],[-73.35699,41.09285],[-73.35723,41.09183],[-73.35746,41.09155],[-73.3581,41.08845],[-73.35898,41.08716],[-73.35911,41.08573],[-73.35891,41.08543],[-73.35828,41.08546],[-73.35744,41.08611],[-73.3564,41.08628],[-73.35598,41.08585],[-73.35591,41.0852],[-73.35556,41.0848],[-73.35434,41.0854],[-73.35401,41.0859],[-73.3537,41.08598],[-73.3532,41.08573],[-73.35328,41.08505],[-73.35286,41.0847],[-73.35125,41.08462],[-73.35082,41.08502],[-73.35106,41.08492],[-73.35097,41.0851],[-73.35112,41.08525],[-73.35089,41.0857],[-73.35003,41.08612],[-73.34926,41.08619],[-73.34847,41.08659],[-73.34565,41.08674],[-73.34415,41.08706],[-73.34417,41.08756],[-73.34382,41.08826],[-73.34397,41.08891],[-73.34345,41.08861],[-73.34332,41.08841],[-73.34351,41.08821],[-73.34333,41.08808],[-73.3437,41.08748],[-73.34373,41.08666],[-73.34404,41.08669],[-73.3444,41.08631],[-73.34397,41.08599],[-73.34393,41.08539],[-73.3445,41.08471],[-73.34495,41.08454],[-73.34513,41.08474],[-73.34504,41.08491],[-73.34524,41.08524],[-73.34507,41.08594],[-73.34527,41.08634],[-73.34567,41.08644],[-73.34676,41.08577],[-73.34619,41.08544],[-73.3465,41.08507],[-73.34582,41.08511],[-73.34538,41.08494],[-73.34519,41.08446],[-73.34621,41.08449],[-73.347,41.08562],[-73.34739,41.08544],[-73.34651,41.08449],[-73.34705,41.08412],[-73.34737,41.08409],[-73.3479,41.08467],[-73.34811,41.08454],[-73.34883,41.08492],[-73.34992,41.08497],[-73.35018,41.08457],[-73.35092,41.08412],[-73.35105,41.08377],[-73.35215,41.08398],[-73.35267,41.08343],[-73.35244,41.08313],[-73.34978,41.0834],[-73.34851,41.08312],[-73.3448,41.08361],[-73.34424,41.08421],[-73.3424,41.08386],[-73.34171,41.08331],[-73.34222,41.08191],[-73.34275,41.08176],[-73.34442,41.08217],[-73.34449,41.08284],[-73.34469,41.08219],[-73.34488,41.08212],[-73.34477,41.08167],[-73.34444,41.08134],[-73.34436,41.08089],[-73.34341,41.08084],[-73.3421,41.08021],[-73.34047,41.07861],[-73.34095,41.07924],[-73.3407,41.07991],[-73.33941,41.08216],[-73.33852,41.08311],[-73.33694,41.08415],[-73.33557,41.0847],[-73.33499,41.08477],[-73.33465,41.08397],[-73.3349,41.0848],[-73.33383,41.08565],[-73.33351,41.08732],[-73.33283,41.08797],[-73.33298,41.08822],[-73.33334,41.08817],[-73.33406,41.08854],[-73.33432,41.08977],[-73.33339,41.09049],[-73.33274,41.09069],[-73.33269,41.09056],[-73.33225,41.09141],[-73.33115,41.09129],[-73.33218,41.09079],[-73.33228,41.09061],[-73.33207,41.09021],[-73.33041,41.09069],[-73.32965,41.09061],[-73.3291,41.09023],[-73.32905,41.08991],[-73.32871,41.08993],[-73.32811,41.08956],[-73.32811,41.08936],[-73.32784,41.08943],[-73.32595,41.08876],[-73.32566,41.08816],[-73.32552,41.08821],[-73.32565,41.08836],[-73.32394,41.08893],[-73.32272,41.0889],[-73.32253,41.08818],[-73.32212,41.08838],[-73.32093,41.08845],[-73.3196,41.08792],[-73.31791,41.08667],[-73.31769,41.08729],[-73.31534,41.08909],[-73.31169,41.09141],[-73.30887,41.09243],[-73.30739,41.09252],[-73.30543,41.0934],[-73.30199,41.09401],[-73.30007,41.09384],[-73.29923,41.09356],[-73.29882,41.09284],[-73.29796,41.09246],[-73.29726,41.09183],[-73.29658,41.09258],[-73.29577,41.09308],[-73.29548,41.09306],[-73.29548,41.09326],[-73.29289,41.09502],[-73.28922,41.09672],[-73.28811,41.09709],[-73.28738,41.09704],[-73.28683,41.09739],[-73.28645,41.09846],[-73.28653,41.09936],[-73.28723,41.10101],[-73.28724,41.10208],[-73.28719,41.10211],[-73.28739,41.10228]]],[[[-73.34257,41.06356],[-73.34523,41.06306],[-73.34604,41.06251],[-73.34655,41.06184],[-73.34722,41.06151],[-73.34654,41.06154],[-73.3455,41.06241],[-73.34449,41.06283],[-73.34229,41.06331],[-73.34231,41.06286],[-73.34274,41.06288],[-73.34314,41.06243],[-73.34398,41.06263],[-73.34491,41.06204],[-73.34457,41.06151],[-73.34329,41.06148],[-73.34263,41.06063],[-73.34344,41.05949],[-73.34445,41.05986],[-73.34498,41.05979],[-73.34514,41.06009],[-73.3455,41.05921],[-73.34634,41.05869],[-73.34713,41.05854],[-73.348,41.05917],[-73.34845,41.06009],[-73.3487,41.05972],[-73.34869,41.05927],[-73.34712,41.05814],[-73.34516,41.05826],[-73.34312,41.05919],[-73.34217,41.05996],[-73.34164,41.06171],[-73.34173,41.06313],[-73.34191,41.06343],[-73.34257,41.06356]]],[[[-73.37043,41.07164],[-73.37035,41.07141],[-73.37,41.07136],[-73.37028,41.07169],[-73.37024,41.07194],[-73.37,41.07214],[-73.36968,41.07194],[-73.36978,41.07204],[-73.36935,41.07236],[-73.36834,41.07249],[-73.36733,41.07218],[-73.36842,41.07266],[-73.36845,41.07286],[-73.36816,41.07304],[-73.36867,41.07294],[-73.3701,41.07371],[-73.37005,41.07384],[-73.36793,41.07336],[-73.36786,41.07351],[-73.36964,41.07386],[-73.37011,41.07409],[-73.37057,41.07476],[-73.37093,41.07474],[-73.371,41.07469],[-73.37048,41.07171],[-73.37043,41.07164]]],[[[-73.36868,41.06707],[-73.36941,41.06694],[-73.36976,41.06659],[-73.36959,41.06642],[-73.36957,41.06584],[-73.36854,41.06544],[-73.3682,41.06554],[-73.36767,41.06634],[-73.367,41.06681],[-73.36737,41.06696],[-73.36797,41.06687],[-73.36868,41.06707]]],[[[-73.36988,41.04619],[-73.36953,41.04666],[-73.36869,41.04704],[-73.36918,41.04726],[-73.36974,41.04714],[-73.36998,41.04704],[-73.36995,41.04599],[-73.36988,41.04619]]],[[[-73.35828,41.04682],[-73.35819,41.04789],[-73.35876,41.04827],[-73.35852,41.04747],[-73.3586,41.0469],[-73.3589,41.04652],[-73.35859,41.04655],[-73.35828,41.04682]]],[[[-73.35648,41.08383],[-73.3563,41.08356],[-73.35526,41.08288],[-73.35524,41.08313],[-73.35579,41.08371],[-73.35628,41.08381],[-73.35648,41.08383]]],[[[-73.36791,41.05995],[-73.36814,41.06037],[-73.36899,41.06032],[-73.3683,41.0599],[-73.36791,41.05995]]],[[[-73.3712,41.07641],[-73.37062,41.07651],[-73.37116,41.07654],[-73.3713,41.07651],[-73.37127,41.07639],[-73.3712,41.07641]]],[[[-73.34869,41.074],[-73.34893,41.07378],[-73.34864,41.07373],[-73.34869,41.074]]]]}' FROM towns WHERE name = 'Westport';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.66304,41.72254],[-72.69183,41.71883],[-72.70194,41.7178],[-72.71339,41.71704],[-72.71411,41.7097],[-72.715,41.70283],[-72.7059,41.66603],[-72.71307,41.66519],[-72.71161,41.66035],[-72.71152,41.66005],[-72.70787,41.66051],[-72.68818,41.66288],[-72.68847,41.66211],[-72.68326,41.66105],[-72.67814,41.66042],[-72.67478,41.66101],[-72.67292,41.66241],[-72.67215,41.66341],[-72.67076,41.67137],[-72.65461,41.67649],[-72.63051,41.67868],[-72.62215,41.67949],[-72.62299,41.67987],[-72.62524,41.6805],[-72.62778,41.6808],[-72.63175,41.6811],[-72.63459,41.68081],[-72.63807,41.68116],[-72.64082,41.68209],[-72.64164,41.68262],[-72.64277,41.68377],[-72.64341,41.68557],[-72.64355,41.68707],[-72.64336,41.68802],[-72.64281,41.68921],[-72.64149,41.69056],[-72.63677,41.69193],[-72.62775,41.69371],[-72.62537,41.69453],[-72.62366,41.69563],[-72.62302,41.6963],[-72.62249,41.6974],[-72.6224,41.699],[-72.62267,41.70002],[-72.62418,41.70187],[-72.62602,41.70315],[-72.62912,41.70443],[-72.63125,41.70481],[-72.6354,41.70479],[-72.64396,41.70547],[-72.64621,41.70595],[-72.64834,41.7072],[-72.65503,41.71383],[-72.65593,41.71548],[-72.64743,41.71669],[-72.64346,41.71726],[-72.64358,41.71734],[-72.65152,41.71947],[-72.65513,41.72043],[-72.66304,41.72254]]]]}' FROM towns WHERE name = 'Wethersfield';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.22834,41.83347],[-72.22839,41.85045],[-72.229,41.86831],[-72.22902,41.8788],[-72.22843,41.88742],[-72.22954,41.89599],[-72.22958,41.90628],[-72.23106,41.94729],[-72.23088,41.95645],[-72.23087,41.95695],[-72.23675,41.95684],[-72.24656,41.95665],[-72.24889,41.95661],[-72.25122,41.95644],[-72.25046,41.9375],[-72.31301,41.9387],[-72.32011,41.93876],[-72.32043,41.93818],[-72.321,41.93689],[-72.32128,41.93549],[-72.32192,41.93436],[-72.32274,41.93167],[-72.32293,41.92977],[-72.32256,41.92885],[-72.32082,41.92829],[-72.32052,41.92737],[-72.32039,41.92707],[-72.32023,41.92654],[-72.32016,41.92422],[-72.3197,41.92345],[-72.31856,41.9227],[-72.3185,41.92162],[-72.31656,41.92014],[-72.31511,41.91794],[-72.31346,41.91744],[-72.3122,41.91659],[-72.31075,41.91369],[-72.31061,41.91294],[-72.31078,41.91147],[-72.31043,41.90957],[-72.30993,41.90825],[-72.30868,41.90702],[-72.30707,41.90622],[-72.30626,41.90414],[-72.30464,41.90367],[-72.30396,41.90304],[-72.30375,41.90172],[-72.30413,41.90122],[-72.30423,41.89972],[-72.30499,41.89837],[-72.30538,41.89678],[-72.30501,41.89598],[-72.30352,41.8941],[-72.30401,41.8933],[-72.30573,41.89216],[-72.3114,41.89077],[-72.31365,41.88959],[-72.31455,41.88877],[-72.31548,41.88673],[-72.3156,41.88603],[-72.31537,41.88563],[-72.31143,41.8853],[-72.31051,41.88472],[-72.31037,41.88427],[-72.31109,41.88385],[-72.31281,41.8837],[-72.31353,41.88325],[-72.31384,41.88233],[-72.31477,41.88081],[-72.31475,41.88023],[-72.31636,41.87971],[-72.31694,41.87866],[-72.31731,41.87879],[-72.3174,41.87946],[-72.31762,41.87964],[-72.31816,41.87974],[-72.31855,41.87954],[-72.31915,41.87864],[-72.31861,41.87759],[-72.31864,41.87719],[-72.32095,41.8746],[-72.32093,41.8739],[-72.32005,41.87205],[-72.31995,41.87135],[-72.32114,41.8704],[-72.32337,41.87025],[-72.32379,41.86965],[-72.32383,41.86851],[-72.32294,41.86713],[-72.32197,41.8666],[-72.322,41.86578],[-72.32234,41.86503],[-72.3222,41.86451],[-72.32094,41.86231],[-72.31959,41.86103],[-72.31937,41.86011],[-72.31957,41.85936],[-72.31941,41.85893],[-72.3184,41.85761],[-72.31785,41.85641],[-72.31797,41.85386],[-72.31861,41.85189],[-72.31773,41.85096],[-72.31709,41.85064],[-72.31641,41.84964],[-72.31517,41.84909],[-72.31495,41.84879],[-72.31552,41.84774],[-72.3155,41.84716],[-72.3151,41.84654],[-72.31518,41.84611],[-72.31585,41.84582],[-72.31702,41.84594],[-72.31762,41.84574],[-72.31824,41.84507],[-72.31903,41.84467],[-72.32045,41.8435],[-72.32082,41.8428],[-72.32069,41.84165],[-72.31986,41.8402],[-72.31999,41.83955],[-72.32054,41.83853],[-72.31978,41.83656],[-72.31957,41.83538],[-72.3198,41.83413],[-72.32022,41.83343],[-72.32038,41.83311],[-72.3204,41.83306],[-72.32073,41.83174],[-72.32081,41.83126],[-72.30768,41.83114],[-72.23354,41.83023],[-72.22827,41.83022],[-72.22834,41.83347]]]]}' FROM towns WHERE name = 'Willington';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.42369,41.23726],[-73.42869,41.24359],[-73.43092,41.24649],[-73.43955,41.24263],[-73.46464,41.23148],[-73.49371,41.21764],[-73.4924,41.21609],[-73.47286,41.19268],[-73.47523,41.19163],[-73.47061,41.18516],[-73.46101,41.16896],[-73.46084,41.16803],[-73.46094,41.16748],[-73.46128,41.16674],[-73.46234,41.16546],[-73.46244,41.16484],[-73.46295,41.16404],[-73.46284,41.16279],[-73.46223,41.16177],[-73.46349,41.16099],[-73.46322,41.1601],[-73.46257,41.15944],[-73.45983,41.15799],[-73.45926,41.15689],[-73.45871,41.15642],[-73.45696,41.15646],[-73.45623,41.15556],[-73.45522,41.15499],[-73.45531,41.14917],[-73.4562,41.14403],[-73.45605,41.14325],[-73.45524,41.14295],[-73.45507,41.1427],[-73.45443,41.1427],[-73.45416,41.14235],[-73.45354,41.1414],[-73.45375,41.1409],[-73.4535,41.13985],[-73.45247,41.13957],[-73.44957,41.13937],[-73.44796,41.13852],[-73.44668,41.13864],[-73.44419,41.13759],[-73.44236,41.13821],[-73.44207,41.13813],[-73.44159,41.13756],[-73.44016,41.13691],[-73.43913,41.13573],[-73.43843,41.13281],[-73.43804,41.13201],[-73.4378,41.13181],[-73.4212,41.13595],[-73.40819,41.13921],[-73.38466,41.15034],[-73.37838,41.14928],[-73.37657,41.149],[-73.36922,41.16387],[-73.37081,41.16605],[-73.4006,41.20668],[-73.41871,41.23096],[-73.4222,41.23541],[-73.42369,41.23726]]]]}' FROM towns WHERE name = 'Wilton';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.06493,41.88754],[-73.06069,41.90679],[-73.05483,41.93505],[-73.05373,41.93867],[-73.04871,41.96489],[-73.04847,41.96596],[-73.04935,41.96591],[-73.0783,41.96353],[-73.12207,41.9617],[-73.14974,41.96025],[-73.16563,41.95945],[-73.17771,41.91176],[-73.18054,41.90085],[-73.18279,41.89216],[-73.17431,41.89065],[-73.17355,41.89065],[-73.1723,41.89017],[-73.17025,41.88982],[-73.16508,41.88459],[-73.16513,41.88284],[-73.16357,41.88269],[-73.16369,41.88134],[-73.16319,41.87927],[-73.16431,41.87515],[-73.16425,41.87245],[-73.1646,41.87018],[-73.13164,41.87445],[-73.08596,41.88148],[-73.06623,41.88472],[-73.06553,41.88484],[-73.06493,41.88754]]]]}' FROM towns WHERE name = 'Winchester';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.16581,41.74436],[-72.16675,41.74421],[-72.16702,41.74404],[-72.16731,41.74329],[-72.16875,41.74262],[-72.16982,41.74269],[-72.1717,41.74222],[-72.17325,41.74267],[-72.1736,41.74307],[-72.17398,41.7443],[-72.17445,41.74462],[-72.17599,41.74495],[-72.17659,41.74485],[-72.17706,41.7442],[-72.17777,41.7437],[-72.17824,41.74303],[-72.17882,41.74123],[-72.17941,41.74086],[-72.18028,41.74134],[-72.18258,41.74356],[-72.18299,41.74336],[-72.18308,41.74241],[-72.18376,41.74229],[-72.18452,41.74262],[-72.18473,41.74312],[-72.18437,41.74394],[-72.18647,41.74522],[-72.18673,41.74567],[-72.18662,41.74599],[-72.18605,41.74641],[-72.18614,41.74794],[-72.18644,41.74821],[-72.18807,41.74812],[-72.18988,41.74862],[-72.19125,41.74875],[-72.1931,41.7483],[-72.19488,41.7483],[-72.19575,41.748],[-72.19622,41.74755],[-72.19611,41.74653],[-72.19541,41.74588],[-72.1939,41.74498],[-72.19374,41.74433],[-72.19402,41.74393],[-72.19455,41.74368],[-72.19651,41.74358],[-72.19732,41.74289],[-72.19739,41.74214],[-72.19676,41.74066],[-72.19719,41.73941],[-72.19816,41.73852],[-72.20057,41.73712],[-72.20156,41.73615],[-72.20226,41.73435],[-72.20248,41.73285],[-72.20294,41.73253],[-72.20337,41.73166],[-72.20332,41.72908],[-72.20267,41.72761],[-72.20162,41.72691],[-72.20053,41.72688],[-72.20004,41.72663],[-72.19893,41.72538],[-72.19847,41.72455],[-72.19844,41.72353],[-72.19923,41.72256],[-72.20007,41.72218],[-72.2024,41.72199],[-72.20449,41.72264],[-72.20537,41.72359],[-72.2061,41.72369],[-72.20636,41.72347],[-72.20659,41.72159],[-72.20644,41.72127],[-72.20556,41.72124],[-72.20514,41.72097],[-72.20498,41.72039],[-72.20538,41.72017],[-72.21865,41.72001],[-72.25721,41.72027],[-72.25986,41.72025],[-72.25968,41.71998],[-72.25931,41.71965],[-72.25927,41.71915],[-72.26039,41.71778],[-72.26035,41.71731],[-72.26002,41.71688],[-72.25931,41.71648],[-72.25888,41.7159],[-72.25844,41.71593],[-72.25758,41.71645],[-72.25678,41.7165],[-72.25502,41.71602],[-72.25431,41.71532],[-72.25396,41.71497],[-72.25384,41.7149],[-72.25198,41.71122],[-72.25134,41.70868],[-72.2495,41.70807],[-72.24835,41.7069],[-72.24635,41.7058],[-72.24595,41.70562],[-72.24563,41.70542],[-72.24535,41.70494],[-72.24395,41.70387],[-72.24267,41.70322],[-72.23462,41.69731],[-72.2048,41.67659],[-72.19022,41.6655],[-72.16399,41.64825],[-72.16312,41.64773],[-72.16143,41.65124],[-72.15904,41.65606],[-72.12832,41.65217],[-72.12552,41.65181],[-72.12548,41.65306],[-72.1257,41.67409],[-72.12993,41.704],[-72.12115,41.73455],[-72.1201,41.73827],[-72.13005,41.74096],[-72.16426,41.74606],[-72.16452,41.74606],[-72.16581,41.74436]]]]}' FROM towns WHERE name = 'Windham';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.72495,41.92494],[-72.73779,41.9267],[-72.7475,41.8974],[-72.75006,41.88948],[-72.75418,41.87705],[-72.75514,41.87423],[-72.70089,41.86216],[-72.6959,41.80306],[-72.69547,41.79769],[-72.69453,41.79784],[-72.6685,41.80199],[-72.66825,41.80024],[-72.66908,41.8004],[-72.67191,41.79995],[-72.67165,41.79843],[-72.67211,41.79603],[-72.66966,41.79618],[-72.6653,41.80064],[-72.66321,41.80091],[-72.65604,41.80073],[-72.65565,41.80073],[-72.65552,41.80137],[-72.65397,41.81051],[-72.65183,41.81828],[-72.65077,41.8213],[-72.64865,41.82509],[-72.64444,41.82963],[-72.64049,41.8333],[-72.63889,41.83579],[-72.63748,41.83901],[-72.63554,41.8458],[-72.63373,41.85012],[-72.63136,41.86185],[-72.63068,41.86498],[-72.63063,41.8651],[-72.63016,41.86959],[-72.6302,41.87244],[-72.63068,41.87631],[-72.63209,41.88121],[-72.63374,41.89158],[-72.6336,41.89752],[-72.63426,41.90165],[-72.63437,41.90207],[-72.63721,41.90182],[-72.64797,41.90092],[-72.65211,41.8955],[-72.65435,41.8971],[-72.65877,41.89968],[-72.67074,41.90377],[-72.67482,41.90538],[-72.67786,41.90718],[-72.68576,41.91286],[-72.68816,41.91414],[-72.69037,41.91494],[-72.69257,41.91545],[-72.70013,41.91616],[-72.7056,41.91771],[-72.7075,41.91869],[-72.70886,41.92014],[-72.70996,41.92047],[-72.72495,41.92494]]]]}' FROM towns WHERE name = 'Windsor';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.6384,41.94229],[-72.68827,41.94588],[-72.70164,41.94685],[-72.70486,41.93664],[-72.70819,41.92611],[-72.70996,41.92047],[-72.70886,41.92014],[-72.7075,41.91869],[-72.7056,41.91771],[-72.70013,41.91616],[-72.69257,41.91545],[-72.69037,41.91494],[-72.68816,41.91414],[-72.68576,41.91286],[-72.67786,41.90718],[-72.67482,41.90538],[-72.67074,41.90377],[-72.65877,41.89968],[-72.65435,41.8971],[-72.65211,41.8955],[-72.64797,41.90092],[-72.63721,41.90182],[-72.63437,41.90207],[-72.63456,41.90292],[-72.63569,41.90817],[-72.63601,41.91046],[-72.63572,41.91461],[-72.63602,41.91976],[-72.63731,41.92455],[-72.63766,41.92663],[-72.63776,41.92902],[-72.6373,41.93232],[-72.63721,41.93254],[-72.63696,41.93319],[-72.63626,41.93429],[-72.63494,41.93571],[-72.62812,41.94167],[-72.62808,41.94175],[-72.6384,41.94229]]]]}' FROM towns WHERE name = 'Windsor Locks';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.94687,41.54791],[-72.94196,41.57213],[-72.94188,41.57445],[-72.94095,41.57745],[-72.94046,41.58137],[-72.93974,41.58486],[-72.93858,41.58831],[-72.93654,41.59717],[-72.94124,41.6234],[-72.9424,41.63125],[-72.94276,41.6349],[-72.9526,41.63391],[-72.96836,41.63206],[-72.98461,41.63061],[-72.98747,41.63032],[-73.00364,41.62163],[-73.00811,41.61926],[-73.02544,41.61769],[-73.02489,41.61551],[-73.0222,41.60437],[-73.01923,41.59228],[-73.01468,41.57336],[-73.00153,41.56061],[-72.98145,41.54189],[-72.96759,41.5426],[-72.9627,41.54319],[-72.95824,41.54371],[-72.95451,41.54418],[-72.94739,41.54504],[-72.94687,41.54791]]]]}' FROM towns WHERE name = 'Wolcott';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-72.96279,41.3775],[-72.97961,41.37583],[-73.02684,41.3728],[-73.02907,41.37403],[-73.03085,41.37586],[-73.03195,41.37603],[-73.03334,41.37546],[-73.03459,41.37586],[-73.03502,41.37629],[-73.03496,41.37676],[-73.03584,41.37764],[-73.03694,41.37784],[-73.03762,41.37874],[-73.03921,41.37976],[-73.03957,41.37984],[-73.04019,41.37924],[-73.04096,41.37909],[-73.04263,41.37947],[-73.04379,41.3795],[-73.04419,41.37982],[-73.04439,41.38007],[-73.04466,41.37425],[-73.04517,41.36476],[-73.04429,41.3557],[-73.04296,41.34533],[-73.04282,41.34388],[-73.0427,41.34315],[-73.04125,41.33129],[-73.03496,41.31472],[-73.03356,41.3093],[-73.03353,41.3091],[-73.03339,41.3086],[-73.03295,41.30435],[-73.03523,41.29606],[-73.03532,41.29579],[-73.03481,41.29576],[-73.02798,41.2952],[-73.01478,41.29261],[-73.00606,41.2912],[-72.99307,41.29508],[-72.99203,41.29662],[-72.99206,41.2968],[-72.99236,41.30527],[-72.98371,41.31112],[-72.98356,41.31257],[-72.98193,41.31324],[-72.98007,41.31544],[-72.97762,41.32045],[-72.97224,41.32229],[-72.96508,41.32528],[-72.9656,41.32723],[-72.96721,41.32805],[-72.96764,41.3287],[-72.96861,41.33025],[-72.97007,41.33443],[-72.97077,41.3416],[-72.97011,41.34522],[-72.96986,41.34826],[-72.96504,41.35865],[-72.9619,41.36484],[-72.95422,41.37696],[-72.95229,41.37833],[-72.96279,41.3775]]]]}' FROM towns WHERE name = 'Woodbridge';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-73.14739,41.54804],[-73.16079,41.60091],[-73.16129,41.60298],[-73.16995,41.60235],[-73.25274,41.59513],[-73.25667,41.59476],[-73.2654,41.59395],[-73.26935,41.59356],[-73.27061,41.59341],[-73.27043,41.59238],[-73.26581,41.5657],[-73.26448,41.55966],[-73.26358,41.55221],[-73.25908,41.52563],[-73.25875,41.52176],[-73.25666,41.51064],[-73.25547,41.50232],[-73.2549,41.49958],[-73.24988,41.50014],[-73.24327,41.50076],[-73.20438,41.49613],[-73.15829,41.50163],[-73.1557,41.5019],[-73.15747,41.50929],[-73.1663,41.54575],[-73.15414,41.54625],[-73.14695,41.54654],[-73.14739,41.54804]]]]}' FROM towns WHERE name = 'Woodbury';
+INSERT INTO town_boundaries (town_id, geojson) SELECT id, '{"type":"MultiPolygon","coordinates":[[[[-71.98623,42.02609],[-72.00426,42.02662],[-72.08841,42.02749],[-72.11703,42.02874],[-72.11883,42.02881],[-72.11674,41.99572],[-72.11624,41.99197],[-72.11562,41.9842],[-72.11463,41.97004],[-72.11448,41.96427],[-72.11376,41.9572],[-72.11376,41.95705],[-72.1131,41.95705],[-72.11026,41.91194],[-72.10297,41.91198],[-72.0812,41.91155],[-72.06526,41.9118],[-72.0576,41.91176],[-72.0568,41.91176],[-72.02231,41.91173],[-71.94759,41.91347],[-71.94723,41.91349],[-71.94623,41.91351],[-71.9405,41.91371],[-71.94567,41.92802],[-71.94586,41.92857],[-71.94594,41.92885],[-71.94645,41.93057],[-71.95114,41.94267],[-71.96096,41.97003],[-71.96987,42.00107],[-71.97727,42.01998],[-71.97985,42.02591],[-71.98623,42.02609]]]]}' FROM towns WHERE name = 'Woodstock';
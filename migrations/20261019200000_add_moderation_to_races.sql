@@ -0,0 +1,18 @@
+-- Races added by members wait for Royalty or an Admin. Everything already in
+-- the table (and the nightly import) is published straight away.
+ALTER TABLE races ADD COLUMN status TEXT NOT NULL DEFAULT 'approved'; -- pending, approved, rejected or merged
+ALTER TABLE races ADD COLUMN submitted_by INTEGER REFERENCES users(id) ON DELETE SET NULL;
+ALTER TABLE races ADD COLUMN reviewed_by INTEGER REFERENCES users(id) ON DELETE SET NULL;
+ALTER TABLE races ADD COLUMN reviewed_at DATETIME;
+ALTER TABLE races ADD COLUMN rejection_reason TEXT;
+ALTER TABLE races ADD COLUMN merged_into_id INTEGER REFERENCES races(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_races_status ON races(status);
+CREATE INDEX idx_races_submitted_by ON races(submitted_by);
+
+-- Pick up the new columns
+DROP VIEW races_view;
+CREATE VIEW races_view AS
+SELECT r.*, t.name AS town, t.county, t.is_elusive
+FROM races r
+LEFT JOIN towns_view t ON t.id = r.town_id;
@@ -0,0 +1,15 @@
+-- Removed races are kept so members don't lose their results for them
+ALTER TABLE races ADD COLUMN deleted_at DATETIME;
+ALTER TABLE races ADD COLUMN deleted_by INTEGER REFERENCES users(id) ON DELETE SET NULL;
+
+-- Admins can fix a name's case (e.g. "Oak Leaf 5K"), which is still the same
+-- race as the importer's "oak leaf 5k".
+CREATE UNIQUE INDEX idx_races_unique_name
+ON races(town_id, name COLLATE NOCASE, miles, start_at);
+
+-- Pick up the new columns
+DROP VIEW races_view;
+CREATE VIEW races_view AS
+SELECT r.*, t.name AS town, t.county, t.is_elusive
+FROM races r
+LEFT JOIN towns_view t ON t.id = r.town_id;
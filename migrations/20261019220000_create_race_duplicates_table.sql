@@ -0,0 +1,26 @@
+-- Races that look like the same race, waiting on an admin to merge or dismiss
+CREATE TABLE race_duplicates (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    race_id INTEGER NOT NULL REFERENCES races(id) ON DELETE CASCADE,
+    duplicate_race_id INTEGER NOT NULL REFERENCES races(id) ON DELETE CASCADE,
+    score REAL NOT NULL, -- 0 to 1, how alike the names and distances are
+    status TEXT NOT NULL DEFAULT 'pending', -- pending, accepted or dismissed
+    reviewed_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+    reviewed_at DATETIME,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(race_id, duplicate_race_id),
+    CHECK (race_id < duplicate_race_id)
+);
+
+CREATE INDEX idx_race_duplicates_status ON race_duplicates(status);
+
+-- Update updated_at when there are changes
+CREATE TRIGGER set_race_duplicates_updated_at
+AFTER UPDATE ON race_duplicates
+FOR EACH ROW
+BEGIN
+    UPDATE race_duplicates
+    SET updated_at = CURRENT_TIMESTAMP
+    WHERE id = NEW.id;
+END;
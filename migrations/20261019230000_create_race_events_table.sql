@@ -0,0 +1,57 @@
+-- A race day in a town, e.g. the Turkey Trot, whose distances (5K, 10K...)
+-- are each a row in races with their own start time.
+CREATE TABLE race_events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    town_id INTEGER NOT NULL REFERENCES towns(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    event_date DATE NOT NULL,
+    race_url TEXT,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE UNIQUE INDEX idx_race_events_unique_name
+ON race_events(town_id, name COLLATE NOCASE, event_date);
+
+-- Update updated_at when there are changes
+CREATE TRIGGER set_race_events_updated_at
+AFTER UPDATE ON race_events
+FOR EACH ROW
+BEGIN
+    UPDATE race_events
+    SET updated_at = CURRENT_TIMESTAMP
+    WHERE id = NEW.id;
+END;
+
+ALTER TABLE races ADD COLUMN event_id INTEGER REFERENCES race_events(id) ON DELETE SET NULL;
+CREATE INDEX idx_races_event_id ON races(event_id);
+
+-- The importer split "5K, 10K" into separate races with the same name, town
+-- and day, so those become one event.
+INSERT INTO race_events (town_id, name, event_date, race_url)
+SELECT town_id, MIN(name), DATE(start_at), MAX(race_url)
+FROM races
+GROUP BY town_id, LOWER(name), DATE(start_at);
+
+UPDATE races
+SET event_id = (
+    SELECT e.id FROM race_events e
+    WHERE e.town_id = races.town_id
+    AND e.name = races.name COLLATE NOCASE
+    AND e.event_date = DATE(races.start_at)
+);
+
+-- Pick up the new column
+DROP VIEW races_view;
+CREATE VIEW races_view AS
+SELECT r.*, t.name AS town, t.county, t.is_elusive
+FROM races r
+LEFT JOIN towns_view t ON t.id = r.town_id;
+
+-- Events with at least one distance members can see
+CREATE VIEW race_events_view AS
+SELECT e.*, t.name AS town, t.county, t.is_elusive, MIN(r.start_at) AS start_at
+FROM race_events e
+JOIN races r ON r.event_id = e.id AND r.status = 'approved' AND r.deleted_at IS NULL
+LEFT JOIN towns_view t ON t.id = e.town_id
+GROUP BY e.id;
@@ -0,0 +1,163 @@
+-- Other names a town goes by, usually its villages (e.g. Storrs is in
+-- Mansfield). A village can span towns, like Mystic.
+CREATE TABLE town_aliases (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    town_id INTEGER NOT NULL REFERENCES towns(id) ON DELETE CASCADE,
+    alias TEXT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(town_id, alias)
+);
+
+INSERT INTO town_aliases (town_id, alias)
+SELECT t.id, a.alias
+FROM (
+    SELECT 'Mansfield' AS town, 'Storrs' AS alias
+    UNION ALL SELECT 'Groton', 'Mystic'
+    UNION ALL SELECT 'Stonington', 'Mystic'
+    UNION ALL SELECT 'Groton', 'Noank'
+    UNION ALL SELECT 'Stonington', 'Pawcatuck'
+    UNION ALL SELECT 'East Lyme', 'Niantic'
+    UNION ALL SELECT 'Waterford', 'Quaker Hill'
+    UNION ALL SELECT 'Montville', 'Uncasville'
+    UNION ALL SELECT 'Ledyard', 'Gales Ferry'
+    UNION ALL SELECT 'Norwich', 'Taftville'
+    UNION ALL SELECT 'Griswold', 'Jewett City'
+    UNION ALL SELECT 'Killingly', 'Danielson'
+    UNION ALL SELECT 'Windham', 'Willimantic'
+    UNION ALL SELECT 'Vernon', 'Rockville'
+    UNION ALL SELECT 'Stafford', 'Stafford Springs'
+    UNION ALL SELECT 'East Windsor', 'Broad Brook'
+    UNION ALL SELECT 'East Haddam', 'Moodus'
+    UNION ALL SELECT 'Haddam', 'Higganum'
+    UNION ALL SELECT 'Essex', 'Ivoryton'
+    UNION ALL SELECT 'Farmington', 'Unionville'
+    UNION ALL SELECT 'Canton', 'Collinsville'
+    UNION ALL SELECT 'Simsbury', 'Weatogue'
+    UNION ALL SELECT 'Simsbury', 'Tariffville'
+    UNION ALL SELECT 'Plymouth', 'Terryville'
+    UNION ALL SELECT 'Watertown', 'Oakville'
+    UNION ALL SELECT 'Wallingford', 'Yalesville'
+    UNION ALL SELECT 'Newtown', 'Sandy Hook'
+    UNION ALL SELECT 'Salisbury', 'Lakeville'
+    UNION ALL SELECT 'Canaan', 'Falls Village'
+    UNION ALL SELECT 'Greenwich', 'Cos Cob'
+    UNION ALL SELECT 'Greenwich', 'Riverside'
+    UNION ALL SELECT 'Greenwich', 'Old Greenwich'
+) a
+JOIN towns t ON t.name = a.town;
+
+-- One row per race event, town, county and member. `title` is what it's
+-- called, and `subtitle` is anything else it should be found by.
+CREATE VIRTUAL TABLE search_index USING fts5(
+    kind UNINDEXED,
+    item_id UNINDEXED,
+    title,
+    subtitle,
+    tokenize = 'unicode61 remove_diacritics 2',
+    prefix = '2 3'
+);
+
+-- Every word in the index, for suggesting a spelling when nothing matches
+CREATE VIRTUAL TABLE search_vocabulary USING fts5vocab(search_index, 'row');
+
+INSERT INTO search_index (kind, item_id, title, subtitle)
+SELECT 'race', e.id, e.name, t.name || ' ' || t.county
+FROM race_events e
+JOIN towns_view t ON t.id = e.town_id;
+
+INSERT INTO search_index (kind, item_id, title, subtitle)
+SELECT 'town', t.id, t.name, t.county || ' ' || COALESCE(
+    (SELECT group_concat(alias, ' ') FROM town_aliases WHERE town_id = t.id), ''
+)
+FROM towns_view t;
+
+INSERT INTO search_index (kind, item_id, title, subtitle)
+SELECT 'county', c.id, c.name, ''
+FROM counties c;
+
+INSERT INTO search_index (kind, item_id, title, subtitle)
+SELECT 'member', u.id, u.full_name, COALESCE(t.name, '')
+FROM users u
+LEFT JOIN towns t ON t.id = u.hometown_id;
+
+-- Keep races in sync
+CREATE TRIGGER search_index_race_events_insert
+AFTER INSERT ON race_events
+BEGIN
+    INSERT INTO search_index (kind, item_id, title, subtitle)
+    SELECT 'race', NEW.id, NEW.name, t.name || ' ' || t.county
+    FROM towns_view t WHERE t.id = NEW.town_id;
+END;
+
+CREATE TRIGGER search_index_race_events_update
+AFTER UPDATE OF name, town_id ON race_events
+BEGIN
+    DELETE FROM search_index WHERE kind = 'race' AND item_id = OLD.id;
+    INSERT INTO search_index (kind, item_id, title, subtitle)
+    SELECT 'race', NEW.id, NEW.name, t.name || ' ' || t.county
+    FROM towns_view t WHERE t.id = NEW.town_id;
+END;
+
+CREATE TRIGGER search_index_race_events_delete
+AFTER DELETE ON race_events
+BEGIN
+    DELETE FROM search_index WHERE kind = 'race' AND item_id = OLD.id;
+END;
+
+-- Keep towns in sync, including their aliases
+CREATE TRIGGER search_index_towns_update
+AFTER UPDATE OF name, county_id ON towns
+BEGIN
+    DELETE FROM search_index WHERE kind = 'town' AND item_id = OLD.id;
+    INSERT INTO search_index (kind, item_id, title, subtitle)
+    SELECT 'town', t.id, t.name, t.county || ' ' || COALESCE(
+        (SELECT group_concat(alias, ' ') FROM town_aliases WHERE town_id = t.id), ''
+    )
+    FROM towns_view t WHERE t.id = NEW.id;
+END;
+
+CREATE TRIGGER search_index_town_aliases_insert
+AFTER INSERT ON town_aliases
+BEGIN
+    DELETE FROM search_index WHERE kind = 'town' AND item_id = NEW.town_id;
+    INSERT INTO search_index (kind, item_id, title, subtitle)
+    SELECT 'town', t.id, t.name, t.county || ' ' || COALESCE(
+        (SELECT group_concat(alias, ' ') FROM town_aliases WHERE town_id = t.id), ''
+    )
+    FROM towns_view t WHERE t.id = NEW.town_id;
+END;
+
+CREATE TRIGGER search_index_town_aliases_delete
+AFTER DELETE ON town_aliases
+BEGIN
+    DELETE FROM search_index WHERE kind = 'town' AND item_id = OLD.town_id;
+    INSERT INTO search_index (kind, item_id, title, subtitle)
+    SELECT 'town', t.id, t.name, t.county || ' ' || COALESCE(
+        (SELECT group_concat(alias, ' ') FROM town_aliases WHERE town_id = t.id), ''
+    )
+    FROM towns_view t WHERE t.id = OLD.town_id;
+END;
+
+-- Keep members in sync
+CREATE TRIGGER search_index_users_insert
+AFTER INSERT ON users
+BEGIN
+    INSERT INTO search_index (kind, item_id, title, subtitle)
+    SELECT 'member', NEW.id, NEW.full_name, COALESCE(t.name, '')
+    FROM (SELECT 1) LEFT JOIN towns t ON t.id = NEW.hometown_id;
+END;
+
+CREATE TRIGGER search_index_users_update
+AFTER UPDATE OF full_name, hometown_id ON users
+BEGIN
+    DELETE FROM search_index WHERE kind = 'member' AND item_id = OLD.id;
+    INSERT INTO search_index (kind, item_id, title, subtitle)
+    SELECT 'member', NEW.id, NEW.full_name, COALESCE(t.name, '')
+    FROM (SELECT 1) LEFT JOIN towns t ON t.id = NEW.hometown_id;
+END;
+
+CREATE TRIGGER search_index_users_delete
+AFTER DELETE ON users
+BEGIN
+    DELETE FROM search_index WHERE kind = 'member' AND item_id = OLD.id;
+END;
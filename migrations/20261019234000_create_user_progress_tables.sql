@@ -0,0 +1,52 @@
+-- Each member's progress, kept up to date as towns are completed so member
+-- lists and leaderboards don't count completed_towns for every row
+CREATE TABLE user_progress (
+    user_id INTEGER PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    completed_count INTEGER NOT NULL DEFAULT 0,
+    elusive_count INTEGER NOT NULL DEFAULT 0,
+    last_completed_at DATETIME,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Update updated_at when there are changes
+CREATE TRIGGER set_user_progress_updated_at
+AFTER UPDATE ON user_progress
+FOR EACH ROW
+BEGIN
+    UPDATE user_progress
+    SET updated_at = CURRENT_TIMESTAMP
+    WHERE user_id = NEW.user_id;
+END;
+
+CREATE TABLE user_county_progress (
+    user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    county_id INTEGER NOT NULL REFERENCES counties(id) ON DELETE CASCADE,
+    completed_count INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (user_id, county_id)
+);
+
+INSERT INTO user_progress (user_id, completed_count, elusive_count, last_completed_at)
+SELECT ct.user_id, COUNT(*), SUM(t.is_elusive), MAX(ct.created_at)
+FROM completed_towns ct
+JOIN towns t ON t.id = ct.town_id
+GROUP BY ct.user_id;
+
+INSERT INTO user_county_progress (user_id, county_id, completed_count)
+SELECT ct.user_id, t.county_id, COUNT(*)
+FROM completed_towns ct
+JOIN towns t ON t.id = ct.town_id
+GROUP BY ct.user_id, t.county_id;
+
+-- Read the count from user_progress instead of counting every time
+DROP VIEW IF EXISTS users_view;
+CREATE VIEW users_view AS
+SELECT
+	u.*,
+	t.name AS hometown,
+	t.county_id AS hometown_county_id,
+	t.county AS hometown_county,
+	COALESCE(p.completed_count, 0) AS completed_towns_count
+FROM users u
+LEFT JOIN towns_view t ON u.hometown_id = t.id
+LEFT JOIN user_progress p ON p.user_id = u.id;
@@ -45,6 +45,28 @@ function attachDropdown(anchorElement) {
 	dropdown.style.width = anchorElement.getBoundingClientRect().width + "px";
 }
 
+/**
+ * Copies the last distance on the "Add Race" form so a race can have a 5K
+ * and a 10K. The start time is kept since it's usually the same day.
+ */
+function addDistance(button) {
+	const rows = button.closest("form").querySelectorAll(".distance-row");
+	const last = rows[rows.length - 1];
+	const row = last.cloneNode(true);
+
+	row.querySelectorAll("[id]").forEach((el) => el.removeAttribute("id"));
+	row.querySelectorAll("label").forEach((label) => label.removeAttribute("for"));
+	row.querySelector("[name=distance_val]").value = "";
+	row.querySelector("[name=distance_unit]").value = last.querySelector("[name=distance_unit]").value;
+	row.querySelector("[name=start_at]").value = last.querySelector("[name=start_at]").value;
+
+	last.after(row);
+}
+
 function goTo(url) {
 	window.location.href = url;
 }
+
+document.addEventListener("rateLimited", function (evt) {
+	alert(evt.detail.message);
+});
@@ -0,0 +1,67 @@
+// Races are often somewhere without signal. A town submitted offline is handed
+// to the service worker, which sends it once the phone is back online.
+
+if ("serviceWorker" in navigator) {
+	navigator.serviceWorker.register("/service-worker.js");
+
+	window.addEventListener("online", function () {
+		sendToWorker({ type: "send-submissions" });
+	});
+}
+
+document.addEventListener("htmx:beforeRequest", function (evt) {
+	if (evt.detail.elt.id === "submit_town_form" && !navigator.onLine) {
+		evt.preventDefault();
+		queueSubmission(evt.detail.elt);
+	}
+});
+
+// A saved copy of the form may be from a day or two ago.
+document.addEventListener("htmx:afterSwap", function () {
+	const startAt = document.getElementById("start_at");
+	if (startAt && !navigator.onLine) {
+		const today = new Date();
+		today.setMinutes(today.getMinutes() - today.getTimezoneOffset());
+		startAt.max = today.toISOString().slice(0, 10);
+	}
+});
+
+document.addEventListener("htmx:sendError", function (evt) {
+	if (evt.detail.elt.id === "submit_town_form") {
+		queueSubmission(evt.detail.elt);
+	}
+});
+
+async function sendToWorker(message) {
+	const registration = await navigator.serviceWorker.ready;
+	registration.active.postMessage(message);
+}
+
+async function queueSubmission(form) {
+	if (!("serviceWorker" in navigator) || !form.reportValidity()) {
+		return;
+	}
+
+	const data = new FormData(form);
+	await sendToWorker({
+		type: "queue-submission",
+		// Sent with every retry, so the town is only ever submitted once.
+		key: crypto.randomUUID(),
+		submission: {
+			town_id: Number(data.get("town_id")),
+			race_name: data.get("race_name"),
+			race_id: Number(data.get("race_id")),
+			distance_val: Number(data.get("distance_val")),
+			distance_unit: data.get("distance_unit"),
+			start_at: data.get("start_at"),
+			notes: data.get("notes") || null,
+		},
+	});
+
+	const message = document.createElement("div");
+	message.className = "alert";
+	message.textContent = "No signal, so we saved it. It'll be submitted when you're back online.";
+	form.prepend(message);
+	document.querySelector('[form="submit_town_form"]').disabled = true;
+	setTimeout(closeModal, 3000);
+}
@@ -0,0 +1,88 @@
+// Subscribes this device to Web Push through the service worker.
+
+document.addEventListener("DOMContentLoaded", function () {
+	loadPushToggle();
+});
+
+document.addEventListener("htmx:afterSwap", function () {
+	loadPushToggle();
+});
+
+function pushSupported() {
+	return "serviceWorker" in navigator && "PushManager" in window;
+}
+
+async function pushRegistration() {
+	return navigator.serviceWorker.register("/service-worker.js");
+}
+
+async function loadPushToggle() {
+	const button = document.getElementById("push_toggle");
+	if (!button) {
+		return;
+	}
+
+	if (!pushSupported()) {
+		button.disabled = true;
+		button.textContent = "Not supported on this browser";
+		return;
+	}
+
+	const registration = await pushRegistration();
+	const subscription = await registration.pushManager.getSubscription();
+	button.textContent = subscription ? "Turn off for this device" : "Turn on for this device";
+}
+
+async function togglePush(button) {
+	const status = document.getElementById("push_status");
+	button.disabled = true;
+
+	try {
+		const registration = await pushRegistration();
+		const subscription = await registration.pushManager.getSubscription();
+
+		if (subscription) {
+			await fetch("/push/subscriptions", {
+				method: "DELETE",
+				headers: { "Content-Type": "application/json" },
+				body: JSON.stringify({ endpoint: subscription.endpoint }),
+			});
+			await subscription.unsubscribe();
+			status.textContent = "Turned off for this device";
+		} else {
+			if ((await Notification.requestPermission()) !== "granted") {
+				status.textContent = "Notifications are blocked for this site";
+				return;
+			}
+
+			const created = await registration.pushManager.subscribe({
+				userVisibleOnly: true,
+				applicationServerKey: base64UrlToBytes(button.dataset.publicKey),
+			});
+			const response = await fetch("/push/subscriptions", {
+				method: "POST",
+				headers: { "Content-Type": "application/json" },
+				body: JSON.stringify(created.toJSON()),
+			});
+
+			if (!response.ok) {
+				await created.unsubscribe();
+				status.textContent = await response.text();
+				return;
+			}
+			status.textContent = "Turned on for this device";
+		}
+	} catch (e) {
+		status.textContent = "Unable to change push notifications";
+	} finally {
+		button.disabled = false;
+		loadPushToggle();
+	}
+}
+
+function base64UrlToBytes(value) {
+	const base64 = (value + "=".repeat((4 - (value.length % 4)) % 4))
+		.replace(/-/g, "+")
+		.replace(/_/g, "/");
+	return Uint8Array.from(atob(base64), (c) => c.charCodeAt(0));
+}
@@ -1,5 +1,11 @@
-const CACHE_NAME = "pwa-v1";
+const CACHE_NAME = "pwa-v2";
 const ASSETS_TO_CACHE = ["/", "/assets/favicon.svg"];
+// Kept up to date while online, so a town can be submitted without signal.
+const OFFLINE_PAGES = ["/", "/dashboard", "/submit-town"];
+
+const QUEUE_DB = "ctrunner";
+const QUEUE_STORE = "submissions";
+const SYNC_TAG = "submit-towns";
 
 self.addEventListener("install", (event) => {
     event.waitUntil(
@@ -7,10 +13,171 @@ self.addEventListener("install", (event) => {
     );
 });
 
+self.addEventListener("activate", (event) => {
+    event.waitUntil(
+        caches
+            .keys()
+            .then((names) =>
+                Promise.all(
+                    names
+                        .filter((name) => name !== CACHE_NAME)
+                        .map((name) => caches.delete(name)),
+                ),
+            ),
+    );
+});
+
 self.addEventListener("fetch", (event) => {
+    const url = new URL(event.request.url);
+    if (url.origin !== self.location.origin) {
+        return;
+    }
+
+    // Nothing of the signed in member's should stay behind after signing out.
+    if (url.pathname === "/auth/signout") {
+        event.waitUntil(caches.delete(CACHE_NAME));
+        return;
+    }
+
+    const cacheable =
+        OFFLINE_PAGES.includes(url.pathname) || url.pathname.startsWith("/assets/");
+    if (event.request.method !== "GET" || !cacheable) {
+        return;
+    }
+
+    // Network first, so pages are only ever stale when there's no signal.
     event.respondWith(
-        caches
-            .match(event.request)
-            .then((response) => response || fetch(event.request)),
+        fetch(event.request)
+            .then((response) => {
+                if (response.ok && !response.redirected) {
+                    const copy = response.clone();
+                    caches.open(CACHE_NAME).then((cache) => cache.put(event.request, copy));
+                }
+                return response;
+            })
+            .catch(() => caches.match(event.request)),
+    );
+});
+
+// Payloads are `{ title, body, url, tag }`, see `PushMessage`.
+self.addEventListener("push", (event) => {
+    const message = event.data ? event.data.json() : {};
+
+    event.waitUntil(
+        self.registration.showNotification(message.title || "CTRunner", {
+            body: message.body,
+            tag: message.tag,
+            icon: "/assets/favicon.svg",
+            data: { url: message.url || "/" },
+        }),
+    );
+});
+
+self.addEventListener("notificationclick", (event) => {
+    event.notification.close();
+
+    event.waitUntil(
+        self.clients
+            .matchAll({ type: "window", includeUncontrolled: true })
+            .then((windows) => {
+                const url = new URL(event.notification.data.url, self.location.origin);
+                const open = windows.find((window) => window.url === url.href);
+                return open ? open.focus() : self.clients.openWindow(url.href);
+            }),
     );
-});
\ No newline at end of file
+});
+
+// Town submissions made without signal, see `offline-submit.js`.
+self.addEventListener("message", (event) => {
+    if (event.data.type === "queue-submission") {
+        event.waitUntil(
+            queueSubmission(event.data.key, event.data.submission).then(() =>
+                self.registration.sync
+                    ? self.registration.sync.register(SYNC_TAG)
+                    : sendQueuedSubmissions().catch(() => {}),
+            ),
+        );
+    } else if (event.data.type === "send-submissions") {
+        event.waitUntil(sendQueuedSubmissions().catch(() => {}));
+    }
+});
+
+self.addEventListener("sync", (event) => {
+    if (event.tag === SYNC_TAG) {
+        event.waitUntil(sendQueuedSubmissions());
+    }
+});
+
+function openQueue() {
+    return new Promise((resolve, reject) => {
+        const request = indexedDB.open(QUEUE_DB, 1);
+        request.onupgradeneeded = () =>
+            request.result.createObjectStore(QUEUE_STORE, { keyPath: "key" });
+        request.onsuccess = () => resolve(request.result);
+        request.onerror = () => reject(request.error);
+    });
+}
+
+async function withQueue(mode, work) {
+    const db = await openQueue();
+    return new Promise((resolve, reject) => {
+        const transaction = db.transaction(QUEUE_STORE, mode);
+        const request = work(transaction.objectStore(QUEUE_STORE));
+        transaction.oncomplete = () => resolve(request.result);
+        transaction.onerror = () => reject(transaction.error);
+    });
+}
+
+function queueSubmission(key, submission) {
+    return withQueue("readwrite", (store) => store.put({ key, submission }));
+}
+
+// Sends every queued submission with its idempotency key, so a retry after a
+// dropped response can never submit the same town twice. Throwing tells
+// background sync to try again later.
+async function sendQueuedSubmissions() {
+    const queued = await withQueue("readonly", (store) => store.getAll());
+    let retryLater = false;
+
+    for (const { key, submission } of queued) {
+        let response;
+        try {
+            response = await fetch("/api/v1/me/submissions", {
+                method: "POST",
+                credentials: "same-origin",
+                headers: { "Content-Type": "application/json", "Idempotency-Key": key },
+                body: JSON.stringify(submission),
+            });
+        } catch (e) {
+            retryLater = true;
+            continue;
+        }
+
+        // Signed out, still in progress, throttled or the society's form is down.
+        if ([401, 409, 429].includes(response.status) || response.status >= 500) {
+            retryLater = true;
+            continue;
+        }
+
+        await withQueue("readwrite", (store) => store.delete(key));
+        if (!response.ok) {
+            const body = await response.json().catch(() => ({}));
+            await notifyFailed(body.error ? body.error.message : response.statusText);
+        }
+    }
+
+    if (retryLater) {
+        throw new Error("Some submissions are still waiting to be sent");
+    }
+}
+
+function notifyFailed(message) {
+    if (Notification.permission !== "granted") {
+        return Promise.resolve();
+    }
+    return self.registration.showNotification("A town you submitted didn't go through", {
+        body: message,
+        icon: "/assets/favicon.svg",
+        data: { url: "/dashboard" },
+    });
+}
@@ -0,0 +1,142 @@
+use chrono::{Duration, Utc};
+use log::warn;
+use tokio::sync::OnceCell;
+
+use crate::{
+    DbConnection,
+    domain::{
+        activity::{Activity, ActivityMatch},
+        boundary::TownLocator,
+    },
+    infrastructure::{
+        db::{RaceRepository, StravaRepository, TownRepository},
+        strava::StravaClient,
+    },
+};
+
+/// How far back to look through a member's Strava activities.
+const STRAVA_LOOKBACK_DAYS: i64 = 30;
+
+pub struct ActivityService {
+    race_repository: RaceRepository,
+    town_repository: TownRepository,
+    strava_repository: StravaRepository,
+    strava: StravaClient,
+    locator: OnceCell<TownLocator>,
+}
+
+impl ActivityService {
+    pub fn new(db: &DbConnection) -> Self {
+        Self {
+            race_repository: RaceRepository::new(db),
+            town_repository: TownRepository::new(db),
+            strava_repository: StravaRepository::new(db),
+            strava: StravaClient::from_env(),
+            locator: OnceCell::new(),
+        }
+    }
+
+    pub async fn match_file(&self, filename: &str, bytes: &[u8]) -> Result<ActivityMatch, String> {
+        let activity = Activity::parse(filename, bytes)?;
+        Ok(self.match_activities(vec![activity]).await.remove(0))
+    }
+
+    /// Town boundaries never change, so they're only loaded the once.
+    async fn locator(&self) -> &TownLocator {
+        self.locator
+            .get_or_init(|| async {
+                TownLocator::new(
+                    self.town_repository.find_all().await,
+                    &self.town_repository.find_all_boundaries().await,
+                )
+            })
+            .await
+    }
+
+    /// Matches each activity against the town boundaries and that day's races.
+    async fn match_activities(&self, activities: Vec<Activity>) -> Vec<ActivityMatch> {
+        let locator = self.locator().await;
+
+        let mut matches = Vec::with_capacity(activities.len());
+        for activity in activities {
+            let races = self
+                .race_repository
+                .find_on_date(activity.start_at.date())
+                .await;
+            matches.push(ActivityMatch::new(activity, locator, &races));
+        }
+
+        matches
+    }
+
+    pub fn is_strava_enabled(&self) -> bool {
+        self.strava.is_enabled()
+    }
+
+    pub fn strava_authorize_url(&self, state: &str) -> Option<String> {
+        self.strava.authorize_url(state)
+    }
+
+    pub async fn is_strava_connected(&self, user_id: i64) -> bool {
+        self.strava_repository.find(user_id).await.is_some()
+    }
+
+    pub async fn connect_strava(&self, user_id: i64, code: &str) -> Result<(), String> {
+        let token = self.strava.exchange_code(code).await?;
+        let athlete_id = token
+            .athlete
+            .as_ref()
+            .map(|athlete| athlete.id)
+            .ok_or("Strava didn't say who you are")?;
+
+        self.strava_repository
+            .save(user_id, athlete_id, &token)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Forgets the tokens even if Strava can't be told, since the member can
+    /// always revoke us from their Strava settings.
+    pub async fn disconnect_strava(&self, user_id: i64) -> Result<(), String> {
+        if let Some(connection) = self.strava_repository.find(user_id).await {
+            let _ = self
+                .strava
+                .deauthorize(&connection.access_token)
+                .await
+                .inspect_err(|e| warn!("Strava deauthorize for user ({user_id}): {e}"));
+        }
+
+        self.strava_repository.delete(user_id).await
+    }
+
+    /// The member's recent Strava activities, newest first. Activities without
+    /// a start point (e.g. treadmill runs) are left out.
+    pub async fn match_strava(&self, user_id: i64) -> Result<Vec<ActivityMatch>, String> {
+        let mut connection = self
+            .strava_repository
+            .find(user_id)
+            .await
+            .ok_or("Connect Strava first")?;
+
+        if connection.is_expired() {
+            let token = self.strava.refresh(&connection.refresh_token).await?;
+            connection = self
+                .strava_repository
+                .save(user_id, connection.athlete_id, &token)
+                .await?;
+        }
+
+        let after = (Utc::now() - Duration::days(STRAVA_LOOKBACK_DAYS)).timestamp();
+        let mut activities: Vec<Activity> = self
+            .strava
+            .activities(&connection.access_token, after)
+            .await?
+            .into_iter()
+            .filter_map(|activity| Activity::try_from(activity).ok())
+            .collect();
+        activities.sort_by_key(|activity| std::cmp::Reverse(activity.start_at));
+
+        Ok(self.match_activities(activities).await)
+    }
+}
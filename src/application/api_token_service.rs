@@ -0,0 +1,67 @@
+use crate::{
+    DbConnection,
+    domain::{
+        api_token::{ApiToken, NewApiToken, NewApiTokenForm, TokenScope},
+        user::UserView,
+    },
+    infrastructure::db::{ApiTokenRepository, UserRepository},
+    util::validation::Validate,
+};
+
+pub struct ApiTokenService {
+    api_token_repository: ApiTokenRepository,
+    user_repository: UserRepository,
+}
+
+impl ApiTokenService {
+    pub fn new(db: &DbConnection) -> Self {
+        Self {
+            api_token_repository: ApiTokenRepository::new(db),
+            user_repository: UserRepository::new(db),
+        }
+    }
+
+    pub async fn find_for_user(&self, user_id: i64) -> Vec<ApiToken> {
+        self.api_token_repository.find_for_user(user_id).await
+    }
+
+    /// Creates a token and returns it with its secret, which is never stored.
+    pub async fn create(
+        &self,
+        user: &UserView,
+        form: NewApiTokenForm,
+    ) -> Result<(ApiToken, String), String> {
+        form.validate()?;
+
+        if form.scopes.contains(&TokenScope::Admin) && !user.is_admin() {
+            return Err("Only admins can create admin tokens".to_string());
+        }
+
+        let (token, secret) = NewApiToken::generate(user.id, form);
+        let token = self.api_token_repository.create(&token).await?;
+
+        Ok((token, secret))
+    }
+
+    pub async fn revoke(&self, user_id: i64, token_id: i64) -> Result<(), String> {
+        self.api_token_repository.revoke(user_id, token_id).await
+    }
+
+    /// Finds the owner of an active token and records that it was used.
+    pub async fn authenticate(&self, secret: &str, ip: &str) -> Option<(UserView, ApiToken)> {
+        let token = self
+            .api_token_repository
+            .find_active_by_hash(&ApiToken::hash(secret))
+            .await?;
+        let user = self
+            .user_repository
+            .find_by_id(token.user_id)
+            .await
+            .ok()
+            .filter(|user| !user.locked)?;
+
+        let _ = self.api_token_repository.touch(token.id, ip).await;
+
+        Some((user, token))
+    }
+}
@@ -0,0 +1,37 @@
+use log::error;
+
+use crate::{
+    DbConnection,
+    domain::audit::{AuditLog, AuditLogSearchParams, NewAuditLog},
+    infrastructure::db::AuditRepository,
+    util::pagination::{CursorPage, CursorPagination},
+};
+
+pub struct AuditService {
+    audit_repository: AuditRepository,
+}
+
+impl AuditService {
+    pub fn new(db: &DbConnection) -> Self {
+        Self {
+            audit_repository: AuditRepository::new(db),
+        }
+    }
+
+    /// Records a privileged action. A failure to write the audit log is
+    /// logged, but never undoes the action that was already taken.
+    pub async fn record(&self, entry: NewAuditLog) {
+        let action = entry.action.clone();
+        if let Err(e) = self.audit_repository.create(entry).await {
+            error!("Failed to audit {action}: {e}");
+        }
+    }
+
+    pub async fn search(
+        &self,
+        params: &AuditLogSearchParams,
+        pagination: &CursorPagination,
+    ) -> CursorPage<AuditLog> {
+        self.audit_repository.search(params, pagination).await
+    }
+}
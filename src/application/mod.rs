@@ -1,7 +1,23 @@
+pub mod activity_service;
+pub mod api_token_service;
+pub mod audit_service;
+pub mod notification_service;
+pub mod progress_service;
+pub mod push_service;
 pub mod race_service;
+pub mod search_service;
 pub mod town_service;
 pub mod user_service;
+pub mod webhook_service;
 
+pub use activity_service::ActivityService;
+pub use api_token_service::ApiTokenService;
+pub use audit_service::AuditService;
+pub use notification_service::NotificationService;
+pub use progress_service::ProgressService;
+pub use push_service::PushService;
 pub use race_service::RaceService;
+pub use search_service::SearchService;
 pub use town_service::TownService;
 pub use user_service::UserService;
+pub use webhook_service::WebhookService;
@@ -0,0 +1,220 @@
+use std::collections::{HashMap, HashSet};
+
+use askama::Template;
+use chrono::{Days, Utc};
+use log::{error, info};
+
+use crate::{
+    AppInfo, DbConnection,
+    domain::{
+        Town,
+        notification::{
+            NotificationPreferences, NotificationRecipient, UpdateNotificationPreferences,
+        },
+        race::RaceView,
+    },
+    filters,
+    infrastructure::{
+        db::{NotificationRepository, RaceRepository, TownRepository},
+        mail::{self, Email, Mailer},
+    },
+    util::validation::Validate,
+};
+
+#[derive(Template)]
+#[template(path = "emails/races.html")]
+struct RacesEmailTemplate<'a> {
+    app_info: &'a AppInfo,
+    first_name: &'a str,
+    heading: &'a str,
+    races: &'a [&'a RaceView],
+    unsubscribe_url: &'a str,
+}
+
+pub struct NotificationService {
+    notification_repository: NotificationRepository,
+    race_repository: RaceRepository,
+    town_repository: TownRepository,
+    mailer: Box<dyn Mailer>,
+    app_info: AppInfo,
+}
+
+impl NotificationService {
+    pub fn new(db: &DbConnection) -> Self {
+        Self::with_mailer(db, mail::from_env())
+    }
+
+    pub fn with_mailer(db: &DbConnection, mailer: Box<dyn Mailer>) -> Self {
+        Self {
+            notification_repository: NotificationRepository::new(db),
+            race_repository: RaceRepository::new(db),
+            town_repository: TownRepository::new(db),
+            mailer,
+            app_info: AppInfo::new(),
+        }
+    }
+
+    pub async fn find_preferences(&self, user_id: i64) -> Result<NotificationPreferences, String> {
+        self.notification_repository.find_or_create(user_id).await
+    }
+
+    pub async fn update_preferences(
+        &self,
+        user_id: i64,
+        preferences: UpdateNotificationPreferences,
+    ) -> Result<NotificationPreferences, String> {
+        preferences.validate()?;
+        self.notification_repository.find_or_create(user_id).await?;
+        self.notification_repository
+            .update(user_id, &preferences)
+            .await
+    }
+
+    pub async fn find_by_unsubscribe_token(&self, token: &str) -> Option<NotificationPreferences> {
+        self.notification_repository.find_by_token(token).await
+    }
+
+    pub async fn unsubscribe(&self, token: &str) -> Result<(), String> {
+        self.notification_repository.unsubscribe(token).await
+    }
+
+    /// Emails everyone on the digest the races added since their last one.
+    pub async fn send_weekly_digests(&self) {
+        let towns = self.towns_by_id().await;
+        let week_ago = Utc::now()
+            .naive_utc()
+            .checked_sub_days(Days::new(7))
+            .unwrap_or_default();
+
+        for recipient in self.notification_repository.find_digest_recipients().await {
+            let since = recipient.last_digest_at.unwrap_or(week_ago);
+            let races = self
+                .race_repository
+                .find_upcoming_created_since(since)
+                .await;
+
+            // Left for next week's digest if it didn't go out.
+            if let Err(e) = self
+                .send_races(
+                    &recipient,
+                    &races,
+                    &towns,
+                    "New races in towns you still need",
+                )
+                .await
+            {
+                error!("{e}");
+                continue;
+            }
+
+            if let Err(e) = self
+                .notification_repository
+                .mark_digest_sent(recipient.user_id)
+                .await
+            {
+                error!(
+                    "Unable to record digest for user ({}): {e}",
+                    recipient.user_id
+                );
+            }
+        }
+    }
+
+    /// Emails anyone who wants to hear straight away about just imported races.
+    pub async fn send_instant_alerts(&self, races: &[RaceView]) {
+        if races.is_empty() {
+            return;
+        }
+
+        let towns = self.towns_by_id().await;
+        for recipient in self.notification_repository.find_instant_recipients().await {
+            if let Err(e) = self
+                .send_races(
+                    &recipient,
+                    races,
+                    &towns,
+                    "A race was just added in a town you need",
+                )
+                .await
+            {
+                error!("{e}");
+            }
+        }
+    }
+
+    /// Emails the recipient the races in towns they still need, if there are
+    /// any. Only a failed send is an error.
+    async fn send_races(
+        &self,
+        recipient: &NotificationRecipient,
+        races: &[RaceView],
+        towns: &HashMap<i64, Town>,
+        heading: &str,
+    ) -> Result<(), String> {
+        let completed: HashSet<i64> = self
+            .town_repository
+            .find_completed(recipient.user_id)
+            .await
+            .into_iter()
+            .map(|town| town.town_id)
+            .collect();
+
+        let races = recipient.races_of_interest(races, &completed, towns);
+        if races.is_empty() {
+            return Ok(());
+        }
+
+        let unsubscribe_url = format!(
+            "{}/notifications/unsubscribe/{}",
+            self.app_info.website_url, recipient.unsubscribe_token
+        );
+        let html = RacesEmailTemplate {
+            app_info: &self.app_info,
+            first_name: &recipient.first_name,
+            heading,
+            races: &races,
+            unsubscribe_url: &unsubscribe_url,
+        }
+        .render()
+        .unwrap_or_default();
+
+        let mut text = format!("Hi {},\n\n{heading}:\n\n", recipient.first_name);
+        for race in &races {
+            text.push_str(&format!(
+                "- {} in {} on {}\n",
+                race.name,
+                race.town,
+                race.start_at.format("%a, %b %-d")
+            ));
+        }
+        text.push_str(&format!("\nUnsubscribe: {unsubscribe_url}\n"));
+
+        let email = Email {
+            to: recipient.email.clone(),
+            subject: match races.len() {
+                1 => "1 upcoming race in a town you need".to_string(),
+                count => format!("{count} upcoming races in towns you need"),
+            },
+            text,
+            html,
+            unsubscribe_url: Some(unsubscribe_url),
+        };
+
+        self.mailer.send(&email).await?;
+        info!(
+            "📧 Sent {} race(s) to user ({})",
+            races.len(),
+            recipient.user_id
+        );
+        Ok(())
+    }
+
+    async fn towns_by_id(&self) -> HashMap<i64, Town> {
+        self.town_repository
+            .find_all()
+            .await
+            .into_iter()
+            .map(|town| (town.id, town))
+            .collect()
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{
+    DbConnection, domain::progress::ProgressDrift, infrastructure::db::ProgressRepository,
+};
+
+pub struct ProgressService {
+    progress_repository: ProgressRepository,
+}
+
+impl ProgressService {
+    pub fn new(db: &DbConnection) -> Self {
+        Self {
+            progress_repository: ProgressRepository::new(db),
+        }
+    }
+
+    /// Counters that don't match the completed towns they're counting.
+    pub async fn check(&self) -> Result<Vec<ProgressDrift>, String> {
+        self.progress_repository
+            .find_drift()
+            .await
+            .map_err(|e| format!("Unable to check progress: {e}"))
+    }
+
+    /// Counts everyone's progress again, returning what was wrong before.
+    pub async fn rebuild(&self) -> Result<Vec<ProgressDrift>, String> {
+        let drift = self.check().await?;
+        self.progress_repository
+            .rebuild()
+            .await
+            .map_err(|e| format!("Unable to rebuild progress: {e}"))?;
+
+        Ok(drift)
+    }
+}
@@ -0,0 +1,89 @@
+use chrono::{Days, Utc};
+use chrono_tz::America::New_York;
+use log::{error, info};
+
+use crate::{
+    DbConnection,
+    domain::push::{NewPushSubscription, PushKind, PushMessage},
+    infrastructure::{
+        db::{PushRepository, RaceRepository},
+        push::{PushError, WebPushSender},
+    },
+    util::validation::Validate,
+};
+
+pub struct PushService {
+    push_repository: PushRepository,
+    race_repository: RaceRepository,
+    sender: WebPushSender,
+}
+
+impl PushService {
+    pub fn new(db: &DbConnection) -> Self {
+        Self {
+            push_repository: PushRepository::new(db),
+            race_repository: RaceRepository::new(db),
+            sender: WebPushSender::from_env(),
+        }
+    }
+
+    pub fn public_key(&self) -> Option<String> {
+        self.sender.public_key()
+    }
+
+    pub async fn subscribe(
+        &self,
+        user_id: i64,
+        subscription: NewPushSubscription,
+        user_agent: Option<&str>,
+    ) -> Result<(), String> {
+        subscription.validate()?;
+        self.push_repository
+            .save(user_id, &subscription, user_agent)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn unsubscribe(&self, user_id: i64, endpoint: &str) -> Result<(), String> {
+        self.push_repository.delete(user_id, endpoint).await
+    }
+
+    pub async fn count_devices(&self, user_id: i64) -> i64 {
+        self.push_repository.count_for_user(user_id).await
+    }
+
+    /// Pushes to each of the member's devices, if they want this kind of push.
+    /// Devices the browser has since unsubscribed are forgotten.
+    pub async fn notify(&self, user_id: i64, kind: PushKind, message: &PushMessage) {
+        for subscription in self.push_repository.find_for(user_id, kind).await {
+            match self.sender.send(&subscription, message).await {
+                Ok(_) => info!("🔔 Pushed \"{}\" to user ({user_id})", message.title),
+                Err(PushError::Gone) => {
+                    let _ = self.push_repository.delete_by_id(subscription.id).await;
+                }
+                Err(PushError::Failed(e)) => {
+                    error!("Unable to push to subscription ({}): {e}", subscription.id)
+                }
+            }
+        }
+    }
+
+    /// Reminds everyone who RSVP'd to a race that it's tomorrow.
+    pub async fn send_race_reminders(&self) {
+        let Some(tomorrow) = Utc::now()
+            .with_timezone(&New_York)
+            .date_naive()
+            .checked_add_days(Days::new(1))
+        else {
+            return;
+        };
+
+        for race in self.race_repository.find_rsvped_on(tomorrow).await {
+            let message = PushMessage::race_reminder(&race);
+            for user_id in self.race_repository.find_rsvp_user_ids(race.id).await {
+                self.notify(user_id, PushKind::RaceReminder, &message).await;
+            }
+        }
+    }
+}
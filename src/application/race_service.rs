@@ -1,19 +1,30 @@
+use log::{error, info};
+
 use crate::{
     DbConnection,
     domain::{
+        duplicate::{DuplicateStatus, RaceDuplicate, RaceDuplicateView, SUGGEST_SCORE},
         google_sheet::GoogleSheet,
         race::{
-            NewRace, NewRaceResult, RaceSearchParams, RaceView, SubmitTownSearchParams,
-            UpcomingRaceFromRun169Society,
+            AdminRaceSearchParams, NewRace, NewRaceResult, RaceDetails, RaceEventGroup,
+            RaceResultView, RaceSearchParams, RaceSeries, RaceStatus, RaceSubmission, RaceView,
+            SubmitTownSearchParams, UpcomingRaceFromRun169Society,
         },
+        user::UserView,
+        webhook::WebhookPayload,
+    },
+    infrastructure::db::{
+        DuplicateRepository, RaceRepository, SearchRepository, TownRepository, WebhookRepository,
     },
-    infrastructure::db::{RaceRepository, TownRepository},
-    util::pagination::PaginatedResponse,
+    util::pagination::{CursorPage, CursorPagination, PaginatedResponse, Pagination},
 };
 
 pub struct RaceService {
     race_repository: RaceRepository,
     town_repository: TownRepository,
+    webhook_repository: WebhookRepository,
+    duplicate_repository: DuplicateRepository,
+    search_repository: SearchRepository,
 }
 
 impl RaceService {
@@ -21,14 +32,50 @@ impl RaceService {
         Self {
             race_repository: RaceRepository::new(db),
             town_repository: TownRepository::new(db),
+            webhook_repository: WebhookRepository::new(db),
+            duplicate_repository: DuplicateRepository::new(db),
+            search_repository: SearchRepository::new(db),
         }
     }
 
     pub async fn submit_town_search(
         &self,
         params: &SubmitTownSearchParams,
-    ) -> PaginatedResponse<RaceView> {
-        self.race_repository.submit_town_search(params).await
+    ) -> PaginatedResponse<RaceEventGroup> {
+        let matching = self.search_repository.match_query(&params.race_name).await;
+        self.race_repository
+            .submit_town_search(params, matching.as_deref())
+            .await
+    }
+
+    pub async fn find_by_id(&self, race_id: i64) -> Result<RaceView, String> {
+        self.race_repository.find_by_id(race_id).await
+    }
+
+    pub async fn find_details(&self, race_id: i64) -> Result<RaceDetails, String> {
+        let race = self.race_repository.find_by_id(race_id).await?;
+
+        Ok(RaceDetails {
+            finishers: self.race_repository.find_finishers(race.id).await,
+            rsvps: self.race_repository.find_rsvpers(race.id).await,
+            series: RaceSeries {
+                editions: self
+                    .race_repository
+                    .find_editions(race.town_id, &race.name)
+                    .await,
+            },
+            race,
+        })
+    }
+
+    pub async fn find_results_for_user(
+        &self,
+        user_id: i64,
+        pagination: &Pagination,
+    ) -> PaginatedResponse<RaceResultView> {
+        self.race_repository
+            .find_results_for_user(user_id, pagination)
+            .await
     }
 
     pub async fn submit_result(&self, result: NewRaceResult) -> Result<(), String> {
@@ -39,15 +86,185 @@ impl RaceService {
         self.race_repository.get_or_create(race).await
     }
 
+    /// `user_id` is who's searching, for leaving out towns they've completed.
+    pub async fn search_upcoming_events(
+        &self,
+        params: &RaceSearchParams,
+        pagination: &CursorPagination,
+        user_id: Option<i64>,
+    ) -> CursorPage<RaceEventGroup> {
+        self.race_repository
+            .search_upcoming_events(params, pagination, user_id)
+            .await
+    }
+
     pub async fn search_for_upcoming(
         &self,
-        params: RaceSearchParams,
+        params: &RaceSearchParams,
+        user_id: Option<i64>,
     ) -> PaginatedResponse<RaceView> {
-        self.race_repository.search_for_upcoming(params).await
+        self.race_repository
+            .search_for_upcoming(params, user_id)
+            .await
+    }
+
+    pub async fn rsvp(&self, user_id: i64, race_id: i64) -> Result<RaceView, String> {
+        let race = self.race_repository.find_by_id(race_id).await?;
+        if !race.is_listed() {
+            return Err("That race isn't listed".to_string());
+        }
+        self.race_repository.rsvp(user_id, race.id).await?;
+        Ok(race)
+    }
+
+    pub async fn cancel_rsvp(&self, user_id: i64, race_id: i64) -> Result<RaceView, String> {
+        let race = self.race_repository.find_by_id(race_id).await?;
+        self.race_repository.cancel_rsvp(user_id, race.id).await?;
+        Ok(race)
+    }
+
+    pub async fn find_rsvp_race_ids(&self, user_id: i64) -> Vec<i64> {
+        self.race_repository.find_rsvp_race_ids(user_id).await
+    }
+
+    /// Adds a race and its distances from the "Add Race" form. Members'
+    /// races wait for a moderator, while Royalty and Admins' are published
+    /// straight away.
+    pub async fn submit(
+        &self,
+        user: &UserView,
+        races: Vec<NewRace>,
+    ) -> Result<Vec<RaceView>, String> {
+        for race in &races {
+            if let Some(duplicate) = self.race_repository.find_duplicate(race).await {
+                return Err(match duplicate.status {
+                    RaceStatus::Pending => {
+                        "That race is already waiting to be approved".to_string()
+                    }
+                    _ => format!("That race is already listed as {}", duplicate.name),
+                });
+            }
+        }
+
+        let status = match user.is_moderator() {
+            true => RaceStatus::Approved,
+            false => RaceStatus::Pending,
+        };
+        let races = self.race_repository.submit(&races, user.id, status).await?;
+
+        for race in races.iter().filter(|race| race.is_approved()) {
+            self.announce(race).await;
+        }
+
+        Ok(races)
+    }
+
+    pub async fn find_pending(&self) -> Vec<RaceSubmission> {
+        self.race_repository.find_pending().await
+    }
+
+    pub async fn find_submission(&self, race_id: i64) -> Result<RaceSubmission, String> {
+        self.race_repository.find_submission(race_id).await
+    }
+
+    pub async fn find_submitted_by(&self, user_id: i64) -> Vec<RaceSubmission> {
+        self.race_repository.find_submitted_by(user_id).await
     }
 
-    pub async fn upcoming_races_nightly(&self) -> Result<(), String> {
+    pub async fn find_merge_candidates(&self, race: &RaceView) -> Vec<RaceView> {
+        self.race_repository.find_merge_candidates(race).await
+    }
+
+    /// Corrects a pending race before it's approved.
+    pub async fn update_pending(&self, race_id: i64, race: NewRace) -> Result<RaceView, String> {
+        let pending = self.race_repository.find_by_id(race_id).await?;
+        if pending.status != RaceStatus::Pending {
+            return Err("That race has already been reviewed".to_string());
+        }
+
+        self.update(race_id, race).await
+    }
+
+    /// Corrects any race, keeping the name's case as typed.
+    pub async fn update(&self, race_id: i64, race: NewRace) -> Result<RaceView, String> {
+        if let Some(duplicate) = self.race_repository.find_duplicate(&race).await
+            && duplicate.id != race_id
+        {
+            return Err(format!("That race is already listed as {}", duplicate.name));
+        }
+
+        self.race_repository.update(race_id, race).await
+    }
+
+    /// Soft deletes the race, letting webhooks know if members could have
+    /// been planning to run it.
+    pub async fn delete(&self, race_id: i64, admin: &UserView) -> Result<RaceView, String> {
+        let before = self.race_repository.find_by_id(race_id).await?;
+        let race = self.race_repository.delete(race_id, admin.id).await?;
+
+        if before.is_listed() && before.is_upcoming() {
+            let payload = WebhookPayload::race_cancelled(&race);
+            if let Err(e) = self.webhook_repository.enqueue(None, &payload).await {
+                error!("{e}");
+            }
+        }
+
+        Ok(race)
+    }
+
+    pub async fn admin_search(
+        &self,
+        params: &AdminRaceSearchParams,
+    ) -> PaginatedResponse<RaceView> {
+        self.race_repository.admin_search(params).await
+    }
+
+    pub async fn approve(&self, race_id: i64, reviewer: &UserView) -> Result<RaceView, String> {
+        let race = self
+            .race_repository
+            .review(race_id, reviewer.id, RaceStatus::Approved, None)
+            .await?;
+        self.announce(&race).await;
+        Ok(race)
+    }
+
+    pub async fn reject(
+        &self,
+        race_id: i64,
+        reviewer: &UserView,
+        reason: &str,
+    ) -> Result<RaceView, String> {
+        let reason = Some(reason.trim().to_string()).filter(|reason| !reason.is_empty());
+        self.race_repository
+            .review(race_id, reviewer.id, RaceStatus::Rejected, reason)
+            .await
+    }
+
+    /// Merges a duplicate race into the listed race that survives it.
+    pub async fn merge(
+        &self,
+        race_id: i64,
+        into_race_id: i64,
+        reviewer: &UserView,
+    ) -> Result<RaceView, String> {
+        self.race_repository
+            .merge(race_id, into_race_id, reviewer.id)
+            .await?;
+        self.race_repository.find_by_id(into_race_id).await
+    }
+
+    /// Lets webhooks know about a newly published race.
+    async fn announce(&self, race: &RaceView) {
+        let payload = WebhookPayload::race_imported(race);
+        if let Err(e) = self.webhook_repository.enqueue(None, &payload).await {
+            error!("{e}");
+        }
+    }
+
+    /// Imports the Run169 Society's upcoming races, returning the ones that are new.
+    pub async fn upcoming_races_nightly(&self) -> Result<Vec<RaceView>, String> {
         let races: Vec<UpcomingRaceFromRun169Society> = GoogleSheet::upcoming_races().await?;
+        let mut new_races = vec![];
 
         for race in races {
             let town_id = match self.town_repository.find_by_name(&race.town_name).await {
@@ -55,20 +272,107 @@ impl RaceService {
                 Err(_) => continue,
             };
 
-            let race = NewRace {
-                town_id,
-                name: race.name.to_string(),
-                start_at: race.start_at,
-                race_url: Some(race.race_url.to_string()),
-                miles: race.miles,
-            };
+            match self
+                .race_repository
+                .create_event_if_new(&race.races(town_id))
+                .await
+            {
+                Ok(races) => {
+                    for race in races {
+                        self.announce(&race).await;
+                        new_races.push(race);
+                    }
+                }
+                Err(e) => error!("{e}"),
+            }
+        }
 
-            match self.race_repository.get_or_create(race).await {
-                Ok(race) => race,
-                Err(_) => continue,
-            };
+        Ok(new_races)
+    }
+
+    /// Looks for races that are probably the same race, e.g. "oak leaf 5k"
+    /// and "oakleaf 5k", and queues them up for an admin. Returns how many
+    /// were suggested.
+    pub async fn detect_duplicates(&self) -> usize {
+        let mut suggested = 0;
+
+        for pair in self.duplicate_repository.find_unchecked_pairs().await {
+            let score = pair.score();
+            if score < SUGGEST_SCORE {
+                continue;
+            }
+
+            match self.duplicate_repository.suggest(&pair, score).await {
+                Ok(_) => suggested += 1,
+                Err(e) => error!("{e}"),
+            }
+        }
+
+        if suggested > 0 {
+            info!("Found {suggested} possible duplicate races.");
+        }
+
+        suggested
+    }
+
+    pub async fn find_duplicates(&self) -> Vec<RaceDuplicateView> {
+        let mut views = vec![];
+
+        for duplicate in self.duplicate_repository.find_pending().await {
+            let race = self.race_repository.find_by_id(duplicate.race_id).await;
+            let other = self
+                .race_repository
+                .find_by_id(duplicate.duplicate_race_id)
+                .await;
+
+            if let (Ok(race), Ok(other)) = (race, other) {
+                views.push(RaceDuplicateView::new(&duplicate, race, other));
+            }
         }
 
-        Ok(())
+        views
+    }
+
+    /// Merges the other race in the suggestion into the one being kept.
+    pub async fn accept_duplicate(
+        &self,
+        duplicate_id: i64,
+        keep_race_id: i64,
+        admin: &UserView,
+    ) -> Result<(RaceView, RaceView), String> {
+        let duplicate = self
+            .duplicate_repository
+            .find_pending_by_id(duplicate_id)
+            .await?;
+
+        let merge_race_id = match keep_race_id {
+            id if id == duplicate.race_id => duplicate.duplicate_race_id,
+            id if id == duplicate.duplicate_race_id => duplicate.race_id,
+            _ => return Err("That race isn't part of the suggestion".to_string()),
+        };
+
+        let merged = self.race_repository.find_by_id(merge_race_id).await?;
+        let kept = self.merge(merge_race_id, keep_race_id, admin).await?;
+        self.duplicate_repository
+            .review(duplicate_id, DuplicateStatus::Accepted, admin.id)
+            .await?;
+
+        Ok((merged, kept))
+    }
+
+    pub async fn dismiss_duplicate(
+        &self,
+        duplicate_id: i64,
+        admin: &UserView,
+    ) -> Result<RaceDuplicate, String> {
+        let duplicate = self
+            .duplicate_repository
+            .find_pending_by_id(duplicate_id)
+            .await?;
+        self.duplicate_repository
+            .review(duplicate_id, DuplicateStatus::Dismissed, admin.id)
+            .await?;
+
+        Ok(duplicate)
     }
 }
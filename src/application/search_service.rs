@@ -0,0 +1,36 @@
+use crate::{
+    DbConnection,
+    domain::search::{RESULTS_PER_KIND, SearchKind, SearchResults},
+    infrastructure::db::SearchRepository,
+};
+
+pub struct SearchService {
+    search_repository: SearchRepository,
+}
+
+impl SearchService {
+    pub fn new(db: &DbConnection) -> Self {
+        Self {
+            search_repository: SearchRepository::new(db),
+        }
+    }
+
+    /// Races, towns, counties and members matching what was typed, allowing
+    /// for a typo or two.
+    pub async fn search(&self, q: &str) -> SearchResults {
+        let Some(query) = self.search_repository.match_query(q).await else {
+            return SearchResults::default();
+        };
+
+        let search = |kind| {
+            self.search_repository
+                .search(&query, kind, RESULTS_PER_KIND)
+        };
+        SearchResults {
+            races: search(SearchKind::Race).await,
+            towns: search(SearchKind::Town).await,
+            counties: search(SearchKind::County).await,
+            members: search(SearchKind::Member).await,
+        }
+    }
+}
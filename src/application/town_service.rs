@@ -1,27 +1,46 @@
+use log::error;
+
 use crate::{
     DbConnection,
+    application::PushService,
     domain::{
         Town,
-        race::{NewRace, NewRaceResult},
+        push::{MILESTONES, PushKind, PushMessage},
+        race::{NewRace, RaceView},
+        submission::{
+            Completion, SubmissionError, SubmissionOutcome, SubmissionStatus, SubmitTownError,
+            TownSubmission,
+        },
         town::{
-            CompletedTown, Run169TownsSocietyGoogleForm, Run169TownsSocietyGoogleFormAnswers,
-            SubmitTown,
+            CompletedTown, County, Run169TownsSocietyGoogleForm,
+            Run169TownsSocietyGoogleFormAnswers, SubmitTown,
         },
-        user::UserView,
+        user::{MemberProfile, UserView},
+        webhook::WebhookPayload,
+    },
+    infrastructure::db::{
+        ProgressRepository, RaceRepository, SubmissionRepository, TownRepository, WebhookRepository,
     },
-    infrastructure::db::{RaceRepository, TownRepository},
 };
 
 pub struct TownService {
     town_repository: TownRepository,
+    progress_repository: ProgressRepository,
     race_repository: RaceRepository,
+    webhook_repository: WebhookRepository,
+    submission_repository: SubmissionRepository,
+    push_service: PushService,
 }
 
 impl TownService {
     pub fn new(db: &DbConnection) -> Self {
         Self {
             town_repository: TownRepository::new(db),
+            progress_repository: ProgressRepository::new(db),
             race_repository: RaceRepository::new(db),
+            webhook_repository: WebhookRepository::new(db),
+            submission_repository: SubmissionRepository::new(db),
+            push_service: PushService::new(db),
         }
     }
 
@@ -29,6 +48,14 @@ impl TownService {
         self.town_repository.find_all().await
     }
 
+    pub async fn find_by_id(&self, town_id: i64) -> Result<Town, String> {
+        self.town_repository.find_by_id(town_id).await
+    }
+
+    pub async fn find_all_counties(&self) -> Vec<County> {
+        self.town_repository.find_all_counties().await
+    }
+
     pub async fn find_completed(&self, user_id: i64) -> Vec<CompletedTown> {
         self.town_repository.find_completed(user_id).await
     }
@@ -37,21 +64,205 @@ impl TownService {
         &self,
         user: UserView,
         form: SubmitTown,
-    ) -> Result<(), String> {
-        let town_id = form.town_id;
-        let town = self.town_repository.find_by_id(town_id).await?;
+    ) -> Result<Completion, SubmitTownError> {
+        let town = self
+            .town_repository
+            .find_by_id(form.town_id)
+            .await
+            .map_err(|_| SubmitTownError::TownNotFound)?;
 
+        // Checked before posting, since the society can't tell it's a repeat.
         let new_race = NewRace::from(form.clone());
-        let race = self.race_repository.get_or_create(new_race).await?;
+        let submitted = self
+            .race_repository
+            .has_result(user.id, &new_race)
+            .await
+            .map_err(SubmitTownError::Race)?;
+        if submitted {
+            return Err(SubmitTownError::AlreadySubmitted);
+        }
+
+        self.post_to_society(&user, &town, &form)
+            .await
+            .map_err(SubmitTownError::Society)?;
+        let (_, completion) = self.record_completed(user, new_race, form.notes).await?;
+
+        Ok(completion)
+    }
+
+    /// Like `submit_completed_town`, but safe to retry with the same key: the
+    /// society form is posted at most once and the result recorded once.
+    pub async fn submit_idempotent(
+        &self,
+        user: UserView,
+        idempotency_key: &str,
+        form: SubmitTown,
+    ) -> Result<SubmissionOutcome, SubmissionError> {
+        TownSubmission::validate_key(idempotency_key).map_err(SubmissionError::Invalid)?;
+        let town = self
+            .town_repository
+            .find_by_id(form.town_id)
+            .await
+            .map_err(|_| SubmissionError::Invalid("Unable to find that town".to_string()))?;
+
+        let fingerprint = TownSubmission::fingerprint(&form);
+        let claimed = self
+            .submission_repository
+            .claim(user.id, idempotency_key, &fingerprint, town.id)
+            .await
+            .map_err(SubmissionError::Failed)?;
+
+        let submission = match claimed {
+            Some(submission) => submission,
+            None => {
+                let existing = self
+                    .submission_repository
+                    .find(user.id, idempotency_key)
+                    .await
+                    .map_err(SubmissionError::Failed)?;
+
+                if existing.fingerprint != fingerprint {
+                    return Err(SubmissionError::KeyReused);
+                }
+
+                match existing.status {
+                    SubmissionStatus::Completed => {
+                        return Ok(SubmissionOutcome::Replayed(existing));
+                    }
+                    // Already posted, so only the recording here is left to do.
+                    SubmissionStatus::Posted => existing,
+                    // Still processing is only taken back once it's stale.
+                    SubmissionStatus::Processing | SubmissionStatus::Failed => {
+                        match self.submission_repository.retry(existing.id).await {
+                            Ok(true) => existing,
+                            Ok(false) => return Err(SubmissionError::InProgress),
+                            Err(e) => return Err(SubmissionError::Failed(e)),
+                        }
+                    }
+                }
+            }
+        };
+
+        let posted = submission.status == SubmissionStatus::Posted;
+        let new_race = NewRace::from(form.clone());
+        if !posted {
+            match self.race_repository.has_result(user.id, &new_race).await {
+                Ok(false) => {}
+                Ok(true) => {
+                    self.fail_submission(&submission, posted, "Already submitted".to_string())
+                        .await;
+                    return Err(SubmissionError::AlreadySubmitted);
+                }
+                Err(e) => {
+                    self.fail_submission(&submission, posted, e.clone()).await;
+                    return Err(SubmissionError::Invalid(e));
+                }
+            }
+            if let Err(e) = self.post_to_society(&user, &town, &form).await {
+                return Err(self.fail_submission(&submission, posted, e).await);
+            }
+            self.submission_repository
+                .mark_posted(submission.id)
+                .await
+                .map_err(SubmissionError::Failed)?;
+        }
 
-        // Submit the form to Run169Towns Society.
-        let answers = Run169TownsSocietyGoogleFormAnswers::new(&user, &town, &form);
-        Run169TownsSocietyGoogleForm::submit_with_answers(answers).await?;
+        // A retry after the result was saved finds it already recorded, and
+        // only has the submission left to finish.
+        let (race, _) = self
+            .record_completed(user, new_race, form.notes)
+            .await
+            .map_err(|e| SubmissionError::Failed(e.to_string()))?;
+        self.submission_repository
+            .mark_completed(submission.id, race.id)
+            .await
+            .map(SubmissionOutcome::Created)
+            .map_err(SubmissionError::Failed)
+    }
+
+    /// Leaves a submission that has been posted to be finished by a retry,
+    /// anything else can be tried again from the start.
+    async fn fail_submission(
+        &self,
+        submission: &TownSubmission,
+        posted: bool,
+        error: String,
+    ) -> SubmissionError {
+        if !posted
+            && let Err(e) = self
+                .submission_repository
+                .mark_failed(submission.id, &error)
+                .await
+        {
+            error!("{e}");
+        }
+        SubmissionError::Failed(error)
+    }
 
-        let race_result = NewRaceResult::new(user.id, &race, form.notes);
-        let _ = self.town_repository.mark_completed(user.id, town_id).await;
-        let _ = self.race_repository.save_result(race_result).await;
+    /// Submits the form to Run169Towns Society.
+    async fn post_to_society(
+        &self,
+        user: &UserView,
+        town: &Town,
+        form: &SubmitTown,
+    ) -> Result<(), String> {
+        let answers = Run169TownsSocietyGoogleFormAnswers::new(user, town, form);
+        if let Err(e) = Run169TownsSocietyGoogleForm::submit_with_answers(answers).await {
+            let message = PushMessage::submission_failed(town, &e);
+            self.push_service
+                .notify(user.id, PushKind::SubmissionFailed, &message)
+                .await;
+            return Err(e);
+        }
 
         Ok(())
     }
+
+    async fn record_completed(
+        &self,
+        user: UserView,
+        race: NewRace,
+        notes: Option<String>,
+    ) -> Result<(RaceView, Completion), SubmitTownError> {
+        let (race, completion) = self
+            .race_repository
+            .record_result(user.id, race, notes)
+            .await
+            .map_err(SubmitTownError::Recording)?;
+
+        // Running a town again is still a result, but nothing to celebrate.
+        if completion == Completion::NewTown {
+            self.announce_completed(user, &race).await;
+        }
+
+        Ok((race, completion))
+    }
+
+    async fn announce_completed(&self, user: UserView, race: &RaceView) {
+        let member_id = Some(user.id);
+        let completed = self
+            .progress_repository
+            .find_by_user(user.id)
+            .await
+            .completed_count;
+        if MILESTONES.contains(&completed) {
+            let message = PushMessage::milestone(completed);
+            self.push_service
+                .notify(user.id, PushKind::Milestone, &message)
+                .await;
+        }
+
+        let member = MemberProfile::from(user);
+
+        let mut payloads = vec![WebhookPayload::town_completed(&member, race, completed)];
+        if completed == 169 {
+            payloads.push(WebhookPayload::towns_finished(&member));
+        }
+
+        for payload in payloads {
+            if let Err(e) = self.webhook_repository.enqueue(member_id, &payload).await {
+                error!("{e}");
+            }
+        }
+    }
 }
@@ -2,10 +2,10 @@ use crate::{
     DbConnection,
     domain::{
         User,
-        user::{NewUser, UpdateRunnerInfo, UpdateUser, UserView},
+        user::{AdminUserSearchParams, NewUser, UpdateRunnerInfo, UpdateUser, UserView},
     },
     infrastructure::db::{TownRepository, UserRepository},
-    util::pagination::{PaginatedResponse, Pagination},
+    util::pagination::{CursorPage, CursorPagination, PaginatedResponse, Pagination},
 };
 
 pub struct UserService {
@@ -58,6 +58,28 @@ impl UserService {
         self.user_repository.search(pagination, search).await
     }
 
+    pub async fn search_cursor(
+        &self,
+        pagination: &CursorPagination,
+        search: &str,
+    ) -> CursorPage<UserView> {
+        self.user_repository.search_cursor(pagination, search).await
+    }
+
+    pub async fn admin_search(
+        &self,
+        params: &AdminUserSearchParams,
+    ) -> PaginatedResponse<UserView> {
+        self.user_repository.admin_search(params).await
+    }
+
+    pub async fn admin_export(
+        &self,
+        params: &AdminUserSearchParams,
+    ) -> Result<Vec<UserView>, sqlx::Error> {
+        self.user_repository.admin_export(params).await
+    }
+
     pub async fn create(&self, user: &NewUser) -> Result<UserView, sqlx::Error> {
         self.user_repository.create(user).await
     }
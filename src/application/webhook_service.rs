@@ -0,0 +1,112 @@
+use log::{error, info};
+
+use crate::{
+    DbConnection,
+    domain::{
+        user::UserView,
+        webhook::{NewWebhook, NewWebhookForm, Webhook, WebhookDelivery, WebhookPayload},
+    },
+    infrastructure::db::WebhookRepository,
+    util::validation::Validate,
+};
+
+pub struct WebhookService {
+    webhook_repository: WebhookRepository,
+}
+
+impl WebhookService {
+    pub fn new(db: &DbConnection) -> Self {
+        Self {
+            webhook_repository: WebhookRepository::new(db),
+        }
+    }
+
+    pub async fn find_for_user(&self, user_id: i64) -> Vec<Webhook> {
+        self.webhook_repository.find_for_user(user_id).await
+    }
+
+    /// Finds one of the user's webhooks along with its latest deliveries.
+    pub async fn find_with_deliveries(
+        &self,
+        user_id: i64,
+        webhook_id: i64,
+    ) -> Result<(Webhook, Vec<WebhookDelivery>), String> {
+        let webhook = self.find_owned(user_id, webhook_id).await?;
+        let deliveries = self
+            .webhook_repository
+            .find_deliveries(webhook.id, 50)
+            .await;
+        Ok((webhook, deliveries))
+    }
+
+    pub async fn create(&self, user: &UserView, form: NewWebhookForm) -> Result<Webhook, String> {
+        form.validate()?;
+        self.webhook_repository
+            .create(&NewWebhook::new(user.id, form))
+            .await
+    }
+
+    pub async fn delete(&self, user_id: i64, webhook_id: i64) -> Result<(), String> {
+        self.webhook_repository.delete(user_id, webhook_id).await
+    }
+
+    /// Sends a ping straight away (rather than waiting on the worker) so the
+    /// result shows up in the delivery log right after pressing the button.
+    pub async fn send_test(&self, user_id: i64, webhook_id: i64) -> Result<(), String> {
+        let webhook = self.find_owned(user_id, webhook_id).await?;
+        let delivery = self
+            .webhook_repository
+            .enqueue_for(webhook.id, &WebhookPayload::ping())
+            .await?;
+
+        self.attempt(&webhook, &delivery).await;
+        Ok(())
+    }
+
+    /// Sends everything that is due. Run by the background worker.
+    pub async fn deliver_pending(&self) {
+        let deliveries = self.webhook_repository.claim_due(25).await;
+        if deliveries.is_empty() {
+            return;
+        }
+
+        info!("🪝 Delivering {} webhook(s)...", deliveries.len());
+        for delivery in deliveries {
+            match self
+                .webhook_repository
+                .find_by_id(delivery.webhook_id)
+                .await
+            {
+                Ok(webhook) => self.attempt(&webhook, &delivery).await,
+                Err(e) => error!("Unable to deliver webhook ({}): {e}", delivery.id),
+            }
+        }
+    }
+
+    async fn attempt(&self, webhook: &Webhook, delivery: &WebhookDelivery) {
+        let result = match delivery.send(webhook).await {
+            Ok(status) => {
+                self.webhook_repository
+                    .mark_delivered(delivery.id, status)
+                    .await
+            }
+            Err((status, e)) => {
+                self.webhook_repository
+                    .mark_attempt_failed(delivery, status, &e)
+                    .await
+            }
+        };
+
+        if let Err(e) = result {
+            error!("Unable to record webhook delivery ({}): {e}", delivery.id);
+        }
+    }
+
+    async fn find_owned(&self, user_id: i64, webhook_id: i64) -> Result<Webhook, String> {
+        let webhook = self.webhook_repository.find_by_id(webhook_id).await?;
+        match webhook.user_id == user_id {
+            true => Ok(webhook),
+            false => Err("Unable to find that webhook".to_string()),
+        }
+    }
+}
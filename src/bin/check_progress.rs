@@ -0,0 +1,51 @@
+//! Checks everyone's progress counters against their completed towns,
+//! reports anything that has drifted, and rebuilds the counters.
+//!
+//! With `--check` nothing is rebuilt; it exits non-zero when anything has
+//! drifted.
+
+use ctrunner::{application::ProgressService, infrastructure::db::Database};
+use std::{process::ExitCode, sync::Arc};
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    dotenv::dotenv().ok();
+
+    let db = Arc::new(Database::initialize().await);
+    let progress = ProgressService::new(&db);
+    let check_only = std::env::args().any(|arg| arg == "--check");
+
+    let drift = match check_only {
+        true => progress.check().await,
+        false => progress.rebuild().await,
+    };
+    let drift = match drift {
+        Ok(drift) => drift,
+        Err(e) => {
+            eprintln!("❌ {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    for counter in &drift {
+        println!("{counter}");
+    }
+
+    match (drift.is_empty(), check_only) {
+        (true, _) => {
+            println!("✅ Progress counters are up to date");
+            ExitCode::SUCCESS
+        }
+        (false, true) => {
+            eprintln!(
+                "❌ {} progress counters have drifted, run `cargo run --bin check_progress`",
+                drift.len()
+            );
+            ExitCode::FAILURE
+        }
+        (false, false) => {
+            println!("✅ Rebuilt {} drifted progress counters", drift.len());
+            ExitCode::SUCCESS
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! Writes the API's OpenAPI document to `openapi.json`.
+//!
+//! With `--check` nothing is written; it exits non-zero when the committed
+//! document has drifted from the code, so CI catches a forgotten regenerate.
+
+use ctrunner::routes::api::ApiDoc;
+use std::{fs, process::ExitCode};
+
+const SCHEMA_PATH: &str = "openapi.json";
+
+fn main() -> ExitCode {
+    let schema = ApiDoc::to_json();
+
+    if !std::env::args().any(|arg| arg == "--check") {
+        fs::write(SCHEMA_PATH, schema).expect("Unable to write openapi.json");
+        println!("✅ Wrote {SCHEMA_PATH}");
+        return ExitCode::SUCCESS;
+    }
+
+    match fs::read_to_string(SCHEMA_PATH) {
+        Ok(committed) if committed == schema => {
+            println!("✅ {SCHEMA_PATH} is up to date");
+            ExitCode::SUCCESS
+        }
+        _ => {
+            eprintln!("❌ {SCHEMA_PATH} is out of date, run `cargo run --bin openapi`");
+            ExitCode::FAILURE
+        }
+    }
+}
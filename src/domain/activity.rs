@@ -0,0 +1,305 @@
+use std::{io::Cursor, path::Path};
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use chrono_tz::America::New_York;
+use fitparser::{FitDataRecord, Value, profile::MesgNum};
+use serde::Deserialize;
+use sqlx::FromRow;
+
+use crate::domain::{
+    Town,
+    boundary::TownLocator,
+    distance::{DistanceUnit, Kilometers, miles_between},
+    race::RaceView,
+    town::SubmitTown,
+};
+
+/// FIT files store coordinates as semicircles.
+const DEGREES_PER_SEMICIRCLE: f64 = 180.0 / 2_147_483_648.0;
+
+/// A run from a GPX or FIT file, or from Strava, boiled down to what's needed
+/// to find the race it was.
+#[derive(Debug, Clone)]
+pub struct Activity {
+    pub name: String,
+    /// Connecticut local time, the same as races.
+    pub start_at: NaiveDateTime,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub miles: f64,
+    /// `(latitude, longitude)` points along the way, or just the start when
+    /// that's all there is.
+    pub track: Vec<(f64, f64)>,
+}
+
+impl Activity {
+    /// Reads an uploaded file, going by its extension.
+    pub fn parse(filename: &str, bytes: &[u8]) -> Result<Self, String> {
+        let path = Path::new(filename);
+        let name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("Run")
+            .to_string();
+
+        match path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.to_lowercase())
+            .as_deref()
+        {
+            Some("gpx") => Self::from_gpx(bytes, name),
+            Some("fit") => Self::from_fit(bytes, name),
+            _ => Err("Only GPX and FIT files can be matched".to_string()),
+        }
+    }
+
+    /// Starts at the first timed trackpoint, with the distance summed along
+    /// the track.
+    pub fn from_gpx(bytes: &[u8], fallback_name: String) -> Result<Self, String> {
+        let gpx = gpx::read(Cursor::new(bytes)).map_err(|_| "That isn't a valid GPX file")?;
+
+        let track = gpx
+            .tracks
+            .first()
+            .ok_or("That GPX file doesn't have a track")?;
+        let points: Vec<_> = track
+            .segments
+            .iter()
+            .flat_map(|segment| segment.points.iter())
+            .collect();
+        let start = points
+            .iter()
+            .find(|point| point.time.is_some())
+            .ok_or("That GPX file doesn't have any times")?;
+
+        let start_at = start
+            .time
+            .and_then(|time| time.format().ok())
+            .and_then(|time| DateTime::parse_from_rfc3339(&time).ok())
+            .ok_or("That GPX file has an invalid time")?
+            .with_timezone(&Utc);
+
+        let miles: f64 = points
+            .windows(2)
+            .map(|pair| {
+                let (from, to) = (pair[0].point(), pair[1].point());
+                miles_between((from.y(), from.x()), (to.y(), to.x()))
+            })
+            .sum();
+
+        let name = track
+            .name
+            .clone()
+            .or_else(|| gpx.metadata.as_ref().and_then(|meta| meta.name.clone()))
+            .unwrap_or(fallback_name);
+
+        Ok(Self {
+            name,
+            start_at: local_time(start_at),
+            latitude: start.point().y(),
+            longitude: start.point().x(),
+            miles: (miles * 10.0).round() / 10.0,
+            track: points
+                .iter()
+                .map(|point| (point.point().y(), point.point().x()))
+                .collect(),
+        })
+    }
+
+    /// Uses the session summary the watch wrote, falling back to the first
+    /// record with a position when the session doesn't have one.
+    pub fn from_fit(bytes: &[u8], name: String) -> Result<Self, String> {
+        let records = fitparser::from_bytes(bytes).map_err(|_| "That isn't a valid FIT file")?;
+
+        let session = records
+            .iter()
+            .find(|record| record.kind() == MesgNum::Session)
+            .ok_or("That FIT file doesn't have a session")?;
+
+        let start_at = match fit_value(session, "start_time") {
+            Some(Value::Timestamp(time)) => time.with_timezone(&Utc),
+            _ => return Err("That FIT file doesn't have a start time".to_string()),
+        };
+        let meters = fit_number(session, "total_distance").unwrap_or_default();
+
+        let track: Vec<(f64, f64)> = records
+            .iter()
+            .filter(|record| record.kind() == MesgNum::Record)
+            .filter_map(|record| fit_position(record, "position"))
+            .collect();
+        let (latitude, longitude) = fit_position(session, "start_position")
+            .or_else(|| track.first().copied())
+            .ok_or("That FIT file doesn't have a GPS position")?;
+
+        Ok(Self {
+            name,
+            start_at: local_time(start_at),
+            latitude,
+            longitude,
+            miles: meters_to_miles(meters),
+            track,
+        })
+    }
+}
+
+fn local_time(time: DateTime<Utc>) -> NaiveDateTime {
+    time.with_timezone(&New_York).naive_local()
+}
+
+fn meters_to_miles(meters: f64) -> f64 {
+    Kilometers::new(meters / 1000.0).to_miles().value()
+}
+
+fn fit_value<'a>(record: &'a FitDataRecord, name: &str) -> Option<&'a Value> {
+    record
+        .fields()
+        .iter()
+        .find(|field| field.name() == name)
+        .map(|field| field.value())
+}
+
+fn fit_number(record: &FitDataRecord, name: &str) -> Option<f64> {
+    fit_value(record, name).and_then(|value| value.clone().try_into().ok())
+}
+
+fn fit_position(record: &FitDataRecord, prefix: &str) -> Option<(f64, f64)> {
+    let latitude = fit_number(record, &format!("{prefix}_lat"))?;
+    let longitude = fit_number(record, &format!("{prefix}_long"))?;
+    Some((
+        latitude * DEGREES_PER_SEMICIRCLE,
+        longitude * DEGREES_PER_SEMICIRCLE,
+    ))
+}
+
+/// What an activity most likely was: the town it started in and the race
+/// that day, in a town it went through, with the closest distance.
+pub struct ActivityMatch {
+    pub activity: Activity,
+    pub town: Option<Town>,
+    /// Every town the activity went through, in order.
+    pub towns: Vec<Town>,
+    pub race: Option<RaceView>,
+}
+
+impl ActivityMatch {
+    /// `races` should be the races on the day of the activity.
+    pub fn new(activity: Activity, locator: &TownLocator, races: &[RaceView]) -> Self {
+        let town = locator
+            .locate(activity.latitude, activity.longitude)
+            .cloned();
+        let towns: Vec<Town> = locator
+            .towns_along(&activity.track)
+            .into_iter()
+            .cloned()
+            .collect();
+
+        let race = races
+            .iter()
+            .filter(|race| {
+                town.iter()
+                    .chain(towns.iter())
+                    .any(|town| town.id == race.town_id)
+            })
+            .filter(|race| is_about_as_far(activity.miles, race.miles))
+            .min_by(|a, b| {
+                (a.miles - activity.miles)
+                    .abs()
+                    .total_cmp(&(b.miles - activity.miles).abs())
+            })
+            .cloned();
+
+        Self {
+            activity,
+            town,
+            towns,
+            race,
+        }
+    }
+
+    /// The town submission for the matched race, ready to be confirmed.
+    pub fn form(&self) -> Option<SubmitTown> {
+        let race = self.race.as_ref()?;
+
+        Some(SubmitTown {
+            town_id: race.town_id,
+            race_name: race.name.clone(),
+            race_id: race.id,
+            distance_val: race.miles,
+            distance_unit: DistanceUnit::Miles,
+            start_at: race.start_at,
+            notes: None,
+        })
+    }
+}
+
+/// GPS distances wander, so anything within a fifth of the race (or half a
+/// mile for short ones) counts.
+fn is_about_as_far(activity_miles: f64, race_miles: f64) -> bool {
+    (activity_miles - race_miles).abs() <= (race_miles * 0.2).max(0.5)
+}
+
+#[derive(Debug, FromRow)]
+pub struct StravaConnection {
+    pub user_id: i64,
+    pub athlete_id: i64,
+    pub access_token: String,
+    pub refresh_token: String,
+    /// Unix timestamp.
+    pub expires_at: i64,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl StravaConnection {
+    /// Leaves a minute to spare so a token doesn't expire mid-request.
+    pub fn is_expired(&self) -> bool {
+        self.expires_at - 60 <= Utc::now().timestamp()
+    }
+}
+
+#[derive(Deserialize)]
+pub struct StravaAthlete {
+    pub id: i64,
+}
+
+/// Strava's response when exchanging a code or refreshing a token. Only the
+/// code exchange includes the athlete.
+#[derive(Deserialize)]
+pub struct StravaToken {
+    pub access_token: String,
+    pub refresh_token: String,
+    pub expires_at: i64,
+    pub athlete: Option<StravaAthlete>,
+}
+
+/// The parts of Strava's `SummaryActivity` we use.
+#[derive(Deserialize)]
+pub struct StravaActivity {
+    pub name: String,
+    /// Meters.
+    pub distance: f64,
+    pub start_date: DateTime<Utc>,
+    /// Empty for treadmill runs and activities with a hidden start.
+    #[serde(default)]
+    pub start_latlng: Vec<f64>,
+}
+
+impl TryFrom<StravaActivity> for Activity {
+    type Error = String;
+
+    fn try_from(activity: StravaActivity) -> Result<Self, Self::Error> {
+        let [latitude, longitude] = activity.start_latlng[..] else {
+            return Err(format!("{} doesn't have a start point", activity.name));
+        };
+
+        Ok(Self {
+            name: activity.name,
+            start_at: local_time(activity.start_date),
+            latitude,
+            longitude,
+            miles: meters_to_miles(activity.distance),
+            track: vec![(latitude, longitude)],
+        })
+    }
+}
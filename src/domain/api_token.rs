@@ -0,0 +1,174 @@
+use std::str::FromStr;
+
+use chrono::NaiveDateTime;
+use rand::{Rng, distr::Alphanumeric};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sqlx::FromRow;
+
+use crate::{
+    domain::user::UserView,
+    util::{StringExt, validation::Validate},
+};
+
+/// Every personal access token starts with this, so they are easy to spot
+/// (and to tell apart from session JWTs).
+pub const TOKEN_PREFIX: &str = "ctr_";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TokenScope {
+    #[serde(rename = "read:progress")]
+    ReadProgress,
+    #[serde(rename = "write:submissions")]
+    WriteSubmissions,
+    #[serde(rename = "admin")]
+    Admin,
+}
+
+impl TokenScope {
+    pub const ALL: [TokenScope; 3] = [
+        TokenScope::ReadProgress,
+        TokenScope::WriteSubmissions,
+        TokenScope::Admin,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TokenScope::ReadProgress => "read:progress",
+            TokenScope::WriteSubmissions => "write:submissions",
+            TokenScope::Admin => "admin",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            TokenScope::ReadProgress => "Read your completed towns and submissions",
+            TokenScope::WriteSubmissions => "Submit completed towns on your behalf",
+            TokenScope::Admin => "Use admin features",
+        }
+    }
+
+    /// The scopes a user's signed in session has, without any token.
+    pub fn for_session(user: &UserView) -> Vec<TokenScope> {
+        Self::ALL
+            .into_iter()
+            .filter(|scope| *scope != TokenScope::Admin || (user.is_admin() && !user.locked))
+            .collect()
+    }
+}
+
+impl FromStr for TokenScope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|scope| scope.as_str() == s.trim())
+            .ok_or_else(|| format!("Unknown scope: {s}"))
+    }
+}
+
+impl std::fmt::Display for TokenScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow, Clone)]
+pub struct ApiToken {
+    pub id: i64,
+    pub user_id: i64,
+    pub name: String,
+    #[serde(skip)]
+    pub token_hash: String,
+    pub token_prefix: String,
+    pub scopes: String,
+    pub last_used_at: Option<NaiveDateTime>,
+    pub last_used_ip: Option<String>,
+    pub revoked_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl ApiToken {
+    pub fn scopes(&self) -> Vec<TokenScope> {
+        self.scopes
+            .split(',')
+            .filter_map(|scope| TokenScope::from_str(scope).ok())
+            .collect()
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+
+    /// Only the hash of a token is stored, so the secret can be shown once.
+    pub fn hash(secret: &str) -> String {
+        hex::encode(Sha256::digest(secret.as_bytes()))
+    }
+
+    pub fn generate_secret() -> String {
+        let random: String = rand::rng()
+            .sample_iter(&Alphanumeric)
+            .take(40)
+            .map(char::from)
+            .collect();
+        format!("{TOKEN_PREFIX}{random}")
+    }
+}
+
+#[derive(Deserialize, Default)]
+pub struct NewApiTokenForm {
+    pub name: String,
+    #[serde(default)]
+    pub scopes: Vec<TokenScope>,
+}
+
+impl Validate for NewApiTokenForm {
+    fn validate(&self) -> Result<(), String> {
+        if self.name.is_whitespace_or_empty() {
+            return Err("Token name cannot be empty".to_string());
+        }
+
+        if self.name.len() > 50 {
+            return Err("Token name cannot be longer than 50 characters".to_string());
+        }
+
+        if self.scopes.is_empty() {
+            return Err("Pick at least one scope".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+pub struct NewApiToken {
+    pub user_id: i64,
+    pub name: String,
+    pub token_hash: String,
+    pub token_prefix: String,
+    pub scopes: Vec<TokenScope>,
+}
+
+impl NewApiToken {
+    /// Builds the token to store alongside the secret to hand back, once.
+    pub fn generate(user_id: i64, form: NewApiTokenForm) -> (Self, String) {
+        let secret = ApiToken::generate_secret();
+        let token = Self {
+            user_id,
+            name: form.name.trim().to_string(),
+            token_hash: ApiToken::hash(&secret),
+            token_prefix: secret.chars().take(TOKEN_PREFIX.len() + 6).collect(),
+            scopes: form.scopes,
+        };
+        (token, secret)
+    }
+
+    pub fn scopes_str(&self) -> String {
+        self.scopes
+            .iter()
+            .map(TokenScope::as_str)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
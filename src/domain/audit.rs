@@ -0,0 +1,83 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::{domain::user::UserView, util::pagination::Paginatable};
+
+#[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct AuditLog {
+    pub id: i64,
+    pub actor_id: Option<i64>,
+    pub actor_name: Option<String>,
+    pub actor_email: Option<String>,
+    pub action: String,
+    pub entity_type: String,
+    pub entity_id: i64,
+    pub before_json: Option<String>,
+    pub after_json: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+impl Paginatable for AuditLog {
+    fn table_name() -> &'static str {
+        "audit_logs_view"
+    }
+}
+
+/// A single privileged action, recorded before it is written to `audit_logs`.
+///
+/// Example:
+/// ```ignore
+/// let entry = NewAuditLog::new(&admin, "user.update", "user", user.id, &ip)
+///     .before(&user)
+///     .after(&updated);
+/// ```
+pub struct NewAuditLog {
+    pub actor_id: i64,
+    pub action: String,
+    pub entity_type: String,
+    pub entity_id: i64,
+    pub before_json: Option<String>,
+    pub after_json: Option<String>,
+    pub ip_address: Option<String>,
+}
+
+impl NewAuditLog {
+    pub fn new(
+        actor: &UserView,
+        action: &str,
+        entity_type: &str,
+        entity_id: i64,
+        ip_address: &str,
+    ) -> Self {
+        Self {
+            actor_id: actor.id,
+            action: action.to_string(),
+            entity_type: entity_type.to_string(),
+            entity_id,
+            before_json: None,
+            after_json: None,
+            ip_address: Some(ip_address.to_string()),
+        }
+    }
+
+    pub fn before<T: Serialize>(mut self, before: &T) -> Self {
+        self.before_json = serde_json::to_string(before).ok();
+        self
+    }
+
+    pub fn after<T: Serialize>(mut self, after: &T) -> Self {
+        self.after_json = serde_json::to_string(after).ok();
+        self
+    }
+}
+
+#[derive(Deserialize, Default)]
+pub struct AuditLogSearchParams {
+    pub actor: Option<String>,
+    pub entity_type: Option<String>,
+    pub entity_id: Option<i64>,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
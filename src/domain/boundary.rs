@@ -0,0 +1,363 @@
+use serde::Deserialize;
+use sqlx::FromRow;
+
+use crate::domain::Town;
+
+/// How far outside every town a point can be and still count for the
+/// closest one, e.g. a GPS fix that drifted over the water on a shoreline
+/// course.
+const SNAP_MILES: f64 = 0.1;
+
+/// Miles per degree of latitude, close enough at Connecticut's size.
+const MILES_PER_DEGREE: f64 = 69.05;
+
+#[derive(Debug, FromRow)]
+pub struct TownBoundary {
+    pub town_id: i64,
+    /// A GeoJSON `MultiPolygon` of `[longitude, latitude]` pairs.
+    pub geojson: String,
+}
+
+#[derive(Deserialize)]
+struct MultiPolygon {
+    coordinates: Vec<Vec<Vec<[f64; 2]>>>,
+}
+
+/// The rings (outer edges and holes alike) making up a town, as
+/// `(latitude, longitude)` points.
+struct TownArea {
+    town: Town,
+    rings: Vec<Vec<(f64, f64)>>,
+    min: (f64, f64),
+    max: (f64, f64),
+}
+
+impl TownArea {
+    fn new(town: Town, boundary: &TownBoundary) -> Option<Self> {
+        let geometry: MultiPolygon = serde_json::from_str(&boundary.geojson).ok()?;
+        // Anything under 3 points has no inside and no edges worth measuring.
+        let rings: Vec<Vec<(f64, f64)>> = geometry
+            .coordinates
+            .into_iter()
+            .flatten()
+            .filter(|ring| ring.len() >= 3)
+            .map(|ring| ring.into_iter().map(|[lon, lat]| (lat, lon)).collect())
+            .collect();
+        if rings.is_empty() {
+            return None;
+        }
+
+        let points = rings.iter().flatten();
+        let min = points.clone().fold((f64::MAX, f64::MAX), |min, p| {
+            (min.0.min(p.0), min.1.min(p.1))
+        });
+        let max = points.fold((f64::MIN, f64::MIN), |max, p| {
+            (max.0.max(p.0), max.1.max(p.1))
+        });
+
+        Some(Self {
+            town,
+            rings,
+            min,
+            max,
+        })
+    }
+
+    fn might_contain(&self, (lat, lon): (f64, f64), margin: f64) -> bool {
+        lat >= self.min.0 - margin
+            && lat <= self.max.0 + margin
+            && lon >= self.min.1 - margin
+            && lon <= self.max.1 + margin
+    }
+
+    /// Even-odd ray casting, so holes take care of themselves. A point on the
+    /// edge between two towns counts for exactly one of them because the
+    /// crossing test is half-open and neighbours share their border points.
+    fn contains(&self, point: (f64, f64)) -> bool {
+        if !self.might_contain(point, 0.0) {
+            return false;
+        }
+
+        let (lat, lon) = point;
+        let mut inside = false;
+        for ring in &self.rings {
+            let mut previous = ring[ring.len() - 1];
+            for &current in ring {
+                let ((lat_i, lon_i), (lat_j, lon_j)) = (current, previous);
+                if (lat_i > lat) != (lat_j > lat)
+                    && lon < (lon_j - lon_i) * (lat - lat_i) / (lat_j - lat_i) + lon_i
+                {
+                    inside = !inside;
+                }
+                previous = current;
+            }
+        }
+
+        inside
+    }
+
+    /// Miles from the point to the nearest edge of town.
+    fn miles_to_edge(&self, (lat, lon): (f64, f64)) -> f64 {
+        // Flattened around the point, which is plenty over a fraction of a mile.
+        let scale = lat.to_radians().cos();
+        let project = |(p_lat, p_lon): (f64, f64)| {
+            (
+                (p_lon - lon) * scale * MILES_PER_DEGREE,
+                (p_lat - lat) * MILES_PER_DEGREE,
+            )
+        };
+
+        self.rings
+            .iter()
+            .flat_map(|ring| {
+                ring.iter()
+                    .zip(ring.iter().cycle().skip(1))
+                    .map(|(&a, &b)| distance_to_segment(project(a), project(b)))
+            })
+            .fold(f64::MAX, f64::min)
+    }
+}
+
+/// Distance from the origin to the segment between `a` and `b`.
+fn distance_to_segment(a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    let length = dx * dx + dy * dy;
+    let t = if length == 0.0 {
+        0.0
+    } else {
+        (-(a.0 * dx + a.1 * dy) / length).clamp(0.0, 1.0)
+    };
+
+    (a.0 + t * dx).hypot(a.1 + t * dy)
+}
+
+/// Works out which town a GPS point is in from the town boundaries.
+pub struct TownLocator {
+    areas: Vec<TownArea>,
+}
+
+impl TownLocator {
+    /// Towns without a (valid) boundary are left out.
+    pub fn new(towns: Vec<Town>, boundaries: &[TownBoundary]) -> Self {
+        let areas = towns
+            .into_iter()
+            .filter_map(|town| {
+                let boundary = boundaries.iter().find(|b| b.town_id == town.id)?;
+                TownArea::new(town, boundary)
+            })
+            .collect();
+
+        Self { areas }
+    }
+
+    /// The town the point is in. Points just outside every town (within
+    /// `SNAP_MILES`) go to the closest one, and anything further out isn't in
+    /// Connecticut.
+    pub fn locate(&self, latitude: f64, longitude: f64) -> Option<&Town> {
+        self.locate_area(None, (latitude, longitude))
+            .map(|area| &area.town)
+    }
+
+    /// Checks `hint` first, since consecutive track points are nearly always
+    /// in the same town.
+    fn locate_area<'a>(
+        &'a self,
+        hint: Option<&'a TownArea>,
+        point: (f64, f64),
+    ) -> Option<&'a TownArea> {
+        if let Some(area) = hint.filter(|area| area.contains(point)) {
+            return Some(area);
+        }
+
+        if let Some(area) = self.areas.iter().find(|area| area.contains(point)) {
+            return Some(area);
+        }
+
+        let margin = SNAP_MILES / MILES_PER_DEGREE / point.0.to_radians().cos();
+        self.areas
+            .iter()
+            .filter(|area| area.might_contain(point, margin))
+            .map(|area| (area, area.miles_to_edge(point)))
+            .filter(|(_, miles)| *miles <= SNAP_MILES)
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(area, _)| area)
+    }
+
+    /// Every town a track of `(latitude, longitude)` points passes through,
+    /// in the order it first enters them.
+    pub fn towns_along(&self, track: &[(f64, f64)]) -> Vec<&Town> {
+        let mut towns: Vec<&Town> = Vec::new();
+        let mut current: Option<&TownArea> = None;
+
+        for &point in track {
+            current = self.locate_area(current, point);
+            if let Some(area) = current
+                && !towns.iter().any(|town| town.id == area.town.id)
+            {
+                towns.push(&area.town);
+            }
+        }
+
+        towns
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NORTH: f64 = 41.6;
+    const SOUTH: f64 = 41.5;
+    const WEST: f64 = -72.6;
+    const BORDER: f64 = -72.5;
+    const EAST: f64 = -72.4;
+
+    fn town(id: i64, name: &str) -> Town {
+        Town {
+            id,
+            name: name.to_string(),
+            county_id: 1,
+            county: "Hartford".to_string(),
+            is_elusive: false,
+            latitude: None,
+            longitude: None,
+        }
+    }
+
+    /// A town made of `rings` of `(latitude, longitude)` points.
+    fn boundary(town_id: i64, rings: &[&[(f64, f64)]]) -> TownBoundary {
+        let polygon: Vec<Vec<[f64; 2]>> = rings
+            .iter()
+            .map(|ring| ring.iter().map(|&(lat, lon)| [lon, lat]).collect())
+            .collect();
+        TownBoundary {
+            town_id,
+            geojson: serde_json::json!({ "type": "MultiPolygon", "coordinates": [polygon] })
+                .to_string(),
+        }
+    }
+
+    fn square(town_id: i64, west: f64, east: f64) -> TownBoundary {
+        boundary(
+            town_id,
+            &[&[
+                (SOUTH, west),
+                (NORTH, west),
+                (NORTH, east),
+                (SOUTH, east),
+                (SOUTH, west),
+            ]],
+        )
+    }
+
+    /// Westville and Eastville, side by side along `BORDER`.
+    fn neighbours() -> TownLocator {
+        TownLocator::new(
+            vec![town(1, "Westville"), town(2, "Eastville")],
+            &[square(1, WEST, BORDER), square(2, BORDER, EAST)],
+        )
+    }
+
+    /// Degrees of longitude `miles` away at the middle of the towns.
+    fn miles_of_longitude(miles: f64) -> f64 {
+        let lat: f64 = (NORTH + SOUTH) / 2.0;
+        miles / MILES_PER_DEGREE / lat.to_radians().cos()
+    }
+
+    fn names(towns: Vec<&Town>) -> Vec<&str> {
+        towns.iter().map(|town| town.name.as_str()).collect()
+    }
+
+    #[test]
+    fn points_inside_a_town() {
+        let locator = neighbours();
+        assert_eq!(locator.locate(41.55, -72.55).unwrap().name, "Westville");
+        assert_eq!(locator.locate(41.55, -72.45).unwrap().name, "Eastville");
+    }
+
+    #[test]
+    fn points_on_a_shared_edge_count_for_exactly_one_town() {
+        let locator = neighbours();
+        for point in [(41.55, BORDER), (41.5001, BORDER), (41.5999, BORDER)] {
+            let containing = locator
+                .areas
+                .iter()
+                .filter(|area| area.contains(point))
+                .count();
+            assert_eq!(containing, 1, "{point:?}");
+            assert!(locator.locate(point.0, point.1).is_some(), "{point:?}");
+        }
+    }
+
+    #[test]
+    fn points_just_outside_snap_to_the_closest_town() {
+        let locator = neighbours();
+        let near = WEST - miles_of_longitude(SNAP_MILES / 2.0);
+        assert_eq!(locator.locate(41.55, near).unwrap().name, "Westville");
+
+        let far = WEST - miles_of_longitude(SNAP_MILES * 2.0);
+        assert!(locator.locate(41.55, far).is_none());
+
+        let near = EAST + miles_of_longitude(SNAP_MILES / 2.0);
+        assert_eq!(locator.locate(41.55, near).unwrap().name, "Eastville");
+    }
+
+    #[test]
+    fn the_hint_wins_where_towns_overlap() {
+        // Badly drawn boundaries, where Eastville takes in a strip of Westville.
+        let overlap = BORDER - 0.02;
+        let locator = TownLocator::new(
+            vec![town(1, "Westville"), town(2, "Eastville")],
+            &[square(1, WEST, BORDER), square(2, overlap, EAST)],
+        );
+        let point = (41.55, BORDER - 0.01);
+
+        assert_eq!(locator.locate(point.0, point.1).unwrap().name, "Westville");
+
+        let eastville = locator.areas.iter().find(|area| area.town.id == 2);
+        let found = locator.locate_area(eastville, point).unwrap();
+        assert_eq!(found.town.name, "Eastville");
+    }
+
+    #[test]
+    fn tracks_list_each_town_once_in_order() {
+        let locator = neighbours();
+        let track = [
+            (41.55, -72.45),
+            (41.55, -72.49),
+            (41.55, BORDER),
+            (41.55, -72.51),
+            (41.55, -72.55),
+            (41.55, -72.45),
+            // Wandered off out of state.
+            (41.55, -72.0),
+        ];
+
+        assert_eq!(
+            names(locator.towns_along(&track)),
+            vec!["Eastville", "Westville"]
+        );
+    }
+
+    #[test]
+    fn rings_too_short_to_be_an_area_are_skipped() {
+        let ring: &[(f64, f64)] = &[
+            (SOUTH, WEST),
+            (NORTH, WEST),
+            (NORTH, BORDER),
+            (SOUTH, BORDER),
+            (SOUTH, WEST),
+        ];
+        let locator = TownLocator::new(
+            vec![town(1, "Westville"), town(2, "Nowhere")],
+            &[
+                boundary(1, &[&[], &[(SOUTH, WEST), (NORTH, WEST)], ring]),
+                boundary(2, &[&[], &[(SOUTH, EAST)]]),
+            ],
+        );
+
+        assert_eq!(locator.areas.len(), 1);
+        assert_eq!(locator.locate(41.55, -72.55).unwrap().name, "Westville");
+        assert!(locator.locate(41.55, -72.45).is_none());
+    }
+}
@@ -1,6 +1,19 @@
 use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Straight line (haversine) miles between two latitude/longitude points.
+pub fn miles_between(from: (f64, f64), to: (f64, f64)) -> f64 {
+    const EARTH_RADIUS_MILES: f64 = 3958.8;
+
+    let (lat1, lon1) = (from.0.to_radians(), from.1.to_radians());
+    let (lat2, lon2) = (to.0.to_radians(), to.1.to_radians());
+
+    let a = ((lat2 - lat1) / 2.0).sin().powi(2)
+        + lat1.cos() * lat2.cos() * ((lon2 - lon1) / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_MILES * a.sqrt().asin()
+}
 
 #[derive(Debug)]
 pub struct Miles(f64);
@@ -75,7 +88,7 @@ impl Kilometers {
     }
 }
 
-#[derive(Default, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[derive(Default, Serialize, Deserialize, PartialEq, Eq, Clone, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum DistanceUnit {
     #[default]
@@ -91,3 +104,101 @@ impl std::fmt::Display for DistanceUnit {
         }
     }
 }
+
+/// The usual race distances to filter by. Some are metric and some are
+/// imperial, so each covers a little either side of the distance to allow
+/// for how it was rounded when saved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum DistanceBucket {
+    #[serde(rename = "1mi")]
+    OneMile,
+    #[serde(rename = "5k")]
+    FiveK,
+    #[serde(rename = "5mi")]
+    FiveMiles,
+    #[serde(rename = "10k")]
+    TenK,
+    #[serde(rename = "10mi")]
+    TenMiles,
+    #[serde(rename = "half")]
+    HalfMarathon,
+    #[serde(rename = "marathon")]
+    Marathon,
+    /// Anything longer than a marathon.
+    #[serde(rename = "ultra")]
+    Ultra,
+}
+
+impl DistanceBucket {
+    pub const ALL: [DistanceBucket; 8] = [
+        DistanceBucket::OneMile,
+        DistanceBucket::FiveK,
+        DistanceBucket::FiveMiles,
+        DistanceBucket::TenK,
+        DistanceBucket::TenMiles,
+        DistanceBucket::HalfMarathon,
+        DistanceBucket::Marathon,
+        DistanceBucket::Ultra,
+    ];
+
+    /// How far races can be off the distance and still count.
+    const TOLERANCE: f64 = 0.03;
+
+    fn distance(&self) -> (f64, DistanceUnit) {
+        match self {
+            DistanceBucket::OneMile => (1.0, DistanceUnit::Miles),
+            DistanceBucket::FiveK => (5.0, DistanceUnit::Kilometers),
+            DistanceBucket::FiveMiles => (5.0, DistanceUnit::Miles),
+            DistanceBucket::TenK => (10.0, DistanceUnit::Kilometers),
+            DistanceBucket::TenMiles => (10.0, DistanceUnit::Miles),
+            DistanceBucket::HalfMarathon | DistanceBucket::Ultra => {
+                (21.0975, DistanceUnit::Kilometers)
+            }
+            DistanceBucket::Marathon => (42.195, DistanceUnit::Kilometers),
+        }
+    }
+
+    /// The shortest and longest race, in miles, that fall in the bucket.
+    pub fn range(&self) -> (f64, f64) {
+        if *self == DistanceBucket::Ultra {
+            return (DistanceBucket::Marathon.range().1, f64::MAX);
+        }
+
+        let miles = match self.distance() {
+            (distance, DistanceUnit::Miles) => distance,
+            (distance, DistanceUnit::Kilometers) => distance / 1.60934,
+        };
+        (
+            miles * (1.0 - Self::TOLERANCE),
+            miles * (1.0 + Self::TOLERANCE),
+        )
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DistanceBucket::OneMile => "1 Mile",
+            DistanceBucket::FiveK => "5K",
+            DistanceBucket::FiveMiles => "5 Miles",
+            DistanceBucket::TenK => "10K",
+            DistanceBucket::TenMiles => "10 Miles",
+            DistanceBucket::HalfMarathon => "Half Marathon",
+            DistanceBucket::Marathon => "Marathon",
+            DistanceBucket::Ultra => "Ultra",
+        }
+    }
+}
+
+impl std::fmt::Display for DistanceBucket {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DistanceBucket::OneMile => write!(f, "1mi"),
+            DistanceBucket::FiveK => write!(f, "5k"),
+            DistanceBucket::FiveMiles => write!(f, "5mi"),
+            DistanceBucket::TenK => write!(f, "10k"),
+            DistanceBucket::TenMiles => write!(f, "10mi"),
+            DistanceBucket::HalfMarathon => write!(f, "half"),
+            DistanceBucket::Marathon => write!(f, "marathon"),
+            DistanceBucket::Ultra => write!(f, "ultra"),
+        }
+    }
+}
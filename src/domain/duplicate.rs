@@ -0,0 +1,282 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::domain::race::RaceView;
+
+/// How many days apart two races in the same town can be and still be the
+/// same race, e.g. a member who typed the wrong day.
+pub const DUPLICATE_WINDOW_DAYS: i64 = 2;
+
+/// How alike two races have to be before an admin is asked about them.
+pub const SUGGEST_SCORE: f64 = 0.75;
+
+/// Words that say nothing about which race it is.
+const FILLER_WORDS: [&str; 5] = ["the", "annual", "race", "run", "and"];
+
+/// Two races in the same town close enough in time to be the same race.
+#[derive(Debug, FromRow)]
+pub struct RacePair {
+    /// Always the lower of the two ids.
+    pub race_id: i64,
+    pub race_name: String,
+    pub race_miles: f64,
+    pub race_town_id: i64,
+    pub race_start_at: NaiveDateTime,
+    pub duplicate_race_id: i64,
+    pub duplicate_name: String,
+    pub duplicate_miles: f64,
+    pub duplicate_town_id: i64,
+    pub duplicate_start_at: NaiveDateTime,
+}
+
+impl RacePair {
+    /// From 0 to 1. Names count for everything, but the distance has to
+    /// match, since a 5K and 10K on the same morning are different races.
+    /// Races in different towns, or too far apart, are never the same.
+    pub fn score(&self) -> f64 {
+        let apart = (self.race_start_at - self.duplicate_start_at).abs();
+        if self.race_town_id != self.duplicate_town_id
+            || apart > chrono::Duration::days(DUPLICATE_WINDOW_DAYS)
+        {
+            return 0.0;
+        }
+
+        let names = strsim::normalized_levenshtein(
+            &normalize_name(&self.race_name),
+            &normalize_name(&self.duplicate_name),
+        );
+
+        names * distance_similarity(self.race_miles, self.duplicate_miles)
+    }
+}
+
+/// Lowercase letters and digits only, without years, ordinals ("41st"),
+/// distances ("5k", "10 mile") or filler words, so "The 41st Annual Oak
+/// Leaf 5K" and "oakleaf" come out the same.
+pub fn normalize_name(name: &str) -> String {
+    let name = name.to_lowercase().replace(['-', '/', '&'], " ");
+    let words: Vec<&str> = name
+        .split_whitespace()
+        .map(|word| word.trim_matches(|c: char| !c.is_alphanumeric()))
+        .collect();
+
+    let mut normalized = String::new();
+    let mut words = words.iter().peekable();
+    while let Some(word) = words.next() {
+        let is_number = !word.is_empty() && word.chars().all(|c| c.is_ascii_digit() || c == '.');
+        if is_number && words.peek().is_some_and(|next| is_distance_unit(next)) {
+            words.next();
+            continue;
+        }
+
+        if word.is_empty() || is_number || is_ordinal(word) || is_distance(word) {
+            continue;
+        }
+
+        if FILLER_WORDS.contains(word) {
+            continue;
+        }
+
+        normalized.extend(word.chars().filter(|c| c.is_alphanumeric()));
+    }
+
+    normalized
+}
+
+fn is_distance_unit(word: &str) -> bool {
+    matches!(
+        word,
+        "k" | "km" | "mi" | "mile" | "miles" | "miler" | "m" | "marathon"
+    )
+}
+
+/// e.g. "5k", "3.1mi" or "10miler".
+fn is_distance(word: &str) -> bool {
+    let unit = word.trim_start_matches(|c: char| c.is_ascii_digit() || c == '.');
+    unit.len() < word.len() && is_distance_unit(unit)
+}
+
+/// e.g. "1st", "22nd" or "41st".
+fn is_ordinal(word: &str) -> bool {
+    let suffix = word.trim_start_matches(|c: char| c.is_ascii_digit());
+    suffix.len() < word.len() && matches!(suffix, "st" | "nd" | "rd" | "th")
+}
+
+/// 1 within a tenth of a mile (a 5K is 3.1 miles, or "3 miles" to some),
+/// falling to 0 once they're 10% apart.
+fn distance_similarity(a: f64, b: f64) -> f64 {
+    // To the hundredth, as 3.1 - 3.0 is a hair over 0.1 in floating point.
+    let difference = ((a - b).abs() * 100.0).round() / 100.0;
+    if difference <= 0.1 {
+        return 1.0;
+    }
+
+    (1.0 - difference / (a.max(b) * 0.1)).max(0.0)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type, Serialize, Deserialize)]
+#[sqlx(type_name = "TEXT")]
+#[sqlx(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum DuplicateStatus {
+    Pending,
+    /// Merged by an admin.
+    Accepted,
+    /// Not the same race after all, so it isn't suggested again.
+    Dismissed,
+}
+
+/// A suggested merge waiting in the admin queue.
+#[derive(Debug, FromRow, Serialize)]
+pub struct RaceDuplicate {
+    pub id: i64,
+    pub race_id: i64,
+    pub duplicate_race_id: i64,
+    pub score: f64,
+    /// RSVPs and results on each race, to suggest which one to keep.
+    pub race_members: i64,
+    pub duplicate_members: i64,
+}
+
+/// A suggestion with both races, for showing side by side.
+pub struct RaceDuplicateView {
+    pub id: i64,
+    pub score: f64,
+    /// The race to keep, i.e. the one with more members (or the older one).
+    pub keep: RaceView,
+    pub keep_members: i64,
+    pub merge: RaceView,
+    pub merge_members: i64,
+}
+
+impl RaceDuplicateView {
+    pub fn new(duplicate: &RaceDuplicate, race: RaceView, other: RaceView) -> Self {
+        let keep_race = duplicate.race_members >= duplicate.duplicate_members;
+        let (keep, keep_members, merge, merge_members) = match keep_race {
+            true => (
+                race,
+                duplicate.race_members,
+                other,
+                duplicate.duplicate_members,
+            ),
+            false => (
+                other,
+                duplicate.duplicate_members,
+                race,
+                duplicate.race_members,
+            ),
+        };
+
+        Self {
+            id: duplicate.id,
+            score: duplicate.score,
+            keep,
+            keep_members,
+            merge,
+            merge_members,
+        }
+    }
+
+    pub fn percent(&self) -> i64 {
+        (self.score * 100.0).round() as i64
+    }
+}
+
+#[derive(Deserialize)]
+pub struct AcceptDuplicateForm {
+    pub keep_race_id: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(day: u32, hour: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 5, day)
+            .and_then(|date| date.and_hms_opt(hour, 0, 0))
+            .unwrap()
+    }
+
+    fn pair(race: &str, duplicate: &str) -> RacePair {
+        RacePair {
+            race_id: 1,
+            race_name: race.to_string(),
+            race_miles: 3.1,
+            race_town_id: 5,
+            race_start_at: at(2, 9),
+            duplicate_race_id: 2,
+            duplicate_name: duplicate.to_string(),
+            duplicate_miles: 3.1,
+            duplicate_town_id: 5,
+            duplicate_start_at: at(2, 9),
+        }
+    }
+
+    #[test]
+    fn normalizes_away_years_ordinals_distances_and_filler() {
+        assert_eq!(normalize_name("The 41st Annual Oak Leaf 5K"), "oakleaf");
+        assert_eq!(normalize_name("Oak-Leaf 10 Mile Run 2026"), "oakleaf");
+        assert_eq!(normalize_name("oakleaf"), "oakleaf");
+    }
+
+    #[test]
+    fn suggests_the_same_race_on_the_same_day() {
+        let pair = pair("The 41st Annual Oak Leaf 5K", "Oak Leaf");
+        assert_eq!(pair.score(), 1.0);
+
+        let later_that_day = RacePair {
+            duplicate_start_at: at(2, 17),
+            ..pair
+        };
+        assert!(later_that_day.score() >= SUGGEST_SCORE);
+    }
+
+    #[test]
+    fn different_names_fall_below_the_threshold() {
+        assert!(pair("Oak Leaf 5K", "Turkey Trot").score() < SUGGEST_SCORE);
+        assert!(pair("Oak Leaf", "Oak Hill").score() < SUGGEST_SCORE);
+        assert!(pair("Oak Leaf", "Oak Leef").score() >= SUGGEST_SCORE);
+    }
+
+    #[test]
+    fn different_distances_are_different_races() {
+        let pair = RacePair {
+            duplicate_miles: 6.2,
+            ..pair("Oak Leaf", "Oak Leaf")
+        };
+        assert_eq!(pair.score(), 0.0);
+
+        // "3 miles" for a 5K is close enough.
+        let pair = RacePair {
+            duplicate_miles: 3.0,
+            ..pair
+        };
+        assert_eq!(pair.score(), 1.0);
+    }
+
+    #[test]
+    fn races_in_different_towns_are_never_the_same() {
+        let pair = RacePair {
+            duplicate_town_id: 6,
+            ..pair("Oak Leaf", "Oak Leaf")
+        };
+        assert_eq!(pair.score(), 0.0);
+    }
+
+    #[test]
+    fn races_outside_the_window_are_never_the_same() {
+        let within = RacePair {
+            duplicate_start_at: at(4, 9),
+            ..pair("Oak Leaf", "Oak Leaf")
+        };
+        assert_eq!(within.score(), 1.0);
+
+        let outside = RacePair {
+            duplicate_start_at: at(4, 10),
+            ..within
+        };
+        assert_eq!(outside.score(), 0.0);
+    }
+}
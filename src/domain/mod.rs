@@ -1,9 +1,20 @@
+pub mod activity;
+pub mod api_token;
+pub mod audit;
+pub mod boundary;
 pub mod distance;
+pub mod duplicate;
 pub mod google_sheet;
+pub mod notification;
+pub mod progress;
+pub mod push;
 pub mod race;
 pub mod rbac;
+pub mod search;
+pub mod submission;
 pub mod town;
 pub mod user;
+pub mod webhook;
 
 pub use race::Race;
 pub use town::Town;
@@ -0,0 +1,107 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::NaiveDateTime;
+use rand::{Rng, distr::Alphanumeric};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::{
+    domain::{Town, race::RaceView},
+    util::validation::Validate,
+};
+
+/// The radius choices offered on the preferences page.
+pub const RADIUS_OPTIONS: [i64; 4] = [10, 25, 50, 75];
+
+#[derive(Debug, Serialize, Deserialize, FromRow, Clone)]
+pub struct NotificationPreferences {
+    pub user_id: i64,
+    pub weekly_digest: bool,
+    pub instant_alerts: bool,
+    pub radius_miles: Option<i64>,
+    #[serde(skip)]
+    pub unsubscribe_token: String,
+    pub last_digest_at: Option<NaiveDateTime>,
+    pub push_race_reminders: bool,
+    pub push_milestones: bool,
+    pub push_submission_failures: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl NotificationPreferences {
+    pub fn generate_token() -> String {
+        rand::rng()
+            .sample_iter(&Alphanumeric)
+            .take(32)
+            .map(char::from)
+            .collect()
+    }
+
+    pub fn is_subscribed(&self) -> bool {
+        self.weekly_digest || self.instant_alerts
+    }
+}
+
+#[derive(Deserialize)]
+pub struct UpdateNotificationPreferences {
+    #[serde(default)]
+    pub weekly_digest: bool,
+    #[serde(default)]
+    pub instant_alerts: bool,
+    pub radius_miles: Option<i64>,
+    #[serde(default)]
+    pub push_race_reminders: bool,
+    #[serde(default)]
+    pub push_milestones: bool,
+    #[serde(default)]
+    pub push_submission_failures: bool,
+}
+
+impl Validate for UpdateNotificationPreferences {
+    fn validate(&self) -> Result<(), String> {
+        match self.radius_miles {
+            Some(radius) if !(1..=200).contains(&radius) => {
+                Err("Radius must be between 1 and 200 miles".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A member who wants race emails, with what we need to pick their races.
+#[derive(Debug, FromRow)]
+pub struct NotificationRecipient {
+    pub user_id: i64,
+    pub email: String,
+    pub first_name: String,
+    pub hometown_id: Option<i64>,
+    pub radius_miles: Option<i64>,
+    pub unsubscribe_token: String,
+    pub last_digest_at: Option<NaiveDateTime>,
+}
+
+impl NotificationRecipient {
+    /// The races in towns the member still needs, and close enough to home
+    /// when they have set a radius (and a hometown to measure it from).
+    pub fn races_of_interest<'a>(
+        &self,
+        races: &'a [RaceView],
+        completed_town_ids: &HashSet<i64>,
+        towns: &HashMap<i64, Town>,
+    ) -> Vec<&'a RaceView> {
+        let hometown = self.hometown_id.and_then(|id| towns.get(&id));
+
+        races
+            .iter()
+            .filter(|race| !completed_town_ids.contains(&race.town_id))
+            .filter(|race| match (self.radius_miles, hometown) {
+                (Some(radius), Some(hometown)) => towns
+                    .get(&race.town_id)
+                    .and_then(|town| hometown.miles_to(town))
+                    .is_none_or(|miles| miles <= radius as f64),
+                _ => true,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,36 @@
+use chrono::NaiveDateTime;
+use sqlx::FromRow;
+
+/// How far a member has got, kept alongside `completed_towns` so it doesn't
+/// have to be counted every time.
+#[derive(Debug, Default, FromRow)]
+pub struct UserProgress {
+    pub user_id: i64,
+    pub completed_count: i64,
+    pub elusive_count: i64,
+    pub last_completed_at: Option<NaiveDateTime>,
+}
+
+/// A stored counter that doesn't match what `completed_towns` says, e.g.
+/// after a completion was added by hand.
+#[derive(Debug, FromRow)]
+pub struct ProgressDrift {
+    pub user_id: i64,
+    /// Which counter, e.g. `elusive_count` or `county:Tolland`.
+    pub counter: String,
+    pub stored: Option<String>,
+    pub actual: Option<String>,
+}
+
+impl std::fmt::Display for ProgressDrift {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "user {} {}: stored {}, actually {}",
+            self.user_id,
+            self.counter,
+            self.stored.as_deref().unwrap_or("nothing"),
+            self.actual.as_deref().unwrap_or("nothing")
+        )
+    }
+}
@@ -0,0 +1,126 @@
+use chrono::NaiveDateTime;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::{
+    domain::{Town, race::RaceView},
+    util::{StringExt, validation::Validate},
+};
+
+/// Completed town counts worth a celebration.
+pub const MILESTONES: [i64; 9] = [1, 10, 25, 50, 75, 100, 125, 150, 169];
+
+/// The kinds of push a member can opt in to, one preference column each.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PushKind {
+    RaceReminder,
+    Milestone,
+    SubmissionFailed,
+}
+
+impl PushKind {
+    pub fn preference_column(&self) -> &'static str {
+        match self {
+            PushKind::RaceReminder => "push_race_reminders",
+            PushKind::Milestone => "push_milestones",
+            PushKind::SubmissionFailed => "push_submission_failures",
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow, Clone)]
+pub struct PushSubscription {
+    pub id: i64,
+    pub user_id: i64,
+    pub endpoint: String,
+    pub p256dh: String,
+    pub auth: String,
+    pub user_agent: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Deserialize)]
+pub struct PushSubscriptionKeys {
+    pub p256dh: String,
+    pub auth: String,
+}
+
+/// What the browser's `PushSubscription.toJSON()` posts to us.
+#[derive(Deserialize)]
+pub struct NewPushSubscription {
+    pub endpoint: String,
+    pub keys: PushSubscriptionKeys,
+}
+
+impl Validate for NewPushSubscription {
+    fn validate(&self) -> Result<(), String> {
+        let url = Url::parse(&self.endpoint).map_err(|_| "That is not a valid endpoint")?;
+        if url.scheme() != "https" {
+            return Err("Push endpoints must use https".to_string());
+        }
+
+        if self.keys.p256dh.is_whitespace_or_empty() || self.keys.auth.is_whitespace_or_empty() {
+            return Err("The subscription is missing its keys".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Deserialize)]
+pub struct RemovePushSubscription {
+    pub endpoint: String,
+}
+
+/// The JSON the service worker turns into a notification.
+#[derive(Debug, Serialize)]
+pub struct PushMessage {
+    pub title: String,
+    pub body: String,
+    /// Opened when the notification is clicked.
+    pub url: String,
+    /// A newer notification with the same tag replaces the older one.
+    pub tag: String,
+}
+
+impl PushMessage {
+    pub fn race_reminder(race: &RaceView) -> Self {
+        Self {
+            title: format!("{} is tomorrow", race.name),
+            body: format!(
+                "{} miles in {} at {}. Good luck!",
+                race.miles,
+                race.town,
+                race.start_at.format("%-I:%M %p")
+            ),
+            url: format!("/races/{}", race.id),
+            tag: format!("race-{}", race.id),
+        }
+    }
+
+    pub fn milestone(completed: i64) -> Self {
+        let title = match completed {
+            1 => "Your first town!".to_string(),
+            169 => "You've run all 169 towns!".to_string(),
+            count => format!("{count} towns down!"),
+        };
+
+        Self {
+            title,
+            body: format!("{} to go. Keep it up!", 169 - completed),
+            url: "/dashboard".to_string(),
+            tag: format!("milestone-{completed}"),
+        }
+    }
+
+    pub fn submission_failed(town: &Town, error: &str) -> Self {
+        Self {
+            title: format!("Your {} submission didn't go through", town.name),
+            body: error.to_string(),
+            url: "/dashboard".to_string(),
+            tag: format!("submission-{}", town.id),
+        }
+    }
+}
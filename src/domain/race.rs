@@ -2,19 +2,24 @@ use std::str::FromStr;
 
 use crate::{
     domain::google_sheet::GoogleSheet,
-    util::{StringExt, parse_no_seconds},
+    util::{StringExt, parse_no_seconds, parse_no_seconds_list, validation::Validate},
 };
-use chrono::{NaiveDateTime, Utc};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Utc};
 use chrono_tz::America::New_York;
+use reqwest::Url;
 use serde::{Deserialize, Serialize};
 use sqlx::prelude::FromRow;
+use utoipa::{IntoParams, ToSchema};
 
 use crate::{
     domain::{
-        distance::{DistanceUnit, Miles},
+        distance::{DistanceBucket, DistanceUnit, Miles},
         town::SubmitTown,
     },
-    util::pagination::{Paginatable, Pagination},
+    util::{
+        filter::Direction,
+        pagination::{Paginatable, Pagination},
+    },
 };
 
 #[derive(Debug, Deserialize, Serialize, FromRow)]
@@ -29,10 +34,46 @@ pub struct Race {
     pub updated_at: chrono::NaiveDateTime,
 }
 
-#[derive(Debug, Deserialize, Serialize, FromRow)]
+/// Where a race is in moderation. Only approved races are shown to members.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type, Serialize, Deserialize, ToSchema)]
+#[sqlx(type_name = "TEXT")]
+#[sqlx(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum RaceStatus {
+    /// Added by a member and waiting on Royalty or an Admin.
+    Pending,
+    Approved,
+    Rejected,
+    /// Turned out to be a race we already had, see `merged_into_id`.
+    Merged,
+}
+
+impl RaceStatus {
+    pub const ALL: [RaceStatus; 4] = [
+        RaceStatus::Pending,
+        RaceStatus::Approved,
+        RaceStatus::Rejected,
+        RaceStatus::Merged,
+    ];
+}
+
+impl std::fmt::Display for RaceStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RaceStatus::Pending => write!(f, "pending"),
+            RaceStatus::Approved => write!(f, "approved"),
+            RaceStatus::Rejected => write!(f, "rejected"),
+            RaceStatus::Merged => write!(f, "merged"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, FromRow, ToSchema)]
 pub struct RaceView {
     pub id: i64,
     pub town_id: i64,
+    /// The event it's one of the distances of.
+    pub event_id: Option<i64>,
     pub name: String,
     pub town: String,
     pub county: String,
@@ -40,6 +81,8 @@ pub struct RaceView {
     pub miles: f64,
     pub race_url: Option<String>,
     pub start_at: chrono::NaiveDateTime,
+    pub status: RaceStatus,
+    pub deleted_at: Option<chrono::NaiveDateTime>,
     pub created_at: chrono::NaiveDateTime,
     pub updated_at: chrono::NaiveDateTime,
 }
@@ -50,22 +93,268 @@ impl Paginatable for RaceView {
     }
 }
 
+impl RaceView {
+    pub fn kilometers(&self) -> f64 {
+        Miles::new(self.miles).to_kilometers().value()
+    }
+
+    pub fn is_upcoming(&self) -> bool {
+        self.start_at > Utc::now().with_timezone(&New_York).naive_local()
+    }
+
+    pub fn is_approved(&self) -> bool {
+        self.status == RaceStatus::Approved
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    /// Whether members can see it, i.e. approved and not removed.
+    pub fn is_listed(&self) -> bool {
+        self.is_approved() && !self.is_deleted()
+    }
+}
+
+/// A race day in a town, e.g. the Turkey Trot, which can have several
+/// distances. Only events with a distance members can see are included.
+#[derive(Debug, Serialize, FromRow)]
+pub struct RaceEvent {
+    pub id: i64,
+    pub town_id: i64,
+    pub name: String,
+    pub event_date: NaiveDate,
+    pub race_url: Option<String>,
+    pub town: String,
+    pub county: String,
+    pub is_elusive: bool,
+    /// When its first distance starts.
+    pub start_at: NaiveDateTime,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl Paginatable for RaceEvent {
+    fn table_name() -> &'static str {
+        "race_events_view"
+    }
+}
+
+/// An event with its distances, in the order they start.
+pub struct RaceEventGroup {
+    pub event: RaceEvent,
+    pub races: Vec<RaceView>,
+}
+
+/// A race a member added, with where it is in moderation.
+#[derive(Debug, FromRow)]
+pub struct RaceSubmission {
+    #[sqlx(flatten)]
+    pub race: RaceView,
+    pub submitted_by: Option<i64>,
+    pub submitter_name: Option<String>,
+    pub reviewed_at: Option<NaiveDateTime>,
+    pub rejection_reason: Option<String>,
+    pub merged_into_id: Option<i64>,
+}
+
+impl RaceSubmission {
+    pub fn is_pending(&self) -> bool {
+        self.race.status == RaceStatus::Pending
+    }
+}
+
+/// A member who RSVP'd to or finished a race.
+#[derive(Debug, FromRow)]
+pub struct RaceMember {
+    pub user_id: i64,
+    pub runner_id: Option<i64>,
+    pub full_name: String,
+    pub image_url: String,
+    /// What a finisher wrote when they submitted the town.
+    pub notes: Option<String>,
+}
+
+/// One year's running of a race.
+#[derive(Debug, FromRow)]
+pub struct RaceEdition {
+    pub id: i64,
+    pub miles: f64,
+    pub start_at: NaiveDateTime,
+    pub finishers: i64,
+    pub rsvps: i64,
+}
+
+impl RaceEdition {
+    pub fn year(&self) -> i32 {
+        self.start_at.year()
+    }
+
+    pub fn attendance(&self) -> i64 {
+        self.finishers.max(self.rsvps)
+    }
+}
+
+/// Every edition of a recurring race, i.e. races with the same name in the
+/// same town, oldest first.
+pub struct RaceSeries {
+    pub editions: Vec<RaceEdition>,
+}
+
+impl RaceSeries {
+    /// The closest edition from an earlier year, preferring the same distance.
+    pub fn previous(&self, race: &RaceView) -> Option<&RaceEdition> {
+        let year = race.start_at.year();
+        self.closest(race, self.editions.iter().rev().filter(|e| e.year() < year))
+    }
+
+    /// The closest edition from a later year, preferring the same distance.
+    pub fn next(&self, race: &RaceView) -> Option<&RaceEdition> {
+        let year = race.start_at.year();
+        self.closest(race, self.editions.iter().filter(|e| e.year() > year))
+    }
+
+    fn closest<'a>(
+        &self,
+        race: &RaceView,
+        editions: impl Iterator<Item = &'a RaceEdition>,
+    ) -> Option<&'a RaceEdition> {
+        let editions: Vec<&RaceEdition> = editions.collect();
+        let year = editions.first()?.year();
+        let same_year = editions.iter().take_while(|e| e.year() == year);
+
+        same_year
+            .clone()
+            .find(|e| e.miles == race.miles)
+            .or_else(|| same_year.clone().next())
+            .copied()
+    }
+
+    /// The most anyone has turned out for an edition, for scaling the trend.
+    pub fn max_attendance(&self) -> i64 {
+        self.editions
+            .iter()
+            .map(RaceEdition::attendance)
+            .max()
+            .unwrap_or_default()
+            .max(1)
+    }
+
+    /// How an edition's turnout compares with the edition before it.
+    pub fn change(&self, index: &usize) -> Option<i64> {
+        let previous = self.editions.get(index.checked_sub(1)?)?;
+        Some(self.editions.get(*index)?.attendance() - previous.attendance())
+    }
+}
+
+/// Everything shown on a race's page.
+pub struct RaceDetails {
+    pub race: RaceView,
+    pub finishers: Vec<RaceMember>,
+    pub rsvps: Vec<RaceMember>,
+    pub series: RaceSeries,
+}
+
 #[derive(Deserialize)]
 pub struct SubmitTownSearchParams {
     pub race_name: String,
     pub town_id: i64,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, IntoParams, Default)]
+#[into_params(parameter_in = Query)]
 pub struct RaceSearchParams {
     pub race_name: Option<String>,
     pub town_id: Option<i64>,
+    pub county_id: Option<i64>,
+    /// Races on or after this day.
+    pub from: Option<NaiveDate>,
+    /// Races on or before this day.
+    pub to: Option<NaiveDate>,
+    /// Only races on a Saturday or Sunday.
+    pub weekends: Option<bool>,
+    pub distance: Option<DistanceBucket>,
+    /// Only races in elusive towns.
+    pub elusive: Option<bool>,
+    /// Only towns the signed-in member hasn't completed.
+    pub not_completed: Option<bool>,
+    pub sort: Option<RaceSort>,
+    pub page: Option<i64>,
+    /// From 1 to 100, 15 by default.
+    pub page_size: Option<i64>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum RaceSort {
+    #[default]
+    Soonest,
+    Latest,
+    Name,
+    Town,
+}
+
+impl RaceSort {
+    pub const ALL: [RaceSort; 4] = [
+        RaceSort::Soonest,
+        RaceSort::Latest,
+        RaceSort::Name,
+        RaceSort::Town,
+    ];
+
+    /// The columns to sort by, ending with `id` so races on the same day
+    /// always come back in the same order.
+    pub fn order_by(&self) -> &'static [(&'static str, Direction)] {
+        match self {
+            RaceSort::Soonest => &[("start_at", Direction::Asc), ("id", Direction::Asc)],
+            RaceSort::Latest => &[("start_at", Direction::Desc), ("id", Direction::Desc)],
+            RaceSort::Name => &[
+                ("LOWER(name)", Direction::Asc),
+                ("start_at", Direction::Asc),
+                ("id", Direction::Asc),
+            ],
+            RaceSort::Town => &[
+                ("town", Direction::Asc),
+                ("start_at", Direction::Asc),
+                ("id", Direction::Asc),
+            ],
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            RaceSort::Soonest => "Soonest",
+            RaceSort::Latest => "Furthest Out",
+            RaceSort::Name => "Race Name",
+            RaceSort::Town => "Town",
+        }
+    }
+}
+
+impl std::fmt::Display for RaceSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RaceSort::Soonest => write!(f, "soonest"),
+            RaceSort::Latest => write!(f, "latest"),
+            RaceSort::Name => write!(f, "name"),
+            RaceSort::Town => write!(f, "town"),
+        }
+    }
+}
+
+/// Filters for the admin race list, which includes races members can't see.
+#[derive(Deserialize, Default)]
+pub struct AdminRaceSearchParams {
+    /// Matches the race or town name.
+    pub q: Option<String>,
+    pub status: Option<RaceStatus>,
+    pub deleted: Option<bool>,
     pub page: Option<i64>,
     pub page_size: Option<i64>,
 }
 
-impl From<RaceSearchParams> for Pagination {
-    fn from(params: RaceSearchParams) -> Self {
+impl From<&AdminRaceSearchParams> for Pagination {
+    fn from(params: &AdminRaceSearchParams) -> Self {
         Self {
             page: params.page,
             page_size: params.page_size,
@@ -73,7 +362,16 @@ impl From<RaceSearchParams> for Pagination {
     }
 }
 
-#[derive(Deserialize)]
+impl From<&RaceSearchParams> for Pagination {
+    fn from(params: &RaceSearchParams) -> Self {
+        Self {
+            page: params.page,
+            page_size: params.page_size,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone)]
 pub struct NewRaceForm {
     pub name: String,
     pub town_id: i64,
@@ -84,6 +382,171 @@ pub struct NewRaceForm {
     pub race_url: String,
 }
 
+impl Validate for NewRaceForm {
+    fn validate(&self) -> Result<(), String> {
+        if self.name.is_whitespace_or_empty() {
+            return Err("Race name cannot be empty".to_string());
+        }
+
+        if self.name.len() > 100 {
+            return Err("Race name cannot be longer than 100 characters".to_string());
+        }
+
+        if !self.distance_val.is_finite() || self.distance_val <= 0.0 {
+            return Err("Distance must be more than zero".to_string());
+        }
+
+        if self.start_at <= Utc::now().with_timezone(&New_York).naive_local() {
+            return Err("The race has to be in the future".to_string());
+        }
+
+        validate_race_url(&self.race_url)
+    }
+}
+
+impl NewRaceForm {
+    pub fn is_kilometers(&self) -> bool {
+        self.distance_unit == DistanceUnit::Kilometers
+    }
+}
+
+/// The "Add Race" form, which takes one or more distances, each with its own
+/// start time.
+#[derive(Deserialize, Clone)]
+pub struct NewRaceEventForm {
+    pub name: String,
+    pub town_id: i64,
+    pub race_url: String,
+    #[serde(default)]
+    pub distance_val: Vec<f64>,
+    #[serde(default)]
+    pub distance_unit: Vec<DistanceUnit>,
+    #[serde(default, deserialize_with = "parse_no_seconds_list")]
+    pub start_at: Vec<NaiveDateTime>,
+}
+
+impl NewRaceEventForm {
+    /// Each distance as its own race.
+    pub fn races(&self) -> Vec<NewRaceForm> {
+        self.distance_val
+            .iter()
+            .zip(&self.distance_unit)
+            .zip(&self.start_at)
+            .map(|((distance_val, distance_unit), start_at)| NewRaceForm {
+                name: self.name.clone(),
+                town_id: self.town_id,
+                distance_val: *distance_val,
+                distance_unit: distance_unit.clone(),
+                start_at: *start_at,
+                race_url: self.race_url.clone(),
+            })
+            .collect()
+    }
+}
+
+impl Validate for NewRaceEventForm {
+    fn validate(&self) -> Result<(), String> {
+        if self.distance_val.is_empty() {
+            return Err("Add at least one distance".to_string());
+        }
+
+        if self.distance_val.len() != self.distance_unit.len()
+            || self.distance_val.len() != self.start_at.len()
+        {
+            return Err("Each distance needs a unit and a start time".to_string());
+        }
+
+        let races = self.races();
+        for race in &races {
+            race.validate()?;
+        }
+
+        if races
+            .iter()
+            .any(|race| race.start_at.date() != races[0].start_at.date())
+        {
+            return Err("All of the distances have to be on the same day".to_string());
+        }
+
+        let miles: Vec<f64> = races
+            .iter()
+            .map(|race| Miles::parse(race.distance_val, race.distance_unit.clone()).value())
+            .collect();
+        for (i, race) in races.iter().enumerate() {
+            if (0..i).any(|j| miles[j] == miles[i] && races[j].start_at == race.start_at) {
+                return Err("Each distance can only be added once".to_string());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<NewRaceEventForm> for Vec<NewRace> {
+    fn from(form: NewRaceEventForm) -> Self {
+        form.races().into_iter().map(NewRace::from).collect()
+    }
+}
+
+fn validate_race_url(race_url: &str) -> Result<(), String> {
+    let url =
+        Url::parse(race_url.trim()).map_err(|_| "Race website is not a valid URL".to_string())?;
+    if !["http", "https"].contains(&url.scheme()) || url.host_str().is_none() {
+        return Err("Race website must start with http:// or https://".to_string());
+    }
+
+    Ok(())
+}
+
+/// An admin's corrections to a race. Unlike adding one, it can be in the
+/// past and doesn't need a website.
+#[derive(Deserialize)]
+pub struct UpdateRaceForm {
+    pub name: String,
+    pub town_id: i64,
+    pub distance_val: f64,
+    pub distance_unit: DistanceUnit,
+    #[serde(deserialize_with = "parse_no_seconds")]
+    pub start_at: NaiveDateTime,
+    pub race_url: Option<String>,
+}
+
+impl Validate for UpdateRaceForm {
+    fn validate(&self) -> Result<(), String> {
+        if self.name.is_whitespace_or_empty() {
+            return Err("Race name cannot be empty".to_string());
+        }
+
+        if self.name.len() > 100 {
+            return Err("Race name cannot be longer than 100 characters".to_string());
+        }
+
+        if !self.distance_val.is_finite() || self.distance_val <= 0.0 {
+            return Err("Distance must be more than zero".to_string());
+        }
+
+        match self
+            .race_url
+            .as_deref()
+            .filter(|url| !url.is_whitespace_or_empty())
+        {
+            Some(race_url) => validate_race_url(race_url),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Why a moderator turned a race down, shown to the member who added it.
+#[derive(Deserialize)]
+pub struct RejectRaceForm {
+    pub reason: String,
+}
+
+#[derive(Deserialize)]
+pub struct MergeRaceForm {
+    pub into_race_id: i64,
+}
+
 pub struct NewRace {
     pub name: String,
     pub town_id: i64,
@@ -96,11 +559,28 @@ pub struct NewRace {
 pub struct UpcomingRaceFromRun169Society {
     pub name: String,
     pub town_name: String,
-    pub miles: Miles,
+    /// Every distance on the row, e.g. "5K, 10K".
+    pub distances: Vec<Miles>,
     pub start_at: chrono::NaiveDateTime,
     pub race_url: String,
 }
 
+impl UpcomingRaceFromRun169Society {
+    /// Each distance as its own race.
+    pub fn races(&self, town_id: i64) -> Vec<NewRace> {
+        self.distances
+            .iter()
+            .map(|miles| NewRace {
+                town_id,
+                name: self.name.to_string(),
+                miles: Miles::new(miles.value()),
+                start_at: self.start_at,
+                race_url: Some(self.race_url.to_string()),
+            })
+            .collect()
+    }
+}
+
 impl From<GoogleSheet> for Vec<UpcomingRaceFromRun169Society> {
     fn from(value: GoogleSheet) -> Self {
         let mut races = Vec::new();
@@ -133,30 +613,50 @@ impl From<GoogleSheet> for Vec<UpcomingRaceFromRun169Society> {
                 continue;
             }
 
-            let miles: Vec<&str> = columns[6].split(',').map(|s| s.trim()).collect();
-            for miles in &miles {
-                let name = columns[4].clone();
-                let town_name = columns[2].clone();
-                let race_url = columns[7].clone();
-                let miles = match Miles::from_str(miles) {
-                    Ok(miles) => miles,
-                    Err(_) => continue,
-                };
-
-                races.push(UpcomingRaceFromRun169Society {
-                    name,
-                    town_name,
-                    miles,
-                    start_at,
-                    race_url,
-                });
+            let distances: Vec<Miles> = columns[6]
+                .split(',')
+                .filter_map(|miles| Miles::from_str(miles.trim()).ok())
+                .collect();
+            if distances.is_empty() {
+                continue;
             }
+
+            races.push(UpcomingRaceFromRun169Society {
+                name: columns[4].clone(),
+                town_name: columns[2].clone(),
+                distances,
+                start_at,
+                race_url: columns[7].clone(),
+            });
         }
 
         races
     }
 }
 
+#[derive(Debug, Deserialize, Serialize, FromRow, ToSchema)]
+pub struct RaceResultView {
+    pub id: i64,
+    pub user_id: i64,
+    pub race_id: i64,
+    pub race_name: String,
+    pub town_id: i64,
+    pub town: String,
+    pub county: String,
+    pub is_elusive: bool,
+    pub miles: f64,
+    pub start_at: chrono::NaiveDateTime,
+    pub notes: Option<String>,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl Paginatable for RaceResultView {
+    fn table_name() -> &'static str {
+        "race_results_view"
+    }
+}
+
 pub struct NewRaceResult {
     pub user_id: i64,
     pub race_id: i64,
@@ -188,11 +688,26 @@ impl From<SubmitTown> for NewRace {
 impl From<NewRaceForm> for NewRace {
     fn from(form: NewRaceForm) -> Self {
         Self {
-            name: form.name,
+            name: form.name.trim().to_string(),
+            town_id: form.town_id,
+            miles: Miles::parse(form.distance_val, form.distance_unit),
+            start_at: form.start_at,
+            race_url: Some(form.race_url.trim().to_string()),
+        }
+    }
+}
+
+impl From<UpdateRaceForm> for NewRace {
+    fn from(form: UpdateRaceForm) -> Self {
+        Self {
+            name: form.name.trim().to_string(),
             town_id: form.town_id,
             miles: Miles::parse(form.distance_val, form.distance_unit),
             start_at: form.start_at,
-            race_url: Some(form.race_url),
+            race_url: form
+                .race_url
+                .map(|url| url.trim().to_string())
+                .filter(|url| !url.is_empty()),
         }
     }
 }
@@ -1,6 +1,18 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Eq, sqlx::Type, Serialize, Deserialize, Default)]
+use crate::{
+    domain::{
+        Race,
+        race::{NewRace, RaceView},
+        town::{CompletedTown, SubmitTown},
+        user::UserView,
+    },
+    util::rbac::Can,
+};
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, sqlx::Type, Serialize, Deserialize, Default,
+)]
 #[sqlx(type_name = "TEXT")]
 #[sqlx(rename_all = "lowercase")]
 #[serde(rename_all = "lowercase")]
@@ -11,10 +23,404 @@ pub enum Role {
     Admin,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+impl std::fmt::Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Role::User => write!(f, "user"),
+            Role::Royalty => write!(f, "royalty"),
+            Role::Admin => write!(f, "admin"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Action {
     Read,
     Create,
     Update,
     Delete,
+    /// Approve something that is waiting on a moderator (e.g. imported races).
+    Approve,
+    /// Change another member's role or lock state.
+    Manage,
+    /// Sign in as another member to see what they see.
+    Impersonate,
+}
+
+/// Things a member has on their own account, checked without loading them
+/// since the services only ever look them up by the member's id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountResource {
+    Rsvp,
+    ApiToken,
+    Webhook,
+    PushSubscription,
+    NotificationPreferences,
+    StravaConnection,
+}
+
+impl Can<AccountResource> for UserView {
+    fn can(&self, action: Action, resource: &AccountResource) -> bool {
+        // Locked members can still tidy up their account, but can't RSVP or
+        // set up anything new that reaches outside the site. Nobody
+        // impersonating them can change it at all.
+        let blocked = match (resource, action) {
+            (AccountResource::Rsvp, _) => self.is_restricted(),
+            (AccountResource::ApiToken | AccountResource::Webhook, Action::Create) => {
+                self.is_restricted()
+            }
+            _ => self.is_impersonated(),
+        };
+
+        match action {
+            Action::Read => true,
+            Action::Create | Action::Update | Action::Delete => !blocked,
+            Action::Approve | Action::Manage | Action::Impersonate => false,
+        }
+    }
+}
+
+impl Can<Race> for UserView {
+    fn can(&self, action: Action, _race: &Race) -> bool {
+        can_on_race(self, action)
+    }
+}
+
+impl Can<RaceView> for UserView {
+    fn can(&self, action: Action, _race: &RaceView) -> bool {
+        can_on_race(self, action)
+    }
+}
+
+fn can_on_race(user: &UserView, action: Action) -> bool {
+    match action {
+        Action::Read => true,
+        Action::Create => !user.is_restricted(),
+        Action::Update | Action::Delete | Action::Approve => user.is_moderator(),
+        Action::Manage | Action::Impersonate => false,
+    }
+}
+
+impl Can<NewRace> for UserView {
+    fn can(&self, action: Action, _race: &NewRace) -> bool {
+        match action {
+            Action::Create => !self.is_restricted(),
+            _ => false,
+        }
+    }
+}
+
+impl Can<UserView> for UserView {
+    fn can(&self, action: Action, member: &UserView) -> bool {
+        let is_self = self.id == member.id;
+
+        match action {
+            Action::Read => true,
+            Action::Create | Action::Approve => false,
+            Action::Update => {
+                !self.is_impersonated() && (is_self || (self.is_admin() && !self.locked))
+            }
+            // Admins should never be able to lock themselves out or demote themselves.
+            Action::Delete | Action::Manage => self.is_admin() && !self.is_restricted() && !is_self,
+            Action::Impersonate => {
+                self.is_admin() && !self.is_restricted() && !is_self && !member.is_admin()
+            }
+        }
+    }
+}
+
+impl Can<CompletedTown> for UserView {
+    fn can(&self, action: Action, town: &CompletedTown) -> bool {
+        let is_owner = self.id == town.user_id;
+
+        match action {
+            Action::Read => true,
+            Action::Create => is_owner && !self.is_restricted(),
+            Action::Update | Action::Delete => {
+                !self.is_restricted() && (is_owner || self.is_admin())
+            }
+            Action::Approve => self.is_moderator(),
+            Action::Manage | Action::Impersonate => false,
+        }
+    }
+}
+
+impl Can<SubmitTown> for UserView {
+    fn can(&self, action: Action, _form: &SubmitTown) -> bool {
+        match action {
+            Action::Create => !self.is_restricted() && self.runner_id.is_some(),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use super::*;
+    use crate::domain::{race::RaceStatus, user::Impersonator};
+    use Action::*;
+
+    const ACTIONS: [Action; 7] = [
+        Action::Read,
+        Action::Create,
+        Action::Update,
+        Action::Delete,
+        Action::Approve,
+        Action::Manage,
+        Action::Impersonate,
+    ];
+
+    const ROLES: [Role; 3] = [Role::User, Role::Royalty, Role::Admin];
+
+    fn member(id: i64, role: Role) -> UserView {
+        UserView {
+            id,
+            runner_id: Some(id),
+            hometown_id: None,
+            hometown: None,
+            hometown_county_id: None,
+            hometown_county: None,
+            completed_towns_count: 0,
+            email: format!("{id}@example.com"),
+            verified: true,
+            first_name: "Pat".to_string(),
+            last_name: "Runner".to_string(),
+            full_name: "Pat Runner".to_string(),
+            image_url: String::new(),
+            role,
+            locked: false,
+            created_at: NaiveDateTime::default(),
+            updated_at: NaiveDateTime::default(),
+            impersonated_by: None,
+        }
+    }
+
+    fn locked(mut user: UserView) -> UserView {
+        user.locked = true;
+        user
+    }
+
+    fn impersonated(mut user: UserView) -> UserView {
+        user.impersonated_by = Some(Impersonator {
+            id: 99,
+            full_name: "Ada Admin".to_string(),
+            expires_at: NaiveDateTime::default(),
+        });
+        user
+    }
+
+    /// Every action the user can take on the resource, in `ACTIONS` order.
+    fn allowed<T>(user: &UserView, resource: &T) -> Vec<Action>
+    where
+        UserView: Can<T>,
+    {
+        ACTIONS
+            .into_iter()
+            .filter(|action| user.can(*action, resource))
+            .collect()
+    }
+
+    fn race() -> Race {
+        Race {
+            id: 1,
+            town_id: 1,
+            name: "Turkey Trot".to_string(),
+            miles: 3.1,
+            race_url: None,
+            start_at: NaiveDateTime::default(),
+            created_at: NaiveDateTime::default(),
+            updated_at: NaiveDateTime::default(),
+        }
+    }
+
+    fn race_view() -> RaceView {
+        RaceView {
+            id: 1,
+            town_id: 1,
+            event_id: None,
+            name: "Turkey Trot".to_string(),
+            town: "Andover".to_string(),
+            county: "Tolland".to_string(),
+            is_elusive: false,
+            miles: 3.1,
+            race_url: None,
+            start_at: NaiveDateTime::default(),
+            status: RaceStatus::Approved,
+            deleted_at: None,
+            created_at: NaiveDateTime::default(),
+            updated_at: NaiveDateTime::default(),
+        }
+    }
+
+    fn new_race() -> NewRace {
+        NewRace::from(SubmitTown::default())
+    }
+
+    fn completed_town(user_id: i64) -> CompletedTown {
+        CompletedTown {
+            id: 1,
+            user_id,
+            town_id: 1,
+            name: "Andover".to_string(),
+            county: "Tolland".to_string(),
+            is_elusive: false,
+            created_at: NaiveDateTime::default(),
+            updated_at: NaiveDateTime::default(),
+        }
+    }
+
+    #[test]
+    fn races() {
+        let expected = [
+            (Role::User, vec![Read, Create]),
+            (Role::Royalty, vec![Read, Create, Update, Delete, Approve]),
+            (Role::Admin, vec![Read, Create, Update, Delete, Approve]),
+        ];
+
+        for (role, expected) in expected {
+            let user = member(1, role.clone());
+            assert_eq!(allowed(&user, &race()), expected, "{role}");
+            assert_eq!(allowed(&user, &race_view()), expected, "{role}");
+            assert_eq!(allowed(&user, &new_race()), vec![Create], "{role}");
+
+            for user in [locked(user.clone()), impersonated(user)] {
+                assert_eq!(allowed(&user, &race()), vec![Read], "{role}");
+                assert_eq!(allowed(&user, &race_view()), vec![Read], "{role}");
+                assert_eq!(allowed(&user, &new_race()), vec![], "{role}");
+            }
+        }
+    }
+
+    #[test]
+    fn members() {
+        for role in ROLES {
+            let user = member(1, role.clone());
+            assert_eq!(allowed(&user, &user), vec![Read, Update], "{role} on self");
+            // Locked members can still fix their own details.
+            let locked_user = locked(user.clone());
+            assert_eq!(allowed(&locked_user, &locked_user), vec![Read, Update]);
+            let impersonated_user = impersonated(user);
+            assert_eq!(allowed(&impersonated_user, &impersonated_user), vec![Read]);
+        }
+
+        let other = member(2, Role::User);
+        let other_admin = member(3, Role::Admin);
+        assert_eq!(allowed(&member(1, Role::User), &other), vec![Read]);
+        assert_eq!(allowed(&member(1, Role::Royalty), &other), vec![Read]);
+
+        let admin = member(1, Role::Admin);
+        assert_eq!(
+            allowed(&admin, &other),
+            vec![Read, Update, Delete, Manage, Impersonate]
+        );
+        assert_eq!(
+            allowed(&admin, &other_admin),
+            vec![Read, Update, Delete, Manage]
+        );
+        assert_eq!(allowed(&locked(admin.clone()), &other), vec![Read]);
+        assert_eq!(allowed(&impersonated(admin), &other), vec![Read]);
+    }
+
+    #[test]
+    fn completed_towns() {
+        // (role, on their own, on someone else's)
+        let expected = [
+            (Role::User, vec![Read, Create, Update, Delete], vec![Read]),
+            (
+                Role::Royalty,
+                vec![Read, Create, Update, Delete, Approve],
+                vec![Read, Approve],
+            ),
+            (
+                Role::Admin,
+                vec![Read, Create, Update, Delete, Approve],
+                vec![Read, Update, Delete, Approve],
+            ),
+        ];
+
+        for (role, own, others) in expected {
+            let user = member(1, role.clone());
+            assert_eq!(allowed(&user, &completed_town(1)), own, "{role}");
+            assert_eq!(allowed(&user, &completed_town(2)), others, "{role}");
+
+            for user in [locked(user.clone()), impersonated(user)] {
+                assert_eq!(allowed(&user, &completed_town(1)), vec![Read], "{role}");
+                assert_eq!(allowed(&user, &completed_town(2)), vec![Read], "{role}");
+            }
+        }
+    }
+
+    #[test]
+    fn submitting_towns() {
+        for role in ROLES {
+            let user = member(1, role.clone());
+            assert_eq!(allowed(&user, &SubmitTown::default()), vec![Create]);
+
+            let mut orphan = user.clone();
+            orphan.runner_id = None;
+            assert_eq!(allowed(&orphan, &SubmitTown::default()), vec![]);
+
+            assert_eq!(
+                allowed(&locked(user.clone()), &SubmitTown::default()),
+                vec![]
+            );
+            assert_eq!(allowed(&impersonated(user), &SubmitTown::default()), vec![]);
+        }
+    }
+
+    #[test]
+    fn account_resources() {
+        let resources = [
+            AccountResource::Rsvp,
+            AccountResource::ApiToken,
+            AccountResource::Webhook,
+            AccountResource::PushSubscription,
+            AccountResource::NotificationPreferences,
+            AccountResource::StravaConnection,
+        ];
+
+        for role in ROLES {
+            let user = member(1, role.clone());
+            for resource in resources {
+                assert_eq!(
+                    allowed(&user, &resource),
+                    vec![Read, Create, Update, Delete],
+                    "{role} {resource:?}"
+                );
+                assert_eq!(
+                    allowed(&impersonated(user.clone()), &resource),
+                    vec![Read],
+                    "impersonated {role} {resource:?}"
+                );
+            }
+
+            let locked_user = locked(user);
+            let expected = [
+                (AccountResource::Rsvp, vec![Read]),
+                (AccountResource::ApiToken, vec![Read, Update, Delete]),
+                (AccountResource::Webhook, vec![Read, Update, Delete]),
+                (
+                    AccountResource::PushSubscription,
+                    vec![Read, Create, Update, Delete],
+                ),
+                (
+                    AccountResource::NotificationPreferences,
+                    vec![Read, Create, Update, Delete],
+                ),
+                (
+                    AccountResource::StravaConnection,
+                    vec![Read, Create, Update, Delete],
+                ),
+            ];
+            for (resource, expected) in expected {
+                assert_eq!(
+                    allowed(&locked_user, &resource),
+                    expected,
+                    "locked {role} {resource:?}"
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use serde::Deserialize;
+use sqlx::FromRow;
+
+/// How many results of each kind the search page shows.
+pub const RESULTS_PER_KIND: i64 = 5;
+
+/// Words shorter than this aren't respelled, since "5k" or "st" would match
+/// nearly anything one letter away.
+const MIN_RESPELL_LENGTH: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "TEXT", rename_all = "lowercase")]
+pub enum SearchKind {
+    Race,
+    Town,
+    County,
+    Member,
+}
+
+impl std::fmt::Display for SearchKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SearchKind::Race => write!(f, "race"),
+            SearchKind::Town => write!(f, "town"),
+            SearchKind::County => write!(f, "county"),
+            SearchKind::Member => write!(f, "member"),
+        }
+    }
+}
+
+#[derive(Deserialize, Default)]
+pub struct SearchParams {
+    pub q: Option<String>,
+}
+
+#[derive(Debug, FromRow)]
+pub struct SearchResult {
+    pub kind: SearchKind,
+    pub item_id: i64,
+    pub title: String,
+    pub subtitle: String,
+    /// When it is, for races.
+    pub date: Option<NaiveDate>,
+    /// Where the result goes, e.g. the race's page.
+    pub url: String,
+}
+
+/// The best matches of each kind, best first.
+#[derive(Default)]
+pub struct SearchResults {
+    pub races: Vec<SearchResult>,
+    pub towns: Vec<SearchResult>,
+    pub counties: Vec<SearchResult>,
+    pub members: Vec<SearchResult>,
+}
+
+impl SearchResults {
+    pub fn is_empty(&self) -> bool {
+        self.races.is_empty()
+            && self.towns.is_empty()
+            && self.counties.is_empty()
+            && self.members.is_empty()
+    }
+}
+
+/// Splits what was typed into the words the index would have, e.g.
+/// "Oak-Leaf 5K" is "oak", "leaf" and "5k".
+pub fn search_terms(q: &str) -> Vec<String> {
+    q.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// An FTS5 query matching every word as the start of a word, so results
+/// show up while still typing. A word with a `respelling` matches either
+/// spelling.
+pub fn match_query(terms: &[String], respellings: &HashMap<String, String>) -> String {
+    terms
+        .iter()
+        .map(|term| match respellings.get(term) {
+            Some(respelling) => format!("(\"{term}\"* OR \"{respelling}\")"),
+            None => format!("\"{term}\"*"),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The closest word in the index to one that doesn't match anything, e.g.
+/// "leef" for "leaf". `vocabulary` is most common first, which wins ties.
+pub fn closest_term(term: &str, vocabulary: &[String]) -> Option<String> {
+    let length = term.chars().count();
+    if length < MIN_RESPELL_LENGTH {
+        return None;
+    }
+
+    let max_edits = max_edits(length);
+    vocabulary
+        .iter()
+        .map(|word| (strsim::damerau_levenshtein(term, word), word))
+        .filter(|(edits, _)| *edits <= max_edits)
+        .min_by_key(|(edits, _)| *edits)
+        .map(|(_, word)| word.to_string())
+}
+
+/// How many typos a word of the given length can have.
+pub fn max_edits(length: usize) -> usize {
+    match length {
+        0..=5 => 1,
+        _ => 2,
+    }
+}
@@ -0,0 +1,123 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+use crate::{domain::town::SubmitTown, util::StringExt};
+
+/// How long a submission can be processing before we take it that the
+/// request died (e.g. the server restarted) and let a retry have it.
+pub const STALE_AFTER_MINUTES: i64 = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type, Serialize, Deserialize, ToSchema)]
+#[sqlx(type_name = "TEXT")]
+#[sqlx(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum SubmissionStatus {
+    /// Claimed by a request that hasn't posted to the society yet. After
+    /// [`STALE_AFTER_MINUTES`] it can be retried.
+    Processing,
+    /// Posted to the society, but not yet recorded here.
+    Posted,
+    Completed,
+    /// The society form wouldn't take it. Retrying with the same key is fine.
+    Failed,
+}
+
+/// A town submitted through the API, remembered by the client's idempotency
+/// key so retries (e.g. from the PWA's background sync) only go through once.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct TownSubmission {
+    pub id: i64,
+    #[serde(skip)]
+    pub user_id: i64,
+    pub idempotency_key: String,
+    #[serde(skip)]
+    pub fingerprint: String,
+    pub town_id: i64,
+    pub race_id: Option<i64>,
+    pub status: SubmissionStatus,
+    pub error: Option<String>,
+    pub society_posted_at: Option<NaiveDateTime>,
+    pub completed_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl TownSubmission {
+    /// A key can only ever be used for the one payload.
+    pub fn fingerprint(form: &SubmitTown) -> String {
+        let body = serde_json::to_string(form).unwrap_or_default();
+        hex::encode(Sha256::digest(body.as_bytes()))
+    }
+
+    pub fn validate_key(key: &str) -> Result<(), String> {
+        if key.is_whitespace_or_empty() || key.len() > 255 {
+            return Err("The Idempotency-Key header must be 1 to 255 characters".to_string());
+        }
+        Ok(())
+    }
+}
+
+pub enum SubmissionOutcome {
+    Created(TownSubmission),
+    /// The key was already used for this payload, nothing was done again.
+    Replayed(TownSubmission),
+}
+
+pub enum SubmissionError {
+    /// Another request with the same key hasn't finished yet.
+    InProgress,
+    /// The key was already used for a different payload.
+    KeyReused,
+    /// The member already has a result for this race, under another key.
+    AlreadySubmitted,
+    Invalid(String),
+    Failed(String),
+}
+
+/// What recording a result did for the member's towns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Completion {
+    /// The first time they've completed the town.
+    NewTown,
+    /// They'd already completed the town, this is another race there.
+    RanAgain,
+    /// The result was already recorded, nothing changed.
+    AlreadyRecorded,
+}
+
+/// Which step of submitting a town went wrong.
+#[derive(Debug)]
+pub enum SubmitTownError {
+    TownNotFound,
+    /// The member already has a result for this race. Nothing was posted.
+    AlreadySubmitted,
+    /// The race can't take results, e.g. it was removed. Nothing was posted.
+    Race(String),
+    /// The society's form didn't take it, so nothing was saved here either.
+    Society(String),
+    /// The society has it, but saving it here didn't work.
+    Recording(String),
+}
+
+impl std::fmt::Display for SubmitTownError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitTownError::TownNotFound => write!(f, "Unable to find that town"),
+            SubmitTownError::AlreadySubmitted => {
+                write!(f, "You've already submitted this race")
+            }
+            SubmitTownError::Race(e) => write!(f, "{e}, so it can't be submitted"),
+            SubmitTownError::Society(e) => write!(
+                f,
+                "The Run169Towns Society didn't accept the submission, so nothing was saved: {e}"
+            ),
+            SubmitTownError::Recording(e) => write!(
+                f,
+                "The Run169Towns Society has the submission, but it wasn't saved here: {e}"
+            ),
+        }
+    }
+}
@@ -1,21 +1,28 @@
 use crate::domain::distance::DistanceUnit;
 use crate::domain::distance::Kilometers;
 use crate::domain::distance::Miles;
+use crate::domain::distance::miles_between;
 use crate::domain::user::UserView;
 use crate::util::parse_no_seconds;
+use crate::util::{StringExt, validation::Validate};
 use chrono::NaiveDateTime;
-use chrono::{Datelike, NaiveDate};
+use chrono::{Datelike, NaiveDate, Utc};
+use chrono_tz::America::New_York;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use std::collections::HashMap;
+use utoipa::ToSchema;
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct Town {
     pub id: i64,
     pub name: String,
     pub county_id: i64,
     pub county: String,
     pub is_elusive: bool,
+    /// Roughly the middle of town.
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
 }
 
 impl Town {
@@ -26,9 +33,23 @@ impl Town {
     pub fn is_not_valid(town_id: i64) -> bool {
         !Self::is_valid(town_id)
     }
+
+    /// Straight line miles between the middle of two towns.
+    pub fn miles_to(&self, other: &Town) -> Option<f64> {
+        Some(miles_between(
+            (self.latitude?, self.longitude?),
+            (other.latitude?, other.longitude?),
+        ))
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, FromRow)]
+pub struct County {
+    pub id: i64,
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct CompletedTown {
     pub id: i64,
     pub user_id: i64,
@@ -40,7 +61,7 @@ pub struct CompletedTown {
     pub updated_at: NaiveDateTime,
 }
 
-#[derive(Serialize, Deserialize, Default, Clone)]
+#[derive(Serialize, Deserialize, Default, Clone, ToSchema)]
 pub struct SubmitTown {
     pub town_id: i64,
     pub race_name: String,
@@ -48,10 +69,29 @@ pub struct SubmitTown {
     pub distance_val: f64,
     pub distance_unit: DistanceUnit,
     #[serde(deserialize_with = "parse_no_seconds")]
+    #[schema(value_type = String, example = "2026-10-18T09:30")]
     pub start_at: NaiveDateTime,
     pub notes: Option<String>,
 }
 
+impl Validate for SubmitTown {
+    fn validate(&self) -> Result<(), String> {
+        if self.race_name.is_whitespace_or_empty() {
+            return Err("Race name cannot be empty".to_string());
+        }
+
+        if !self.distance_val.is_finite() || self.distance_val <= 0.0 {
+            return Err("Distance must be more than zero".to_string());
+        }
+
+        if self.start_at.date() > Utc::now().with_timezone(&New_York).date_naive() {
+            return Err("The race can't be after today".to_string());
+        }
+
+        Ok(())
+    }
+}
+
 pub struct Run169TownsSocietyGoogleFormAnswers {
     pub town_name: String,
     pub race_name: String,
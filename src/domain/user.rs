@@ -1,11 +1,16 @@
-use chrono::NaiveDateTime;
+use chrono::{NaiveDate, NaiveDateTime};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
+use utoipa::ToSchema;
 
 use crate::{
     domain::{Town, rbac::Role},
     infrastructure::auth::GoogleUser,
-    util::{StringExt, pagination::Paginatable, validation::Validate},
+    util::{
+        StringExt,
+        pagination::{Paginatable, Pagination},
+        validation::Validate,
+    },
 };
 
 pub struct UpdateUser {
@@ -91,12 +96,44 @@ pub struct UserView {
     pub locked: bool,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    #[sqlx(skip)]
+    #[serde(skip)]
+    pub impersonated_by: Option<Impersonator>,
+}
+
+/// The admin behind an impersonated session, and when it runs out.
+#[derive(Clone)]
+pub struct Impersonator {
+    pub id: i64,
+    pub full_name: String,
+    pub expires_at: NaiveDateTime,
 }
 
 impl UserView {
     pub fn is_admin(&self) -> bool {
         self.role == Role::Admin
     }
+
+    /// Whether the user holds at least the given role. Roles are ordered
+    /// `User < Royalty < Admin`, so an admin can do anything royalty can.
+    pub fn has_role(&self, role: Role) -> bool {
+        self.role >= role
+    }
+
+    /// Royalty and admins that are not locked can moderate races and imports.
+    pub fn is_moderator(&self) -> bool {
+        !self.is_restricted() && self.has_role(Role::Royalty)
+    }
+
+    pub fn is_impersonated(&self) -> bool {
+        self.impersonated_by.is_some()
+    }
+
+    /// Locked users and impersonated sessions can look around, but can't
+    /// change anything on the member's behalf.
+    pub fn is_restricted(&self) -> bool {
+        self.locked || self.is_impersonated()
+    }
 }
 
 impl Paginatable for UserView {
@@ -105,6 +142,71 @@ impl Paginatable for UserView {
     }
 }
 
+/// The public face of a member, without their email, role or account state.
+#[derive(Serialize, Deserialize, Clone, ToSchema)]
+pub struct MemberProfile {
+    pub runner_id: Option<i64>,
+    pub full_name: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub image_url: String,
+    pub hometown_id: Option<i64>,
+    pub hometown: Option<String>,
+    pub hometown_county: Option<String>,
+    pub completed_towns_count: i64,
+    pub created_at: NaiveDateTime,
+}
+
+impl From<UserView> for MemberProfile {
+    fn from(user: UserView) -> Self {
+        Self {
+            runner_id: user.runner_id,
+            full_name: user.full_name,
+            first_name: user.first_name,
+            last_name: user.last_name,
+            image_url: user.image_url,
+            hometown_id: user.hometown_id,
+            hometown: user.hometown,
+            hometown_county: user.hometown_county,
+            completed_towns_count: user.completed_towns_count,
+            created_at: user.created_at,
+        }
+    }
+}
+
+/// Whether a member has finished filling in their runner info.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Onboarding {
+    Complete,
+    Pending,
+}
+
+/// Filters for the admin user list. Unlike the public member search, this
+/// includes members who haven't finished onboarding yet.
+#[derive(Deserialize, Default)]
+pub struct AdminUserSearchParams {
+    pub q: Option<String>,
+    pub onboarding: Option<Onboarding>,
+    pub role: Option<Role>,
+    pub locked: Option<bool>,
+    pub verified: Option<bool>,
+    pub county_id: Option<i64>,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub page: Option<i64>,
+    pub page_size: Option<i64>,
+}
+
+impl From<&AdminUserSearchParams> for Pagination {
+    fn from(params: &AdminUserSearchParams) -> Self {
+        Self {
+            page: params.page,
+            page_size: params.page_size,
+        }
+    }
+}
+
 #[derive(Deserialize, Serialize, Default, Debug)]
 pub struct UpdateRunnerInfo {
     pub runner_id: i64,
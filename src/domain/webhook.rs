@@ -0,0 +1,494 @@
+use std::{
+    net::{IpAddr, SocketAddr},
+    str::FromStr,
+    time::Duration,
+};
+
+use chrono::{NaiveDateTime, Utc};
+use hmac::{Hmac, Mac};
+use rand::{Rng, distr::Alphanumeric};
+use reqwest::{Url, redirect::Policy};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sha2::Sha256;
+use sqlx::FromRow;
+use tokio::net::lookup_host;
+
+use crate::{
+    domain::{race::RaceView, user::MemberProfile},
+    util::validation::Validate,
+};
+
+/// How many times a delivery is tried before it is marked as failed.
+pub const MAX_DELIVERY_ATTEMPTS: i64 = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WebhookEvent {
+    #[serde(rename = "town.completed")]
+    TownCompleted,
+    #[serde(rename = "towns.finished")]
+    TownsFinished,
+    #[serde(rename = "race.imported")]
+    RaceImported,
+    #[serde(rename = "race.cancelled")]
+    RaceCancelled,
+    /// Only ever sent from the "Send test" button.
+    #[serde(rename = "ping")]
+    Ping,
+}
+
+impl WebhookEvent {
+    /// The events a webhook can subscribe to.
+    pub const ALL: [WebhookEvent; 4] = [
+        WebhookEvent::TownCompleted,
+        WebhookEvent::TownsFinished,
+        WebhookEvent::RaceImported,
+        WebhookEvent::RaceCancelled,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEvent::TownCompleted => "town.completed",
+            WebhookEvent::TownsFinished => "towns.finished",
+            WebhookEvent::RaceImported => "race.imported",
+            WebhookEvent::RaceCancelled => "race.cancelled",
+            WebhookEvent::Ping => "ping",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            WebhookEvent::TownCompleted => "A member completes a town",
+            WebhookEvent::TownsFinished => "A member finishes all 169 towns",
+            WebhookEvent::RaceImported => "A new upcoming race is imported",
+            WebhookEvent::RaceCancelled => "An upcoming race is cancelled",
+            WebhookEvent::Ping => "A test delivery",
+        }
+    }
+}
+
+impl FromStr for WebhookEvent {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .chain([WebhookEvent::Ping])
+            .find(|event| event.as_str() == s.trim())
+            .ok_or_else(|| format!("Unknown event: {s}"))
+    }
+}
+
+impl std::fmt::Display for WebhookEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// What the receiving end expects the body to look like. Slack and Discord
+/// only want a message, everything else gets the full signed envelope.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type, Serialize, Deserialize, Default)]
+#[sqlx(type_name = "TEXT")]
+#[sqlx(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookFormat {
+    #[default]
+    Json,
+    Slack,
+    Discord,
+}
+
+impl std::fmt::Display for WebhookFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WebhookFormat::Json => write!(f, "json"),
+            WebhookFormat::Slack => write!(f, "slack"),
+            WebhookFormat::Discord => write!(f, "discord"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow, Clone)]
+pub struct Webhook {
+    pub id: i64,
+    pub user_id: i64,
+    pub url: String,
+    pub format: WebhookFormat,
+    #[serde(skip)]
+    pub secret: String,
+    pub events: String,
+    pub active: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl Webhook {
+    pub fn events(&self) -> Vec<WebhookEvent> {
+        self.events
+            .split(',')
+            .filter_map(|event| WebhookEvent::from_str(event).ok())
+            .collect()
+    }
+
+    /// `sha256=<hex>` of `"{timestamp}.{body}"`, keyed with the webhook secret.
+    pub fn sign(&self, timestamp: i64, body: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.secret.as_bytes())
+            .expect("HMAC accepts a key of any length");
+        mac.update(format!("{timestamp}.{body}").as_bytes());
+        format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+    }
+
+    fn generate_secret() -> String {
+        let random: String = rand::rng()
+            .sample_iter(&Alphanumeric)
+            .take(32)
+            .map(char::from)
+            .collect();
+        format!("whsec_{random}")
+    }
+}
+
+#[derive(Deserialize, Default)]
+pub struct NewWebhookForm {
+    pub url: String,
+    #[serde(default)]
+    pub format: WebhookFormat,
+    #[serde(default)]
+    pub events: Vec<WebhookEvent>,
+}
+
+impl Validate for NewWebhookForm {
+    fn validate(&self) -> Result<(), String> {
+        let url = Url::parse(self.url.trim()).map_err(|_| "That is not a valid URL".to_string())?;
+
+        if !["http", "https"].contains(&url.scheme()) {
+            return Err("Webhook URLs must start with http:// or https://".to_string());
+        }
+
+        if is_private_host(&url) && !allow_private_urls() {
+            return Err("Webhooks cannot be sent to private addresses".to_string());
+        }
+
+        if self.events.is_empty() {
+            return Err("Pick at least one event".to_string());
+        }
+
+        if self.events.contains(&WebhookEvent::Ping) {
+            return Err("Unknown event: ping".to_string());
+        }
+
+        Ok(())
+    }
+}
+
+/// Set `WEBHOOKS_ALLOW_PRIVATE_URLS=true` to send to a receiver on your own
+/// machine or network while developing.
+fn allow_private_urls() -> bool {
+    std::env::var("WEBHOOKS_ALLOW_PRIVATE_URLS").is_ok_and(|allow| allow == "true")
+}
+
+fn is_private_host(url: &Url) -> bool {
+    let host = url.host_str().unwrap_or_default().trim_matches(['[', ']']);
+    if host.eq_ignore_ascii_case("localhost") || host.ends_with(".localhost") {
+        return true;
+    }
+
+    IpAddr::from_str(host).is_ok_and(is_private_ip)
+}
+
+/// Anything that isn't a plain public address, including the special use
+/// ranges the standard library doesn't have stable checks for yet.
+fn is_private_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, c, _] = ip.octets();
+            ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_documentation()
+                || ip.is_multicast()
+                // 0.0.0.0/8, "this network"
+                || a == 0
+                // 100.64.0.0/10, carrier-grade NAT
+                || (a == 100 && (64..128).contains(&b))
+                // 192.0.0.0/24, IETF protocol assignments
+                || (a == 192 && b == 0 && c == 0)
+                // 198.18.0.0/15, benchmarking
+                || (a == 198 && (b == 18 || b == 19))
+        }
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_private_ip(IpAddr::V4(ip)),
+            None => {
+                let segments = ip.segments();
+                ip.is_loopback()
+                    || ip.is_unspecified()
+                    || ip.is_unique_local()
+                    || ip.is_unicast_link_local()
+                    || ip.is_multicast()
+                    // 2001:db8::/32, documentation
+                    || segments[..2] == [0x2001, 0xdb8]
+                    // 64:ff9b::/96, NAT64, which can reach any IPv4 address
+                    || segments[..6] == [0x64, 0xff9b, 0, 0, 0, 0]
+            }
+        },
+    }
+}
+
+/// Looks the host up when sending, since a public name can point at a
+/// private address (or be changed to after the webhook was added). The
+/// addresses that were checked are the ones to connect to, so a second
+/// lookup can't sneak a different one in.
+async fn resolve_public(url: &Url) -> Result<Vec<SocketAddr>, String> {
+    let host = url
+        .host_str()
+        .ok_or("The webhook URL has no host")?
+        .trim_matches(['[', ']']);
+    let port = url.port_or_known_default().unwrap_or(443);
+    let addrs: Vec<SocketAddr> = lookup_host((host, port))
+        .await
+        .map_err(|e| format!("Unable to look up {host}: {e}"))?
+        .collect();
+
+    if addrs.is_empty() {
+        return Err(format!("Unable to look up {host}"));
+    }
+
+    let private = is_private_host(url) || addrs.iter().any(|addr| is_private_ip(addr.ip()));
+    if private && !allow_private_urls() {
+        return Err("Webhooks cannot be sent to private addresses".to_string());
+    }
+
+    Ok(addrs)
+}
+
+pub struct NewWebhook {
+    pub user_id: i64,
+    pub url: String,
+    pub format: WebhookFormat,
+    pub secret: String,
+    pub events: Vec<WebhookEvent>,
+}
+
+impl NewWebhook {
+    pub fn new(user_id: i64, form: NewWebhookForm) -> Self {
+        Self {
+            user_id,
+            url: form.url.trim().to_string(),
+            format: form.format,
+            secret: Webhook::generate_secret(),
+            events: form.events,
+        }
+    }
+
+    pub fn events_str(&self) -> String {
+        self.events
+            .iter()
+            .map(WebhookEvent::as_str)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+/// The body of every delivery. `text` is a ready made message for chat apps.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WebhookPayload {
+    pub event: WebhookEvent,
+    pub text: String,
+    pub data: serde_json::Value,
+    pub created_at: NaiveDateTime,
+}
+
+impl WebhookPayload {
+    fn new(event: WebhookEvent, text: String, data: serde_json::Value) -> Self {
+        Self {
+            event,
+            text,
+            data,
+            created_at: Utc::now().naive_utc(),
+        }
+    }
+
+    pub fn town_completed(member: &MemberProfile, race: &RaceView, completed: i64) -> Self {
+        let text = format!(
+            "🎉 {} ran {} in {} ({completed}/169)",
+            member.full_name, race.name, race.town
+        );
+        let data = json!({ "member": member, "race": race, "completed_towns_count": completed });
+        Self::new(WebhookEvent::TownCompleted, text, data)
+    }
+
+    pub fn towns_finished(member: &MemberProfile) -> Self {
+        let text = format!("🏆 {} has run all 169 towns!", member.full_name);
+        Self::new(
+            WebhookEvent::TownsFinished,
+            text,
+            json!({ "member": member }),
+        )
+    }
+
+    pub fn race_imported(race: &RaceView) -> Self {
+        let text = format!(
+            "🏃 New race: {} in {} on {}",
+            race.name,
+            race.town,
+            race.start_at.format("%B %-d, %Y")
+        );
+        Self::new(WebhookEvent::RaceImported, text, json!({ "race": race }))
+    }
+
+    pub fn race_cancelled(race: &RaceView) -> Self {
+        let text = format!("🚫 Cancelled: {} in {}", race.name, race.town);
+        Self::new(WebhookEvent::RaceCancelled, text, json!({ "race": race }))
+    }
+
+    pub fn ping() -> Self {
+        let text = "👋 Your CTRunner webhook is working".to_string();
+        Self::new(WebhookEvent::Ping, text, json!({}))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::Type, Serialize, Deserialize)]
+#[sqlx(type_name = "TEXT")]
+#[sqlx(rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum DeliveryStatus {
+    Pending,
+    Delivered,
+    Failed,
+}
+
+#[derive(Debug, Serialize, Deserialize, FromRow, Clone)]
+pub struct WebhookDelivery {
+    pub id: i64,
+    pub webhook_id: i64,
+    pub event: String,
+    pub payload: String,
+    pub status: DeliveryStatus,
+    pub attempts: i64,
+    pub next_attempt_at: NaiveDateTime,
+    pub response_status: Option<i64>,
+    pub error: Option<String>,
+    pub delivered_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+impl WebhookDelivery {
+    /// Waits 1, 2, 4, 8... minutes between attempts.
+    pub fn backoff(attempts: i64) -> Duration {
+        Duration::from_secs(60 * 2u64.pow(attempts.clamp(1, 10) as u32 - 1))
+    }
+
+    /// Posts the delivery and returns the response status when it was a 2xx.
+    pub async fn send(&self, webhook: &Webhook) -> Result<u16, (Option<u16>, String)> {
+        let body = match webhook.format {
+            WebhookFormat::Json => self.payload.clone(),
+            WebhookFormat::Slack | WebhookFormat::Discord => {
+                let payload: WebhookPayload =
+                    serde_json::from_str(&self.payload).map_err(|e| (None, e.to_string()))?;
+                match webhook.format {
+                    WebhookFormat::Slack => json!({ "text": payload.text }).to_string(),
+                    _ => json!({ "content": payload.text }).to_string(),
+                }
+            }
+        };
+
+        let url = Url::parse(&webhook.url).map_err(|e| (None, e.to_string()))?;
+        let addrs = resolve_public(&url).await.map_err(|e| (None, e))?;
+        // No redirects either, or a receiver could bounce us somewhere private.
+        let client = reqwest::Client::builder()
+            .resolve_to_addrs(url.host_str().unwrap_or_default(), &addrs)
+            .redirect(Policy::none())
+            .build()
+            .map_err(|e| (None, e.to_string()))?;
+
+        let timestamp = Utc::now().timestamp();
+        let response = client
+            .post(url)
+            .timeout(Duration::from_secs(10))
+            .header("Content-Type", "application/json")
+            .header("User-Agent", "CTRunner-Webhooks")
+            .header("X-CTRunner-Event", &self.event)
+            .header("X-CTRunner-Delivery", self.id)
+            .header("X-CTRunner-Timestamp", timestamp)
+            .header("X-CTRunner-Signature", webhook.sign(timestamp, &body))
+            .body(body)
+            .send()
+            .await
+            .map_err(|e| (None, e.to_string()))?;
+
+        let status = response.status();
+        match status.is_success() {
+            true => Ok(status.as_u16()),
+            false => Err((
+                Some(status.as_u16()),
+                format!("Receiver responded {status}"),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn private(ip: &str) -> bool {
+        is_private_ip(IpAddr::from_str(ip).unwrap())
+    }
+
+    #[test]
+    fn blocks_special_use_ipv4_ranges() {
+        for ip in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "0.0.0.0",
+            "0.1.2.3",
+            "100.64.0.1",
+            "100.127.255.254",
+            "192.0.0.8",
+            "198.18.0.1",
+            "198.19.255.255",
+            "192.0.2.1",
+            "224.0.0.1",
+            "255.255.255.255",
+        ] {
+            assert!(private(ip), "{ip} should be blocked");
+        }
+    }
+
+    #[test]
+    fn blocks_special_use_ipv6_ranges() {
+        for ip in [
+            "::1",
+            "::",
+            "fd00::1",
+            "fe80::1",
+            "ff02::1",
+            "2001:db8::1",
+            "64:ff9b::7f00:1",
+            "::ffff:10.0.0.1",
+        ] {
+            assert!(private(ip), "{ip} should be blocked");
+        }
+    }
+
+    #[test]
+    fn allows_public_addresses() {
+        for ip in [
+            "8.8.8.8",
+            "100.63.255.255",
+            "100.128.0.1",
+            "192.0.1.1",
+            "198.20.0.1",
+            "2606:4700::1111",
+            "::ffff:1.1.1.1",
+        ] {
+            assert!(!private(ip), "{ip} should be allowed");
+        }
+    }
+}
@@ -19,7 +19,7 @@ impl FromRequestParts<SharedState> for AdminUser {
 
         match user {
             BaseUser::User(user) => {
-                if user.is_admin() {
+                if user.is_admin() && !user.locked {
                     return Ok(AdminUser(user));
                 }
                 Err(Redirect::to("/dashboard").into_response())
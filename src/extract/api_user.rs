@@ -0,0 +1,84 @@
+use crate::SharedState;
+use crate::domain::{
+    api_token::{TOKEN_PREFIX, TokenScope},
+    user::UserView,
+};
+use crate::extract::{
+    BaseUser,
+    token_user::{TokenUser, bearer_token},
+};
+use crate::infrastructure::jwt::{JwtService, user_claims::UserClaims};
+use crate::util::api::ApiError;
+use axum::{extract::FromRequestParts, http::request::Parts};
+
+/// A user calling the JSON API with an `Authorization: Bearer <token>` header.
+/// The token is either a personal access token or a session JWT, which can
+/// do anything the user can. Without the header, the signed in session is
+/// used instead, so the site's own pages (and service worker) can call it.
+pub struct ApiUser {
+    pub user: Box<UserView>,
+    pub scopes: Vec<TokenScope>,
+}
+
+impl ApiUser {
+    pub fn has(&self, scope: TokenScope) -> bool {
+        self.scopes.contains(&scope)
+    }
+
+    pub fn require(&self, scope: TokenScope) -> Result<(), ApiError> {
+        match self.has(scope) {
+            true => Ok(()),
+            false => Err(ApiError::new(
+                axum::http::StatusCode::FORBIDDEN,
+                &format!("This token is missing the {scope} scope"),
+            )),
+        }
+    }
+}
+
+impl FromRequestParts<SharedState> for ApiUser {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &SharedState,
+    ) -> Result<Self, Self::Rejection> {
+        let Some(token) = bearer_token(parts).map(str::to_string) else {
+            return match BaseUser::from_request_parts(parts, state).await {
+                Ok(BaseUser::User(user)) => Ok(ApiUser {
+                    scopes: TokenScope::for_session(&user),
+                    user,
+                }),
+                _ => Err(ApiError::unauthorized()),
+            };
+        };
+
+        if token.starts_with(TOKEN_PREFIX) {
+            let TokenUser(user, token) = TokenUser::from_request_parts(parts, state).await?;
+            // A token can never do more than its owner can.
+            let allowed = TokenScope::for_session(&user);
+            let scopes = token
+                .scopes()
+                .into_iter()
+                .filter(|scope| allowed.contains(scope))
+                .collect();
+            return Ok(ApiUser { user, scopes });
+        }
+
+        let claims = JwtService::verify::<UserClaims>(&token)
+            .map_err(|_| ApiError::unauthorized())?
+            .claims;
+
+        let user = state
+            .user_service
+            .find_by_email(&claims.sub)
+            .await
+            .map_err(|_| ApiError::internal())?
+            .ok_or_else(ApiError::unauthorized)?;
+
+        Ok(ApiUser {
+            scopes: TokenScope::for_session(&user),
+            user: Box::new(user),
+        })
+    }
+}
@@ -1,7 +1,13 @@
 use crate::{
-    SharedState,
-    domain::user::UserView,
-    infrastructure::jwt::{JwtService, user_claims::UserClaims},
+    AppState, SharedState,
+    domain::{
+        rbac::Action,
+        user::{Impersonator, UserView},
+    },
+    infrastructure::jwt::{
+        JwtService, impersonation_claims::ImpersonationClaims, user_claims::UserClaims,
+    },
+    util::rbac::Can,
 };
 use axum::{
     extract::{FromRef, FromRequestParts},
@@ -11,18 +17,27 @@ use axum_extra::extract::CookieJar;
 use std::sync::Arc;
 
 pub mod admin_user;
+pub mod api_user;
 pub mod current_user;
 pub mod maybe_current_user;
 pub mod no_user;
 pub mod orphan_user;
+pub mod royalty_user;
+pub mod token_user;
 
 pub use admin_user::AdminUser;
+pub use api_user::ApiUser;
 pub use current_user::CurrentUser;
 pub use maybe_current_user::MaybeCurrentUser;
 pub use no_user::NoUser;
 pub use orphan_user::OrphanUser;
+pub use royalty_user::RoyaltyUser;
+pub use token_user::TokenUser;
 
 pub mod real_ip;
+pub mod throttle;
+
+pub use throttle::Throttle;
 
 #[derive(Clone)]
 pub enum BaseUser {
@@ -55,6 +70,9 @@ impl FromRequestParts<SharedState> for BaseUser {
                 .flatten();
 
             if let Some(user) = user {
+                if let Some(target) = impersonated_user(&jar, &user, &state).await {
+                    return Ok(BaseUser::User(Box::new(target)));
+                }
                 return Ok(BaseUser::User(Box::new(user)));
             }
         }
@@ -62,3 +80,39 @@ impl FromRequestParts<SharedState> for BaseUser {
         Ok(BaseUser::None)
     }
 }
+
+/// Swaps the signed in admin for the member they are impersonating, as long
+/// as the impersonation cookie was issued to that admin and hasn't expired.
+async fn impersonated_user(
+    jar: &CookieJar,
+    admin: &UserView,
+    state: &AppState,
+) -> Option<UserView> {
+    let token = jar.get("impersonation_token")?.value();
+    let claims = JwtService::verify::<ImpersonationClaims>(token)
+        .ok()?
+        .claims;
+
+    if claims.act != admin.email {
+        return None;
+    }
+
+    let mut target = state
+        .user_service
+        .find_by_email(&claims.target)
+        .await
+        .ok()
+        .flatten()?;
+
+    if admin.cannot(Action::Impersonate, &target) {
+        return None;
+    }
+
+    target.impersonated_by = Some(Impersonator {
+        id: admin.id,
+        full_name: admin.full_name.clone(),
+        expires_at: claims.expires_at(),
+    });
+
+    Some(target)
+}
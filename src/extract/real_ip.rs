@@ -1,7 +1,30 @@
 use axum::extract::{ConnectInfo, FromRequestParts};
 use axum::http::StatusCode;
-use std::net::SocketAddr;
-use std::sync::Arc;
+use log::warn;
+use std::net::{IpAddr, SocketAddr};
+use std::str::FromStr;
+use std::sync::{Arc, LazyLock};
+
+/// Where the proxy is when `TRUSTED_PROXIES` isn't set: nginx on the same
+/// host, reaching the container through Docker's default bridge.
+const DEFAULT_TRUSTED_PROXIES: &str = "127.0.0.0/8, ::1, 172.17.0.0/16";
+
+/// Proxies we trust to set `X-Forwarded-For`, from the comma separated
+/// `TRUSTED_PROXIES` environment variable (e.g. `"10.0.0.0/8, 127.0.0.1"`).
+/// Set it to `""` to trust no one.
+static TRUSTED_PROXIES: LazyLock<Vec<IpRange>> = LazyLock::new(|| {
+    std::env::var("TRUSTED_PROXIES")
+        .unwrap_or_else(|_| DEFAULT_TRUSTED_PROXIES.to_string())
+        .split(',')
+        .map(str::trim)
+        .filter(|range| !range.is_empty())
+        .filter_map(|range| {
+            IpRange::from_str(range)
+                .inspect_err(|e| warn!("Ignoring trusted proxy {range}: {e}"))
+                .ok()
+        })
+        .collect()
+});
 
 pub struct RealIp(pub String);
 
@@ -19,17 +42,84 @@ where
         let connect_info = ConnectInfo::<SocketAddr>::from_request_parts(parts, _state)
             .await
             .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        let peer = connect_info.ip();
+
+        // Only a trusted proxy gets to tell us who the client is
+        if !is_trusted(&peer) {
+            return Ok(RealIp(peer.to_string()));
+        }
 
-        // Check for X-Forwarded-For
-        let ip = if let Some(forwarded) = parts.headers.get("x-forwarded-for") {
-            match forwarded.to_str() {
-                Ok(forwarded) => forwarded.split(',').next().unwrap().trim().to_string(),
-                Err(_) => connect_info.ip().to_string(),
+        let forwarded = parts
+            .headers
+            .get("x-forwarded-for")
+            .and_then(|forwarded| forwarded.to_str().ok())
+            .unwrap_or_default();
+
+        // Each proxy appends the address it received the request from, so walk
+        // back from the end and stop at the first hop that isn't one of ours.
+        let mut ip = peer;
+        for hop in forwarded.rsplit(',') {
+            match IpAddr::from_str(hop.trim()) {
+                Ok(hop) => {
+                    ip = hop;
+                    if !is_trusted(&hop) {
+                        break;
+                    }
+                }
+                Err(_) => break,
             }
-        } else {
-            connect_info.ip().to_string()
+        }
+
+        Ok(RealIp(ip.to_string()))
+    }
+}
+
+fn is_trusted(ip: &IpAddr) -> bool {
+    TRUSTED_PROXIES.iter().any(|range| range.contains(ip))
+}
+
+/// A CIDR block such as `10.0.0.0/8`; a bare address is a block of one.
+struct IpRange {
+    network: IpAddr,
+    prefix: u8,
+}
+
+impl IpRange {
+    fn contains(&self, ip: &IpAddr) -> bool {
+        match (self.network, ip) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => {
+                let mask = u32::MAX.checked_shl(32 - self.prefix as u32).unwrap_or(0);
+                u32::from(network) & mask == u32::from(*ip) & mask
+            }
+            (IpAddr::V6(network), IpAddr::V6(ip)) => {
+                let mask = u128::MAX.checked_shl(128 - self.prefix as u32).unwrap_or(0);
+                u128::from(network) & mask == u128::from(*ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for IpRange {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (network, prefix) = match s.split_once('/') {
+            Some((network, prefix)) => (network, Some(prefix)),
+            None => (s, None),
         };
 
-        Ok(RealIp(ip))
+        let network = IpAddr::from_str(network).map_err(|e| e.to_string())?;
+        let max_prefix = if network.is_ipv4() { 32 } else { 128 };
+        let prefix = match prefix {
+            Some(prefix) => prefix.parse::<u8>().map_err(|e| e.to_string())?,
+            None => max_prefix,
+        };
+
+        if prefix > max_prefix {
+            return Err(format!("Prefix /{prefix} is too long"));
+        }
+
+        Ok(Self { network, prefix })
     }
 }
@@ -0,0 +1,31 @@
+use crate::SharedState;
+use crate::domain::user::UserView;
+use crate::extract::BaseUser;
+use axum::response::{IntoResponse, Redirect, Response};
+use axum::{extract::FromRequestParts, http::request::Parts};
+
+/// A signed in user who is allowed to moderate, that is Royalty or an Admin.
+pub struct RoyaltyUser(pub Box<UserView>);
+
+impl FromRequestParts<SharedState> for RoyaltyUser {
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &SharedState,
+    ) -> Result<Self, Self::Rejection> {
+        let user = BaseUser::from_request_parts(parts, state)
+            .await
+            .map_err(|_| Redirect::to("/").into_response())?;
+
+        match user {
+            BaseUser::User(user) => {
+                if user.is_moderator() {
+                    return Ok(RoyaltyUser(user));
+                }
+                Err(Redirect::to("/dashboard").into_response())
+            }
+            _ => Err(Redirect::to("/").into_response()),
+        }
+    }
+}
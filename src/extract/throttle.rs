@@ -0,0 +1,82 @@
+use std::marker::PhantomData;
+
+use crate::SharedState;
+use crate::domain::api_token::{ApiToken, TOKEN_PREFIX};
+use crate::extract::{real_ip::RealIp, token_user::bearer_token};
+use crate::infrastructure::jwt::{JwtService, user_claims::UserClaims};
+use crate::util::rate_limit::RateLimitPolicy;
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::{extract::FromRequestParts, http::request::Parts};
+use axum_extra::extract::CookieJar;
+
+/// Counts the request against the policy `P`, both for the caller's IP and,
+/// when signed in or calling with a bearer token, for the user (or token).
+/// Rejects with a `429 Too Many Requests`.
+///
+/// Example:
+/// ```ignore
+/// async fn submit_town(_: Throttle<rate_limit::SubmitTown>, ...) { ... }
+/// ```
+pub struct Throttle<P: RateLimitPolicy>(PhantomData<P>);
+
+impl<P: RateLimitPolicy> FromRequestParts<SharedState> for Throttle<P> {
+    type Rejection = Response;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &SharedState,
+    ) -> Result<Self, Self::Rejection> {
+        let RealIp(ip) = RealIp::from_request_parts(parts, state)
+            .await
+            .map_err(|status| status.into_response())?;
+
+        let limiter = &state.rate_limiter;
+        limiter
+            .check::<P>(&format!("ip:{ip}"))
+            .map_err(too_many_requests)?;
+
+        if let Some(caller) = caller(parts) {
+            limiter.check::<P>(&caller).map_err(too_many_requests)?;
+        }
+
+        Ok(Throttle(PhantomData))
+    }
+}
+
+/// Who is calling, without going to the database: the subject of the JWT, or
+/// the hash of a personal access token (each token gets its own bucket).
+fn caller(parts: &Parts) -> Option<String> {
+    let jwt = match bearer_token(parts) {
+        Some(token) if token.starts_with(TOKEN_PREFIX) => {
+            return Some(format!("token:{}", ApiToken::hash(token)));
+        }
+        Some(token) => token.to_string(),
+        None => CookieJar::from_headers(&parts.headers)
+            .get("auth_token")?
+            .value()
+            .to_string(),
+    };
+
+    JwtService::verify::<UserClaims>(&jwt)
+        .ok()
+        .map(|token| format!("user:{}", token.claims.sub))
+}
+
+fn too_many_requests(retry_after: std::time::Duration) -> Response {
+    let seconds = retry_after.as_secs().max(1);
+    let message = format!("You're going a little too fast. Please try again in {seconds} seconds.");
+
+    // HTMX doesn't swap error responses, so we let the page know through an
+    // event instead (see `common.js`).
+    let mut headers = HeaderMap::new();
+    headers.insert("Retry-After", seconds.into());
+    if let Ok(trigger) = serde_json::json!({ "rateLimited": { "message": message } })
+        .to_string()
+        .parse()
+    {
+        headers.insert("HX-Trigger", trigger);
+    }
+
+    (StatusCode::TOO_MANY_REQUESTS, headers, message).into_response()
+}
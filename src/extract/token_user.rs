@@ -0,0 +1,45 @@
+use crate::SharedState;
+use crate::domain::{
+    api_token::{ApiToken, TOKEN_PREFIX},
+    user::UserView,
+};
+use crate::extract::real_ip::RealIp;
+use crate::util::api::ApiError;
+use axum::http::header::AUTHORIZATION;
+use axum::{extract::FromRequestParts, http::request::Parts};
+
+/// A user calling with a personal access token, `Authorization: Bearer ctr_...`.
+pub struct TokenUser(pub Box<UserView>, pub ApiToken);
+
+impl FromRequestParts<SharedState> for TokenUser {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &SharedState,
+    ) -> Result<Self, Self::Rejection> {
+        let secret = bearer_token(parts)
+            .filter(|token| token.starts_with(TOKEN_PREFIX))
+            .ok_or_else(ApiError::unauthorized)?
+            .to_string();
+
+        let RealIp(ip) = RealIp::from_request_parts(parts, state).await?;
+
+        let (user, token) = state
+            .api_token_service
+            .authenticate(&secret, &ip)
+            .await
+            .ok_or_else(ApiError::unauthorized)?;
+
+        Ok(TokenUser(Box::new(user), token))
+    }
+}
+
+pub fn bearer_token(parts: &Parts) -> Option<&str> {
+    parts
+        .headers
+        .get(AUTHORIZATION)
+        .and_then(|header| header.to_str().ok())
+        .and_then(|header| header.strip_prefix("Bearer "))
+        .map(str::trim)
+}
@@ -22,3 +22,24 @@ pub fn daydatetime<T: Display>(s: T, _: &dyn askama::Values) -> askama::Result<S
         Err(_) => Ok("Unknown Date and Time".to_string()),
     }
 }
+
+/// Race names are saved in lowercase and shown in title case, unless an admin
+/// has fixed one up (e.g. "Oak Leaf 5K"), in which case it's shown as typed.
+pub fn race_name<T: Display>(s: T, _: &dyn askama::Values) -> askama::Result<String> {
+    let name = s.to_string();
+    if name.chars().any(char::is_uppercase) {
+        return Ok(name);
+    }
+
+    Ok(name
+        .split(' ')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" "))
+}
@@ -0,0 +1,84 @@
+use sqlx::{query, query_as};
+
+use crate::{
+    DbConnection,
+    domain::api_token::{ApiToken, NewApiToken},
+};
+
+pub struct ApiTokenRepository {
+    db: DbConnection,
+}
+
+impl ApiTokenRepository {
+    pub fn new(db: &DbConnection) -> Self {
+        Self { db: db.clone() }
+    }
+
+    pub async fn create(&self, token: &NewApiToken) -> Result<ApiToken, String> {
+        query_as(
+            r#"
+            INSERT INTO api_tokens (user_id, name, token_hash, token_prefix, scopes)
+            VALUES (?, ?, ?, ?, ?)
+            RETURNING *
+            "#,
+        )
+        .bind(token.user_id)
+        .bind(&token.name)
+        .bind(&token.token_hash)
+        .bind(&token.token_prefix)
+        .bind(token.scopes_str())
+        .fetch_one(self.db.as_ref())
+        .await
+        .map_err(|e| format!("Unable to create the token: {e}"))
+    }
+
+    pub async fn find_for_user(&self, user_id: i64) -> Vec<ApiToken> {
+        query_as(
+            "SELECT * FROM api_tokens WHERE user_id = ? ORDER BY revoked_at IS NOT NULL, created_at DESC",
+        )
+        .bind(user_id)
+        .fetch_all(self.db.as_ref())
+        .await
+        .unwrap_or_default()
+    }
+
+    pub async fn find_active_by_hash(&self, token_hash: &str) -> Option<ApiToken> {
+        query_as("SELECT * FROM api_tokens WHERE token_hash = ? AND revoked_at IS NULL")
+            .bind(token_hash)
+            .fetch_optional(self.db.as_ref())
+            .await
+            .ok()
+            .flatten()
+    }
+
+    pub async fn revoke(&self, user_id: i64, token_id: i64) -> Result<(), String> {
+        let result = query(
+            r#"
+            UPDATE api_tokens SET revoked_at = CURRENT_TIMESTAMP
+            WHERE id = ? AND user_id = ? AND revoked_at IS NULL
+            "#,
+        )
+        .bind(token_id)
+        .bind(user_id)
+        .execute(self.db.as_ref())
+        .await
+        .map_err(|_| "Unable to revoke the token".to_string())?;
+
+        match result.rows_affected() {
+            0 => Err("Unable to find that token".to_string()),
+            _ => Ok(()),
+        }
+    }
+
+    pub async fn touch(&self, token_id: i64, ip: &str) -> Result<(), String> {
+        query(
+            "UPDATE api_tokens SET last_used_at = CURRENT_TIMESTAMP, last_used_ip = ? WHERE id = ?",
+        )
+        .bind(ip)
+        .bind(token_id)
+        .execute(self.db.as_ref())
+        .await
+        .map_err(|_| "Unable to update the token".to_string())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use sqlx::query;
+
+use crate::{
+    DbConnection,
+    domain::audit::{AuditLog, AuditLogSearchParams, NewAuditLog},
+    util::{
+        StringExt,
+        filter::Filter,
+        pagination::{CursorPage, CursorPagination, Paginatable},
+    },
+};
+
+pub struct AuditRepository {
+    db: DbConnection,
+}
+
+impl AuditRepository {
+    pub fn new(db: &DbConnection) -> Self {
+        Self { db: db.clone() }
+    }
+
+    pub async fn create(&self, entry: NewAuditLog) -> Result<(), String> {
+        query(
+            r#"
+            INSERT INTO audit_logs (
+                actor_id, action, entity_type, entity_id, before_json, after_json, ip_address
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(entry.actor_id)
+        .bind(entry.action)
+        .bind(entry.entity_type)
+        .bind(entry.entity_id)
+        .bind(entry.before_json)
+        .bind(entry.after_json)
+        .bind(entry.ip_address)
+        .execute(self.db.as_ref())
+        .await
+        .map_err(|e| format!("Unable to write to the audit log: {e}"))?;
+
+        Ok(())
+    }
+
+    /// The newest entries first. There's no total, since the log only grows.
+    pub async fn search(
+        &self,
+        params: &AuditLogSearchParams,
+        pagination: &CursorPagination,
+    ) -> CursorPage<AuditLog> {
+        let mut filter = Filter::new()
+            .and_bind_opt(
+                "entity_type = ?",
+                params
+                    .entity_type
+                    .as_ref()
+                    .filter(|e| !e.is_whitespace_or_empty())
+                    .cloned(),
+            )
+            .and_bind_opt("entity_id = ?", params.entity_id)
+            .and_bind_opt("DATE(created_at) >= ?", params.from)
+            .and_bind_opt("DATE(created_at) <= ?", params.to)
+            .desc("created_at")
+            .desc("id");
+
+        if let Some(actor) = params
+            .actor
+            .as_ref()
+            .filter(|a| !a.is_whitespace_or_empty())
+        {
+            let pattern = format!("%{}%", actor.trim().to_lowercase());
+            filter = filter.and_binds(
+                "(LOWER(actor_name) LIKE ? OR LOWER(actor_email) LIKE ?)",
+                [pattern.clone(), pattern],
+            );
+        }
+
+        AuditLog::paginate_cursor(&self.db, pagination, filter, false)
+            .await
+            .unwrap_or_default()
+    }
+}
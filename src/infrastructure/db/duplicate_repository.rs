@@ -0,0 +1,125 @@
+use sqlx::{query, query_as};
+
+use crate::{
+    DbConnection,
+    domain::duplicate::{DUPLICATE_WINDOW_DAYS, DuplicateStatus, RaceDuplicate, RacePair},
+};
+
+pub struct DuplicateRepository {
+    db: DbConnection,
+}
+
+impl DuplicateRepository {
+    pub fn new(db: &DbConnection) -> Self {
+        Self { db: db.clone() }
+    }
+
+    /// Listed races in the same town within `DUPLICATE_WINDOW_DAYS` of each
+    /// other that haven't been suggested (or dismissed) before.
+    pub async fn find_unchecked_pairs(&self) -> Vec<RacePair> {
+        query_as(
+            r#"
+            SELECT
+                a.id AS race_id, a.name AS race_name, a.miles AS race_miles,
+                a.town_id AS race_town_id, a.start_at AS race_start_at,
+                b.id AS duplicate_race_id, b.name AS duplicate_name, b.miles AS duplicate_miles,
+                b.town_id AS duplicate_town_id, b.start_at AS duplicate_start_at
+            FROM races a
+            JOIN races b ON b.town_id = a.town_id AND b.id > a.id
+            WHERE a.status = 'approved' AND a.deleted_at IS NULL
+            AND b.status = 'approved' AND b.deleted_at IS NULL
+            AND ABS(JULIANDAY(a.start_at) - JULIANDAY(b.start_at)) <= ?
+            AND NOT EXISTS (
+                SELECT 1 FROM race_duplicates d
+                WHERE d.race_id = a.id AND d.duplicate_race_id = b.id
+            )
+            "#,
+        )
+        .bind(DUPLICATE_WINDOW_DAYS)
+        .fetch_all(self.db.as_ref())
+        .await
+        .unwrap_or_default()
+    }
+
+    pub async fn suggest(&self, pair: &RacePair, score: f64) -> Result<(), String> {
+        query(
+            r#"
+            INSERT INTO race_duplicates (race_id, duplicate_race_id, score)
+            VALUES (?, ?, ?)
+            ON CONFLICT(race_id, duplicate_race_id) DO NOTHING
+            "#,
+        )
+        .bind(pair.race_id)
+        .bind(pair.duplicate_race_id)
+        .bind(score)
+        .execute(self.db.as_ref())
+        .await
+        .map_err(|e| format!("Unable to save the duplicate race suggestion: {e}"))?;
+
+        Ok(())
+    }
+
+    const SELECT: &str = r#"
+        SELECT
+            d.id, d.race_id, d.duplicate_race_id, d.score,
+            (SELECT COUNT(*) FROM race_results WHERE race_id = d.race_id)
+                + (SELECT COUNT(*) FROM race_rsvps WHERE race_id = d.race_id) AS race_members,
+            (SELECT COUNT(*) FROM race_results WHERE race_id = d.duplicate_race_id)
+                + (SELECT COUNT(*) FROM race_rsvps WHERE race_id = d.duplicate_race_id)
+                AS duplicate_members
+        FROM race_duplicates d
+        JOIN races a ON a.id = d.race_id
+        JOIN races b ON b.id = d.duplicate_race_id
+    "#;
+
+    /// Suggestions where both races are still listed, most alike first.
+    pub async fn find_pending(&self) -> Vec<RaceDuplicate> {
+        query_as(&format!(
+            r#"
+            {}
+            WHERE d.status = 'pending'
+            AND a.status = 'approved' AND a.deleted_at IS NULL
+            AND b.status = 'approved' AND b.deleted_at IS NULL
+            ORDER BY d.score DESC, d.created_at ASC
+            "#,
+            Self::SELECT
+        ))
+        .fetch_all(self.db.as_ref())
+        .await
+        .unwrap_or_default()
+    }
+
+    pub async fn find_pending_by_id(&self, duplicate_id: i64) -> Result<RaceDuplicate, String> {
+        query_as(&format!(
+            "{} WHERE d.id = ? AND d.status = 'pending'",
+            Self::SELECT
+        ))
+        .bind(duplicate_id)
+        .fetch_one(self.db.as_ref())
+        .await
+        .map_err(|_| "That suggestion has already been dealt with".to_string())
+    }
+
+    pub async fn review(
+        &self,
+        duplicate_id: i64,
+        status: DuplicateStatus,
+        reviewed_by: i64,
+    ) -> Result<(), String> {
+        query(
+            r#"
+            UPDATE race_duplicates
+            SET status = ?, reviewed_by = ?, reviewed_at = CURRENT_TIMESTAMP
+            WHERE id = ? AND status = 'pending'
+            "#,
+        )
+        .bind(status)
+        .bind(reviewed_by)
+        .bind(duplicate_id)
+        .execute(self.db.as_ref())
+        .await
+        .map_err(|_| "Unable to update the suggestion".to_string())?;
+
+        Ok(())
+    }
+}
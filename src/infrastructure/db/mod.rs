@@ -1,13 +1,33 @@
 use log::info;
 use sqlx::{Connection, SqlitePool, migrate::Migrator, sqlite::SqlitePoolOptions};
 
+pub mod api_token_repository;
+pub mod audit_repository;
+pub mod duplicate_repository;
+pub mod notification_repository;
+pub mod progress_repository;
+pub mod push_repository;
 pub mod race_repository;
+pub mod search_repository;
+pub mod strava_repository;
+pub mod submission_repository;
 pub mod town_repository;
 pub mod user_repository;
+pub mod webhook_repository;
 
+pub use api_token_repository::ApiTokenRepository;
+pub use audit_repository::AuditRepository;
+pub use duplicate_repository::DuplicateRepository;
+pub use notification_repository::NotificationRepository;
+pub use progress_repository::ProgressRepository;
+pub use push_repository::PushRepository;
 pub use race_repository::RaceRepository;
+pub use search_repository::SearchRepository;
+pub use strava_repository::StravaRepository;
+pub use submission_repository::SubmissionRepository;
 pub use town_repository::TownRepository;
 pub use user_repository::UserRepository;
+pub use webhook_repository::WebhookRepository;
 
 static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
 
@@ -0,0 +1,129 @@
+use sqlx::{query, query_as};
+
+use crate::{
+    DbConnection,
+    domain::notification::{
+        NotificationPreferences, NotificationRecipient, UpdateNotificationPreferences,
+    },
+};
+
+pub struct NotificationRepository {
+    db: DbConnection,
+}
+
+impl NotificationRepository {
+    pub fn new(db: &DbConnection) -> Self {
+        Self { db: db.clone() }
+    }
+
+    /// Everyone has preferences (all off) from the first time we look.
+    pub async fn find_or_create(&self, user_id: i64) -> Result<NotificationPreferences, String> {
+        query(
+            r#"
+            INSERT INTO notification_preferences (user_id, unsubscribe_token)
+            VALUES (?, ?)
+            ON CONFLICT(user_id) DO NOTHING
+            "#,
+        )
+        .bind(user_id)
+        .bind(NotificationPreferences::generate_token())
+        .execute(self.db.as_ref())
+        .await
+        .map_err(|e| format!("Unable to create notification preferences: {e}"))?;
+
+        query_as("SELECT * FROM notification_preferences WHERE user_id = ?")
+            .bind(user_id)
+            .fetch_one(self.db.as_ref())
+            .await
+            .map_err(|_| "Unable to find notification preferences".to_string())
+    }
+
+    pub async fn update(
+        &self,
+        user_id: i64,
+        preferences: &UpdateNotificationPreferences,
+    ) -> Result<NotificationPreferences, String> {
+        query_as(
+            r#"
+            UPDATE notification_preferences
+            SET weekly_digest = ?, instant_alerts = ?, radius_miles = ?,
+                push_race_reminders = ?, push_milestones = ?, push_submission_failures = ?
+            WHERE user_id = ?
+            RETURNING *
+            "#,
+        )
+        .bind(preferences.weekly_digest)
+        .bind(preferences.instant_alerts)
+        .bind(preferences.radius_miles)
+        .bind(preferences.push_race_reminders)
+        .bind(preferences.push_milestones)
+        .bind(preferences.push_submission_failures)
+        .bind(user_id)
+        .fetch_one(self.db.as_ref())
+        .await
+        .map_err(|_| "Unable to update notification preferences".to_string())
+    }
+
+    pub async fn find_by_token(&self, token: &str) -> Option<NotificationPreferences> {
+        query_as("SELECT * FROM notification_preferences WHERE unsubscribe_token = ?")
+            .bind(token)
+            .fetch_optional(self.db.as_ref())
+            .await
+            .ok()
+            .flatten()
+    }
+
+    pub async fn unsubscribe(&self, token: &str) -> Result<(), String> {
+        let result = query(
+            r#"
+            UPDATE notification_preferences
+            SET weekly_digest = 0, instant_alerts = 0
+            WHERE unsubscribe_token = ?
+            "#,
+        )
+        .bind(token)
+        .execute(self.db.as_ref())
+        .await
+        .map_err(|_| "Unable to unsubscribe".to_string())?;
+
+        match result.rows_affected() {
+            0 => Err("Unable to find that subscription".to_string()),
+            _ => Ok(()),
+        }
+    }
+
+    pub async fn find_digest_recipients(&self) -> Vec<NotificationRecipient> {
+        self.find_recipients("p.weekly_digest = 1").await
+    }
+
+    pub async fn find_instant_recipients(&self) -> Vec<NotificationRecipient> {
+        self.find_recipients("p.instant_alerts = 1").await
+    }
+
+    async fn find_recipients(&self, condition: &str) -> Vec<NotificationRecipient> {
+        query_as(&format!(
+            r#"
+            SELECT p.user_id, u.email, u.first_name, u.hometown_id, p.radius_miles,
+                   p.unsubscribe_token, p.last_digest_at
+            FROM notification_preferences p
+            JOIN users u ON u.id = p.user_id
+            WHERE u.locked = 0 AND {condition}
+            "#
+        ))
+        .fetch_all(self.db.as_ref())
+        .await
+        .unwrap_or_default()
+    }
+
+    pub async fn mark_digest_sent(&self, user_id: i64) -> Result<(), String> {
+        query(
+            "UPDATE notification_preferences SET last_digest_at = CURRENT_TIMESTAMP WHERE user_id = ?",
+        )
+        .bind(user_id)
+        .execute(self.db.as_ref())
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+}
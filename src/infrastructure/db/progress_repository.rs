@@ -0,0 +1,167 @@
+use sqlx::{SqliteConnection, query, query_as};
+
+use crate::{
+    DbConnection,
+    domain::progress::{ProgressDrift, UserProgress},
+};
+
+pub struct ProgressRepository {
+    db: DbConnection,
+}
+
+impl ProgressRepository {
+    pub fn new(db: &DbConnection) -> Self {
+        Self { db: db.clone() }
+    }
+
+    /// Counts a town the member just completed. Run it in the same
+    /// transaction as adding it to `completed_towns`, so the counters can't
+    /// be left behind.
+    pub async fn record_completed(
+        conn: &mut SqliteConnection,
+        user_id: i64,
+        town_id: i64,
+    ) -> Result<(), sqlx::Error> {
+        query(
+            r#"
+            INSERT INTO user_progress (user_id, completed_count, elusive_count, last_completed_at)
+            SELECT ct.user_id, 1, t.is_elusive, ct.created_at
+            FROM completed_towns ct
+            JOIN towns t ON t.id = ct.town_id
+            WHERE ct.user_id = ? AND ct.town_id = ?
+            ON CONFLICT(user_id) DO UPDATE SET
+                completed_count = completed_count + 1,
+                elusive_count = elusive_count + excluded.elusive_count,
+                last_completed_at = excluded.last_completed_at
+            "#,
+        )
+        .bind(user_id)
+        .bind(town_id)
+        .execute(&mut *conn)
+        .await?;
+
+        query(
+            r#"
+            INSERT INTO user_county_progress (user_id, county_id, completed_count)
+            SELECT ?, county_id, 1 FROM towns WHERE id = ?
+            ON CONFLICT(user_id, county_id) DO UPDATE SET
+                completed_count = completed_count + 1
+            "#,
+        )
+        .bind(user_id)
+        .bind(town_id)
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+
+    /// A member's progress, all zeros if they haven't completed a town yet.
+    pub async fn find_by_user(&self, user_id: i64) -> UserProgress {
+        query_as("SELECT * FROM user_progress WHERE user_id = ?")
+            .bind(user_id)
+            .fetch_optional(self.db.as_ref())
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or(UserProgress {
+                user_id,
+                ..Default::default()
+            })
+    }
+
+    /// Every counter that doesn't match `completed_towns`.
+    pub async fn find_drift(&self) -> Result<Vec<ProgressDrift>, sqlx::Error> {
+        query_as(
+            r#"
+            WITH actual AS (
+                SELECT ct.user_id, COUNT(*) AS completed_count,
+                    SUM(t.is_elusive) AS elusive_count, MAX(ct.created_at) AS last_completed_at
+                FROM completed_towns ct
+                JOIN towns t ON t.id = ct.town_id
+                GROUP BY ct.user_id
+            ),
+            totals AS (
+                SELECT ids.user_id,
+                    p.completed_count AS stored_completed, a.completed_count AS actual_completed,
+                    p.elusive_count AS stored_elusive, a.elusive_count AS actual_elusive,
+                    p.last_completed_at AS stored_last, a.last_completed_at AS actual_last
+                FROM (SELECT user_id FROM actual UNION SELECT user_id FROM user_progress) ids
+                LEFT JOIN user_progress p ON p.user_id = ids.user_id
+                LEFT JOIN actual a ON a.user_id = ids.user_id
+            ),
+            actual_counties AS (
+                SELECT ct.user_id, t.county_id, COUNT(*) AS completed_count
+                FROM completed_towns ct
+                JOIN towns t ON t.id = ct.town_id
+                GROUP BY ct.user_id, t.county_id
+            ),
+            county_totals AS (
+                SELECT ids.user_id, ids.county_id,
+                    cp.completed_count AS stored, ac.completed_count AS actual
+                FROM (
+                    SELECT user_id, county_id FROM actual_counties
+                    UNION SELECT user_id, county_id FROM user_county_progress
+                ) ids
+                LEFT JOIN user_county_progress cp
+                    ON cp.user_id = ids.user_id AND cp.county_id = ids.county_id
+                LEFT JOIN actual_counties ac
+                    ON ac.user_id = ids.user_id AND ac.county_id = ids.county_id
+            )
+            SELECT user_id, 'completed_count' AS counter,
+                CAST(stored_completed AS TEXT) AS stored, CAST(actual_completed AS TEXT) AS actual
+            FROM totals WHERE COALESCE(stored_completed, 0) != COALESCE(actual_completed, 0)
+            UNION ALL
+            SELECT user_id, 'elusive_count',
+                CAST(stored_elusive AS TEXT), CAST(actual_elusive AS TEXT)
+            FROM totals WHERE COALESCE(stored_elusive, 0) != COALESCE(actual_elusive, 0)
+            UNION ALL
+            SELECT user_id, 'last_completed_at', stored_last, actual_last
+            FROM totals WHERE stored_last IS NOT actual_last
+            UNION ALL
+            SELECT ct.user_id, 'county:' || c.name, CAST(ct.stored AS TEXT), CAST(ct.actual AS TEXT)
+            FROM county_totals ct
+            JOIN counties c ON c.id = ct.county_id
+            WHERE COALESCE(ct.stored, 0) != COALESCE(ct.actual, 0)
+            ORDER BY user_id, counter
+            "#,
+        )
+        .fetch_all(self.db.as_ref())
+        .await
+    }
+
+    /// Counts everyone's progress again from `completed_towns`.
+    pub async fn rebuild(&self) -> Result<(), sqlx::Error> {
+        let mut tx = self.db.begin().await?;
+
+        query("DELETE FROM user_progress").execute(&mut *tx).await?;
+        query(
+            r#"
+            INSERT INTO user_progress (user_id, completed_count, elusive_count, last_completed_at)
+            SELECT ct.user_id, COUNT(*), SUM(t.is_elusive), MAX(ct.created_at)
+            FROM completed_towns ct
+            JOIN towns t ON t.id = ct.town_id
+            GROUP BY ct.user_id
+            "#,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        query("DELETE FROM user_county_progress")
+            .execute(&mut *tx)
+            .await?;
+        query(
+            r#"
+            INSERT INTO user_county_progress (user_id, county_id, completed_count)
+            SELECT ct.user_id, t.county_id, COUNT(*)
+            FROM completed_towns ct
+            JOIN towns t ON t.id = ct.town_id
+            GROUP BY ct.user_id, t.county_id
+            "#,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await
+    }
+}
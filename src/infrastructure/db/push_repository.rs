@@ -0,0 +1,94 @@
+use sqlx::{query, query_as};
+
+use crate::{
+    DbConnection,
+    domain::push::{NewPushSubscription, PushKind, PushSubscription},
+};
+
+pub struct PushRepository {
+    db: DbConnection,
+}
+
+impl PushRepository {
+    pub fn new(db: &DbConnection) -> Self {
+        Self { db: db.clone() }
+    }
+
+    /// Browsers hand out one endpoint per device, so subscribing again (even
+    /// as someone else) just takes the existing row over.
+    pub async fn save(
+        &self,
+        user_id: i64,
+        subscription: &NewPushSubscription,
+        user_agent: Option<&str>,
+    ) -> Result<PushSubscription, String> {
+        query_as(
+            r#"
+            INSERT INTO push_subscriptions (user_id, endpoint, p256dh, auth, user_agent)
+            VALUES (?, ?, ?, ?, ?)
+            ON CONFLICT(endpoint) DO UPDATE SET
+                user_id = excluded.user_id,
+                p256dh = excluded.p256dh,
+                auth = excluded.auth,
+                user_agent = excluded.user_agent
+            RETURNING *
+            "#,
+        )
+        .bind(user_id)
+        .bind(&subscription.endpoint)
+        .bind(&subscription.keys.p256dh)
+        .bind(&subscription.keys.auth)
+        .bind(user_agent)
+        .fetch_one(self.db.as_ref())
+        .await
+        .map_err(|e| format!("Unable to save the push subscription: {e}"))
+    }
+
+    pub async fn delete(&self, user_id: i64, endpoint: &str) -> Result<(), String> {
+        query("DELETE FROM push_subscriptions WHERE user_id = ? AND endpoint = ?")
+            .bind(user_id)
+            .bind(endpoint)
+            .execute(self.db.as_ref())
+            .await
+            .map_err(|_| "Unable to remove the push subscription".to_string())?;
+
+        Ok(())
+    }
+
+    pub async fn delete_by_id(&self, id: i64) -> Result<(), String> {
+        query("DELETE FROM push_subscriptions WHERE id = ?")
+            .bind(id)
+            .execute(self.db.as_ref())
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    pub async fn count_for_user(&self, user_id: i64) -> i64 {
+        query_as("SELECT COUNT(*) FROM push_subscriptions WHERE user_id = ?")
+            .bind(user_id)
+            .fetch_one(self.db.as_ref())
+            .await
+            .map(|(count,)| count)
+            .unwrap_or_default()
+    }
+
+    /// The member's devices, if they have opted in to this kind of push.
+    pub async fn find_for(&self, user_id: i64, kind: PushKind) -> Vec<PushSubscription> {
+        query_as(&format!(
+            r#"
+            SELECT s.*
+            FROM push_subscriptions s
+            JOIN notification_preferences p ON p.user_id = s.user_id
+            JOIN users u ON u.id = s.user_id
+            WHERE s.user_id = ? AND u.locked = 0 AND p.{} = 1
+            "#,
+            kind.preference_column()
+        ))
+        .bind(user_id)
+        .fetch_all(self.db.as_ref())
+        .await
+        .unwrap_or_default()
+    }
+}
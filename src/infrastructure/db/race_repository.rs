@@ -1,9 +1,22 @@
-use sqlx::{query, query_as};
+use std::collections::HashMap;
+
+use chrono::{NaiveDate, NaiveDateTime};
+use sqlx::{SqliteConnection, query, query_as};
 
 use crate::{
     DbConnection,
-    domain::race::{NewRace, NewRaceResult, RaceSearchParams, RaceView, SubmitTownSearchParams},
-    util::pagination::{Paginatable, PaginatedResponse, Pagination},
+    domain::race::{
+        AdminRaceSearchParams, NewRace, NewRaceResult, RaceEdition, RaceEvent, RaceEventGroup,
+        RaceMember, RaceResultView, RaceSearchParams, RaceStatus, RaceSubmission, RaceView,
+        SubmitTownSearchParams,
+    },
+    domain::submission::Completion,
+    infrastructure::db::TownRepository,
+    util::{
+        StringExt,
+        filter::Filter,
+        pagination::{CursorPage, CursorPagination, Paginatable, PaginatedResponse, Pagination},
+    },
 };
 
 pub struct RaceRepository {
@@ -25,26 +38,251 @@ impl RaceRepository {
         Ok(race)
     }
 
+    /// Races published since the given time that haven't happened yet.
+    pub async fn find_upcoming_created_since(&self, since: NaiveDateTime) -> Vec<RaceView> {
+        query_as(
+            r#"
+            SELECT * FROM races_view
+            WHERE status = 'approved' AND deleted_at IS NULL
+            AND COALESCE(reviewed_at, created_at) >= ?
+            AND start_at > CURRENT_TIMESTAMP
+            ORDER BY start_at ASC
+            "#,
+        )
+        .bind(since)
+        .fetch_all(self.db.as_ref())
+        .await
+        .unwrap_or_default()
+    }
+
+    /// Finds or adds the event the race is a distance of, i.e. the race of
+    /// that name in that town on that day. A name with capitals in it
+    /// replaces a lowercase one, the same as fixing up a race's name.
+    async fn event_id(
+        conn: &mut SqliteConnection,
+        race: &NewRace,
+        name: &str,
+    ) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar(
+            r#"
+            INSERT INTO race_events (town_id, name, event_date, race_url)
+            VALUES (?, ?, DATE(?), ?)
+            ON CONFLICT(town_id, name COLLATE NOCASE, event_date) DO UPDATE
+            SET name = CASE
+                    WHEN excluded.name = LOWER(excluded.name) THEN race_events.name
+                    ELSE excluded.name
+                END,
+                race_url = COALESCE(race_events.race_url, excluded.race_url)
+            RETURNING id
+            "#,
+        )
+        .bind(race.town_id)
+        .bind(name)
+        .bind(race.start_at)
+        .bind(&race.race_url)
+        .fetch_one(conn)
+        .await
+    }
+
     pub async fn get_or_create(&self, race: NewRace) -> Result<RaceView, String> {
+        let error = |_| "Something went wrong creating the race".to_string();
+        let mut tx = self.db.begin().await.map_err(error)?;
+        let race_id = Self::upsert(&mut tx, &race).await?;
+        tx.commit().await.map_err(error)?;
+        self.find_by_id(race_id).await
+    }
+
+    /// Adds the race, or finds the one we already have.
+    async fn upsert(conn: &mut SqliteConnection, race: &NewRace) -> Result<i64, String> {
+        let error = |_| "Something went wrong creating the race".to_string();
+        let event_id = Self::event_id(&mut *conn, race, &race.name.to_lowercase())
+            .await
+            .map_err(error)?;
         let race_id: i64 = sqlx::query_scalar(
             r#"
-       		INSERT INTO races (town_id, name, miles, start_at, race_url)
-            VALUES (?, LOWER(?), ?, ?, ?)
-            ON CONFLICT(town_id, name, miles, start_at)
-            DO UPDATE SET name = name
+       		INSERT INTO races (town_id, name, miles, start_at, race_url, event_id)
+            VALUES (?, LOWER(?), ?, ?, ?, ?)
+            ON CONFLICT(town_id, name COLLATE NOCASE, miles, start_at)
+            DO UPDATE SET event_id = COALESCE(event_id, excluded.event_id)
             RETURNING id
        		"#,
         )
         .bind(race.town_id)
-        .bind(race.name)
+        .bind(&race.name)
         .bind(race.miles.value())
         .bind(race.start_at)
-        .bind(race.race_url)
-        .fetch_one(self.db.as_ref())
+        .bind(&race.race_url)
+        .bind(event_id)
+        .fetch_one(&mut *conn)
         .await
-        .map_err(|_| "Something went wrong creating the race")?;
+        .map_err(error)?;
 
-        self.find_by_id(race_id).await
+        Self::listed(&mut *conn, race_id).await
+    }
+
+    /// The race results for `race_id` go on: the one it was merged into, if
+    /// it was. Only approved races can take results, so submitting one doesn't
+    /// get around moderation.
+    async fn listed(conn: &mut SqliteConnection, race_id: i64) -> Result<i64, String> {
+        let (race_id, status, deleted): (i64, RaceStatus, bool) = query_as(
+            r#"
+            WITH RECURSIVE merges(id, status, deleted, merged_into_id, depth) AS (
+                SELECT id, status, deleted_at IS NOT NULL, merged_into_id, 0
+                FROM races WHERE id = ?
+                UNION ALL
+                SELECT r.id, r.status, r.deleted_at IS NOT NULL, r.merged_into_id, m.depth + 1
+                FROM races r
+                JOIN merges m ON r.id = m.merged_into_id
+                WHERE m.status = 'merged' AND m.depth < 10
+            )
+            SELECT id, status, deleted FROM merges ORDER BY depth DESC LIMIT 1
+            "#,
+        )
+        .bind(race_id)
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|_| "Something went wrong finding the race".to_string())?;
+
+        match status {
+            _ if deleted => Err("That race has been removed".to_string()),
+            RaceStatus::Rejected => Err("That race was turned down by a moderator".to_string()),
+            // Merged into a race that has since been deleted outright.
+            RaceStatus::Merged => Err("That race has been removed".to_string()),
+            RaceStatus::Pending => Err("That race is still waiting on a moderator".to_string()),
+            RaceStatus::Approved => Ok(race_id),
+        }
+    }
+
+    /// Records a member's result all at once: the race if it's new, the town
+    /// as completed and the result itself. Nothing is saved if any of it fails.
+    pub async fn record_result(
+        &self,
+        user_id: i64,
+        race: NewRace,
+        notes: Option<String>,
+    ) -> Result<(RaceView, Completion), String> {
+        let mut tx = self
+            .db
+            .begin()
+            .await
+            .map_err(|_| "Unable to save your result".to_string())?;
+
+        let race_id = Self::upsert(&mut tx, &race).await?;
+        let new_town = TownRepository::complete(&mut tx, user_id, race.town_id)
+            .await
+            .map_err(|_| "Unable to mark town as completed".to_string())?;
+        let recorded = query(
+            r#"
+            INSERT INTO race_results (user_id, race_id, notes)
+            VALUES (?, ?, ?)
+            ON CONFLICT(user_id, race_id) DO NOTHING
+            "#,
+        )
+        .bind(user_id)
+        .bind(race_id)
+        .bind(notes)
+        .execute(&mut *tx)
+        .await
+        .map_err(|_| "Something went wrong submitting the result".to_string())?
+        .rows_affected()
+            > 0;
+
+        tx.commit()
+            .await
+            .map_err(|_| "Unable to save your result".to_string())?;
+
+        let completion = if new_town {
+            Completion::NewTown
+        } else if recorded {
+            Completion::RanAgain
+        } else {
+            Completion::AlreadyRecorded
+        };
+        Ok((self.find_by_id(race_id).await?, completion))
+    }
+
+    /// Whether the member already has a result for this race, or why it
+    /// can't take one.
+    pub async fn has_result(&self, user_id: i64, race: &NewRace) -> Result<bool, String> {
+        let error = |_| "Something went wrong finding the race".to_string();
+        let mut conn = self.db.acquire().await.map_err(error)?;
+
+        let race_id: Option<i64> = sqlx::query_scalar(
+            r#"
+            SELECT id FROM races
+            WHERE town_id = ? AND name = ? COLLATE NOCASE AND miles = ? AND start_at = ?
+            "#,
+        )
+        .bind(race.town_id)
+        .bind(&race.name)
+        .bind(race.miles.value())
+        .bind(race.start_at)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(error)?;
+        let Some(race_id) = race_id else {
+            return Ok(false);
+        };
+
+        let race_id = Self::listed(&mut conn, race_id).await?;
+        sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM race_results WHERE user_id = ? AND race_id = ?)",
+        )
+        .bind(user_id)
+        .bind(race_id)
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(error)
+    }
+
+    /// Creates an event and its distances in one go, returning the distances
+    /// that are new. A member's pending race that turns up in the import is
+    /// approved, since the Society has confirmed it.
+    pub async fn create_event_if_new(&self, races: &[NewRace]) -> Result<Vec<RaceView>, String> {
+        let Some(first) = races.first() else {
+            return Ok(vec![]);
+        };
+
+        let error = |_| "Something went wrong creating the race".to_string();
+        let mut tx = self.db.begin().await.map_err(error)?;
+
+        let event_id = Self::event_id(&mut tx, first, &first.name.to_lowercase())
+            .await
+            .map_err(error)?;
+        let mut race_ids = vec![];
+        for race in races {
+            let race_id: Option<i64> = sqlx::query_scalar(
+                r#"
+                INSERT INTO races (town_id, name, miles, start_at, race_url, event_id)
+                VALUES (?, LOWER(?), ?, ?, ?, ?)
+                ON CONFLICT(town_id, name COLLATE NOCASE, miles, start_at) DO UPDATE
+                SET status = 'approved', reviewed_at = CURRENT_TIMESTAMP,
+                    event_id = COALESCE(event_id, excluded.event_id)
+                WHERE status = 'pending'
+                RETURNING id
+                "#,
+            )
+            .bind(race.town_id)
+            .bind(&race.name)
+            .bind(race.miles.value())
+            .bind(race.start_at)
+            .bind(&race.race_url)
+            .bind(event_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(error)?;
+
+            race_ids.extend(race_id);
+        }
+
+        tx.commit().await.map_err(error)?;
+
+        let mut new_races = vec![];
+        for race_id in race_ids {
+            new_races.push(self.find_by_id(race_id).await?);
+        }
+
+        Ok(new_races)
     }
 
     pub async fn save_result(&self, result: NewRaceResult) -> Result<(), String> {
@@ -64,44 +302,653 @@ impl RaceRepository {
         Ok(())
     }
 
-    pub async fn search_for_upcoming(
+    pub async fn find_results_for_user(
         &self,
-        params: RaceSearchParams,
-    ) -> PaginatedResponse<RaceView> {
-        let search_str = &format!(
-            "%{}%",
-            params.race_name.clone().unwrap_or_default().to_lowercase()
-        );
-        RaceView::paginate_filter(
+        user_id: i64,
+        pagination: &Pagination,
+    ) -> PaginatedResponse<RaceResultView> {
+        RaceResultView::paginate_filter(
             &self.db,
-            &Pagination::from(params),
-            Some(r#"LOWER(name) LIKE ? AND start_at >= DateTime('now') ORDER BY start_at ASC"#),
-            vec![search_str],
+            pagination,
+            &Filter::new()
+                .and_bind("user_id = ?", user_id)
+                .desc("start_at"),
+        )
+        .await
+        .unwrap_or_default()
+    }
+
+    /// The filter and sort for upcoming races or events matching the
+    /// search. `distance` is how to match the distance bucket's shortest and
+    /// longest miles. Whether a town's been completed is only checked for a
+    /// signed-in member.
+    fn upcoming_filter(
+        filter: Filter,
+        params: &RaceSearchParams,
+        user_id: Option<i64>,
+        distance: &'static str,
+    ) -> Filter {
+        let mut filter = filter
+            .and("start_at >= DateTime('now')")
+            .and_bind(
+                "LOWER(name) LIKE ?",
+                format!(
+                    "%{}%",
+                    params.race_name.clone().unwrap_or_default().to_lowercase()
+                ),
+            )
+            .and_bind_opt("town_id = ?", params.town_id)
+            .and_bind_opt(
+                "town_id IN (SELECT id FROM towns WHERE county_id = ?)",
+                params.county_id,
+            )
+            .and_bind_opt("DATE(start_at) >= ?", params.from)
+            .and_bind_opt("DATE(start_at) <= ?", params.to)
+            .and_if(
+                params.weekends == Some(true),
+                "strftime('%w', start_at) IN ('0', '6')",
+            )
+            .and_if(params.elusive == Some(true), "is_elusive = 1")
+            .and_bind_opt(
+                "town_id NOT IN (SELECT town_id FROM completed_towns WHERE user_id = ?)",
+                user_id.filter(|_| params.not_completed == Some(true)),
+            );
+
+        if let Some(bucket) = params.distance {
+            let (shortest, longest) = bucket.range();
+            filter = filter.and_binds(distance, [shortest, longest]);
+        }
+
+        filter.order_by(params.sort.unwrap_or_default().order_by())
+    }
+
+    /// Upcoming events, with their distances, matching the search.
+    pub async fn search_upcoming_events(
+        &self,
+        params: &RaceSearchParams,
+        pagination: &CursorPagination,
+        user_id: Option<i64>,
+    ) -> CursorPage<RaceEventGroup> {
+        let filter = Self::upcoming_filter(
+            Filter::new(),
+            params,
+            user_id,
+            r#"id IN (
+                SELECT event_id FROM races
+                WHERE miles BETWEEN ? AND ? AND status = 'approved' AND deleted_at IS NULL
+            )"#,
+        );
+        let events = RaceEvent::paginate_cursor(&self.db, pagination, filter, true)
+            .await
+            .unwrap_or_default();
+
+        let mut by_event = self.races_by_event(&events.items).await;
+        events.map(|event| RaceEventGroup {
+            races: by_event.remove(&event.id).unwrap_or_default(),
+            event,
+        })
+    }
+
+    /// Each event's distances that members can see.
+    async fn races_by_event(&self, events: &[RaceEvent]) -> HashMap<i64, Vec<RaceView>> {
+        let event_ids: Vec<i64> = events.iter().map(|event| event.id).collect();
+        let races: Vec<RaceView> = query_as(
+            r#"
+            SELECT * FROM races_view
+            WHERE event_id IN (SELECT value FROM json_each(?))
+            AND status = 'approved' AND deleted_at IS NULL
+            ORDER BY start_at ASC, miles ASC
+            "#,
         )
+        .bind(serde_json::to_string(&event_ids).unwrap_or_default())
+        .fetch_all(self.db.as_ref())
         .await
-        .unwrap()
+        .unwrap_or_default();
+
+        let mut by_event: HashMap<i64, Vec<RaceView>> = HashMap::new();
+        for race in races {
+            if let Some(event_id) = race.event_id {
+                by_event.entry(event_id).or_default().push(race);
+            }
+        }
+
+        by_event
     }
 
+    pub async fn search_for_upcoming(
+        &self,
+        params: &RaceSearchParams,
+        user_id: Option<i64>,
+    ) -> PaginatedResponse<RaceView> {
+        let filter = Self::upcoming_filter(
+            Filter::new().and("status = 'approved' AND deleted_at IS NULL"),
+            params,
+            user_id,
+            "miles BETWEEN ? AND ?",
+        );
+        RaceView::paginate_filter(&self.db, &Pagination::from(params), &filter)
+            .await
+            .unwrap_or_default()
+    }
+
+    /// Recent races in the town. `matching` is an FTS5 query for the name,
+    /// or `None` for all of them.
     pub async fn submit_town_search(
         &self,
         params: &SubmitTownSearchParams,
-    ) -> PaginatedResponse<RaceView> {
-        let pattern = &format!("%{}%", params.race_name.to_lowercase());
+        matching: Option<&str>,
+    ) -> PaginatedResponse<RaceEventGroup> {
+        let filter = Filter::new()
+            .and_bind("town_id = ?", params.town_id)
+            .and("start_at >= DateTime('now', '-6 months')")
+            .and("start_at <= DateTime('now')")
+            .and_bind_opt(
+                "id IN (SELECT item_id FROM search_index WHERE search_index MATCH ? AND kind = 'race')",
+                matching,
+            )
+            .desc("start_at");
 
-        RaceView::paginate_filter(
-            &self.db,
-            &Pagination::default(),
-            Some(
+        let events = RaceEvent::paginate_filter(&self.db, &Pagination::default(), &filter)
+            .await
+            .unwrap_or_default();
+
+        let mut by_event = self.races_by_event(&events.items).await;
+        events.map(|event| RaceEventGroup {
+            races: by_event.remove(&event.id).unwrap_or_default(),
+            event,
+        })
+    }
+
+    pub async fn rsvp(&self, user_id: i64, race_id: i64) -> Result<(), String> {
+        query("INSERT INTO race_rsvps (user_id, race_id) VALUES (?, ?) ON CONFLICT DO NOTHING")
+            .bind(user_id)
+            .bind(race_id)
+            .execute(self.db.as_ref())
+            .await
+            .map_err(|_| "Unable to save your RSVP".to_string())?;
+
+        Ok(())
+    }
+
+    pub async fn cancel_rsvp(&self, user_id: i64, race_id: i64) -> Result<(), String> {
+        query("DELETE FROM race_rsvps WHERE user_id = ? AND race_id = ?")
+            .bind(user_id)
+            .bind(race_id)
+            .execute(self.db.as_ref())
+            .await
+            .map_err(|_| "Unable to cancel your RSVP".to_string())?;
+
+        Ok(())
+    }
+
+    pub async fn find_rsvp_race_ids(&self, user_id: i64) -> Vec<i64> {
+        query_as("SELECT race_id FROM race_rsvps WHERE user_id = ?")
+            .bind(user_id)
+            .fetch_all(self.db.as_ref())
+            .await
+            .map(|rows: Vec<(i64,)>| rows.into_iter().map(|(id,)| id).collect())
+            .unwrap_or_default()
+    }
+
+    /// Races on the given (Connecticut) day.
+    pub async fn find_on_date(&self, date: NaiveDate) -> Vec<RaceView> {
+        query_as(
+            r#"
+            SELECT * FROM races_view
+            WHERE DATE(start_at) = ? AND status = 'approved' AND deleted_at IS NULL
+            ORDER BY start_at ASC
+            "#,
+        )
+        .bind(date)
+        .fetch_all(self.db.as_ref())
+        .await
+        .unwrap_or_default()
+    }
+
+    /// Races on the given (Connecticut) day that anyone has RSVP'd to.
+    pub async fn find_rsvped_on(&self, date: NaiveDate) -> Vec<RaceView> {
+        query_as(
+            r#"
+            SELECT * FROM races_view
+            WHERE DATE(start_at) = ? AND status = 'approved' AND deleted_at IS NULL
+            AND id IN (SELECT race_id FROM race_rsvps)
+            ORDER BY start_at ASC
+            "#,
+        )
+        .bind(date)
+        .fetch_all(self.db.as_ref())
+        .await
+        .unwrap_or_default()
+    }
+
+    pub async fn find_rsvp_user_ids(&self, race_id: i64) -> Vec<i64> {
+        query_as("SELECT user_id FROM race_rsvps WHERE race_id = ?")
+            .bind(race_id)
+            .fetch_all(self.db.as_ref())
+            .await
+            .map(|rows: Vec<(i64,)>| rows.into_iter().map(|(id,)| id).collect())
+            .unwrap_or_default()
+    }
+
+    pub async fn find_finishers(&self, race_id: i64) -> Vec<RaceMember> {
+        query_as(
+            r#"
+            SELECT u.id AS user_id, u.runner_id, u.full_name, u.image_url, rr.notes
+            FROM race_results rr
+            JOIN users u ON u.id = rr.user_id
+            WHERE rr.race_id = ? AND u.locked = 0
+            ORDER BY u.full_name ASC
+            "#,
+        )
+        .bind(race_id)
+        .fetch_all(self.db.as_ref())
+        .await
+        .unwrap_or_default()
+    }
+
+    pub async fn find_rsvpers(&self, race_id: i64) -> Vec<RaceMember> {
+        query_as(
+            r#"
+            SELECT u.id AS user_id, u.runner_id, u.full_name, u.image_url, NULL AS notes
+            FROM race_rsvps rsvp
+            JOIN users u ON u.id = rsvp.user_id
+            WHERE rsvp.race_id = ? AND u.locked = 0
+            ORDER BY u.full_name ASC
+            "#,
+        )
+        .bind(race_id)
+        .fetch_all(self.db.as_ref())
+        .await
+        .unwrap_or_default()
+    }
+
+    /// Every race with the same name in the same town, with its turnout.
+    pub async fn find_editions(&self, town_id: i64, name: &str) -> Vec<RaceEdition> {
+        query_as(
+            r#"
+            SELECT
+                r.id,
+                r.miles,
+                r.start_at,
+                (SELECT COUNT(*) FROM race_results rr WHERE rr.race_id = r.id) AS finishers,
+                (SELECT COUNT(*) FROM race_rsvps rsvp WHERE rsvp.race_id = r.id) AS rsvps
+            FROM races r
+            WHERE r.town_id = ? AND LOWER(r.name) = LOWER(?) AND r.status = 'approved'
+            AND r.deleted_at IS NULL
+            ORDER BY r.start_at ASC, r.miles ASC
+            "#,
+        )
+        .bind(town_id)
+        .bind(name)
+        .fetch_all(self.db.as_ref())
+        .await
+        .unwrap_or_default()
+    }
+
+    /// Adds a member's race along with all of its distances, or none of them
+    /// if any were already added. Royalty and Admins' own races skip the
+    /// queue, so they're added as approved and reviewed by themselves.
+    pub async fn submit(
+        &self,
+        races: &[NewRace],
+        submitted_by: i64,
+        status: RaceStatus,
+    ) -> Result<Vec<RaceView>, String> {
+        let Some(first) = races.first() else {
+            return Ok(vec![]);
+        };
+
+        let error = |_| "Something went wrong adding the race".to_string();
+        let mut tx = self.db.begin().await.map_err(error)?;
+
+        let event_id = Self::event_id(&mut tx, first, &first.name.to_lowercase())
+            .await
+            .map_err(error)?;
+        let mut race_ids = vec![];
+        for race in races {
+            let race_id: Option<i64> = sqlx::query_scalar(
                 r#"
-            	LOWER(name) LIKE ? AND town_id = ? AND
-                start_at >= DateTime('now', '-6 months') AND
-                start_at <= DateTime('now')
-                ORDER BY start_at DESC
+                INSERT INTO races
+                    (town_id, name, miles, start_at, race_url, status, submitted_by,
+                     reviewed_by, reviewed_at, event_id)
+                VALUES (?, LOWER(?), ?, ?, ?, ?, ?,
+                    CASE WHEN ?6 = 'approved' THEN ?7 END,
+                    CASE WHEN ?6 = 'approved' THEN CURRENT_TIMESTAMP END, ?8)
+                ON CONFLICT(town_id, name COLLATE NOCASE, miles, start_at) DO NOTHING
+                RETURNING id
                 "#,
-            ),
-            vec![pattern, &params.town_id.to_string()],
+            )
+            .bind(race.town_id)
+            .bind(&race.name)
+            .bind(race.miles.value())
+            .bind(race.start_at)
+            .bind(&race.race_url)
+            .bind(status)
+            .bind(submitted_by)
+            .bind(event_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(error)?;
+
+            match race_id {
+                Some(race_id) => race_ids.push(race_id),
+                None => return Err("That race has already been added".to_string()),
+            }
+        }
+
+        tx.commit().await.map_err(error)?;
+
+        let mut submitted = vec![];
+        for race_id in race_ids {
+            submitted.push(self.find_by_id(race_id).await?);
+        }
+
+        Ok(submitted)
+    }
+
+    /// A pending or approved race in the same town, of the same distance,
+    /// starting within a day of the given race.
+    pub async fn find_duplicate(&self, race: &NewRace) -> Option<RaceView> {
+        query_as(
+            r#"
+            SELECT * FROM races_view
+            WHERE town_id = ?
+            AND status IN ('pending', 'approved') AND deleted_at IS NULL
+            AND ABS(miles - ?) < 0.05
+            AND ABS(JULIANDAY(start_at) - JULIANDAY(?)) < 1
+            ORDER BY status = 'approved' DESC, start_at ASC
+            LIMIT 1
+            "#,
         )
+        .bind(race.town_id)
+        .bind(race.miles.value())
+        .bind(race.start_at)
+        .fetch_optional(self.db.as_ref())
         .await
-        .unwrap()
+        .ok()
+        .flatten()
+    }
+
+    /// Races waiting on a moderator, soonest first.
+    pub async fn find_pending(&self) -> Vec<RaceSubmission> {
+        query_as(
+            r#"
+            SELECT r.*, u.full_name AS submitter_name
+            FROM races_view r
+            LEFT JOIN users u ON u.id = r.submitted_by
+            WHERE r.status = 'pending' AND r.deleted_at IS NULL
+            ORDER BY r.start_at ASC
+            "#,
+        )
+        .fetch_all(self.db.as_ref())
+        .await
+        .unwrap_or_default()
+    }
+
+    pub async fn find_submission(&self, race_id: i64) -> Result<RaceSubmission, String> {
+        query_as(
+            r#"
+            SELECT r.*, u.full_name AS submitter_name
+            FROM races_view r
+            LEFT JOIN users u ON u.id = r.submitted_by
+            WHERE r.id = ?
+            "#,
+        )
+        .bind(race_id)
+        .fetch_one(self.db.as_ref())
+        .await
+        .map_err(|e| format!("Failed to find race submission by ID: {e}"))
+    }
+
+    /// Every race the member added, newest first.
+    pub async fn find_submitted_by(&self, user_id: i64) -> Vec<RaceSubmission> {
+        query_as(
+            r#"
+            SELECT r.*, u.full_name AS submitter_name
+            FROM races_view r
+            LEFT JOIN users u ON u.id = r.submitted_by
+            WHERE r.submitted_by = ?
+            ORDER BY r.created_at DESC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(self.db.as_ref())
+        .await
+        .unwrap_or_default()
+    }
+
+    /// Approved races in the same town within a month either side, which a
+    /// pending race could be merged into.
+    pub async fn find_merge_candidates(&self, race: &RaceView) -> Vec<RaceView> {
+        query_as(
+            r#"
+            SELECT * FROM races_view
+            WHERE town_id = ? AND id != ? AND status = 'approved'
+            AND deleted_at IS NULL
+            AND ABS(JULIANDAY(start_at) - JULIANDAY(?)) <= 31
+            ORDER BY ABS(JULIANDAY(start_at) - JULIANDAY(?)) ASC
+            "#,
+        )
+        .bind(race.town_id)
+        .bind(race.id)
+        .bind(race.start_at)
+        .bind(race.start_at)
+        .fetch_all(self.db.as_ref())
+        .await
+        .unwrap_or_default()
+    }
+
+    /// Unlike adding a race, the name is saved as typed so its case can be
+    /// fixed up. A new name, town or day moves it to that event. The town is
+    /// fixed once members have results on it, as they completed that town.
+    pub async fn update(&self, race_id: i64, race: NewRace) -> Result<RaceView, String> {
+        let error = |_| "Something went wrong updating the race".to_string();
+        let mut tx = self.db.begin().await.map_err(error)?;
+
+        let (town_id, has_results): (i64, bool) = query_as(
+            r#"
+            SELECT town_id, EXISTS(SELECT 1 FROM race_results WHERE race_id = races.id)
+            FROM races WHERE id = ?
+            "#,
+        )
+        .bind(race_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(error)?
+        .ok_or_else(|| "Race not found".to_string())?;
+
+        if has_results && town_id != race.town_id {
+            return Err(
+                "Members have results on that race, so its town can't be changed".to_string(),
+            );
+        }
+
+        let event_id = Self::event_id(&mut tx, &race, &race.name)
+            .await
+            .map_err(error)?;
+        query(
+            r#"
+            UPDATE races
+            SET town_id = ?, name = ?, miles = ?, start_at = ?, race_url = ?, event_id = ?
+            WHERE id = ?
+            "#,
+        )
+        .bind(race.town_id)
+        .bind(race.name)
+        .bind(race.miles.value())
+        .bind(race.start_at)
+        .bind(race.race_url)
+        .bind(event_id)
+        .bind(race_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(e) if e.is_unique_violation() => {
+                "That race has already been added".to_string()
+            }
+            _ => "Something went wrong updating the race".to_string(),
+        })?;
+
+        tx.commit().await.map_err(error)?;
+        self.find_by_id(race_id).await
+    }
+
+    /// Approves or rejects a pending race. Races that have already been
+    /// reviewed are left alone.
+    pub async fn review(
+        &self,
+        race_id: i64,
+        reviewed_by: i64,
+        status: RaceStatus,
+        rejection_reason: Option<String>,
+    ) -> Result<RaceView, String> {
+        let result = query(
+            r#"
+            UPDATE races
+            SET status = ?, reviewed_by = ?, reviewed_at = CURRENT_TIMESTAMP,
+                rejection_reason = ?
+            WHERE id = ? AND status = 'pending'
+            "#,
+        )
+        .bind(status)
+        .bind(reviewed_by)
+        .bind(rejection_reason)
+        .bind(race_id)
+        .execute(self.db.as_ref())
+        .await
+        .map_err(|_| "Something went wrong reviewing the race".to_string())?;
+
+        if result.rows_affected() == 0 {
+            return Err("That race has already been reviewed".to_string());
+        }
+
+        self.find_by_id(race_id).await
+    }
+
+    /// Marks a pending or approved race as merged into another approved race
+    /// in the same town, moving anything linked to it over. RSVPs and results
+    /// the member already has on the other race win.
+    pub async fn merge(
+        &self,
+        race_id: i64,
+        into_race_id: i64,
+        reviewed_by: i64,
+    ) -> Result<(), String> {
+        let error = |_| "Something went wrong merging the race".to_string();
+        if race_id == into_race_id {
+            return Err("A race can't be merged into itself".to_string());
+        }
+
+        let mut tx = self.db.begin().await.map_err(error)?;
+
+        let towns: Option<(i64, i64)> = query_as(
+            r#"
+            SELECT race.town_id, into_race.town_id
+            FROM races race, races into_race
+            WHERE race.id = ? AND into_race.id = ?
+            AND into_race.status = 'approved' AND into_race.deleted_at IS NULL
+            "#,
+        )
+        .bind(race_id)
+        .bind(into_race_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(error)?;
+
+        match towns {
+            None => return Err("Races can only be merged into another approved race".to_string()),
+            Some((town_id, into_town_id)) if town_id != into_town_id => {
+                return Err("Races can only be merged into a race in the same town".to_string());
+            }
+            Some(_) => {}
+        }
+
+        let result = query(
+            r#"
+            UPDATE races
+            SET status = 'merged', merged_into_id = ?, reviewed_by = ?,
+                reviewed_at = CURRENT_TIMESTAMP
+            WHERE id = ? AND status IN ('pending', 'approved') AND deleted_at IS NULL
+            "#,
+        )
+        .bind(into_race_id)
+        .bind(reviewed_by)
+        .bind(race_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(error)?;
+
+        if result.rows_affected() == 0 {
+            return Err("That race has already been merged or removed".to_string());
+        }
+
+        for statement in [
+            "INSERT OR IGNORE INTO race_rsvps (user_id, race_id) SELECT user_id, ?1 FROM race_rsvps WHERE race_id = ?2",
+            "DELETE FROM race_rsvps WHERE race_id = ?2",
+            "UPDATE OR IGNORE race_results SET race_id = ?1 WHERE race_id = ?2",
+            "DELETE FROM race_results WHERE race_id = ?2",
+            "UPDATE town_submissions SET race_id = ?1 WHERE race_id = ?2",
+        ] {
+            query(statement)
+                .bind(into_race_id)
+                .bind(race_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(error)?;
+        }
+
+        tx.commit().await.map_err(error)
+    }
+
+    /// Hides the race everywhere but keeps it, and everything linked to it,
+    /// in the database.
+    pub async fn delete(&self, race_id: i64, deleted_by: i64) -> Result<RaceView, String> {
+        let result = query(
+            r#"
+            UPDATE races
+            SET deleted_at = CURRENT_TIMESTAMP, deleted_by = ?
+            WHERE id = ? AND deleted_at IS NULL
+            "#,
+        )
+        .bind(deleted_by)
+        .bind(race_id)
+        .execute(self.db.as_ref())
+        .await
+        .map_err(|_| "Something went wrong removing the race".to_string())?;
+
+        if result.rows_affected() == 0 {
+            return Err("That race has already been removed".to_string());
+        }
+
+        self.find_by_id(race_id).await
+    }
+
+    /// Every race for admins, including ones that are pending or removed.
+    pub async fn admin_search(
+        &self,
+        params: &AdminRaceSearchParams,
+    ) -> PaginatedResponse<RaceView> {
+        let pattern = params
+            .q
+            .as_ref()
+            .filter(|q| !q.is_whitespace_or_empty())
+            .map(|q| format!("%{}%", q.trim().to_lowercase()));
+
+        let mut filter = Filter::new()
+            .and_bind_opt("status = ?", params.status.map(|status| status.to_string()))
+            .and_if(params.deleted == Some(true), "deleted_at IS NOT NULL")
+            .and_if(params.deleted == Some(false), "deleted_at IS NULL")
+            .desc("start_at");
+
+        if let Some(pattern) = pattern {
+            filter = filter.and_binds(
+                "(LOWER(name) LIKE ? OR LOWER(town) LIKE ?)",
+                [pattern.clone(), pattern],
+            );
+        }
+
+        RaceView::paginate_filter(&self.db, &Pagination::from(params), &filter)
+            .await
+            .unwrap_or_default()
     }
 }
@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+
+use sqlx::{query_as, query_scalar};
+
+use crate::{
+    DbConnection,
+    domain::search::{
+        SearchKind, SearchResult, closest_term, match_query, max_edits, search_terms,
+    },
+};
+
+pub struct SearchRepository {
+    db: DbConnection,
+}
+
+impl SearchRepository {
+    pub fn new(db: &DbConnection) -> Self {
+        Self { db: db.clone() }
+    }
+
+    /// Turns what was typed into an FTS5 query, respelling any word that
+    /// doesn't start a word in the index. `None` when nothing was typed.
+    pub async fn match_query(&self, q: &str) -> Option<String> {
+        let terms = search_terms(q);
+        if terms.is_empty() {
+            return None;
+        }
+
+        let mut respellings = HashMap::new();
+        for term in &terms {
+            if self.is_known(term).await {
+                continue;
+            }
+
+            let vocabulary = self.vocabulary_near(term).await;
+            if let Some(respelling) = closest_term(term, &vocabulary) {
+                respellings.insert(term.to_string(), respelling);
+            }
+        }
+
+        Some(match_query(&terms, &respellings))
+    }
+
+    /// Whether any word in the index starts with the term.
+    async fn is_known(&self, term: &str) -> bool {
+        query_scalar(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM search_vocabulary
+                WHERE term >= ?1 AND term < ?1 || char(1114111)
+            )
+            "#,
+        )
+        .bind(term)
+        .fetch_one(self.db.as_ref())
+        .await
+        .unwrap_or(true)
+    }
+
+    /// Words in the index that could be the term with a typo or two, most
+    /// common first.
+    async fn vocabulary_near(&self, term: &str) -> Vec<String> {
+        let length = term.chars().count();
+        let edits = max_edits(length);
+
+        query_scalar(
+            r#"
+            SELECT term FROM search_vocabulary
+            WHERE length(term) BETWEEN ? AND ?
+            ORDER BY doc DESC
+            "#,
+        )
+        .bind(length.saturating_sub(edits) as i64)
+        .bind((length + edits) as i64)
+        .fetch_all(self.db.as_ref())
+        .await
+        .unwrap_or_default()
+    }
+
+    /// The best matches of one kind, leaving out anything members can't
+    /// see, like races still in moderation or people who haven't signed up
+    /// with a runner number.
+    pub async fn search(
+        &self,
+        match_query: &str,
+        kind: SearchKind,
+        limit: i64,
+    ) -> Vec<SearchResult> {
+        query_as(
+            r#"
+            SELECT * FROM (
+                SELECT m.kind, m.item_id, COALESCE(e.name, m.title) AS title,
+                    CASE m.kind
+                        WHEN 'race' THEN e.town
+                        WHEN 'town' THEN t.county || ' County'
+                        WHEN 'county' THEN 'County'
+                        ELSE COALESCE(u.hometown, '')
+                    END AS subtitle,
+                    e.event_date AS date,
+                    CASE m.kind
+                        WHEN 'race' THEN '/races/' || (
+                            SELECT r.id FROM races r
+                            WHERE r.event_id = e.id
+                            AND r.status = 'approved' AND r.deleted_at IS NULL
+                            ORDER BY r.start_at ASC LIMIT 1
+                        )
+                        WHEN 'town' THEN '/upcoming-races?town_id=' || m.item_id
+                        WHEN 'county' THEN '/upcoming-races?county_id=' || m.item_id
+                        ELSE '/members/' || u.runner_id
+                    END AS url,
+                    m.rank
+                FROM (
+                    SELECT kind, item_id, title, bm25(search_index, 0.0, 0.0, 10.0, 1.0) AS rank
+                    FROM search_index
+                    WHERE search_index MATCH ? AND kind = ?
+                ) m
+                LEFT JOIN race_events_view e ON m.kind = 'race' AND e.id = m.item_id
+                LEFT JOIN towns_view t ON m.kind = 'town' AND t.id = m.item_id
+                LEFT JOIN users_view u ON m.kind = 'member' AND u.id = m.item_id
+            )
+            WHERE url IS NOT NULL
+            ORDER BY rank ASC
+            LIMIT ?
+            "#,
+        )
+        .bind(match_query)
+        .bind(kind)
+        .bind(limit)
+        .fetch_all(self.db.as_ref())
+        .await
+        .unwrap_or_default()
+    }
+}
@@ -0,0 +1,64 @@
+use sqlx::{query, query_as};
+
+use crate::{
+    DbConnection,
+    domain::activity::{StravaConnection, StravaToken},
+};
+
+pub struct StravaRepository {
+    db: DbConnection,
+}
+
+impl StravaRepository {
+    pub fn new(db: &DbConnection) -> Self {
+        Self { db: db.clone() }
+    }
+
+    pub async fn save(
+        &self,
+        user_id: i64,
+        athlete_id: i64,
+        token: &StravaToken,
+    ) -> Result<StravaConnection, String> {
+        query_as(
+            r#"
+            INSERT INTO strava_connections
+                (user_id, athlete_id, access_token, refresh_token, expires_at)
+            VALUES (?, ?, ?, ?, ?)
+            ON CONFLICT(user_id) DO UPDATE SET
+                athlete_id = excluded.athlete_id,
+                access_token = excluded.access_token,
+                refresh_token = excluded.refresh_token,
+                expires_at = excluded.expires_at
+            RETURNING *
+            "#,
+        )
+        .bind(user_id)
+        .bind(athlete_id)
+        .bind(&token.access_token)
+        .bind(&token.refresh_token)
+        .bind(token.expires_at)
+        .fetch_one(self.db.as_ref())
+        .await
+        .map_err(|e| format!("Unable to save the Strava connection: {e}"))
+    }
+
+    pub async fn find(&self, user_id: i64) -> Option<StravaConnection> {
+        query_as("SELECT * FROM strava_connections WHERE user_id = ?")
+            .bind(user_id)
+            .fetch_optional(self.db.as_ref())
+            .await
+            .ok()
+            .flatten()
+    }
+
+    pub async fn delete(&self, user_id: i64) -> Result<(), String> {
+        query("DELETE FROM strava_connections WHERE user_id = ?")
+            .bind(user_id)
+            .execute(self.db.as_ref())
+            .await
+            .map_err(|_| "Unable to remove the Strava connection".to_string())?;
+
+        Ok(())
+    }
+}
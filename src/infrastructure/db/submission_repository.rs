@@ -0,0 +1,120 @@
+use sqlx::{query, query_as};
+
+use crate::{
+    DbConnection,
+    domain::submission::{STALE_AFTER_MINUTES, TownSubmission},
+};
+
+pub struct SubmissionRepository {
+    db: DbConnection,
+}
+
+impl SubmissionRepository {
+    pub fn new(db: &DbConnection) -> Self {
+        Self { db: db.clone() }
+    }
+
+    /// Records the key, or returns `None` when it has been used before.
+    pub async fn claim(
+        &self,
+        user_id: i64,
+        idempotency_key: &str,
+        fingerprint: &str,
+        town_id: i64,
+    ) -> Result<Option<TownSubmission>, String> {
+        query_as(
+            r#"
+            INSERT INTO town_submissions (user_id, idempotency_key, fingerprint, town_id)
+            VALUES (?, ?, ?, ?)
+            ON CONFLICT(user_id, idempotency_key) DO NOTHING
+            RETURNING *
+            "#,
+        )
+        .bind(user_id)
+        .bind(idempotency_key)
+        .bind(fingerprint)
+        .bind(town_id)
+        .fetch_optional(self.db.as_ref())
+        .await
+        .map_err(|e| format!("Unable to record the submission: {e}"))
+    }
+
+    pub async fn find(
+        &self,
+        user_id: i64,
+        idempotency_key: &str,
+    ) -> Result<TownSubmission, String> {
+        query_as("SELECT * FROM town_submissions WHERE user_id = ? AND idempotency_key = ?")
+            .bind(user_id)
+            .bind(idempotency_key)
+            .fetch_one(self.db.as_ref())
+            .await
+            .map_err(|_| "Unable to find that submission".to_string())
+    }
+
+    /// Takes a failed submission, or one stuck processing for longer than
+    /// `STALE_AFTER_MINUTES`, back for another go. Only one request can win.
+    pub async fn retry(&self, id: i64) -> Result<bool, String> {
+        let result = query(
+            r#"
+            UPDATE town_submissions
+            SET status = 'processing', error = NULL
+            WHERE id = ? AND (
+                status = 'failed'
+                OR (status = 'processing' AND updated_at <= datetime('now', ?))
+            )
+            "#,
+        )
+        .bind(id)
+        .bind(format!("-{STALE_AFTER_MINUTES} minutes"))
+        .execute(self.db.as_ref())
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    pub async fn mark_posted(&self, id: i64) -> Result<(), String> {
+        query(
+            r#"
+            UPDATE town_submissions
+            SET status = 'posted', society_posted_at = CURRENT_TIMESTAMP
+            WHERE id = ?
+            "#,
+        )
+        .bind(id)
+        .execute(self.db.as_ref())
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    pub async fn mark_failed(&self, id: i64, error: &str) -> Result<(), String> {
+        query("UPDATE town_submissions SET status = 'failed', error = ? WHERE id = ?")
+            .bind(error)
+            .bind(id)
+            .execute(self.db.as_ref())
+            .await
+            .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    pub async fn mark_completed(&self, id: i64, race_id: i64) -> Result<TownSubmission, String> {
+        query_as(
+            r#"
+            UPDATE town_submissions
+            SET status = 'completed', race_id = ?, error = NULL,
+                completed_at = CURRENT_TIMESTAMP
+            WHERE id = ?
+            RETURNING *
+            "#,
+        )
+        .bind(race_id)
+        .bind(id)
+        .fetch_one(self.db.as_ref())
+        .await
+        .map_err(|e| e.to_string())
+    }
+}
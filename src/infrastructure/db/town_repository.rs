@@ -1,7 +1,10 @@
+use sqlx::SqliteConnection;
 use sqlx::query;
 use sqlx::query_as;
 
-use crate::domain::town::CompletedTown;
+use crate::domain::boundary::TownBoundary;
+use crate::domain::town::{CompletedTown, County};
+use crate::infrastructure::db::ProgressRepository;
 use crate::{DbConnection, domain::Town};
 
 pub struct TownRepository {
@@ -20,6 +23,20 @@ impl TownRepository {
             .unwrap_or_default()
     }
 
+    pub async fn find_all_boundaries(&self) -> Vec<TownBoundary> {
+        query_as("SELECT * FROM town_boundaries")
+            .fetch_all(self.db.as_ref())
+            .await
+            .unwrap_or_default()
+    }
+
+    pub async fn find_all_counties(&self) -> Vec<County> {
+        query_as("SELECT * FROM counties ORDER BY name ASC")
+            .fetch_all(self.db.as_ref())
+            .await
+            .unwrap_or_default()
+    }
+
     pub async fn find_completed(&self, user_id: i64) -> Vec<CompletedTown> {
         query_as("SELECT * FROM completed_towns_view WHERE user_id = ?")
             .bind(user_id)
@@ -44,13 +61,40 @@ impl TownRepository {
             .map_err(|_| "Unable to find that town".to_string())
     }
 
+    /// Adds the town to the member's completed towns and counts it towards
+    /// their progress, or neither.
     pub async fn mark_completed(&self, user_id: i64, town_id: i64) -> Result<(), String> {
-        query("INSERT INTO completed_towns (user_id, town_id) VALUES(?, ?)")
-            .bind(user_id)
-            .bind(town_id)
-            .execute(self.db.as_ref())
+        let error = |_| "Unable to mark town as completed".to_string();
+        let mut tx = self.db.begin().await.map_err(error)?;
+        Self::complete(&mut tx, user_id, town_id)
             .await
-            .map_err(|_| "Unable to mark town as completed".to_string())?;
-        Ok(())
+            .map_err(error)?;
+        tx.commit().await.map_err(error)
+    }
+
+    /// Adds the town to the member's completed towns and counts it, or
+    /// returns false if they'd already completed it.
+    pub async fn complete(
+        conn: &mut SqliteConnection,
+        user_id: i64,
+        town_id: i64,
+    ) -> Result<bool, sqlx::Error> {
+        let inserted = query(
+            r#"
+            INSERT INTO completed_towns (user_id, town_id) VALUES(?, ?)
+            ON CONFLICT(user_id, town_id) DO NOTHING
+            "#,
+        )
+        .bind(user_id)
+        .bind(town_id)
+        .execute(&mut *conn)
+        .await?
+        .rows_affected()
+            > 0;
+
+        if inserted {
+            ProgressRepository::record_completed(conn, user_id, town_id).await?;
+        }
+        Ok(inserted)
     }
 }
@@ -1,7 +1,13 @@
 use crate::DbConnection;
-use crate::domain::user::{UpdateRunnerInfo, UpdateUser, UserView};
+use crate::domain::user::{
+    AdminUserSearchParams, Onboarding, UpdateRunnerInfo, UpdateUser, UserView,
+};
 use crate::domain::{User, user::NewUser};
-use crate::util::pagination::{Paginatable, PaginatedResponse, Pagination};
+use crate::util::StringExt;
+use crate::util::filter::Filter;
+use crate::util::pagination::{
+    CursorPage, CursorPagination, Paginatable, PaginatedResponse, Pagination,
+};
 use sqlx::{query, query_as, query_scalar};
 
 pub struct UserRepository {
@@ -65,16 +71,86 @@ impl UserRepository {
         pagination: &Pagination,
         search: &str,
     ) -> PaginatedResponse<UserView> {
-        let pattern = &format!("%{}%", search.to_lowercase());
+        UserView::paginate_filter(&self.db, pagination, &Self::member_filter(search))
+            .await
+            .unwrap_or_default()
+    }
+
+    /// Members matching the search, a page at a time for scrolling through.
+    pub async fn search_cursor(
+        &self,
+        pagination: &CursorPagination,
+        search: &str,
+    ) -> CursorPage<UserView> {
+        UserView::paginate_cursor(&self.db, pagination, Self::member_filter(search), true)
+            .await
+            .unwrap_or_default()
+    }
+
+    fn member_filter(search: &str) -> Filter {
+        let pattern = format!("%{}%", search.to_lowercase());
+
+        Filter::new()
+            .and_binds(
+                "(LOWER(full_name) LIKE ? OR LOWER(email) LIKE ?)",
+                [pattern.clone(), pattern],
+            )
+            .and("runner_id IS NOT NULL")
+            .asc("full_name")
+            .asc("id")
+    }
 
+    pub async fn admin_search(
+        &self,
+        params: &AdminUserSearchParams,
+    ) -> PaginatedResponse<UserView> {
         UserView::paginate_filter(
             &self.db,
-            pagination,
-            Some(r#"(LOWER(full_name) LIKE ? OR LOWER(email) LIKE ?) AND runner_id IS NOT NULL ORDER BY full_name ASC"#),
-            vec![pattern, pattern],
+            &Pagination::from(params),
+            &Self::admin_filter(params),
         )
         .await
-        .unwrap()
+        .unwrap_or_default()
+    }
+
+    /// Every user matching the admin filters, without pagination.
+    pub async fn admin_export(
+        &self,
+        params: &AdminUserSearchParams,
+    ) -> Result<Vec<UserView>, sqlx::Error> {
+        UserView::find_all(&self.db, &Self::admin_filter(params)).await
+    }
+
+    fn admin_filter(params: &AdminUserSearchParams) -> Filter {
+        let mut filter = Filter::new()
+            .and_if(
+                params.onboarding == Some(Onboarding::Complete),
+                "runner_id IS NOT NULL",
+            )
+            .and_if(
+                params.onboarding == Some(Onboarding::Pending),
+                "runner_id IS NULL",
+            )
+            .and_bind_opt(
+                "role = ?",
+                params.role.as_ref().map(|role| role.to_string()),
+            )
+            .and_bind_opt("locked = ?", params.locked)
+            .and_bind_opt("verified = ?", params.verified)
+            .and_bind_opt("hometown_county_id = ?", params.county_id)
+            .and_bind_opt("DATE(created_at) >= ?", params.from)
+            .and_bind_opt("DATE(created_at) <= ?", params.to)
+            .asc("full_name");
+
+        if let Some(q) = params.q.as_ref().filter(|q| !q.is_whitespace_or_empty()) {
+            let pattern = format!("%{}%", q.trim().to_lowercase());
+            filter = filter.and_binds(
+                "(LOWER(full_name) LIKE ? OR LOWER(email) LIKE ? OR runner_id = ?)",
+                [pattern.clone(), pattern, q.trim().to_string()],
+            );
+        }
+
+        filter
     }
 
     pub async fn create(&self, user: &NewUser) -> Result<UserView, sqlx::Error> {
@@ -0,0 +1,211 @@
+use sqlx::{query, query_as};
+
+use crate::{
+    DbConnection,
+    domain::webhook::{
+        MAX_DELIVERY_ATTEMPTS, NewWebhook, Webhook, WebhookDelivery, WebhookPayload,
+    },
+};
+
+pub struct WebhookRepository {
+    db: DbConnection,
+}
+
+impl WebhookRepository {
+    pub fn new(db: &DbConnection) -> Self {
+        Self { db: db.clone() }
+    }
+
+    pub async fn create(&self, webhook: &NewWebhook) -> Result<Webhook, String> {
+        query_as(
+            r#"
+            INSERT INTO webhooks (user_id, url, format, secret, events)
+            VALUES (?, ?, ?, ?, ?)
+            RETURNING *
+            "#,
+        )
+        .bind(webhook.user_id)
+        .bind(&webhook.url)
+        .bind(webhook.format)
+        .bind(&webhook.secret)
+        .bind(webhook.events_str())
+        .fetch_one(self.db.as_ref())
+        .await
+        .map_err(|e| format!("Unable to create the webhook: {e}"))
+    }
+
+    pub async fn find_for_user(&self, user_id: i64) -> Vec<Webhook> {
+        query_as("SELECT * FROM webhooks WHERE user_id = ? ORDER BY created_at DESC")
+            .bind(user_id)
+            .fetch_all(self.db.as_ref())
+            .await
+            .unwrap_or_default()
+    }
+
+    pub async fn find_by_id(&self, id: i64) -> Result<Webhook, String> {
+        query_as("SELECT * FROM webhooks WHERE id = ?")
+            .bind(id)
+            .fetch_one(self.db.as_ref())
+            .await
+            .map_err(|_| "Unable to find that webhook".to_string())
+    }
+
+    pub async fn delete(&self, user_id: i64, id: i64) -> Result<(), String> {
+        let result = query("DELETE FROM webhooks WHERE id = ? AND user_id = ?")
+            .bind(id)
+            .bind(user_id)
+            .execute(self.db.as_ref())
+            .await
+            .map_err(|_| "Unable to delete the webhook".to_string())?;
+
+        match result.rows_affected() {
+            0 => Err("Unable to find that webhook".to_string()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Queues a delivery for every active webhook subscribed to the event.
+    /// Events about a member only go to that member's webhooks and to admins'.
+    pub async fn enqueue(
+        &self,
+        member_id: Option<i64>,
+        payload: &WebhookPayload,
+    ) -> Result<(), String> {
+        let event = payload.event.as_str();
+        let body = serde_json::to_string(payload).map_err(|e| e.to_string())?;
+
+        query(
+            r#"
+            INSERT INTO webhook_deliveries (webhook_id, event, payload)
+            SELECT w.id, ?, ?
+            FROM webhooks w
+            JOIN users u ON u.id = w.user_id
+            WHERE w.active = 1
+            AND (',' || w.events || ',') LIKE ('%,' || ? || ',%')
+            AND (? IS NULL OR w.user_id = ? OR u.role = 'admin')
+            "#,
+        )
+        .bind(event)
+        .bind(&body)
+        .bind(event)
+        .bind(member_id)
+        .bind(member_id)
+        .execute(self.db.as_ref())
+        .await
+        .map_err(|e| format!("Unable to queue webhook deliveries: {e}"))?;
+
+        Ok(())
+    }
+
+    /// Queues a delivery for one webhook, regardless of its events.
+    pub async fn enqueue_for(
+        &self,
+        webhook_id: i64,
+        payload: &WebhookPayload,
+    ) -> Result<WebhookDelivery, String> {
+        let body = serde_json::to_string(payload).map_err(|e| e.to_string())?;
+
+        query_as(
+            r#"
+            INSERT INTO webhook_deliveries (webhook_id, event, payload)
+            VALUES (?, ?, ?)
+            RETURNING *
+            "#,
+        )
+        .bind(webhook_id)
+        .bind(payload.event.as_str())
+        .bind(&body)
+        .fetch_one(self.db.as_ref())
+        .await
+        .map_err(|e| format!("Unable to queue the webhook delivery: {e}"))
+    }
+
+    /// Claims deliveries that are due by pushing their next attempt out, so a
+    /// slow run can't overlap with the next one and send them twice.
+    pub async fn claim_due(&self, limit: i64) -> Vec<WebhookDelivery> {
+        query_as(
+            r#"
+            UPDATE webhook_deliveries
+            SET next_attempt_at = DATETIME('now', '+5 minutes')
+            WHERE id IN (
+                SELECT id FROM webhook_deliveries
+                WHERE status = 'pending' AND next_attempt_at <= CURRENT_TIMESTAMP
+                ORDER BY next_attempt_at
+                LIMIT ?
+            )
+            RETURNING *
+            "#,
+        )
+        .bind(limit)
+        .fetch_all(self.db.as_ref())
+        .await
+        .unwrap_or_default()
+    }
+
+    pub async fn mark_delivered(&self, id: i64, response_status: u16) -> Result<(), String> {
+        query(
+            r#"
+            UPDATE webhook_deliveries
+            SET status = 'delivered', attempts = attempts + 1, response_status = ?,
+                error = NULL, delivered_at = CURRENT_TIMESTAMP
+            WHERE id = ?
+            "#,
+        )
+        .bind(response_status)
+        .bind(id)
+        .execute(self.db.as_ref())
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    /// Records a failed attempt, giving up once it has been tried enough.
+    pub async fn mark_attempt_failed(
+        &self,
+        delivery: &WebhookDelivery,
+        response_status: Option<u16>,
+        error: &str,
+    ) -> Result<(), String> {
+        let attempts = delivery.attempts + 1;
+        let retry_in = WebhookDelivery::backoff(attempts).as_secs();
+
+        query(
+            r#"
+            UPDATE webhook_deliveries
+            SET attempts = ?, response_status = ?, error = ?,
+                status = CASE WHEN ? >= ? THEN 'failed' ELSE 'pending' END,
+                next_attempt_at = DATETIME('now', '+' || ? || ' seconds')
+            WHERE id = ?
+            "#,
+        )
+        .bind(attempts)
+        .bind(response_status)
+        .bind(error)
+        .bind(attempts)
+        .bind(MAX_DELIVERY_ATTEMPTS)
+        .bind(retry_in as i64)
+        .bind(delivery.id)
+        .execute(self.db.as_ref())
+        .await
+        .map_err(|e| e.to_string())?;
+
+        Ok(())
+    }
+
+    pub async fn find_deliveries(&self, webhook_id: i64, limit: i64) -> Vec<WebhookDelivery> {
+        query_as(
+            r#"
+            SELECT * FROM webhook_deliveries
+            WHERE webhook_id = ?
+            ORDER BY created_at DESC, id DESC
+            LIMIT ?
+            "#,
+        )
+        .bind(webhook_id)
+        .bind(limit)
+        .fetch_all(self.db.as_ref())
+        .await
+        .unwrap_or_default()
+    }
+}
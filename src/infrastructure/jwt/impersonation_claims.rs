@@ -0,0 +1,42 @@
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use chrono_tz::America::New_York;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::user::UserView;
+
+/// How long an admin may view the site as another member.
+pub const IMPERSONATION_MINUTES: i64 = 30;
+
+/// Claims for an impersonation session. These deliberately have no `sub`,
+/// so they can never be mistaken for `UserClaims` in the `auth_token` cookie.
+#[derive(Serialize, Deserialize)]
+pub struct ImpersonationClaims {
+    /// The email of the member being impersonated.
+    pub target: String,
+    /// The email of the admin doing the impersonating.
+    pub act: String,
+    pub exp: usize,
+}
+
+impl ImpersonationClaims {
+    pub fn new(admin: &UserView, target: &UserView) -> Self {
+        let exp = Utc::now()
+            .checked_add_signed(Duration::minutes(IMPERSONATION_MINUTES))
+            .expect("valid timestamp")
+            .timestamp() as usize;
+
+        Self {
+            target: target.email.clone(),
+            act: admin.email.clone(),
+            exp,
+        }
+    }
+
+    /// When the session ends, in Connecticut's local time.
+    pub fn expires_at(&self) -> NaiveDateTime {
+        DateTime::from_timestamp(self.exp as i64, 0)
+            .unwrap_or_default()
+            .with_timezone(&New_York)
+            .naive_local()
+    }
+}
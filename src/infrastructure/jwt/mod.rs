@@ -5,6 +5,7 @@ use jsonwebtoken::{
 };
 use serde::{Serialize, de::DeserializeOwned};
 
+pub mod impersonation_claims;
 pub mod user_claims;
 
 pub struct JwtService {}
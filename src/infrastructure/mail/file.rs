@@ -0,0 +1,51 @@
+use std::{env, path::PathBuf};
+
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::infrastructure::mail::{Email, Mailer, from_address};
+
+/// Writes each email to its own file instead of sending it. Handy for
+/// development and for checking what would have gone out.
+pub struct FileMailer {
+    dir: PathBuf,
+}
+
+impl FileMailer {
+    /// Writes to `MAIL_DIR`, `db/emails` by default.
+    pub fn from_env() -> Self {
+        Self {
+            dir: env::var("MAIL_DIR")
+                .unwrap_or_else(|_| "db/emails".to_string())
+                .into(),
+        }
+    }
+}
+
+#[async_trait]
+impl Mailer for FileMailer {
+    async fn send(&self, email: &Email) -> Result<(), String> {
+        tokio::fs::create_dir_all(&self.dir)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let name = format!(
+            "{}-{}.eml",
+            Utc::now().format("%Y%m%d%H%M%S%f"),
+            email.to.replace(|c: char| !c.is_ascii_alphanumeric(), "_")
+        );
+        let contents = format!(
+            "From: {}\nTo: {}\nSubject: {}\nList-Unsubscribe: <{}>\n\n{}\n\n{}\n",
+            from_address(),
+            email.to,
+            email.subject,
+            email.unsubscribe_url.clone().unwrap_or_default(),
+            email.text,
+            email.html,
+        );
+
+        tokio::fs::write(self.dir.join(name), contents)
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
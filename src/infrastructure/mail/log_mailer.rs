@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use log::info;
+
+use crate::infrastructure::mail::{Email, Mailer};
+
+/// Logs emails rather than sending them, the default when nothing is set up.
+pub struct LogMailer;
+
+#[async_trait]
+impl Mailer for LogMailer {
+    async fn send(&self, email: &Email) -> Result<(), String> {
+        info!("📧 To: {} | {}\n{}", email.to, email.subject, email.text);
+        Ok(())
+    }
+}
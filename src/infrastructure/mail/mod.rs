@@ -0,0 +1,46 @@
+use std::env;
+
+use async_trait::async_trait;
+use log::warn;
+
+pub mod file;
+pub mod log_mailer;
+pub mod smtp;
+
+pub use file::FileMailer;
+pub use log_mailer::LogMailer;
+pub use smtp::SmtpMailer;
+
+pub struct Email {
+    pub to: String,
+    pub subject: String,
+    pub text: String,
+    pub html: String,
+    /// Where a mail client's "unsubscribe" button should go.
+    pub unsubscribe_url: Option<String>,
+}
+
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, email: &Email) -> Result<(), String>;
+}
+
+/// Picks the transport from `MAIL_TRANSPORT` (`smtp`, `file` or `log`).
+/// Anything else, including nothing at all, only logs emails.
+pub fn from_env() -> Box<dyn Mailer> {
+    match env::var("MAIL_TRANSPORT").unwrap_or_default().as_str() {
+        "smtp" => match SmtpMailer::from_env() {
+            Ok(mailer) => Box::new(mailer),
+            Err(e) => {
+                warn!("Unable to set up SMTP, only logging emails: {e}");
+                Box::new(LogMailer)
+            }
+        },
+        "file" => Box::new(FileMailer::from_env()),
+        _ => Box::new(LogMailer),
+    }
+}
+
+pub fn from_address() -> String {
+    env::var("MAIL_FROM").unwrap_or_else(|_| "CTRunner <no-reply@ctrunner.org>".to_string())
+}
@@ -0,0 +1,79 @@
+use std::env;
+
+use async_trait::async_trait;
+use lettre::{
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+    message::{
+        MultiPart,
+        header::{HeaderName, HeaderValue},
+    },
+    transport::smtp::authentication::Credentials,
+};
+
+use crate::infrastructure::mail::{Email, Mailer, from_address};
+
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl SmtpMailer {
+    /// Needs `SMTP_HOST`, and optionally `SMTP_PORT`, `SMTP_USERNAME` and `SMTP_PASSWORD`.
+    pub fn from_env() -> Result<Self, String> {
+        let host = env::var("SMTP_HOST").map_err(|_| "SMTP_HOST is not defined")?;
+        let mut builder = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&host)
+            .map_err(|e| e.to_string())?;
+
+        if let Ok(port) = env::var("SMTP_PORT") {
+            builder = builder.port(port.parse().map_err(|_| "SMTP_PORT is not a number")?);
+        }
+
+        if let (Ok(username), Ok(password)) = (env::var("SMTP_USERNAME"), env::var("SMTP_PASSWORD"))
+        {
+            builder = builder.credentials(Credentials::new(username, password));
+        }
+
+        Ok(Self {
+            transport: builder.build(),
+        })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, email: &Email) -> Result<(), String> {
+        let mut message = Message::builder()
+            .from(
+                from_address()
+                    .parse()
+                    .map_err(|_| "MAIL_FROM is not valid")?,
+            )
+            .to(email.to.parse().map_err(|_| "Invalid recipient")?)
+            .subject(&email.subject);
+
+        if let Some(url) = &email.unsubscribe_url {
+            // Lets mail clients show their own one-click unsubscribe button (RFC 8058).
+            message = message
+                .raw_header(HeaderValue::new(
+                    HeaderName::new_from_ascii_str("List-Unsubscribe"),
+                    format!("<{url}>"),
+                ))
+                .raw_header(HeaderValue::new(
+                    HeaderName::new_from_ascii_str("List-Unsubscribe-Post"),
+                    "List-Unsubscribe=One-Click".to_string(),
+                ));
+        }
+
+        let message = message
+            .multipart(MultiPart::alternative_plain_html(
+                email.text.clone(),
+                email.html.clone(),
+            ))
+            .map_err(|e| e.to_string())?;
+
+        self.transport
+            .send(message)
+            .await
+            .map(|_| ())
+            .map_err(|e| format!("Unable to send email to {}: {e}", email.to))
+    }
+}
@@ -1,3 +1,6 @@
 pub mod auth;
 pub mod db;
 pub mod jwt;
+pub mod mail;
+pub mod push;
+pub mod strava;
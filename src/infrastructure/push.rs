@@ -0,0 +1,110 @@
+use std::{env, time::Duration};
+
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use log::warn;
+use web_push::{
+    ContentEncoding, PartialVapidSignatureBuilder, SubscriptionInfo, VapidSignatureBuilder,
+    WebPushMessageBuilder, request_builder,
+};
+
+use crate::domain::push::{PushMessage, PushSubscription};
+
+/// How long a push service holds on to a message for an offline device.
+const TTL_SECONDS: u32 = 60 * 60 * 12;
+
+pub enum PushError {
+    /// The browser unsubscribed, so the subscription should be forgotten.
+    Gone,
+    Failed(String),
+}
+
+/// Sends Web Push messages signed with the server's VAPID key.
+///
+/// Set `VAPID_PRIVATE_KEY` (the raw key, base64url without padding, as given
+/// by `npx web-push generate-vapid-keys`) and `VAPID_SUBJECT` (a `mailto:` or
+/// `https:` contact). Without a key, push is turned off.
+#[derive(Clone)]
+pub struct WebPushSender {
+    vapid: Option<PartialVapidSignatureBuilder>,
+    subject: String,
+    client: reqwest::Client,
+}
+
+impl WebPushSender {
+    pub fn from_env() -> Self {
+        let vapid = env::var("VAPID_PRIVATE_KEY").ok().and_then(|key| {
+            VapidSignatureBuilder::from_base64_no_sub(key.trim())
+                .inspect_err(|e| warn!("Invalid VAPID_PRIVATE_KEY, push is turned off: {e}"))
+                .ok()
+        });
+
+        Self {
+            vapid,
+            subject: env::var("VAPID_SUBJECT")
+                .unwrap_or_else(|_| "mailto:no-reply@ctrunner.org".to_string()),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// The key browsers need to subscribe, when push is set up.
+    pub fn public_key(&self) -> Option<String> {
+        self.vapid
+            .as_ref()
+            .map(|vapid| URL_SAFE_NO_PAD.encode(vapid.get_public_key()))
+    }
+
+    pub async fn send(
+        &self,
+        subscription: &PushSubscription,
+        message: &PushMessage,
+    ) -> Result<(), PushError> {
+        let Some(vapid) = &self.vapid else {
+            return Err(PushError::Failed("Push is not set up".to_string()));
+        };
+
+        let info = SubscriptionInfo::new(
+            subscription.endpoint.as_str(),
+            subscription.p256dh.as_str(),
+            subscription.auth.as_str(),
+        );
+        let payload = serde_json::to_vec(message).map_err(|e| PushError::Failed(e.to_string()))?;
+
+        let mut signature = vapid.clone().add_sub_info(&info);
+        signature.add_claim("sub", self.subject.as_str());
+        let signature = signature
+            .build()
+            .map_err(|e| PushError::Failed(e.to_string()))?;
+
+        let mut builder = WebPushMessageBuilder::new(&info);
+        builder.set_ttl(TTL_SECONDS);
+        builder.set_payload(ContentEncoding::Aes128Gcm, &payload);
+        builder.set_vapid_signature(signature);
+        let push = builder
+            .build()
+            .map_err(|e| PushError::Failed(e.to_string()))?;
+
+        // web-push builds an `http` request; reqwest sends it.
+        let request = request_builder::build_request::<Vec<u8>>(push);
+        let mut outgoing = self
+            .client
+            .post(request.uri().to_string())
+            .timeout(Duration::from_secs(10));
+        for (name, value) in request.headers() {
+            outgoing = outgoing.header(name.as_str(), value.as_bytes());
+        }
+
+        let response = outgoing
+            .body(request.into_body())
+            .send()
+            .await
+            .map_err(|e| PushError::Failed(e.to_string()))?;
+
+        match response.status().as_u16() {
+            200..=299 => Ok(()),
+            404 | 410 => Err(PushError::Gone),
+            status => Err(PushError::Failed(format!(
+                "Push service responded {status}"
+            ))),
+        }
+    }
+}
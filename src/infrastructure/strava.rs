@@ -0,0 +1,124 @@
+use std::env;
+
+use reqwest::{Client, Url};
+
+use crate::domain::activity::{StravaActivity, StravaToken};
+
+/// Talks to Strava's OAuth and activity APIs.
+///
+/// Set `STRAVA_CLIENT_ID`, `STRAVA_CLIENT_SECRET` and `STRAVA_CALLBACK_URL`
+/// to turn it on. `STRAVA_BASE_URL` points it somewhere other than
+/// `https://www.strava.com`, e.g. a local mock while testing.
+pub struct StravaClient {
+    client_id: Option<String>,
+    client_secret: String,
+    callback_url: String,
+    base_url: String,
+    client: Client,
+}
+
+impl StravaClient {
+    pub fn from_env() -> Self {
+        Self {
+            client_id: env::var("STRAVA_CLIENT_ID")
+                .ok()
+                .filter(|id| !id.is_empty()),
+            client_secret: env::var("STRAVA_CLIENT_SECRET").unwrap_or_default(),
+            callback_url: env::var("STRAVA_CALLBACK_URL").unwrap_or_default(),
+            base_url: env::var("STRAVA_BASE_URL")
+                .unwrap_or_else(|_| "https://www.strava.com".to_string())
+                .trim_end_matches('/')
+                .to_string(),
+            client: Client::new(),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.client_id.is_some()
+    }
+
+    /// Where to send a member to let us read their activities.
+    pub fn authorize_url(&self, state: &str) -> Option<String> {
+        let client_id = self.client_id.as_ref()?;
+        Url::parse_with_params(
+            &format!("{}/oauth/authorize", self.base_url),
+            &[
+                ("client_id", client_id.as_str()),
+                ("redirect_uri", self.callback_url.as_str()),
+                ("response_type", "code"),
+                ("approval_prompt", "auto"),
+                ("scope", "activity:read_all"),
+                ("state", state),
+            ],
+        )
+        .ok()
+        .map(String::from)
+    }
+
+    pub async fn exchange_code(&self, code: &str) -> Result<StravaToken, String> {
+        self.request_token(&[("code", code), ("grant_type", "authorization_code")])
+            .await
+    }
+
+    pub async fn refresh(&self, refresh_token: &str) -> Result<StravaToken, String> {
+        self.request_token(&[
+            ("refresh_token", refresh_token),
+            ("grant_type", "refresh_token"),
+        ])
+        .await
+    }
+
+    async fn request_token(&self, params: &[(&str, &str)]) -> Result<StravaToken, String> {
+        let client_id = self.client_id.as_ref().ok_or("Strava isn't set up")?;
+
+        let mut form = vec![
+            ("client_id", client_id.as_str()),
+            ("client_secret", self.client_secret.as_str()),
+        ];
+        form.extend_from_slice(params);
+
+        self.client
+            .post(format!("{}/oauth/token", self.base_url))
+            .form(&form)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| format!("Strava wouldn't give us a token: {e}"))?
+            .json()
+            .await
+            .map_err(|e| format!("Unable to read Strava's token: {e}"))
+    }
+
+    /// The athlete's activities that started after the given unix timestamp.
+    pub async fn activities(
+        &self,
+        access_token: &str,
+        after: i64,
+    ) -> Result<Vec<StravaActivity>, String> {
+        self.client
+            .get(format!("{}/api/v3/athlete/activities", self.base_url))
+            .bearer_auth(access_token)
+            .query(&[("after", after), ("per_page", 50)])
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| format!("Unable to get your Strava activities: {e}"))?
+            .json()
+            .await
+            .map_err(|e| format!("Unable to read your Strava activities: {e}"))
+    }
+
+    /// Revokes our access, as if the member removed us from their Strava
+    /// settings.
+    pub async fn deauthorize(&self, access_token: &str) -> Result<(), String> {
+        self.client
+            .post(format!("{}/oauth/deauthorize", self.base_url))
+            .bearer_auth(access_token)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| format!("Unable to disconnect from Strava: {e}"))?;
+
+        Ok(())
+    }
+}
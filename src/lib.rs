@@ -6,12 +6,18 @@ use sqlx::SqlitePool;
 use std::{env, net::SocketAddr, sync::Arc};
 use tokio::net::TcpListener;
 use tower_http::{
-    compression::CompressionLayer, services::ServeDir, set_header::SetResponseHeaderLayer,
+    compression::CompressionLayer,
+    services::{ServeDir, ServeFile},
+    set_header::SetResponseHeaderLayer,
 };
 
 use crate::{
-    application::{RaceService, TownService, UserService},
+    application::{
+        ActivityService, ApiTokenService, AuditService, NotificationService, PushService,
+        RaceService, SearchService, TownService, UserService, WebhookService,
+    },
     infrastructure::db::Database,
+    util::rate_limit::RateLimiter,
 };
 
 pub mod application;
@@ -49,12 +55,26 @@ async fn initialize() -> Router {
 
     Router::new()
         .merge(serve_static)
+        // From the root, so the worker can look after every page, and without
+        // the long cache so updates to it are picked up.
+        .route_service(
+            "/service-worker.js",
+            ServeFile::new("public/service-worker.js"),
+        )
         .merge(routes::homepage::routes())
         .merge(routes::auth::routes())
         .merge(routes::admin::routes())
         .merge(routes::members::routes())
+        .merge(routes::moderation::routes())
         .merge(routes::submit_town::routes())
         .merge(routes::races::routes())
+        .merge(routes::search::routes())
+        .merge(routes::api::routes())
+        .merge(routes::tokens::routes())
+        .merge(routes::webhooks::routes())
+        .merge(routes::notifications::routes())
+        .merge(routes::push::routes())
+        .merge(routes::activities::routes())
         .with_state(state)
         .layer(CompressionLayer::new())
 }
@@ -84,6 +104,14 @@ pub struct AppState {
     pub user_service: UserService,
     pub town_service: TownService,
     pub race_service: RaceService,
+    pub search_service: SearchService,
+    pub audit_service: AuditService,
+    pub api_token_service: ApiTokenService,
+    pub webhook_service: WebhookService,
+    pub notification_service: NotificationService,
+    pub push_service: PushService,
+    pub activity_service: ActivityService,
+    pub rate_limiter: RateLimiter,
 }
 impl AppState {
     pub fn new(db: &DbConnection, app_info: AppInfo) -> Self {
@@ -92,6 +120,14 @@ impl AppState {
             user_service: UserService::new(db),
             town_service: TownService::new(db),
             race_service: RaceService::new(db),
+            search_service: SearchService::new(db),
+            audit_service: AuditService::new(db),
+            api_token_service: ApiTokenService::new(db),
+            webhook_service: WebhookService::new(db),
+            notification_service: NotificationService::new(db),
+            push_service: PushService::new(db),
+            activity_service: ActivityService::new(db),
+            rate_limiter: RateLimiter::from_env(),
         }
     }
 }
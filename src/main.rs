@@ -1,4 +1,7 @@
-use ctrunner::{application::RaceService, infrastructure::db::Database};
+use ctrunner::{
+    application::{NotificationService, PushService, RaceService, WebhookService},
+    infrastructure::db::Database,
+};
 use dotenv::dotenv;
 use log::info;
 use std::{fs, path::Path, sync::Arc};
@@ -25,16 +28,55 @@ async fn run_nightly_jobs() {
     let upcoming_races_job = Job::new_async("0 * * * * *", |_uuid, _l| {
         Box::pin(async move {
             info!("🦉 Gathering upcoming races...");
+            let db = Arc::new(Database::initialize().await);
+            let race_service = RaceService::new(&db);
+            if let Ok(races) = race_service.upcoming_races_nightly().await {
+                NotificationService::new(&db)
+                    .send_instant_alerts(&races)
+                    .await;
+            }
+            race_service.detect_duplicates().await;
+            info!("Gathered some new races.");
+        })
+    })
+    .unwrap();
+
+    // Deliveries are retried with a backoff, so checking often is cheap.
+    let webhooks = Arc::new(WebhookService::new(&Arc::new(Database::initialize().await)));
+    let webhook_delivery_job = Job::new_async("*/15 * * * * *", move |_uuid, _l| {
+        let webhooks = webhooks.clone();
+        Box::pin(async move {
+            webhooks.deliver_pending().await;
+        })
+    })
+    .unwrap();
+
+    // Monday mornings, 9am Eastern.
+    let weekly_digest_job = Job::new_async("0 0 13 * * Mon", |_uuid, _l| {
+        Box::pin(async move {
+            info!("📬 Sending weekly race digests...");
             let db = Database::initialize().await;
-            let _ = RaceService::new(&Arc::new(db))
-                .upcoming_races_nightly()
+            NotificationService::new(&Arc::new(db))
+                .send_weekly_digests()
                 .await;
-            info!("Gathered some new races.");
+        })
+    })
+    .unwrap();
+
+    // Evenings, 6pm Eastern, for races tomorrow.
+    let race_reminders_job = Job::new_async("0 0 22 * * *", |_uuid, _l| {
+        Box::pin(async move {
+            info!("🔔 Sending race reminders...");
+            let db = Database::initialize().await;
+            PushService::new(&Arc::new(db)).send_race_reminders().await;
         })
     })
     .unwrap();
 
     sched.add(upcoming_races_job).await.unwrap();
+    sched.add(webhook_delivery_job).await.unwrap();
+    sched.add(weekly_digest_job).await.unwrap();
+    sched.add(race_reminders_job).await.unwrap();
     sched.start().await.unwrap();
 }
 
@@ -0,0 +1,214 @@
+use askama::Template;
+use askama_web::WebTemplate;
+use axum::{
+    Router,
+    extract::{DefaultBodyLimit, Multipart, State},
+    http::StatusCode,
+    response::{IntoResponse, Redirect},
+    routing::{get, post},
+};
+use axum_extra::extract::{
+    CookieJar, Query,
+    cookie::{self, Cookie},
+};
+use log::error;
+use oauth2::CsrfToken;
+use serde::Deserialize;
+
+use crate::{
+    SharedState,
+    domain::{
+        activity::ActivityMatch,
+        rbac::{AccountResource, Action, Role},
+    },
+    extract::CurrentUser,
+    filters,
+    routes::SharedContext,
+    util::{htmx::HTMX, rbac::Can},
+};
+
+/// Long runs recorded every second make for big GPX files.
+const MAX_UPLOAD_BYTES: usize = 20 * 1024 * 1024;
+
+const STRAVA_STATE_COOKIE: &str = "strava_state";
+
+pub fn routes() -> Router<SharedState> {
+    Router::new()
+        .route("/activities", get(activities_page))
+        .route(
+            "/activities/upload",
+            post(upload_activity).layer(DefaultBodyLimit::max(MAX_UPLOAD_BYTES)),
+        )
+        .route(
+            "/activities/strava",
+            get(connect_strava).delete(disconnect_strava),
+        )
+        .route("/activities/strava/callback", get(strava_callback))
+        .route("/activities/strava/matches", get(strava_matches))
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "activities/activities.html")]
+struct ActivitiesTemplate {
+    shared: SharedContext,
+    strava_enabled: bool,
+    strava_connected: bool,
+    error: Option<String>,
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "activities/matches.html")]
+struct MatchesTemplate {
+    matches: Vec<ActivityMatch>,
+    error: Option<String>,
+}
+
+impl MatchesTemplate {
+    fn error(error: String) -> Self {
+        Self {
+            matches: vec![],
+            error: Some(error),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct StravaCallback {
+    code: Option<String>,
+    state: Option<String>,
+    /// Set to `access_denied` when the member cancels.
+    error: Option<String>,
+}
+
+async fn activities_page(
+    State(state): State<SharedState>,
+    CurrentUser(user): CurrentUser,
+) -> impl IntoResponse {
+    ActivitiesTemplate {
+        strava_enabled: state.activity_service.is_strava_enabled(),
+        strava_connected: state.activity_service.is_strava_connected(user.id).await,
+        shared: SharedContext::new(&state.app_info, Some(*user)),
+        error: None,
+    }
+}
+
+async fn upload_activity(
+    State(state): State<SharedState>,
+    CurrentUser(_): CurrentUser,
+    mut multipart: Multipart,
+) -> impl IntoResponse {
+    let field = match multipart.next_field().await {
+        Ok(Some(field)) => field,
+        _ => return MatchesTemplate::error("Choose a GPX or FIT file".to_string()),
+    };
+
+    let filename = field.file_name().unwrap_or_default().to_string();
+    let bytes = match field.bytes().await {
+        Ok(bytes) => bytes,
+        Err(_) => return MatchesTemplate::error("That file is too big".to_string()),
+    };
+
+    match state.activity_service.match_file(&filename, &bytes).await {
+        Ok(activity_match) => MatchesTemplate {
+            matches: vec![activity_match],
+            error: None,
+        },
+        Err(e) => MatchesTemplate::error(e),
+    }
+}
+
+async fn strava_matches(
+    State(state): State<SharedState>,
+    CurrentUser(user): CurrentUser,
+) -> impl IntoResponse {
+    match state.activity_service.match_strava(user.id).await {
+        Ok(matches) => MatchesTemplate {
+            matches,
+            error: None,
+        },
+        Err(e) => {
+            error!(
+                "Unable to match Strava activities for user ({}): {e}",
+                user.id
+            );
+            MatchesTemplate::error("Unable to get your activities from Strava".to_string())
+        }
+    }
+}
+
+async fn connect_strava(
+    State(state): State<SharedState>,
+    CurrentUser(user): CurrentUser,
+    cookies: CookieJar,
+) -> impl IntoResponse {
+    // Otherwise the member's Strava would end up connected to the admin.
+    if let Err(status) = user.authorize(Action::Create, &AccountResource::StravaConnection) {
+        return status.into_response();
+    }
+
+    let csrf = CsrfToken::new_random();
+    let Some(url) = state.activity_service.strava_authorize_url(csrf.secret()) else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    let state_cookie = Cookie::build((STRAVA_STATE_COOKIE, csrf.secret().clone()))
+        .path("/activities/strava")
+        .http_only(true)
+        .same_site(cookie::SameSite::Lax);
+
+    (cookies.add(state_cookie), Redirect::to(&url)).into_response()
+}
+
+async fn strava_callback(
+    State(state): State<SharedState>,
+    CurrentUser(user): CurrentUser,
+    Query(params): Query<StravaCallback>,
+    cookies: CookieJar,
+) -> impl IntoResponse {
+    let expected_state = cookies
+        .get(STRAVA_STATE_COOKIE)
+        .map(|cookie| cookie.value().to_string());
+    let cookies = cookies.remove(Cookie::build(STRAVA_STATE_COOKIE).path("/activities/strava"));
+
+    if params.error.is_some() {
+        return (cookies, Redirect::to("/activities")).into_response();
+    }
+
+    let (Some(code), Some(expected_state)) = (params.code, expected_state) else {
+        return (cookies, StatusCode::BAD_REQUEST).into_response();
+    };
+    if params.state.as_deref() != Some(expected_state.as_str()) {
+        return (cookies, StatusCode::BAD_REQUEST).into_response();
+    }
+
+    match state.activity_service.connect_strava(user.id, &code).await {
+        Ok(_) => (cookies, Redirect::to("/activities")).into_response(),
+        Err(e) => {
+            error!("Unable to connect Strava for user ({}): {e}", user.id);
+            (
+                cookies,
+                ActivitiesTemplate {
+                    strava_enabled: true,
+                    strava_connected: false,
+                    shared: SharedContext::new(&state.app_info, Some(*user)),
+                    error: Some("Unable to connect to Strava, please try again".to_string()),
+                },
+            )
+                .into_response()
+        }
+    }
+}
+
+async fn disconnect_strava(
+    State(state): State<SharedState>,
+    CurrentUser(user): CurrentUser,
+) -> impl IntoResponse {
+    if let Err(status) = user.authorize(Action::Delete, &AccountResource::StravaConnection) {
+        return status.into_response();
+    }
+
+    match state.activity_service.disconnect_strava(user.id).await {
+        Ok(_) => HTMX::refresh().into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e).into_response(),
+    }
+}
@@ -1,94 +1,322 @@
 use crate::{
     SharedState,
     domain::{
-        rbac::Role,
-        user::{UpdateUser, UserView},
+        Town,
+        audit::{AuditLog, AuditLogSearchParams, NewAuditLog},
+        duplicate::{AcceptDuplicateForm, RaceDuplicateView},
+        race::{
+            AdminRaceSearchParams, MergeRaceForm, NewRace, RaceStatus, RaceView, UpdateRaceForm,
+        },
+        rbac::{Action, Role},
+        town::County,
+        user::{AdminUserSearchParams, Onboarding, UpdateUser, UserView},
     },
-    util::htmx::HTMX,
+    filters,
+    infrastructure::jwt::{JwtService, impersonation_claims::ImpersonationClaims},
+    util::{csv::Csv, htmx::HTMX, rbac::Can, validation::Validate},
 };
 
 use askama::Template;
 use askama_web::WebTemplate;
 use axum::{
     Form, Router,
-    extract::{Path, Query, State},
-    response::IntoResponse,
-    routing::{get, patch},
+    extract::{Path, State},
+    http::header::{CONTENT_DISPOSITION, CONTENT_TYPE},
+    response::{IntoResponse, Response},
+    routing::{delete, get, patch, post},
 };
+use axum_extra::extract::{
+    CookieJar, Form as MultiForm, Query,
+    cookie::{self, Cookie},
+};
+use log::error;
 use reqwest::StatusCode;
 use serde::Deserialize;
 
 use crate::{
-    extract::admin_user::AdminUser,
+    extract::{MaybeCurrentUser, RoyaltyUser, admin_user::AdminUser, real_ip::RealIp},
     routes::SharedContext,
-    util::pagination::{PaginatedResponse, Pagination},
+    util::pagination::{CursorPage, CursorPagination, PaginatedResponse},
 };
 
 pub fn routes() -> Router<SharedState> {
     Router::new()
         .route("/admin/users", get(users))
+        .route("/admin/users/export.csv", get(export_users))
+        .route("/admin/users/bulk", post(bulk_edit_users))
         .route("/admin/users/{id}", get(view_user))
         .route("/admin/users/{id}", patch(edit_user))
-}
-
-#[derive(Deserialize)]
-struct UserSearch {
-    page_size: Option<i64>,
-    page: Option<i64>,
-    q: Option<String>,
+        .route("/admin/users/{id}/impersonate", post(impersonate_user))
+        .route("/admin/impersonate", delete(stop_impersonating))
+        .route("/admin/audit", get(audit_logs))
+        .route("/admin/races", get(races))
+        .route("/admin/races/duplicates", get(race_duplicates))
+        .route(
+            "/admin/races/duplicates/{id}/accept",
+            post(accept_race_duplicate),
+        )
+        .route(
+            "/admin/races/duplicates/{id}/dismiss",
+            post(dismiss_race_duplicate),
+        )
+        .route(
+            "/admin/races/{id}",
+            get(view_race).patch(edit_race).delete(delete_race),
+        )
+        .route("/admin/races/{id}/merge", post(merge_race))
 }
 
 #[derive(Template, WebTemplate)]
 #[template(path = "admin/users.html")]
 struct AdminUsersTemplate {
     shared: SharedContext,
+    params: AdminUserSearchParams,
+    counties: Vec<County>,
     users: PaginatedResponse<UserView>,
 }
 
 #[derive(Template, WebTemplate)]
 #[template(path = "admin/view_user.html")]
 struct AdminViewUserTemplate {
+    current_user: UserView,
     user: UserView,
 }
 
+impl AdminViewUserTemplate {
+    fn can_impersonate(&self) -> bool {
+        self.current_user.can(Action::Impersonate, &self.user)
+    }
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "admin/race_duplicates.html")]
+struct AdminRaceDuplicatesTemplate {
+    shared: SharedContext,
+    duplicates: Vec<RaceDuplicateView>,
+    error: Option<String>,
+}
+
+impl AdminRaceDuplicatesTemplate {
+    async fn new(state: &SharedState, admin: UserView, error: Option<String>) -> Self {
+        Self {
+            shared: SharedContext::new(&state.app_info, Some(admin)),
+            duplicates: state.race_service.find_duplicates().await,
+            error,
+        }
+    }
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "admin/audit.html")]
+struct AdminAuditTemplate {
+    shared: SharedContext,
+    params: AuditLogSearchParams,
+    logs: CursorPage<AuditLog>,
+}
+
+/// Older entries, for scrolling further down the log.
+#[derive(Template, WebTemplate)]
+#[template(path = "admin/audit_rows.html")]
+struct AdminAuditRowsTemplate {
+    logs: CursorPage<AuditLog>,
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "admin/races.html")]
+struct AdminRacesTemplate {
+    shared: SharedContext,
+    params: AdminRaceSearchParams,
+    statuses: [RaceStatus; 4],
+    races: PaginatedResponse<RaceView>,
+}
+
+impl AdminRacesTemplate {
+    fn is_status(&self, status: &RaceStatus) -> bool {
+        self.params.status == Some(*status)
+    }
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "admin/edit_race.html")]
+struct AdminEditRaceTemplate {
+    race: RaceView,
+    towns: Vec<Town>,
+    /// Races nearby that this one could be merged into.
+    candidates: Vec<RaceView>,
+    error: Option<String>,
+}
+
+impl AdminEditRaceTemplate {
+    async fn new(state: &SharedState, race: RaceView) -> Self {
+        Self {
+            towns: state.town_service.find_all().await,
+            candidates: state.race_service.find_merge_candidates(&race).await,
+            race,
+            error: None,
+        }
+    }
+
+    /// Shows the race again with what went wrong.
+    async fn error(state: &SharedState, race_id: i64, error: String) -> Response {
+        match state.race_service.find_by_id(race_id).await {
+            Ok(race) => {
+                let mut template = Self::new(state, race).await;
+                template.error = Some(error);
+                template.into_response()
+            }
+            Err(_) => StatusCode::NOT_FOUND.into_response(),
+        }
+    }
+}
+
 #[derive(Deserialize)]
 struct UpdateUserForm {
     locked: Option<String>,
     role: Role,
 }
 
+#[derive(Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+enum BulkAction {
+    Lock,
+    Unlock,
+    SetRole,
+}
+
+#[derive(Deserialize)]
+struct BulkEditUsersForm {
+    #[serde(default)]
+    user_ids: Vec<i64>,
+    action: BulkAction,
+    role: Option<Role>,
+}
+
 async fn users(
     State(state): State<SharedState>,
     AdminUser(user): AdminUser,
-    Query(params): Query<UserSearch>,
+    Query(params): Query<AdminUserSearchParams>,
 ) -> impl IntoResponse {
-    let pagination = Pagination {
-        page: params.page,
-        page_size: params.page_size,
-    };
     AdminUsersTemplate {
         shared: SharedContext::new(&state.app_info, Some(*user)),
-        users: state
-            .user_service
-            .search(&pagination, &params.q.unwrap_or_default())
-            .await,
+        counties: state.town_service.find_all_counties().await,
+        users: state.user_service.admin_search(&params).await,
+        params,
     }
 }
 
-async fn view_user(
+async fn export_users(
     State(state): State<SharedState>,
     AdminUser(_): AdminUser,
+    Query(params): Query<AdminUserSearchParams>,
+) -> impl IntoResponse {
+    let users = match state.user_service.admin_export(&params).await {
+        Ok(users) => users,
+        Err(e) => {
+            error!("Unable to export users: {e}");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let mut csv = Csv::new(&[
+        "id",
+        "runner_id",
+        "full_name",
+        "email",
+        "role",
+        "locked",
+        "verified",
+        "hometown",
+        "hometown_county",
+        "completed_towns_count",
+        "created_at",
+    ]);
+    for user in users {
+        csv.row(&[
+            &user.id.to_string(),
+            &user.runner_id.map(|id| id.to_string()).unwrap_or_default(),
+            &user.full_name,
+            &user.email,
+            &user.role.to_string(),
+            &user.locked.to_string(),
+            &user.verified.to_string(),
+            &user.hometown.unwrap_or_default(),
+            &user.hometown_county.unwrap_or_default(),
+            &user.completed_towns_count.to_string(),
+            &user.created_at.to_string(),
+        ]);
+    }
+
+    (
+        [
+            (CONTENT_TYPE, "text/csv; charset=utf-8"),
+            (CONTENT_DISPOSITION, "attachment; filename=\"users.csv\""),
+        ],
+        csv.to_string(),
+    )
+        .into_response()
+}
+
+async fn bulk_edit_users(
+    State(state): State<SharedState>,
+    AdminUser(admin): AdminUser,
+    RealIp(ip): RealIp,
+    MultiForm(form): MultiForm<BulkEditUsersForm>,
+) -> impl IntoResponse {
+    if form.action == BulkAction::SetRole && form.role.is_none() {
+        return StatusCode::BAD_REQUEST.into_response();
+    }
+
+    for user_id in form.user_ids {
+        let user = match state.user_service.find_by_id(user_id).await {
+            Ok(user) => user,
+            Err(_) => continue,
+        };
+
+        // Quietly skip anyone the admin can't manage, such as themselves.
+        if admin.cannot(Action::Manage, &user) {
+            continue;
+        }
+
+        let mut update = UpdateUser::from(user.clone());
+        match form.action {
+            BulkAction::Lock => update.locked = true,
+            BulkAction::Unlock => update.locked = false,
+            BulkAction::SetRole => update.role = form.role.clone().unwrap_or_default(),
+        }
+
+        match state.user_service.update(&update).await {
+            Ok(updated) => {
+                let entry = NewAuditLog::new(&admin, "user.bulk_update", "user", user.id, &ip)
+                    .before(&user)
+                    .after(&updated);
+                state.audit_service.record(entry).await;
+            }
+            Err(e) => error!("Unable to bulk update user ({user_id}): {e}"),
+        }
+    }
+
+    HTMX::refresh().into_response()
+}
+
+async fn view_user(
+    State(state): State<SharedState>,
+    AdminUser(admin): AdminUser,
     Path(user_id): Path<i64>,
 ) -> impl IntoResponse {
     match state.user_service.find_by_id(user_id).await {
-        Ok(user) => AdminViewUserTemplate { user }.into_response(),
+        Ok(user) => AdminViewUserTemplate {
+            current_user: *admin,
+            user,
+        }
+        .into_response(),
         Err(_) => StatusCode::NOT_FOUND.into_response(),
     }
 }
 
 async fn edit_user(
     State(state): State<SharedState>,
-    AdminUser(_): AdminUser,
+    AdminUser(admin): AdminUser,
+    RealIp(ip): RealIp,
     Path(user_id): Path<i64>,
     Form(form): Form<UpdateUserForm>,
 ) -> impl IntoResponse {
@@ -97,12 +325,295 @@ async fn edit_user(
         Err(_) => return StatusCode::NOT_FOUND.into_response(),
     };
 
-    let mut user = UpdateUser::from(user);
-    user.locked = form.locked.is_some();
-    user.role = form.role;
+    if let Err(status) = admin.authorize(Action::Manage, &user) {
+        return status.into_response();
+    }
+
+    let mut update = UpdateUser::from(user.clone());
+    update.locked = form.locked.is_some();
+    update.role = form.role;
 
-    match state.user_service.update(&user).await {
-        Ok(_) => HTMX::refresh().into_response(),
+    match state.user_service.update(&update).await {
+        Ok(updated) => {
+            let entry = NewAuditLog::new(&admin, "user.update", "user", user.id, &ip)
+                .before(&user)
+                .after(&updated);
+            state.audit_service.record(entry).await;
+            HTMX::refresh().into_response()
+        }
         Err(_) => StatusCode::NOT_FOUND.into_response(),
     }
 }
+
+async fn impersonate_user(
+    State(state): State<SharedState>,
+    AdminUser(admin): AdminUser,
+    RealIp(ip): RealIp,
+    Path(user_id): Path<i64>,
+    cookies: CookieJar,
+) -> impl IntoResponse {
+    let user = match state.user_service.find_by_id(user_id).await {
+        Ok(user) => user,
+        Err(_) => return StatusCode::NOT_FOUND.into_response(),
+    };
+
+    if let Err(status) = admin.authorize(Action::Impersonate, &user) {
+        return status.into_response();
+    }
+
+    let token = match JwtService::generate(&ImpersonationClaims::new(&admin, &user)) {
+        Ok(token) => token,
+        Err(e) => {
+            error!("Unable to generate impersonation JWT: {e}");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+
+    let entry = NewAuditLog::new(&admin, "user.impersonate.start", "user", user.id, &ip);
+    state.audit_service.record(entry).await;
+
+    let impersonation_cookie = Cookie::build(("impersonation_token", token))
+        .path("/")
+        .http_only(true)
+        .same_site(cookie::SameSite::Strict)
+        .secure(true);
+
+    let redirect = match user.runner_id {
+        Some(_) => "/dashboard",
+        None => "/update-info",
+    };
+
+    (cookies.add(impersonation_cookie), HTMX::redirect(redirect)).into_response()
+}
+
+async fn stop_impersonating(
+    State(state): State<SharedState>,
+    MaybeCurrentUser(user): MaybeCurrentUser,
+    RealIp(ip): RealIp,
+    cookies: CookieJar,
+) -> impl IntoResponse {
+    if let Some(user) = user
+        && let Some(impersonator) = &user.impersonated_by
+        && let Ok(admin) = state.user_service.find_by_id(impersonator.id).await
+    {
+        let entry = NewAuditLog::new(&admin, "user.impersonate.stop", "user", user.id, &ip);
+        state.audit_service.record(entry).await;
+    }
+
+    let cookies = cookies.remove(
+        Cookie::build(("impersonation_token", ""))
+            .path("/")
+            .http_only(true)
+            .same_site(cookie::SameSite::Strict)
+            .secure(true),
+    );
+    (cookies, HTMX::redirect("/admin/users")).into_response()
+}
+
+async fn audit_logs(
+    State(state): State<SharedState>,
+    AdminUser(user): AdminUser,
+    Query(params): Query<AuditLogSearchParams>,
+    Query(pagination): Query<CursorPagination>,
+) -> impl IntoResponse {
+    let logs = state.audit_service.search(&params, &pagination).await;
+
+    if pagination.is_continued() {
+        return AdminAuditRowsTemplate { logs }.into_response();
+    }
+
+    AdminAuditTemplate {
+        shared: SharedContext::new(&state.app_info, Some(*user)),
+        logs,
+        params,
+    }
+    .into_response()
+}
+
+async fn races(
+    State(state): State<SharedState>,
+    AdminUser(user): AdminUser,
+    Query(params): Query<AdminRaceSearchParams>,
+) -> impl IntoResponse {
+    AdminRacesTemplate {
+        shared: SharedContext::new(&state.app_info, Some(*user)),
+        races: state.race_service.admin_search(&params).await,
+        statuses: RaceStatus::ALL,
+        params,
+    }
+}
+
+async fn view_race(
+    State(state): State<SharedState>,
+    RoyaltyUser(user): RoyaltyUser,
+    Path(race_id): Path<i64>,
+) -> impl IntoResponse {
+    let race = match state.race_service.find_by_id(race_id).await {
+        Ok(race) => race,
+        Err(_) => return StatusCode::NOT_FOUND.into_response(),
+    };
+
+    if let Err(status) = user.authorize(Action::Update, &race) {
+        return status.into_response();
+    }
+
+    AdminEditRaceTemplate::new(&state, race)
+        .await
+        .into_response()
+}
+
+async fn edit_race(
+    State(state): State<SharedState>,
+    RoyaltyUser(user): RoyaltyUser,
+    RealIp(ip): RealIp,
+    Path(race_id): Path<i64>,
+    MultiForm(form): MultiForm<UpdateRaceForm>,
+) -> impl IntoResponse {
+    let race = match state.race_service.find_by_id(race_id).await {
+        Ok(race) => race,
+        Err(_) => return StatusCode::NOT_FOUND.into_response(),
+    };
+
+    if let Err(status) = user.authorize(Action::Update, &race) {
+        return status.into_response();
+    }
+
+    if let Err(error) = form.validate() {
+        return AdminEditRaceTemplate::error(&state, race_id, error).await;
+    }
+
+    match state
+        .race_service
+        .update(race_id, NewRace::from(form))
+        .await
+    {
+        Ok(updated) => {
+            let entry = NewAuditLog::new(&user, "race.update", "race", race.id, &ip)
+                .before(&race)
+                .after(&updated);
+            state.audit_service.record(entry).await;
+            HTMX::refresh().into_response()
+        }
+        Err(error) => AdminEditRaceTemplate::error(&state, race_id, error).await,
+    }
+}
+
+async fn delete_race(
+    State(state): State<SharedState>,
+    RoyaltyUser(user): RoyaltyUser,
+    RealIp(ip): RealIp,
+    Path(race_id): Path<i64>,
+) -> impl IntoResponse {
+    let race = match state.race_service.find_by_id(race_id).await {
+        Ok(race) => race,
+        Err(_) => return StatusCode::NOT_FOUND.into_response(),
+    };
+
+    if let Err(status) = user.authorize(Action::Delete, &race) {
+        return status.into_response();
+    }
+
+    match state.race_service.delete(race_id, &user).await {
+        Ok(deleted) => {
+            let entry = NewAuditLog::new(&user, "race.delete", "race", race.id, &ip)
+                .before(&race)
+                .after(&deleted);
+            state.audit_service.record(entry).await;
+            HTMX::refresh().into_response()
+        }
+        Err(error) => AdminEditRaceTemplate::error(&state, race_id, error).await,
+    }
+}
+
+async fn merge_race(
+    State(state): State<SharedState>,
+    RoyaltyUser(user): RoyaltyUser,
+    RealIp(ip): RealIp,
+    Path(race_id): Path<i64>,
+    Form(form): Form<MergeRaceForm>,
+) -> impl IntoResponse {
+    let race = match state.race_service.find_by_id(race_id).await {
+        Ok(race) => race,
+        Err(_) => return StatusCode::NOT_FOUND.into_response(),
+    };
+
+    if let Err(status) = user.authorize(Action::Delete, &race) {
+        return status.into_response();
+    }
+
+    match state
+        .race_service
+        .merge(race_id, form.into_race_id, &user)
+        .await
+    {
+        Ok(into) => {
+            let entry = NewAuditLog::new(&user, "race.merge", "race", race.id, &ip)
+                .before(&race)
+                .after(&into);
+            state.audit_service.record(entry).await;
+            HTMX::refresh().into_response()
+        }
+        Err(error) => AdminEditRaceTemplate::error(&state, race_id, error).await,
+    }
+}
+
+async fn race_duplicates(
+    State(state): State<SharedState>,
+    AdminUser(admin): AdminUser,
+) -> impl IntoResponse {
+    AdminRaceDuplicatesTemplate::new(&state, *admin, None).await
+}
+
+async fn accept_race_duplicate(
+    State(state): State<SharedState>,
+    AdminUser(admin): AdminUser,
+    RealIp(ip): RealIp,
+    Path(duplicate_id): Path<i64>,
+    Form(form): Form<AcceptDuplicateForm>,
+) -> impl IntoResponse {
+    match state
+        .race_service
+        .accept_duplicate(duplicate_id, form.keep_race_id, &admin)
+        .await
+    {
+        Ok((merged, kept)) => {
+            let entry = NewAuditLog::new(&admin, "race.merge", "race", merged.id, &ip)
+                .before(&merged)
+                .after(&kept);
+            state.audit_service.record(entry).await;
+            HTMX::refresh().into_response()
+        }
+        Err(error) => AdminRaceDuplicatesTemplate::new(&state, *admin, Some(error))
+            .await
+            .into_response(),
+    }
+}
+
+async fn dismiss_race_duplicate(
+    State(state): State<SharedState>,
+    AdminUser(admin): AdminUser,
+    RealIp(ip): RealIp,
+    Path(duplicate_id): Path<i64>,
+) -> impl IntoResponse {
+    match state
+        .race_service
+        .dismiss_duplicate(duplicate_id, &admin)
+        .await
+    {
+        Ok(duplicate) => {
+            let entry = NewAuditLog::new(
+                &admin,
+                "race_duplicate.dismiss",
+                "race_duplicate",
+                duplicate.id,
+                &ip,
+            )
+            .before(&duplicate);
+            state.audit_service.record(entry).await;
+            HTMX::refresh().into_response()
+        }
+        Err(error) => AdminRaceDuplicatesTemplate::new(&state, *admin, Some(error))
+            .await
+            .into_response(),
+    }
+}
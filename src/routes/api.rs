@@ -0,0 +1,616 @@
+use askama::Template;
+use askama_web::WebTemplate;
+use axum::{
+    Json, Router,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    middleware,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use serde::Deserialize;
+use utoipa::{
+    IntoParams, Modify, OpenApi,
+    openapi::{
+        self, PathItem, RefOr, Required,
+        security::{Http, HttpAuthScheme, SecurityScheme},
+    },
+};
+
+use crate::{
+    SharedState,
+    domain::{
+        Town,
+        api_token::TokenScope,
+        distance::DistanceUnit,
+        race::{RaceResultView, RaceSearchParams, RaceView},
+        rbac::{Action, Role},
+        submission::{SubmissionError, SubmissionOutcome, TownSubmission},
+        town::{CompletedTown, SubmitTown},
+        user::MemberProfile,
+    },
+    extract::{ApiUser, MaybeCurrentUser, Throttle},
+    routes::SharedContext,
+    util::{
+        api::{ApiError, ApiErrorBody, ApiJson, ApiPath, ApiQuery, ApiResult, json_errors},
+        pagination::{PaginatedResponse, Pagination},
+        rate_limit,
+        rbac::Can,
+        validation::Validate,
+    },
+};
+
+pub fn routes() -> Router<SharedState> {
+    let v1 = Router::new()
+        .route("/towns", get(towns))
+        .route("/towns/{id}", get(town))
+        .route("/races", get(races))
+        .route("/races/{id}", get(race))
+        .route("/members", get(members))
+        .route("/members/{runner_id}", get(member))
+        .route(
+            "/members/{runner_id}/completed-towns",
+            get(member_completed_towns),
+        )
+        .route("/me", get(me))
+        .route("/me/submissions", get(my_submissions).post(submit_town))
+        .fallback(not_found)
+        .method_not_allowed_fallback(method_not_allowed)
+        .layer(middleware::map_response(json_errors));
+
+    Router::new()
+        .route("/api/openapi.json", get(openapi_json))
+        .route("/api/docs", get(docs_page))
+        .nest("/api/v1", v1)
+}
+
+/// The contract for `/api/v1`. The committed copy in `openapi.json` is kept
+/// in step with this by `cargo run --bin openapi`.
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "CTRunner API",
+        description = "Towns, races and member progress. Every request needs \
+            `Authorization: Bearer <token>` with a personal API token."
+    ),
+    paths(
+        towns,
+        town,
+        races,
+        race,
+        members,
+        member,
+        member_completed_towns,
+        me,
+        my_submissions,
+        submit_town
+    ),
+    components(schemas(DistanceUnit)),
+    modifiers(&BearerAuth),
+    security(("bearer" = [])),
+    tags(
+        (name = "towns", description = "The 169 towns of Connecticut"),
+        (name = "races", description = "Upcoming races"),
+        (name = "members", description = "Members and their completed towns"),
+        (name = "me", description = "The member the token belongs to")
+    )
+)]
+pub struct ApiDoc;
+
+impl ApiDoc {
+    /// The document exactly as it's committed to `openapi.json`.
+    pub fn to_json() -> String {
+        ApiDoc::openapi()
+            .to_pretty_json()
+            .expect("OpenAPI document should serialize")
+            + "\n"
+    }
+}
+
+struct BearerAuth;
+
+impl Modify for BearerAuth {
+    fn modify(&self, openapi: &mut openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer",
+            SecurityScheme::Http(Http::new(HttpAuthScheme::Bearer)),
+        );
+    }
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "api/docs.html")]
+struct ApiDocsTemplate {
+    shared: SharedContext,
+    openapi: openapi::OpenApi,
+}
+
+/// One endpoint on the docs page.
+struct DocOperation {
+    method: &'static str,
+    path: String,
+    description: String,
+    /// `(name, where, required, description)`
+    params: Vec<(String, String, bool, String)>,
+    statuses: Vec<String>,
+}
+
+impl ApiDocsTemplate {
+    /// Every operation, in path order.
+    fn operations(&self) -> Vec<DocOperation> {
+        self.openapi
+            .paths
+            .paths
+            .iter()
+            .flat_map(|(path, item): (&String, &PathItem)| {
+                [
+                    ("GET", &item.get),
+                    ("POST", &item.post),
+                    ("PUT", &item.put),
+                    ("PATCH", &item.patch),
+                    ("DELETE", &item.delete),
+                ]
+                .into_iter()
+                .filter_map(move |(method, operation)| {
+                    operation
+                        .as_ref()
+                        .map(|operation| DocOperation::new(method, path, operation))
+                })
+            })
+            .collect()
+    }
+
+    /// Every schema as `(name, pretty JSON)`.
+    fn schemas(&self) -> Vec<(&str, String)> {
+        self.openapi
+            .components
+            .iter()
+            .flat_map(|components| components.schemas.iter())
+            .map(|(name, schema)| {
+                (
+                    name.as_str(),
+                    serde_json::to_string_pretty(schema).unwrap_or_default(),
+                )
+            })
+            .collect()
+    }
+}
+
+impl DocOperation {
+    fn new(method: &'static str, path: &str, operation: &openapi::path::Operation) -> Self {
+        let params = operation
+            .parameters
+            .iter()
+            .flatten()
+            .filter_map(|param| match param {
+                RefOr::T(param) => Some((
+                    param.name.clone(),
+                    serde_json::to_value(&param.parameter_in)
+                        .ok()
+                        .and_then(|value| value.as_str().map(str::to_string))
+                        .unwrap_or_default(),
+                    param.required == Required::True,
+                    param.description.clone().unwrap_or_default(),
+                )),
+                RefOr::Ref(_) => None,
+            })
+            .collect();
+
+        Self {
+            method,
+            path: path.to_string(),
+            description: operation.description.clone().unwrap_or_default(),
+            params,
+            statuses: operation.responses.responses.keys().cloned().collect(),
+        }
+    }
+}
+
+async fn openapi_json() -> impl IntoResponse {
+    Json(ApiDoc::openapi())
+}
+
+async fn docs_page(
+    State(state): State<SharedState>,
+    MaybeCurrentUser(user): MaybeCurrentUser,
+) -> impl IntoResponse {
+    ApiDocsTemplate {
+        shared: SharedContext::new(&state.app_info, user.as_deref().cloned()),
+        openapi: ApiDoc::openapi(),
+    }
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct MemberSearchParams {
+    /// Matches a member's name or runner id.
+    q: Option<String>,
+    page: Option<i64>,
+    /// From 1 to 100, 15 by default.
+    page_size: Option<i64>,
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct PageParams {
+    page: Option<i64>,
+    /// From 1 to 100, 15 by default.
+    page_size: Option<i64>,
+}
+
+impl From<PageParams> for Pagination {
+    fn from(params: PageParams) -> Self {
+        Self {
+            page: params.page,
+            page_size: params.page_size,
+        }
+    }
+}
+
+/// Wraps a complete list in a single page, so every list has the same shape.
+fn single_page<T>(items: Vec<T>) -> PaginatedResponse<T> {
+    let total = items.len() as i64;
+    PaginatedResponse::new(items, total, 1, total.max(1))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/towns",
+    tag = "towns",
+    responses(
+        (status = 200, description = "Every town", body = PaginatedResponse<Town>),
+        (status = 401, body = ApiErrorBody)
+    )
+)]
+async fn towns(State(state): State<SharedState>, _: ApiUser) -> ApiResult<PaginatedResponse<Town>> {
+    Ok(Json(single_page(state.town_service.find_all().await)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/towns/{id}",
+    tag = "towns",
+    params(("id" = i64, Path, description = "Town id")),
+    responses(
+        (status = 200, body = Town),
+        (status = 401, body = ApiErrorBody),
+        (status = 404, body = ApiErrorBody)
+    )
+)]
+async fn town(
+    State(state): State<SharedState>,
+    _: ApiUser,
+    ApiPath(town_id): ApiPath<i64>,
+) -> ApiResult<Town> {
+    state
+        .town_service
+        .find_by_id(town_id)
+        .await
+        .map(Json)
+        .map_err(|_| ApiError::not_found())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/races",
+    tag = "races",
+    params(RaceSearchParams),
+    responses(
+        (status = 200, description = "Upcoming races", body = PaginatedResponse<RaceView>),
+        (status = 400, body = ApiErrorBody),
+        (status = 401, body = ApiErrorBody),
+        (status = 403, body = ApiErrorBody)
+    )
+)]
+async fn races(
+    State(state): State<SharedState>,
+    api_user: ApiUser,
+    ApiQuery(params): ApiQuery<RaceSearchParams>,
+) -> ApiResult<PaginatedResponse<RaceView>> {
+    // Leaving out completed towns gives away the member's progress.
+    if params.not_completed == Some(true) {
+        api_user.require(TokenScope::ReadProgress)?;
+    }
+
+    Ok(Json(
+        state
+            .race_service
+            .search_for_upcoming(&params, Some(api_user.user.id))
+            .await,
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/races/{id}",
+    tag = "races",
+    description = "Tokens with the `admin` scope can also see races that aren't listed, e.g. \
+        pending or removed ones.",
+    params(("id" = i64, Path, description = "Race id")),
+    responses(
+        (status = 200, body = RaceView),
+        (status = 401, body = ApiErrorBody),
+        (status = 404, body = ApiErrorBody)
+    )
+)]
+async fn race(
+    State(state): State<SharedState>,
+    api_user: ApiUser,
+    ApiPath(race_id): ApiPath<i64>,
+) -> ApiResult<RaceView> {
+    state
+        .race_service
+        .find_by_id(race_id)
+        .await
+        .ok()
+        .filter(|race| race.is_listed() || api_user.has(TokenScope::Admin))
+        .map(Json)
+        .ok_or_else(ApiError::not_found)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/members",
+    tag = "members",
+    params(MemberSearchParams),
+    responses(
+        (status = 200, body = PaginatedResponse<MemberProfile>),
+        (status = 400, body = ApiErrorBody),
+        (status = 401, body = ApiErrorBody)
+    )
+)]
+async fn members(
+    State(state): State<SharedState>,
+    _: ApiUser,
+    ApiQuery(params): ApiQuery<MemberSearchParams>,
+) -> ApiResult<PaginatedResponse<MemberProfile>> {
+    let pagination = Pagination {
+        page: params.page,
+        page_size: params.page_size,
+    };
+    let members = state
+        .user_service
+        .search(&pagination, &params.q.unwrap_or_default())
+        .await;
+
+    Ok(Json(members.map(MemberProfile::from)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/members/{runner_id}",
+    tag = "members",
+    params(("runner_id" = i64, Path, description = "The member's runner id")),
+    responses(
+        (status = 200, body = MemberProfile),
+        (status = 401, body = ApiErrorBody),
+        (status = 404, body = ApiErrorBody)
+    )
+)]
+async fn member(
+    State(state): State<SharedState>,
+    _: ApiUser,
+    ApiPath(runner_id): ApiPath<i64>,
+) -> ApiResult<MemberProfile> {
+    state
+        .user_service
+        .find_by_runner_id(runner_id)
+        .await
+        .map(|user| Json(MemberProfile::from(user)))
+        .map_err(|_| ApiError::not_found())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/members/{runner_id}/completed-towns",
+    tag = "members",
+    description = "Needs the `read:progress` scope.",
+    params(("runner_id" = i64, Path, description = "The member's runner id")),
+    responses(
+        (status = 200, body = PaginatedResponse<CompletedTown>),
+        (status = 401, body = ApiErrorBody),
+        (status = 403, body = ApiErrorBody),
+        (status = 404, body = ApiErrorBody)
+    )
+)]
+async fn member_completed_towns(
+    State(state): State<SharedState>,
+    api_user: ApiUser,
+    ApiPath(runner_id): ApiPath<i64>,
+) -> ApiResult<PaginatedResponse<CompletedTown>> {
+    api_user.require(TokenScope::ReadProgress)?;
+
+    let member = state
+        .user_service
+        .find_by_runner_id(runner_id)
+        .await
+        .map_err(|_| ApiError::not_found())?;
+
+    let towns = state.town_service.find_completed(member.id).await;
+    Ok(Json(single_page(towns)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/me",
+    tag = "me",
+    responses(
+        (status = 200, body = MemberProfile),
+        (status = 401, body = ApiErrorBody)
+    )
+)]
+async fn me(api_user: ApiUser) -> ApiResult<MemberProfile> {
+    Ok(Json(MemberProfile::from(*api_user.user)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/me/submissions",
+    tag = "me",
+    description = "Needs the `read:progress` scope.",
+    params(PageParams),
+    responses(
+        (status = 200, body = PaginatedResponse<RaceResultView>),
+        (status = 400, body = ApiErrorBody),
+        (status = 401, body = ApiErrorBody),
+        (status = 403, body = ApiErrorBody)
+    )
+)]
+async fn my_submissions(
+    State(state): State<SharedState>,
+    api_user: ApiUser,
+    ApiQuery(params): ApiQuery<PageParams>,
+) -> ApiResult<PaginatedResponse<RaceResultView>> {
+    api_user.require(TokenScope::ReadProgress)?;
+
+    let pagination = Pagination::from(params);
+    Ok(Json(
+        state
+            .race_service
+            .find_results_for_user(api_user.user.id, &pagination)
+            .await,
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/me/submissions",
+    tag = "me",
+    description = "Submits a completed town to the Run169Towns Society. Needs the \
+        `write:submissions` scope. Safe to retry with the same `Idempotency-Key`: \
+        a replay answers `200` with `Idempotent-Replayed: true`.",
+    params(
+        (
+            "Idempotency-Key" = String,
+            Header,
+            description = "Any unique string (e.g. a UUID) made by the client for this \
+                submission. Retrying with the same key never submits the town twice."
+        )
+    ),
+    request_body = SubmitTown,
+    responses(
+        (status = 201, description = "Submitted", body = TownSubmission),
+        (status = 200, description = "Already submitted with this key", body = TownSubmission),
+        (status = 400, body = ApiErrorBody),
+        (status = 401, body = ApiErrorBody),
+        (status = 403, body = ApiErrorBody),
+        (status = 409, description = "Still being submitted by an earlier request, or the race was already submitted", body = ApiErrorBody),
+        (status = 422, description = "The key was used for a different submission", body = ApiErrorBody),
+        (status = 502, description = "The society's form didn't take it, retry later", body = ApiErrorBody)
+    )
+)]
+async fn submit_town(
+    State(state): State<SharedState>,
+    _: Throttle<rate_limit::SubmitTown>,
+    api_user: ApiUser,
+    headers: HeaderMap,
+    ApiJson(form): ApiJson<SubmitTown>,
+) -> Result<Response, ApiError> {
+    api_user.require(TokenScope::WriteSubmissions)?;
+    if api_user.user.cannot(Action::Create, &form) {
+        return Err(ApiError::forbidden());
+    }
+    form.validate().map_err(|e| ApiError::bad_request(&e))?;
+
+    let key = headers
+        .get("Idempotency-Key")
+        .and_then(|key| key.to_str().ok())
+        .ok_or_else(|| ApiError::bad_request("The Idempotency-Key header is required"))?;
+
+    match state
+        .town_service
+        .submit_idempotent(*api_user.user, key, form)
+        .await
+    {
+        Ok(SubmissionOutcome::Created(submission)) => {
+            Ok((StatusCode::CREATED, Json(submission)).into_response())
+        }
+        Ok(SubmissionOutcome::Replayed(submission)) => {
+            Ok(([("Idempotent-Replayed", "true")], Json(submission)).into_response())
+        }
+        Err(SubmissionError::InProgress) => Err(ApiError::new(
+            StatusCode::CONFLICT,
+            "This submission is still being processed, try again shortly",
+        )),
+        Err(SubmissionError::AlreadySubmitted) => Err(ApiError::new(
+            StatusCode::CONFLICT,
+            "You've already submitted this race",
+        )),
+        Err(SubmissionError::KeyReused) => Err(ApiError::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "This Idempotency-Key was already used for a different submission",
+        )),
+        Err(SubmissionError::Invalid(e)) => Err(ApiError::bad_request(&e)),
+        Err(SubmissionError::Failed(e)) => Err(ApiError::new(StatusCode::BAD_GATEWAY, &e)),
+    }
+}
+
+async fn not_found() -> ApiError {
+    ApiError::not_found()
+}
+
+async fn method_not_allowed() -> ApiError {
+    ApiError::from(StatusCode::METHOD_NOT_ALLOWED)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn openapi_json_is_up_to_date() {
+        let committed =
+            std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/openapi.json"))
+                .expect("openapi.json should be committed");
+        assert!(
+            committed == ApiDoc::to_json(),
+            "openapi.json is out of date, run `cargo run --bin openapi`"
+        );
+    }
+
+    async fn body(response: Response) -> String {
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        String::from_utf8(body.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn plain_text_errors_become_json() {
+        let response = (
+            StatusCode::TOO_MANY_REQUESTS,
+            [("Retry-After", "30")],
+            "Slow down",
+        )
+            .into_response();
+
+        let response = json_errors(response).await;
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()["retry-after"], "30");
+        assert_eq!(response.headers()["content-type"], "application/json");
+        assert_eq!(
+            body(response).await,
+            r#"{"error":{"status":429,"message":"Slow down"}}"#
+        );
+
+        // Without a message, the status's reason is used.
+        let response = json_errors(StatusCode::METHOD_NOT_ALLOWED.into_response()).await;
+        assert_eq!(
+            body(response).await,
+            r#"{"error":{"status":405,"message":"Method Not Allowed"}}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn leaves_json_and_successful_responses_alone() {
+        let response = json_errors(ApiError::not_found().into_response()).await;
+        assert_eq!(
+            body(response).await,
+            r#"{"error":{"status":404,"message":"Not found"}}"#
+        );
+
+        let response = json_errors("Fine".into_response()).await;
+        assert_eq!(body(response).await, "Fine");
+    }
+}
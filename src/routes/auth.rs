@@ -14,11 +14,12 @@ use serde::Deserialize;
 
 use crate::{
     SharedState,
+    extract::Throttle,
     infrastructure::{
         auth::{OAuthProvider, google::GoogleOAuth},
         jwt::{JwtService, user_claims::UserClaims},
     },
-    util::htmx::HTMX,
+    util::{htmx::HTMX, rate_limit},
 };
 
 pub fn routes() -> Router<SharedState> {
@@ -39,6 +40,7 @@ async fn signin_with_google() -> impl IntoResponse {
 
 async fn google_callback(
     State(state): State<SharedState>,
+    _: Throttle<rate_limit::SignIn>,
     Query(params): Query<AuthRequest>,
     cookies: CookieJar,
 ) -> Result<impl IntoResponse, StatusCode> {
@@ -4,8 +4,8 @@ use crate::domain::user::UpdateRunnerInfo;
 use crate::filters;
 use crate::{
     SharedState,
-    domain::rbac::Role,
-    util::{htmx::HTMX, validation::Validate},
+    domain::rbac::{Action, Role},
+    util::{htmx::HTMX, rbac::Can, validation::Validate},
 };
 use askama::Template;
 use askama_web::WebTemplate;
@@ -108,6 +108,11 @@ async fn update_runner_info(
     OrphanUser(user): OrphanUser,
     Form(form): Form<UpdateRunnerInfo>,
 ) -> impl IntoResponse {
+    // Impersonating admins can look, but not change who the member is.
+    if let Err(status) = user.authorize(Action::Update, user.as_ref()) {
+        return status.into_response();
+    }
+
     let user_id = user.id;
     let towns = state.town_service.find_all().await;
     let error_response = |error_message: &str, form| {
@@ -11,9 +11,12 @@ use serde::Deserialize;
 
 use crate::{
     SharedState,
-    extract::maybe_current_user::MaybeCurrentUser,
+    extract::{Throttle, maybe_current_user::MaybeCurrentUser},
     routes::SharedContext,
-    util::pagination::{PaginatedResponse, Pagination},
+    util::{
+        pagination::{CursorPage, CursorPagination},
+        rate_limit,
+    },
 };
 
 pub fn routes() -> Router<SharedState> {
@@ -24,8 +27,6 @@ pub fn routes() -> Router<SharedState> {
 
 #[derive(Deserialize)]
 struct MemberSearch {
-    page_size: Option<i64>,
-    page: Option<i64>,
     q: Option<String>,
 }
 
@@ -33,7 +34,14 @@ struct MemberSearch {
 #[template(path = "members/members.html")]
 struct MembersTemplate {
     shared: SharedContext,
-    users: PaginatedResponse<UserView>,
+    users: CursorPage<UserView>,
+}
+
+/// The next members, for scrolling further down the list.
+#[derive(Template, WebTemplate)]
+#[template(path = "members/member_rows.html")]
+struct MemberRowsTemplate {
+    users: CursorPage<UserView>,
 }
 
 #[derive(Template, WebTemplate)]
@@ -45,20 +53,25 @@ struct ProfilePageTemplate {
 
 async fn members(
     State(state): State<SharedState>,
+    _: Throttle<rate_limit::Search>,
     MaybeCurrentUser(user): MaybeCurrentUser,
     Query(params): Query<MemberSearch>,
-) -> MembersTemplate {
-    let pagination = Pagination {
-        page: params.page,
-        page_size: params.page_size,
-    };
+    Query(pagination): Query<CursorPagination>,
+) -> impl IntoResponse {
+    let users = state
+        .user_service
+        .search_cursor(&pagination, &params.q.unwrap_or_default())
+        .await;
+
+    if pagination.is_continued() {
+        return MemberRowsTemplate { users }.into_response();
+    }
+
     MembersTemplate {
         shared: SharedContext::new(&state.app_info, user.as_deref().cloned()),
-        users: state
-            .user_service
-            .search(&pagination, &params.q.unwrap_or_default())
-            .await,
+        users,
     }
+    .into_response()
 }
 
 async fn profile_page(
@@ -1,11 +1,19 @@
 use crate::{AppInfo, domain::user::UserView};
 
+pub mod activities;
 pub mod admin;
+pub mod api;
 pub mod auth;
 pub mod homepage;
 pub mod members;
+pub mod moderation;
+pub mod notifications;
+pub mod push;
 pub mod races;
+pub mod search;
 pub mod submit_town;
+pub mod tokens;
+pub mod webhooks;
 
 #[derive(Default)]
 pub struct SharedContext {
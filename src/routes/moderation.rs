@@ -0,0 +1,238 @@
+use askama::Template;
+use askama_web::WebTemplate;
+use axum::{
+    Form, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+
+use crate::{
+    SharedState,
+    domain::{
+        Town,
+        audit::NewAuditLog,
+        race::{
+            MergeRaceForm, NewRace, NewRaceForm, RaceStatus, RaceSubmission, RaceView,
+            RejectRaceForm,
+        },
+        rbac::{Action, Role},
+        user::UserView,
+    },
+    extract::{RoyaltyUser, real_ip::RealIp},
+    filters,
+    routes::SharedContext,
+    util::{htmx::HTMX, rbac::Can, validation::Validate},
+};
+
+pub fn routes() -> Router<SharedState> {
+    Router::new()
+        .route("/moderation/races", get(pending_races_page))
+        .route("/moderation/races/{id}", get(review_race).patch(edit_race))
+        .route("/moderation/races/{id}/approve", post(approve_race))
+        .route("/moderation/races/{id}/reject", post(reject_race))
+        .route("/moderation/races/{id}/merge", post(merge_race))
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "moderation/races.html")]
+struct PendingRacesTemplate {
+    shared: SharedContext,
+    submissions: Vec<RaceSubmission>,
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "moderation/review_race.html")]
+struct ReviewRaceTemplate {
+    submission: RaceSubmission,
+    towns: Vec<Town>,
+    /// Approved races nearby that this one might be a duplicate of.
+    candidates: Vec<RaceView>,
+    error: Option<String>,
+}
+
+impl ReviewRaceTemplate {
+    async fn new(state: &SharedState, submission: RaceSubmission) -> Self {
+        Self {
+            towns: state.town_service.find_all().await,
+            candidates: state
+                .race_service
+                .find_merge_candidates(&submission.race)
+                .await,
+            submission,
+            error: None,
+        }
+    }
+}
+
+async fn pending_races_page(
+    State(state): State<SharedState>,
+    RoyaltyUser(user): RoyaltyUser,
+) -> impl IntoResponse {
+    PendingRacesTemplate {
+        shared: SharedContext::new(&state.app_info, Some(*user)),
+        submissions: state.race_service.find_pending().await,
+    }
+}
+
+async fn review_race(
+    State(state): State<SharedState>,
+    RoyaltyUser(_): RoyaltyUser,
+    Path(race_id): Path<i64>,
+) -> impl IntoResponse {
+    match state.race_service.find_submission(race_id).await {
+        Ok(submission) => ReviewRaceTemplate::new(&state, submission)
+            .await
+            .into_response(),
+        Err(_) => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+/// Finds the race and checks the moderator can take `action` on it.
+async fn authorize(
+    state: &SharedState,
+    user: &UserView,
+    action: Action,
+    race_id: i64,
+) -> Result<RaceView, StatusCode> {
+    let race = state
+        .race_service
+        .find_by_id(race_id)
+        .await
+        .map_err(|_| StatusCode::NOT_FOUND)?;
+    user.authorize(action, &race)?;
+    Ok(race)
+}
+
+/// Shows the race again with what went wrong, e.g. when another moderator
+/// got to it first.
+async fn review_error(state: &SharedState, race_id: i64, error: String) -> Response {
+    match state.race_service.find_submission(race_id).await {
+        Ok(submission) => {
+            let mut template = ReviewRaceTemplate::new(state, submission).await;
+            template.error = Some(error);
+            template.into_response()
+        }
+        Err(_) => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+async fn edit_race(
+    State(state): State<SharedState>,
+    RoyaltyUser(user): RoyaltyUser,
+    RealIp(ip): RealIp,
+    Path(race_id): Path<i64>,
+    Form(form): Form<NewRaceForm>,
+) -> impl IntoResponse {
+    let before = match authorize(&state, &user, Action::Update, race_id).await {
+        Ok(race) => race,
+        Err(status) => return status.into_response(),
+    };
+
+    let result = match form.validate() {
+        Ok(_) => {
+            state
+                .race_service
+                .update_pending(race_id, NewRace::from(form))
+                .await
+        }
+        Err(error) => Err(error),
+    };
+
+    match result {
+        Ok(after) => {
+            let entry = NewAuditLog::new(&user, "race.update", "race", race_id, &ip)
+                .before(&before)
+                .after(&after);
+            state.audit_service.record(entry).await;
+            HTMX::refresh().into_response()
+        }
+        Err(error) => review_error(&state, race_id, error).await,
+    }
+}
+
+async fn approve_race(
+    State(state): State<SharedState>,
+    RoyaltyUser(user): RoyaltyUser,
+    RealIp(ip): RealIp,
+    Path(race_id): Path<i64>,
+) -> impl IntoResponse {
+    let before = match authorize(&state, &user, Action::Approve, race_id).await {
+        Ok(race) => race,
+        Err(status) => return status.into_response(),
+    };
+
+    match state.race_service.approve(race_id, &user).await {
+        Ok(after) => {
+            let entry = NewAuditLog::new(&user, "race.approve", "race", race_id, &ip)
+                .before(&before)
+                .after(&after);
+            state.audit_service.record(entry).await;
+            HTMX::refresh().into_response()
+        }
+        Err(error) => review_error(&state, race_id, error).await,
+    }
+}
+
+async fn reject_race(
+    State(state): State<SharedState>,
+    RoyaltyUser(user): RoyaltyUser,
+    RealIp(ip): RealIp,
+    Path(race_id): Path<i64>,
+    Form(form): Form<RejectRaceForm>,
+) -> impl IntoResponse {
+    let before = match authorize(&state, &user, Action::Approve, race_id).await {
+        Ok(race) => race,
+        Err(status) => return status.into_response(),
+    };
+
+    match state
+        .race_service
+        .reject(race_id, &user, &form.reason)
+        .await
+    {
+        Ok(after) => {
+            let entry = NewAuditLog::new(&user, "race.reject", "race", race_id, &ip)
+                .before(&before)
+                .after(&after);
+            state.audit_service.record(entry).await;
+            HTMX::refresh().into_response()
+        }
+        Err(error) => review_error(&state, race_id, error).await,
+    }
+}
+
+async fn merge_race(
+    State(state): State<SharedState>,
+    RoyaltyUser(user): RoyaltyUser,
+    RealIp(ip): RealIp,
+    Path(race_id): Path<i64>,
+    Form(form): Form<MergeRaceForm>,
+) -> impl IntoResponse {
+    let before = match authorize(&state, &user, Action::Approve, race_id).await {
+        Ok(race) => race,
+        Err(status) => return status.into_response(),
+    };
+
+    // Merging listed races is for admins, see `routes::admin`.
+    if before.status != RaceStatus::Pending {
+        let error = "That race has already been reviewed".to_string();
+        return review_error(&state, race_id, error).await;
+    }
+
+    match state
+        .race_service
+        .merge(race_id, form.into_race_id, &user)
+        .await
+    {
+        Ok(into) => {
+            let entry = NewAuditLog::new(&user, "race.merge", "race", race_id, &ip)
+                .before(&before)
+                .after(&into);
+            state.audit_service.record(entry).await;
+            HTMX::refresh().into_response()
+        }
+        Err(error) => review_error(&state, race_id, error).await,
+    }
+}
@@ -0,0 +1,147 @@
+use askama::Template;
+use askama_web::WebTemplate;
+use axum::{
+    Router,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+};
+use axum_extra::extract::Form;
+
+use crate::{
+    SharedState,
+    domain::{
+        notification::{NotificationPreferences, RADIUS_OPTIONS, UpdateNotificationPreferences},
+        rbac::{AccountResource, Action, Role},
+    },
+    extract::{CurrentUser, MaybeCurrentUser},
+    routes::SharedContext,
+    util::rbac::Can,
+};
+
+pub fn routes() -> Router<SharedState> {
+    Router::new()
+        .route(
+            "/settings/notifications",
+            get(preferences_page).post(update_preferences),
+        )
+        .route(
+            "/notifications/unsubscribe/{token}",
+            get(unsubscribe_page).post(unsubscribe),
+        )
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "settings/notifications.html")]
+struct NotificationPreferencesTemplate {
+    shared: SharedContext,
+    preferences: NotificationPreferences,
+    radius_options: [i64; 4],
+    /// Only set when the server has a VAPID key, so push can be turned on.
+    push_public_key: Option<String>,
+    push_devices: i64,
+    message: Option<String>,
+    error: Option<String>,
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "notifications/unsubscribe.html")]
+struct UnsubscribeTemplate {
+    shared: SharedContext,
+    token: String,
+    unsubscribed: bool,
+}
+
+async fn preferences_page(
+    State(state): State<SharedState>,
+    CurrentUser(user): CurrentUser,
+) -> impl IntoResponse {
+    let push_devices = state.push_service.count_devices(user.id).await;
+    match state.notification_service.find_preferences(user.id).await {
+        Ok(preferences) => NotificationPreferencesTemplate {
+            shared: SharedContext::new(&state.app_info, Some(*user)),
+            preferences,
+            radius_options: RADIUS_OPTIONS,
+            push_public_key: state.push_service.public_key(),
+            push_devices,
+            message: None,
+            error: None,
+        }
+        .into_response(),
+        Err(_) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+async fn update_preferences(
+    State(state): State<SharedState>,
+    CurrentUser(user): CurrentUser,
+    Form(form): Form<UpdateNotificationPreferences>,
+) -> impl IntoResponse {
+    if let Err(status) = user.authorize(Action::Update, &AccountResource::NotificationPreferences) {
+        return status.into_response();
+    }
+
+    let (message, error) = match state
+        .notification_service
+        .update_preferences(user.id, form)
+        .await
+    {
+        Ok(_) => (Some("Your preferences have been saved".to_string()), None),
+        Err(e) => (None, Some(e)),
+    };
+
+    let push_devices = state.push_service.count_devices(user.id).await;
+    match state.notification_service.find_preferences(user.id).await {
+        Ok(preferences) => NotificationPreferencesTemplate {
+            shared: SharedContext::new(&state.app_info, Some(*user)),
+            preferences,
+            radius_options: RADIUS_OPTIONS,
+            push_public_key: state.push_service.public_key(),
+            push_devices,
+            message,
+            error,
+        }
+        .into_response(),
+        Err(_) => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
+    }
+}
+
+/// Links in emails land here, and only unsubscribe once the button is pressed
+/// so link scanners can't unsubscribe anyone by following them.
+async fn unsubscribe_page(
+    State(state): State<SharedState>,
+    MaybeCurrentUser(user): MaybeCurrentUser,
+    Path(token): Path<String>,
+) -> impl IntoResponse {
+    match state
+        .notification_service
+        .find_by_unsubscribe_token(&token)
+        .await
+    {
+        Some(preferences) => UnsubscribeTemplate {
+            shared: SharedContext::new(&state.app_info, user.as_deref().cloned()),
+            token,
+            unsubscribed: !preferences.is_subscribed(),
+        }
+        .into_response(),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+/// Also the target of mail clients' one-click unsubscribe (RFC 8058).
+async fn unsubscribe(
+    State(state): State<SharedState>,
+    MaybeCurrentUser(user): MaybeCurrentUser,
+    Path(token): Path<String>,
+) -> impl IntoResponse {
+    match state.notification_service.unsubscribe(&token).await {
+        Ok(_) => UnsubscribeTemplate {
+            shared: SharedContext::new(&state.app_info, user.as_deref().cloned()),
+            token,
+            unsubscribed: true,
+        }
+        .into_response(),
+        Err(_) => StatusCode::NOT_FOUND.into_response(),
+    }
+}
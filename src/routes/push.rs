@@ -0,0 +1,66 @@
+use axum::{
+    Json, Router,
+    extract::State,
+    http::{HeaderMap, StatusCode, header::USER_AGENT},
+    response::IntoResponse,
+    routing::post,
+};
+
+use crate::{
+    SharedState,
+    domain::{
+        push::{NewPushSubscription, RemovePushSubscription},
+        rbac::{AccountResource, Action},
+    },
+    extract::CurrentUser,
+    util::rbac::Can,
+};
+
+pub fn routes() -> Router<SharedState> {
+    Router::new().route("/push/subscriptions", post(subscribe).delete(unsubscribe))
+}
+
+/// Called by `push.js` with the browser's subscription once it has one.
+async fn subscribe(
+    State(state): State<SharedState>,
+    CurrentUser(user): CurrentUser,
+    headers: HeaderMap,
+    Json(subscription): Json<NewPushSubscription>,
+) -> impl IntoResponse {
+    // Otherwise an admin's device would get the member's notifications.
+    if let Err(status) = user.authorize(Action::Create, &AccountResource::PushSubscription) {
+        return status.into_response();
+    }
+
+    let user_agent = headers
+        .get(USER_AGENT)
+        .and_then(|agent| agent.to_str().ok());
+
+    match state
+        .push_service
+        .subscribe(user.id, subscription, user_agent)
+        .await
+    {
+        Ok(_) => StatusCode::CREATED.into_response(),
+        Err(e) => (StatusCode::BAD_REQUEST, e).into_response(),
+    }
+}
+
+async fn unsubscribe(
+    State(state): State<SharedState>,
+    CurrentUser(user): CurrentUser,
+    Json(subscription): Json<RemovePushSubscription>,
+) -> impl IntoResponse {
+    if let Err(status) = user.authorize(Action::Delete, &AccountResource::PushSubscription) {
+        return status.into_response();
+    }
+
+    match state
+        .push_service
+        .unsubscribe(user.id, &subscription.endpoint)
+        .await
+    {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e).into_response(),
+    }
+}
@@ -1,25 +1,32 @@
 use crate::domain::Town;
-use crate::domain::race::{NewRace, NewRaceForm};
-use crate::extract::CurrentUser;
+use crate::domain::distance::DistanceBucket;
+use crate::domain::race::{NewRace, NewRaceEventForm, NewRaceForm, RaceEventGroup, RaceSubmission};
+use crate::domain::rbac::{AccountResource, Action};
+use crate::extract::{CurrentUser, Throttle};
 use crate::filters;
 use crate::util::htmx::HTMX;
+use crate::util::rate_limit;
+use crate::util::rbac::Can;
+use crate::util::validation::Validate;
 use crate::{
     domain::{
-        race::{RaceSearchParams, RaceView},
+        race::{RaceMember, RaceSearchParams, RaceSeries, RaceSort, RaceView},
         rbac::Role,
+        town::County,
     },
-    util::pagination::PaginatedResponse,
+    util::pagination::{CursorPage, CursorPagination},
 };
 use askama::Template;
 use askama_web::WebTemplate;
-use axum::Form;
-use axum::routing::put;
+use axum::routing::{post, put};
 use axum::{
     Router,
-    extract::{Query, State},
-    response::IntoResponse,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Redirect},
     routing::get,
 };
+use axum_extra::extract::{Form, Query};
 use chrono::{Days, NaiveDateTime, NaiveTime, Utc};
 use chrono_tz::America::New_York;
 
@@ -28,6 +35,9 @@ use crate::{SharedState, extract::MaybeCurrentUser, routes::SharedContext};
 pub fn routes() -> Router<SharedState> {
     Router::new()
         .route("/races", put(add_race))
+        .route("/races/submitted", get(submitted_races_page))
+        .route("/races/{id}", get(race_page))
+        .route("/races/{id}/rsvp", post(rsvp).delete(cancel_rsvp))
         .route("/upcoming-races", get(upcoming_races_page))
         .route("/upcoming-races/add", get(add_race_page))
 }
@@ -36,7 +46,65 @@ pub fn routes() -> Router<SharedState> {
 #[template(path = "races/upcoming.html")]
 pub struct UpcomingRacesTemplate {
     shared: SharedContext,
-    races: PaginatedResponse<RaceView>,
+    params: RaceSearchParams,
+    counties: Vec<County>,
+    distances: [DistanceBucket; 8],
+    sorts: [RaceSort; 4],
+    races: CursorPage<RaceEventGroup>,
+    /// The races the current user is going to.
+    rsvps: Vec<i64>,
+}
+
+/// The next upcoming races, for scrolling further down the list.
+#[derive(Template, WebTemplate)]
+#[template(path = "races/upcoming_rows.html")]
+struct UpcomingRaceRowsTemplate {
+    shared: SharedContext,
+    races: CursorPage<RaceEventGroup>,
+    rsvps: Vec<i64>,
+}
+
+impl UpcomingRaceRowsTemplate {
+    fn is_going(&self, race_id: &i64) -> bool {
+        self.rsvps.contains(race_id)
+    }
+}
+
+impl UpcomingRacesTemplate {
+    fn is_going(&self, race_id: &i64) -> bool {
+        self.rsvps.contains(race_id)
+    }
+
+    fn is_county(&self, county_id: &i64) -> bool {
+        self.params.county_id == Some(*county_id)
+    }
+
+    fn is_distance(&self, distance: &DistanceBucket) -> bool {
+        self.params.distance == Some(*distance)
+    }
+
+    fn is_sort(&self, sort: &RaceSort) -> bool {
+        self.params.sort.unwrap_or_default() == *sort
+    }
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "races/race.html")]
+struct RacePageTemplate {
+    shared: SharedContext,
+    race: RaceView,
+    finishers: Vec<RaceMember>,
+    rsvps: Vec<RaceMember>,
+    series: RaceSeries,
+    /// Whether the current user RSVP'd.
+    going: bool,
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "races/rsvp_button.html")]
+struct RsvpButtonTemplate {
+    race_id: i64,
+    going: bool,
 }
 
 #[derive(Template, WebTemplate)]
@@ -44,28 +112,196 @@ pub struct UpcomingRacesTemplate {
 pub struct AddUpcomingRaceTemplate {
     min_race_date: NaiveDateTime,
     towns: Vec<Town>,
+    /// What the member entered, when it needs fixing.
+    form: Option<NewRaceEventForm>,
+    error: Option<String>,
+    /// The race waiting on a moderator after a successful submission.
+    submitted: Option<RaceView>,
+}
+
+impl AddUpcomingRaceTemplate {
+    async fn new(state: &SharedState) -> Self {
+        Self {
+            towns: state.town_service.find_all().await,
+            min_race_date: Utc::now()
+                .with_timezone(&New_York)
+                .date_naive()
+                .checked_add_days(Days::new(1))
+                .unwrap()
+                .and_time(NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
+            form: None,
+            error: None,
+            submitted: None,
+        }
+    }
+
+    fn is_town(&self, town_id: &i64) -> bool {
+        self.form
+            .as_ref()
+            .is_some_and(|form| form.town_id == *town_id)
+    }
+
+    /// The distances entered so far, or one blank one to start with.
+    fn distances(&self) -> Vec<Option<NewRaceForm>> {
+        match self.form.as_ref().map(NewRaceEventForm::races) {
+            Some(races) if !races.is_empty() => races.into_iter().map(Some).collect(),
+            _ => vec![None],
+        }
+    }
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "races/submitted.html")]
+struct SubmittedRacesTemplate {
+    shared: SharedContext,
+    submissions: Vec<RaceSubmission>,
 }
 
 pub async fn add_race(
     State(state): State<SharedState>,
-    CurrentUser(_): CurrentUser,
-    Form(form): Form<NewRaceForm>,
+    _: Throttle<rate_limit::AddRace>,
+    CurrentUser(user): CurrentUser,
+    Form(form): Form<NewRaceEventForm>,
 ) -> impl IntoResponse {
-    // TODO: validate the input and handle error scenarios
-    match state.race_service.get_or_create(NewRace::from(form)).await {
-        Ok(_) => HTMX::refresh().into_response(),
-        Err(_) => HTMX::refresh().into_response(),
+    let mut template = AddUpcomingRaceTemplate::new(&state).await;
+
+    if let Err(error) = form.validate() {
+        template.error = Some(error);
+        template.form = Some(form);
+        return template.into_response();
+    }
+
+    let races = Vec::<NewRace>::from(form.clone());
+    for race in &races {
+        if let Err(status) = user.authorize(Action::Create, race) {
+            return status.into_response();
+        }
+    }
+
+    match state.race_service.submit(&user, races).await {
+        Ok(races) if races.iter().all(RaceView::is_approved) => HTMX::refresh().into_response(),
+        Ok(races) => {
+            template.submitted = races.into_iter().next();
+            template.into_response()
+        }
+        Err(error) => {
+            template.error = Some(error);
+            template.form = Some(form);
+            template.into_response()
+        }
     }
 }
 
 async fn upcoming_races_page(
     State(state): State<SharedState>,
+    _: Throttle<rate_limit::Search>,
     MaybeCurrentUser(user): MaybeCurrentUser,
     Query(params): Query<RaceSearchParams>,
+    Query(pagination): Query<CursorPagination>,
 ) -> impl IntoResponse {
+    let rsvps = match &user {
+        Some(user) => state.race_service.find_rsvp_race_ids(user.id).await,
+        None => vec![],
+    };
+
+    let races = state
+        .race_service
+        .search_upcoming_events(&params, &pagination, user.as_ref().map(|user| user.id))
+        .await;
+
+    if pagination.is_continued() {
+        return UpcomingRaceRowsTemplate {
+            shared: SharedContext::new(&state.app_info, user.as_deref().cloned()),
+            races,
+            rsvps,
+        }
+        .into_response();
+    }
+
     UpcomingRacesTemplate {
         shared: SharedContext::new(&state.app_info, user.as_deref().cloned()),
-        races: state.race_service.search_for_upcoming(params).await,
+        params,
+        counties: state.town_service.find_all_counties().await,
+        distances: DistanceBucket::ALL,
+        sorts: RaceSort::ALL,
+        races,
+        rsvps,
+    }
+    .into_response()
+}
+
+async fn race_page(
+    State(state): State<SharedState>,
+    MaybeCurrentUser(user): MaybeCurrentUser,
+    Path(race_id): Path<i64>,
+) -> impl IntoResponse {
+    let details = match state.race_service.find_details(race_id).await {
+        Ok(details) => details,
+        Err(_) => return Redirect::to("/404").into_response(),
+    };
+
+    // Races in moderation or removed are only for moderators and whoever added them.
+    if !details.race.is_listed() {
+        let submission = state.race_service.find_submission(race_id).await.ok();
+        let can_see = user.as_ref().is_some_and(|user| {
+            user.is_moderator()
+                || submission.is_some_and(|submission| submission.submitted_by == Some(user.id))
+        });
+        if !can_see {
+            return Redirect::to("/404").into_response();
+        }
+    }
+
+    let going = user
+        .as_ref()
+        .is_some_and(|user| details.rsvps.iter().any(|rsvp| rsvp.user_id == user.id));
+
+    RacePageTemplate {
+        shared: SharedContext::new(&state.app_info, user.as_deref().cloned()),
+        race: details.race,
+        finishers: details.finishers,
+        rsvps: details.rsvps,
+        series: details.series,
+        going,
+    }
+    .into_response()
+}
+
+async fn rsvp(
+    State(state): State<SharedState>,
+    CurrentUser(user): CurrentUser,
+    Path(race_id): Path<i64>,
+) -> impl IntoResponse {
+    if let Err(status) = user.authorize(Action::Create, &AccountResource::Rsvp) {
+        return status.into_response();
+    }
+
+    match state.race_service.rsvp(user.id, race_id).await {
+        Ok(race) => RsvpButtonTemplate {
+            race_id: race.id,
+            going: true,
+        }
+        .into_response(),
+        Err(_) => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+async fn cancel_rsvp(
+    State(state): State<SharedState>,
+    CurrentUser(user): CurrentUser,
+    Path(race_id): Path<i64>,
+) -> impl IntoResponse {
+    if let Err(status) = user.authorize(Action::Delete, &AccountResource::Rsvp) {
+        return status.into_response();
+    }
+
+    match state.race_service.cancel_rsvp(user.id, race_id).await {
+        Ok(race) => RsvpButtonTemplate {
+            race_id: race.id,
+            going: false,
+        }
+        .into_response(),
+        Err(_) => StatusCode::NOT_FOUND.into_response(),
     }
 }
 
@@ -73,13 +309,15 @@ async fn add_race_page(
     State(state): State<SharedState>,
     CurrentUser(_): CurrentUser,
 ) -> impl IntoResponse {
-    AddUpcomingRaceTemplate {
-        towns: state.town_service.find_all().await,
-        min_race_date: Utc::now()
-            .with_timezone(&New_York)
-            .date_naive()
-            .checked_add_days(Days::new(1))
-            .unwrap()
-            .and_time(NaiveTime::from_hms_opt(0, 0, 0).unwrap()),
+    AddUpcomingRaceTemplate::new(&state).await
+}
+
+async fn submitted_races_page(
+    State(state): State<SharedState>,
+    CurrentUser(user): CurrentUser,
+) -> impl IntoResponse {
+    SubmittedRacesTemplate {
+        submissions: state.race_service.find_submitted_by(user.id).await,
+        shared: SharedContext::new(&state.app_info, Some(*user)),
     }
 }
@@ -0,0 +1,47 @@
+use askama::Template;
+use askama_web::WebTemplate;
+use axum::{
+    Router,
+    extract::{Query, State},
+    response::IntoResponse,
+    routing::get,
+};
+
+use crate::{
+    SharedState,
+    domain::{
+        rbac::Role,
+        search::{SearchParams, SearchResults},
+    },
+    extract::{MaybeCurrentUser, Throttle},
+    filters,
+    routes::SharedContext,
+    util::rate_limit,
+};
+
+pub fn routes() -> Router<SharedState> {
+    Router::new().route("/search", get(search))
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "search.html")]
+struct SearchTemplate {
+    shared: SharedContext,
+    q: String,
+    results: SearchResults,
+}
+
+async fn search(
+    State(state): State<SharedState>,
+    _: Throttle<rate_limit::Search>,
+    MaybeCurrentUser(user): MaybeCurrentUser,
+    Query(params): Query<SearchParams>,
+) -> impl IntoResponse {
+    let q = params.q.unwrap_or_default();
+
+    SearchTemplate {
+        shared: SharedContext::new(&state.app_info, user.as_deref().cloned()),
+        results: state.search_service.search(&q).await,
+        q,
+    }
+}
@@ -1,12 +1,15 @@
 use crate::domain::distance::DistanceUnit;
-use crate::domain::race::{RaceView, SubmitTownSearchParams};
+use crate::domain::race::{RaceEventGroup, SubmitTownSearchParams};
+use crate::domain::rbac::Action;
 use crate::filters;
-use crate::util::htmx::HTMX;
 use crate::util::pagination::PaginatedResponse;
+use crate::util::rate_limit;
+use crate::util::rbac::Can;
+use crate::util::validation::Validate;
 use crate::{
     SharedState,
-    domain::{Town, town::SubmitTown},
-    extract::current_user::CurrentUser,
+    domain::{Town, submission::Completion, town::SubmitTown},
+    extract::{Throttle, current_user::CurrentUser},
 };
 
 use askama::Template;
@@ -18,8 +21,9 @@ use axum::{
     response::IntoResponse,
     routing::{get, put},
 };
-use chrono::{NaiveDate, Utc};
+use chrono::{NaiveDateTime, NaiveTime, Utc};
 use chrono_tz::America::New_York;
+use log::error;
 
 pub fn routes() -> Router<SharedState> {
     Router::new()
@@ -31,24 +35,56 @@ pub fn routes() -> Router<SharedState> {
 #[derive(Template, WebTemplate, Default)]
 #[template(path = "submit_town/submit_town.html")]
 struct SubmitTownPage {
-    _form: SubmitTown,
     towns: Vec<Town>,
-    max_race_date: NaiveDate,
+    max_race_date: NaiveDateTime,
+    /// What the member entered, when something went wrong.
+    form: Option<SubmitTown>,
+    error: Option<String>,
+    /// How it went, after a successful submission.
+    completion: Option<Completion>,
+    completed_town: String,
+}
+
+impl SubmitTownPage {
+    async fn new(state: &SharedState) -> Self {
+        Self {
+            towns: state.town_service.find_all().await,
+            max_race_date: Utc::now()
+                .with_timezone(&New_York)
+                .date_naive()
+                .and_time(NaiveTime::from_hms_opt(23, 59, 0).unwrap()),
+            ..Default::default()
+        }
+    }
+
+    fn is_town(&self, town_id: &i64) -> bool {
+        self.form
+            .as_ref()
+            .is_some_and(|form| form.town_id == *town_id)
+    }
+
+    /// Shows the form again, as the member filled it in, with what went wrong.
+    fn error(mut self, form: SubmitTown, error: String) -> Self {
+        self.form = Some(form);
+        self.error = Some(error);
+        self
+    }
 }
 
 #[derive(Template, WebTemplate)]
 #[template(path = "submit_town/race_autocomplete.html")]
 struct RaceAutocompletePage {
-    races: PaginatedResponse<RaceView>,
+    events: PaginatedResponse<RaceEventGroup>,
 }
 
 async fn search_races(
     State(state): State<SharedState>,
+    _: Throttle<rate_limit::Search>,
     CurrentUser(_): CurrentUser,
     Query(params): Query<SubmitTownSearchParams>,
 ) -> impl IntoResponse {
     RaceAutocompletePage {
-        races: state.race_service.submit_town_search(&params).await,
+        events: state.race_service.submit_town_search(&params).await,
     }
 }
 
@@ -56,31 +92,41 @@ async fn submit_town_page(
     State(state): State<SharedState>,
     CurrentUser(_): CurrentUser,
 ) -> SubmitTownPage {
-    SubmitTownPage {
-        towns: state.town_service.find_all().await,
-        max_race_date: Utc::now().with_timezone(&New_York).date_naive(),
-        ..Default::default()
-    }
+    SubmitTownPage::new(&state).await
 }
 
 async fn submit_town(
     State(state): State<SharedState>,
+    _: Throttle<rate_limit::SubmitTown>,
     CurrentUser(user): CurrentUser,
     Form(form): Form<SubmitTown>,
 ) -> impl IntoResponse {
-    // TODO: We'll need to validate the form data.
-    // TODO: We'll need to handle the error scenario and success scenario.
-    //       We should celebrate the user's achievement!
-    match state.town_service.submit_completed_town(*user, form).await {
-        Ok(_) => HTMX::refresh().into_response(),
-        Err(msg) => {
-            println!("{msg}");
-            SubmitTownPage {
-                towns: state.town_service.find_all().await,
-                max_race_date: Utc::now().with_timezone(&New_York).date_naive(),
-                ..Default::default()
-            }
-            .into_response()
+    if let Err(status) = user.authorize(Action::Create, &form) {
+        return status.into_response();
+    }
+
+    let mut page = SubmitTownPage::new(&state).await;
+    if let Err(error) = form.validate() {
+        return page.error(form, error).into_response();
+    }
+
+    match state
+        .town_service
+        .submit_completed_town(*user, form.clone())
+        .await
+    {
+        Ok(completion) => {
+            page.completion = Some(completion);
+            page.completed_town = page
+                .towns
+                .iter()
+                .find(|town| town.id == form.town_id)
+                .map_or("That town".to_string(), |town| town.name.clone());
+            page.into_response()
+        }
+        Err(e) => {
+            error!("{e}");
+            page.error(form, e.to_string()).into_response()
         }
     }
 }
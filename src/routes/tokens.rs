@@ -0,0 +1,106 @@
+use askama::Template;
+use askama_web::WebTemplate;
+use axum::{
+    Router,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{delete, get},
+};
+use axum_extra::extract::Form;
+
+use crate::{
+    SharedState,
+    domain::{
+        api_token::{ApiToken, NewApiTokenForm, TokenScope},
+        audit::NewAuditLog,
+        rbac::{AccountResource, Action, Role},
+        user::UserView,
+    },
+    extract::{CurrentUser, real_ip::RealIp},
+    filters,
+    routes::SharedContext,
+    util::{htmx::HTMX, rbac::Can},
+};
+
+pub fn routes() -> Router<SharedState> {
+    Router::new()
+        .route("/settings/tokens", get(tokens_page).post(create_token))
+        .route("/settings/tokens/{id}", delete(revoke_token))
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "settings/tokens.html")]
+struct TokensTemplate {
+    shared: SharedContext,
+    tokens: Vec<ApiToken>,
+    scopes: Vec<TokenScope>,
+    new_secret: Option<String>,
+    error: Option<String>,
+}
+
+impl TokensTemplate {
+    async fn new(state: &SharedState, user: UserView) -> Self {
+        Self {
+            tokens: state.api_token_service.find_for_user(user.id).await,
+            scopes: TokenScope::for_session(&user),
+            shared: SharedContext::new(&state.app_info, Some(user)),
+            new_secret: None,
+            error: None,
+        }
+    }
+}
+
+async fn tokens_page(
+    State(state): State<SharedState>,
+    CurrentUser(user): CurrentUser,
+) -> impl IntoResponse {
+    TokensTemplate::new(&state, *user).await
+}
+
+async fn create_token(
+    State(state): State<SharedState>,
+    CurrentUser(user): CurrentUser,
+    RealIp(ip): RealIp,
+    Form(form): Form<NewApiTokenForm>,
+) -> impl IntoResponse {
+    // Nobody else (including an impersonating admin) gets to mint a token for you.
+    if let Err(status) = user.authorize(Action::Create, &AccountResource::ApiToken) {
+        return status.into_response();
+    }
+
+    let result = state.api_token_service.create(&user, form).await;
+    let mut template = TokensTemplate::new(&state, *user.clone()).await;
+
+    match result {
+        Ok((token, secret)) => {
+            let entry = NewAuditLog::new(&user, "api_token.create", "api_token", token.id, &ip)
+                .after(&token);
+            state.audit_service.record(entry).await;
+            template.new_secret = Some(secret);
+        }
+        Err(e) => template.error = Some(e),
+    }
+
+    template.into_response()
+}
+
+async fn revoke_token(
+    State(state): State<SharedState>,
+    CurrentUser(user): CurrentUser,
+    RealIp(ip): RealIp,
+    Path(token_id): Path<i64>,
+) -> impl IntoResponse {
+    if let Err(status) = user.authorize(Action::Delete, &AccountResource::ApiToken) {
+        return status.into_response();
+    }
+
+    match state.api_token_service.revoke(user.id, token_id).await {
+        Ok(_) => {
+            let entry = NewAuditLog::new(&user, "api_token.revoke", "api_token", token_id, &ip);
+            state.audit_service.record(entry).await;
+            HTMX::refresh().into_response()
+        }
+        Err(_) => StatusCode::NOT_FOUND.into_response(),
+    }
+}
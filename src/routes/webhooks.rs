@@ -0,0 +1,159 @@
+use askama::Template;
+use askama_web::WebTemplate;
+use axum::{
+    Router,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::{get, post},
+};
+use axum_extra::extract::Form;
+
+use crate::{
+    SharedState,
+    domain::{
+        audit::NewAuditLog,
+        rbac::{AccountResource, Action, Role},
+        user::UserView,
+        webhook::{
+            DeliveryStatus, MAX_DELIVERY_ATTEMPTS, NewWebhookForm, Webhook, WebhookDelivery,
+            WebhookEvent, WebhookFormat,
+        },
+    },
+    extract::{CurrentUser, real_ip::RealIp},
+    filters,
+    routes::SharedContext,
+    util::{htmx::HTMX, rbac::Can},
+};
+
+pub fn routes() -> Router<SharedState> {
+    Router::new()
+        .route(
+            "/settings/webhooks",
+            get(webhooks_page).post(create_webhook),
+        )
+        .route(
+            "/settings/webhooks/{id}",
+            get(webhook_page).delete(delete_webhook),
+        )
+        .route("/settings/webhooks/{id}/test", post(test_webhook))
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "settings/webhooks.html")]
+struct WebhooksTemplate {
+    shared: SharedContext,
+    webhooks: Vec<Webhook>,
+    events: [WebhookEvent; 4],
+    formats: [WebhookFormat; 3],
+    error: Option<String>,
+}
+
+impl WebhooksTemplate {
+    async fn new(state: &SharedState, user: UserView) -> Self {
+        Self {
+            webhooks: state.webhook_service.find_for_user(user.id).await,
+            shared: SharedContext::new(&state.app_info, Some(user)),
+            events: WebhookEvent::ALL,
+            formats: [
+                WebhookFormat::Json,
+                WebhookFormat::Slack,
+                WebhookFormat::Discord,
+            ],
+            error: None,
+        }
+    }
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "settings/webhook.html")]
+struct WebhookTemplate {
+    shared: SharedContext,
+    webhook: Webhook,
+    deliveries: Vec<WebhookDelivery>,
+    max_attempts: i64,
+}
+
+async fn webhooks_page(
+    State(state): State<SharedState>,
+    CurrentUser(user): CurrentUser,
+) -> impl IntoResponse {
+    WebhooksTemplate::new(&state, *user).await
+}
+
+async fn create_webhook(
+    State(state): State<SharedState>,
+    CurrentUser(user): CurrentUser,
+    RealIp(ip): RealIp,
+    Form(form): Form<NewWebhookForm>,
+) -> impl IntoResponse {
+    if let Err(status) = user.authorize(Action::Create, &AccountResource::Webhook) {
+        return status.into_response();
+    }
+
+    match state.webhook_service.create(&user, form).await {
+        Ok(webhook) => {
+            let entry = NewAuditLog::new(&user, "webhook.create", "webhook", webhook.id, &ip)
+                .after(&webhook);
+            state.audit_service.record(entry).await;
+            HTMX::redirect(&format!("/settings/webhooks/{}", webhook.id)).into_response()
+        }
+        Err(e) => {
+            let mut template = WebhooksTemplate::new(&state, *user).await;
+            template.error = Some(e);
+            template.into_response()
+        }
+    }
+}
+
+async fn webhook_page(
+    State(state): State<SharedState>,
+    CurrentUser(user): CurrentUser,
+    Path(webhook_id): Path<i64>,
+) -> impl IntoResponse {
+    match state
+        .webhook_service
+        .find_with_deliveries(user.id, webhook_id)
+        .await
+    {
+        Ok((webhook, deliveries)) => WebhookTemplate {
+            shared: SharedContext::new(&state.app_info, Some(*user)),
+            webhook,
+            deliveries,
+            max_attempts: MAX_DELIVERY_ATTEMPTS,
+        }
+        .into_response(),
+        Err(_) => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+async fn delete_webhook(
+    State(state): State<SharedState>,
+    CurrentUser(user): CurrentUser,
+    RealIp(ip): RealIp,
+    Path(webhook_id): Path<i64>,
+) -> impl IntoResponse {
+    if let Err(status) = user.authorize(Action::Delete, &AccountResource::Webhook) {
+        return status.into_response();
+    }
+
+    match state.webhook_service.delete(user.id, webhook_id).await {
+        Ok(_) => {
+            let entry = NewAuditLog::new(&user, "webhook.delete", "webhook", webhook_id, &ip);
+            state.audit_service.record(entry).await;
+            HTMX::redirect("/settings/webhooks").into_response()
+        }
+        Err(_) => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+async fn test_webhook(
+    State(state): State<SharedState>,
+    CurrentUser(user): CurrentUser,
+    Path(webhook_id): Path<i64>,
+) -> impl IntoResponse {
+    match state.webhook_service.send_test(user.id, webhook_id).await {
+        Ok(_) => HTMX::refresh().into_response(),
+        Err(_) => StatusCode::NOT_FOUND.into_response(),
+    }
+}
@@ -0,0 +1,45 @@
+use std::borrow::Cow;
+
+/// Builds a CSV document one row at a time, quoting fields as needed.
+///
+/// Example:
+/// ```ignore
+/// let mut csv = Csv::new(&["id", "name"]);
+/// csv.row(&[&1.to_string(), "Jane, Doe"]);
+/// csv.to_string(); // "id,name\r\n1,\"Jane, Doe\"\r\n"
+/// ```
+pub struct Csv(String);
+
+impl Csv {
+    pub fn new(headers: &[&str]) -> Self {
+        let mut csv = Self(String::new());
+        csv.row(headers);
+        csv
+    }
+
+    pub fn row(&mut self, fields: &[&str]) {
+        let row: Vec<Cow<str>> = fields.iter().map(|f| Self::escape(f)).collect();
+        self.0.push_str(&row.join(","));
+        self.0.push_str("\r\n");
+    }
+
+    fn escape(field: &str) -> Cow<'_, str> {
+        // Guard against spreadsheet formula injection, too.
+        let field = match field.starts_with(['=', '+', '-', '@']) {
+            true => Cow::Owned(format!("'{field}")),
+            false => Cow::Borrowed(field),
+        };
+
+        if field.contains([',', '"', '\n', '\r']) {
+            Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+        } else {
+            field
+        }
+    }
+}
+
+impl std::fmt::Display for Csv {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
@@ -0,0 +1,399 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use sqlx::{
+    Database, Encode, Sqlite, Type, encode::IsNull, error::BoxDynError, sqlite::SqliteTypeInfo,
+};
+
+/// A value for a `?` in a [`Filter`], bound with its own type so ids, dates
+/// and flags don't have to be turned into strings first.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Bind {
+    Int(i64),
+    Real(f64),
+    Bool(bool),
+    Text(String),
+    Date(NaiveDate),
+    DateTime(NaiveDateTime),
+}
+
+impl From<i64> for Bind {
+    fn from(value: i64) -> Self {
+        Bind::Int(value)
+    }
+}
+
+impl From<f64> for Bind {
+    fn from(value: f64) -> Self {
+        Bind::Real(value)
+    }
+}
+
+impl From<bool> for Bind {
+    fn from(value: bool) -> Self {
+        Bind::Bool(value)
+    }
+}
+
+impl From<String> for Bind {
+    fn from(value: String) -> Self {
+        Bind::Text(value)
+    }
+}
+
+impl From<&str> for Bind {
+    fn from(value: &str) -> Self {
+        Bind::Text(value.to_string())
+    }
+}
+
+impl From<NaiveDate> for Bind {
+    fn from(value: NaiveDate) -> Self {
+        Bind::Date(value)
+    }
+}
+
+impl From<NaiveDateTime> for Bind {
+    fn from(value: NaiveDateTime) -> Self {
+        Bind::DateTime(value)
+    }
+}
+
+impl Type<Sqlite> for Bind {
+    fn type_info() -> SqliteTypeInfo {
+        <String as Type<Sqlite>>::type_info()
+    }
+
+    fn compatible(_: &SqliteTypeInfo) -> bool {
+        true
+    }
+}
+
+impl<'q> Encode<'q, Sqlite> for Bind {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <Sqlite as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        match self {
+            Bind::Int(value) => Encode::<Sqlite>::encode_by_ref(value, buf),
+            Bind::Real(value) => Encode::<Sqlite>::encode_by_ref(value, buf),
+            Bind::Bool(value) => Encode::<Sqlite>::encode_by_ref(value, buf),
+            Bind::Text(value) => Encode::<Sqlite>::encode_by_ref(value, buf),
+            Bind::Date(value) => Encode::<Sqlite>::encode_by_ref(value, buf),
+            Bind::DateTime(value) => Encode::<Sqlite>::encode_by_ref(value, buf),
+        }
+    }
+
+    fn produces(&self) -> Option<SqliteTypeInfo> {
+        Some(match self {
+            Bind::Int(_) => <i64 as Type<Sqlite>>::type_info(),
+            Bind::Real(_) => <f64 as Type<Sqlite>>::type_info(),
+            Bind::Bool(_) => <bool as Type<Sqlite>>::type_info(),
+            Bind::Text(_) => <String as Type<Sqlite>>::type_info(),
+            Bind::Date(_) => <NaiveDate as Type<Sqlite>>::type_info(),
+            Bind::DateTime(_) => <NaiveDateTime as Type<Sqlite>>::type_info(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Asc,
+    Desc,
+}
+
+impl Direction {
+    fn reversed(self) -> Self {
+        match self {
+            Direction::Asc => Direction::Desc,
+            Direction::Desc => Direction::Asc,
+        }
+    }
+}
+
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Direction::Asc => write!(f, "ASC"),
+            Direction::Desc => write!(f, "DESC"),
+        }
+    }
+}
+
+/// The `WHERE` and `ORDER BY` for a [`Paginatable`](super::pagination::Paginatable)
+/// query. Conditions and columns are `'static` so only SQL written in the
+/// code ends up in the query, and everything a member typed goes through a
+/// [`Bind`].
+///
+/// Example:
+/// ```ignore
+/// let filter = Filter::new()
+///     .and("deleted_at IS NULL")
+///     .and_bind_opt("town_id = ?", params.town_id)
+///     .and_binds("miles BETWEEN ? AND ?", [shortest, longest])
+///     .desc("start_at")
+///     .desc("id");
+/// let races = RaceView::paginate_filter(&db, &paging, &filter).await;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Filter {
+    clauses: Vec<&'static str>,
+    binds: Vec<Bind>,
+    seek: Option<(String, Vec<Bind>)>,
+    order_by: Vec<(&'static str, Direction)>,
+}
+
+impl Filter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a condition that doesn't need any values, e.g. `"deleted_at IS NULL"`.
+    pub fn and(mut self, clause: &'static str) -> Self {
+        self.clauses.push(clause);
+        self
+    }
+
+    /// Adds the condition only when `condition` is true.
+    pub fn and_if(self, condition: bool, clause: &'static str) -> Self {
+        if condition { self.and(clause) } else { self }
+    }
+
+    /// Adds a condition with one `?`.
+    pub fn and_bind(self, clause: &'static str, value: impl Into<Bind>) -> Self {
+        self.and_binds(clause, [value.into()])
+    }
+
+    /// Adds a condition with one `?`, only when there's a value for it.
+    pub fn and_bind_opt(self, clause: &'static str, value: Option<impl Into<Bind>>) -> Self {
+        match value {
+            Some(value) => self.and_bind(clause, value),
+            None => self,
+        }
+    }
+
+    /// Adds a condition with a value for each of its `?`s, in order.
+    pub fn and_binds(
+        mut self,
+        clause: &'static str,
+        values: impl IntoIterator<Item = impl Into<Bind>>,
+    ) -> Self {
+        let values: Vec<Bind> = values.into_iter().map(Into::into).collect();
+        debug_assert_eq!(
+            clause.matches('?').count(),
+            values.len(),
+            "wrong number of values for {clause}"
+        );
+
+        self.clauses.push(clause);
+        self.binds.extend(values);
+        self
+    }
+
+    pub fn asc(self, column: &'static str) -> Self {
+        self.order_by(&[(column, Direction::Asc)])
+    }
+
+    pub fn desc(self, column: &'static str) -> Self {
+        self.order_by(&[(column, Direction::Desc)])
+    }
+
+    /// Sorts by each column in turn, after any already added.
+    pub fn order_by(mut self, columns: &[(&'static str, Direction)]) -> Self {
+        self.order_by.extend_from_slice(columns);
+        self
+    }
+
+    /// Only rows that sort after the one with these values for the
+    /// `ORDER BY` columns, for the next page of a cursor. The columns can't
+    /// be `NULL` and the last one should be unique, like `id`, so no row is
+    /// skipped or repeated.
+    pub fn after(self, values: Vec<Bind>) -> Self {
+        self.seek(values, false)
+    }
+
+    /// Only rows that sort before the one with these values, for the
+    /// previous page of a cursor. The order is flipped so the closest rows
+    /// come first and the `LIMIT` keeps them, so reverse the rows that come
+    /// back.
+    pub fn before(mut self, values: Vec<Bind>) -> Self {
+        self = self.seek(values, true);
+        for (_, direction) in self.order_by.iter_mut() {
+            *direction = direction.reversed();
+        }
+        self
+    }
+
+    /// `(a > ?) OR (a = ? AND b > ?) ...`, with `<` for descending columns.
+    fn seek(mut self, values: Vec<Bind>, backwards: bool) -> Self {
+        debug_assert_eq!(
+            self.order_by.len(),
+            values.len(),
+            "a cursor needs a value for each ORDER BY column"
+        );
+
+        let mut alternatives = vec![];
+        let mut binds = vec![];
+        for (i, (column, direction)) in self.order_by.iter().enumerate() {
+            let mut terms: Vec<String> = self.order_by[..i]
+                .iter()
+                .map(|(column, _)| format!("{column} = ?"))
+                .collect();
+            let forwards = (*direction == Direction::Asc) != backwards;
+            terms.push(format!("{column} {} ?", if forwards { ">" } else { "<" }));
+            alternatives.push(format!("({})", terms.join(" AND ")));
+            binds.extend_from_slice(&values[..=i]);
+        }
+
+        self.seek = Some((format!("({})", alternatives.join(" OR ")), binds));
+        self
+    }
+
+    /// The `WHERE` clause, with a leading space, or nothing without conditions.
+    pub fn where_sql(&self) -> String {
+        let clauses: Vec<&str> = self
+            .clauses
+            .iter()
+            .copied()
+            .chain(self.seek.as_ref().map(|(clause, _)| clause.as_str()))
+            .collect();
+
+        if clauses.is_empty() {
+            String::new()
+        } else {
+            format!(" WHERE {}", clauses.join(" AND "))
+        }
+    }
+
+    /// The `WHERE` clause without the cursor, for counting every row that
+    /// matches.
+    pub fn count_where_sql(&self) -> String {
+        if self.clauses.is_empty() {
+            String::new()
+        } else {
+            format!(" WHERE {}", self.clauses.join(" AND "))
+        }
+    }
+
+    /// The `ORDER BY` clause, with a leading space, or nothing without any sort.
+    pub fn order_sql(&self) -> String {
+        if self.order_by.is_empty() {
+            return String::new();
+        }
+
+        let columns: Vec<String> = self
+            .order_by
+            .iter()
+            .map(|(column, direction)| format!("{column} {direction}"))
+            .collect();
+        format!(" ORDER BY {}", columns.join(", "))
+    }
+
+    /// The columns in the `ORDER BY`, for reading a row's cursor values.
+    pub fn order_columns(&self) -> Vec<&'static str> {
+        self.order_by.iter().map(|(column, _)| *column).collect()
+    }
+
+    /// The values for [`where_sql`](Self::where_sql), in order.
+    pub fn binds(&self) -> impl Iterator<Item = &Bind> {
+        self.binds
+            .iter()
+            .chain(self.seek.iter().flat_map(|(_, binds)| binds))
+    }
+
+    /// The values for [`count_where_sql`](Self::count_where_sql), in order.
+    pub fn count_binds(&self) -> impl Iterator<Item = &Bind> {
+        self.binds.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn binds<'a>(binds: impl Iterator<Item = &'a Bind>) -> Vec<Bind> {
+        binds.cloned().collect()
+    }
+
+    #[test]
+    fn keeps_clauses_and_binds_in_the_order_they_were_added() {
+        let filter = Filter::new()
+            .and("deleted_at IS NULL")
+            .and_bind_opt("town_id = ?", Some(7))
+            .and_bind_opt("county_id = ?", None::<i64>)
+            .and_if(false, "status = 'pending'")
+            .and_binds("miles BETWEEN ? AND ?", [3.1, 6.2])
+            .and_bind("LOWER(name) LIKE ?", "%oak%");
+
+        assert_eq!(
+            filter.where_sql(),
+            " WHERE deleted_at IS NULL AND town_id = ? AND miles BETWEEN ? AND ? AND LOWER(name) LIKE ?"
+        );
+        assert_eq!(
+            binds(filter.binds()),
+            [
+                Bind::Int(7),
+                Bind::Real(3.1),
+                Bind::Real(6.2),
+                Bind::Text("%oak%".to_string())
+            ]
+        );
+        assert_eq!(filter.count_where_sql(), filter.where_sql());
+        assert_eq!(binds(filter.count_binds()), binds(filter.binds()));
+    }
+
+    #[test]
+    fn leaves_out_empty_clauses() {
+        let filter = Filter::new();
+        assert_eq!(filter.where_sql(), "");
+        assert_eq!(filter.order_sql(), "");
+        assert_eq!(filter.binds().count(), 0);
+    }
+
+    #[test]
+    fn sorts_by_each_column_in_turn() {
+        let filter = Filter::new().desc("start_at").asc("id");
+        assert_eq!(filter.order_sql(), " ORDER BY start_at DESC, id ASC");
+        assert_eq!(filter.order_columns(), ["start_at", "id"]);
+    }
+
+    #[test]
+    fn seeks_after_a_row_with_mixed_directions() {
+        let filter = Filter::new()
+            .and_bind("town_id = ?", 7)
+            .desc("start_at")
+            .asc("id")
+            .after(vec![Bind::Text("2026-05-01".to_string()), Bind::Int(12)]);
+
+        assert_eq!(
+            filter.where_sql(),
+            " WHERE town_id = ? AND ((start_at < ?) OR (start_at = ? AND id > ?))"
+        );
+        assert_eq!(filter.order_sql(), " ORDER BY start_at DESC, id ASC");
+        assert_eq!(
+            binds(filter.binds()),
+            [
+                Bind::Int(7),
+                Bind::Text("2026-05-01".to_string()),
+                Bind::Text("2026-05-01".to_string()),
+                Bind::Int(12)
+            ]
+        );
+
+        // The total counts every row, not just the ones past the cursor.
+        assert_eq!(filter.count_where_sql(), " WHERE town_id = ?");
+        assert_eq!(binds(filter.count_binds()), [Bind::Int(7)]);
+    }
+
+    #[test]
+    fn seeks_before_a_row_with_the_order_flipped() {
+        let filter = Filter::new()
+            .desc("start_at")
+            .asc("id")
+            .before(vec![Bind::Text("2026-05-01".to_string()), Bind::Int(12)]);
+
+        assert_eq!(
+            filter.where_sql(),
+            " WHERE ((start_at > ?) OR (start_at = ? AND id < ?))"
+        );
+        assert_eq!(filter.order_sql(), " ORDER BY start_at ASC, id DESC");
+    }
+}
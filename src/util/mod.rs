@@ -1,8 +1,12 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Deserializer};
 
+pub mod api;
+pub mod csv;
+pub mod filter;
 pub mod htmx;
 pub mod pagination;
+pub mod rate_limit;
 pub mod rbac;
 pub mod validation;
 
@@ -31,3 +35,15 @@ where
     let s = String::deserialize(deserializer)?;
     NaiveDateTime::parse_from_str(&s, "%Y-%m-%dT%H:%M").map_err(serde::de::Error::custom)
 }
+
+/// Like `parse_no_seconds`, for a field that's repeated in a form.
+pub fn parse_no_seconds_list<'de, D>(deserializer: D) -> Result<Vec<NaiveDateTime>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Vec::<String>::deserialize(deserializer)?
+        .iter()
+        .map(|s| NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M"))
+        .collect::<Result<_, _>>()
+        .map_err(serde::de::Error::custom)
+}
@@ -1,8 +1,25 @@
 use std::{cmp::min, sync::Arc};
 
 use async_trait::async_trait;
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
 use serde::{Deserialize, Serialize};
-use sqlx::{FromRow, SqlitePool};
+use serde_json::Value;
+use sqlx::{FromRow, Row, SqlitePool};
+use utoipa::ToSchema;
+
+use super::filter::{Bind, Filter};
+
+/// The most items a page can have, however many are asked for.
+pub const MAX_PAGE_SIZE: i64 = 100;
+
+const DEFAULT_PAGE_SIZE: i64 = 15;
+
+/// The page size asked for, kept between 1 and [`MAX_PAGE_SIZE`].
+fn page_size(page_size: Option<i64>) -> i64 {
+    page_size
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE)
+}
 
 #[derive(Deserialize)]
 pub struct Pagination {
@@ -13,12 +30,23 @@ impl Default for Pagination {
     fn default() -> Self {
         Self {
             page: Some(1),
-            page_size: Some(15),
+            page_size: Some(DEFAULT_PAGE_SIZE),
         }
     }
 }
+impl Pagination {
+    /// The page asked for, from 1. Kept low enough that its offset can't
+    /// overflow.
+    pub fn page(&self) -> i64 {
+        self.page.unwrap_or(1).clamp(1, i64::MAX / MAX_PAGE_SIZE)
+    }
+
+    pub fn page_size(&self) -> i64 {
+        page_size(self.page_size)
+    }
+}
 
-#[derive(Serialize)]
+#[derive(Serialize, ToSchema)]
 pub struct PaginatedResponse<T> {
     pub items: Vec<T>,
     pub total: i64,
@@ -48,6 +76,20 @@ impl<T> PaginatedResponse<T> {
             has_prev,
         }
     }
+
+    /// Converts the items while keeping the paging details.
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> PaginatedResponse<U> {
+        PaginatedResponse {
+            items: self.items.into_iter().map(f).collect(),
+            total: self.total,
+            start: self.start,
+            end: self.end,
+            page: self.page,
+            page_size: self.page_size,
+            has_next: self.has_next,
+            has_prev: self.has_prev,
+        }
+    }
 }
 impl<T> Default for PaginatedResponse<T> {
     fn default() -> Self {
@@ -64,6 +106,104 @@ impl<T> Default for PaginatedResponse<T> {
     }
 }
 
+/// Where to start a page of a list paged with cursors instead of page
+/// numbers. `after` and `before` are the `next` and `prev` of a [`CursorPage`].
+#[derive(Deserialize, Default)]
+pub struct CursorPagination {
+    pub after: Option<String>,
+    pub before: Option<String>,
+    pub page_size: Option<i64>,
+}
+
+impl CursorPagination {
+    /// Whether this is a later page, rather than the start of the list.
+    pub fn is_continued(&self) -> bool {
+        self.after.is_some() || self.before.is_some()
+    }
+
+    pub fn page_size(&self) -> i64 {
+        page_size(self.page_size)
+    }
+}
+
+/// A page of a list paged with cursors, which stays quick however far in
+/// it is since there's no `OFFSET` to skip through. `total`, when asked for,
+/// is counted with the first page and carried along in the cursors, so it
+/// can be a little out of date after scrolling a while.
+#[derive(Serialize, ToSchema)]
+pub struct CursorPage<T> {
+    pub items: Vec<T>,
+    /// The cursor for the page after this one, if there is one.
+    pub next: Option<String>,
+    /// The cursor for the page before this one, if there is one.
+    pub prev: Option<String>,
+    pub total: Option<i64>,
+    pub page_size: i64,
+}
+impl<T> CursorPage<T> {
+    /// Converts the items while keeping the cursors.
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> CursorPage<U> {
+        CursorPage {
+            items: self.items.into_iter().map(f).collect(),
+            next: self.next,
+            prev: self.prev,
+            total: self.total,
+            page_size: self.page_size,
+        }
+    }
+}
+impl<T> Default for CursorPage<T> {
+    fn default() -> Self {
+        Self {
+            items: vec![],
+            next: None,
+            prev: None,
+            total: None,
+            page_size: 10,
+        }
+    }
+}
+
+/// What's in a cursor: the row's values for the `ORDER BY` columns, as
+/// SQLite gave them back, and the total from the first page. It's base64 so
+/// it's opaque and safe in a URL.
+#[derive(Serialize, Deserialize)]
+struct Cursor {
+    values: Vec<Value>,
+    total: Option<i64>,
+}
+
+impl Cursor {
+    fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(serde_json::to_vec(self).unwrap_or_default())
+    }
+
+    fn decode(cursor: &str) -> Option<Self> {
+        let json = URL_SAFE_NO_PAD.decode(cursor).ok()?;
+        serde_json::from_slice(&json).ok()
+    }
+
+    /// The values to seek past, if there's one for each of the `columns`.
+    fn binds(&self, columns: usize) -> Option<Vec<Bind>> {
+        if self.values.len() != columns {
+            return None;
+        }
+
+        self.values
+            .iter()
+            .map(|value| match value {
+                Value::Bool(value) => Some(Bind::Bool(*value)),
+                Value::Number(value) => value
+                    .as_i64()
+                    .map(Bind::Int)
+                    .or(value.as_f64().map(Bind::Real)),
+                Value::String(value) => Some(Bind::Text(value.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
 #[async_trait]
 pub trait Paginatable:
     Sized + Send + Unpin + for<'r> FromRow<'r, sqlx::sqlite::SqliteRow> + Serialize
@@ -79,62 +219,57 @@ pub trait Paginatable:
     }
 
     /// Helps you paginate all things in the table without any kind of filtering.
-    /// If you would like to include filtering, use the `paginate` function and
-    /// include a `"WHERE"` clause.
+    /// If you would like to include filtering, use the `paginate_filter`
+    /// function with a [`Filter`].
     ///
     /// Example:
-    /// ```
+    /// ```ignore
     /// let data = User::paginate(&db, &paging).await;
     /// ```
     async fn paginate(
         pool: &Arc<SqlitePool>,
         pagination: &Pagination,
     ) -> Result<PaginatedResponse<Self>, sqlx::Error> {
-        Paginatable::paginate_filter(pool, pagination, None, vec![]).await
+        Paginatable::paginate_filter(pool, pagination, &Filter::new()).await
     }
 
-    /// Helps you paginate anything in the table, with a filter applied.
-    /// The filter is just a `"WHERE"` clause without the `"WHERE"` keyword.
+    /// Helps you paginate anything in the table, with a filter and sort applied.
     /// See the example below for a sample usage.
     ///
     /// Example:
-    /// ```
-    /// let data = User::paginate_filter(&db, &paging, "first_name LIKE ?", vec!["%am%"]).await;
+    /// ```ignore
+    /// let filter = Filter::new()
+    ///     .and_bind("LOWER(first_name) LIKE ?", "%am%")
+    ///     .asc("full_name");
+    /// let data = User::paginate_filter(&db, &paging, &filter).await;
     /// ```
     async fn paginate_filter(
         pool: &Arc<SqlitePool>,
         pagination: &Pagination,
-        where_clause: Option<&str>,
-        args: Vec<&str>,
+        filter: &Filter,
     ) -> Result<PaginatedResponse<Self>, sqlx::Error> {
-        let page = pagination.page.unwrap_or(1);
-        let page_size = pagination
-            .page_size
-            .unwrap_or(Pagination::default().page_size.unwrap());
+        let page = pagination.page();
+        let page_size = pagination.page_size();
         let offset = (page - 1) * page_size;
 
         // count total
-        let count_sql = if let Some(wc) = where_clause {
-            format!("{} WHERE {}", Self::count_query(), wc)
-        } else {
-            Self::count_query().to_string()
-        };
-
+        let count_sql = format!("{}{}", Self::count_query(), filter.count_where_sql());
         let mut total_query = sqlx::query_as(&count_sql);
-        for arg in args.iter() {
-            total_query = total_query.bind(arg);
+        for bind in filter.count_binds() {
+            total_query = total_query.bind(bind);
         }
         let total: (i64,) = total_query.fetch_one(pool.as_ref()).await?;
 
         // fetch rows
-        let page_sql = if let Some(wc) = where_clause {
-            format!("{} WHERE {} LIMIT ? OFFSET ?", Self::page_query(), wc)
-        } else {
-            format!("{} LIMIT ? OFFSET ?", Self::page_query())
-        };
+        let page_sql = format!(
+            "{}{}{} LIMIT ? OFFSET ?",
+            Self::page_query(),
+            filter.where_sql(),
+            filter.order_sql()
+        );
         let mut rows_query = sqlx::query_as::<_, Self>(&page_sql);
-        for arg in args.iter() {
-            rows_query = rows_query.bind(arg);
+        for bind in filter.binds() {
+            rows_query = rows_query.bind(bind);
         }
         let rows = rows_query
             .bind(page_size)
@@ -144,4 +279,244 @@ pub trait Paginatable:
 
         Ok(PaginatedResponse::new(rows, total.0, page, page_size))
     }
+
+    /// Helps you page through anything in the table with cursors, for lists
+    /// that get long enough for `OFFSET` to be slow. The filter's sort has to
+    /// end with a unique column, like `id`, and a cursor that doesn't fit it
+    /// starts back at the beginning. `count` is whether to include the total.
+    ///
+    /// Example:
+    /// ```ignore
+    /// let filter = Filter::new().asc("full_name").asc("id");
+    /// let data = User::paginate_cursor(&db, &cursor, filter, true).await;
+    /// ```
+    async fn paginate_cursor(
+        pool: &Arc<SqlitePool>,
+        pagination: &CursorPagination,
+        filter: Filter,
+        count: bool,
+    ) -> Result<CursorPage<Self>, sqlx::Error> {
+        let page_size = pagination.page_size();
+        let columns = filter.order_columns();
+
+        let after = pagination.after.as_deref().and_then(Cursor::decode);
+        let before = pagination.before.as_deref().and_then(Cursor::decode);
+        let (filter, cursor, backwards) = match (after, before) {
+            (Some(cursor), _) => match cursor.binds(columns.len()) {
+                Some(values) => (filter.after(values), Some(cursor), false),
+                None => (filter, None, false),
+            },
+            (None, Some(cursor)) => match cursor.binds(columns.len()) {
+                Some(values) => (filter.before(values), Some(cursor), true),
+                None => (filter, None, false),
+            },
+            (None, None) => (filter, None, false),
+        };
+
+        let total = match &cursor {
+            Some(cursor) => cursor.total,
+            None if count => {
+                let count_sql = format!("{}{}", Self::count_query(), filter.count_where_sql());
+                let mut total_query = sqlx::query_as(&count_sql);
+                for bind in filter.count_binds() {
+                    total_query = total_query.bind(bind);
+                }
+                let total: (i64,) = total_query.fetch_one(pool.as_ref()).await?;
+                Some(total.0)
+            }
+            None => None,
+        };
+
+        // fetch one more row than the page to know if there's another page
+        let page_sql = format!(
+            "SELECT *, json_array({}) AS _cursor FROM ({}){}{} LIMIT ?",
+            columns.join(", "),
+            Self::page_query(),
+            filter.where_sql(),
+            filter.order_sql()
+        );
+        let mut rows_query = sqlx::query(&page_sql);
+        for bind in filter.binds() {
+            rows_query = rows_query.bind(bind);
+        }
+        let rows = rows_query
+            .bind(page_size + 1)
+            .fetch_all(pool.as_ref())
+            .await?;
+        let has_more = rows.len() as i64 > page_size;
+
+        let mut items = vec![];
+        let mut keys = vec![];
+        for row in rows.iter().take(page_size as usize) {
+            items.push(Self::from_row(row)?);
+            keys.push(row.try_get::<String, _>("_cursor")?);
+        }
+        if backwards {
+            items.reverse();
+            keys.reverse();
+        }
+
+        let cursor_for = |key: &String| {
+            Cursor {
+                values: serde_json::from_str(key).unwrap_or_default(),
+                total,
+            }
+            .encode()
+        };
+        let (more_after, more_before) = match backwards {
+            true => (true, has_more),
+            false => (has_more, cursor.is_some()),
+        };
+
+        Ok(CursorPage {
+            next: keys.last().filter(|_| more_after).map(cursor_for),
+            prev: keys.first().filter(|_| more_before).map(cursor_for),
+            items,
+            total,
+            page_size,
+        })
+    }
+
+    /// Everything in the table matching the filter, without pagination, e.g.
+    /// for an export.
+    async fn find_all(pool: &Arc<SqlitePool>, filter: &Filter) -> Result<Vec<Self>, sqlx::Error> {
+        let sql = format!(
+            "{}{}{}",
+            Self::page_query(),
+            filter.where_sql(),
+            filter.order_sql()
+        );
+        let mut rows_query = sqlx::query_as::<_, Self>(&sql);
+        for bind in filter.binds() {
+            rows_query = rows_query.bind(bind);
+        }
+        rows_query.fetch_all(pool.as_ref()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    #[derive(Debug, FromRow, Serialize)]
+    struct Item {
+        id: i64,
+        rank: i64,
+        name: String,
+    }
+
+    impl Paginatable for Item {
+        fn table_name() -> &'static str {
+            "items"
+        }
+    }
+
+    /// Ten items, in pairs of the same rank, with one that's filtered out.
+    async fn items() -> Arc<SqlitePool> {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+
+        sqlx::query("CREATE TABLE items (id INTEGER PRIMARY KEY, rank INTEGER, name TEXT)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        for id in 1..=11 {
+            let name = if id == 11 { "hidden" } else { "shown" };
+            sqlx::query("INSERT INTO items (id, rank, name) VALUES (?, ?, ?)")
+                .bind(id)
+                .bind(id / 2)
+                .bind(name)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        Arc::new(pool)
+    }
+
+    fn filter() -> Filter {
+        Filter::new()
+            .and_bind("name = ?", "shown")
+            .desc("rank")
+            .asc("id")
+    }
+
+    fn cursor(after: Option<String>, before: Option<String>) -> CursorPagination {
+        CursorPagination {
+            after,
+            before,
+            page_size: Some(3),
+        }
+    }
+
+    fn ids(page: &CursorPage<Item>) -> Vec<i64> {
+        page.items.iter().map(|item| item.id).collect()
+    }
+
+    #[test]
+    fn cursor_decodes_what_it_encodes() {
+        let cursor = Cursor {
+            values: vec![Value::from("2026-05-01"), Value::from(12), Value::from(3.1)],
+            total: Some(40),
+        };
+
+        let decoded = Cursor::decode(&cursor.encode()).unwrap();
+        assert_eq!(decoded.total, Some(40));
+        assert_eq!(
+            decoded.binds(3),
+            Some(vec![
+                Bind::Text("2026-05-01".to_string()),
+                Bind::Int(12),
+                Bind::Real(3.1)
+            ])
+        );
+        assert_eq!(decoded.binds(2), None);
+        assert!(Cursor::decode("not a cursor").is_none());
+    }
+
+    #[tokio::test]
+    async fn pages_forwards_and_back_with_cursors() {
+        let pool = items().await;
+
+        let first = Item::paginate_cursor(&pool, &cursor(None, None), filter(), true)
+            .await
+            .unwrap();
+        assert_eq!(ids(&first), [10, 8, 9]);
+        assert_eq!(first.total, Some(10));
+        assert!(first.prev.is_none());
+
+        let second =
+            Item::paginate_cursor(&pool, &cursor(first.next.clone(), None), filter(), true)
+                .await
+                .unwrap();
+        assert_eq!(ids(&second), [6, 7, 4]);
+        assert_eq!(second.total, Some(10));
+
+        let third = Item::paginate_cursor(&pool, &cursor(second.next, None), filter(), true)
+            .await
+            .unwrap();
+        assert_eq!(ids(&third), [5, 2, 3]);
+
+        let last = Item::paginate_cursor(&pool, &cursor(third.next.clone(), None), filter(), true)
+            .await
+            .unwrap();
+        assert_eq!(ids(&last), [1]);
+        assert!(last.next.is_none());
+
+        let back = Item::paginate_cursor(&pool, &cursor(None, last.prev), filter(), true)
+            .await
+            .unwrap();
+        assert_eq!(ids(&back), ids(&third));
+        assert_eq!(back.next, third.next);
+
+        let start = Item::paginate_cursor(&pool, &cursor(None, second.prev), filter(), true)
+            .await
+            .unwrap();
+        assert_eq!(ids(&start), ids(&first));
+        assert!(start.prev.is_none());
+    }
 }
@@ -0,0 +1,173 @@
+use std::{
+    collections::HashMap,
+    env,
+    str::FromStr,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use log::warn;
+
+/// How many requests a single caller may make within a window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub max_requests: u32,
+    pub window: Duration,
+}
+
+impl RateLimit {
+    pub const fn per_minute(max_requests: u32) -> Self {
+        Self {
+            max_requests,
+            window: Duration::from_secs(60),
+        }
+    }
+
+    pub const fn per_hour(max_requests: u32) -> Self {
+        Self {
+            max_requests,
+            window: Duration::from_secs(60 * 60),
+        }
+    }
+}
+
+impl FromStr for RateLimit {
+    type Err = String;
+
+    /// Parses `"<requests>/<seconds>"`, e.g. `"10/60"` for ten requests a minute.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (requests, seconds) = s
+            .trim()
+            .trim_matches('"')
+            .split_once('/')
+            .ok_or_else(|| format!("Invalid rate limit: {s}"))?;
+
+        let max_requests = requests
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| format!("Invalid number of requests: {s}"))?;
+        let seconds = seconds
+            .trim()
+            .parse::<u64>()
+            .map_err(|_| format!("Invalid number of seconds: {s}"))?;
+
+        Ok(Self {
+            max_requests,
+            window: Duration::from_secs(seconds),
+        })
+    }
+}
+
+/// A named limit that can be applied to a route with the `Throttle` extractor.
+/// The default can be overridden with a `RATE_LIMIT_<NAME>` environment variable.
+pub trait RateLimitPolicy: Send + Sync {
+    const NAME: &'static str;
+    const DEFAULT: RateLimit;
+}
+
+pub struct SignIn;
+impl RateLimitPolicy for SignIn {
+    const NAME: &'static str = "SIGN_IN";
+    const DEFAULT: RateLimit = RateLimit::per_minute(10);
+}
+
+pub struct SubmitTown;
+impl RateLimitPolicy for SubmitTown {
+    const NAME: &'static str = "SUBMIT_TOWN";
+    const DEFAULT: RateLimit = RateLimit::per_minute(5);
+}
+
+pub struct AddRace;
+impl RateLimitPolicy for AddRace {
+    const NAME: &'static str = "ADD_RACE";
+    const DEFAULT: RateLimit = RateLimit::per_hour(20);
+}
+
+pub struct Search;
+impl RateLimitPolicy for Search {
+    const NAME: &'static str = "SEARCH";
+    const DEFAULT: RateLimit = RateLimit::per_minute(120);
+}
+
+struct Window {
+    resets_at: Instant,
+    count: u32,
+}
+
+/// Fixed-window request counters, kept in memory.
+pub struct RateLimiter {
+    overrides: HashMap<String, RateLimit>,
+    windows: Mutex<HashMap<String, Window>>,
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::from_env()
+    }
+}
+
+impl RateLimiter {
+    /// The most counters we keep around before sweeping out expired ones.
+    const MAX_WINDOWS: usize = 10_000;
+
+    pub fn new(overrides: HashMap<String, RateLimit>) -> Self {
+        Self {
+            overrides,
+            windows: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Reads every `RATE_LIMIT_<NAME>="<requests>/<seconds>"` variable.
+    pub fn from_env() -> Self {
+        let mut overrides = HashMap::new();
+
+        for (key, value) in env::vars() {
+            let Some(name) = key.strip_prefix("RATE_LIMIT_") else {
+                continue;
+            };
+            match RateLimit::from_str(&value) {
+                Ok(limit) => {
+                    overrides.insert(name.to_string(), limit);
+                }
+                Err(e) => warn!("Ignoring {key}: {e}"),
+            }
+        }
+
+        Self::new(overrides)
+    }
+
+    pub fn limit_for<P: RateLimitPolicy>(&self) -> RateLimit {
+        self.overrides.get(P::NAME).copied().unwrap_or(P::DEFAULT)
+    }
+
+    /// Counts a request against `key` under policy `P`. When the caller is
+    /// over the limit, returns how long until they may try again.
+    pub fn check<P: RateLimitPolicy>(&self, key: &str) -> Result<(), Duration> {
+        let limit = self.limit_for::<P>();
+        let now = Instant::now();
+        let mut windows = self.windows.lock().unwrap_or_else(|e| e.into_inner());
+
+        if windows.len() >= Self::MAX_WINDOWS {
+            windows.retain(|_, w| w.resets_at > now);
+        }
+
+        let window = windows
+            .entry(format!("{}:{key}", P::NAME))
+            .or_insert(Window {
+                resets_at: now + limit.window,
+                count: 0,
+            });
+
+        if window.resets_at <= now {
+            window.resets_at = now + limit.window;
+            window.count = 0;
+        }
+
+        if window.count >= limit.max_requests {
+            return Err(window.resets_at - now);
+        }
+
+        window.count += 1;
+        Ok(())
+    }
+}
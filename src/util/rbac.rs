@@ -1,3 +1,5 @@
+use axum::http::StatusCode;
+
 use crate::domain::rbac::Action;
 
 pub trait Can<T> {
@@ -5,4 +7,17 @@ pub trait Can<T> {
     fn cannot(&self, action: Action, resource: &T) -> bool {
         !self.can(action, resource)
     }
+
+    /// Same as `can`, but shaped for handlers so the check can be `?`-ed.
+    ///
+    /// Example:
+    /// ```ignore
+    /// user.authorize(Action::Update, &race)?;
+    /// ```
+    fn authorize(&self, action: Action, resource: &T) -> Result<(), StatusCode> {
+        match self.can(action, resource) {
+            true => Ok(()),
+            false => Err(StatusCode::FORBIDDEN),
+        }
+    }
 }